@@ -39,6 +39,9 @@ pub use notifications::{
 mod memory_overlay;
 pub use memory_overlay::{MemoryOverlayStateProvider, MemoryOverlayStateProviderRef};
 
+mod state_override;
+pub use state_override::{StateOverrideProvider, StateOverrides};
+
 #[cfg(any(test, feature = "test-utils"))]
 /// Common test helpers
 pub mod test_utils;
@@ -206,6 +206,20 @@ impl<N: NodePrimitives> StateProofProvider for MemoryOverlayStateProviderRef<'_,
         input.prepend_self(self.trie_input().clone());
         self.historical.witness(input, target, mode)
     }
+
+    fn proof_input(&self) -> ProviderResult<Option<(B256, TrieInput)>> {
+        let Some(oldest) = self.in_memory.last() else { return self.historical.proof_input() };
+
+        let mut input = self.trie_input().clone();
+        let base = match self.historical.proof_input()? {
+            Some((base, historical)) => {
+                input.prepend_self(historical);
+                base
+            }
+            None => oldest.recovered_block().parent_hash(),
+        };
+        Ok(Some((base, input)))
+    }
 }
 
 impl<N: NodePrimitives> HashedPostStateProvider for MemoryOverlayStateProviderRef<'_, N> {
@@ -0,0 +1,389 @@
+use alloy_primitives::{
+    keccak256,
+    map::{AddressMap, B256Map},
+    Address, BlockNumber, Bytes, StorageKey, StorageValue, B256, U256,
+};
+use reth_errors::ProviderResult;
+use reth_primitives_traits::{Account, Bytecode};
+use reth_storage_api::{
+    AccountReader, BlockHashReader, BytecodeReader, HashedPostStateProvider, StateProofProvider,
+    StateProvider, StateProviderBox, StateRootProvider, StorageRootProvider,
+};
+use reth_trie::{
+    updates::TrieUpdates, AccountProof, HashedPostState, HashedStorage, MultiProof,
+    MultiProofTargets, StorageMultiProof, TrieInput,
+};
+use revm::database::BundleState;
+use std::sync::Arc;
+
+/// Account, storage and bytecode overrides of a [`StateOverrideProvider`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateOverrides {
+    /// Overridden accounts, `None` if the account is deleted.
+    accounts: AddressMap<Option<Account>>,
+    /// Overridden storage of accounts.
+    storages: AddressMap<StorageOverride>,
+    /// Bytecodes of overridden accounts by code hash.
+    bytecodes: B256Map<Bytecode>,
+}
+
+impl StateOverrides {
+    /// Returns `true` if nothing is overridden.
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty() && self.storages.is_empty() && self.bytecodes.is_empty()
+    }
+
+    /// Returns the overrides as a [`HashedPostState`], to be applied on top of the trie of the
+    /// underlying state.
+    pub fn hashed_post_state(&self) -> HashedPostState {
+        let mut state = HashedPostState::default();
+        state
+            .accounts
+            .extend(self.accounts.iter().map(|(address, account)| (keccak256(address), *account)));
+        state.storages.extend(
+            self.storages.iter().map(|(address, storage)| (keccak256(address), storage.hashed())),
+        );
+        state
+    }
+
+    /// Returns the overridden storage of the account as a [`HashedStorage`].
+    fn hashed_storage(&self, address: &Address) -> HashedStorage {
+        self.storages.get(address).map(StorageOverride::hashed).unwrap_or_default()
+    }
+}
+
+/// Overridden storage of an account.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct StorageOverride {
+    /// Whether the storage of the underlying state is discarded.
+    wiped: bool,
+    /// Overridden storage slots.
+    slots: B256Map<U256>,
+}
+
+impl StorageOverride {
+    fn hashed(&self) -> HashedStorage {
+        HashedStorage::from_iter(
+            self.wiped,
+            self.slots.iter().map(|(key, value)| (keccak256(key), *value)),
+        )
+    }
+}
+
+/// A state provider that layers in-memory account, storage and bytecode overrides on top of
+/// another state provider.
+///
+/// The overrides are shared between clones of the provider and only copied when a clone is
+/// modified, so a provider can be cheaply forked, e.g. to simulate several calls on top of the
+/// same overrides. Providers can be nested with [`StateOverrideProvider::nested`], each layer
+/// taking precedence over the layers below it.
+#[derive(Debug, Clone)]
+pub struct StateOverrideProvider<S> {
+    /// The underlying state provider, for lookups that are not overridden.
+    inner: S,
+    /// The overrides applied on top of the underlying state.
+    overrides: Arc<StateOverrides>,
+}
+
+impl<S> StateOverrideProvider<S> {
+    /// Creates a new provider without overrides on top of `inner`.
+    pub fn new(inner: S) -> Self {
+        Self::with_overrides(inner, Arc::default())
+    }
+
+    /// Creates a new provider with the given overrides on top of `inner`.
+    pub const fn with_overrides(inner: S, overrides: Arc<StateOverrides>) -> Self {
+        Self { inner, overrides }
+    }
+
+    /// Returns the underlying state provider.
+    pub const fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Consumes the provider and returns the underlying state provider.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+
+    /// Returns the overrides applied on top of the underlying state.
+    pub fn overrides(&self) -> &Arc<StateOverrides> {
+        &self.overrides
+    }
+
+    /// Returns a new provider without overrides on top of this one.
+    ///
+    /// Overrides of the returned provider don't affect this one.
+    pub fn nested(&self) -> StateOverrideProvider<&Self> {
+        StateOverrideProvider::new(self)
+    }
+
+    /// Overrides the account, deleting it and its storage if `account` is `None`.
+    pub fn set_account(&mut self, address: Address, account: Option<Account>) {
+        let overrides = Arc::make_mut(&mut self.overrides);
+        if account.is_none() {
+            overrides
+                .storages
+                .insert(address, StorageOverride { wiped: true, ..Default::default() });
+        }
+        overrides.accounts.insert(address, account);
+    }
+
+    /// Overrides the value of a storage slot of the account.
+    pub fn set_storage(&mut self, address: Address, key: B256, value: U256) {
+        let overrides = Arc::make_mut(&mut self.overrides);
+        overrides.storages.entry(address).or_default().slots.insert(key, value);
+    }
+
+    /// Replaces the whole storage of the account with the given slots.
+    pub fn replace_storage(
+        &mut self,
+        address: Address,
+        slots: impl IntoIterator<Item = (B256, U256)>,
+    ) {
+        let overrides = Arc::make_mut(&mut self.overrides);
+        overrides
+            .storages
+            .insert(address, StorageOverride { wiped: true, slots: slots.into_iter().collect() });
+    }
+}
+
+impl<S: StateProvider> StateOverrideProvider<S> {
+    /// Overrides the balance of the account, creating it if it does not exist.
+    pub fn set_balance(&mut self, address: Address, balance: U256) -> ProviderResult<()> {
+        let account = self.basic_account(&address)?.unwrap_or_default();
+        self.set_account(address, Some(Account { balance, ..account }));
+        Ok(())
+    }
+
+    /// Overrides the nonce of the account, creating it if it does not exist.
+    pub fn set_nonce(&mut self, address: Address, nonce: u64) -> ProviderResult<()> {
+        let account = self.basic_account(&address)?.unwrap_or_default();
+        self.set_account(address, Some(Account { nonce, ..account }));
+        Ok(())
+    }
+
+    /// Overrides the code of the account, creating it if it does not exist.
+    pub fn set_code(&mut self, address: Address, code: Bytecode) -> ProviderResult<()> {
+        let account = self.basic_account(&address)?.unwrap_or_default();
+        let code_hash = code.hash_slow();
+        let bytecode_hash = (!code.is_empty()).then_some(code_hash);
+        self.set_account(address, Some(Account { bytecode_hash, ..account }));
+        Arc::make_mut(&mut self.overrides).bytecodes.insert(code_hash, code);
+        Ok(())
+    }
+}
+
+impl<S: StateProvider + 'static> StateOverrideProvider<S> {
+    /// Wraps the [`Self`] in a `Box`.
+    pub fn boxed(self) -> StateProviderBox {
+        Box::new(self)
+    }
+}
+
+impl<S: BlockHashReader> BlockHashReader for StateOverrideProvider<S> {
+    fn block_hash(&self, number: BlockNumber) -> ProviderResult<Option<B256>> {
+        self.inner.block_hash(number)
+    }
+
+    fn canonical_hashes_range(
+        &self,
+        start: BlockNumber,
+        end: BlockNumber,
+    ) -> ProviderResult<Vec<B256>> {
+        self.inner.canonical_hashes_range(start, end)
+    }
+}
+
+impl<S: AccountReader> AccountReader for StateOverrideProvider<S> {
+    fn basic_account(&self, address: &Address) -> ProviderResult<Option<Account>> {
+        if let Some(account) = self.overrides.accounts.get(address) {
+            return Ok(*account)
+        }
+
+        self.inner.basic_account(address)
+    }
+}
+
+impl<S: StateRootProvider> StateRootProvider for StateOverrideProvider<S> {
+    fn state_root(&self, state: HashedPostState) -> ProviderResult<B256> {
+        self.state_root_from_nodes(TrieInput::from_state(state))
+    }
+
+    fn state_root_from_nodes(&self, mut input: TrieInput) -> ProviderResult<B256> {
+        input.prepend(self.overrides.hashed_post_state());
+        self.inner.state_root_from_nodes(input)
+    }
+
+    fn state_root_with_updates(
+        &self,
+        state: HashedPostState,
+    ) -> ProviderResult<(B256, TrieUpdates)> {
+        self.state_root_from_nodes_with_updates(TrieInput::from_state(state))
+    }
+
+    fn state_root_from_nodes_with_updates(
+        &self,
+        mut input: TrieInput,
+    ) -> ProviderResult<(B256, TrieUpdates)> {
+        input.prepend(self.overrides.hashed_post_state());
+        self.inner.state_root_from_nodes_with_updates(input)
+    }
+}
+
+impl<S: StorageRootProvider> StorageRootProvider for StateOverrideProvider<S> {
+    fn storage_root(&self, address: Address, storage: HashedStorage) -> ProviderResult<B256> {
+        let mut merged = self.overrides.hashed_storage(&address);
+        merged.extend(&storage);
+        self.inner.storage_root(address, merged)
+    }
+
+    fn storage_proof(
+        &self,
+        address: Address,
+        slot: B256,
+        storage: HashedStorage,
+    ) -> ProviderResult<reth_trie::StorageProof> {
+        let mut merged = self.overrides.hashed_storage(&address);
+        merged.extend(&storage);
+        self.inner.storage_proof(address, slot, merged)
+    }
+
+    fn storage_multiproof(
+        &self,
+        address: Address,
+        slots: &[B256],
+        storage: HashedStorage,
+    ) -> ProviderResult<StorageMultiProof> {
+        let mut merged = self.overrides.hashed_storage(&address);
+        merged.extend(&storage);
+        self.inner.storage_multiproof(address, slots, merged)
+    }
+}
+
+impl<S: StateProofProvider> StateProofProvider for StateOverrideProvider<S> {
+    fn proof(
+        &self,
+        mut input: TrieInput,
+        address: Address,
+        slots: &[B256],
+    ) -> ProviderResult<AccountProof> {
+        input.prepend(self.overrides.hashed_post_state());
+        self.inner.proof(input, address, slots)
+    }
+
+    fn multiproof(
+        &self,
+        mut input: TrieInput,
+        targets: MultiProofTargets,
+    ) -> ProviderResult<MultiProof> {
+        input.prepend(self.overrides.hashed_post_state());
+        self.inner.multiproof(input, targets)
+    }
+
+    fn witness(
+        &self,
+        mut input: TrieInput,
+        target: HashedPostState,
+        mode: reth_trie::ExecutionWitnessMode,
+    ) -> ProviderResult<Vec<Bytes>> {
+        input.prepend(self.overrides.hashed_post_state());
+        self.inner.witness(input, target, mode)
+    }
+}
+
+impl<S: HashedPostStateProvider> HashedPostStateProvider for StateOverrideProvider<S> {
+    fn hashed_post_state(&self, bundle_state: &BundleState) -> HashedPostState {
+        self.inner.hashed_post_state(bundle_state)
+    }
+}
+
+impl<S: StateProvider> StateProvider for StateOverrideProvider<S> {
+    fn storage(
+        &self,
+        address: Address,
+        storage_key: StorageKey,
+    ) -> ProviderResult<Option<StorageValue>> {
+        if let Some(storage) = self.overrides.storages.get(&address) {
+            if let Some(value) = storage.slots.get(&storage_key) {
+                return Ok(Some(*value))
+            }
+            if storage.wiped {
+                return Ok(None)
+            }
+        }
+
+        self.inner.storage(address, storage_key)
+    }
+}
+
+impl<S: BytecodeReader> BytecodeReader for StateOverrideProvider<S> {
+    fn bytecode_by_hash(&self, code_hash: &B256) -> ProviderResult<Option<Bytecode>> {
+        if let Some(bytecode) = self.overrides.bytecodes.get(code_hash) {
+            return Ok(Some(bytecode.clone()))
+        }
+
+        self.inner.bytecode_by_hash(code_hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+    use reth_storage_api::noop::NoopProvider;
+
+    const ADDRESS: Address = address!("0x00000000000000000000000000000000000000aa");
+
+    fn provider() -> StateOverrideProvider<NoopProvider> {
+        StateOverrideProvider::new(NoopProvider::default())
+    }
+
+    #[test]
+    fn overrides_are_layered() {
+        let mut provider = provider();
+        provider.set_balance(ADDRESS, U256::from(1)).unwrap();
+        provider.set_storage(ADDRESS, B256::with_last_byte(1), U256::from(2));
+        provider.set_code(ADDRESS, Bytecode::new_raw(Bytes::from_static(&[0x00]))).unwrap();
+
+        let account = provider.basic_account(&ADDRESS).unwrap().unwrap();
+        assert_eq!(account.balance, U256::from(1));
+        assert_eq!(
+            provider.account_code(&ADDRESS).unwrap().unwrap().original_bytes(),
+            Bytes::from_static(&[0x00])
+        );
+        assert_eq!(
+            provider.storage(ADDRESS, B256::with_last_byte(1)).unwrap(),
+            Some(U256::from(2))
+        );
+
+        // A nested layer takes precedence without modifying its parent.
+        let mut nested = provider.nested();
+        nested.set_nonce(ADDRESS, 5).unwrap();
+        nested.replace_storage(ADDRESS, [(B256::with_last_byte(2), U256::from(3))]);
+        let nested_account = nested.basic_account(&ADDRESS).unwrap().unwrap();
+        assert_eq!(nested_account, Account { nonce: 5, ..account });
+        assert_eq!(nested.storage(ADDRESS, B256::with_last_byte(1)).unwrap(), None);
+        assert_eq!(nested.storage(ADDRESS, B256::with_last_byte(2)).unwrap(), Some(U256::from(3)));
+        assert_eq!(provider.basic_account(&ADDRESS).unwrap(), Some(account));
+
+        // Deleting an account also deletes its storage.
+        nested.set_account(ADDRESS, None);
+        assert_eq!(nested.basic_account(&ADDRESS).unwrap(), None);
+        assert_eq!(nested.storage(ADDRESS, B256::with_last_byte(2)).unwrap(), None);
+    }
+
+    #[test]
+    fn overrides_are_copied_on_write() {
+        let mut provider = provider();
+        provider.set_balance(ADDRESS, U256::from(1)).unwrap();
+
+        let mut fork = provider.clone();
+        assert!(Arc::ptr_eq(provider.overrides(), fork.overrides()));
+
+        fork.set_balance(ADDRESS, U256::from(2)).unwrap();
+        assert!(!Arc::ptr_eq(provider.overrides(), fork.overrides()));
+        assert_eq!(provider.basic_account(&ADDRESS).unwrap().unwrap().balance, U256::from(1));
+        assert_eq!(fork.basic_account(&ADDRESS).unwrap().unwrap().balance, U256::from(2));
+    }
+}
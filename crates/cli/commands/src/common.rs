@@ -212,6 +212,11 @@ impl<C: ChainSpecParser> EnvironmentArgs<C> {
         .with_minimum_pruning_distance(config.prune.minimum_pruning_distance)
         .with_bal_store(bal_store);
 
+        // A read-only environment may be opened next to a running node, so catch up with its
+        // writes to RocksDB and static files whenever a new database transaction is opened.
+        let factory =
+            if access.is_read_write() { factory } else { factory.with_read_only_sync(false) };
+
         // Check for consistency between database and static files.
         if !access.is_read_only_inconsistent() &&
             let Some(unwind_target) =
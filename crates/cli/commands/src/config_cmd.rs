@@ -1,12 +1,15 @@
 //! CLI command to show configs.
 
-use clap::Parser;
+use clap::{Parser, Subcommand, ValueEnum};
 use eyre::{bail, WrapErr};
+use reth_chainspec::{EthChainSpec, EthereumHardforks};
+use reth_cli::chainspec::ChainSpecParser;
 use reth_config::Config;
-use std::path::PathBuf;
+use reth_node_core::args::{DatadirArgs, PruningArgs, StaticFilesArgs};
+use std::{path::PathBuf, sync::Arc};
 /// `reth config` command
 #[derive(Debug, Parser)]
-pub struct Command {
+pub struct Command<C: ChainSpecParser> {
     /// The path to the configuration file to use.
     #[arg(long, value_name = "FILE", verbatim_doc_comment)]
     config: Option<PathBuf>,
@@ -14,11 +17,71 @@ pub struct Command {
     /// Show the default config
     #[arg(long, verbatim_doc_comment, conflicts_with = "config")]
     default: bool,
+
+    #[command(subcommand)]
+    command: Option<Subcommands<C>>,
+}
+
+/// `reth config` subcommands
+#[derive(Debug, Subcommand)]
+pub enum Subcommands<C: ChainSpecParser> {
+    /// Validates the configuration file together with the given CLI overrides and prints the
+    /// effective configuration the node would run with.
+    Validate(ValidateCommand<C>),
+}
+
+/// `reth config validate` command
+#[derive(Debug, Parser)]
+pub struct ValidateCommand<C: ChainSpecParser> {
+    /// Parameters for datadir configuration
+    #[command(flatten)]
+    datadir: DatadirArgs,
+
+    /// The path to the configuration file to use. Defaults to `reth.toml` in the data directory.
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
+
+    /// The chain this node is running.
+    ///
+    /// Possible values are either a built-in chain or the path to a chain specification file.
+    #[arg(
+        long,
+        value_name = "CHAIN_OR_PATH",
+        long_help = C::help_message(),
+        default_value = C::default_value(),
+        value_parser = C::parser()
+    )]
+    chain: Arc<C::ChainSpec>,
+
+    /// Pruning overrides, as passed to `reth node`
+    #[command(flatten)]
+    pruning: PruningArgs,
+
+    /// Static files overrides, as passed to `reth node`
+    #[command(flatten)]
+    static_files: StaticFilesArgs,
+
+    /// Format to print the effective configuration in
+    #[arg(long, value_enum, default_value_t = OutputFormat::Toml)]
+    format: OutputFormat,
 }
 
-impl Command {
+/// Output format of the effective configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// TOML, as used by the configuration file
+    Toml,
+    /// JSON
+    Json,
+}
+
+impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C> {
     /// Execute `config` command
     pub async fn execute(&self) -> eyre::Result<()> {
+        if let Some(Subcommands::Validate(command)) = &self.command {
+            return command.execute()
+        }
+
         let config = if self.default {
             Config::default()
         } else {
@@ -36,3 +99,91 @@ impl Command {
         Ok(())
     }
 }
+
+impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> ValidateCommand<C> {
+    /// Execute `config validate` command
+    pub fn execute(&self) -> eyre::Result<()> {
+        let config = self.effective_config()?;
+        config.validate().wrap_err("Invalid configuration")?;
+
+        match self.format {
+            OutputFormat::Toml => println!("{}", toml::to_string_pretty(&config)?),
+            OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&config)?),
+        }
+        Ok(())
+    }
+
+    /// Loads the configuration file and applies the CLI overrides the same way the node does on
+    /// launch, without writing anything back to disk.
+    fn effective_config(&self) -> eyre::Result<Config> {
+        let path = self
+            .config
+            .clone()
+            .unwrap_or_else(|| self.datadir.clone().resolve_datadir(self.chain.chain()).config());
+
+        let mut config = if path.exists() {
+            let contents = reth_fs_util::read_to_string(&path)?;
+            toml::from_str::<Config>(&contents)
+                .wrap_err_with(|| format!("Could not parse config file: {}", path.display()))?
+        } else if self.config.is_some() {
+            bail!("Config file does not exist: {}", path.display());
+        } else {
+            Config::default()
+        };
+
+        config.prune.segments.migrate();
+        if let Some(prune_config) = self.pruning.prune_config(self.chain.as_ref()) {
+            config.set_prune_config(prune_config);
+        }
+        config.static_files =
+            self.static_files.merge_with_config(config.static_files, self.pruning.is_minimal());
+
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
+
+    fn parse_validate(args: &[&str]) -> ValidateCommand<EthereumChainSpecParser> {
+        let command = Command::<EthereumChainSpecParser>::try_parse_from(
+            ["reth", "validate"].iter().chain(args),
+        )
+        .unwrap();
+        match command.command {
+            Some(Subcommands::Validate(command)) => command,
+            None => panic!("expected validate subcommand"),
+        }
+    }
+
+    #[test]
+    fn validate_applies_cli_overrides() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("reth.toml");
+        reth_fs_util::write(&path, "[prune]\nblock_interval = 10\n").unwrap();
+
+        let command = parse_validate(&[
+            "--config",
+            path.to_str().unwrap(),
+            "--full",
+            "--static-files.blocks-per-file.headers",
+            "1000",
+        ]);
+        let config = command.effective_config().unwrap();
+
+        assert!(config.prune.segments.account_history.is_some());
+        assert_eq!(config.static_files.blocks_per_file.headers, Some(1000));
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_short_prune_distance() {
+        let command =
+            parse_validate(&["--datadir", "/nonexistent", "--prune.receipts.distance", "1"]);
+        let config = command.effective_config().unwrap();
+
+        assert!(config.validate().is_err());
+    }
+}
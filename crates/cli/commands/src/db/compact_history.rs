@@ -0,0 +1,45 @@
+//! `reth db compact-history` command for compacting fragmented history index shards.
+
+use clap::Parser;
+use reth_db_common::DbTool;
+use reth_provider::providers::ProviderNodeTypes;
+use tracing::info;
+
+/// The arguments for the `reth db compact-history` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// Number of addresses or storage slots compacted per committed batch.
+    ///
+    /// Progress is checkpointed after every batch, so an interrupted compaction resumes from the
+    /// last committed one.
+    #[arg(long, default_value_t = 10_000)]
+    batch_size: usize,
+}
+
+impl Command {
+    /// Execute `db compact-history` command
+    pub fn execute<N: ProviderNodeTypes>(self, tool: &DbTool<N>) -> eyre::Result<()> {
+        let progress =
+            tool.provider_factory.compact_history_shards(self.batch_size, |progress| {
+                info!(
+                    target: "reth::cli",
+                    accounts = progress.accounts,
+                    storage_slots = progress.storage_slots,
+                    compacted_keys = progress.compacted_keys,
+                    removed_shards = progress.removed_shards,
+                    "Compacting history shards"
+                );
+            })?;
+
+        info!(
+            target: "reth::cli",
+            accounts = progress.accounts,
+            storage_slots = progress.storage_slots,
+            compacted_keys = progress.compacted_keys,
+            removed_shards = progress.removed_shards,
+            "Compacted history shards"
+        );
+
+        Ok(())
+    }
+}
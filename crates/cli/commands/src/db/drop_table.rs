@@ -0,0 +1,95 @@
+use clap::Parser;
+use reth_db_api::{table::Table, TableViewer, Tables};
+use reth_db_common::DbTool;
+use reth_fs_util as fs;
+use reth_node_core::dirs::{ChainPath, DataDirPath};
+use reth_provider::providers::ProviderNodeTypes;
+use std::{
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tracing::info;
+
+/// The arguments for the `reth db drop-table` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The table to drop
+    table: Tables,
+
+    /// Directory to write the compressed export of the table to before dropping it.
+    ///
+    /// Defaults to `<DATADIR>/exports`. The export can be loaded back with `reth db
+    /// restore-table`.
+    #[arg(long, value_name = "DIR")]
+    export_dir: Option<PathBuf>,
+
+    /// Drop the table without exporting its contents first
+    #[arg(long)]
+    skip_export: bool,
+
+    /// Bypasses the interactive confirmation and drops the table directly
+    #[arg(short, long)]
+    force: bool,
+}
+
+impl Command {
+    /// Execute `db drop-table` command
+    pub fn execute<N: ProviderNodeTypes>(
+        self,
+        data_dir: &ChainPath<DataDirPath>,
+        tool: &DbTool<N>,
+    ) -> eyre::Result<()> {
+        let table = self.table.name();
+
+        if !self.force {
+            print!("Type the name of the table to confirm dropping it ({table}): ");
+            io::stdout().flush()?;
+
+            let mut input = String::new();
+            io::stdin().read_line(&mut input)?;
+
+            if input.trim() != table {
+                println!("Table drop aborted!");
+                return Ok(())
+            }
+        }
+
+        let export_path = (!self.skip_export).then(|| {
+            let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+            self.export_dir
+                .unwrap_or_else(|| data_dir.data_dir().join("exports"))
+                .join(format!("{table}-{}.bin.zst", timestamp.as_secs()))
+        });
+
+        self.table.view(&DropTableViewer { tool, export_path })
+    }
+}
+
+struct DropTableViewer<'a, N: ProviderNodeTypes> {
+    tool: &'a DbTool<N>,
+    export_path: Option<PathBuf>,
+}
+
+impl<N: ProviderNodeTypes> TableViewer<()> for DropTableViewer<'_, N> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<(), Self::Error> {
+        if let Some(path) = &self.export_path {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            info!(target: "reth::cli", table = T::NAME, ?path, "Exporting table");
+            let mut encoder = zstd::Encoder::new(BufWriter::new(fs::create_file(path)?), 0)?;
+            let rows = self.tool.export_table::<T>(&mut encoder)?;
+            encoder.finish()?.into_inner().map_err(|err| err.into_error())?.sync_all()?;
+            info!(target: "reth::cli", table = T::NAME, rows, ?path, "Exported table");
+        }
+
+        self.tool.drop_table::<T>()?;
+        info!(target: "reth::cli", table = T::NAME, "Dropped table");
+
+        Ok(())
+    }
+}
@@ -2,15 +2,18 @@ use alloy_primitives::{hex, Address, BlockHash, B256};
 use clap::Parser;
 use reth_db::{
     static_file::{
-        AccountChangesetMask, ColumnSelectorOne, ColumnSelectorTwo, HeaderWithHashMask,
-        ReceiptMask, TransactionMask, TransactionSenderMask,
+        AccountChangesetMask, BlobSidecarsMask, BlockAccessListMask, ColumnSelectorOne,
+        ColumnSelectorTwo, HeaderWithHashMask, ReceiptMask, TransactionMask, TransactionSenderMask,
     },
     RawDupSort,
 };
 use reth_db_api::{
     cursor::{DbCursorRO, DbDupCursorRO},
     database::Database,
-    models::{storage_sharded_key::StorageShardedKey, ShardedKey},
+    models::{
+        storage_sharded_key::StorageShardedKey, ShardedKey, StoredBlobSidecars,
+        StoredBlockAccessList,
+    },
     table::{Compress, Decompress, DupSort, Table},
     tables,
     transaction::DbTx,
@@ -21,7 +24,7 @@ use reth_node_api::{HeaderTy, ReceiptTy, TxTy};
 use reth_node_builder::NodeTypesWithDB;
 use reth_primitives_traits::ValueWithSubKey;
 use reth_provider::{
-    providers::ProviderNodeTypes, ChangeSetReader, RocksDBProviderFactory,
+    providers::ProviderNodeTypes, ChangeSetReader, ReceiptProvider, RocksDBProviderFactory,
     StaticFileProviderFactory,
 };
 use reth_static_file_types::StaticFileSegment;
@@ -207,6 +210,12 @@ impl Command {
                     StaticFileSegment::StorageChangeSets => {
                         unreachable!("storage changesets handled above");
                     }
+                    StaticFileSegment::BlobSidecars => {
+                        (table_key::<tables::Headers>(&key)?, None, BlobSidecarsMask::MASK)
+                    }
+                    StaticFileSegment::BlockAccessLists => {
+                        (table_key::<tables::Headers>(&key)?, None, BlockAccessListMask::MASK)
+                    }
                 };
 
                 // handle account changesets differently if a subkey is provided.
@@ -268,8 +277,12 @@ impl Command {
                                     println!("{}", serde_json::to_string_pretty(&transaction)?);
                                 }
                                 StaticFileSegment::Receipts => {
-                                    let receipt =
-                                        ReceiptTy::<N>::decompress(content[0].as_slice())?;
+                                    // Receipts can be stored with different encodings, which the
+                                    // provider tells apart.
+                                    let receipt = tool
+                                        .provider_factory
+                                        .static_file_provider()
+                                        .receipt(key)?;
                                     println!("{}", serde_json::to_string_pretty(&receipt)?);
                                 }
                                 StaticFileSegment::TransactionSenders => {
@@ -285,6 +298,19 @@ impl Command {
                                 StaticFileSegment::StorageChangeSets => {
                                     unreachable!("storage changeset static files are special cased before this match")
                                 }
+                                StaticFileSegment::BlobSidecars => {
+                                    let sidecars =
+                                        StoredBlobSidecars::decompress(content[0].as_slice())?;
+                                    println!(
+                                        "{}",
+                                        serde_json::to_string_pretty(sidecars.sidecars())?
+                                    );
+                                }
+                                StaticFileSegment::BlockAccessLists => {
+                                    let bal =
+                                        StoredBlockAccessList::decompress(content[0].as_slice())?;
+                                    println!("{}", serde_json::to_string_pretty(&bal.bal())?);
+                                }
                             }
                         }
                     }
@@ -1,5 +1,5 @@
 use super::tui::DbListTUI;
-use alloy_primitives::hex;
+use alloy_primitives::{hex, Bytes};
 use clap::{builder::RangedU64ValueParser, Parser};
 use eyre::WrapErr;
 use reth_chainspec::EthereumHardforks;
@@ -21,6 +21,14 @@ pub struct Command {
     /// Reverse the order of the entries. If enabled last table entries are read.
     #[arg(long, short, default_value_t = false)]
     reverse: bool,
+    /// Only list entries whose encoded key starts with these bytes, e.g. an address to list all
+    /// history shards of an account. Expects hex encoded bytes.
+    #[arg(long)]
+    key_prefix: Option<Bytes>,
+    /// Encoded key to start listing from, in the order given by `--reverse`. Expects hex encoded
+    /// bytes.
+    #[arg(long)]
+    start_key: Option<Bytes>,
     /// How many items to take from the walker
     #[arg(long, short, default_value_t = 5, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
     len: usize,
@@ -83,6 +91,8 @@ impl Command {
             min_key_size: self.min_key_size,
             min_value_size: self.min_value_size,
             reverse: self.reverse,
+            key_prefix: self.key_prefix.clone().unwrap_or_default().into(),
+            start_key: self.start_key.clone().map(Into::into),
             only_count: self.count,
         })
     }
@@ -161,4 +171,26 @@ mod tests {
 
         assert_eq!(command.len, 10);
     }
+
+    #[test]
+    fn command_parses_hex_keys() {
+        let command = Command::try_parse_from([
+            "list",
+            "AccountsHistory",
+            "--reverse",
+            "--key-prefix",
+            "0xdead",
+            "--start-key",
+            "0xdeadbeef",
+        ])
+        .unwrap();
+
+        assert!(command.reverse);
+        let filter = command.list_filter().unwrap();
+        assert_eq!(filter.key_prefix, vec![0xde, 0xad]);
+        assert_eq!(filter.start_key, Some(vec![0xde, 0xad, 0xbe, 0xef]));
+        assert!(
+            Command::try_parse_from(["list", "AccountsHistory", "--key-prefix", "0xzz"]).is_err()
+        );
+    }
 }
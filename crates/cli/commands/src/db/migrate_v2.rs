@@ -175,6 +175,8 @@ impl Command {
         let sf_provider = factory.static_file_provider();
 
         let mut cursor = provider.tx_ref().cursor_read::<tables::StorageChangeSets>()?;
+        let mut packed_cursor =
+            provider.tx_ref().cursor_read::<tables::PackedStorageChangeSets>()?;
 
         let first_block = provider
             .get_prune_checkpoint(PruneSegment::StorageHistory)?
@@ -190,10 +192,25 @@ impl Command {
 
         let mut count = 0u64;
         let mut walker = cursor.walk(Some((first_block, Address::ZERO).into()))?.peekable();
+        let mut packed_walker =
+            packed_cursor.walk(Some((first_block, Address::ZERO).into()))?.peekable();
 
         for block in first_block..=tip {
             let mut entries = Vec::new();
 
+            // Blocks packed by `reth db pack-storage-changesets` are not in the dupsort table.
+            while let Some(Ok((key, _))) = packed_walker.peek() {
+                if key.block_number() != block {
+                    break;
+                }
+                let (key, changeset) = packed_walker.next().expect("peeked")?;
+                entries.extend(changeset.into_entries().into_iter().map(|entry| StorageBeforeTx {
+                    address: key.address(),
+                    key: entry.key,
+                    value: entry.value,
+                }));
+            }
+
             while let Some(Ok((key, _))) = walker.peek() {
                 if key.block_number() != block {
                     break;
@@ -322,6 +339,7 @@ impl Command {
         // Migrated changeset tables (now in static files)
         clear_table!(tables::AccountChangeSets);
         clear_table!(tables::StorageChangeSets);
+        clear_table!(tables::PackedStorageChangeSets);
 
         // Senders — rebuilt by SenderRecovery
         clear_table!(tables::TransactionSenders);
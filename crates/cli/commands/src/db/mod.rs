@@ -12,20 +12,27 @@ use std::{
 mod account_storage;
 mod checksum;
 mod clear;
+mod compact_history;
 mod copy;
 mod diff;
+mod drop_table;
 mod get;
 mod list;
 mod migrate_v2;
+mod pack_storage_changesets;
 mod prune_checkpoints;
+mod rebuild;
 mod repair_trie;
+mod restore_table;
 mod settings;
 mod stage_checkpoints;
 mod state;
+mod static_file;
 mod static_file_header;
 mod stats;
 /// DB List TUI
 mod tui;
+mod tx_lookup;
 
 /// `reth db` command
 #[derive(Debug, Parser)]
@@ -60,10 +67,22 @@ pub enum Subcommands {
     },
     /// Deletes all table entries
     Clear(clear::Command),
+    /// Exports the contents of a table to disk and then deletes all its entries
+    DropTable(drop_table::Command),
+    /// Loads a table export written by `drop-table` back into the database
+    RestoreTable(restore_table::Command),
+    /// Rebuilds derived indices, e.g. the transaction hash index, from static files
+    Rebuild(rebuild::Command),
+    /// Compacts fragmented account and storage history shards
+    CompactHistory(compact_history::Command),
+    /// Moves storage changesets of old blocks into the prefix-compressed packed table
+    PackStorageChangesets(pack_storage_changesets::Command),
     /// Verifies trie consistency and outputs any inconsistencies
     RepairTrie(repair_trie::Command),
     /// Reads and displays the static file segment header
     StaticFileHeader(static_file_header::Command),
+    /// Rewrites or verifies existing static files
+    StaticFile(static_file::Command),
     /// Lists current and local database versions
     Version,
     /// Returns the full database path
@@ -78,6 +97,8 @@ pub enum Subcommands {
     AccountStorage(account_storage::Command),
     /// Gets account state and storage at a specific block
     State(state::Command),
+    /// Resolves a transaction hash to its block, index and storage location
+    TxLookup(tx_lookup::Command),
     /// Migrate storage layout from v1 (MDBX-only) to v2 (static files + RocksDB)
     #[command(name = "migrate-v2")]
     MigrateV2(migrate_v2::Command),
@@ -180,6 +201,32 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
                     command.execute(&tool)?;
                 });
             }
+            Subcommands::DropTable(command) => {
+                db_exec!(self.env, tool, N, AccessRights::RW, {
+                    command.execute(&data_dir, &tool)?;
+                });
+            }
+            Subcommands::RestoreTable(command) => {
+                db_exec!(self.env, tool, N, AccessRights::RW, {
+                    command.execute(&tool)?;
+                });
+            }
+            Subcommands::Rebuild(command) => {
+                let Environment { provider_factory, config, .. } =
+                    self.env.init::<N>(AccessRights::RW, ctx.task_executor.clone())?;
+
+                command.execute::<N>(provider_factory, config)?;
+            }
+            Subcommands::CompactHistory(command) => {
+                db_exec!(self.env, tool, N, AccessRights::RW, {
+                    command.execute(&tool)?;
+                });
+            }
+            Subcommands::PackStorageChangesets(command) => {
+                db_exec!(self.env, tool, N, AccessRights::RW, {
+                    command.execute(&tool)?;
+                });
+            }
             Subcommands::RepairTrie(command) => {
                 let access_rights =
                     if command.dry_run { AccessRights::RO } else { AccessRights::RW };
@@ -192,6 +239,11 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
                     command.execute(&tool)?;
                 });
             }
+            Subcommands::StaticFile(command) => {
+                db_exec!(self.env, tool, N, AccessRights::RW, {
+                    command.execute(&tool)?;
+                });
+            }
             Subcommands::Version => {
                 let local_db_version = match get_db_version(&db_path) {
                     Ok(version) => Some(version),
@@ -235,6 +287,11 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
                     command.execute(&tool)?;
                 });
             }
+            Subcommands::TxLookup(command) => {
+                db_exec!(self.env, tool, N, AccessRights::RO, {
+                    command.execute(&tool)?;
+                });
+            }
             Subcommands::MigrateV2(command) => {
                 let Environment { provider_factory, .. } =
                     self.env.init::<N>(AccessRights::RW, ctx.task_executor.clone())?;
@@ -0,0 +1,64 @@
+//! `reth db pack-storage-changesets` command for moving storage changesets into the packed table.
+
+use clap::Parser;
+use reth_db_api::{cursor::DbCursorRO, tables, transaction::DbTx};
+use reth_db_common::DbTool;
+use reth_provider::{
+    providers::ProviderNodeTypes, ChainStateBlockReader, DBProvider, StorageSettingsCache,
+};
+use tracing::info;
+
+/// The arguments for the `reth db pack-storage-changesets` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The last block whose storage changesets are packed.
+    ///
+    /// Defaults to, and is capped at, the last finalized block, so that packed changesets are
+    /// never unwound.
+    #[arg(long)]
+    to_block: Option<u64>,
+
+    /// Number of blocks packed per committed batch.
+    #[arg(long, default_value_t = 10_000)]
+    batch_size: u64,
+}
+
+impl Command {
+    /// Execute `db pack-storage-changesets` command
+    pub fn execute<N: ProviderNodeTypes>(self, tool: &DbTool<N>) -> eyre::Result<()> {
+        let factory = &tool.provider_factory;
+        if factory.cached_storage_settings().storage_v2 {
+            eyre::bail!("Storage changesets are stored in static files with storage v2")
+        }
+
+        let provider = factory.provider()?;
+        let Some(finalized) = provider.last_finalized_block_number()? else {
+            info!(target: "reth::cli", "No finalized block, no storage changesets to pack");
+            return Ok(())
+        };
+        let to_block = self.to_block.map_or(finalized, |to_block| to_block.min(finalized));
+        let Some((first, _)) =
+            provider.tx_ref().cursor_read::<tables::StorageChangeSets>()?.first()?
+        else {
+            info!(target: "reth::cli", "No storage changesets to pack");
+            return Ok(())
+        };
+        drop(provider);
+
+        let batch_size = self.batch_size.max(1);
+        let mut packed = 0;
+        for from in (first.block_number()..=to_block).step_by(batch_size as usize) {
+            let to = from.saturating_add(batch_size - 1).min(to_block);
+
+            let provider = factory.provider_rw()?;
+            packed += provider.pack_storage_changesets(from..=to)?;
+            provider.commit()?;
+
+            info!(target: "reth::cli", from, to, packed, "Packing storage changesets");
+        }
+
+        info!(target: "reth::cli", packed, to_block, "Packed storage changesets");
+
+        Ok(())
+    }
+}
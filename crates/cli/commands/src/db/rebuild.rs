@@ -0,0 +1,117 @@
+//! `reth db rebuild` command for re-deriving indices from static files.
+
+use crate::common::CliNodeTypes;
+use clap::{Parser, Subcommand};
+use reth_config::{config::TransactionLookupConfig, Config};
+use reth_db::DatabaseEnv;
+use reth_db_api::{tables, transaction::DbTxMut};
+use reth_node_builder::NodeTypesWithDBAdapter;
+use reth_primitives_traits::FastInstant as Instant;
+use reth_provider::{
+    DBProvider, DatabaseProviderFactory, ProviderFactory, RocksDBProviderFactory,
+    StageCheckpointReader, StageCheckpointWriter, StorageSettingsCache,
+};
+use reth_prune_types::PruneSegment;
+use reth_stages::{stages::TransactionLookupStage, ExecInput, ExecOutput, Stage};
+use reth_stages_types::{StageCheckpoint, StageId};
+use tracing::info;
+
+/// The arguments for the `reth db rebuild` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    #[command(subcommand)]
+    command: Subcommands,
+}
+
+#[derive(Subcommand, Debug)]
+enum Subcommands {
+    /// Rebuilds the transaction hash to number index from the transactions in static files.
+    ///
+    /// The existing index is cleared and re-derived up to the transaction lookup stage
+    /// checkpoint. Transactions outside of the configured prune distance are not indexed.
+    TxLookup {
+        /// Number of transactions to hash before flushing them to the ETL collector.
+        #[arg(long, default_value_t = TransactionLookupConfig::default().chunk_size)]
+        batch_size: u64,
+    },
+}
+
+impl Command {
+    /// Execute `db rebuild` command
+    pub fn execute<N: CliNodeTypes>(
+        self,
+        provider_factory: ProviderFactory<NodeTypesWithDBAdapter<N, DatabaseEnv>>,
+        config: Config,
+    ) -> eyre::Result<()> {
+        match self.command {
+            Subcommands::TxLookup { batch_size } => {
+                let provider_rw = provider_factory.database_provider_rw()?;
+
+                let Some(target) = provider_rw
+                    .get_stage_checkpoint(StageId::TransactionLookup)?
+                    .map(|checkpoint| checkpoint.block_number)
+                    .filter(|block| *block > 0)
+                else {
+                    info!(
+                        target: "reth::cli",
+                        "Transaction lookup stage has not run yet, nothing to rebuild"
+                    );
+                    return Ok(())
+                };
+
+                info!(target: "reth::cli", target, "Clearing transaction lookup index");
+                if provider_rw.cached_storage_settings().storage_v2 {
+                    provider_factory
+                        .rocksdb_provider()
+                        .clear::<tables::TransactionHashNumbers>()?;
+                } else {
+                    provider_rw.tx_ref().clear::<tables::TransactionHashNumbers>()?;
+                }
+
+                // The stage saves a fresh prune checkpoint derived from the configured prune mode,
+                // so that blocks below the prune distance are skipped and not reported as pruned
+                // further than they are.
+                provider_rw
+                    .tx_ref()
+                    .delete::<tables::PruneCheckpoints>(PruneSegment::TransactionLookup, None)?;
+                provider_rw
+                    .save_stage_checkpoint(StageId::TransactionLookup, Default::default())?;
+                provider_rw.commit()?;
+
+                let mut stage = TransactionLookupStage::new(
+                    TransactionLookupConfig { chunk_size: batch_size, ..Default::default() },
+                    config.stages.etl,
+                    config.prune.segments.transaction_lookup,
+                );
+                let mut input =
+                    ExecInput { target: Some(target), checkpoint: Some(StageCheckpoint::new(0)) };
+
+                let start = Instant::now();
+                info!(
+                    target: "reth::cli",
+                    target,
+                    prune_mode = ?config.prune.segments.transaction_lookup,
+                    "Rebuilding transaction lookup index"
+                );
+                loop {
+                    let provider_rw = provider_factory.database_provider_rw()?;
+                    let ExecOutput { checkpoint, done } = stage.execute(&provider_rw, input)?;
+                    provider_rw.save_stage_checkpoint(StageId::TransactionLookup, checkpoint)?;
+                    provider_rw.commit()?;
+
+                    input.checkpoint = Some(checkpoint);
+                    if done {
+                        break
+                    }
+                }
+                info!(
+                    target: "reth::cli",
+                    time = ?start.elapsed(),
+                    "Rebuilt transaction lookup index"
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use clap::Parser;
+use reth_db_api::{table::Table, TableViewer, Tables};
+use reth_db_common::DbTool;
+use reth_fs_util as fs;
+use reth_provider::providers::ProviderNodeTypes;
+use std::path::PathBuf;
+use tracing::info;
+
+/// The arguments for the `reth db restore-table` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The table to restore
+    table: Tables,
+
+    /// Path to the export written by `reth db drop-table`
+    path: PathBuf,
+}
+
+impl Command {
+    /// Execute `db restore-table` command
+    pub fn execute<N: ProviderNodeTypes>(self, tool: &DbTool<N>) -> eyre::Result<()> {
+        self.table.view(&RestoreTableViewer { tool, path: self.path })
+    }
+}
+
+struct RestoreTableViewer<'a, N: ProviderNodeTypes> {
+    tool: &'a DbTool<N>,
+    path: PathBuf,
+}
+
+impl<N: ProviderNodeTypes> TableViewer<()> for RestoreTableViewer<'_, N> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<(), Self::Error> {
+        info!(target: "reth::cli", table = T::NAME, path = ?self.path, "Restoring table");
+        let decoder = zstd::Decoder::new(fs::open(&self.path)?)?;
+        let rows = self.tool.import_table::<T>(decoder)?;
+        info!(target: "reth::cli", table = T::NAME, rows, "Restored table");
+
+        Ok(())
+    }
+}
@@ -347,6 +347,20 @@ impl Command {
                                     }
                                 }
 
+                                // Changesets of old blocks may have been packed.
+                                let mut packed_cursor =
+                                    tx.cursor_read::<tables::PackedStorageChangeSets>()?;
+                                if let Ok(walker) = packed_cursor.walk_range(start_key..=end_key) {
+                                    for (block_addr, changeset) in walker.flatten() {
+                                        if block_addr.address() == address {
+                                            local_keys.extend(
+                                                changeset.entries().iter().map(|entry| entry.key),
+                                            );
+                                        }
+                                        entries_in_chunk += changeset.entries().len();
+                                    }
+                                }
+
                                 // Merge into global state
                                 collected_keys_ref.lock().extend(local_keys);
                                 *total_entries_ref.lock() += entries_in_chunk;
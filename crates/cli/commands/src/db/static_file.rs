@@ -0,0 +1,281 @@
+use clap::{Parser, Subcommand};
+use human_bytes::human_bytes;
+use reth_db::static_file::iter_static_files;
+use reth_db_common::DbTool;
+use reth_fs_util as fs;
+use reth_nippy_jar::{
+    compression::Compressors, NippyJar, NippyJarError, CONFIG_FILE_EXTENSION, NIPPY_JAR_VERSION,
+};
+use reth_provider::{
+    providers::{with_compression, ProviderNodeTypes},
+    StaticFileProviderFactory,
+};
+use reth_static_file_types::{Compression, SegmentHeader, StaticFileSegment};
+use std::{
+    path::{Path, PathBuf},
+    time::Instant,
+};
+use tracing::{debug, error, info};
+
+/// Name of the directory, relative to the static files directory, where rewritten jars are staged
+/// before being swapped in.
+const STAGING_DIR: &str = ".recompress";
+
+/// Extension of the marker file written once a staged jar has been fully written and can be
+/// swapped in.
+const READY_EXTENSION: &str = "ready";
+
+/// Extensions of the jar files that are replaced on swap. The configuration file goes last, since
+/// it describes how the data file is encoded.
+const SWAPPED_EXTENSIONS: [Option<&str>; 3] = [None, Some("off"), Some(CONFIG_FILE_EXTENSION)];
+
+/// The arguments for the `reth db static-file` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    #[command(subcommand)]
+    command: Subcommands,
+}
+
+#[derive(Subcommand, Debug)]
+enum Subcommands {
+    /// Rewrites the static files of a segment with a different compression, upgrading them to the
+    /// latest file format version.
+    ///
+    /// Every file is first written to a staging directory and then atomically swapped in place of
+    /// the original. An interrupted run is resumed the next time this command is executed.
+    Recompress {
+        /// Static file segment to rewrite
+        #[arg(value_enum)]
+        segment: StaticFileSegment,
+        /// Compression to use for the rewritten files
+        #[arg(long, value_enum, default_value_t = Compression::Lz4)]
+        compression: Compression,
+        /// Only rewrite the static file containing this block
+        #[arg(long)]
+        block: Option<u64>,
+        /// Also rewrite files that already use the requested compression and format version
+        #[arg(long, default_value_t = false)]
+        force: bool,
+    },
+    /// Verifies the contents of sealed static files against the checksums in their
+    /// configuration, to detect disk corruption.
+    ///
+    /// Static files are sealed once full, so the latest static file of each segment has no
+    /// checksum yet.
+    Verify {
+        /// Only verify the static files of this segment
+        #[arg(value_enum)]
+        segment: Option<StaticFileSegment>,
+    },
+}
+
+impl Command {
+    /// Execute `db static-file` command
+    pub fn execute<N: ProviderNodeTypes>(self, tool: &DbTool<N>) -> eyre::Result<()> {
+        match self.command {
+            Subcommands::Recompress { segment, compression, block, force } => {
+                let static_file_provider = tool.provider_factory.static_file_provider();
+                let directory = static_file_provider.directory();
+                let staging_dir = directory.join(STAGING_DIR);
+
+                // Finish swapping jars staged by an interrupted run before doing anything else.
+                resume_staged_swaps(directory, &staging_dir)?;
+
+                let static_files = iter_static_files(directory)?;
+                let Some(ranges) = static_files.get(segment) else {
+                    info!(target: "reth::cli", ?segment, "No static files found for segment");
+                    return Ok(())
+                };
+
+                fs::create_dir_all(&staging_dir)?;
+
+                for (block_range, header) in ranges {
+                    if block.is_some_and(|block| !header.expected_block_range().contains(block)) {
+                        continue
+                    }
+
+                    let file_name = segment.filename(&header.expected_block_range());
+                    let path = directory.join(&file_name);
+                    let jar = NippyJar::<SegmentHeader>::load(&path)?;
+
+                    if !force &&
+                        jar.version() == NIPPY_JAR_VERSION &&
+                        compression_of(&jar) == compression
+                    {
+                        info!(
+                            target: "reth::cli",
+                            ?segment,
+                            %block_range,
+                            "Static file is up to date, skipping"
+                        );
+                        continue
+                    }
+
+                    let start = Instant::now();
+                    let size_before = jar_size(&path)?;
+
+                    let staged_path = staging_dir.join(&file_name);
+                    remove_jar_files(&staged_path)?;
+
+                    // Dictionaries are trained on the values of the rewritten file itself.
+                    let target = with_compression(
+                        NippyJar::new(segment.columns(), &staged_path, header.clone()),
+                        compression,
+                        Some(&jar),
+                    );
+                    let rewritten = jar.rewrite_into(target)?;
+
+                    // Once the marker exists, the staged jar is complete and the swap can be
+                    // resumed if interrupted.
+                    fs::write(staged_path.with_extension(READY_EXTENSION), b"")?;
+                    swap_staged_jar(&staged_path, &path)?;
+
+                    // Drop any cached handle to the previous version of the file.
+                    static_file_provider
+                        .remove_cached_provider(segment, header.expected_block_range().end());
+
+                    info!(
+                        target: "reth::cli",
+                        ?segment,
+                        %block_range,
+                        from = %compression_of(&jar).as_ref(),
+                        to = %compression_of(&rewritten).as_ref(),
+                        size_before = %human_bytes(size_before as f64),
+                        size_after = %human_bytes(jar_size(&path)? as f64),
+                        elapsed = ?start.elapsed(),
+                        "Rewrote static file"
+                    );
+                }
+
+                fs::remove_dir_all(&staging_dir)?;
+            }
+            Subcommands::Verify { segment } => {
+                let directory =
+                    tool.provider_factory.static_file_provider().directory().to_path_buf();
+                let (mut verified, mut unsealed, mut corrupted) = (0, 0, 0);
+
+                for (file_segment, headers) in &*iter_static_files(&directory)? {
+                    if segment.is_some_and(|segment| segment != file_segment) {
+                        continue
+                    }
+
+                    for (block_range, header) in headers {
+                        let path =
+                            directory.join(file_segment.filename(&header.expected_block_range()));
+                        if !path.exists() {
+                            info!(
+                                target: "reth::cli",
+                                segment = ?file_segment,
+                                %block_range,
+                                "Static file is in cold storage, skipping"
+                            );
+                            continue
+                        }
+
+                        let start = Instant::now();
+                        match NippyJar::<SegmentHeader>::load(&path)?.verify_checksum() {
+                            Ok(true) => verified += 1,
+                            Ok(false) => unsealed += 1,
+                            Err(NippyJarError::ChecksumMismatch(_)) => {
+                                error!(
+                                    target: "reth::cli",
+                                    segment = ?file_segment,
+                                    %block_range,
+                                    ?path,
+                                    "Static file doesn't match its checksum"
+                                );
+                                corrupted += 1;
+                                continue
+                            }
+                            Err(err) => return Err(err.into()),
+                        }
+                        debug!(
+                            target: "reth::cli",
+                            segment = ?file_segment,
+                            %block_range,
+                            elapsed = ?start.elapsed(),
+                            "Verified static file"
+                        );
+                    }
+                }
+
+                info!(
+                    target: "reth::cli",
+                    verified,
+                    unsealed,
+                    corrupted,
+                    "Verified static file checksums"
+                );
+                if corrupted > 0 {
+                    eyre::bail!("{corrupted} static files are corrupted")
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the [`Compression`] used by a jar.
+fn compression_of(jar: &NippyJar<SegmentHeader>) -> Compression {
+    match jar.compressor() {
+        None => Compression::Uncompressed,
+        Some(Compressors::Lz4(_)) => Compression::Lz4,
+        Some(Compressors::Zstd(zstd)) if zstd.use_dict => Compression::ZstdWithDictionary,
+        Some(Compressors::Zstd(_)) => Compression::Zstd,
+    }
+}
+
+/// Returns the combined size of the data and offsets files of a jar.
+fn jar_size(path: &Path) -> eyre::Result<u64> {
+    Ok(fs::metadata(path)?.len() + fs::metadata(path.with_extension("off"))?.len())
+}
+
+/// Returns the path of a jar file with the given extension, or the data file if [`None`].
+fn jar_file(path: &Path, extension: Option<&str>) -> PathBuf {
+    extension.map_or_else(|| path.to_path_buf(), |extension| path.with_extension(extension))
+}
+
+/// Removes any leftover jar files at `path`.
+fn remove_jar_files(path: &Path) -> eyre::Result<()> {
+    for extension in SWAPPED_EXTENSIONS {
+        fs::remove_file_if_exists(jar_file(path, extension))?;
+    }
+    fs::remove_file_if_exists(path.with_extension(READY_EXTENSION))?;
+    Ok(())
+}
+
+/// Moves the files of a fully written staged jar in place of the original one.
+///
+/// Each rename is atomic and files that were already moved are skipped, so this can be called
+/// again to finish a swap that was interrupted.
+fn swap_staged_jar(staged_path: &Path, path: &Path) -> eyre::Result<()> {
+    for extension in SWAPPED_EXTENSIONS {
+        let staged_file = jar_file(staged_path, extension);
+        if staged_file.exists() {
+            fs::rename(&staged_file, jar_file(path, extension))?;
+        }
+    }
+    fs::remove_file(staged_path.with_extension(READY_EXTENSION))?;
+    Ok(())
+}
+
+/// Finishes swapping every staged jar that was marked as ready, and discards incomplete ones.
+fn resume_staged_swaps(directory: &Path, staging_dir: &Path) -> eyre::Result<()> {
+    if !staging_dir.exists() {
+        return Ok(())
+    }
+
+    for entry in fs::read_dir(staging_dir)?.filter_map(Result::ok) {
+        let marker = entry.path();
+        if marker.extension().is_some_and(|extension| extension == READY_EXTENSION) &&
+            let Some(file_name) = marker.file_stem()
+        {
+            info!(target: "reth::cli", file = ?file_name, "Resuming interrupted static file swap");
+            swap_staged_jar(&staging_dir.join(file_name), &directory.join(file_name))?;
+        }
+    }
+
+    fs::remove_dir_all(staging_dir)?;
+    Ok(())
+}
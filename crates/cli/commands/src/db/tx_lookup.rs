@@ -0,0 +1,101 @@
+use alloy_primitives::TxHash;
+use clap::Parser;
+use reth_db_api::{
+    table::Table,
+    tables::{self, RawKey, RawTable},
+    transaction::DbTx,
+};
+use reth_db_common::DbTool;
+use reth_provider::{
+    providers::ProviderNodeTypes, BlockBodyIndicesProvider, BlockNumReader, BlockReader,
+    DBProvider, PruneCheckpointReader, StageCheckpointReader, StaticFileProviderFactory,
+    StorageSettingsCache, TransactionsProvider,
+};
+use reth_prune_types::PruneSegment;
+use reth_stages_types::StageId;
+use reth_static_file_types::StaticFileSegment;
+
+/// The arguments for the `reth db tx-lookup` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The transaction hash to look up
+    hash: TxHash,
+}
+
+impl Command {
+    /// Execute `db tx-lookup` command
+    pub fn execute<N: ProviderNodeTypes>(self, tool: &DbTool<N>) -> eyre::Result<()> {
+        let provider = tool.provider_factory.provider()?;
+        let index_location =
+            if provider.cached_storage_settings().storage_v2 { "RocksDB" } else { "MDBX" };
+
+        println!("Transaction hash: {}", self.hash);
+        println!("Hash index: {} ({index_location})", tables::TransactionHashNumbers::NAME);
+
+        let Some(tx_number) = provider.transaction_id(self.hash)? else {
+            println!("Status: not found");
+
+            let stage_checkpoint = provider
+                .get_stage_checkpoint(StageId::TransactionLookup)?
+                .map(|checkpoint| checkpoint.block_number);
+            println!("Indexed up to block: {stage_checkpoint:?}");
+
+            match provider.get_prune_checkpoint(PruneSegment::TransactionLookup)? {
+                Some(checkpoint) => println!(
+                    "Pruned up to block: {:?} (mode: {:?}). The transaction may have been pruned \
+                     if it was included at or below this block.",
+                    checkpoint.block_number, checkpoint.prune_mode
+                ),
+                None => println!("Pruned up to block: none"),
+            }
+            println!("Best block: {}", provider.best_block_number()?);
+
+            return Ok(())
+        };
+
+        let block_number = provider
+            .block_by_transaction_id(tx_number)?
+            .ok_or_else(|| eyre::eyre!("No block found for transaction number {tx_number}"))?;
+        let body_indices = provider
+            .block_body_indices(block_number)?
+            .ok_or_else(|| eyre::eyre!("No body indices found for block {block_number}"))?;
+
+        println!("Status: found");
+        println!("Transaction number: {tx_number}");
+        println!("Block number: {block_number}");
+        println!("Index in block: {}", tx_number - body_indices.first_tx_num());
+
+        let static_file_provider = tool.provider_factory.static_file_provider();
+        let in_static_files = static_file_provider
+            .get_highest_static_file_tx(StaticFileSegment::Transactions)
+            .is_some_and(|highest| tx_number <= highest);
+        if in_static_files {
+            let jar = static_file_provider.get_segment_provider_for_transaction(
+                StaticFileSegment::Transactions,
+                tx_number,
+                None,
+            )?;
+            let header = jar.user_header();
+            let offset = header
+                .tx_start()
+                .map(|tx_start| tx_number - tx_start)
+                .ok_or_else(|| eyre::eyre!("Static file has no transactions"))?;
+
+            println!("Location: static file");
+            println!("Segment: {}", header.segment());
+            println!("Block Range: {}", header.expected_block_range());
+            println!("Path: {}", jar.data_path().display());
+            println!("Row offset: {offset}");
+        } else if provider
+            .tx_ref()
+            .get::<RawTable<tables::Transactions>>(RawKey::new(tx_number))?
+            .is_some()
+        {
+            println!("Location: MDBX ({})", <tables::Transactions as Table>::NAME);
+        } else {
+            println!("Location: missing, the transaction body has been pruned");
+        }
+
+        Ok(())
+    }
+}
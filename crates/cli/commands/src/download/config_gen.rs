@@ -178,7 +178,10 @@ pub(crate) fn config_for_selections(
             transaction_senders: blocks_per_file(SnapshotComponentType::TransactionSenders),
             account_change_sets: blocks_per_file(SnapshotComponentType::AccountChangesets),
             storage_change_sets: blocks_per_file(SnapshotComponentType::StorageChangesets),
+            blob_sidecars: None,
+            block_access_lists: None,
         },
+        ..Default::default()
     };
 
     if is_archive || matches!(preset, Some(SelectionPreset::Archive)) {
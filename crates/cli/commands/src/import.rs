@@ -34,6 +34,14 @@ pub struct ImportCommand<C: ChainSpecParser> {
     #[arg(long, verbatim_doc_comment)]
     fail_on_invalid_block: bool,
 
+    /// Append blocks straight to static files and run the remaining stages once.
+    ///
+    /// By default, the pipeline runs for every chunk of the file. With this flag, the blocks of
+    /// all chunks are appended to static files first, and execution, hashing, merkle and history
+    /// indexing then run once over the whole range.
+    #[arg(long, verbatim_doc_comment)]
+    bulk: bool,
+
     /// The path(s) to block file(s) for import.
     ///
     /// The online stages (headers and bodies) are replaced by a file import, after which the
@@ -66,6 +74,7 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> ImportComm
             no_state: self.no_state,
             chunk_len: self.chunk_len,
             fail_on_invalid_block: self.fail_on_invalid_block,
+            bulk: self.bulk,
         };
 
         let executor = components.evm_config().clone();
@@ -177,6 +186,13 @@ mod tests {
         assert_eq!(args.paths[0], PathBuf::from("chain.rlp"));
     }
 
+    #[test]
+    fn parse_import_command_with_bulk() {
+        let args: ImportCommand<EthereumChainSpecParser> =
+            ImportCommand::parse_from(["reth", "--bulk", "chain.rlp"]);
+        assert!(args.bulk);
+    }
+
     #[test]
     fn parse_import_command_default_stops_on_invalid_block() {
         let args: ImportCommand<EthereumChainSpecParser> =
@@ -1,6 +1,6 @@
 //! Core import functionality without CLI dependencies.
 
-use alloy_primitives::B256;
+use alloy_primitives::{BlockHash, BlockNumber, B256};
 use futures::StreamExt;
 use reth_config::Config;
 use reth_consensus::FullConsensus;
@@ -10,6 +10,8 @@ use reth_downloaders::{
     file_client::{ChunkedFileReader, FileClient, DEFAULT_BYTE_LEN_CHUNK_CHAIN_FILE},
     headers::reverse_headers::ReverseHeadersDownloaderBuilder,
 };
+use reth_era_utils as era;
+use reth_etl::Collector;
 use reth_evm::ConfigureEvm;
 use reth_network_p2p::{
     bodies::downloader::BodyDownloader,
@@ -19,11 +21,12 @@ use reth_node_api::BlockTy;
 use reth_node_events::node::NodeEvent;
 use reth_provider::{
     providers::ProviderNodeTypes, BlockNumReader, HeaderProvider, ProviderError, ProviderFactory,
-    RocksDBProviderFactory, StageCheckpointReader,
+    RocksDBProviderFactory, StageCheckpointReader, StaticFileProviderFactory,
 };
 use reth_prune::PruneModes;
 use reth_stages::{prelude::*, ControlFlow, Pipeline, StageId, StageSet};
 use reth_static_file::StaticFileProducer;
+use reth_static_file_types::StaticFileSegment;
 use reth_storage_api::StorageSettingsCache;
 use std::{path::Path, sync::Arc};
 use tokio::sync::watch;
@@ -39,6 +42,28 @@ pub struct ImportConfig {
     /// If true, fail immediately when an invalid block is encountered.
     /// By default (false), the import stops at the last valid block and exits successfully.
     pub fail_on_invalid_block: bool,
+    /// Appends the blocks of every chunk straight to static files and runs the remaining stages
+    /// once over the whole range, instead of running the pipeline for every chunk.
+    ///
+    /// The trie and history indices are then built in a single pass, and the block hash index is
+    /// built from a sorted stream after the last chunk.
+    pub bulk: bool,
+}
+
+/// Outcome of running the import pipeline.
+#[derive(Debug)]
+enum PipelineOutcome {
+    /// The pipeline synced to the tip of the file client.
+    Synced,
+    /// The import was interrupted by the user.
+    Interrupted,
+    /// The pipeline stopped at the last valid block because of an invalid block.
+    InvalidBlock {
+        /// The invalid block.
+        bad_block: u64,
+        /// The last valid block.
+        last_valid_block: u64,
+    },
 }
 
 /// Result of an import operation.
@@ -132,6 +157,15 @@ where
     let mut last_valid_block_number: Option<u64> = None;
 
     let skip_invalid_blocks = !import_config.fail_on_invalid_block;
+
+    if import_config.bulk {
+        info!(target: "reth::import", "Appending chain file chunks straight to static files");
+    }
+    let mut hash_collector =
+        Collector::new(config.stages.etl.file_size, config.stages.etl.dir.clone());
+    // The last appended chunk, used to run the pipeline over all appended blocks at the end
+    let mut bulk_chunk = None;
+
     while let Some(file_client) = reader
         .next_chunk_with_invalid_block_handling::<BlockTy<N>>(
             consensus.clone(),
@@ -150,77 +184,36 @@ where
             continue;
         }
 
-        let tip = file_client.tip().ok_or(eyre::eyre!("file client has no tip"))?;
         info!(target: "reth::import", "Chain file chunk read");
 
         total_decoded_blocks += file_client.headers_len();
         total_decoded_txns += file_client.total_transactions();
 
-        let (mut pipeline, events) = build_import_pipeline_impl(
-            config,
-            provider_factory.clone(),
-            &consensus,
-            Arc::new(file_client),
-            static_file_producer.clone(),
-            import_config.no_state,
-            executor.clone(),
-            runtime.clone(),
-        )?;
-
-        // override the tip
-        pipeline.set_tip(tip);
-        debug!(target: "reth::import", ?tip, "Tip manually set");
-
-        let latest_block_number =
-            provider_factory.get_stage_checkpoint(StageId::Finish)?.map(|ch| ch.block_number);
-        tokio::spawn(reth_node_events::node::handle_events(None, latest_block_number, events));
-
-        // Run pipeline
-        info!(target: "reth::import", "Starting sync pipeline");
-        if import_config.fail_on_invalid_block {
-            // Original behavior: fail on unwind
-            tokio::select! {
-                res = pipeline.run() => res?,
-                _ = tokio::signal::ctrl_c() => {
-                    info!(target: "reth::import", "Import interrupted by user");
-                    break;
-                },
-            }
+        if import_config.bulk {
+            let last_block = append_blocks(&provider_factory, &file_client, &mut hash_collector)?;
+            info!(target: "reth::import", last_block, "Appended chain file chunk");
+            bulk_chunk = Some(file_client);
         } else {
-            // Default behavior: Use run_loop() to handle unwinds gracefully
-            let result = tokio::select! {
-                res = pipeline.run_loop() => res,
-                _ = tokio::signal::ctrl_c() => {
-                    info!(target: "reth::import", "Import interrupted by user");
-                    break;
-                },
-            };
-
-            match result {
-                Ok(ControlFlow::Unwind { target, bad_block }) => {
-                    // An invalid block was encountered; stop at last valid block
-                    let bad = bad_block.block.number;
-                    warn!(
-                        target: "reth::import",
-                        bad_block = bad,
-                        last_valid_block = target,
-                        "Invalid block encountered during import; stopping at last valid block"
-                    );
+            match run_import_pipeline(
+                file_client,
+                &import_config,
+                config,
+                provider_factory.clone(),
+                &consensus,
+                static_file_producer.clone(),
+                executor.clone(),
+                runtime.clone(),
+            )
+            .await?
+            {
+                PipelineOutcome::Synced => {}
+                PipelineOutcome::Interrupted => break,
+                PipelineOutcome::InvalidBlock { bad_block, last_valid_block } => {
                     stopped_on_invalid_block = true;
-                    bad_block_number = Some(bad);
-                    last_valid_block_number = Some(target);
+                    bad_block_number = Some(bad_block);
+                    last_valid_block_number = Some(last_valid_block);
                     break;
                 }
-                Ok(ControlFlow::Continue { block_number }) => {
-                    debug!(target: "reth::import", block_number, "Pipeline chunk completed");
-                }
-                Ok(ControlFlow::NoProgress { block_number }) => {
-                    debug!(target: "reth::import", ?block_number, "Pipeline made no progress");
-                }
-                Err(e) => {
-                    // Propagate other pipeline errors
-                    return Err(e.into());
-                }
             }
         }
 
@@ -229,6 +222,31 @@ where
             .expect("should have genesis");
     }
 
+    if let Some(file_client) = bulk_chunk {
+        let provider = provider_factory.provider_rw()?;
+        era::build_index(&provider, &mut hash_collector)?;
+        provider.commit()?;
+
+        // The headers and bodies of all chunks are in place, so the pipeline runs the remaining
+        // stages once up to the tip of the last chunk.
+        if let PipelineOutcome::InvalidBlock { bad_block, last_valid_block } = run_import_pipeline(
+            file_client,
+            &import_config,
+            config,
+            provider_factory.clone(),
+            &consensus,
+            static_file_producer,
+            executor,
+            runtime,
+        )
+        .await?
+        {
+            stopped_on_invalid_block = true;
+            bad_block_number = Some(bad_block);
+            last_valid_block_number = Some(last_valid_block);
+        }
+    }
+
     let provider = provider_factory.provider()?;
     let total_imported_blocks = provider.tx_ref().entries::<tables::HeaderNumbers>()? - init_blocks;
     let current_txns = if provider_factory.cached_storage_settings().storage_v2 {
@@ -275,6 +293,126 @@ where
     Ok(result)
 }
 
+/// Appends the blocks of the file client straight to static files and advances the checkpoints of
+/// the headers and bodies stages, doing their work without going through the downloaders.
+///
+/// The blocks were already validated when the chunk was read. Their hashes are collected into
+/// `hash_collector`, so the hash to number index can be built once after the last chunk.
+///
+/// Returns the last appended block.
+fn append_blocks<N>(
+    provider_factory: &ProviderFactory<N>,
+    file_client: &FileClient<BlockTy<N>>,
+    hash_collector: &mut Collector<BlockHash, BlockNumber>,
+) -> eyre::Result<BlockNumber>
+where
+    N: ProviderNodeTypes,
+{
+    let static_file_provider = provider_factory.static_file_provider();
+    let from = static_file_provider
+        .get_highest_static_file_block(StaticFileSegment::Headers)
+        .unwrap_or_default();
+
+    let provider = provider_factory.provider_rw()?;
+    let last_block = era::process_iter(
+        file_client.blocks_iter().map(|(header, body)| Ok((header.clone(), body.clone()))),
+        &mut static_file_provider.latest_writer(StaticFileSegment::Headers)?,
+        &provider,
+        hash_collector,
+        from..,
+    )?;
+    era::save_stage_checkpoints(&provider, from, last_block, last_block, last_block)?;
+    provider.commit()?;
+
+    Ok(last_block)
+}
+
+/// Runs the import pipeline up to the tip of the file client.
+#[expect(clippy::too_many_arguments)]
+async fn run_import_pipeline<N, C, E>(
+    file_client: FileClient<BlockTy<N>>,
+    import_config: &ImportConfig,
+    config: &Config,
+    provider_factory: ProviderFactory<N>,
+    consensus: &Arc<C>,
+    static_file_producer: StaticFileProducer<ProviderFactory<N>>,
+    executor: E,
+    runtime: reth_tasks::Runtime,
+) -> eyre::Result<PipelineOutcome>
+where
+    N: ProviderNodeTypes,
+    C: FullConsensus<N::Primitives> + 'static,
+    E: ConfigureEvm<Primitives = N::Primitives> + 'static,
+{
+    let tip = file_client.tip().ok_or(eyre::eyre!("file client has no tip"))?;
+
+    let (mut pipeline, events) = build_import_pipeline_impl(
+        config,
+        provider_factory.clone(),
+        consensus,
+        Arc::new(file_client),
+        static_file_producer,
+        import_config.no_state,
+        executor,
+        runtime,
+    )?;
+
+    // override the tip
+    pipeline.set_tip(tip);
+    debug!(target: "reth::import", ?tip, "Tip manually set");
+
+    let latest_block_number =
+        provider_factory.get_stage_checkpoint(StageId::Finish)?.map(|ch| ch.block_number);
+    tokio::spawn(reth_node_events::node::handle_events(None, latest_block_number, events));
+
+    // Run pipeline
+    info!(target: "reth::import", "Starting sync pipeline");
+    if import_config.fail_on_invalid_block {
+        // Original behavior: fail on unwind
+        tokio::select! {
+            res = pipeline.run() => res?,
+            _ = tokio::signal::ctrl_c() => {
+                info!(target: "reth::import", "Import interrupted by user");
+                return Ok(PipelineOutcome::Interrupted);
+            },
+        }
+        return Ok(PipelineOutcome::Synced)
+    }
+
+    // Default behavior: Use run_loop() to handle unwinds gracefully
+    let result = tokio::select! {
+        res = pipeline.run_loop() => res,
+        _ = tokio::signal::ctrl_c() => {
+            info!(target: "reth::import", "Import interrupted by user");
+            return Ok(PipelineOutcome::Interrupted);
+        },
+    };
+
+    match result {
+        Ok(ControlFlow::Unwind { target, bad_block }) => {
+            // An invalid block was encountered; stop at last valid block
+            let bad = bad_block.block.number;
+            warn!(
+                target: "reth::import",
+                bad_block = bad,
+                last_valid_block = target,
+                "Invalid block encountered during import; stopping at last valid block"
+            );
+            Ok(PipelineOutcome::InvalidBlock { bad_block: bad, last_valid_block: target })
+        }
+        Ok(ControlFlow::Continue { block_number }) => {
+            debug!(target: "reth::import", block_number, "Pipeline chunk completed");
+            Ok(PipelineOutcome::Synced)
+        }
+        Ok(ControlFlow::NoProgress { block_number }) => {
+            debug!(target: "reth::import", ?block_number, "Pipeline made no progress");
+            Ok(PipelineOutcome::Synced)
+        }
+        // Propagate other pipeline errors
+        Err(e) => Err(e.into()),
+    }
+}
+
 /// Builds import pipeline.
 ///
 /// If configured to execute, all stages will run. Otherwise, only stages that don't require state
@@ -348,7 +486,10 @@ where
         )
         .build(provider_factory, static_file_producer);
 
-    let events = pipeline.events().map(Into::into);
+    let events = futures::stream::select(
+        pipeline.events().map(Into::into),
+        pipeline.stage_events().map(Into::into),
+    );
 
     Ok((pipeline, events))
 }
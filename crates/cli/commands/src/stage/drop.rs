@@ -99,6 +99,12 @@ impl<C: ChainSpecParser> Command<C> {
                     StaticFileSegment::StorageChangeSets => {
                         writer.prune_storage_changesets(highest_block)?;
                     }
+                    StaticFileSegment::BlobSidecars => {
+                        unreachable!("blob sidecars are not written by any stage")
+                    }
+                    StaticFileSegment::BlockAccessLists => {
+                        unreachable!("block access lists are not dropped by any stage")
+                    }
                 }
             }
         }
@@ -111,7 +117,9 @@ impl<C: ChainSpecParser> Command<C> {
                 tx.clear::<tables::CanonicalHeaders>()?;
                 tx.clear::<tables::Headers<HeaderTy<N>>>()?;
                 tx.clear::<tables::HeaderNumbers>()?;
+                tx.clear::<tables::HeadersDownloadBuffer<HeaderTy<N>>>()?;
                 reset_stage_checkpoint(tx, StageId::Headers)?;
+                tx.delete::<tables::StageCheckpointProgresses>(StageId::Headers.to_string(), None)?;
 
                 insert_genesis_header(&provider_rw, &self.env.chain)?;
             }
@@ -144,6 +152,7 @@ impl<C: ChainSpecParser> Command<C> {
                 }
                 tx.clear::<tables::AccountChangeSets>()?;
                 tx.clear::<tables::StorageChangeSets>()?;
+                tx.clear::<tables::PackedStorageChangeSets>()?;
                 tx.clear::<tables::Bytecodes>()?;
                 tx.clear::<tables::Receipts<ReceiptTy<N>>>()?;
 
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use super::setup;
 use alloy_primitives::{Address, BlockNumber};
 use eyre::Result;
-use reth_config::config::EtlConfig;
+use reth_config::config::{EtlConfig, MerkleRebuildStrategy};
 use reth_consensus::FullConsensus;
 use reth_db::DatabaseEnv;
 use reth_db_api::{database::Database, models::BlockNumberAddress, table::TableImporter, tables};
@@ -18,7 +18,7 @@ use reth_provider::{
 };
 use reth_stages::{
     stages::{
-        AccountHashingStage, ExecutionStage, MerkleStage, StorageHashingStage,
+        AccountHashingStage, ExecutionStage, MerkleRebuildPolicy, MerkleStage, StorageHashingStage,
         MERKLE_STAGE_DEFAULT_REBUILD_THRESHOLD,
     },
     ExecutionStageThresholds, Stage, StageCheckpoint, UnwindInput,
@@ -110,6 +110,8 @@ fn unwind_and_copy<N: ProviderNodeTypes>(
             max_changes: None,
             max_cumulative_gas: None,
             max_duration: None,
+            max_memory: None,
+            target_batch_duration: None,
         },
         MERKLE_STAGE_DEFAULT_REBUILD_THRESHOLD,
         ExExManagerHandle::empty(),
@@ -170,6 +172,7 @@ where
         // Forces updating the root instead of calculating from scratch
         rebuild_threshold: u64::MAX,
         incremental_threshold: u64::MAX,
+        rebuild_policy: MerkleRebuildPolicy::new(MerkleRebuildStrategy::Incremental),
     };
 
     loop {
@@ -196,13 +196,16 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + Hardforks + EthereumHardforks>
                     };
                     let (_, rx) = watch::channel(tip.hash_slow());
                     (
-                        Box::new(HeaderStage::new(
-                            provider_factory.clone(),
-                            ReverseHeadersDownloaderBuilder::new(config.stages.headers)
-                                .build(fetch_client, consensus.clone()),
-                            rx,
-                            etl_config,
-                        )),
+                        Box::new(
+                            HeaderStage::new(
+                                provider_factory.clone(),
+                                ReverseHeadersDownloaderBuilder::new(config.stages.headers)
+                                    .build(fetch_client, consensus.clone()),
+                                rx,
+                                etl_config,
+                            )
+                            .with_commit_threshold(config.stages.headers.commit_threshold),
+                        ),
                         None,
                     )
                 }
@@ -253,7 +256,7 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + Hardforks + EthereumHardforks>
                 }
                 StageEnum::Senders => (
                     Box::new(SenderRecoveryStage::new(
-                        SenderRecoveryConfig { commit_threshold: batch_size },
+                        SenderRecoveryConfig { commit_threshold: batch_size, workers: None },
                         None,
                     )),
                     None,
@@ -267,6 +270,8 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + Hardforks + EthereumHardforks>
                             max_changes: None,
                             max_cumulative_gas: None,
                             max_duration: None,
+                            max_memory: None,
+                            target_batch_duration: None,
                         },
                         config.stages.merkle.incremental_threshold,
                         ExExManagerHandle::empty(),
@@ -275,7 +280,7 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + Hardforks + EthereumHardforks>
                 ),
                 StageEnum::TxLookup => (
                     Box::new(TransactionLookupStage::new(
-                        TransactionLookupConfig { chunk_size: batch_size },
+                        TransactionLookupConfig { chunk_size: batch_size, ..Default::default() },
                         etl_config,
                         prune_modes.transaction_lookup,
                     )),
@@ -307,6 +312,7 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + Hardforks + EthereumHardforks>
                     Box::new(MerkleStage::new_execution(
                         config.stages.merkle.rebuild_threshold,
                         config.stages.merkle.incremental_threshold,
+                        config.stages.merkle.rebuild_strategy,
                     )),
                     Some(Box::new(MerkleStage::default_unwind())),
                 ),
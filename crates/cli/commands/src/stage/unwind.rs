@@ -5,26 +5,32 @@ use crate::{
     stage::CliNodeComponents,
 };
 use alloy_eips::BlockHashOrNumber;
-use alloy_primitives::B256;
+use alloy_primitives::{BlockNumber, B256};
 use clap::{Parser, Subcommand};
 use reth_chainspec::{ChainSpecProvider, EthChainSpec, EthereumHardforks};
 use reth_cli::chainspec::ChainSpecParser;
+use reth_cli_util::cancellation::CancellationToken;
 use reth_config::Config;
 use reth_consensus::noop::NoopConsensus;
 use reth_db::DatabaseEnv;
 use reth_downloaders::{bodies::noop::NoopBodiesDownloader, headers::noop::NoopHeaderDownloader};
+use reth_errors::RethError;
 use reth_evm::ConfigureEvm;
-use reth_exex::ExExManagerHandle;
-use reth_provider::{providers::ProviderNodeTypes, BlockNumReader, ProviderFactory};
+use reth_exex::{ExExManagerHandle, ExExNotification, Wal};
+use reth_node_core::args::StageEnum;
+use reth_provider::{
+    providers::ProviderNodeTypes, BlockNumReader, BlockReader, Chain, ExecutionOutcome,
+    ProviderFactory, ReceiptProvider,
+};
 use reth_stages::{
     sets::{DefaultStages, OfflineStages},
     stages::ExecutionStage,
-    ExecutionStageThresholds, Pipeline, StageSet,
+    ExecutionStageThresholds, Pipeline, PipelineError, PipelineTarget, StageId, StageSet,
 };
 use reth_static_file::StaticFileProducer;
-use std::sync::Arc;
-use tokio::sync::watch;
-use tracing::info;
+use std::{collections::BTreeMap, path::Path, sync::Arc};
+use tokio::{sync::watch, task::JoinHandle};
+use tracing::{error, info};
 
 /// `reth stage unwind` command
 #[derive(Debug, Parser)]
@@ -36,9 +42,37 @@ pub struct Command<C: ChainSpecParser> {
     command: Subcommands,
 
     /// If this is enabled, then all stages except headers, bodies, and sender recovery will be
-    /// unwound.
+    /// unwound, unless narrowed down with `--exclude-stage` or `--only-stage`.
     #[arg(long)]
     offline: bool,
+
+    /// Keep the given stages out of the offline unwind. Can be passed multiple times.
+    #[arg(long, value_enum, requires = "offline", conflicts_with = "only_stage")]
+    exclude_stage: Vec<StageEnum>,
+
+    /// Only unwind the given stages in the offline unwind. Can be passed multiple times.
+    #[arg(long, value_enum, requires = "offline")]
+    only_stage: Vec<StageEnum>,
+
+    /// Do not record the unwound blocks in the ExEx write-ahead log.
+    ///
+    /// By default, if the datadir contains an ExEx WAL, the unwound blocks are committed to it, so
+    /// that ExExes that already processed them are reverted to the unwind target on the next
+    /// start.
+    #[arg(long)]
+    skip_exex_wal: bool,
+
+    /// Skip moving data from the database to static files before unwinding.
+    ///
+    /// Only use this if the static files are known to already contain all applicable data, e.g.
+    /// when re-running an unwind that was interrupted after this step.
+    #[arg(long)]
+    skip_static_file_move: bool,
+
+    /// Print which stages would be unwound over which ranges, and which data would be moved to
+    /// static files, without unwinding anything.
+    #[arg(long)]
+    dry_run: bool,
 }
 
 impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C> {
@@ -52,8 +86,10 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
         Comp: CliNodeComponents<N>,
         F: FnOnce(Arc<C::ChainSpec>) -> Comp,
     {
-        let Environment { provider_factory, config, data_dir: _ } =
-            self.env.init::<N>(AccessRights::RW, runtime)?;
+        // A dry run only reads the database.
+        let access = if self.dry_run { AccessRights::RO } else { AccessRights::RW };
+        let Environment { provider_factory, config, data_dir } =
+            self.env.init::<N>(access, runtime.clone())?;
 
         let target = self.command.unwind_target(provider_factory.clone())?;
 
@@ -66,14 +102,72 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
         let highest_static_file_block = provider_factory.provider()?.last_block_number()?;
         info!(target: "reth::cli", ?target, ?highest_static_file_block, prune_config=?config.prune,  "Executing a pipeline unwind.");
 
+        let (skip_static_file_move, skip_exex_wal, dry_run) =
+            (self.skip_static_file_move, self.skip_exex_wal, self.dry_run);
+
         // This will build an offline-only pipeline if the `offline` flag is enabled
         let mut pipeline =
-            self.build_pipeline(config, provider_factory, components.evm_config().clone())?;
+            self.build_pipeline(config, provider_factory.clone(), components.evm_config().clone())?;
 
-        // Move all applicable data from database to static files.
-        pipeline.move_to_static_files()?;
+        if dry_run {
+            let mut plan = pipeline.plan(PipelineTarget::Unwind(target))?;
+            if skip_static_file_move {
+                plan.static_files.receipts = None;
+                plan.prune_tip = None;
+            }
+            print!("{plan}");
+            return Ok(())
+        }
 
-        pipeline.unwind(target, None)?;
+        // Set up cancellation token for graceful shutdown on Ctrl+C, listened for until the
+        // command completes, since listening replaces the default handler of the process. The
+        // command is also stopped if listening for Ctrl+C fails, the error is returned once it
+        // stopped.
+        let cancellation = CancellationToken::new();
+        let cancellation_clone = cancellation.clone();
+        let ctrl_c = runtime.handle().spawn(async move {
+            let result = tokio::signal::ctrl_c().await;
+            cancellation_clone.cancel();
+            result
+        });
+        if skip_static_file_move {
+            info!(target: "reth::cli", "Skipping moving data from database to static files");
+        } else {
+            // Move all applicable data from database to static files.
+            info!(target: "reth::cli", "Moving data from database to static files...");
+            let outcome =
+                pipeline.move_to_static_files_with_interrupt(|| cancellation.is_cancelled())?;
+            if outcome.is_interrupted() {
+                return interrupted(ctrl_c, "Moving data to static files").await
+            }
+        }
+
+        let exex_wal_path = data_dir.exex_wal();
+        if !skip_exex_wal && exex_wal_path.exists() {
+            commit_unwound_blocks_to_exex_wal(&provider_factory, &exex_wal_path, target)?;
+        }
+
+        let outcome =
+            match pipeline.unwind_with_interrupt(target, None, || cancellation.is_cancelled()) {
+                Ok(outcome) => outcome,
+                Err(err) => {
+                    if let PipelineError::Internal(RethError::Unwind(unwind)) = &err {
+                        error!(
+                            target: "reth::cli",
+                            stage = %unwind.stage,
+                            progress = unwind.progress,
+                            target = unwind.target,
+                            "Unwind failed, progress up to this block has been saved. Resolve the \
+                             cause and re-run the command with the same target to resume"
+                        );
+                    }
+                    return Err(err.into())
+                }
+            };
+        if outcome.is_interrupted() {
+            return interrupted(ctrl_c, "Unwinding").await
+        }
+        ctrl_c.abort();
 
         info!(target: "reth::cli", ?target, "Unwound blocks");
 
@@ -87,21 +181,43 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
         evm_config: impl ConfigureEvm<Primitives = N::Primitives> + 'static,
     ) -> Result<Pipeline<N>, eyre::Error> {
         let stage_conf = &config.stages;
+        let unwind_thresholds = stage_conf.unwind.into();
         let prune_modes = config.prune.segments.clone();
 
         let (tip_tx, tip_rx) = watch::channel(B256::ZERO);
 
         let builder = if self.offline {
-            Pipeline::<N>::builder().add_stages(
-                OfflineStages::new(
-                    evm_config,
-                    NoopConsensus::arc(),
-                    config.stages,
-                    prune_modes.clone(),
-                )
-                .builder()
-                .disable(reth_stages::StageId::SenderRecovery),
+            let mut stages = OfflineStages::new(
+                evm_config,
+                NoopConsensus::arc(),
+                config.stages,
+                prune_modes.clone(),
             )
+            .builder()
+            .disable(StageId::SenderRecovery);
+
+            let offline_stages = stages.stages().collect::<Vec<_>>();
+            for stage in self.exclude_stage.iter().chain(&self.only_stage) {
+                eyre::ensure!(
+                    stage.stage_ids().iter().all(|id| offline_stages.contains(id)),
+                    "Stage {stage} is not part of the offline unwind"
+                );
+            }
+
+            if !self.only_stage.is_empty() {
+                let excluded = offline_stages
+                    .into_iter()
+                    .filter(|id| {
+                        !self.only_stage.iter().any(|stage| stage.stage_ids().contains(id))
+                    })
+                    .collect::<Vec<_>>();
+                stages = stages.disable_all(&excluded);
+            }
+            for stage in &self.exclude_stage {
+                stages = stages.disable_all(stage.stage_ids());
+            }
+
+            Pipeline::<N>::builder().add_stages(stages)
         } else {
             Pipeline::<N>::builder().with_tip_sender(tip_tx).add_stages(
                 DefaultStages::new(
@@ -123,6 +239,8 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
                         max_changes: None,
                         max_cumulative_gas: None,
                         max_duration: None,
+                        max_memory: None,
+                        target_batch_duration: None,
                     },
                     stage_conf.execution_external_clean_threshold(),
                     ExExManagerHandle::empty(),
@@ -130,7 +248,7 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
             )
         };
 
-        let pipeline = builder.build(
+        let pipeline = builder.with_unwind_thresholds(unwind_thresholds).build(
             provider_factory.clone(),
             StaticFileProducer::new(provider_factory, prune_modes),
         );
@@ -138,6 +256,53 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
     }
 }
 
+/// Waits for the Ctrl+C listener that interrupted `what`, returning its error if listening failed.
+async fn interrupted(ctrl_c: JoinHandle<std::io::Result<()>>, what: &str) -> eyre::Result<()> {
+    ctrl_c.await?.map_err(|err| eyre::eyre!("failed to listen for ctrl-c: {err}"))?;
+    info!(target: "reth::cli", "{what} was interrupted, re-run the command to resume");
+    Ok(())
+}
+
+/// Maximum number of blocks committed to the ExEx WAL in a single notification.
+const EXEX_WAL_CHUNK_SIZE: u64 = 1_000;
+
+/// Commits the blocks above `target` to the ExEx WAL located at `wal_path`.
+///
+/// On the next start, an ExEx whose head is one of these blocks finds it in the WAL and receives
+/// [`ExExNotification::ChainReverted`]s down to `target`, instead of failing to find its head on
+/// the canonical chain. The reverted chains carry the blocks and their receipts, but no bundle
+/// state.
+///
+/// The blocks are read and committed in chunks of [`EXEX_WAL_CHUNK_SIZE`] blocks, one notification
+/// per chunk, so that large unwinds don't have to be held in memory at once.
+fn commit_unwound_blocks_to_exex_wal<N: ProviderNodeTypes>(
+    provider_factory: &ProviderFactory<N>,
+    wal_path: &Path,
+    target: BlockNumber,
+) -> eyre::Result<()> {
+    let provider = provider_factory.provider()?;
+    let last = provider.last_block_number()?;
+    if target >= last {
+        return Ok(())
+    }
+
+    let wal = Wal::<N::Primitives>::new(wal_path)?;
+    for start in (target + 1..=last).step_by(EXEX_WAL_CHUNK_SIZE as usize) {
+        let range = start..=last.min(start + EXEX_WAL_CHUNK_SIZE - 1);
+        let blocks = provider.recovered_block_range(range.clone())?;
+        let receipts = provider.receipts_by_block_range(range.clone())?;
+        let execution_outcome = ExecutionOutcome::new(Default::default(), receipts, start, vec![]);
+
+        wal.commit(&ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(blocks, execution_outcome, BTreeMap::new())),
+        })?;
+
+        info!(target: "reth::cli", ?range, "Committed unwound blocks to the ExEx WAL");
+    }
+
+    Ok(())
+}
+
 impl<C: ChainSpecParser> Command<C> {
     /// Return the underlying chain being used to run this command
     pub fn chain_spec(&self) -> Option<&Arc<C::ChainSpec>> {
@@ -209,6 +374,73 @@ mod tests {
             "100",
         ]);
         assert_eq!(cmd.command, Subcommands::NumBlocks { amount: 100 });
+        assert!(!cmd.skip_exex_wal);
+
+        let cmd = Command::<EthereumChainSpecParser>::parse_from([
+            "reth",
+            "--datadir",
+            "dir",
+            "--skip-exex-wal",
+            "num-blocks",
+            "100",
+        ]);
+        assert!(cmd.skip_exex_wal);
+
+        let cmd = Command::<EthereumChainSpecParser>::parse_from([
+            "reth",
+            "--datadir",
+            "dir",
+            "--skip-static-file-move",
+            "num-blocks",
+            "100",
+        ]);
+        assert!(cmd.skip_static_file_move);
+    }
+
+    #[test]
+    fn parse_unwind_stage_selection() {
+        let cmd = Command::<EthereumChainSpecParser>::parse_from([
+            "reth",
+            "--offline",
+            "--only-stage",
+            "merkle",
+            "--only-stage",
+            "hashing",
+            "num-blocks",
+            "100",
+        ]);
+        assert_eq!(cmd.only_stage, vec![StageEnum::Merkle, StageEnum::Hashing]);
+
+        let cmd = Command::<EthereumChainSpecParser>::parse_from([
+            "reth",
+            "--offline",
+            "--exclude-stage",
+            "account-history",
+            "num-blocks",
+            "100",
+        ]);
+        assert_eq!(cmd.exclude_stage, vec![StageEnum::AccountHistory]);
+
+        // Stage selection requires an offline unwind and is mutually exclusive
+        assert!(Command::<EthereumChainSpecParser>::try_parse_from([
+            "reth",
+            "--only-stage",
+            "merkle",
+            "num-blocks",
+            "100",
+        ])
+        .is_err());
+        assert!(Command::<EthereumChainSpecParser>::try_parse_from([
+            "reth",
+            "--offline",
+            "--only-stage",
+            "merkle",
+            "--exclude-stage",
+            "tx-lookup",
+            "num-blocks",
+            "100",
+        ])
+        .is_err());
     }
 
     #[test]
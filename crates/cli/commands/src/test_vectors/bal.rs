@@ -0,0 +1,111 @@
+//! EIP-7928 block access list test vectors.
+
+use alloy_consensus::Header;
+use alloy_eip7928::AccountChanges;
+use alloy_primitives::{hex, keccak256, Bytes, B256};
+use alloy_rlp::Encodable;
+use eyre::Result;
+use proptest::{
+    prelude::ProptestConfig,
+    strategy::{Strategy, ValueTree},
+    test_runner::{TestRng, TestRunner},
+};
+use proptest_arbitrary_interop::arb;
+use reth_fs_util as fs;
+use serde::Serialize;
+
+const VECTORS_FOLDER: &str = "testdata/micro/bal";
+
+/// A single block access list test vector.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BalVector {
+    /// RLP encoded block header committing to the block access list.
+    header: Bytes,
+    /// The block access list in its JSON representation.
+    block_access_list: Vec<AccountChanges>,
+    /// Expected RLP encoding of the block access list.
+    encoded: Bytes,
+    /// Expected `keccak256` hash of the encoding, equal to the header's `block_access_list_hash`.
+    hash: B256,
+}
+
+/// Generates `count` block access list vectors for random blocks and writes them to
+/// `testdata/micro/bal/block_access_lists.json`.
+pub fn generate_vectors(count: usize) -> Result<()> {
+    // Prepare random seed for test (same method as used by proptest)
+    let seed = B256::random();
+    println!("Seed for block access list test vectors: {:?}", hex::encode_prefixed(seed));
+
+    // Start the runner with the seed
+    let config = ProptestConfig::default();
+    let rng = TestRng::from_seed(config.rng_algorithm, &seed.0);
+    let mut runner = TestRunner::new_with_rng(config, rng);
+
+    fs::create_dir_all(VECTORS_FOLDER)?;
+
+    let strategy = (arb::<Header>(), arb::<Vec<AccountChanges>>()).no_shrink().boxed();
+
+    let mut vectors = Vec::with_capacity(count);
+    for number in 0..count as u64 {
+        let (mut header, mut block_access_list) =
+            strategy.new_tree(&mut runner).map_err(|e| eyre::eyre!("{e}"))?.current();
+        canonicalize(&mut block_access_list);
+
+        let mut encoded = Vec::new();
+        alloy_rlp::encode_list(&block_access_list, &mut encoded);
+        let hash = keccak256(&encoded);
+
+        header.number = number;
+        header.block_access_list_hash = Some(hash);
+        let mut encoded_header = Vec::with_capacity(header.length());
+        header.encode(&mut encoded_header);
+
+        vectors.push(BalVector {
+            header: encoded_header.into(),
+            block_access_list,
+            encoded: encoded.into(),
+            hash,
+        });
+    }
+
+    serde_json::to_writer_pretty(
+        std::io::BufWriter::new(std::fs::File::create(format!(
+            "{VECTORS_FOLDER}/block_access_lists.json"
+        ))?),
+        &vectors,
+    )?;
+
+    Ok(())
+}
+
+/// Brings a randomly generated block access list into the canonical form required by EIP-7928:
+/// accounts are unique and sorted by address, slots are unique and sorted, storage reads do not
+/// overlap with storage changes, and all changes are sorted by their block access index with at
+/// most one change per index.
+fn canonicalize(block_access_list: &mut Vec<AccountChanges>) {
+    block_access_list.sort_by_key(|account| account.address);
+    block_access_list.dedup_by_key(|account| account.address);
+
+    for account in block_access_list {
+        account.storage_changes.sort_by_key(|slot| slot.slot);
+        account.storage_changes.dedup_by_key(|slot| slot.slot);
+        for slot in &mut account.storage_changes {
+            slot.changes.sort_by_key(|change| change.block_access_index);
+            slot.changes.dedup_by_key(|change| change.block_access_index);
+        }
+
+        account.storage_reads.sort();
+        account.storage_reads.dedup();
+        account.storage_reads.retain(|read| {
+            account.storage_changes.binary_search_by_key(read, |slot| slot.slot).is_err()
+        });
+
+        account.balance_changes.sort_by_key(|change| change.block_access_index);
+        account.balance_changes.dedup_by_key(|change| change.block_access_index);
+        account.nonce_changes.sort_by_key(|change| change.block_access_index);
+        account.nonce_changes.dedup_by_key(|change| change.block_access_index);
+        account.code_changes.sort_by_key(|change| change.block_access_index);
+        account.code_changes.dedup_by_key(|change| change.block_access_index);
+    }
+}
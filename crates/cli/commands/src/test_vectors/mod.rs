@@ -2,6 +2,7 @@
 
 use clap::{Parser, Subcommand};
 
+pub mod bal;
 pub mod compact;
 pub mod tables;
 
@@ -36,6 +37,15 @@ pub enum Subcommands {
         #[arg(long)]
         read: bool,
     },
+    /// Randomly generate EIP-7928 block access list test vectors.
+    ///
+    /// Each vector contains a random block header, its block access list in JSON format, the
+    /// expected RLP encoding of the list and its hash, which the header commits to.
+    Bal {
+        /// Number of vectors to generate.
+        #[arg(long, default_value_t = 100)]
+        count: usize,
+    },
 }
 
 impl Command {
@@ -52,6 +62,9 @@ impl Command {
                     compact::read_vectors()?;
                 }
             }
+            Subcommands::Bal { count } => {
+                bal::generate_vectors(count)?;
+            }
         }
         Ok(())
     }
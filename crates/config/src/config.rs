@@ -1,9 +1,10 @@
 //! Configuration files.
 use reth_network_types::{PeersConfig, SessionsConfig};
-use reth_prune_types::{PruneModes, MINIMUM_UNWIND_SAFE_DISTANCE};
-use reth_stages_types::ExecutionStageThresholds;
-use reth_static_file_types::{StaticFileMap, StaticFileSegment};
+use reth_prune_types::{PruneMode, PruneModes, PruneSegment, MINIMUM_UNWIND_SAFE_DISTANCE};
+use reth_stages_types::{ExecutionStageThresholds, StageRetryPolicy, UnwindStageThresholds};
+use reth_static_file_types::{Compression, StaticFileMap, StaticFileSegment};
 use std::{
+    collections::BTreeMap,
     path::{Path, PathBuf},
     time::Duration,
 };
@@ -39,6 +40,13 @@ impl Config {
     pub fn set_prune_config(&mut self, prune_config: PruneConfig) {
         self.prune = prune_config;
     }
+
+    /// Validates constraints between configuration values that can't be expressed by their types.
+    pub fn validate(&self) -> eyre::Result<()> {
+        self.stages.validate()?;
+        self.prune.validate()?;
+        self.static_files.validate()
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -128,11 +136,31 @@ pub struct StageConfig {
     pub index_account_history: IndexHistoryConfig,
     /// Index Storage History stage configuration.
     pub index_storage_history: IndexHistoryConfig,
+    /// Block Access List stage configuration.
+    pub block_access_list: BlockAccessListConfig,
+    /// Unwind configuration shared by all stages.
+    pub unwind: UnwindConfig,
+    /// Retry policy for stages that failed with a transient error.
+    pub retry: StageRetryConfig,
     /// Common ETL related configuration.
     pub etl: EtlConfig,
 }
 
 impl StageConfig {
+    /// Validates the stage configuration.
+    ///
+    /// Returns an error if the bodies downloader concurrency range is empty.
+    pub fn validate(&self) -> eyre::Result<()> {
+        eyre::ensure!(
+            self.bodies.downloader_min_concurrent_requests <=
+                self.bodies.downloader_max_concurrent_requests,
+            "Bodies downloader minimum concurrent requests ({}) must not exceed the maximum ({})",
+            self.bodies.downloader_min_concurrent_requests,
+            self.bodies.downloader_max_concurrent_requests
+        );
+        Ok(())
+    }
+
     /// The highest threshold (in number of blocks) for switching between incremental and full
     /// calculations across `MerkleStage`, `AccountHashingStage` and `StorageHashingStage`. This is
     /// required to figure out if can prune or not changesets on subsequent pipeline runs during
@@ -192,6 +220,10 @@ pub struct HeadersConfig {
     pub downloader_request_limit: u64,
     /// The maximum number of headers to download before committing progress to the database.
     pub commit_threshold: u64,
+    /// The maximum number of bytes to download per second.
+    ///
+    /// Default: unlimited
+    pub downloader_max_bytes_per_second: Option<u64>,
 }
 
 impl Default for HeadersConfig {
@@ -202,6 +234,7 @@ impl Default for HeadersConfig {
             downloader_max_concurrent_requests: 100,
             downloader_min_concurrent_requests: 5,
             downloader_max_buffered_responses: 100,
+            downloader_max_bytes_per_second: None,
         }
     }
 }
@@ -232,6 +265,10 @@ pub struct BodiesConfig {
     ///
     /// Default: 100
     pub downloader_max_concurrent_requests: usize,
+    /// The maximum number of bytes to download per second.
+    ///
+    /// Default: unlimited
+    pub downloader_max_bytes_per_second: Option<u64>,
 }
 
 impl Default for BodiesConfig {
@@ -242,6 +279,7 @@ impl Default for BodiesConfig {
             downloader_max_buffered_blocks_size_bytes: 2 * 1024 * 1024 * 1024, // ~2GB
             downloader_min_concurrent_requests: 5,
             downloader_max_concurrent_requests: 100,
+            downloader_max_bytes_per_second: None,
         }
     }
 }
@@ -253,11 +291,15 @@ impl Default for BodiesConfig {
 pub struct SenderRecoveryConfig {
     /// The maximum number of transactions to process before committing progress to the database.
     pub commit_threshold: u64,
+    /// The number of threads used to recover senders.
+    ///
+    /// If not set, senders are recovered on the global rayon thread pool.
+    pub workers: Option<usize>,
 }
 
 impl Default for SenderRecoveryConfig {
     fn default() -> Self {
-        Self { commit_threshold: 5_000_000 }
+        Self { commit_threshold: 5_000_000, workers: None }
     }
 }
 
@@ -281,6 +323,30 @@ pub struct ExecutionConfig {
         )
     )]
     pub max_duration: Option<Duration>,
+    /// The maximum estimated size in bytes of the state held in memory before the execution stage
+    /// commits.
+    pub max_memory: Option<u64>,
+    /// The target duration of a batch of the execution stage, including writing its state
+    /// changes.
+    ///
+    /// If set, the number of blocks per batch is adapted to the measured gas throughput to keep
+    /// batches near this duration. `max_blocks` still bounds the batch size.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "humantime_serde::serialize",
+            deserialize_with = "deserialize_duration"
+        )
+    )]
+    pub target_batch_duration: Option<Duration>,
+    /// Whether to execute the transactions of blocks with a stored block access list in parallel.
+    ///
+    /// Blocks without a block access list, or whose transactions access state that isn't listed
+    /// in it, are executed serially.
+    pub parallel: bool,
+    /// The number of blocks ahead of the executed block whose state is prewarmed on a background
+    /// thread. Prewarming is disabled if unset.
+    pub prewarm_lookahead: Option<u64>,
 }
 
 impl Default for ExecutionConfig {
@@ -292,6 +358,10 @@ impl Default for ExecutionConfig {
             max_cumulative_gas: Some(30_000_000 * 50_000),
             // 10 minutes
             max_duration: Some(Duration::from_secs(10 * 60)),
+            max_memory: None,
+            target_batch_duration: None,
+            parallel: false,
+            prewarm_lookahead: None,
         }
     }
 }
@@ -303,6 +373,8 @@ impl From<ExecutionConfig> for ExecutionStageThresholds {
             max_changes: config.max_changes,
             max_cumulative_gas: config.max_cumulative_gas,
             max_duration: config.max_duration,
+            max_memory: config.max_memory,
+            target_batch_duration: config.target_batch_duration,
         }
     }
 }
@@ -359,15 +431,43 @@ pub struct MerkleConfig {
     pub incremental_threshold: u64,
     /// The threshold (in number of blocks) for switching from incremental trie building of changes
     /// to whole rebuild.
+    ///
+    /// Only used by [`MerkleRebuildStrategy::Threshold`], and by
+    /// [`MerkleRebuildStrategy::Adaptive`] if the changeset density can't be estimated.
     pub rebuild_threshold: u64,
+    /// How the stage decides between incremental trie building and whole rebuild.
+    ///
+    /// Default: adaptive
+    pub rebuild_strategy: MerkleRebuildStrategy,
 }
 
 impl Default for MerkleConfig {
     fn default() -> Self {
-        Self { incremental_threshold: 7_000, rebuild_threshold: 100_000 }
+        Self {
+            incremental_threshold: 7_000,
+            rebuild_threshold: 100_000,
+            rebuild_strategy: MerkleRebuildStrategy::default(),
+        }
     }
 }
 
+/// How the merkle stage decides between incremental trie building of changes and whole rebuild.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum MerkleRebuildStrategy {
+    /// Compare the estimated cost of both, based on the changeset density of the blocks to
+    /// process and the timings measured in previous runs of the stage.
+    #[default]
+    Adaptive,
+    /// Rebuild if there are more blocks to process than the `rebuild_threshold`.
+    Threshold,
+    /// Always build the trie incrementally, unless there is no trie yet.
+    Incremental,
+    /// Always rebuild the trie.
+    Rebuild,
+}
+
 /// Transaction Lookup stage configuration.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -375,11 +475,111 @@ impl Default for MerkleConfig {
 pub struct TransactionLookupConfig {
     /// The maximum number of transactions to process before writing to disk.
     pub chunk_size: u64,
+    /// The number of transactions hashed by a single worker at a time.
+    ///
+    /// Transactions are hashed in parallel in chunks of this size. Smaller chunks balance the
+    /// work better across workers, larger chunks reduce the scheduling overhead.
+    pub hashing_chunk_size: usize,
 }
 
 impl Default for TransactionLookupConfig {
     fn default() -> Self {
-        Self { chunk_size: 5_000_000 }
+        Self { chunk_size: 5_000_000, hashing_chunk_size: 100 }
+    }
+}
+
+/// Block Access List stage configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct BlockAccessListConfig {
+    /// Whether the stage is added to the pipeline. Disabled by default.
+    pub enabled: bool,
+    /// The maximum number of blocks to process before committing progress.
+    pub commit_threshold: u64,
+}
+
+impl Default for BlockAccessListConfig {
+    fn default() -> Self {
+        Self { enabled: false, commit_threshold: 10_000 }
+    }
+}
+
+/// Unwind configuration shared by all stages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct UnwindConfig {
+    /// The maximum number of blocks a stage unwinds before committing progress to the database.
+    ///
+    /// Stages that can't be unwound in chunks, like the merkle stage, always unwind the whole
+    /// range at once.
+    ///
+    /// Default: `10_000`
+    pub max_blocks: Option<u64>,
+}
+
+impl Default for UnwindConfig {
+    fn default() -> Self {
+        Self { max_blocks: UnwindStageThresholds::default().max_blocks }
+    }
+}
+
+impl From<UnwindConfig> for UnwindStageThresholds {
+    fn from(config: UnwindConfig) -> Self {
+        Self { max_blocks: config.max_blocks }
+    }
+}
+
+/// Retry policy for stages that failed with a transient error, e.g. because the database was busy
+/// or a network request timed out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct StageRetryConfig {
+    /// The maximum number of consecutive retries of a stage before the pipeline fails.
+    ///
+    /// Default: 5
+    pub max_attempts: u32,
+    /// The time to wait before the first retry. Doubles after every failed retry.
+    ///
+    /// Default: 1s
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub initial_backoff: Duration,
+    /// The maximum time to wait between retries.
+    ///
+    /// Default: 30s
+    #[cfg_attr(feature = "serde", serde(with = "humantime_serde"))]
+    pub max_backoff: Duration,
+    /// Overrides of `max_attempts` for individual stages, keyed by stage id, e.g. `Execution`.
+    pub max_attempts_per_stage: BTreeMap<String, u32>,
+}
+
+impl Default for StageRetryConfig {
+    fn default() -> Self {
+        let StageRetryPolicy { max_attempts, initial_backoff, max_backoff } =
+            StageRetryPolicy::default();
+        Self { max_attempts, initial_backoff, max_backoff, max_attempts_per_stage: BTreeMap::new() }
+    }
+}
+
+impl StageRetryConfig {
+    /// Returns the retry policy of stages without an override.
+    pub const fn default_policy(&self) -> StageRetryPolicy {
+        StageRetryPolicy {
+            max_attempts: self.max_attempts,
+            initial_backoff: self.initial_backoff,
+            max_backoff: self.max_backoff,
+        }
+    }
+
+    /// Returns the retry policy of the stage with the given id.
+    pub fn policy(&self, stage_id: &str) -> StageRetryPolicy {
+        let mut policy = self.default_policy();
+        if let Some(max_attempts) = self.max_attempts_per_stage.get(stage_id) {
+            policy.max_attempts = *max_attempts;
+        }
+        policy
     }
 }
 
@@ -419,12 +619,70 @@ impl EtlConfig {
 }
 
 /// Static files configuration.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct StaticFilesConfig {
+    /// Whether receipts of new static files are stored with the compact receipt encoding.
+    ///
+    /// It trades decoding time for considerably smaller receipt static files. Existing static
+    /// files keep their encoding.
+    pub compact_receipts: bool,
     /// Number of blocks per file for each segment.
     pub blocks_per_file: BlocksPerFileConfig,
+    /// Compression of new static files for each segment.
+    pub compression: CompressionConfig,
+    /// Secondary directory tier for old static files, disabled if not set.
+    pub cold_storage: Option<ColdStorageConfig>,
+    /// Persistence of the blob sidecars of canonical blocks, disabled if not set.
+    pub blob_sidecars: Option<BlobSidecarsConfig>,
+}
+
+/// Configuration of the secondary directory tier for old static files.
+///
+/// Static files whose block range ends below `below_block` are moved to the directory at `path`,
+/// e.g. on a larger but slower disk. Only their configuration files are kept locally, and they are
+/// copied back into the static files directory on access.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColdStorageConfig {
+    /// Secondary directory the old static files are moved to.
+    pub path: PathBuf,
+    /// Static files whose block range ends below this block are moved to `path`.
+    pub below_block: u64,
+    /// Maximum number of moved static files copied back into the static files directory at once.
+    #[cfg_attr(feature = "serde", serde(default = "default_max_cached_cold_files"))]
+    pub max_cached_files: usize,
+}
+
+/// Returns the default maximum number of locally cached cold static files.
+const fn default_max_cached_cold_files() -> usize {
+    16
+}
+
+/// Configuration of the persistence of blob sidecars.
+///
+/// The EIP-4844 blob sidecars of canonical blocks are moved from the transaction pool into the
+/// blob sidecars static files, so they can still be served by `engine_getBlobs*` after they left
+/// the pool. Only the sidecars of the last `retention_blocks` blocks are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct BlobSidecarsConfig {
+    /// Number of most recent blocks whose blob sidecars are kept.
+    pub retention_blocks: u64,
+}
+
+impl BlobSidecarsConfig {
+    /// The default retention of 4096 epochs, which is the minimum period consensus clients serve
+    /// blob sidecars for.
+    pub const DEFAULT_RETENTION_BLOCKS: u64 = 4096 * 32;
+}
+
+impl Default for BlobSidecarsConfig {
+    fn default() -> Self {
+        Self { retention_blocks: Self::DEFAULT_RETENTION_BLOCKS }
+    }
 }
 
 /// Configuration for the number of blocks per file for each segment.
@@ -444,6 +702,35 @@ pub struct BlocksPerFileConfig {
     pub account_change_sets: Option<u64>,
     /// Number of blocks per file for the storage changesets segment.
     pub storage_change_sets: Option<u64>,
+    /// Number of blocks per file for the blob sidecars segment.
+    pub blob_sidecars: Option<u64>,
+    /// Number of blocks per file for the block access lists segment.
+    pub block_access_lists: Option<u64>,
+}
+
+/// Configuration for the compression of new static files for each segment.
+///
+/// Existing static files keep the compression they were created with.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct CompressionConfig {
+    /// Compression of the headers segment.
+    pub headers: Option<Compression>,
+    /// Compression of the transactions segment.
+    pub transactions: Option<Compression>,
+    /// Compression of the receipts segment.
+    pub receipts: Option<Compression>,
+    /// Compression of the transaction senders segment.
+    pub transaction_senders: Option<Compression>,
+    /// Compression of the account changesets segment.
+    pub account_change_sets: Option<Compression>,
+    /// Compression of the storage changesets segment.
+    pub storage_change_sets: Option<Compression>,
+    /// Compression of the blob sidecars segment.
+    pub blob_sidecars: Option<Compression>,
+    /// Compression of the block access lists segment.
+    pub block_access_lists: Option<Compression>,
 }
 
 impl StaticFilesConfig {
@@ -458,6 +745,8 @@ impl StaticFilesConfig {
             transaction_senders,
             account_change_sets,
             storage_change_sets,
+            blob_sidecars,
+            block_access_lists,
         } = self.blocks_per_file;
         eyre::ensure!(headers != Some(0), "Headers segment blocks per file must be greater than 0");
         eyre::ensure!(
@@ -480,6 +769,14 @@ impl StaticFilesConfig {
             storage_change_sets != Some(0),
             "Storage changesets segment blocks per file must be greater than 0"
         );
+        eyre::ensure!(
+            blob_sidecars != Some(0),
+            "Blob sidecars segment blocks per file must be greater than 0"
+        );
+        eyre::ensure!(
+            block_access_lists != Some(0),
+            "Block access lists segment blocks per file must be greater than 0"
+        );
         Ok(())
     }
 
@@ -492,6 +789,8 @@ impl StaticFilesConfig {
             transaction_senders,
             account_change_sets,
             storage_change_sets,
+            blob_sidecars,
+            block_access_lists,
         } = self.blocks_per_file;
 
         let mut map = StaticFileMap::default();
@@ -505,6 +804,8 @@ impl StaticFilesConfig {
                 StaticFileSegment::TransactionSenders => transaction_senders,
                 StaticFileSegment::AccountChangeSets => account_change_sets,
                 StaticFileSegment::StorageChangeSets => storage_change_sets,
+                StaticFileSegment::BlobSidecars => blob_sidecars,
+                StaticFileSegment::BlockAccessLists => block_access_lists,
             };
 
             if let Some(blocks_per_file) = blocks_per_file {
@@ -513,6 +814,39 @@ impl StaticFilesConfig {
         }
         map
     }
+
+    /// Converts the compression configuration into a [`StaticFileMap`].
+    pub fn as_compression_map(&self) -> StaticFileMap<Compression> {
+        let CompressionConfig {
+            headers,
+            transactions,
+            receipts,
+            transaction_senders,
+            account_change_sets,
+            storage_change_sets,
+            blob_sidecars,
+            block_access_lists,
+        } = self.compression;
+
+        let mut map = StaticFileMap::default();
+        for segment in StaticFileSegment::iter() {
+            let compression = match segment {
+                StaticFileSegment::Headers => headers,
+                StaticFileSegment::Transactions => transactions,
+                StaticFileSegment::Receipts => receipts,
+                StaticFileSegment::TransactionSenders => transaction_senders,
+                StaticFileSegment::AccountChangeSets => account_change_sets,
+                StaticFileSegment::StorageChangeSets => storage_change_sets,
+                StaticFileSegment::BlobSidecars => blob_sidecars,
+                StaticFileSegment::BlockAccessLists => block_access_lists,
+            };
+
+            if let Some(compression) = compression {
+                map.insert(segment, compression);
+            }
+        }
+        map
+    }
 }
 
 /// History stage configuration.
@@ -572,6 +906,43 @@ impl PruneConfig {
         self.segments.has_receipts_pruning()
     }
 
+    /// Validates the pruning configuration.
+    ///
+    /// Returns an error if the prune interval is zero, or if any segment is configured to keep
+    /// fewer blocks than the minimum it requires for the node to handle reorgs.
+    pub fn validate(&self) -> eyre::Result<()> {
+        eyre::ensure!(self.block_interval > 0, "Prune block interval must be greater than 0");
+
+        let PruneModes {
+            sender_recovery,
+            transaction_lookup,
+            receipts,
+            account_history,
+            storage_history,
+            bodies_history,
+            receipts_log_filter: _,
+        } = &self.segments;
+
+        for (segment, mode) in [
+            (PruneSegment::SenderRecovery, sender_recovery),
+            (PruneSegment::TransactionLookup, transaction_lookup),
+            (PruneSegment::Receipts, receipts),
+            (PruneSegment::AccountHistory, account_history),
+            (PruneSegment::StorageHistory, storage_history),
+            (PruneSegment::Bodies, bodies_history),
+        ] {
+            if let Some(PruneMode::Distance(distance)) = mode {
+                eyre::ensure!(
+                    *distance >= segment.min_blocks(),
+                    "{segment} prune distance ({distance}) must be at least {} blocks",
+                    segment.min_blocks()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     /// Merges values from `other` into `self`.
     /// - `Option<PruneMode>` fields: set from `other` only if `self` is `None`.
     /// - `block_interval`: set from `other` only if `self.block_interval ==
@@ -638,14 +1009,21 @@ where
 
 #[cfg(all(test, feature = "serde"))]
 mod tests {
-    use super::{Config, EXTENSION};
+    use super::{
+        BlobSidecarsConfig, ColdStorageConfig, Compression, Config, StaticFileSegment, EXTENSION,
+    };
     use crate::PruneConfig;
     use alloy_primitives::Address;
     use reth_network_peers::TrustedPeer;
     use reth_prune_types::{
         PruneMode, PruneModes, ReceiptsLogPruneConfig, MINIMUM_UNWIND_SAFE_DISTANCE,
     };
-    use std::{collections::BTreeMap, path::Path, str::FromStr, time::Duration};
+    use std::{
+        collections::BTreeMap,
+        path::{Path, PathBuf},
+        str::FromStr,
+        time::Duration,
+    };
 
     fn with_tempdir(filename: &str, proc: fn(&std::path::Path)) {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -1160,6 +1538,23 @@ receipts = { distance = 16384 }
         assert_eq!(config1.segments.receipts_log_filter, original_filter);
     }
 
+    #[test]
+    fn test_validate() {
+        assert!(Config::default().validate().is_ok());
+
+        let mut config = Config::default();
+        config.prune.segments.receipts = Some(PruneMode::Distance(10));
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("Receipts prune distance (10)"), "{err}");
+
+        config.prune.segments.receipts = Some(PruneMode::Distance(64));
+        config.prune.segments.transaction_lookup = Some(PruneMode::Distance(0));
+        assert!(config.validate().is_ok());
+
+        config.stages.bodies.downloader_min_concurrent_requests = 200;
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_conf_trust_nodes_only() {
         let trusted_nodes_only = r"#
@@ -1200,4 +1595,89 @@ connect_trusted_nodes_only = true
             assert!(conf.peers.trusted_nodes.contains(&node));
         }
     }
+
+    #[test]
+    fn test_static_files_compression() {
+        let reth_toml = r#"
+    [static_files.compression]
+    transactions = "zstd-dict"
+    receipts = "zstd"
+    "#;
+
+        let conf: Config = toml::from_str(reth_toml).unwrap();
+        let compression = conf.static_files.as_compression_map();
+        assert_eq!(
+            compression.get(StaticFileSegment::Transactions),
+            Some(&Compression::ZstdWithDictionary)
+        );
+        assert_eq!(compression.get(StaticFileSegment::Receipts), Some(&Compression::Zstd));
+        assert_eq!(compression.get(StaticFileSegment::Headers), None);
+    }
+
+    #[test]
+    fn test_static_files_cold_storage() {
+        let reth_toml = r#"
+    [static_files.cold_storage]
+    path = "/mnt/archive/static_files"
+    below_block = 15537394
+    "#;
+
+        let conf: Config = toml::from_str(reth_toml).unwrap();
+        assert_eq!(
+            conf.static_files.cold_storage,
+            Some(ColdStorageConfig {
+                path: PathBuf::from("/mnt/archive/static_files"),
+                below_block: 15537394,
+                max_cached_files: 16,
+            })
+        );
+        assert_eq!(Config::default().static_files.cold_storage, None);
+    }
+
+    #[test]
+    fn test_static_files_blob_sidecars() {
+        let reth_toml = r#"
+    [static_files.blob_sidecars]
+    "#;
+
+        let conf: Config = toml::from_str(reth_toml).unwrap();
+        assert_eq!(
+            conf.static_files.blob_sidecars,
+            Some(BlobSidecarsConfig {
+                retention_blocks: BlobSidecarsConfig::DEFAULT_RETENTION_BLOCKS
+            })
+        );
+        assert_eq!(Config::default().static_files.blob_sidecars, None);
+
+        let reth_toml = r#"
+    [static_files.blob_sidecars]
+    retention_blocks = 1000
+
+    [static_files.blocks_per_file]
+    blob_sidecars = 100
+    "#;
+
+        let conf: Config = toml::from_str(reth_toml).unwrap();
+        assert_eq!(conf.static_files.blob_sidecars.unwrap().retention_blocks, 1000);
+        assert_eq!(
+            conf.static_files.as_blocks_per_file_map().get(StaticFileSegment::BlobSidecars),
+            Some(&100)
+        );
+    }
+
+    #[test]
+    fn test_static_files_compact_receipts() {
+        let reth_toml = r#"
+    [static_files]
+    compact_receipts = true
+
+    [static_files.compression]
+    receipts = "zstd"
+    "#;
+
+        let conf: Config = toml::from_str(reth_toml).unwrap();
+        assert!(conf.static_files.compact_receipts);
+        assert_eq!(conf.static_files.compression.receipts, Some(Compression::Zstd));
+        assert!(!Config::default().static_files.compact_receipts);
+    }
 }
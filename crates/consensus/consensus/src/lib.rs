@@ -501,6 +501,10 @@ pub enum ConsensusError {
     /// EIP-7928: Error when the block access list cannot be decoded or converted.
     #[error("invalid block access list: {0}")]
     BlockAccessListInvalid(String),
+    /// EIP-7928: Error when the block access list built during execution doesn't match the block
+    /// access list the block was executed with.
+    #[error("block access list mismatch: {0}")]
+    BlockAccessListMismatch(GotExpectedBoxed<B256>),
     /// Any additional consensus error, for example L2-specific errors.
     #[error(transparent)]
     Other(#[from] Arc<dyn Error + Send + Sync>),
@@ -520,6 +524,69 @@ impl ConsensusError {
         Self::other(MessageError(msg.to_string()))
     }
 
+    /// Returns a stable numeric code identifying the variant of this error.
+    ///
+    /// Codes are in the range `1..=999` and are never reassigned.
+    pub const fn error_code(&self) -> u32 {
+        match self {
+            Self::HeaderGasUsedExceedsGasLimit { .. } => 1,
+            Self::HeaderGasLimitExceedsMax { .. } => 2,
+            Self::BlockGasUsed { .. } => 3,
+            Self::BodyOmmersHashDiff(..) => 4,
+            Self::BodyStateRootDiff(..) => 5,
+            Self::BodyTransactionRootDiff(..) => 6,
+            Self::BodyReceiptRootDiff(..) => 7,
+            Self::BodyBloomLogDiff(..) => 8,
+            Self::BodyWithdrawalsRootDiff(..) => 9,
+            Self::BodyRequestsHashDiff(..) => 10,
+            Self::BlockKnown { .. } => 11,
+            Self::ParentUnknown { .. } => 12,
+            Self::ParentBlockNumberMismatch { .. } => 13,
+            Self::ParentHashMismatch(..) => 14,
+            Self::TimestampIsInFuture { .. } => 15,
+            Self::BaseFeeMissing => 16,
+            Self::TransactionSignerRecoveryError => 17,
+            Self::ExtraDataExceedsMax { .. } => 18,
+            Self::TheMergeDifficultyIsNotZero => 19,
+            Self::TheMergeNonceIsNotZero => 20,
+            Self::TheMergeOmmerRootIsNotEmpty => 21,
+            Self::WithdrawalsRootMissing => 22,
+            Self::RequestsHashMissing => 23,
+            Self::BlockAccessListHashMissing => 24,
+            Self::SlotNumberMissing => 25,
+            Self::WithdrawalsRootUnexpected => 26,
+            Self::RequestsHashUnexpected => 27,
+            Self::BlockAccessListHashUnexpected => 28,
+            Self::SlotNumberUnexpected => 29,
+            Self::BodyWithdrawalsMissing => 30,
+            Self::BodyRequestsMissing => 31,
+            Self::BlobGasUsedMissing => 32,
+            Self::BlobGasUsedUnexpected => 33,
+            Self::ExcessBlobGasMissing => 34,
+            Self::ExcessBlobGasUnexpected => 35,
+            Self::ParentBeaconBlockRootMissing => 36,
+            Self::ParentBeaconBlockRootUnexpected => 37,
+            Self::BlobGasUsedExceedsMaxBlobGasPerBlock { .. } => 38,
+            Self::BlobGasUsedNotMultipleOfBlobGasPerBlob { .. } => 39,
+            Self::BlobGasUsedDiff(..) => 40,
+            Self::InvalidTransaction(..) => 41,
+            Self::BaseFeeDiff(..) => 42,
+            Self::ExcessBlobGasDiff { .. } => 43,
+            Self::GasLimitInvalidIncrease { .. } => 44,
+            Self::GasLimitInvalidMinimum { .. } => 45,
+            Self::GasLimitInvalidBlockMaximum { .. } => 46,
+            Self::GasLimitInvalidDecrease { .. } => 47,
+            Self::TimestampIsInPast { .. } => 48,
+            Self::BlockTooLarge { .. } => 49,
+            Self::TransactionGasLimitTooHigh(..) => 50,
+            Self::BlockAccessListCostMoreThanGasLimit(..) => 51,
+            Self::BlockAccessListHashMismatch(..) => 52,
+            Self::BlockAccessListInvalid(..) => 53,
+            Self::Other(..) => 54,
+            Self::BlockAccessListMismatch(..) => 55,
+        }
+    }
+
     /// Returns `true` if the error is a state root error.
     pub const fn is_state_root_error(&self) -> bool {
         matches!(self, Self::BodyStateRootDiff(_))
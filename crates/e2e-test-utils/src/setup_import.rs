@@ -542,6 +542,67 @@ mod tests {
         assert_eq!(block_10_hash, test_blocks[9].hash(), "Block 10 hash should match");
     }
 
+    #[tokio::test]
+    async fn test_bulk_import_blocks() {
+        reth_tracing::init_test_tracing();
+
+        let chain_spec = create_test_chain_spec();
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let (test_blocks, rlp_path) =
+            setup_test_blocks_and_rlp(&chain_spec, 10, temp_dir.path()).await;
+        let rlp_size = std::fs::metadata(&rlp_path).unwrap().len();
+
+        let datadir = temp_dir.path().join("datadir");
+        std::fs::create_dir_all(&datadir).unwrap();
+        let db_path = datadir.join("db");
+        let db_args = reth_node_core::args::DatabaseArgs::default().database_args();
+        let db_env = reth_db::init_db(&db_path, db_args).unwrap();
+        let db = Arc::new(reth_db::test_utils::TempDatabase::new(db_env, db_path));
+
+        let provider_factory: ProviderFactory<MockNodeTypesWithDB> = ProviderFactory::new(
+            db.clone(),
+            chain_spec.clone(),
+            reth_provider::providers::StaticFileProvider::read_write(datadir.join("static_files"))
+                .unwrap(),
+            reth_provider::providers::RocksDBProvider::builder(datadir.join("rocksdb"))
+                .with_default_tables()
+                .build()
+                .unwrap(),
+            reth_tasks::Runtime::test(),
+        )
+        .expect("failed to create provider factory");
+        reth_db_common::init::init_genesis(&provider_factory).unwrap();
+
+        // Read the file in several chunks, so the blocks are appended across chunks before the
+        // pipeline runs once.
+        let import_config =
+            ImportConfig { bulk: true, chunk_len: Some(rlp_size / 3), ..Default::default() };
+        let result = import_blocks_from_file(
+            &rlp_path,
+            import_config,
+            provider_factory.clone(),
+            &Config::default(),
+            reth_node_ethereum::EthEvmConfig::new(chain_spec.clone()),
+            reth_consensus::noop::NoopConsensus::arc(),
+            reth_tasks::Runtime::test(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.total_decoded_blocks, 10);
+        assert_eq!(result.total_imported_blocks, 10);
+
+        let provider = provider_factory.database_provider_ro().unwrap();
+        assert_eq!(provider.last_block_number().unwrap(), 10);
+        for block in &test_blocks {
+            assert_eq!(provider.block_number(block.hash()).unwrap(), Some(block.number));
+        }
+        assert_eq!(
+            provider.get_stage_checkpoint(StageId::Finish).unwrap().unwrap().block_number,
+            10
+        );
+    }
+
     #[tokio::test]
     async fn test_import_with_node_integration() {
         // Tests the full integration with node setup, forkchoice updates, and syncing
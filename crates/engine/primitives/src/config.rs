@@ -15,6 +15,10 @@ pub const DEFAULT_MEMORY_BLOCK_BUFFER_TARGET: u64 = 0;
 /// The size of proof targets chunk to spawn in one multiproof calculation.
 pub const DEFAULT_MULTIPROOF_TASK_CHUNK_SIZE: usize = 5;
 
+/// Number of pending proof targets above which they are always chunked across proof workers,
+/// regardless of whether any workers are idle.
+pub const DEFAULT_MULTIPROOF_CHUNKING_THRESHOLD: usize = 300;
+
 /// Default number of cache hits before an invalid header entry is evicted and reprocessed.
 pub const DEFAULT_INVALID_HEADER_HIT_EVICTION_THRESHOLD: u8 = 128;
 
@@ -138,6 +142,14 @@ pub struct TreeConfig {
     has_enough_parallelism: bool,
     /// Multiproof task chunk size for proof targets.
     multiproof_chunk_size: usize,
+    /// Number of pending proof targets above which they are chunked across proof workers even
+    /// if none of them are idle.
+    multiproof_chunking_threshold: usize,
+    /// Whether to disable chunking of proof targets across proof workers.
+    disable_multiproof_chunking: bool,
+    /// Maximum number of transactions ahead of execution for which prewarming prefetches proofs,
+    /// unlimited if `None`.
+    proof_prefetch_depth: Option<usize>,
     /// Number of reserved CPU cores for non-reth processes
     reserved_cpu_cores: usize,
     /// Whether to disable the precompile cache
@@ -160,6 +172,11 @@ pub struct TreeConfig {
     always_process_payload_attributes_on_canonical_head: bool,
     /// Whether to unwind canonical header to ancestor during forkchoice updates.
     allow_unwind_canonical_header: bool,
+    /// Maximum number of canonical blocks a forkchoice update is allowed to unwind.
+    ///
+    /// Forkchoice updates that would reorg deeper are refused and require operator intervention.
+    /// If `None`, reorgs of any depth are applied.
+    max_reorg_depth: Option<u64>,
     /// Whether to disable cache metrics recording (can be expensive with large cached state).
     disable_cache_metrics: bool,
     /// Depth for sparse trie pruning after state root computation.
@@ -232,11 +249,15 @@ impl Default for TreeConfig {
             cross_block_cache_size: DEFAULT_CROSS_BLOCK_CACHE_SIZE,
             has_enough_parallelism: has_enough_parallelism(),
             multiproof_chunk_size: DEFAULT_MULTIPROOF_TASK_CHUNK_SIZE,
+            multiproof_chunking_threshold: DEFAULT_MULTIPROOF_CHUNKING_THRESHOLD,
+            disable_multiproof_chunking: false,
+            proof_prefetch_depth: None,
             reserved_cpu_cores: DEFAULT_RESERVED_CPU_CORES,
             precompile_cache_disabled: false,
             state_root_fallback: false,
             always_process_payload_attributes_on_canonical_head: false,
             allow_unwind_canonical_header: false,
+            max_reorg_depth: None,
             disable_cache_metrics: false,
             sparse_trie_prune_depth: DEFAULT_SPARSE_TRIE_PRUNE_DEPTH,
             sparse_trie_max_hot_slots: DEFAULT_SPARSE_TRIE_MAX_HOT_SLOTS,
@@ -308,11 +329,15 @@ impl TreeConfig {
             cross_block_cache_size,
             has_enough_parallelism,
             multiproof_chunk_size,
+            multiproof_chunking_threshold: DEFAULT_MULTIPROOF_CHUNKING_THRESHOLD,
+            disable_multiproof_chunking: false,
+            proof_prefetch_depth: None,
             reserved_cpu_cores,
             precompile_cache_disabled,
             state_root_fallback,
             always_process_payload_attributes_on_canonical_head,
             allow_unwind_canonical_header,
+            max_reorg_depth: None,
             disable_cache_metrics,
             sparse_trie_prune_depth,
             sparse_trie_max_hot_slots,
@@ -375,6 +400,22 @@ impl TreeConfig {
         self.multiproof_chunk_size
     }
 
+    /// Return the number of pending proof targets above which they are always chunked.
+    pub const fn multiproof_chunking_threshold(&self) -> usize {
+        self.multiproof_chunking_threshold
+    }
+
+    /// Returns whether chunking of proof targets across proof workers is disabled.
+    pub const fn disable_multiproof_chunking(&self) -> bool {
+        self.disable_multiproof_chunking
+    }
+
+    /// Returns the maximum number of transactions ahead of execution for which prewarming
+    /// prefetches proofs.
+    pub const fn proof_prefetch_depth(&self) -> Option<usize> {
+        self.proof_prefetch_depth
+    }
+
     /// Return the effective multiproof task chunk size.
     pub const fn effective_multiproof_chunk_size(&self) -> usize {
         self.multiproof_chunk_size
@@ -442,6 +483,11 @@ impl TreeConfig {
         self.allow_unwind_canonical_header
     }
 
+    /// Returns the maximum number of canonical blocks a forkchoice update is allowed to unwind.
+    pub const fn max_reorg_depth(&self) -> Option<u64> {
+        self.max_reorg_depth
+    }
+
     /// Setter for persistence threshold.
     pub const fn with_persistence_threshold(mut self, persistence_threshold: u64) -> Self {
         self.persistence_threshold = persistence_threshold;
@@ -561,6 +607,29 @@ impl TreeConfig {
         self
     }
 
+    /// Setter for the number of pending proof targets above which they are always chunked across
+    /// proof workers.
+    pub const fn with_multiproof_chunking_threshold(
+        mut self,
+        multiproof_chunking_threshold: usize,
+    ) -> Self {
+        self.multiproof_chunking_threshold = multiproof_chunking_threshold;
+        self
+    }
+
+    /// Setter for whether to disable chunking of proof targets across proof workers.
+    pub const fn without_multiproof_chunking(mut self, disable_multiproof_chunking: bool) -> Self {
+        self.disable_multiproof_chunking = disable_multiproof_chunking;
+        self
+    }
+
+    /// Setter for the maximum number of transactions ahead of execution for which prewarming
+    /// prefetches proofs.
+    pub const fn with_proof_prefetch_depth(mut self, proof_prefetch_depth: Option<usize>) -> Self {
+        self.proof_prefetch_depth = proof_prefetch_depth;
+        self
+    }
+
     /// Setter for the number of reserved CPU cores for any non-reth processes
     pub const fn with_reserved_cpu_cores(mut self, reserved_cpu_cores: usize) -> Self {
         self.reserved_cpu_cores = reserved_cpu_cores;
@@ -585,6 +654,12 @@ impl TreeConfig {
         self
     }
 
+    /// Setter for the maximum number of canonical blocks a forkchoice update is allowed to unwind.
+    pub const fn with_max_reorg_depth(mut self, max_reorg_depth: Option<u64>) -> Self {
+        self.max_reorg_depth = max_reorg_depth;
+        self
+    }
+
     /// Returns whether cache metrics recording is disabled.
     pub const fn disable_cache_metrics(&self) -> bool {
         self.disable_cache_metrics
@@ -1,6 +1,6 @@
 use crate::backfill::{BackfillAction, BackfillEvent, BackfillSync};
 use futures::Stream;
-use reth_stages_api::{ControlFlow, PipelineTarget};
+use reth_stages_api::{ControlFlow, PipelineError, PipelineTarget};
 use std::{
     fmt::{Display, Formatter, Result},
     pin::Pin,
@@ -96,6 +96,12 @@ where
                                 this.handler.on_event(FromOrchestrator::BackfillSyncFinished(ctrl));
                                 Poll::Ready(ChainEvent::BackfillSyncFinished)
                             }
+                            Err(PipelineError::Aborted) => {
+                                tracing::info!("backfill sync aborted");
+                                // notify handler that backfill sync stopped
+                                this.handler.on_event(FromOrchestrator::BackfillSyncAborted);
+                                Poll::Ready(ChainEvent::BackfillSyncAborted)
+                            }
                             Err(err) => {
                                 tracing::error!( %err, "backfill sync failed");
                                 Poll::Ready(ChainEvent::FatalError)
@@ -160,6 +166,9 @@ pub enum ChainEvent<T> {
     BackfillSyncStarted,
     /// Backfill sync finished
     BackfillSyncFinished,
+    /// Backfill sync was aborted through the
+    /// [`PipelineHandle`](reth_stages_api::PipelineHandle) of the pipeline
+    BackfillSyncAborted,
     /// Fatal error
     FatalError,
     /// Event emitted by the handler
@@ -175,6 +184,9 @@ impl<T: Display> Display for ChainEvent<T> {
             Self::BackfillSyncFinished => {
                 write!(f, "BackfillSyncFinished")
             }
+            Self::BackfillSyncAborted => {
+                write!(f, "BackfillSyncAborted")
+            }
             Self::FatalError => {
                 write!(f, "FatalError")
             }
@@ -225,6 +237,8 @@ pub enum FromOrchestrator {
     BackfillSyncFinished(ControlFlow),
     /// Invoked when backfill sync started
     BackfillSyncStarted,
+    /// Invoked when backfill sync was aborted before reaching its target
+    BackfillSyncAborted,
     /// Gracefully terminate the engine service.
     ///
     /// When this variant is received, the engine will persist all remaining in-memory blocks
@@ -235,3 +249,69 @@ pub enum FromOrchestrator {
         tx: tokio::sync::oneshot::Sender<()>,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{backfill::PipelineSync, test_utils::TestPipelineBuilder};
+    use alloy_primitives::B256;
+    use assert_matches::assert_matches;
+    use futures::StreamExt;
+    use reth_chainspec::MAINNET;
+    use reth_stages::ExecOutput;
+    use reth_stages_api::StageCheckpoint;
+    use reth_tasks::Runtime;
+    use std::collections::VecDeque;
+
+    /// Handler recording the events of the orchestrator.
+    #[derive(Default)]
+    struct TestHandler {
+        events: Vec<FromOrchestrator>,
+    }
+
+    impl ChainHandler for TestHandler {
+        type Event = ();
+
+        fn on_event(&mut self, event: FromOrchestrator) {
+            self.events.push(event);
+        }
+
+        fn poll(&mut self, _cx: &mut Context<'_>) -> Poll<HandlerEvent<Self::Event>> {
+            Poll::Pending
+        }
+    }
+
+    #[tokio::test]
+    async fn aborted_backfill_sync_is_not_fatal() {
+        let pipeline = TestPipelineBuilder::new()
+            .with_pipeline_exec_outputs(VecDeque::from([Ok(ExecOutput {
+                checkpoint: StageCheckpoint::new(5),
+                done: true,
+            })]))
+            .build(MAINNET.clone());
+        let handle = pipeline.handle();
+        let mut orchestrator = ChainOrchestrator::new(
+            TestHandler::default(),
+            PipelineSync::new(pipeline, Runtime::test()),
+        );
+
+        handle.abort();
+        orchestrator.start_backfill_sync(B256::random());
+        assert_matches!(orchestrator.next().await, Some(ChainEvent::BackfillSyncStarted));
+        assert_matches!(orchestrator.next().await, Some(ChainEvent::BackfillSyncAborted));
+        assert_matches!(
+            orchestrator.handler().events.as_slice(),
+            [FromOrchestrator::BackfillSyncStarted, FromOrchestrator::BackfillSyncAborted]
+        );
+
+        // the orchestrator is idle again and can run the pipeline to completion
+        handle.resume();
+        orchestrator.start_backfill_sync(B256::random());
+        assert_matches!(orchestrator.next().await, Some(ChainEvent::BackfillSyncStarted));
+        assert_matches!(orchestrator.next().await, Some(ChainEvent::BackfillSyncFinished));
+        assert_matches!(
+            orchestrator.handler().events.last(),
+            Some(FromOrchestrator::BackfillSyncFinished(ControlFlow::Continue { block_number: 5 }))
+        );
+    }
+}
@@ -162,9 +162,18 @@ where
         let start_time = Instant::now();
 
         if let Some(last) = last_block {
+            // blob sidecars staged for the blocks are written in the same transaction
+            let blob_sidecars = self.provider.blob_sidecar_stage().cloned().map(|stage| {
+                (stage, blocks.iter().map(|b| b.recovered_block.num_hash()).collect::<Vec<_>>())
+            });
+
             let provider_rw = self.provider.database_provider_rw()?;
             provider_rw.save_blocks(blocks, SaveBlocksMode::Full)?;
 
+            if let Some((stage, blocks)) = blob_sidecars {
+                stage.persist(&*provider_rw, blocks)?;
+            }
+
             if let Some(finalized) = pending_finalized {
                 provider_rw.save_finalized_block_number(finalized.min(last.number))?;
                 if finalized > last.number {
@@ -40,6 +40,7 @@ use reth_tasks::{spawn_os_thread, utils::increase_thread_priority};
 use reth_trie::prefix_set::TriePrefixSetsMut;
 use reth_trie_db::ChangesetCache;
 use revm::interpreter::debug_unreachable;
+use schnellru::{ByLength, LruMap};
 use state::TreeState;
 use std::{fmt::Debug, ops, sync::Arc, time::Duration};
 
@@ -64,12 +65,16 @@ mod tests;
 mod trie_updates;
 pub mod types;
 
+/// Number of recently received payloads whose height is remembered to detect forkchoice updates
+/// that supersede the payload being validated.
+const SUPERSEDING_PAYLOADS_LIMIT: u32 = 64;
+
 use crate::{persistence::PersistenceResult, tree::error::AdvancePersistenceError};
 pub use block_buffer::BlockBuffer;
 pub use invalid_headers::InvalidHeaderCache;
 pub use metrics::EngineApiMetrics;
 pub use payload_processor::*;
-pub use payload_validator::{BasicEngineValidator, EngineValidator};
+pub use payload_validator::{BasicEngineValidator, EngineValidator, StateRootCanceller};
 pub use persistence_state::PersistenceState;
 pub use reth_engine_primitives::TreeConfig;
 pub use reth_execution_cache::{
@@ -319,6 +324,9 @@ where
     /// Retained paths from the latest persistence cleanup to apply during the next sparse trie
     /// cache preservation.
     pending_sparse_trie_prune: Option<TriePrefixSetsMut>,
+    /// Cancels the state root task of the block being validated once another block at the same
+    /// height arrives.
+    state_root_canceller: StateRootCanceller,
     /// Task runtime for spawning blocking work on named, reusable threads.
     runtime: reth_tasks::Runtime,
 }
@@ -347,6 +355,7 @@ where
             .field("evm_config", &self.evm_config)
             .field("changeset_cache", &self.changeset_cache)
             .field("execution_timing_stats", &self.execution_timing_stats.len())
+            .field("state_root_canceller", &self.state_root_canceller)
             .field("runtime", &self.runtime)
             .finish()
     }
@@ -413,6 +422,7 @@ where
             execution_timing_stats: B256Map::default(),
             building_payload: false,
             pending_sparse_trie_prune: None,
+            state_root_canceller: StateRootCanceller::default(),
             runtime,
         }
     }
@@ -422,6 +432,9 @@ where
     ///
     /// Returns the sender through which incoming requests can be sent to the task and the receiver
     /// end of a [`EngineApiEvent`] unbounded channel to receive events from the engine.
+    ///
+    /// Requests are forwarded to the task by a separate thread, which cancels the state root task
+    /// of the block being validated once a request for another block at the same height arrives.
     #[expect(clippy::complexity)]
     pub fn spawn_new(
         provider: P,
@@ -472,14 +485,61 @@ where
             changeset_cache,
             runtime,
         );
-        let incoming = task.incoming_tx.clone();
+        let to_tree = task.incoming_tx.clone();
+        let state_root_canceller = task.state_root_canceller.clone();
         spawn_os_thread("engine", || {
             increase_thread_priority();
             task.run()
         });
+
+        let (incoming, requests) = crossbeam_channel::unbounded();
+        spawn_os_thread("engine-requests", || {
+            Self::forward_requests(requests, to_tree, state_root_canceller)
+        });
         (incoming, outgoing)
     }
 
+    /// Forwards requests to the tree task until either side of the channel is closed.
+    ///
+    /// The tree task is busy while it validates a payload, so a `newPayload` or forkchoice update
+    /// for another block at the same height is only seen here. In that case the state root task of
+    /// the block being validated is cancelled, because its result is no longer needed to follow the
+    /// chain.
+    fn forward_requests(
+        requests: Receiver<FromEngine<EngineApiRequest<T, N>, N::Block>>,
+        to_tree: Sender<FromEngine<EngineApiRequest<T, N>, N::Block>>,
+        state_root_canceller: StateRootCanceller,
+    ) {
+        // Heights of recently received payloads, used to resolve the head of forkchoice updates.
+        let mut payloads = LruMap::new(ByLength::new(SUPERSEDING_PAYLOADS_LIMIT));
+
+        for request in requests {
+            if let FromEngine::Request(EngineApiRequest::Beacon(message)) = &request {
+                let block = match message {
+                    BeaconEngineMessage::NewPayload { payload, .. } |
+                    BeaconEngineMessage::RethNewPayload { payload, .. } => {
+                        let num_hash = payload.num_hash();
+                        payloads.insert(num_hash.hash, num_hash.number);
+                        Some(num_hash)
+                    }
+                    BeaconEngineMessage::ForkchoiceUpdated { state, .. } => payloads
+                        .get(&state.head_block_hash)
+                        .map(|number| NumHash::new(*number, state.head_block_hash)),
+                };
+
+                if let Some(block) = block &&
+                    state_root_canceller.cancel_superseded(block)
+                {
+                    debug!(target: "engine::tree", ?block, "Cancelled state root task of superseded block");
+                }
+            }
+
+            if to_tree.send(request).is_err() {
+                return
+            }
+        }
+    }
+
     /// Returns a [`TreeOutcome`] indicating the forkchoice head is valid and canonical.
     fn valid_outcome(state: ForkchoiceState) -> TreeOutcome<OnForkChoiceUpdated> {
         TreeOutcome::new(OnForkChoiceUpdated::valid(PayloadStatus::new(
@@ -1276,6 +1336,14 @@ where
                 // pool operate with the correct chain state after forkchoice update processing, and
                 // new payloads built on the reorg'd head will be added to the tree immediately.
                 if self.config.unwind_canonical_header() {
+                    let depth = self
+                        .state
+                        .tree_state
+                        .canonical_block_number()
+                        .saturating_sub(canonical_header.number());
+                    if self.exceeds_max_reorg_depth(depth, &state) {
+                        return Ok(Some(TreeOutcome::new(OnForkChoiceUpdated::syncing())));
+                    }
                     self.update_latest_block_to_canonical_ancestor(&canonical_header)?;
                 }
 
@@ -1303,6 +1371,12 @@ where
 
         // Ensure we can apply a new chain update for the head block
         if let Some(chain_update) = self.on_new_head(state.head_block_hash)? {
+            if let NewCanonicalChain::Reorg { old, .. } = &chain_update &&
+                self.exceeds_max_reorg_depth(old.len() as u64, &state)
+            {
+                return Ok(Some(TreeOutcome::new(OnForkChoiceUpdated::syncing())));
+            }
+
             let tip = chain_update.tip().clone_sealed_header();
             self.on_canonical_chain_update(chain_update);
 
@@ -1324,6 +1398,26 @@ where
         Ok(None)
     }
 
+    /// Returns `true` if unwinding `depth` canonical blocks exceeds the configured
+    /// [`TreeConfig::max_reorg_depth`], in which case the forkchoice update must not be applied.
+    fn exceeds_max_reorg_depth(&self, depth: u64, state: &ForkchoiceState) -> bool {
+        let Some(max_reorg_depth) = self.config.max_reorg_depth() else { return false };
+        if depth <= max_reorg_depth {
+            return false
+        }
+
+        warn!(
+            target: "engine::tree",
+            depth,
+            max_reorg_depth,
+            head = ?state.head_block_hash,
+            canonical_head = ?self.state.tree_state.canonical_head(),
+            "Refusing forkchoice update that exceeds the maximum reorg depth, raise \
+             --engine.max-reorg-depth or unwind the node manually to apply it"
+        );
+        true
+    }
+
     /// Handles the case where the head block is missing and needs to be downloaded.
     ///
     /// This is the fallback case when all other forkchoice update scenarios have been exhausted.
@@ -1538,6 +1632,10 @@ where
                 FromOrchestrator::BackfillSyncFinished(ctrl) => {
                     self.on_backfill_sync_finished(ctrl)?;
                 }
+                FromOrchestrator::BackfillSyncAborted => {
+                    debug!(target: "engine::tree", "received backfill sync aborted event");
+                    self.backfill_sync_state = BackfillSyncState::Idle;
+                }
                 FromOrchestrator::Terminate { tx } => {
                     debug!(target: "engine::tree", "received terminate request");
                     if let Err(err) = self.finish_termination(tx) {
@@ -3015,15 +3113,18 @@ where
             &mut self.state,
             &self.canonical_in_memory_state,
             &mut self.pending_sparse_trie_prune,
+            &self.state_root_canceller,
         );
 
         let start = Instant::now();
 
+        let output = execute(&mut self.payload_validator, input, ctx);
+        self.state_root_canceller.clear();
         let ValidationOutput {
             executed_block: executed,
             execution_timing_stats: timing_stats,
             raw_bal,
-        } = execute(&mut self.payload_validator, input, ctx)?;
+        } = output?;
 
         if let Some(raw_bal) = raw_bal {
             let num_hash = executed.recovered_block().num_hash();
@@ -20,17 +20,20 @@ use alloy_eip7928::{
     compute_block_access_list_hash, BlockAccessList,
 };
 use alloy_evm::{
-    block::{BlockExecutionError, BlockExecutor, BlockValidationError, TxResult},
+    block::{BlockExecutionError, BlockExecutor, TxResult},
     Evm,
 };
 use alloy_primitives::Address;
 use crossbeam_channel::{Receiver, Sender};
-use reth_evm::{execute::ExecutableTxFor, ConfigureEvm, Database, EvmEnvFor, ExecutionCtxFor};
+use reth_evm::{
+    execute::ExecutableTxFor, parallel::BlockGasTracker, ConfigureEvm, Database, EvmEnvFor,
+    ExecutionCtxFor,
+};
 use reth_primitives_traits::ReceiptTy;
 use reth_provider::BlockExecutionOutput;
 use reth_tasks::Runtime;
 use revm::{
-    context::{result::ResultAndState, Block},
+    context::Block,
     database::{states::bundle_state::BundleRetention, State},
     state::bal::Bal as RevmBal,
 };
@@ -239,60 +242,6 @@ impl AbortGuard {
     }
 }
 
-/// Mirrors `EthBlockExecutor`'s cumulative gas admission check in the ordered BAL commit loop.
-#[derive(Debug)]
-struct BlockGasTracker {
-    block_gas_limit: u64,
-    enable_amsterdam_eip8037: bool,
-    tx_gas_limit_cap: Option<u64>,
-    cumulative_tx_gas_used: u64,
-    block_regular_gas_used: u64,
-}
-
-impl BlockGasTracker {
-    const fn new(
-        block_gas_limit: u64,
-        enable_amsterdam_eip8037: bool,
-        tx_gas_limit_cap: Option<u64>,
-    ) -> Self {
-        Self {
-            block_gas_limit,
-            enable_amsterdam_eip8037,
-            tx_gas_limit_cap,
-            cumulative_tx_gas_used: 0,
-            block_regular_gas_used: 0,
-        }
-    }
-
-    fn validate_tx_limit(&self, tx_gas_limit: u64) -> Result<(), BlockExecutionError> {
-        let block_gas_used = if self.enable_amsterdam_eip8037 {
-            self.block_regular_gas_used
-        } else {
-            self.cumulative_tx_gas_used
-        };
-        let block_available_gas = self.block_gas_limit.saturating_sub(block_gas_used);
-        let tx_min_gas_limit =
-            self.tx_gas_limit_cap.map_or(tx_gas_limit, |cap| tx_gas_limit.min(cap));
-
-        if tx_min_gas_limit > block_available_gas {
-            return Err(BlockValidationError::TransactionGasLimitMoreThanAvailableBlockGas {
-                transaction_gas_limit: tx_gas_limit,
-                block_available_gas,
-            }
-            .into());
-        }
-
-        Ok(())
-    }
-
-    const fn record_result<H>(&mut self, result: &ResultAndState<H>) {
-        let gas = result.result.gas();
-        self.cumulative_tx_gas_used = self.cumulative_tx_gas_used.saturating_add(gas.tx_gas_used());
-        self.block_regular_gas_used =
-            self.block_regular_gas_used.saturating_add(gas.block_regular_gas_used());
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,6 +259,7 @@ mod tests {
     use reth_revm::db::BundleState;
     use reth_tasks::Runtime;
     use revm::{
+        context::result::ResultAndState,
         database::{CacheDB, EmptyDB},
         state::{AccountInfo, Bytecode},
     };
@@ -126,6 +126,8 @@ where
     disable_bal_parallel_state_root: bool,
     /// Whether BAL state prefetching during prewarm is disabled.
     disable_bal_batch_io: bool,
+    /// Maximum number of transactions ahead of execution for which prewarming prefetches proofs.
+    proof_prefetch_depth: Option<usize>,
     /// Dedicated blocking pool for warming the BAL read-set, created lazily on the first BAL block
     /// (see [`Self::bal_prewarm_pool`]). Its threads exit when the processor is dropped.
     bal_prewarm_pool: OnceLock<Arc<bal_prewarm_pool::BalPrewarmPool>>,
@@ -152,7 +154,8 @@ impl PayloadProcessorSpawnOptions {
 
 struct SparseTrieTaskOptions {
     parent_state_root: B256,
-    chunk_size: usize,
+    chunking: MultiproofChunking,
+    cancel_rx: CrossbeamReceiver<()>,
     pending_sparse_trie_prune: Option<TriePrefixSetsMut>,
 }
 
@@ -193,6 +196,7 @@ where
                 .then(CachedStateCacheMetrics::default),
             disable_bal_parallel_state_root: config.disable_bal_parallel_state_root(),
             disable_bal_batch_io: config.disable_bal_batch_io(),
+            proof_prefetch_depth: config.proof_prefetch_depth(),
             bal_prewarm_pool: OnceLock::new(),
         }
     }
@@ -406,6 +410,7 @@ where
 
         let (state_root_tx, state_root_rx) = channel();
         let (hashed_state_tx, hashed_state_rx) = channel();
+        let (cancellation, cancel_rx) = StateRootCancellation::new();
 
         self.spawn_sparse_trie_task(
             proof_handle,
@@ -414,7 +419,8 @@ where
             from_multi_proof,
             SparseTrieTaskOptions {
                 parent_state_root,
-                chunk_size: config.multiproof_chunk_size(),
+                chunking: MultiproofChunking::from_config(config),
+                cancel_rx,
                 pending_sparse_trie_prune: if self.disable_sparse_trie_cache_pruning {
                     None
                 } else {
@@ -424,6 +430,7 @@ where
         );
 
         StateRootHandle::new(parent_state_root, updates_tx, state_root_rx, hashed_state_rx)
+            .with_cancellation(cancellation)
     }
 
     /// Transaction count threshold below which proof workers are halved, since fewer transactions
@@ -610,6 +617,7 @@ where
             precompile_cache_map: self.precompile_cache_map.clone(),
             disable_bal_parallel_state_root: self.disable_bal_parallel_state_root,
             disable_bal_batch_io: self.disable_bal_batch_io,
+            proof_prefetch_depth: self.proof_prefetch_depth,
         };
 
         let (prewarm_task, to_prewarm_task) = PrewarmCacheTask::new(
@@ -664,8 +672,12 @@ where
         from_multi_proof: CrossbeamReceiver<StateRootMessage>,
         options: SparseTrieTaskOptions,
     ) {
-        let SparseTrieTaskOptions { parent_state_root, chunk_size, pending_sparse_trie_prune } =
-            options;
+        let SparseTrieTaskOptions {
+            parent_state_root,
+            chunking,
+            cancel_rx,
+            pending_sparse_trie_prune,
+        } = options;
         let state_trie_overlays = self.state_trie_overlays.clone();
         let trie_metrics = self.trie_metrics.clone();
         let max_hot_slots = self.sparse_trie_max_hot_slots;
@@ -714,7 +726,8 @@ where
                 trie_metrics.clone(),
                 sparse_state_trie,
                 parent_state_root,
-                chunk_size,
+                chunking,
+                cancel_rx,
             );
 
             let result = task.run();
@@ -894,6 +907,11 @@ impl<Tx, Err, R: Send + Sync + 'static> PayloadHandle<Tx, Err, R> {
         self.state_root_handle.as_mut().expect("state_root_handle is None").state_root()
     }
 
+    /// Returns a handle to cancel the background state root computation, if spawned.
+    pub fn state_root_cancellation(&self) -> Option<StateRootCancellation> {
+        self.state_root_handle.as_ref().and_then(|handle| handle.cancellation()).cloned()
+    }
+
     /// Takes the state root receiver out of the handle for use with custom waiting logic
     /// (e.g., timeout-based waiting).
     ///
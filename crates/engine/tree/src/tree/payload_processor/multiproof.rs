@@ -1,16 +1,37 @@
 //! Multiproof task related functionality.
 
 use metrics::{Gauge, Histogram};
+use reth_engine_primitives::TreeConfig;
 use reth_metrics::Metrics;
 
 pub use reth_trie_parallel::state_root_task::{
-    evm_state_to_hashed_post_state, StateHookSender, StateRootComputeOutcome, StateRootHandle,
-    StateRootMessage,
+    evm_state_to_hashed_post_state, StateHookSender, StateRootCancellation,
+    StateRootComputeOutcome, StateRootHandle, StateRootMessage,
 };
 
-/// The default max targets, for limiting the number of account and storage proof targets to be
-/// fetched by a single worker. If exceeded, chunking is forced regardless of worker availability.
-pub(crate) const DEFAULT_MAX_TARGETS_FOR_CHUNKING: usize = 300;
+/// Controls how pending proof targets are split across proof workers.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MultiproofChunking {
+    /// The size of proof targets chunk to spawn in one calculation.
+    pub(crate) chunk_size: usize,
+    /// If this number is exceeded and chunking is enabled, then this will override whether or not
+    /// there are any idle workers and force chunking across workers. This is to prevent tasks
+    /// which are very long from hitting a single worker.
+    pub(crate) max_targets_for_chunking: usize,
+    /// Whether chunking is enabled. If disabled, all targets are processed in a single proof.
+    pub(crate) enabled: bool,
+}
+
+impl MultiproofChunking {
+    /// Creates the chunking parameters configured in the given [`TreeConfig`].
+    pub(crate) const fn from_config(config: &TreeConfig) -> Self {
+        Self {
+            chunk_size: config.multiproof_chunk_size(),
+            max_targets_for_chunking: config.multiproof_chunking_threshold(),
+            enabled: !config.disable_multiproof_chunking(),
+        }
+    }
+}
 
 #[derive(Metrics, Clone)]
 #[metrics(scope = "tree.root")]
@@ -53,14 +74,12 @@ pub(crate) struct MultiProofTaskMetrics {
     pub sparse_trie_retained_storage_tries: Gauge,
 }
 
-/// Dispatches work items as a single unit or in chunks based on target size and worker
-/// availability.
-#[expect(clippy::too_many_arguments)]
+/// Dispatches work items as a single unit or in chunks based on target size, worker availability
+/// and the configured [`MultiproofChunking`].
 pub(crate) fn dispatch_with_chunking<T, I>(
     items: T,
     chunking_len: usize,
-    chunk_size: usize,
-    max_targets_for_chunking: usize,
+    chunking: MultiproofChunking,
     has_multiple_idle_account_workers: bool,
     has_multiple_idle_storage_workers: bool,
     chunker: impl FnOnce(T, usize) -> I,
@@ -68,6 +87,12 @@ pub(crate) fn dispatch_with_chunking<T, I>(
 ) where
     I: IntoIterator<Item = T>,
 {
+    let MultiproofChunking { chunk_size, max_targets_for_chunking, enabled } = chunking;
+    if !enabled {
+        dispatch(items);
+        return;
+    }
+
     let has_full_chunks = chunking_len >= chunk_size.saturating_mul(2);
     let should_chunk = chunking_len > max_targets_for_chunking ||
         (has_full_chunks &&
@@ -18,9 +18,9 @@ use crate::tree::{
     CachedStateCacheMetrics, CachedStateMetrics, CachedStateProvider, ExecutionEnv,
     PayloadExecutionCache, SavedCache, StateProviderBuilder,
 };
-use alloy_consensus::transaction::TxHashRef;
+use alloy_consensus::{transaction::TxHashRef, Transaction};
 use alloy_eip7928::bal::DecodedBal;
-use alloy_eips::eip4895::Withdrawal;
+use alloy_eips::{eip2930::AccessList, eip4895::Withdrawal};
 use alloy_primitives::{keccak256, B256, U256};
 use crossbeam_channel::Sender as CrossbeamSender;
 use metrics::{Counter, Gauge, Histogram};
@@ -163,6 +163,17 @@ where
                         continue;
                     }
 
+                    // Fetch proofs for the declared access list right away, so the trie nodes
+                    // along these paths are revealed before state root computation needs them.
+                    if let Some(to_sparse_trie_task) = to_sparse_trie_task &&
+                        ctx.should_prefetch_proofs(index) &&
+                        let Some(access_list) = tx.tx().access_list() &&
+                        !access_list.is_empty()
+                    {
+                        let targets = multiproof_targets_from_access_list(access_list);
+                        let _ = to_sparse_trie_task.send(StateRootMessage::PrefetchProofs(targets));
+                    }
+
                     tx_count += 1;
                     let parent_span = Span::current();
                     s.spawn(move |_| {
@@ -246,7 +257,7 @@ where
                 return;
             }
 
-            if index > 0 {
+            if index > 0 && ctx.should_prefetch_proofs(index) {
                 let (targets, storage_targets) = MultiProofTargetsV2::from_state(res.state);
                 ctx.metrics.prefetch_storage_targets.record(storage_targets as f64);
                 if let Some(to_sparse_trie_task) = to_sparse_trie_task {
@@ -548,6 +559,9 @@ where
     pub disable_bal_parallel_state_root: bool,
     /// Whether BAL state prefetching during prewarm is disabled.
     pub disable_bal_batch_io: bool,
+    /// Maximum number of transactions ahead of execution for which proofs are prefetched,
+    /// unlimited if `None`.
+    pub proof_prefetch_depth: Option<usize>,
 }
 
 /// Per-thread EVM state initialised by [`PrewarmContext::evm_for_ctx`] and stored in
@@ -561,6 +575,14 @@ where
     P: BlockReader + StateProviderFactory + StateReader + Clone + 'static,
     Evm: ConfigureEvm<Primitives = N> + 'static,
 {
+    /// Returns whether the proofs of the transaction at the index are prefetched, given how far
+    /// ahead of execution it is.
+    fn should_prefetch_proofs(&self, index: usize) -> bool {
+        self.proof_prefetch_depth.is_none_or(|depth| {
+            index < self.executed_tx_index.load(Ordering::Relaxed).saturating_add(depth)
+        })
+    }
+
     /// Creates a per-thread EVM for prewarming.
     #[instrument(level = "debug", target = "engine::tree::payload_processor::prewarm", skip_all)]
     fn evm_for_ctx(&self) -> PrewarmEvmState<Evm> {
@@ -782,6 +804,23 @@ fn multiproof_targets_from_withdrawals(withdrawals: &[Withdrawal]) -> MultiProof
     }
 }
 
+/// Returns [`MultiProofTargetsV2`] for the accounts and storage slots declared in an access list.
+fn multiproof_targets_from_access_list(access_list: &AccessList) -> MultiProofTargetsV2 {
+    let mut targets = MultiProofTargetsV2::default();
+    for item in access_list.iter() {
+        let hashed_address = keccak256(item.address);
+        targets.account_targets.push(hashed_address.into());
+        if !item.storage_keys.is_empty() {
+            targets
+                .storage_targets
+                .entry(hashed_address)
+                .or_default()
+                .extend(item.storage_keys.iter().map(|slot| keccak256(slot).into()));
+        }
+    }
+    targets
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -789,6 +828,7 @@ mod tests {
         AccountChanges, BalanceChange, BlockAccessIndex, CodeChange, NonceChange, SlotChanges,
         StorageChange,
     };
+    use alloy_eips::eip2930::AccessListItem;
     use alloy_primitives::{address, bytes};
 
     #[test]
@@ -841,6 +881,32 @@ mod tests {
         assert_eq!(account.nonce, 3);
         assert_eq!(account.bytecode_hash, Some(B256::repeat_byte(0xaa)));
     }
+
+    #[test]
+    fn access_list_targets_include_accounts_and_slots() {
+        let with_slots = address!("0000000000000000000000000000000000000001");
+        let without_slots = address!("0000000000000000000000000000000000000002");
+        let slot = B256::with_last_byte(1);
+        let access_list = AccessList::from(vec![
+            AccessListItem { address: with_slots, storage_keys: vec![slot] },
+            AccessListItem { address: without_slots, storage_keys: vec![] },
+        ]);
+
+        let targets = multiproof_targets_from_access_list(&access_list);
+
+        assert_eq!(
+            targets.account_targets.iter().map(|target| target.key()).collect::<Vec<_>>(),
+            vec![keccak256(with_slots), keccak256(without_slots)]
+        );
+        assert_eq!(targets.storage_targets.len(), 1);
+        assert_eq!(
+            targets.storage_targets[&keccak256(with_slots)]
+                .iter()
+                .map(|target| target.key())
+                .collect::<Vec<_>>(),
+            vec![keccak256(slot)]
+        );
+    }
 }
 
 /// The events the pre-warm task can handle.
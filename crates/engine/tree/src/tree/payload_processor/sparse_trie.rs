@@ -4,8 +4,8 @@ use std::sync::Arc;
 
 use crate::tree::{
     multiproof::{
-        dispatch_with_chunking, evm_state_to_hashed_post_state, StateRootComputeOutcome,
-        StateRootMessage, DEFAULT_MAX_TARGETS_FOR_CHUNKING,
+        dispatch_with_chunking, evm_state_to_hashed_post_state, MultiproofChunking,
+        StateRootComputeOutcome, StateRootMessage,
     },
     payload_processor::multiproof::MultiProofTaskMetrics,
 };
@@ -53,13 +53,10 @@ pub(super) struct SparseTrieCacheTask<A = ArenaParallelSparseTrie, S = ArenaPara
     /// Handle to the proof worker pools (storage and account).
     proof_worker_handle: ProofWorkerHandle,
 
-    /// The size of proof targets chunk to spawn in one calculation.
-    /// If None, chunking is disabled and all targets are processed in a single proof.
-    chunk_size: usize,
-    /// If this number is exceeded and chunking is enabled, then this will override whether or not
-    /// there are any active workers and force chunking across workers. This is to prevent tasks
-    /// which are very long from hitting a single worker.
-    max_targets_for_chunking: usize,
+    /// How pending proof targets are split across proof workers.
+    chunking: MultiproofChunking,
+    /// Receives a message when the computation should be cancelled.
+    cancel_rx: CrossbeamReceiver<()>,
 
     /// Account trie updates.
     account_updates: B256Map<LeafUpdate>,
@@ -133,7 +130,8 @@ where
         metrics: MultiProofTaskMetrics,
         trie: SparseStateTrie<A, S>,
         parent_state_root: B256,
-        chunk_size: usize,
+        chunking: MultiproofChunking,
+        cancel_rx: CrossbeamReceiver<()>,
     ) -> Self {
         let (proof_result_tx, proof_result_rx) = crossbeam_channel::unbounded();
         let (hashed_state_tx, hashed_state_rx) = crossbeam_channel::unbounded();
@@ -153,8 +151,8 @@ where
             final_hashed_state_tx: Some(final_hashed_state_tx),
             trie,
             parent_state_root,
-            chunk_size,
-            max_targets_for_chunking: DEFAULT_MAX_TARGETS_FOR_CHUNKING,
+            chunking,
+            cancel_rx,
             account_updates: Default::default(),
             storage_updates: Default::default(),
             new_account_updates: Default::default(),
@@ -255,6 +253,16 @@ where
         loop {
             let mut t = Instant::now();
             crossbeam_channel::select_biased! {
+                recv(self.cancel_rx) -> message => {
+                    if message.is_ok() {
+                        debug!(target: "engine::root", "State root computation cancelled");
+                        return Err(StateRootTaskError::Cancelled)
+                    }
+                    // All cancellation handles were dropped, so the computation can no longer be
+                    // cancelled.
+                    self.cancel_rx = crossbeam_channel::never();
+                    continue
+                }
                 recv(self.updates) -> message => {
                     let wake = Instant::now();
 
@@ -335,7 +343,7 @@ where
                 self.process_new_updates()?;
                 self.metrics.sparse_trie_process_updates_duration_histogram.record(t.elapsed());
                 self.dispatch_pending_targets();
-            } else if self.pending_targets.len() > self.chunk_size {
+            } else if self.pending_targets.len() > self.chunking.chunk_size {
                 // Make sure to dispatch targets if we've accumulated a lot of them.
                 self.dispatch_pending_targets();
             }
@@ -795,8 +803,7 @@ where
         dispatch_with_chunking(
             targets,
             chunking_length,
-            self.chunk_size,
-            self.max_targets_for_chunking,
+            self.chunking,
             self.proof_worker_handle.has_multiple_idle_account_workers(),
             self.proof_worker_handle.has_multiple_idle_storage_workers(),
             MultiProofTargetsV2::chunks,
@@ -884,7 +891,10 @@ enum SparseTrieTaskMessage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tree::{multiproof::StateRootCancellation, StateRootCanceller};
+    use alloy_eips::NumHash;
     use alloy_primitives::{keccak256, Address, B256, U256};
+    use reth_engine_primitives::TreeConfig;
     use reth_provider::{
         providers::{OverlayBuilder, OverlayStateProviderFactory},
         test_utils::create_test_provider_factory,
@@ -973,9 +983,12 @@ mod tests {
         assert_eq!(account_rlp_buf, encoded);
     }
 
-    #[test]
-    fn run_returns_parent_root_without_revealing_blind_trie_when_no_state_updates() {
-        let runtime = reth_tasks::Runtime::test();
+    fn blind_trie_task(
+        runtime: &reth_tasks::Runtime,
+        updates_rx: CrossbeamReceiver<StateRootMessage>,
+        parent_state_root: B256,
+        cancel_rx: CrossbeamReceiver<()>,
+    ) -> SparseTrieCacheTask {
         let provider_factory = create_test_provider_factory();
         let anchor_hash = provider_factory.chain_spec().genesis_hash();
         let overlay_factory = OverlayStateProviderFactory::new(
@@ -994,18 +1007,26 @@ mod tests {
             .with_default_storage_trie(default_trie)
             .with_updates(true);
 
-        let parent_state_root = B256::from([0x55; 32]);
-        let (updates_tx, updates_rx) = crossbeam_channel::unbounded();
-        let mut task = SparseTrieCacheTask::new_with_trie(
-            &runtime,
+        SparseTrieCacheTask::new_with_trie(
+            runtime,
             updates_rx,
             std::sync::mpsc::channel().0,
             proof_worker_handle,
             MultiProofTaskMetrics::default(),
             trie,
             parent_state_root,
-            1,
-        );
+            MultiproofChunking::from_config(&TreeConfig::default().with_multiproof_chunk_size(1)),
+            cancel_rx,
+        )
+    }
+
+    #[test]
+    fn run_returns_parent_root_without_revealing_blind_trie_when_no_state_updates() {
+        let runtime = reth_tasks::Runtime::test();
+        let parent_state_root = B256::from([0x55; 32]);
+        let (updates_tx, updates_rx) = crossbeam_channel::unbounded();
+        let mut task =
+            blind_trie_task(&runtime, updates_rx, parent_state_root, crossbeam_channel::never());
 
         updates_tx.send(StateRootMessage::FinishedStateUpdates).unwrap();
         drop(updates_tx);
@@ -1016,4 +1037,28 @@ mod tests {
         assert!(outcome.trie_updates.is_empty());
         assert!(task.trie.state_trie_ref().is_none(), "blind trie should not be revealed");
     }
+
+    #[test]
+    fn run_stops_when_superseded_block_is_cancelled() {
+        let runtime = reth_tasks::Runtime::test();
+        let (cancellation, cancel_rx) = StateRootCancellation::new();
+        // Keep the updates channel open, so the task only stops because it was cancelled.
+        let (_updates_tx, updates_rx) = crossbeam_channel::unbounded();
+        let mut task = blind_trie_task(&runtime, updates_rx, B256::from([0x55; 32]), cancel_rx);
+
+        let block = NumHash::new(1, B256::with_last_byte(1));
+        let canceller = StateRootCanceller::default();
+        canceller.set(block, cancellation);
+
+        // The block itself and blocks at other heights don't supersede it.
+        assert!(!canceller.cancel_superseded(block));
+        assert!(!canceller.cancel_superseded(NumHash::new(2, B256::with_last_byte(2))));
+        assert!(canceller.cancel_superseded(NumHash::new(1, B256::with_last_byte(2))));
+
+        assert!(matches!(task.run(), Err(StateRootTaskError::Cancelled)));
+
+        // Once the task is gone there is nothing left to cancel.
+        drop(task);
+        assert!(!canceller.cancel_superseded(NumHash::new(1, B256::with_last_byte(3))));
+    }
 }
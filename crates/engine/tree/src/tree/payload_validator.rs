@@ -99,7 +99,7 @@
 use crate::tree::{
     error::{InsertBlockError, InsertBlockErrorKind, InsertPayloadError},
     instrumented_state::{InstrumentedStateProvider, StateProviderMetrics, StateProviderStats},
-    multiproof::{StateRootComputeOutcome, StateRootHandle},
+    multiproof::{StateRootCancellation, StateRootComputeOutcome, StateRootHandle},
     payload_processor::{PayloadProcessor, PayloadProcessorSpawnOptions},
     precompile_cache::{CachedPrecompile, CachedPrecompileMetrics, PrecompileCacheMap},
     types::{InsertPayloadResult, ValidationOutput},
@@ -158,7 +158,7 @@ use std::{
     sync::{
         atomic::{AtomicUsize, Ordering},
         mpsc::RecvTimeoutError,
-        Arc,
+        Arc, Mutex, PoisonError,
     },
     time::Duration,
 };
@@ -192,6 +192,8 @@ pub struct TreeCtx<'a, N: NodePrimitives> {
     canonical_in_memory_state: &'a CanonicalInMemoryState<N>,
     /// Pending sparse trie prune request to consume when spawning a sparse trie task.
     pending_sparse_trie_prune: &'a mut Option<TriePrefixSetsMut>,
+    /// Cancels the state root task once the block being validated is superseded.
+    state_root_canceller: &'a StateRootCanceller,
 }
 
 impl<'a, N: NodePrimitives> std::fmt::Debug for TreeCtx<'a, N> {
@@ -200,6 +202,7 @@ impl<'a, N: NodePrimitives> std::fmt::Debug for TreeCtx<'a, N> {
             .field("state", &"EngineApiTreeState")
             .field("canonical_in_memory_state", &self.canonical_in_memory_state)
             .field("pending_sparse_trie_prune", &self.pending_sparse_trie_prune.is_some())
+            .field("state_root_canceller", &self.state_root_canceller)
             .finish()
     }
 }
@@ -210,8 +213,9 @@ impl<'a, N: NodePrimitives> TreeCtx<'a, N> {
         state: &'a mut EngineApiTreeState<N>,
         canonical_in_memory_state: &'a CanonicalInMemoryState<N>,
         pending_sparse_trie_prune: &'a mut Option<TriePrefixSetsMut>,
+        state_root_canceller: &'a StateRootCanceller,
     ) -> Self {
-        Self { state, canonical_in_memory_state, pending_sparse_trie_prune }
+        Self { state, canonical_in_memory_state, pending_sparse_trie_prune, state_root_canceller }
     }
 }
 
@@ -235,6 +239,11 @@ impl<'a, N: NodePrimitives> TreeCtx<'a, N> {
     pub const fn take_sparse_trie_prune(&mut self) -> Option<TriePrefixSetsMut> {
         self.pending_sparse_trie_prune.take()
     }
+
+    /// Returns the handle used to cancel the state root task of the block being validated.
+    pub const fn state_root_canceller(&self) -> &'a StateRootCanceller {
+        self.state_root_canceller
+    }
 }
 
 /// Pauses JIT helper execution while validating imported payloads.
@@ -261,6 +270,42 @@ impl<Evm: ConfigureEvm> Drop for JitPauseGuard<Evm> {
     }
 }
 
+/// Cancels the background state root computation of the block being validated once another block
+/// at the same height supersedes it.
+///
+/// Owned by the [`EngineApiTreeHandler`](crate::tree::EngineApiTreeHandler) and handed to the
+/// [`EngineValidator`] through [`TreeCtx`]. Cancelling releases the proof workers of a payload
+/// whose result is no longer needed. Validation of the cancelled payload still completes by
+/// computing the state root synchronously, so cancellation never turns into a validation error.
+#[derive(Debug, Clone, Default)]
+pub struct StateRootCanceller(Arc<Mutex<Option<(NumHash, StateRootCancellation)>>>);
+
+impl StateRootCanceller {
+    /// Registers the state root task of the block being validated.
+    pub fn set(&self, block: NumHash, cancellation: StateRootCancellation) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = Some((block, cancellation));
+    }
+
+    /// Forgets the state root task of the last validated block.
+    pub fn clear(&self) {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).take();
+    }
+
+    /// Cancels the state root task if the block being validated is at the height of the given
+    /// block but has a different hash.
+    ///
+    /// Returns `true` if a running state root task was signalled.
+    pub fn cancel_superseded(&self, block: NumHash) -> bool {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).as_ref().is_some_and(
+            |(validating, cancellation)| {
+                validating.number == block.number &&
+                    validating.hash != block.hash &&
+                    cancellation.cancel()
+            },
+        )
+    }
+}
+
 /// A helper type that provides reusable payload validation logic for network-specific validators.
 ///
 /// This type satisfies [`EngineValidator`] and is responsible for executing blocks/payloads.
@@ -611,6 +656,9 @@ where
             &strategy,
             processor_options,
         ));
+        if let Some(cancellation) = handle.state_root_cancellation() {
+            ctx.state_root_canceller().set(input.num_hash(), cancellation);
+        }
 
         // Create optional cache stats for detailed block logging
         let slow_block_enabled = self.config.slow_block_threshold().is_some();
@@ -864,6 +912,11 @@ where
 
                         maybe_new_hashed_state
                     }
+                    Err(StateRootTaskError::Cancelled) => {
+                        debug!(target: "engine::tree::payload_validator", "State root task cancelled");
+                        state_root_task_failed = true;
+                        None
+                    }
                     Err(error) => {
                         debug!(target: "engine::tree::payload_validator", %error, "State root task failed");
                         state_root_task_failed = true;
@@ -1200,7 +1253,8 @@ where
     /// 2. Relies on BAL prewarm to stream sparse-trie updates and optional state prefetches.
     /// 3. Spawns the receipt-root task.
     /// 4. Calls [`crate::tree::payload_processor::bal::execute_block`].
-    /// 5. Returns the rebuilt BAL for post-execution consensus validation.
+    /// 5. Rejects the block if the rebuilt BAL doesn't match the received one.
+    /// 6. Returns the rebuilt BAL for post-execution consensus validation.
     #[instrument(level = "debug", target = "engine::tree::payload_validator", skip_all)]
     #[expect(clippy::type_complexity)]
     fn execute_block_bal<Tx, Err, MakeStateProvider, T>(
@@ -1232,6 +1286,7 @@ where
         let input_bal = env.decoded_bal.ok_or_else(|| {
             InsertBlockErrorKind::Other("BAL execute path: no decoded BAL available".into())
         })?;
+        let input_bal_hash = input_bal.as_raw_bal().hash();
 
         let make_db = |fill_on_miss| {
             let provider = make_state_provider(fill_on_miss)
@@ -1262,6 +1317,16 @@ where
             "Executed block via BAL path",
         );
 
+        // The block was executed against the received BAL, so the BAL built during execution must
+        // be identical to it.
+        let built_bal_hash = compute_block_access_list_hash(&built_bal);
+        if built_bal_hash != input_bal_hash {
+            return Err(ConsensusError::BlockAccessListMismatch(
+                GotExpected { got: built_bal_hash, expected: input_bal_hash }.into(),
+            )
+            .into())
+        }
+
         Ok((output, senders, result_rx, Some(built_bal)))
     }
 
@@ -503,6 +503,7 @@ impl ValidatorTestHarness {
             &mut self.harness.tree.state,
             &self.harness.tree.canonical_in_memory_state,
             &mut self.harness.tree.pending_sparse_trie_prune,
+            &self.harness.tree.state_root_canceller,
         );
         let result = self.validator.validate_block(block, ctx);
         self.metrics.record_validation(result.is_ok());
@@ -957,6 +958,55 @@ fn test_backpressure_waits_for_persistence_before_reading_incoming() {
     assert_eq!(test_harness.tree.incoming.len(), 0);
 }
 
+#[test]
+fn test_forkchoice_update_exceeding_max_reorg_depth() {
+    reth_tracing::init_test_tracing();
+    let chain_spec = MAINNET.clone();
+
+    let mut test_harness = TestHarness::new(chain_spec);
+    test_harness.tree.config = test_harness.tree.config.with_max_reorg_depth(Some(2));
+    let mut test_block_builder = TestBlockBuilder::eth();
+    let blocks: Vec<_> = test_block_builder.get_executed_blocks(1..6).collect();
+
+    for block in &blocks {
+        test_harness.tree.state.tree_state.insert_executed(block.clone());
+    }
+    test_harness.tree.state.tree_state.set_canonical_head(blocks[4].recovered_block().num_hash());
+
+    // fork off block 2, which would unwind the three canonical blocks 3..=5
+    let fork_block_3 =
+        test_block_builder.get_executed_block_with_number(3, blocks[1].recovered_block().hash());
+    let fork_block_4 =
+        test_block_builder.get_executed_block_with_number(4, fork_block_3.recovered_block().hash());
+    let fork_block_5 =
+        test_block_builder.get_executed_block_with_number(5, fork_block_4.recovered_block().hash());
+    test_harness.tree.state.tree_state.insert_executed(fork_block_3);
+    test_harness.tree.state.tree_state.insert_executed(fork_block_4);
+    test_harness.tree.state.tree_state.insert_executed(fork_block_5.clone());
+
+    let state = ForkchoiceState {
+        head_block_hash: fork_block_5.recovered_block().hash(),
+        safe_block_hash: B256::ZERO,
+        finalized_block_hash: B256::ZERO,
+    };
+
+    let outcome = test_harness.tree.apply_chain_update(state, &None).unwrap().unwrap();
+    assert_eq!(outcome.outcome.forkchoice_status(), ForkchoiceStatus::Syncing);
+    assert_eq!(
+        test_harness.tree.state.tree_state.canonical_block_hash(),
+        blocks[4].recovered_block().hash()
+    );
+
+    // raising the limit allows the reorg to be applied
+    test_harness.tree.config = test_harness.tree.config.with_max_reorg_depth(Some(3));
+    let outcome = test_harness.tree.apply_chain_update(state, &None).unwrap().unwrap();
+    assert_eq!(outcome.outcome.forkchoice_status(), ForkchoiceStatus::Valid);
+    assert_eq!(
+        test_harness.tree.state.tree_state.canonical_block_hash(),
+        fork_block_5.recovered_block().hash()
+    );
+}
+
 #[tokio::test]
 async fn test_tree_state_on_new_head_reorg() {
     reth_tracing::init_test_tracing();
@@ -0,0 +1,43 @@
+//! Backtrace capturing for [`RethError::Other`](crate::RethError::Other).
+
+use alloc::boxed::Box;
+use core::fmt;
+use std::backtrace::Backtrace;
+
+/// Error wrapper that records the [`Backtrace`] of where the error was created.
+///
+/// Display and source are forwarded to the wrapped error.
+pub(crate) struct Backtraced {
+    /// The wrapped error.
+    pub(crate) error: Box<dyn core::error::Error + Send + Sync>,
+    /// The backtrace captured when the error was created.
+    pub(crate) backtrace: Backtrace,
+}
+
+impl Backtraced {
+    /// Wraps the error and captures the current backtrace.
+    ///
+    /// Whether a backtrace is actually captured is controlled by the `RUST_BACKTRACE` and
+    /// `RUST_LIB_BACKTRACE` environment variables, see [`Backtrace::capture`].
+    pub(crate) fn capture(error: Box<dyn core::error::Error + Send + Sync>) -> Self {
+        Self { error, backtrace: Backtrace::capture() }
+    }
+}
+
+impl fmt::Debug for Backtraced {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.error, f)
+    }
+}
+
+impl fmt::Display for Backtraced {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl core::error::Error for Backtraced {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.error.source()
+    }
+}
@@ -0,0 +1,82 @@
+use crate::RethError;
+use core::{error::Error, fmt};
+
+/// Iterator over the nested sources of a [`RethError`], outermost first.
+///
+/// Created by [`RethError::sources`].
+#[derive(Debug, Clone)]
+pub struct Sources<'a> {
+    next: Option<&'a (dyn Error + 'static)>,
+}
+
+impl<'a> Iterator for Sources<'a> {
+    type Item = &'a (dyn Error + 'static);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = current.source();
+        Some(current)
+    }
+}
+
+/// Formats a [`RethError`] and all of its sources on a single line, separated by `: `.
+///
+/// Created by [`RethError::display_chain`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayChain<'a>(&'a RethError);
+
+impl fmt::Display for DisplayChain<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+        for source in self.0.sources() {
+            write!(f, ": {source}")?;
+        }
+        Ok(())
+    }
+}
+
+impl RethError {
+    /// Returns an iterator over the nested sources of this error, outermost first.
+    ///
+    /// The error itself is not included.
+    pub fn sources(&self) -> Sources<'_> {
+        Sources { next: Error::source(self) }
+    }
+
+    /// Returns the innermost source of this error, or the error itself if it has no source.
+    pub fn root_cause(&self) -> &(dyn Error + 'static) {
+        self.sources().last().unwrap_or(self)
+    }
+
+    /// Returns a formatter that displays this error followed by all of its sources on a single
+    /// line, e.g. for log lines.
+    pub const fn display_chain(&self) -> DisplayChain<'_> {
+        DisplayChain(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec::Vec};
+    use reth_stages_types::StageId;
+    use reth_storage_errors::provider::ProviderError;
+
+    #[test]
+    fn error_chain() {
+        let error = RethError::unwind(StageId::Execution, 1, 5, ProviderError::BestBlockNotFound);
+        let sources = error.sources().map(|err| err.to_string()).collect::<Vec<_>>();
+        assert_eq!(sources, ["best block does not exist"]);
+        assert!(error.root_cause().is::<ProviderError>());
+        assert_eq!(
+            error.display_chain().to_string(),
+            "failed to unwind stage Execution to block 1, stage is at block 5: \
+             best block does not exist"
+        );
+
+        let error = RethError::msg("boom");
+        assert_eq!(error.sources().count(), 0);
+        assert!(error.root_cause().downcast_ref::<RethError>().is_some());
+        assert_eq!(error.display_chain().to_string(), "boom");
+    }
+}
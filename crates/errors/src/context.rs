@@ -0,0 +1,179 @@
+use crate::RethError;
+use alloc::boxed::Box;
+use alloy_primitives::{BlockHash, BlockNumber, TxHash};
+use core::fmt;
+use reth_stages_types::StageId;
+
+/// Context attached to a [`RethError`], describing where the error occurred.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// Number of the block being processed.
+    pub block_number: Option<BlockNumber>,
+    /// Hash of the block being processed.
+    pub block_hash: Option<BlockHash>,
+    /// Hash of the transaction being processed.
+    pub tx_hash: Option<TxHash>,
+    /// The stage that was running.
+    pub stage: Option<StageId>,
+}
+
+impl ErrorContext {
+    /// Creates an empty context.
+    pub const fn new() -> Self {
+        Self { block_number: None, block_hash: None, tx_hash: None, stage: None }
+    }
+
+    /// Sets the block number.
+    pub const fn with_block_number(mut self, block_number: BlockNumber) -> Self {
+        self.block_number = Some(block_number);
+        self
+    }
+
+    /// Sets the block hash.
+    pub const fn with_block_hash(mut self, block_hash: BlockHash) -> Self {
+        self.block_hash = Some(block_hash);
+        self
+    }
+
+    /// Sets the transaction hash.
+    pub const fn with_tx_hash(mut self, tx_hash: TxHash) -> Self {
+        self.tx_hash = Some(tx_hash);
+        self
+    }
+
+    /// Sets the stage.
+    pub const fn with_stage(mut self, stage: StageId) -> Self {
+        self.stage = Some(stage);
+        self
+    }
+
+    /// Returns `true` if no field is set.
+    pub const fn is_empty(&self) -> bool {
+        self.block_number.is_none() &&
+            self.block_hash.is_none() &&
+            self.tx_hash.is_none() &&
+            self.stage.is_none()
+    }
+
+    /// Fills all fields that are not set in `self` from `other`.
+    fn merge(&mut self, other: Self) {
+        self.block_number = self.block_number.or(other.block_number);
+        self.block_hash = self.block_hash.or(other.block_hash);
+        self.tx_hash = self.tx_hash.or(other.tx_hash);
+        self.stage = self.stage.or(other.stage);
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        if let Some(stage) = &self.stage {
+            write!(f, "stage {stage}")?;
+            separator = ", ";
+        }
+        match (self.block_number, self.block_hash) {
+            (Some(number), Some(hash)) => write!(f, "{separator}block #{number} ({hash})")?,
+            (Some(number), None) => write!(f, "{separator}block #{number}")?,
+            (None, Some(hash)) => write!(f, "{separator}block {hash}")?,
+            (None, None) => {}
+        }
+        if self.block_number.is_some() || self.block_hash.is_some() {
+            separator = ", ";
+        }
+        if let Some(tx_hash) = self.tx_hash {
+            write!(f, "{separator}tx {tx_hash}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A [`RethError`] with attached [`ErrorContext`].
+///
+/// The context is prepended to the error message, the source chain is the one of the wrapped
+/// error.
+#[derive(Debug)]
+pub struct ContextError {
+    /// The wrapped error.
+    pub error: RethError,
+    /// Where the error occurred.
+    pub context: ErrorContext,
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.context.is_empty() {
+            return write!(f, "{}", self.error)
+        }
+        write!(f, "{}: {}", self.context, self.error)
+    }
+}
+
+impl core::error::Error for ContextError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        core::error::Error::source(&self.error)
+    }
+}
+
+impl RethError {
+    /// Attaches the given context to this error.
+    ///
+    /// If the error already carries a context, fields that are already set are kept, since the
+    /// innermost context is the most specific one.
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        match self {
+            Self::WithContext(mut err) => {
+                err.context.merge(context);
+                Self::WithContext(err)
+            }
+            error => Self::WithContext(Box::new(ContextError { error, context })),
+        }
+    }
+
+    /// Returns the attached context, if any.
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            Self::WithContext(err) => Some(&err.context),
+            _ => None,
+        }
+    }
+
+    /// Returns the error without its attached context.
+    pub fn without_context(&self) -> &Self {
+        match self {
+            Self::WithContext(err) => &err.error,
+            error => error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::ToString};
+    use alloy_primitives::B256;
+    use reth_storage_errors::provider::ProviderError;
+
+    #[test]
+    fn context_display() {
+        let error = RethError::from(ProviderError::BestBlockNotFound)
+            .with_context(ErrorContext::new().with_block_number(10).with_tx_hash(B256::ZERO))
+            .with_context(ErrorContext::new().with_block_number(11).with_stage(StageId::Execution));
+
+        assert_eq!(
+            error.to_string(),
+            format!("stage Execution, block #10, tx {}: best block does not exist", B256::ZERO)
+        );
+        assert_eq!(error.error_code(), 4015);
+        assert!(matches!(
+            error.without_context(),
+            RethError::Provider(ProviderError::BestBlockNotFound)
+        ));
+    }
+
+    #[test]
+    fn empty_context_display() {
+        let error =
+            RethError::from(ProviderError::BestBlockNotFound).with_context(ErrorContext::new());
+        assert_eq!(error.to_string(), "best block does not exist");
+    }
+}
@@ -1,7 +1,16 @@
+use crate::{
+    hook::{record, ErrorCategory},
+    ContextError, NetworkError, PruneError, UnwindError,
+};
 use alloc::{boxed::Box, string::ToString};
+#[cfg(feature = "serde")]
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::BlockNumber;
 use core::fmt::Display;
 use reth_consensus::ConsensusError;
 use reth_execution_errors::BlockExecutionError;
+use reth_prune_types::PruneSegment;
+use reth_stages_types::StageId;
 use reth_storage_errors::{db::DatabaseError, provider::ProviderError};
 
 /// Result alias for [`RethError`].
@@ -16,23 +25,39 @@ pub type RethResult<T> = Result<T, RethError>;
 pub enum RethError {
     /// Error encountered during block execution.
     #[error(transparent)]
-    Execution(#[from] BlockExecutionError),
+    Execution(BlockExecutionError),
 
     /// Consensus-related errors.
     #[error(transparent)]
-    Consensus(#[from] ConsensusError),
+    Consensus(ConsensusError),
 
     /// Database-related errors.
     #[error(transparent)]
-    Database(#[from] DatabaseError),
+    Database(DatabaseError),
 
     /// Errors originating from providers.
     #[error(transparent)]
-    Provider(#[from] ProviderError),
+    Provider(ProviderError),
+
+    /// Unwinding a stage failed.
+    #[error(transparent)]
+    Unwind(Box<UnwindError>),
+
+    /// Pruning a segment failed.
+    #[error(transparent)]
+    Prune(Box<PruneError>),
+
+    /// Network errors, e.g. failed downloads, disconnected peers or timed out requests.
+    #[error(transparent)]
+    Network(Box<NetworkError>),
 
     /// Any other error.
     #[error(transparent)]
     Other(Box<dyn core::error::Error + Send + Sync>),
+
+    /// An error with attached context, see [`RethError::with_context`].
+    #[error(transparent)]
+    WithContext(Box<ContextError>),
 }
 
 impl RethError {
@@ -41,12 +66,296 @@ impl RethError {
     where
         E: core::error::Error + Send + Sync + 'static,
     {
-        Self::Other(Box::new(error))
+        Self::new_other(Box::new(error))
     }
 
     /// Create a new `RethError` from a given message.
     pub fn msg(msg: impl Display) -> Self {
-        Self::Other(msg.to_string().into())
+        Self::new_other(msg.to_string().into())
+    }
+
+    /// Creates a [`RethError::Other`], capturing a backtrace if the `backtrace` feature is
+    /// enabled.
+    fn new_other(error: Box<dyn core::error::Error + Send + Sync>) -> Self {
+        record(ErrorCategory::Other);
+        #[cfg(feature = "backtrace")]
+        let error = Box::new(crate::backtrace::Backtraced::capture(error));
+        Self::Other(error)
+    }
+
+    /// Returns the backtrace captured when this error was created via [`RethError::other`] or
+    /// [`RethError::msg`].
+    ///
+    /// Returns `None` for other variants. Whether the backtrace contains frames depends on the
+    /// `RUST_BACKTRACE` and `RUST_LIB_BACKTRACE` environment variables.
+    #[cfg(feature = "backtrace")]
+    pub fn backtrace(&self) -> Option<&std::backtrace::Backtrace> {
+        match self {
+            Self::Other(err) => err
+                .downcast_ref::<crate::backtrace::Backtraced>()
+                .map(|backtraced| &backtraced.backtrace),
+            Self::WithContext(err) => err.error.backtrace(),
+            _ => None,
+        }
+    }
+
+    /// Returns the error wrapped by [`RethError::Other`], if this is one.
+    ///
+    /// Attached context and captured backtraces are skipped.
+    pub fn as_other(&self) -> Option<&(dyn core::error::Error + Send + Sync + 'static)> {
+        match self {
+            Self::Other(err) => {
+                #[cfg(feature = "backtrace")]
+                if let Some(backtraced) = err.downcast_ref::<crate::backtrace::Backtraced>() {
+                    return Some(backtraced.error.as_ref())
+                }
+                Some(err.as_ref())
+            }
+            Self::WithContext(err) => err.error.as_other(),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the error wrapped by [`RethError::Other`] if it is of type `E`.
+    pub fn downcast_ref<E: core::error::Error + 'static>(&self) -> Option<&E> {
+        self.as_other()?.downcast_ref()
+    }
+
+    /// Returns `true` if this is a [`RethError::Other`] wrapping an error of type `E`.
+    pub fn is<E: core::error::Error + 'static>(&self) -> bool {
+        self.as_other().is_some_and(|err| err.is::<E>())
+    }
+
+    /// Attempts to downcast the error wrapped by [`RethError::Other`] into `E`.
+    ///
+    /// Returns the unchanged error if it is not a [`RethError::Other`] of type `E`.
+    pub fn downcast<E: core::error::Error + 'static>(self) -> Result<E, Self> {
+        match self {
+            Self::Other(err) => {
+                #[cfg(feature = "backtrace")]
+                let err = match err.downcast::<crate::backtrace::Backtraced>() {
+                    Ok(backtraced) => {
+                        let crate::backtrace::Backtraced { error, backtrace } = *backtraced;
+                        return error.downcast::<E>().map(|err| *err).map_err(|error| {
+                            Self::Other(Box::new(crate::backtrace::Backtraced { error, backtrace }))
+                        })
+                    }
+                    Err(err) => err,
+                };
+                err.downcast::<E>().map(|err| *err).map_err(Self::Other)
+            }
+            Self::WithContext(err) => {
+                let ContextError { error, context } = *err;
+                error.downcast().map_err(|error| error.with_context(context))
+            }
+            err => Err(err),
+        }
+    }
+
+    /// Create a new [`RethError::Unwind`] for a stage that failed to unwind to `target` after
+    /// reaching `progress`.
+    pub fn unwind<E>(stage: StageId, target: BlockNumber, progress: BlockNumber, error: E) -> Self
+    where
+        E: Into<Box<dyn core::error::Error + Send + Sync>>,
+    {
+        record(ErrorCategory::Unwind);
+        Self::Unwind(Box::new(UnwindError { stage, target, progress, source: error.into() }))
+    }
+
+    /// Create a new [`RethError::Prune`] for a segment that failed to prune up to `target` after
+    /// pruning up to `progress`.
+    pub fn prune<E>(
+        segment: PruneSegment,
+        target: BlockNumber,
+        progress: Option<BlockNumber>,
+        error: E,
+    ) -> Self
+    where
+        E: Into<Box<dyn core::error::Error + Send + Sync>>,
+    {
+        record(ErrorCategory::Prune);
+        Self::Prune(Box::new(PruneError { segment, target, progress, source: error.into() }))
+    }
+
+    /// Returns the category of this error.
+    ///
+    /// For errors with attached context, this is the category of the wrapped error.
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Self::Execution(_) => ErrorCategory::Execution,
+            Self::Consensus(_) => ErrorCategory::Consensus,
+            Self::Database(_) => ErrorCategory::Database,
+            Self::Provider(_) => ErrorCategory::Provider,
+            Self::Unwind(_) => ErrorCategory::Unwind,
+            Self::Prune(_) => ErrorCategory::Prune,
+            Self::Network(_) => ErrorCategory::Network,
+            Self::Other(_) => ErrorCategory::Other,
+            Self::WithContext(err) => err.error.category(),
+        }
+    }
+
+    /// Returns the name of the variant of this error.
+    ///
+    /// For errors with attached context, this is the name of the wrapped error's variant.
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            Self::Execution(_) => "Execution",
+            Self::Consensus(_) => "Consensus",
+            Self::Database(_) => "Database",
+            Self::Provider(_) => "Provider",
+            Self::Unwind(_) => "Unwind",
+            Self::Prune(_) => "Prune",
+            Self::Network(_) => "Network",
+            Self::Other(_) => "Other",
+            Self::WithContext(err) => err.error.variant_name(),
+        }
+    }
+
+    /// Returns `true` if the failed operation may succeed when retried.
+    ///
+    /// See [`DatabaseError::is_retryable`], [`ProviderError::is_retryable`] and
+    /// [`NetworkError::is_retryable`].
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Database(err) => err.is_retryable(),
+            Self::Provider(err) => err.is_retryable(),
+            Self::Network(err) => err.is_retryable(),
+            Self::WithContext(err) => err.error.is_retryable(),
+            Self::Execution(_) |
+            Self::Consensus(_) |
+            Self::Unwind(_) |
+            Self::Prune(_) |
+            Self::Other(_) => false,
+        }
+    }
+
+    /// Returns `true` if the error can't be recovered from without operator intervention and
+    /// processing should be aborted.
+    ///
+    /// See [`DatabaseError::is_fatal`] and [`ProviderError::is_fatal`].
+    pub fn is_fatal(&self) -> bool {
+        match self {
+            Self::Database(err) => err.is_fatal(),
+            Self::Provider(err) => err.is_fatal(),
+            Self::WithContext(err) => err.error.is_fatal(),
+            Self::Execution(_) |
+            Self::Consensus(_) |
+            Self::Unwind(_) |
+            Self::Prune(_) |
+            Self::Network(_) |
+            Self::Other(_) => false,
+        }
+    }
+
+    /// Returns a stable numeric code classifying this error.
+    ///
+    /// The code space is split by category, the nested error's own code is added to the base of
+    /// its category:
+    ///
+    /// | Range         | Category                                                     |
+    /// |---------------|--------------------------------------------------------------|
+    /// | `1000..=1999` | [`BlockExecutionError`], `1100` validation, `1200` internal  |
+    /// | `2000..=2999` | [`ConsensusError`], see [`ConsensusError::error_code`]       |
+    /// | `3000..=3999` | [`DatabaseError`], see [`DatabaseError::error_code`]         |
+    /// | `4000..=4999` | [`ProviderError`], see [`ProviderError::error_code`]         |
+    /// | `5000`        | [`RethError::Unwind`]                                        |
+    /// | `6000`        | [`RethError::Prune`]                                         |
+    /// | `7000..=7999` | [`NetworkError`], see [`NetworkErrorKind::error_code`]       |
+    /// | `9000`        | [`RethError::Other`]                                         |
+    ///
+    /// Errors with attached context have the code of the wrapped error.
+    ///
+    /// Codes are never reassigned, so they can be used by dashboards and tooling to classify
+    /// failures without matching on the [`Display`] output.
+    ///
+    /// [`NetworkErrorKind::error_code`]: crate::NetworkErrorKind::error_code
+    pub fn error_code(&self) -> u32 {
+        match self {
+            Self::Execution(err) => match err {
+                BlockExecutionError::Validation(_) => 1100,
+                BlockExecutionError::Internal(_) => 1200,
+            },
+            Self::Consensus(err) => 2000 + err.error_code(),
+            Self::Database(err) => 3000 + err.error_code(),
+            Self::Provider(err) => 4000 + err.error_code(),
+            Self::Unwind(_) => 5000,
+            Self::Prune(_) => 6000,
+            Self::Network(err) => 7000 + err.kind.error_code(),
+            Self::Other(_) => 9000,
+            Self::WithContext(err) => err.error.error_code(),
+        }
+    }
+}
+
+impl From<BlockExecutionError> for RethError {
+    fn from(error: BlockExecutionError) -> Self {
+        record(ErrorCategory::Execution);
+        Self::Execution(error)
+    }
+}
+
+impl From<ConsensusError> for RethError {
+    fn from(error: ConsensusError) -> Self {
+        record(ErrorCategory::Consensus);
+        Self::Consensus(error)
+    }
+}
+
+impl From<DatabaseError> for RethError {
+    fn from(error: DatabaseError) -> Self {
+        record(ErrorCategory::Database);
+        Self::Database(error)
+    }
+}
+
+impl From<NetworkError> for RethError {
+    fn from(error: NetworkError) -> Self {
+        record(ErrorCategory::Network);
+        Self::Network(Box::new(error))
+    }
+}
+
+impl From<ProviderError> for RethError {
+    fn from(error: ProviderError) -> Self {
+        record(ErrorCategory::Provider);
+        Self::Provider(error)
+    }
+}
+
+/// Structured representation of a [`RethError`], used to serialize errors, e.g. for RPC debug
+/// endpoints or structured logs.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SerializedError {
+    /// The name of the [`RethError`] variant.
+    pub variant: String,
+    /// The stable error code, see [`RethError::error_code`].
+    pub code: u32,
+    /// The error message.
+    pub message: String,
+    /// Messages of the nested source errors, outermost first.
+    pub sources: Vec<String>,
+}
+
+#[cfg(feature = "serde")]
+impl From<&RethError> for SerializedError {
+    fn from(error: &RethError) -> Self {
+        Self {
+            variant: error.variant_name().to_string(),
+            code: error.error_code(),
+            message: error.to_string(),
+            sources: error.sources().map(|err| err.to_string()).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RethError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializedError::from(self).serialize(serializer)
     }
 }
 
@@ -67,3 +376,131 @@ mod size_asserts {
     static_assert_size!(DatabaseError, 32);
     static_assert_size!(ProviderError, 56);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NetworkErrorKind;
+    use reth_storage_errors::db::DatabaseErrorInfo;
+
+    #[test]
+    fn error_codes() {
+        assert_eq!(RethError::msg("custom").error_code(), 9000);
+        assert_eq!(RethError::from(DatabaseError::Decode).error_code(), 3009);
+        assert_eq!(RethError::from(ProviderError::BestBlockNotFound).error_code(), 4015);
+        assert_eq!(RethError::from(ConsensusError::BaseFeeMissing).error_code(), 2016);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_error() {
+        let error = RethError::from(ProviderError::Database(DatabaseError::Decode));
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "variant": "Provider",
+                "code": 4001,
+                "message": "failed to decode a key from a table",
+                "sources": [],
+            })
+        );
+
+        let serialized: SerializedError = serde_json::from_value(json).unwrap();
+        assert_eq!(serialized, SerializedError::from(&error));
+    }
+
+    #[test]
+    fn error_hook() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static DATABASE_ERRORS: AtomicUsize = AtomicUsize::new(0);
+
+        let installed = crate::set_error_hook(Box::new(|category| {
+            if category == ErrorCategory::Database {
+                DATABASE_ERRORS.fetch_add(1, Ordering::Relaxed);
+            }
+        }));
+        assert!(installed.is_ok());
+
+        let error = RethError::from(DatabaseError::Decode);
+        assert_eq!(error.category(), ErrorCategory::Database);
+        assert!(DATABASE_ERRORS.load(Ordering::Relaxed) >= 1);
+    }
+
+    #[test]
+    fn unwind_and_prune() {
+        let unwind = RethError::unwind(StageId::Execution, 10, 15, DatabaseError::Decode);
+        assert_eq!(
+            unwind.to_string(),
+            "failed to unwind stage Execution to block 10, stage is at block 15"
+        );
+        assert_eq!(unwind.error_code(), 5000);
+        assert_eq!(unwind.category(), ErrorCategory::Unwind);
+
+        let prune = RethError::prune(PruneSegment::Receipts, 10, None, "boom");
+        assert_eq!(
+            prune.to_string(),
+            "failed to prune segment Receipts up to block 10, pruned up to block none"
+        );
+        assert_eq!(core::error::Error::source(&prune).unwrap().to_string(), "boom");
+    }
+
+    #[cfg(feature = "backtrace")]
+    #[test]
+    fn captures_backtrace() {
+        let error = RethError::msg("boom");
+        assert!(error.backtrace().is_some());
+        assert_eq!(error.to_string(), "boom");
+        assert!(RethError::from(DatabaseError::Decode).backtrace().is_none());
+    }
+
+    #[test]
+    fn downcast_other() {
+        #[derive(Debug, thiserror::Error, PartialEq, Eq)]
+        #[error("custom")]
+        struct CustomError;
+
+        let error = RethError::other(CustomError);
+        assert!(error.is::<CustomError>());
+        assert!(!error.is::<DatabaseError>());
+        assert_eq!(error.downcast_ref::<CustomError>(), Some(&CustomError));
+
+        let error = error.with_context(crate::ErrorContext::new().with_block_number(1));
+        assert!(error.is::<CustomError>());
+        let error = error.downcast::<DatabaseError>().unwrap_err();
+        assert_eq!(error.context().and_then(|context| context.block_number), Some(1));
+        assert_eq!(error.downcast::<CustomError>().unwrap(), CustomError);
+
+        let error = RethError::from(ProviderError::BestBlockNotFound);
+        assert!(!error.is::<CustomError>());
+        assert!(error.downcast::<CustomError>().is_err());
+    }
+
+    #[test]
+    fn retryable_and_fatal() {
+        let info = |code| DatabaseErrorInfo { message: "".into(), code };
+
+        let busy = RethError::from(DatabaseError::Read(info(-30778)));
+        assert!(busy.is_retryable());
+        assert!(!busy.is_fatal());
+
+        let map_full =
+            RethError::from(ProviderError::Database(DatabaseError::Commit(info(-30792))));
+        assert!(!map_full.is_retryable());
+        assert!(map_full.is_fatal());
+
+        let not_found = RethError::from(ProviderError::BestBlockNotFound);
+        assert!(!not_found.is_retryable());
+        assert!(!not_found.is_fatal());
+
+        let timeout = RethError::from(NetworkError::new(NetworkErrorKind::Timeout, "timed out"));
+        assert!(timeout.is_retryable());
+        assert!(!timeout.is_fatal());
+        assert_eq!(timeout.error_code(), 7001);
+        assert_eq!(timeout.category(), ErrorCategory::Network);
+        // the source is reported by the error chain, not the message
+        assert_eq!(timeout.to_string(), "network error, request timed out");
+        assert_eq!(core::error::Error::source(&timeout).unwrap().to_string(), "timed out");
+    }
+}
@@ -0,0 +1,74 @@
+//! Hook that observes every constructed [`RethError`](crate::RethError).
+
+use alloc::boxed::Box;
+use once_cell::race::OnceBox;
+
+/// Category of a [`RethError`](crate::RethError), reported to the error hook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// Block execution errors.
+    Execution,
+    /// Consensus errors.
+    Consensus,
+    /// Database errors.
+    Database,
+    /// Provider errors.
+    Provider,
+    /// Stage unwind errors.
+    Unwind,
+    /// Prune errors.
+    Prune,
+    /// Network errors.
+    Network,
+    /// Any other error.
+    Other,
+}
+
+impl ErrorCategory {
+    /// Returns the category as a string, used as the metric label.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Execution => "execution",
+            Self::Consensus => "consensus",
+            Self::Database => "database",
+            Self::Provider => "provider",
+            Self::Unwind => "unwind",
+            Self::Prune => "prune",
+            Self::Network => "network",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl core::fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Callback invoked with the category of every [`RethError`](crate::RethError) constructed via
+/// `From` or [`RethError::other`](crate::RethError::other).
+pub type ErrorHook = Box<dyn Fn(ErrorCategory) + Send + Sync>;
+
+static ERROR_HOOK: OnceBox<ErrorHook> = OnceBox::new();
+
+/// Installs the global error hook.
+///
+/// The hook can only be set once, returns the given hook back if one is already installed.
+pub fn set_error_hook(hook: ErrorHook) -> Result<(), ErrorHook> {
+    ERROR_HOOK.set(Box::new(hook)).map_err(|hook| *hook)
+}
+
+/// Reports a newly constructed error of the given category.
+///
+/// With the `metrics` feature enabled, this increments the `errors_total` counter labeled with
+/// the category.
+#[inline]
+pub(crate) fn record(category: ErrorCategory) {
+    #[cfg(feature = "metrics")]
+    metrics::counter!("errors_total", "category" => category.as_str()).increment(1);
+
+    if let Some(hook) = ERROR_HOOK.get() {
+        hook(category);
+    }
+}
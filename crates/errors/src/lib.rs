@@ -10,10 +10,32 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "backtrace")]
+extern crate std;
+
+#[cfg(feature = "backtrace")]
+mod backtrace;
+
+mod chain;
+pub use chain::{DisplayChain, Sources};
+
+mod context;
+pub use context::{ContextError, ErrorContext};
+
+mod network;
+pub use network::{NetworkError, NetworkErrorKind};
+
+mod pipeline;
+pub use pipeline::{PruneError, UnwindError};
 
 mod error;
+#[cfg(feature = "serde")]
+pub use error::SerializedError;
 pub use error::{RethError, RethResult};
 
+pub mod hook;
+pub use hook::{set_error_hook, ErrorCategory, ErrorHook};
+
 pub use reth_consensus::ConsensusError;
 pub use reth_execution_errors::{BlockExecutionError, BlockValidationError};
 pub use reth_storage_errors::{
@@ -0,0 +1,70 @@
+use alloc::boxed::Box;
+
+/// Kind of a [`NetworkError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum NetworkErrorKind {
+    /// The request timed out while awaiting the response.
+    #[error("request timed out")]
+    Timeout,
+    /// The connection to the peer dropped while handling the request.
+    #[error("peer disconnected")]
+    PeerDisconnected,
+    /// The channel to the peer or the network is closed, e.g. on shutdown.
+    #[error("channel closed")]
+    ChannelClosed,
+    /// The request is not supported by the remote peer.
+    #[error("unsupported capability")]
+    UnsupportedCapability,
+    /// The peer sent an invalid response.
+    #[error("bad response")]
+    BadResponse,
+    /// The downloader failed for a reason unrelated to a single request.
+    #[error("download failed")]
+    Download,
+}
+
+impl NetworkErrorKind {
+    /// Returns `true` if the request may succeed when retried.
+    pub const fn is_retryable(&self) -> bool {
+        matches!(self, Self::Timeout | Self::PeerDisconnected)
+    }
+
+    /// Returns a stable numeric code for this kind, see
+    /// [`RethError::error_code`](crate::RethError::error_code).
+    pub const fn error_code(&self) -> u32 {
+        match self {
+            Self::Timeout => 1,
+            Self::PeerDisconnected => 2,
+            Self::ChannelClosed => 3,
+            Self::UnsupportedCapability => 4,
+            Self::BadResponse => 5,
+            Self::Download => 6,
+        }
+    }
+}
+
+/// Error returned when syncing data from the network failed, e.g. a failed download, a
+/// disconnected peer or a timed out request.
+#[derive(Debug, thiserror::Error)]
+#[error("network error, {kind}")]
+pub struct NetworkError {
+    /// The kind of the failure.
+    pub kind: NetworkErrorKind,
+    /// The cause of the failure.
+    pub source: Box<dyn core::error::Error + Send + Sync>,
+}
+
+impl NetworkError {
+    /// Creates a new error of the given kind.
+    pub fn new<E>(kind: NetworkErrorKind, error: E) -> Self
+    where
+        E: Into<Box<dyn core::error::Error + Send + Sync>>,
+    {
+        Self { kind, source: error.into() }
+    }
+
+    /// Returns `true` if the request may succeed when retried.
+    pub const fn is_retryable(&self) -> bool {
+        self.kind.is_retryable()
+    }
+}
@@ -0,0 +1,58 @@
+use alloc::boxed::Box;
+use alloy_primitives::BlockNumber;
+use reth_prune_types::PruneSegment;
+use reth_stages_types::StageId;
+
+/// Boxed error that caused an unwind or prune to fail.
+type BoxedError = Box<dyn core::error::Error + Send + Sync>;
+
+/// Error returned when unwinding a stage failed.
+///
+/// Checkpoints are saved after every unwind step, so the unwind can be resumed from `progress`
+/// after the cause has been resolved.
+#[derive(Debug, thiserror::Error)]
+#[error("failed to unwind stage {stage} to block {target}, stage is at block {progress}")]
+pub struct UnwindError {
+    /// The stage that failed to unwind.
+    pub stage: StageId,
+    /// The block the stage was supposed to be unwound to.
+    pub target: BlockNumber,
+    /// The block the stage was unwound to before the failure.
+    pub progress: BlockNumber,
+    /// The cause of the failure.
+    #[source]
+    pub source: BoxedError,
+}
+
+/// Error returned when pruning a segment failed.
+///
+/// Prune checkpoints are saved after every pruned batch, so the next prune run resumes from
+/// `progress`.
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "failed to prune segment {segment} up to block {target}, pruned up to block {}",
+    DisplayProgress(*progress)
+)]
+pub struct PruneError {
+    /// The segment that failed to prune.
+    pub segment: PruneSegment,
+    /// The block the segment was supposed to be pruned up to.
+    pub target: BlockNumber,
+    /// The highest pruned block before the failure, if anything was pruned.
+    pub progress: Option<BlockNumber>,
+    /// The cause of the failure.
+    #[source]
+    pub source: BoxedError,
+}
+
+/// Formats an optional block number, `none` if not set.
+struct DisplayProgress(Option<BlockNumber>);
+
+impl core::fmt::Display for DisplayProgress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Some(block) => write!(f, "{block}"),
+            None => f.write_str("none"),
+        }
+    }
+}
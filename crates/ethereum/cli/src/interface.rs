@@ -327,7 +327,7 @@ pub enum Commands<
     TestVectors(reth_cli_commands::test_vectors::Command),
     /// Write config to stdout
     #[command(name = "config")]
-    Config(config_cmd::Command),
+    Config(config_cmd::Command<C>),
     /// Prune according to the configuration without any limits
     #[command(name = "prune")]
     Prune(prune::PruneCommand<C>),
@@ -20,7 +20,9 @@ use jsonrpsee::server::{HttpBody, HttpRequest, HttpResponse};
 use reth_chainspec::EthereumHardforks;
 use reth_engine_primitives::EngineApiValidator;
 use reth_ethereum_engine_primitives::EthEngineTypes;
-use reth_provider::{BalProvider, BlockReader, HeaderProvider, StateProviderFactory};
+use reth_provider::{
+    BalProvider, BlobSidecarProvider, BlockReader, HeaderProvider, StateProviderFactory,
+};
 use reth_rpc::EngineApi;
 use reth_transaction_pool::TransactionPool;
 use ssz::Decode;
@@ -159,7 +161,12 @@ impl<S, ChainSpec, Provider, Pool, Validator> Service<HttpRequest>
 where
     S: Service<HttpRequest, Response = HttpResponse, Error = BoxError> + Send + Clone,
     S::Future: Send + 'static,
-    Provider: HeaderProvider + BlockReader + StateProviderFactory + BalProvider + 'static,
+    Provider: HeaderProvider
+        + BlockReader
+        + StateProviderFactory
+        + BalProvider
+        + BlobSidecarProvider
+        + 'static,
     Pool: TransactionPool + 'static,
     Validator: EngineApiValidator<EthEngineTypes>,
     ChainSpec: EthereumHardforks + Send + Sync + 'static,
@@ -188,7 +195,12 @@ async fn handle_engine_ssz_request<ChainSpec, Provider, Pool, Validator>(
     request: HttpRequest,
 ) -> HttpResponse
 where
-    Provider: HeaderProvider + BlockReader + StateProviderFactory + BalProvider + 'static,
+    Provider: HeaderProvider
+        + BlockReader
+        + StateProviderFactory
+        + BalProvider
+        + BlobSidecarProvider
+        + 'static,
     Pool: TransactionPool + 'static,
     Validator: EngineApiValidator<EthEngineTypes>,
     ChainSpec: EthereumHardforks + Send + Sync + 'static,
@@ -366,7 +378,12 @@ fn handle_identity<Provider, Pool, Validator, ChainSpec>(
     engine_api: EthEngineApi<Provider, Pool, Validator, ChainSpec>,
 ) -> HttpResponse
 where
-    Provider: HeaderProvider + BlockReader + StateProviderFactory + BalProvider + 'static,
+    Provider: HeaderProvider
+        + BlockReader
+        + StateProviderFactory
+        + BalProvider
+        + BlobSidecarProvider
+        + 'static,
     Pool: TransactionPool + 'static,
     Validator: EngineApiValidator<EthEngineTypes>,
     ChainSpec: EthereumHardforks + Send + Sync + 'static,
@@ -380,7 +397,12 @@ async fn handle_new_payload<Provider, Pool, Validator, ChainSpec>(
     body: &[u8],
 ) -> HttpResponse
 where
-    Provider: HeaderProvider + BlockReader + StateProviderFactory + BalProvider + 'static,
+    Provider: HeaderProvider
+        + BlockReader
+        + StateProviderFactory
+        + BalProvider
+        + BlobSidecarProvider
+        + 'static,
     Pool: TransactionPool + 'static,
     Validator: EngineApiValidator<EthEngineTypes>,
     ChainSpec: EthereumHardforks + Send + Sync + 'static,
@@ -411,7 +433,12 @@ async fn handle_forkchoice_updated<Provider, Pool, Validator, ChainSpec>(
     body: &[u8],
 ) -> HttpResponse
 where
-    Provider: HeaderProvider + BlockReader + StateProviderFactory + BalProvider + 'static,
+    Provider: HeaderProvider
+        + BlockReader
+        + StateProviderFactory
+        + BalProvider
+        + BlobSidecarProvider
+        + 'static,
     Pool: TransactionPool + 'static,
     Validator: EngineApiValidator<EthEngineTypes>,
     ChainSpec: EthereumHardforks + Send + Sync + 'static,
@@ -442,7 +469,12 @@ async fn handle_get_blobs<ChainSpec, Provider, Pool, Validator>(
     body: &[u8],
 ) -> HttpResponse
 where
-    Provider: HeaderProvider + BlockReader + StateProviderFactory + BalProvider + 'static,
+    Provider: HeaderProvider
+        + BlockReader
+        + StateProviderFactory
+        + BalProvider
+        + BlobSidecarProvider
+        + 'static,
     Pool: TransactionPool + 'static,
     Validator: EngineApiValidator<EthEngineTypes>,
     ChainSpec: EthereumHardforks + Send + Sync + 'static,
@@ -1,7 +1,7 @@
 use crate::utils::{eth_payload_attributes, eth_payload_attributes_amsterdam};
-use alloy_eips::{eip2718::Encodable2718, eip7910::EthConfig};
+use alloy_eips::{eip2718::Encodable2718, eip7910::EthConfig, BlockId};
 use alloy_genesis::Genesis;
-use alloy_primitives::{Address, Bytes, B256, U256};
+use alloy_primitives::{keccak256, Address, Bytes, B256, U256};
 use alloy_provider::{network::EthereumWallet, Provider, ProviderBuilder, SendableTx};
 use alloy_rpc_types_beacon::relay::{
     BidTrace, BuilderBlockValidationRequestV3, BuilderBlockValidationRequestV4,
@@ -13,9 +13,11 @@ use alloy_rpc_types_engine::{
     ExecutionPayloadV3, PraguePayloadFields,
 };
 use alloy_rpc_types_eth::TransactionRequest;
+use alloy_rpc_types_trace::geth::GethDebugTracerConfig;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use reth_chainspec::{ChainSpecBuilder, EthChainSpec, MAINNET};
-use reth_e2e_test_utils::setup_engine;
+use reth_e2e_test_utils::{setup_engine, transaction::TransactionTestContext};
+use reth_evm::{EvmEnvFor, HaltReasonFor, TxEnvFor};
 use reth_network::{types::NatResolver, PeersInfo};
 use reth_node_builder::{NodeBuilder, NodeHandle};
 use reth_node_core::{
@@ -25,8 +27,14 @@ use reth_node_core::{
 use reth_node_ethereum::EthereumNode;
 use reth_payload_primitives::BuiltPayload;
 use reth_primitives_traits::Block as _;
+use reth_rpc::NativeTracer;
 use reth_rpc_api::servers::AdminApiServer;
+use reth_rpc_eth_api::helpers::{Trace, TraceExt};
+use reth_rpc_eth_types::StateCacheDb;
+use reth_rpc_server_types::RpcModuleSelection;
 use reth_tasks::Runtime;
+use revm::context_interface::result::ResultAndState;
+use revm_inspectors::tracing::TransactionContext;
 use std::{
     net::{IpAddr, Ipv4Addr},
     sync::Arc,
@@ -606,3 +614,328 @@ async fn test_admin_node_info_discv5_enr_uses_nat_extip_when_discv4_is_disabled(
 
     Ok(())
 }
+
+/// Native tracer returning the gas used by the transaction.
+struct GasUsedTracer;
+
+impl<Eth> NativeTracer<Eth> for GasUsedTracer {
+    fn trace(
+        &self,
+        eth_api: &Eth,
+        db: &mut StateCacheDb,
+        evm_env: EvmEnvFor<Eth::Evm>,
+        tx_env: TxEnvFor<Eth::Evm>,
+        _config: &GethDebugTracerConfig,
+        _tx_ctx: Option<TransactionContext>,
+    ) -> Result<(serde_json::Value, ResultAndState<HaltReasonFor<Eth::Evm>>), Eth::Error>
+    where
+        Eth: TraceExt,
+    {
+        let res = eth_api.inspect(db, evm_env, tx_env, ())?;
+        Ok((serde_json::json!({ "gasUsed": res.result.gas_used() }), res))
+    }
+}
+
+#[tokio::test]
+async fn test_debug_trace_call_native_tracer() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let runtime = Runtime::test();
+
+    let genesis: Genesis = serde_json::from_str(include_str!("../assets/genesis.json")).unwrap();
+    let chain_spec =
+        Arc::new(ChainSpecBuilder::default().chain(MAINNET.chain).genesis(genesis).build());
+
+    let node_config = NodeConfig::test().with_chain(chain_spec).with_unused_ports().with_rpc(
+        RpcServerArgs::default()
+            .with_unused_ports()
+            .with_http()
+            .with_http_api(RpcModuleSelection::all_modules().into()),
+    );
+
+    let NodeHandle { node, node_exit_future: _ } = NodeBuilder::new(node_config)
+        .testing_node(runtime)
+        .node(EthereumNode::default())
+        .with_native_tracer("gasUsedTracer", GasUsedTracer)
+        .launch()
+        .await?;
+
+    let provider = node.rpc_server_handle().eth_http_provider().unwrap();
+    let request = TransactionRequest::default().to(Address::ZERO);
+
+    let trace: serde_json::Value = provider
+        .raw_request(
+            "debug_traceCall".into(),
+            (&request, BlockId::latest(), serde_json::json!({ "tracer": "gasUsedTracer" })),
+        )
+        .await?;
+    assert_eq!(trace, serde_json::json!({ "gasUsed": 21_000 }));
+
+    // built-in tracers can't be replaced
+    let trace: serde_json::Value = provider
+        .raw_request(
+            "debug_traceCall".into(),
+            (&request, BlockId::latest(), serde_json::json!({ "tracer": "callTracer" })),
+        )
+        .await?;
+    assert_eq!(trace["type"], "CALL");
+
+    Ok(())
+}
+
+/// Runtime code incrementing the counter in slot 0 and returning its new value.
+const COUNTER_CODE: &str = "0x6000546001018060005560005260206000f3";
+
+/// Runtime code returning the number and the timestamp of the block.
+const BLOCK_ENV_CODE: &str = "0x436000524260205260406000f3";
+
+#[tokio::test]
+async fn test_debug_trace_call_overrides() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let runtime = Runtime::test();
+
+    let genesis: Genesis = serde_json::from_str(include_str!("../assets/genesis.json")).unwrap();
+    let chain_spec =
+        Arc::new(ChainSpecBuilder::default().chain(MAINNET.chain).genesis(genesis).build());
+
+    let node_config = NodeConfig::test().with_chain(chain_spec).with_unused_ports().with_rpc(
+        RpcServerArgs::default()
+            .with_unused_ports()
+            .with_http()
+            .with_http_api(RpcModuleSelection::all_modules().into()),
+    );
+
+    let NodeHandle { node, node_exit_future: _ } = NodeBuilder::new(node_config)
+        .testing_node(runtime)
+        .node(EthereumNode::default())
+        .launch()
+        .await?;
+
+    let provider = node.rpc_server_handle().eth_http_provider().unwrap();
+    let contract = Address::with_last_byte(0xb1);
+    let request = TransactionRequest::default().to(contract);
+
+    // the call runs the overridden code in the overridden block context
+    let trace: serde_json::Value = provider
+        .raw_request(
+            "debug_traceCall".into(),
+            (
+                &request,
+                BlockId::latest(),
+                serde_json::json!({
+                    "tracer": "callTracer",
+                    "stateOverrides": { contract.to_string(): { "code": BLOCK_ENV_CODE } },
+                    "blockOverrides": { "number": "0x1234", "time": "0x5678" },
+                }),
+            ),
+        )
+        .await?;
+    let mut output = U256::from(0x1234).to_be_bytes::<32>().to_vec();
+    output.extend(U256::from(0x5678).to_be_bytes::<32>());
+    assert_eq!(trace["output"], serde_json::json!(Bytes::from(output)));
+
+    // the block access list records the storage write of the overridden code
+    let counter = Address::with_last_byte(0xc0);
+    let bal: serde_json::Value = provider
+        .raw_request(
+            "debug_traceCall".into(),
+            (
+                TransactionRequest::default().to(counter),
+                BlockId::latest(),
+                serde_json::json!({
+                    "tracer": "blockAccessList",
+                    "stateOverrides": { counter.to_string(): { "code": COUNTER_CODE } },
+                }),
+            ),
+        )
+        .await?;
+    let accounts = bal.as_array().expect("block access list is an array");
+    let counter_changes = accounts
+        .iter()
+        .find(|account| {
+            serde_json::from_value::<Address>(account["address"].clone()).ok() == Some(counter)
+        })
+        .expect("counter is in the block access list");
+    assert_eq!(counter_changes["storageChanges"].as_array().map(Vec::len), Some(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_many_shares_state_between_calls() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let runtime = Runtime::test();
+
+    let genesis: Genesis = serde_json::from_str(include_str!("../assets/genesis.json")).unwrap();
+    let chain_spec =
+        Arc::new(ChainSpecBuilder::default().chain(MAINNET.chain).genesis(genesis).build());
+
+    let node_config = NodeConfig::test().with_chain(chain_spec).with_unused_ports().with_rpc(
+        RpcServerArgs::default()
+            .with_unused_ports()
+            .with_http()
+            .with_http_api(RpcModuleSelection::all_modules().into()),
+    );
+
+    let NodeHandle { node, node_exit_future: _ } = NodeBuilder::new(node_config)
+        .testing_node(runtime)
+        .node(EthereumNode::default())
+        .launch()
+        .await?;
+
+    let provider = node.rpc_server_handle().eth_http_provider().unwrap();
+    let counter = Address::with_last_byte(0xc0);
+    let call = TransactionRequest::default().to(counter);
+    let bundles = serde_json::json!([
+        { "transactions": [&call, &call] },
+        { "transactions": [&call] },
+    ]);
+    let state_context = serde_json::json!({ "blockNumber": "latest" });
+    let state_override = serde_json::json!({ counter.to_string(): { "code": COUNTER_CODE } });
+    let counter_value = |value: u64| Bytes::from(U256::from(value).to_be_bytes::<32>());
+
+    // each call sees the changes of the calls before it, also across bundles
+    let results: serde_json::Value = provider
+        .raw_request("eth_callMany".into(), (&bundles, &state_context, &state_override))
+        .await?;
+    assert_eq!(results[0][0]["value"], serde_json::json!(counter_value(1)));
+    assert_eq!(results[0][1]["value"], serde_json::json!(counter_value(2)));
+    assert_eq!(results[1][0]["value"], serde_json::json!(counter_value(3)));
+
+    let traces: serde_json::Value = provider
+        .raw_request(
+            "debug_traceCallMany".into(),
+            (
+                &bundles,
+                &state_context,
+                serde_json::json!({ "tracer": "callTracer", "stateOverrides": state_override }),
+            ),
+        )
+        .await?;
+    assert_eq!(traces[0][1]["output"], serde_json::json!(counter_value(2)));
+    assert_eq!(traces[1][0]["output"], serde_json::json!(counter_value(3)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_bundle_simulation() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let chain_spec = Arc::new(
+        ChainSpecBuilder::default()
+            .chain(MAINNET.chain)
+            .genesis(serde_json::from_str(include_str!("../assets/genesis.json")).unwrap())
+            .cancun_activated()
+            .build(),
+    );
+
+    let (mut nodes, wallet) = setup_engine::<EthereumNode>(
+        1,
+        chain_spec,
+        false,
+        Default::default(),
+        eth_payload_attributes,
+    )
+    .await?;
+    let mut node = nodes.pop().unwrap();
+    node.advance_block().await?;
+    let provider = ProviderBuilder::new().connect_http(node.rpc_url());
+
+    // the second transaction is only valid after the first one
+    let signer = wallet.wallet_gen().swap_remove(0);
+    let mut txs = Vec::new();
+    for nonce in 0..2 {
+        txs.push(
+            TransactionTestContext::transfer_tx_bytes_with_nonce(1, signer.clone(), nonce).await,
+        );
+    }
+
+    let bundle: serde_json::Value = provider
+        .raw_request(
+            "eth_callBundle".into(),
+            [serde_json::json!({ "txs": txs, "blockNumber": "0x2", "stateBlockNumber": "latest" })],
+        )
+        .await?;
+    assert_eq!(u256(&bundle["totalGasUsed"]), U256::from(42_000));
+    assert_eq!(u256(&bundle["stateBlockNumber"]), U256::from(1));
+    assert_eq!(bundle["results"].as_array().unwrap().len(), 2);
+    assert_eq!(bundle["results"][1]["fromAddress"], serde_json::json!(signer.address()));
+    // the coinbase only receives the priority fees
+    assert!(!u256(&bundle["gasFees"]).is_zero());
+    assert_eq!(u256(&bundle["coinbaseDiff"]), u256(&bundle["gasFees"]));
+    assert!(u256(&bundle["ethSentToCoinbase"]).is_zero());
+
+    let sim: serde_json::Value = provider
+        .raw_request(
+            "mev_simBundle".into(),
+            (
+                serde_json::json!({
+                    "version": "v0.1",
+                    "inclusion": { "block": "0x1" },
+                    "body": txs
+                        .iter()
+                        .map(|tx| serde_json::json!({ "tx": tx, "canRevert": false }))
+                        .collect::<Vec<_>>(),
+                }),
+                serde_json::json!({}),
+            ),
+        )
+        .await?;
+    assert_eq!(sim["success"], true);
+    assert_eq!(u256(&sim["gasUsed"]), U256::from(42_000));
+    assert_eq!(u256(&sim["profit"]), u256(&bundle["coinbaseDiff"]));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_debug_execution_witness() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let chain_spec = Arc::new(
+        ChainSpecBuilder::default()
+            .chain(MAINNET.chain)
+            .genesis(serde_json::from_str(include_str!("../assets/genesis.json")).unwrap())
+            .cancun_activated()
+            .build(),
+    );
+    let genesis_hash = chain_spec.genesis_hash();
+
+    let (mut nodes, wallet) = setup_engine::<EthereumNode>(
+        1,
+        chain_spec,
+        false,
+        Default::default(),
+        eth_payload_attributes,
+    )
+    .await?;
+    let mut node = nodes.pop().unwrap();
+    let signer = wallet.wallet_gen().swap_remove(0);
+    node.rpc.inject_tx(TransactionTestContext::transfer_tx_bytes(1, signer.clone()).await).await?;
+    node.advance_block().await?;
+    let provider = ProviderBuilder::new().connect_http(node.rpc_url());
+
+    let witness: serde_json::Value =
+        provider.raw_request("debug_executionWitness".into(), ["0x1"]).await?;
+    let bytes =
+        |field: &str| -> Vec<Bytes> { serde_json::from_value(witness[field].clone()).unwrap() };
+
+    // the parent header, needed to verify the pre-state root
+    let headers = bytes("headers");
+    assert_eq!(headers.len(), 1);
+    assert_eq!(keccak256(&headers[0]), genesis_hash);
+
+    // the trie nodes and preimages of the accessed accounts
+    assert!(!bytes("state").is_empty());
+    assert!(bytes("keys").contains(&Bytes::copy_from_slice(signer.address().as_slice())));
+
+    Ok(())
+}
+
+/// Parses a number of a response, encoded as a JSON number, a decimal or a hex string.
+fn u256(value: &serde_json::Value) -> U256 {
+    serde_json::from_value(value.clone()).unwrap()
+}
@@ -1,5 +1,5 @@
 use crate::utils::eth_payload_attributes;
-use alloy_primitives::{bytes, Address, U256};
+use alloy_primitives::{address, bytes, Address, U256};
 use alloy_provider::{network::EthereumWallet, Provider, ProviderBuilder};
 use alloy_rpc_types_eth::{
     simulate::{SimBlock, SimulatePayload, SimulatedBlock},
@@ -267,6 +267,74 @@ async fn test_simulate_v1_with_max_fee_per_blob_gas_only() -> eyre::Result<()> {
     Ok(())
 }
 
+/// Tests that state changes of a simulated block, including its state overrides, carry over to
+/// the following blocks, and that transfers are traced as logs when validation is enabled.
+#[tokio::test]
+async fn test_simulate_v1_multi_block_transfers_with_validation() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let chain_spec = Arc::new(
+        ChainSpecBuilder::default()
+            .chain(MAINNET.chain)
+            .genesis(serde_json::from_str(include_str!("../assets/genesis.json")).unwrap())
+            .cancun_activated()
+            .build(),
+    );
+
+    let (mut nodes, wallet) = setup_engine::<EthereumNode>(
+        1,
+        chain_spec,
+        false,
+        Default::default(),
+        eth_payload_attributes,
+    )
+    .await?;
+    let node = nodes.pop().unwrap();
+    let provider = ProviderBuilder::new()
+        .wallet(EthereumWallet::new(wallet.wallet_gen().swap_remove(0)))
+        .connect_http(node.rpc_url());
+
+    let alice: Address = "0xc000000000000000000000000000000000000000".parse()?;
+    let bob: Address = "0xc100000000000000000000000000000000000000".parse()?;
+    let ether = U256::from(10u64.pow(18));
+    let transfer = |from, to, value| {
+        TransactionRequest::default()
+            .from(from)
+            .to(to)
+            .value(value)
+            .gas_limit(21_000)
+            .max_fee_per_gas(100_000_000_000)
+    };
+
+    // Only the first block funds alice, bob can only pay back with the ether received in it.
+    let state_overrides =
+        StateOverridesBuilder::default().with_balance(alice, ether * U256::from(2)).build();
+    let payload = SimulatePayload::default()
+        .with_validation()
+        .with_trace_transfers()
+        .extend(
+            SimBlock::default()
+                .with_state_overrides(state_overrides)
+                .call(transfer(alice, bob, ether)),
+        )
+        .extend(SimBlock::default().call(transfer(bob, alice, ether / U256::from(2))));
+
+    let result: Vec<SimulatedBlock> =
+        provider.raw_request("eth_simulateV1".into(), (&payload, "latest")).await?;
+
+    assert_eq!(result.len(), 2);
+    for block in &result {
+        assert_eq!(block.calls.len(), 1);
+        let call = &block.calls[0];
+        assert!(call.status, "expected call to succeed: {:?}", call.error);
+        assert_eq!(call.logs.len(), 1, "expected the transfer to be traced");
+        assert_eq!(call.logs[0].address(), address!("0xEeeeeEeeeEeEeeEeEeEeeEEEeeeeEeeeeeeeEEeE"));
+    }
+    assert_eq!(result[1].inner.header.parent_hash, result[0].inner.header.hash);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_simulate_v1_too_many_blocks_error() -> eyre::Result<()> {
     reth_tracing::init_test_tracing();
@@ -80,6 +80,27 @@ where
         }
     }
 
+    fn execute_one_with_bal(
+        &mut self,
+        block: &RecoveredBlock<<Self::Primitives as NodePrimitives>::Block>,
+        bal: &alloy_eip7928::BlockAccessList,
+    ) -> Result<
+        Option<BlockExecutionResult<<Self::Primitives as NodePrimitives>::Receipt>>,
+        Self::Error,
+    > {
+        match self {
+            Self::Left(a) => a.execute_one_with_bal(block, bal),
+            Self::Right(b) => b.execute_one_with_bal(block, bal),
+        }
+    }
+
+    fn memory_size_hint(&self) -> usize {
+        match self {
+            Self::Left(a) => a.memory_size_hint(),
+            Self::Right(b) => b.memory_size_hint(),
+        }
+    }
+
     fn take_bal(&mut self) -> Option<alloy_eip7928::BlockAccessList> {
         match self {
             Self::Left(a) => a.take_bal(),
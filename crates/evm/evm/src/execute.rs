@@ -10,7 +10,7 @@ use alloy_evm::{
     block::{CommitChanges, ExecutableTxParts},
     Evm, EvmEnv, EvmFactory, RecoveredTx, ToTxEnv,
 };
-use alloy_primitives::{Address, B256};
+use alloy_primitives::{Address, B256, U256};
 pub use reth_execution_errors::{
     BlockExecutionError, BlockValidationError, InternalBlockExecutionError,
 };
@@ -23,8 +23,8 @@ use reth_storage_api::StateProvider;
 pub use reth_storage_errors::provider::ProviderError;
 use reth_trie_common::{updates::TrieUpdates, HashedPostState};
 use revm::{
-    database::{states::bundle_state::BundleRetention, BundleState, State},
-    state::bal::Bal,
+    database::{states::bundle_state::BundleRetention, BundleState, State, TransitionAccount},
+    state::{bal::Bal, AccountInfo},
 };
 
 /// A type that knows how to execute a block. It is assumed to operate on a
@@ -41,6 +41,25 @@ pub trait Executor<DB: Database>: Sized {
         block: &RecoveredBlock<<Self::Primitives as NodePrimitives>::Block>,
     ) -> Result<BlockExecutionResult<<Self::Primitives as NodePrimitives>::Receipt>, Self::Error>;
 
+    /// Executes a single block like [`Self::execute_one`], running its transactions in parallel
+    /// using the given [`BlockAccessList`] of the block.
+    ///
+    /// Returns `Ok(None)` if the block can't be executed in parallel, e.g. because the access list
+    /// doesn't match the block header or a transaction accessed state that isn't listed in it. No
+    /// state changes are applied in that case and the block should be executed with
+    /// [`Self::execute_one`] instead.
+    fn execute_one_with_bal(
+        &mut self,
+        block: &RecoveredBlock<<Self::Primitives as NodePrimitives>::Block>,
+        bal: &BlockAccessList,
+    ) -> Result<
+        Option<BlockExecutionResult<<Self::Primitives as NodePrimitives>::Receipt>>,
+        Self::Error,
+    > {
+        let _ = (block, bal);
+        Ok(None)
+    }
+
     /// Executes the EVM with the given input and accepts a state hook closure that is invoked with
     /// the EVM state after execution.
     fn execute_one_with_state_hook<F>(
@@ -150,6 +169,13 @@ pub trait Executor<DB: Database>: Sized {
     /// This is used to optimize DB commits depending on the size of the state.
     fn size_hint(&self) -> usize;
 
+    /// Estimated size in bytes of the state changes of the batch held in memory.
+    ///
+    /// Defaults to the changes of the batch counted as storage slots.
+    fn memory_size_hint(&self) -> usize {
+        self.size_hint() * STORAGE_SLOT_SIZE
+    }
+
     /// Takes built [`BlockAccessList`] from executor.
     fn take_bal(&mut self) -> Option<BlockAccessList>;
 }
@@ -569,13 +595,27 @@ pub struct BasicBlockExecutor<F, DB> {
     pub(crate) strategy_factory: F,
     /// Database.
     pub(crate) db: State<DB>,
+    /// Estimated size in bytes of the state changes merged into the bundle state.
+    memory_size: usize,
 }
 
 impl<F, DB: Database> BasicBlockExecutor<F, DB> {
     /// Creates a new `BasicBlockExecutor` with the given strategy.
     pub fn new(strategy_factory: F, db: DB) -> Self {
         let db = State::builder().with_database(db).with_bundle_update().build();
-        Self { strategy_factory, db }
+        Self { strategy_factory, db, memory_size: 0 }
+    }
+}
+
+impl<F, DB> BasicBlockExecutor<F, DB> {
+    /// Merges the transitions of the executed block into the bundle state, adding the size of the
+    /// changed accounts, storage and bytecodes to the tracked memory size.
+    fn merge_transitions(&mut self) {
+        if let Some(transitions) = &self.db.transition_state {
+            self.memory_size +=
+                transitions.transitions.values().map(transition_memory_size).sum::<usize>();
+        }
+        self.db.merge_transitions(BundleRetention::Reverts);
     }
 }
 
@@ -620,11 +660,77 @@ where
 
         let result = executor.apply_post_execution_changes()?;
 
-        self.db.merge_transitions(BundleRetention::Reverts);
+        self.merge_transitions();
 
         Ok(result)
     }
 
+    #[cfg(feature = "std")]
+    fn execute_one_with_bal(
+        &mut self,
+        block: &RecoveredBlock<<Self::Primitives as NodePrimitives>::Block>,
+        bal: &BlockAccessList,
+    ) -> Result<
+        Option<BlockExecutionResult<<Self::Primitives as NodePrimitives>::Receipt>>,
+        Self::Error,
+    > {
+        use crate::parallel::{execute_transactions, BalSnapshot, BlockGasTracker};
+        use alloy_consensus::Transaction;
+        use alloy_evm::block::TxResult;
+        use revm::context::Block as _;
+
+        let Some(bal_hash) = block.header().block_access_list_hash() else { return Ok(None) };
+        if compute_block_access_list_hash(bal) != bal_hash {
+            return Ok(None)
+        }
+        let Ok(revm_bal) = Bal::clone_from_alloy(bal) else { return Ok(None) };
+
+        let evm_env =
+            self.strategy_factory.evm_env(block.header()).map_err(BlockExecutionError::other)?;
+        let ctx = self
+            .strategy_factory
+            .context_for_block(block.sealed_block())
+            .map_err(BlockExecutionError::other)?;
+        let snapshot = BalSnapshot::load(&mut self.db, bal).map_err(BlockExecutionError::other)?;
+        let transactions = block.transactions_recovered().collect::<Vec<_>>();
+
+        let Ok(results) = execute_transactions(
+            &self.strategy_factory,
+            &snapshot,
+            Arc::new(revm_bal),
+            evm_env.clone(),
+            ctx.clone(),
+            &transactions,
+        ) else {
+            return Ok(None)
+        };
+
+        let mut gas_tracker = BlockGasTracker::new(
+            evm_env.block_env.gas_limit(),
+            evm_env.cfg_env.enable_amsterdam_eip8037,
+            evm_env.cfg_env.tx_gas_limit_cap,
+        );
+        let evm = self.strategy_factory.evm_with_env(&mut self.db, evm_env);
+        let mut executor = self.strategy_factory.create_executor_with_state(evm, ctx);
+        executor.evm_mut().db_mut().bal_state.bal_builder = Some(Bal::new());
+
+        executor.apply_pre_execution_changes()?;
+        executor.evm_mut().db_mut().bump_bal_index();
+
+        for (tx, result) in transactions.iter().zip(results) {
+            gas_tracker.validate_tx_limit(tx.gas_limit())?;
+            gas_tracker.record_result(result.result());
+            let _ = executor.commit_transaction(result);
+            executor.evm_mut().db_mut().bump_bal_index();
+        }
+
+        let result = executor.apply_post_execution_changes()?;
+
+        self.merge_transitions();
+
+        Ok(Some(result))
+    }
+
     fn execute_one_with_state_hook<H>(
         &mut self,
         block: &RecoveredBlock<<Self::Primitives as NodePrimitives>::Block>,
@@ -643,7 +749,7 @@ where
         let result = executor.execute_block(block.transactions_recovered());
 
         self.db.set_state_hook(None);
-        self.db.merge_transitions(BundleRetention::Reverts);
+        self.merge_transitions();
 
         result
     }
@@ -656,11 +762,39 @@ where
         self.db.bundle_state.size_hint()
     }
 
+    fn memory_size_hint(&self) -> usize {
+        self.memory_size
+    }
+
     fn take_bal(&mut self) -> Option<BlockAccessList> {
         self.db.take_built_alloy_bal()
     }
 }
 
+/// Approximate size in bytes of an account, without its storage.
+const ACCOUNT_SIZE: usize = size_of::<Address>() + size_of::<AccountInfo>();
+
+/// Approximate size in bytes of a storage slot.
+const STORAGE_SLOT_SIZE: usize = 2 * size_of::<U256>();
+
+/// Returns the estimated size in bytes of the account, storage slots and newly deployed bytecode
+/// changed by the given transition.
+fn transition_memory_size(transition: &TransitionAccount) -> usize {
+    let code = transition
+        .info
+        .as_ref()
+        .filter(|info| {
+            transition
+                .previous_info
+                .as_ref()
+                .is_none_or(|previous| previous.code_hash != info.code_hash)
+        })
+        .and_then(|info| info.code.as_ref())
+        .map_or(0, |code| code.original_bytes().len());
+
+    ACCOUNT_SIZE + transition.storage.len() * STORAGE_SLOT_SIZE + code
+}
+
 /// A helper trait marking a 'static type that can be converted into an [`ExecutableTxParts`] for
 /// block executor.
 pub trait ExecutableTxFor<Evm: ConfigureEvm>:
@@ -773,11 +907,36 @@ mod tests {
             0
         }
 
+        fn memory_size_hint(&self) -> usize {
+            0
+        }
+
         fn take_bal(&mut self) -> Option<BlockAccessList> {
             None
         }
     }
 
+    #[test]
+    fn transition_memory_size_counts_storage_and_new_code() {
+        use revm::{bytecode::Bytecode, database::StorageWithOriginalValues};
+
+        let code = Bytecode::new_raw([0x60, 0x00].into());
+        let storage = StorageWithOriginalValues::from_iter(
+            (0..3u64).map(|slot| (U256::from(slot), Default::default())),
+        );
+        let deployed = TransitionAccount {
+            info: Some(AccountInfo::default().with_code(code)),
+            storage,
+            ..Default::default()
+        };
+        assert_eq!(transition_memory_size(&deployed), ACCOUNT_SIZE + 3 * STORAGE_SLOT_SIZE + 2);
+
+        // bytecode that didn't change isn't counted again
+        let updated =
+            TransitionAccount { previous_info: deployed.info.clone(), ..deployed.clone() };
+        assert_eq!(transition_memory_size(&updated), ACCOUNT_SIZE + 3 * STORAGE_SLOT_SIZE);
+    }
+
     #[test]
     fn test_provider() {
         let provider = TestExecutorProvider;
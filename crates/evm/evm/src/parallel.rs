@@ -0,0 +1,294 @@
+//! Parallel transaction execution driven by an EIP-7928 block access list.
+//!
+//! A block access list lists every account and storage slot a block touches and the values
+//! written by each transaction. Given the pre-block state of everything listed, each transaction
+//! can be executed independently: revm's BAL state serves the writes of the preceding
+//! transactions from the access list. The results are then committed in transaction order.
+
+use crate::{ConfigureEvm, Database, EvmEnvFor, ExecutionCtxFor};
+use alloc::{sync::Arc, vec::Vec};
+use alloy_eip7928::{BlockAccessIndex, BlockAccessList};
+use alloy_evm::{
+    block::{BlockExecutionError, BlockExecutor, BlockExecutorFactory, BlockValidationError},
+    Evm,
+};
+use alloy_primitives::{map::HashMap, Address, B256, U256};
+use core::fmt;
+use reth_primitives_traits::{Recovered, TxTy};
+use reth_storage_errors::provider::ProviderError;
+use revm::{
+    bytecode::Bytecode,
+    context::result::ResultAndState,
+    database::State,
+    primitives::KECCAK_EMPTY,
+    state::{bal::Bal, AccountInfo},
+};
+
+/// Result of executing a single transaction without committing it.
+pub(crate) type TxExecutionResultFor<Evm> =
+    <<Evm as ConfigureEvm>::BlockExecutorFactory as BlockExecutorFactory>::TxExecutionResult;
+
+/// Pre-block state of all accounts, storage slots and bytecodes listed in a block access list.
+///
+/// Reads of state that is not part of the snapshot fail with [`BalSnapshotMiss`]. For a valid
+/// block access list this can only happen for `BLOCKHASH` lookups, which aren't recorded in the
+/// access list.
+#[derive(Debug, Default)]
+pub(crate) struct BalSnapshot {
+    accounts: HashMap<Address, Option<AccountInfo>>,
+    storage: HashMap<(Address, U256), U256>,
+    contracts: HashMap<B256, Bytecode>,
+}
+
+impl BalSnapshot {
+    /// Loads the state of everything listed in the block access list from the given database.
+    pub(crate) fn load<DB: Database>(mut db: DB, bal: &BlockAccessList) -> Result<Self, DB::Error> {
+        let mut snapshot = Self::default();
+        for account in bal {
+            let info = db.basic(account.address)?;
+            if let Some(info) = &info &&
+                info.code_hash != KECCAK_EMPTY &&
+                !snapshot.contracts.contains_key(&info.code_hash)
+            {
+                let code = match &info.code {
+                    Some(code) => code.clone(),
+                    None => db.code_by_hash(info.code_hash)?,
+                };
+                snapshot.contracts.insert(info.code_hash, code);
+            }
+            snapshot.accounts.insert(account.address, info);
+
+            let slots = account
+                .storage_changes
+                .iter()
+                .map(|changes| changes.slot)
+                .chain(account.storage_reads.iter().copied());
+            for slot in slots {
+                let value = db.storage(account.address, slot)?;
+                snapshot.storage.insert((account.address, slot), value);
+            }
+        }
+        Ok(snapshot)
+    }
+}
+
+impl revm::Database for &BalSnapshot {
+    type Error = ProviderError;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        self.accounts
+            .get(&address)
+            .cloned()
+            .ok_or_else(|| ProviderError::other(BalSnapshotMiss::Account(address)))
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        self.contracts
+            .get(&code_hash)
+            .cloned()
+            .ok_or_else(|| ProviderError::other(BalSnapshotMiss::Code(code_hash)))
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        self.storage
+            .get(&(address, index))
+            .copied()
+            .ok_or_else(|| ProviderError::other(BalSnapshotMiss::Storage(address, index)))
+    }
+
+    fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+        Err(ProviderError::other(BalSnapshotMiss::BlockHash(number)))
+    }
+}
+
+/// State read outside of a [`BalSnapshot`].
+#[derive(Debug)]
+pub(crate) enum BalSnapshotMiss {
+    /// Account not listed in the block access list.
+    Account(Address),
+    /// Storage slot not listed in the block access list.
+    Storage(Address, U256),
+    /// Bytecode of an account not listed in the block access list.
+    Code(B256),
+    /// Block hash lookup.
+    BlockHash(u64),
+}
+
+impl fmt::Display for BalSnapshotMiss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Account(address) => write!(f, "account {address} not in block access list"),
+            Self::Storage(address, slot) => {
+                write!(f, "storage slot {slot} of {address} not in block access list")
+            }
+            Self::Code(code_hash) => write!(f, "bytecode {code_hash} not in block access list"),
+            Self::BlockHash(number) => write!(f, "block hash {number} not in block access list"),
+        }
+    }
+}
+
+impl core::error::Error for BalSnapshotMiss {}
+
+/// Executes the given transactions of a block in parallel on top of the pre-block `snapshot`.
+///
+/// The transactions are split into one chunk per thread of the current rayon pool, each chunk is
+/// executed on its own [`State`] that serves the writes of earlier transactions from `bal`.
+///
+/// Returns the uncommitted results in transaction order, or the first error of any worker.
+pub(crate) fn execute_transactions<F>(
+    evm_config: &F,
+    snapshot: &BalSnapshot,
+    bal: Arc<Bal>,
+    evm_env: EvmEnvFor<F>,
+    ctx: ExecutionCtxFor<'_, F>,
+    transactions: &[Recovered<&TxTy<F::Primitives>>],
+) -> Result<Vec<TxExecutionResultFor<F>>, BlockExecutionError>
+where
+    F: ConfigureEvm,
+{
+    if transactions.is_empty() {
+        return Ok(Vec::new())
+    }
+
+    let chunk_size = transactions.len().div_ceil(rayon::current_num_threads().max(1));
+    let (result_tx, result_rx) = std::sync::mpsc::channel();
+
+    rayon::scope(|scope| {
+        for (chunk_index, chunk) in transactions.chunks(chunk_size).enumerate() {
+            let result_tx = result_tx.clone();
+            let bal = Arc::clone(&bal);
+            let evm_env = evm_env.clone();
+            let ctx = ctx.clone();
+
+            scope.spawn(move |_| {
+                let mut state = State::builder()
+                    .with_database(snapshot)
+                    .with_bal(bal)
+                    .with_bundle_update()
+                    .build();
+                let evm = evm_config.evm_with_env(&mut state, evm_env);
+                let mut executor = evm_config.create_executor_with_state(evm, ctx);
+
+                let results = chunk
+                    .iter()
+                    .enumerate()
+                    .map(|(offset, tx)| {
+                        let index = chunk_index * chunk_size + offset;
+                        executor
+                            .evm_mut()
+                            .db_mut()
+                            .set_bal_index(BlockAccessIndex::new(index as u64 + 1));
+                        executor.execute_transaction_without_commit(*tx)
+                    })
+                    .collect::<Result<Vec<_>, _>>();
+
+                let _ = result_tx.send((chunk_index, results));
+            });
+        }
+    });
+    drop(result_tx);
+
+    let mut chunks = result_rx.into_iter().collect::<Vec<_>>();
+    chunks.sort_unstable_by_key(|(chunk_index, _)| *chunk_index);
+
+    let mut results = Vec::with_capacity(transactions.len());
+    for (_, chunk) in chunks {
+        results.extend(chunk?);
+    }
+    Ok(results)
+}
+
+/// Tracks the gas used by a block whose transactions are executed without the block executor's
+/// cumulative gas admission check, e.g. in parallel, and performs that check when the results are
+/// committed in order.
+#[derive(Debug)]
+pub struct BlockGasTracker {
+    block_gas_limit: u64,
+    enable_amsterdam_eip8037: bool,
+    tx_gas_limit_cap: Option<u64>,
+    cumulative_tx_gas_used: u64,
+    block_regular_gas_used: u64,
+}
+
+impl BlockGasTracker {
+    /// Creates a new tracker for a block with the given gas limit.
+    pub const fn new(
+        block_gas_limit: u64,
+        enable_amsterdam_eip8037: bool,
+        tx_gas_limit_cap: Option<u64>,
+    ) -> Self {
+        Self {
+            block_gas_limit,
+            enable_amsterdam_eip8037,
+            tx_gas_limit_cap,
+            cumulative_tx_gas_used: 0,
+            block_regular_gas_used: 0,
+        }
+    }
+
+    /// Checks that a transaction with the given gas limit fits into the remaining block gas.
+    pub fn validate_tx_limit(&self, tx_gas_limit: u64) -> Result<(), BlockExecutionError> {
+        let block_gas_used = if self.enable_amsterdam_eip8037 {
+            self.block_regular_gas_used
+        } else {
+            self.cumulative_tx_gas_used
+        };
+        let block_available_gas = self.block_gas_limit.saturating_sub(block_gas_used);
+        let tx_min_gas_limit =
+            self.tx_gas_limit_cap.map_or(tx_gas_limit, |cap| tx_gas_limit.min(cap));
+
+        if tx_min_gas_limit > block_available_gas {
+            return Err(BlockValidationError::TransactionGasLimitMoreThanAvailableBlockGas {
+                transaction_gas_limit: tx_gas_limit,
+                block_available_gas,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Records the gas used by an executed transaction.
+    pub const fn record_result<H>(&mut self, result: &ResultAndState<H>) {
+        let gas = result.result.gas();
+        self.cumulative_tx_gas_used = self.cumulative_tx_gas_used.saturating_add(gas.tx_gas_used());
+        self.block_regular_gas_used =
+            self.block_regular_gas_used.saturating_add(gas.block_regular_gas_used());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eip7928::AccountChanges;
+    use revm::{
+        database::{CacheDB, EmptyDB},
+        Database as _,
+    };
+
+    #[test]
+    fn snapshot_serves_listed_state_only() {
+        let address = Address::with_last_byte(1);
+        let info = AccountInfo { balance: U256::from(10), nonce: 1, ..Default::default() };
+        let mut db = CacheDB::new(EmptyDB::default());
+        db.insert_account_info(address, info.clone());
+        db.insert_account_storage(address, U256::from(1), U256::from(2)).unwrap();
+
+        let bal = vec![AccountChanges {
+            address,
+            storage_changes: Vec::new(),
+            storage_reads: vec![U256::from(1)],
+            balance_changes: Vec::new(),
+            nonce_changes: Vec::new(),
+            code_changes: Vec::new(),
+        }];
+        let snapshot = BalSnapshot::load(&mut db, &bal).unwrap();
+        let mut snapshot = &snapshot;
+
+        assert_eq!(snapshot.basic(address).unwrap().map(|info| info.balance), Some(info.balance));
+        assert_eq!(snapshot.storage(address, U256::from(1)).unwrap(), U256::from(2));
+        assert!(snapshot.storage(address, U256::from(2)).is_err());
+        assert!(snapshot.basic(Address::with_last_byte(2)).is_err());
+        assert!(snapshot.block_hash(0).is_err());
+    }
+}
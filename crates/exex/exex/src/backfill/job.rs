@@ -121,12 +121,14 @@ where
 
             // Seal the block back and save it
             blocks.push(block);
+
             // Check if we should commit now
             if self.thresholds.is_end_of_batch(
                 block_number - *self.range.start() + 1,
                 executor.size_hint() as u64,
                 cumulative_gas,
                 batch_start.elapsed(),
+                executor.memory_size_hint() as u64,
             ) {
                 break
             }
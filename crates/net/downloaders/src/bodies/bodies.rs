@@ -1,5 +1,9 @@
 use super::queue::BodiesRequestQueue;
-use crate::{bodies::task::TaskDownloader, metrics::BodyDownloaderMetrics};
+use crate::{
+    bodies::task::TaskDownloader,
+    metrics::BodyDownloaderMetrics,
+    rate_limit::{DownloadRateLimiter, RateLimit},
+};
 use alloy_consensus::BlockHeader;
 use alloy_primitives::BlockNumber;
 use futures::Stream;
@@ -66,6 +70,8 @@ pub struct BodiesDownloader<
     queued_bodies: Vec<BlockResponse<B>>,
     /// The bodies downloader metrics.
     metrics: BodyDownloaderMetrics,
+    /// Bandwidth and concurrency limits.
+    rate_limit: RateLimit,
 }
 
 impl<B, C, Provider> BodiesDownloader<B, C, Provider>
@@ -139,10 +145,17 @@ where
         }
     }
 
+    /// Returns the handle to the bandwidth and concurrency limits of the downloader, which can be
+    /// used to adjust them at runtime.
+    pub const fn rate_limiter(&self) -> &DownloadRateLimiter {
+        self.rate_limit.limiter()
+    }
+
     /// Max requests to handle at the same time
     ///
     /// This depends on the number of active peers but will always be
-    /// `min_concurrent_requests..max_concurrent_requests`
+    /// `min_concurrent_requests..max_concurrent_requests`, capped by the limit of the
+    /// [`DownloadRateLimiter`].
     #[inline]
     fn concurrent_request_limit(&self) -> usize {
         let num_peers = self.client.num_connected_peers();
@@ -150,11 +163,13 @@ where
         let max_requests = num_peers.max(*self.concurrent_requests_range.start());
 
         // if we're only connected to a few peers, we keep it low
-        if num_peers < *self.concurrent_requests_range.start() {
-            return max_requests
-        }
+        let limit = if num_peers < *self.concurrent_requests_range.start() {
+            max_requests
+        } else {
+            max_requests.min(*self.concurrent_requests_range.end())
+        };
 
-        max_requests.min(*self.concurrent_requests_range.end())
+        limit.min(self.rate_limit.limiter().max_concurrent_requests())
     }
 
     /// Returns true if the size of buffered blocks is lower than the configured maximum
@@ -219,6 +234,8 @@ where
         let response = OrderedBodiesResponse { resp: response, size };
         let response_len = response.len();
 
+        self.rate_limit.limiter().record_downloaded(size);
+
         self.buffered_blocks_size_bytes += size;
         self.buffered_responses.push(response);
 
@@ -286,8 +303,11 @@ where
     Provider: HeaderProvider<Header = B::Header> + Unpin + 'static,
 {
     /// Convert the downloader into a [`TaskDownloader`] by spawning it via the given [`Runtime`].
+    ///
+    /// The limits of the downloader remain adjustable via [`TaskDownloader::rate_limiter`].
     pub fn into_task_with(self, runtime: &Runtime) -> TaskDownloader<B> {
-        TaskDownloader::spawn_with(self, runtime)
+        let rate_limiter = self.rate_limiter().clone();
+        TaskDownloader::spawn_with(self, runtime).with_rate_limiter(rate_limiter)
     }
 }
 
@@ -388,7 +408,8 @@ where
             // Loop exit condition
             let mut new_request_submitted = false;
             // Submit new requests
-            'inner: while this.can_submit_new_request() {
+            'inner: while this.can_submit_new_request() && this.rate_limit.poll_ready(cx).is_ready()
+            {
                 match this.next_headers_request() {
                     Ok(Some(request)) => {
                         this.metrics.in_flight_requests.increment(1.);
@@ -508,6 +529,11 @@ pub struct BodiesDownloaderBuilder {
     pub max_buffered_blocks_size_bytes: usize,
     /// The maximum number of requests to send concurrently.
     pub concurrent_requests_range: RangeInclusive<usize>,
+    /// The maximum number of bytes to download per second.
+    pub max_bytes_per_second: Option<u64>,
+    /// Shared bandwidth and concurrency limits, overriding `max_bytes_per_second` and the end of
+    /// `concurrent_requests_range` if set.
+    pub rate_limiter: Option<DownloadRateLimiter>,
 }
 
 impl BodiesDownloaderBuilder {
@@ -522,6 +548,7 @@ impl BodiesDownloaderBuilder {
                 config.downloader_min_concurrent_requests..=
                     config.downloader_max_concurrent_requests,
             )
+            .with_max_bytes_per_second(config.downloader_max_bytes_per_second)
     }
 }
 
@@ -532,6 +559,8 @@ impl Default for BodiesDownloaderBuilder {
             stream_batch_size: 1_000,
             max_buffered_blocks_size_bytes: 2 * 1024 * 1024 * 1024, // ~2GB
             concurrent_requests_range: 5..=100,
+            max_bytes_per_second: None,
+            rate_limiter: None,
         }
     }
 }
@@ -558,6 +587,19 @@ impl BodiesDownloaderBuilder {
         self
     }
 
+    /// Set the maximum number of bytes to download per second on the downloader.
+    pub const fn with_max_bytes_per_second(mut self, max_bytes_per_second: Option<u64>) -> Self {
+        self.max_bytes_per_second = max_bytes_per_second;
+        self
+    }
+
+    /// Set the [`DownloadRateLimiter`] of the downloader, e.g. to share the limits with another
+    /// downloader.
+    pub fn with_rate_limiter(mut self, rate_limiter: DownloadRateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
     /// Set max buffered block bytes on the downloader.
     pub const fn with_max_buffered_blocks_size_bytes(
         mut self,
@@ -584,7 +626,12 @@ impl BodiesDownloaderBuilder {
             stream_batch_size,
             concurrent_requests_range,
             max_buffered_blocks_size_bytes,
+            max_bytes_per_second,
+            rate_limiter,
         } = self;
+        let rate_limiter = rate_limiter.unwrap_or_else(|| {
+            DownloadRateLimiter::new(max_bytes_per_second, *concurrent_requests_range.end())
+        });
         let metrics = BodyDownloaderMetrics::default();
         let in_progress_queue = BodiesRequestQueue::new(metrics.clone());
         BodiesDownloader {
@@ -602,6 +649,7 @@ impl BodiesDownloaderBuilder {
             buffered_responses: Default::default(),
             queued_bodies: Default::default(),
             buffered_blocks_size_bytes: 0,
+            rate_limit: RateLimit::new(rate_limiter),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::rate_limit::DownloadRateLimiter;
 use alloy_primitives::BlockNumber;
 use futures::Stream;
 use futures_util::StreamExt;
@@ -29,6 +30,8 @@ pub struct TaskDownloader<B: Block> {
     #[pin]
     from_downloader: ReceiverStream<BodyDownloaderResult<B>>,
     to_downloader: UnboundedSender<RangeInclusive<BlockNumber>>,
+    /// The limits of the spawned downloader, if known.
+    rate_limiter: Option<DownloadRateLimiter>,
 }
 
 impl<B: Block + 'static> TaskDownloader<B> {
@@ -49,7 +52,22 @@ impl<B: Block + 'static> TaskDownloader<B> {
 
         runtime.spawn_task(downloader);
 
-        Self { from_downloader: ReceiverStream::new(bodies_rx), to_downloader }
+        Self { from_downloader: ReceiverStream::new(bodies_rx), to_downloader, rate_limiter: None }
+    }
+
+    /// Sets the limits of the spawned downloader, returned by [`Self::rate_limiter`].
+    pub(crate) fn with_rate_limiter(mut self, rate_limiter: DownloadRateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Returns the handle to the bandwidth and concurrency limits of the spawned downloader, which
+    /// can be used to adjust them at runtime.
+    ///
+    /// Only set if the task was spawned with
+    /// [`BodiesDownloader::into_task_with`](crate::bodies::bodies::BodiesDownloader::into_task_with).
+    pub const fn rate_limiter(&self) -> Option<&DownloadRateLimiter> {
+        self.rate_limiter.as_ref()
     }
 }
 
@@ -174,6 +192,40 @@ mod tests {
         assert_eq!(client.times_requested(), 1);
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn adjust_limits_of_task() {
+        reth_tracing::init_test_tracing();
+
+        let factory = create_test_provider_factory();
+        let (headers, mut bodies) = generate_bodies(0..=19);
+
+        insert_headers(&factory, &headers);
+
+        let client = Arc::new(TestBodiesClient::default().with_bodies(bodies.clone()));
+        let runtime = Runtime::test();
+        let mut downloader = BodiesDownloaderBuilder::default()
+            .with_request_limit(5)
+            .build::<reth_ethereum_primitives::Block, _, _>(
+                client.clone(),
+                Arc::new(TestConsensus::default()),
+                factory,
+            )
+            .into_task_with(&runtime);
+
+        // a limit of zero concurrent requests doesn't stall the downloader
+        let rate_limiter = downloader.rate_limiter().unwrap().clone();
+        rate_limiter.set_max_concurrent_requests(0);
+        assert_eq!(rate_limiter.max_concurrent_requests(), 1);
+
+        downloader.set_download_range(0..=19).expect("failed to set download range");
+
+        let mut downloaded = Vec::new();
+        while downloaded.len() < headers.len() {
+            downloaded.extend(downloader.next().await.unwrap().unwrap());
+        }
+        assert_eq!(downloaded, zip_blocks(headers.iter(), &mut bodies));
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     #[expect(clippy::reversed_empty_ranges)]
     async fn set_download_range_error_returned() {
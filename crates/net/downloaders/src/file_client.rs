@@ -207,6 +207,16 @@ impl<B: FullBlock> FileClient<B> {
         bodies.iter_mut().map(|(hash, body)| (numbers[hash], body))
     }
 
+    /// Returns an iterator over the headers and bodies in the client, ordered by block number.
+    ///
+    /// Panics, if file client headers and bodies are not mapping 1-1.
+    pub fn blocks_iter(&self) -> impl Iterator<Item = (&B::Header, &B::Body)> {
+        let mut blocks: Vec<_> =
+            self.hash_to_number.iter().map(|(hash, number)| (*number, hash)).collect();
+        blocks.sort_unstable_by_key(|(number, _)| *number);
+        blocks.into_iter().map(|(number, hash)| (&self.headers[&number], &self.bodies[hash]))
+    }
+
     /// Returns the current number of transactions in the client.
     pub fn total_transactions(&self) -> usize {
         self.bodies.iter().fold(0, |acc, (_, body)| acc + body.transactions().len())
@@ -826,6 +836,20 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn blocks_iter_is_ordered_by_number() {
+        let (file, headers, bodies) = generate_bodies_file(0..=19).await;
+        let client: FileClient<Block> =
+            FileClient::from_file(file, NoopConsensus::arc()).await.unwrap();
+
+        let blocks: Vec<_> = client.blocks_iter().collect();
+        assert_eq!(blocks.len(), headers.len());
+        for ((header, body), expected) in blocks.into_iter().zip(&headers) {
+            assert_eq!(header, expected.header());
+            assert_eq!(Some(body), bodies.get(&expected.hash()));
+        }
+    }
+
     #[tokio::test]
     async fn strict_chunk_decode_fails_on_invalid_block() {
         let (file, _, _) = generate_bodies_file(0..=2).await;
@@ -1,7 +1,10 @@
 //! A headers downloader that can handle multiple requests concurrently.
 
 use super::task::TaskDownloader;
-use crate::metrics::HeaderDownloaderMetrics;
+use crate::{
+    metrics::HeaderDownloaderMetrics,
+    rate_limit::{DownloadRateLimiter, RateLimit},
+};
 use alloy_consensus::BlockHeader;
 use alloy_eips::BlockHashOrNumber;
 use alloy_primitives::{BlockNumber, Sealable, B256};
@@ -20,7 +23,7 @@ use reth_network_p2p::{
     priority::Priority,
 };
 use reth_network_peers::PeerId;
-use reth_primitives_traits::{GotExpected, SealedHeader};
+use reth_primitives_traits::{GotExpected, InMemorySize, SealedHeader};
 use reth_tasks::Runtime;
 use std::{
     cmp::{Ordering, Reverse},
@@ -106,6 +109,8 @@ pub struct ReverseHeadersDownloader<H: HeadersClient> {
     queued_validated_headers: Vec<SealedHeader<H::Header>>,
     /// Header downloader metrics.
     metrics: HeaderDownloaderMetrics,
+    /// Bandwidth and concurrency limits.
+    rate_limit: RateLimit,
 }
 
 // === impl ReverseHeadersDownloader ===
@@ -145,10 +150,17 @@ where
         self.sync_target.as_ref().expect("is initialized").clone()
     }
 
+    /// Returns the handle to the bandwidth and concurrency limits of the downloader, which can be
+    /// used to adjust them at runtime.
+    pub const fn rate_limiter(&self) -> &DownloadRateLimiter {
+        self.rate_limit.limiter()
+    }
+
     /// Max requests to handle at the same time
     ///
     /// This depends on the number of active peers but will always be
-    /// `min_concurrent_requests..max_concurrent_requests`
+    /// `min_concurrent_requests..max_concurrent_requests`, capped by the limit of the
+    /// [`DownloadRateLimiter`].
     #[inline]
     fn concurrent_request_limit(&self) -> usize {
         let num_peers = self.client.num_connected_peers();
@@ -159,11 +171,13 @@ where
         let max_dynamic = dynamic_target.max(self.min_concurrent_requests);
 
         // If only a few peers are connected we keep it low
-        if num_peers < self.min_concurrent_requests {
-            return max_dynamic
-        }
+        let limit = if num_peers < self.min_concurrent_requests {
+            max_dynamic
+        } else {
+            max_dynamic.min(self.max_concurrent_requests)
+        };
 
-        max_dynamic.min(self.max_concurrent_requests)
+        limit.min(self.rate_limit.limiter().max_concurrent_requests())
     }
 
     /// Returns the next header request
@@ -384,6 +398,9 @@ where
 
                 // update total downloaded metric
                 self.metrics.total_downloaded.increment(headers.len() as u64);
+                self.rate_limit
+                    .limiter()
+                    .record_downloaded(headers.iter().map(InMemorySize::size).sum());
 
                 // sort headers from highest to lowest block number
                 headers.sort_unstable_by_key(|h| Reverse(h.number()));
@@ -463,6 +480,9 @@ where
 
                 // update total downloaded metric
                 self.metrics.total_downloaded.increment(headers.len() as u64);
+                self.rate_limit
+                    .limiter()
+                    .record_downloaded(headers.iter().map(InMemorySize::size).sum());
 
                 trace!(target: "downloaders::headers", len=%headers.len(), "Received headers response");
 
@@ -661,11 +681,14 @@ where
     Self: HeaderDownloader + 'static,
 {
     /// Convert the downloader into a [`TaskDownloader`] by spawning it via the given [`Runtime`].
+    ///
+    /// The limits of the downloader remain adjustable via [`TaskDownloader::rate_limiter`].
     pub fn into_task_with(
         self,
         runtime: &Runtime,
     ) -> TaskDownloader<<Self as HeaderDownloader>::Header> {
-        TaskDownloader::spawn_with(self, runtime)
+        let rate_limiter = self.rate_limiter().clone();
+        TaskDownloader::spawn_with(self, runtime).with_rate_limiter(rate_limiter)
     }
 }
 
@@ -861,7 +884,8 @@ where
             let concurrent_request_limit = this.concurrent_request_limit();
             // populate requests
             while this.in_progress_queue.len() < concurrent_request_limit &&
-                this.buffered_responses.len() < this.max_buffered_responses
+                this.buffered_responses.len() < this.max_buffered_responses &&
+                this.rate_limit.poll_ready(cx).is_ready()
             {
                 if let Some(request) = this.next_request() {
                     trace!(
@@ -1106,6 +1130,10 @@ pub struct ReverseHeadersDownloaderBuilder {
     max_concurrent_requests: usize,
     /// How many responses to buffer
     max_buffered_responses: usize,
+    /// The maximum number of bytes to download per second
+    max_bytes_per_second: Option<u64>,
+    /// Shared bandwidth and concurrency limits
+    rate_limiter: Option<DownloadRateLimiter>,
 }
 
 impl ReverseHeadersDownloaderBuilder {
@@ -1118,6 +1146,7 @@ impl ReverseHeadersDownloaderBuilder {
             .max_concurrent_requests(config.downloader_max_concurrent_requests)
             .max_buffered_responses(config.downloader_max_buffered_responses)
             .stream_batch_size(config.commit_threshold as usize)
+            .max_bytes_per_second(config.downloader_max_bytes_per_second)
     }
 }
 
@@ -1131,6 +1160,8 @@ impl Default for ReverseHeadersDownloaderBuilder {
             max_concurrent_requests: 100,
             min_concurrent_requests: 5,
             max_buffered_responses: 100,
+            max_bytes_per_second: None,
+            rate_limiter: None,
         }
     }
 }
@@ -1183,6 +1214,23 @@ impl ReverseHeadersDownloaderBuilder {
         self
     }
 
+    /// Set the maximum number of bytes to download per second, `None` for no limit.
+    pub const fn max_bytes_per_second(mut self, max_bytes_per_second: Option<u64>) -> Self {
+        self.max_bytes_per_second = max_bytes_per_second;
+        self
+    }
+
+    /// Set the [`DownloadRateLimiter`] of the downloader, e.g. to share the limits with another
+    /// downloader.
+    ///
+    /// The limits of the given limiter take precedence over the configured
+    /// [`max_bytes_per_second`](Self::max_bytes_per_second) and
+    /// [`max_concurrent_requests`](Self::max_concurrent_requests).
+    pub fn rate_limiter(mut self, rate_limiter: DownloadRateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
     /// Build [`ReverseHeadersDownloader`] with provided consensus
     /// and header client implementations
     pub fn build<H>(
@@ -1199,7 +1247,12 @@ impl ReverseHeadersDownloaderBuilder {
             min_concurrent_requests,
             max_concurrent_requests,
             max_buffered_responses,
+            max_bytes_per_second,
+            rate_limiter,
         } = self;
+        let rate_limiter = rate_limiter.unwrap_or_else(|| {
+            DownloadRateLimiter::new(max_bytes_per_second, max_concurrent_requests)
+        });
         ReverseHeadersDownloader {
             consensus,
             client: Arc::new(client),
@@ -1220,6 +1273,7 @@ impl ReverseHeadersDownloaderBuilder {
             buffered_responses: Default::default(),
             queued_validated_headers: Default::default(),
             metrics: Default::default(),
+            rate_limit: RateLimit::new(rate_limiter),
         }
     }
 }
@@ -1,3 +1,4 @@
+use crate::rate_limit::DownloadRateLimiter;
 use alloy_primitives::Sealable;
 use futures::Stream;
 use futures_util::StreamExt;
@@ -28,6 +29,8 @@ pub struct TaskDownloader<H: Sealable> {
     #[pin]
     from_downloader: ReceiverStream<HeadersDownloaderResult<Vec<SealedHeader<H>>, H>>,
     to_downloader: UnboundedSender<DownloaderUpdates<H>>,
+    /// The limits of the spawned downloader, if known.
+    rate_limiter: Option<DownloadRateLimiter>,
 }
 
 // === impl TaskDownloader ===
@@ -49,7 +52,22 @@ impl<H: Sealable + Send + Sync + Unpin + 'static> TaskDownloader<H> {
         };
         runtime.spawn_task(downloader);
 
-        Self { from_downloader: ReceiverStream::new(headers_rx), to_downloader }
+        Self { from_downloader: ReceiverStream::new(headers_rx), to_downloader, rate_limiter: None }
+    }
+
+    /// Sets the limits of the spawned downloader, returned by [`Self::rate_limiter`].
+    pub(crate) fn with_rate_limiter(mut self, rate_limiter: DownloadRateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Returns the handle to the bandwidth and concurrency limits of the spawned downloader, which
+    /// can be used to adjust them at runtime.
+    ///
+    /// Only set if the task was spawned with
+    /// [`ReverseHeadersDownloader::into_task_with`](crate::headers::reverse_headers::ReverseHeadersDownloader::into_task_with).
+    pub const fn rate_limiter(&self) -> Option<&DownloadRateLimiter> {
+        self.rate_limiter.as_ref()
     }
 }
 
@@ -25,6 +25,9 @@ pub mod headers;
 /// Common downloader metrics.
 pub mod metrics;
 
+/// Bandwidth and concurrency limits of the downloaders.
+pub mod rate_limit;
+
 /// Module managing file-based data retrieval and buffering.
 ///
 /// Contains [`FileClient`](file_client::FileClient) to read block data from files,
@@ -0,0 +1,193 @@
+use parking_lot::Mutex;
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{ready, Context, Poll},
+    time::{Duration, Instant},
+};
+use tokio::time::Sleep;
+
+/// A cloneable handle to the bandwidth and concurrency limits of a downloader.
+///
+/// The limits can be adjusted at runtime through any clone of the handle, the downloader applies
+/// them before submitting its next request. Downloaded bytes are measured by the in-memory size
+/// of the received data, so the limit is approximate.
+#[derive(Debug, Clone)]
+pub struct DownloadRateLimiter {
+    inner: Arc<Mutex<RateLimiterInner>>,
+}
+
+#[derive(Debug)]
+struct RateLimiterInner {
+    /// The maximum number of bytes to download per second, unlimited if `None`.
+    bytes_per_second: Option<u64>,
+    /// The maximum number of requests to have in flight, at least one.
+    max_concurrent_requests: usize,
+    /// Bytes that can be downloaded before the limit is exceeded, negative if the downloader is
+    /// over the limit.
+    available_bytes: f64,
+    /// The last time `available_bytes` was refilled.
+    last_refill: Instant,
+}
+
+impl RateLimiterInner {
+    /// Refills the available bytes for the time passed since the last refill, allowing bursts of
+    /// up to one second worth of bytes.
+    fn refill(&mut self) {
+        let now = Instant::now();
+        if let Some(bytes_per_second) = self.bytes_per_second {
+            let refill =
+                now.duration_since(self.last_refill).as_secs_f64() * bytes_per_second as f64;
+            self.available_bytes = (self.available_bytes + refill).min(bytes_per_second as f64);
+        }
+        self.last_refill = now;
+    }
+}
+
+impl DownloadRateLimiter {
+    /// Creates a new limiter with the given limits.
+    ///
+    /// A limit of zero concurrent requests is raised to one, see
+    /// [`Self::set_max_concurrent_requests`].
+    pub fn new(bytes_per_second: Option<u64>, max_concurrent_requests: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(RateLimiterInner {
+                bytes_per_second,
+                max_concurrent_requests: max_concurrent_requests.max(1),
+                available_bytes: bytes_per_second.unwrap_or_default() as f64,
+                last_refill: Instant::now(),
+            })),
+        }
+    }
+
+    /// Returns the maximum number of bytes to download per second, `None` if unlimited.
+    pub fn bytes_per_second(&self) -> Option<u64> {
+        self.inner.lock().bytes_per_second
+    }
+
+    /// Sets the maximum number of bytes to download per second, `None` to disable the limit.
+    pub fn set_bytes_per_second(&self, bytes_per_second: Option<u64>) {
+        let mut inner = self.inner.lock();
+        inner.refill();
+        inner.bytes_per_second = bytes_per_second;
+        if let Some(bytes_per_second) = bytes_per_second {
+            inner.available_bytes = inner.available_bytes.min(bytes_per_second as f64);
+        } else {
+            inner.available_bytes = 0.;
+        }
+    }
+
+    /// Returns the maximum number of requests to have in flight.
+    pub fn max_concurrent_requests(&self) -> usize {
+        self.inner.lock().max_concurrent_requests
+    }
+
+    /// Sets the maximum number of requests to have in flight.
+    ///
+    /// Requests that are already in flight are not cancelled if the limit is lowered. The limit is
+    /// at least one, a limit of zero is raised to one so that downloads don't stall. To pause
+    /// downloads, lower the bandwidth limit instead.
+    pub fn set_max_concurrent_requests(&self, max_concurrent_requests: usize) {
+        self.inner.lock().max_concurrent_requests = max_concurrent_requests.max(1);
+    }
+
+    /// Records that `bytes` were downloaded.
+    pub(crate) fn record_downloaded(&self, bytes: usize) {
+        let mut inner = self.inner.lock();
+        if inner.bytes_per_second.is_some() {
+            inner.refill();
+            inner.available_bytes -= bytes as f64;
+        }
+    }
+
+    /// Returns the time to wait until a new request can be submitted, `None` if it can be
+    /// submitted right away.
+    fn delay(&self) -> Option<Duration> {
+        let mut inner = self.inner.lock();
+        let bytes_per_second = inner.bytes_per_second?;
+        inner.refill();
+
+        (inner.available_bytes < 0.).then(|| {
+            Duration::from_secs_f64(-inner.available_bytes / bytes_per_second.max(1) as f64)
+        })
+    }
+}
+
+impl Default for DownloadRateLimiter {
+    fn default() -> Self {
+        Self::new(None, usize::MAX)
+    }
+}
+
+/// The [`DownloadRateLimiter`] of a downloader, together with the timer that wakes the
+/// downloader once it's below the bandwidth limit again.
+#[derive(Debug, Default)]
+pub(crate) struct RateLimit {
+    limiter: DownloadRateLimiter,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl RateLimit {
+    /// Creates a new instance for the given limiter.
+    pub(crate) const fn new(limiter: DownloadRateLimiter) -> Self {
+        Self { limiter, sleep: None }
+    }
+
+    /// Returns the limiter.
+    pub(crate) const fn limiter(&self) -> &DownloadRateLimiter {
+        &self.limiter
+    }
+
+    /// Returns [`Poll::Ready`] if a new request can be submitted.
+    ///
+    /// Otherwise schedules a wakeup for when the downloader is below the bandwidth limit again.
+    pub(crate) fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        while let Some(delay) = self.limiter.delay() {
+            let deadline = tokio::time::Instant::now() + delay;
+            let sleep =
+                self.sleep.get_or_insert_with(|| Box::pin(tokio::time::sleep_until(deadline)));
+            sleep.as_mut().reset(deadline);
+            ready!(sleep.as_mut().poll(cx));
+        }
+
+        self.sleep = None;
+        Poll::Ready(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_bandwidth() {
+        let limiter = DownloadRateLimiter::new(Some(1_000), 10);
+        assert_eq!(limiter.delay(), None);
+
+        // A burst of up to one second worth of bytes is allowed.
+        limiter.record_downloaded(1_000);
+        assert_eq!(limiter.delay(), None);
+
+        // Going over the limit requires waiting until the bytes are refilled.
+        limiter.record_downloaded(500);
+        let delay = limiter.delay().unwrap();
+        assert!(delay > Duration::from_millis(400) && delay <= Duration::from_millis(500));
+
+        // Limits can be changed at runtime through any clone.
+        limiter.clone().set_bytes_per_second(None);
+        assert_eq!(limiter.delay(), None);
+        limiter.clone().set_max_concurrent_requests(2);
+        assert_eq!(limiter.max_concurrent_requests(), 2);
+    }
+
+    #[test]
+    fn allows_at_least_one_request() {
+        let limiter = DownloadRateLimiter::new(None, 0);
+        assert_eq!(limiter.max_concurrent_requests(), 1);
+
+        limiter.set_max_concurrent_requests(10);
+        limiter.set_max_concurrent_requests(0);
+        assert_eq!(limiter.max_concurrent_requests(), 1);
+    }
+}
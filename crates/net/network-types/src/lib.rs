@@ -27,7 +27,7 @@ pub use peers::{
     kind::PeerKind,
     reputation::{
         is_banned_reputation, is_connection_failed_reputation, ReputationChangeOutcome,
-        DEFAULT_REPUTATION,
+        DEFAULT_REPUTATION, DEFAULT_REPUTATION_RECOVERY_RATE,
     },
     state::PeerConnectionState,
     ConnectionsConfig, Peer, PeersConfig, PersistedPeerInfo,
@@ -5,7 +5,10 @@ use std::{collections::HashSet, time::Duration};
 use reth_net_banlist::{BanList, IpFilter};
 use reth_network_peers::{NodeRecord, TrustedPeer};
 
-use crate::{peers::PersistedPeerInfo, BackoffKind, ReputationChangeWeights};
+use crate::{
+    peers::{reputation::DEFAULT_REPUTATION_RECOVERY_RATE, PersistedPeerInfo},
+    BackoffKind, Reputation, ReputationChangeWeights,
+};
 
 /// Maximum number of available slots for outbound sessions.
 pub const DEFAULT_MAX_COUNT_PEERS_OUTBOUND: u32 = 100;
@@ -163,6 +166,9 @@ pub struct PeersConfig {
     pub connection_info: ConnectionsConfig,
     /// How to weigh reputation changes.
     pub reputation_weights: ReputationChangeWeights,
+    /// Reputation regained per second by connected peers with a negative reputation, until they
+    /// are back at the default reputation.
+    pub reputation_recovery_rate: Reputation,
     /// How long to backoff peers that we are failed to connect to for non-fatal reasons.
     ///
     /// The backoff duration increases with number of backoff attempts.
@@ -195,6 +201,7 @@ impl Default for PeersConfig {
             refill_slots_interval: Duration::from_millis(5_000),
             connection_info: Default::default(),
             reputation_weights: Default::default(),
+            reputation_recovery_rate: DEFAULT_REPUTATION_RECOVERY_RATE,
             ban_list: Default::default(),
             // Ban peers for 12h
             ban_duration: Duration::from_secs(60 * 60 * 12),
@@ -299,6 +306,16 @@ impl PeersConfig {
         self
     }
 
+    /// Configures the reputation regained per second by connected peers with a negative
+    /// reputation.
+    pub const fn with_reputation_recovery_rate(
+        mut self,
+        reputation_recovery_rate: Reputation,
+    ) -> Self {
+        self.reputation_recovery_rate = reputation_recovery_rate;
+        self
+    }
+
     /// Configures how long to backoff peers that are we failed to connect to for non-fatal reasons
     pub const fn with_backoff_durations(mut self, backoff_durations: PeerBackoffDurations) -> Self {
         self.backoff_durations = backoff_durations;
@@ -6,6 +6,9 @@ pub const DEFAULT_REPUTATION: Reputation = 0;
 /// The minimal unit we're measuring reputation
 const REPUTATION_UNIT: i32 = -1024;
 
+/// The default reputation regained per second by connected peers with a negative reputation.
+pub const DEFAULT_REPUTATION_RECOVERY_RATE: Reputation = 1;
+
 /// The reputation value below which new connection from/to peers are rejected.
 pub const BANNED_REPUTATION: i32 = 50 * REPUTATION_UNIT;
 
@@ -20,10 +20,11 @@ use reth_primitives_traits::Block;
 use reth_storage_api::{BalProvider, BlockReader, GetBlockAccessListLimit, HeaderProvider};
 use reth_transaction_pool::{blobstore::NoopBlobStore, BlobStore};
 use std::{
+    collections::HashMap,
     future::Future,
     pin::Pin,
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 use tokio::sync::{mpsc::Receiver, oneshot};
 use tokio_stream::wrappers::ReceiverStream;
@@ -51,6 +52,13 @@ pub const MAX_BODIES_SERVE: usize = 1024;
 /// Used to limit lookups.
 pub const MAX_BLOCK_ACCESS_LISTS_SERVE: usize = 1024;
 
+/// Maximum number of block access lists served to a single peer per
+/// [`BLOCK_ACCESS_LISTS_RATE_LIMIT_INTERVAL`].
+pub const MAX_BLOCK_ACCESS_LISTS_SERVE_PER_INTERVAL: usize = 4 * MAX_BLOCK_ACCESS_LISTS_SERVE;
+
+/// Interval over which the block access lists served to a peer are limited.
+pub const BLOCK_ACCESS_LISTS_RATE_LIMIT_INTERVAL: Duration = Duration::from_secs(10);
+
 /// Maximum number of cell lookups to serve.
 ///
 /// Used to limit lookups.
@@ -77,6 +85,8 @@ pub struct EthRequestHandler<C, N: NetworkPrimitives = EthNetworkPrimitives> {
     incoming_requests: ReceiverStream<IncomingEthRequest<N>>,
     /// Metrics for the eth request handler.
     metrics: EthRequestHandlerMetrics,
+    /// Limits the block access lists served to each peer.
+    bal_rate_limiter: BlockAccessListsRateLimiter,
 }
 
 // === impl EthRequestHandler ===
@@ -89,6 +99,10 @@ impl<C, N: NetworkPrimitives> EthRequestHandler<C, N> {
             peers,
             incoming_requests: ReceiverStream::new(incoming),
             metrics: Default::default(),
+            bal_rate_limiter: BlockAccessListsRateLimiter::new(
+                MAX_BLOCK_ACCESS_LISTS_SERVE_PER_INTERVAL,
+                BLOCK_ACCESS_LISTS_RATE_LIMIT_INTERVAL,
+            ),
         }
     }
 
@@ -97,6 +111,16 @@ impl<C, N: NetworkPrimitives> EthRequestHandler<C, N> {
         self.blob_store = blob_store;
         self
     }
+
+    /// Set the maximum number of block access lists served to a single peer per interval.
+    pub fn with_block_access_lists_rate_limit(
+        mut self,
+        max_per_interval: usize,
+        interval: Duration,
+    ) -> Self {
+        self.bal_rate_limiter = BlockAccessListsRateLimiter::new(max_per_interval, interval);
+        self
+    }
 }
 
 impl<C, N> EthRequestHandler<C, N>
@@ -365,15 +389,23 @@ where
     ///
     /// EIP-8159 defines the final `BlockAccessLists` response semantics:
     /// <https://eips.ethereum.org/EIPS/eip-8159>
+    ///
+    /// Peers exceeding their rate limit are served a truncated response.
     fn on_block_access_lists_request(
-        &self,
-        _peer_id: PeerId,
+        &mut self,
+        peer_id: PeerId,
         mut request: GetBlockAccessLists,
         response: oneshot::Sender<RequestResult<BlockAccessLists>>,
     ) {
         self.metrics.eth_block_access_lists_requests_received_total.increment(1);
         request.0.truncate(MAX_BLOCK_ACCESS_LISTS_SERVE);
 
+        let allowed = self.bal_rate_limiter.acquire(peer_id, request.0.len(), Instant::now());
+        if allowed < request.0.len() {
+            self.metrics.eth_block_access_lists_requests_rate_limited_total.increment(1);
+            request.0.truncate(allowed);
+        }
+
         let limit = GetBlockAccessListLimit::ResponseSizeSoftLimit(SOFT_RESPONSE_LIMIT);
         let access_lists =
             self.client.bal_store().get_by_hashes_with_limit(&request.0, limit).unwrap_or_default();
@@ -381,6 +413,47 @@ where
     }
 }
 
+/// Limits the number of block access lists served to each peer per interval.
+///
+/// Peers backfilling access lists request them in large batches, the limit keeps a single peer from
+/// monopolizing the lookups.
+#[derive(Debug)]
+struct BlockAccessListsRateLimiter {
+    /// Maximum number of access lists served to a peer per interval
+    max_per_interval: usize,
+    interval: Duration,
+    /// Access lists served to the peers in their current interval
+    peers: HashMap<PeerId, ServedAccessLists>,
+}
+
+#[derive(Debug)]
+struct ServedAccessLists {
+    interval_start: Instant,
+    served: usize,
+}
+
+impl BlockAccessListsRateLimiter {
+    const fn new(max_per_interval: usize, interval: Duration) -> Self {
+        Self { max_per_interval, interval, peers: HashMap::new() }
+    }
+
+    /// Returns how many of the requested access lists can be served to the peer, and counts them
+    /// as served.
+    fn acquire(&mut self, peer_id: PeerId, requested: usize, now: Instant) -> usize {
+        // forget the peers whose interval elapsed, including disconnected peers
+        let interval = self.interval;
+        self.peers.retain(|_, peer| now.saturating_duration_since(peer.interval_start) < interval);
+
+        let peer = self
+            .peers
+            .entry(peer_id)
+            .or_insert(ServedAccessLists { interval_start: now, served: 0 });
+        let allowed = requested.min(self.max_per_interval.saturating_sub(peer.served));
+        peer.served += allowed;
+        allowed
+    }
+}
+
 /// An endless future.
 ///
 /// This should be spawned or used as part of `tokio::select!`.
@@ -694,4 +767,21 @@ mod tests {
         assert!(cells.hashes.is_empty());
         assert_eq!(get_cells_calls.load(Ordering::Relaxed), MAX_CELLS_SERVE);
     }
+
+    #[test]
+    fn block_access_lists_rate_limit() {
+        let interval = Duration::from_secs(10);
+        let mut limiter = BlockAccessListsRateLimiter::new(10, interval);
+        let (peer, other) = (PeerId::random(), PeerId::random());
+        let now = Instant::now();
+
+        assert_eq!(limiter.acquire(peer, 6, now), 6);
+        assert_eq!(limiter.acquire(peer, 6, now), 4);
+        assert_eq!(limiter.acquire(peer, 1, now + interval / 2), 0);
+        assert_eq!(limiter.acquire(other, 6, now), 6);
+
+        // the limit resets once the interval elapsed
+        assert_eq!(limiter.acquire(peer, 6, now + interval), 6);
+        assert_eq!(limiter.peers.len(), 1);
+    }
 }
@@ -142,6 +142,7 @@ mod listener;
 mod manager;
 mod metrics;
 mod network;
+mod peer_list;
 mod required_block_filter;
 mod session;
 mod state;
@@ -170,6 +171,9 @@ pub use flattened_response::FlattenedResponse;
 pub use manager::NetworkManager;
 pub use metrics::TxTypesCounter;
 pub use network::{NetworkHandle, NetworkProtocols};
+pub use peer_list::{
+    parse_peer_list, PeerListError, PeerListWatcher, DEFAULT_PEER_LIST_RELOAD_INTERVAL,
+};
 pub use swarm::NetworkConnectionState;
 
 /// re-export p2p interfaces
@@ -560,6 +560,9 @@ pub struct EthRequestHandlerMetrics {
     /// Number of `GetBlockAccessLists` requests received
     pub(crate) eth_block_access_lists_requests_received_total: Counter,
 
+    /// Number of `GetBlockAccessLists` requests truncated by the per-peer rate limit
+    pub(crate) eth_block_access_lists_requests_rate_limited_total: Counter,
+
     /// Duration in seconds of call to poll
     /// [`EthRequestHandler`](crate::eth_requests::EthRequestHandler).
     pub(crate) acc_duration_poll_eth_req_handler: Gauge,
@@ -0,0 +1,387 @@
+//! Trusted and static peers listed in a file, reloaded at runtime.
+
+use alloy_primitives::map::HashMap;
+use reth_fs_util::FsPathError;
+use reth_network_api::Peers;
+use reth_network_peers::{NodeRecordParseError, PeerId, TrustedPeer};
+use reth_network_types::PeerKind;
+use std::{path::PathBuf, time::Duration};
+use tracing::{debug, info, warn};
+
+/// Default interval at which a [`PeerListWatcher`] checks its file for changes.
+pub const DEFAULT_PEER_LIST_RELOAD_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Errors when reading a peer list file.
+#[derive(Debug, thiserror::Error)]
+pub enum PeerListError {
+    /// The file couldn't be read.
+    #[error(transparent)]
+    Fs(#[from] FsPathError),
+    /// A line of the file isn't a valid enode URL.
+    #[error("invalid peer {peer:?} on line {line}: {err}")]
+    InvalidPeer {
+        /// Number of the line, starting at 1.
+        line: usize,
+        /// Content of the line.
+        peer: String,
+        /// The parse error.
+        err: NodeRecordParseError,
+    },
+}
+
+/// Parses a peer list, one enode URL per line.
+///
+/// The host of a peer can be a domain name. Empty lines and lines starting with `#` are ignored.
+pub fn parse_peer_list(content: &str) -> Result<Vec<TrustedPeer>, PeerListError> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line, peer)| {
+            peer.parse().map_err(|err| PeerListError::InvalidPeer {
+                line,
+                peer: peer.to_string(),
+                err,
+            })
+        })
+        .collect()
+}
+
+/// Keeps the trusted or static peers of the network in sync with a peer list file, see
+/// [`parse_peer_list`].
+///
+/// The file is checked for changes periodically, by its modification time. Peers added to the file
+/// are added to the network with the kind of the list and dialed, peers removed from the file are
+/// removed from the peer set:
+///  - removed trusted peers become basic peers, their sessions are kept
+///  - removed static peers are disconnected
+///
+/// If the file can't be read or contains an invalid line, the peers are kept as they are until the
+/// file is fixed. Static peers whose host can't be resolved are retried on every check until they
+/// are added.
+#[derive(Debug)]
+pub struct PeerListWatcher<N> {
+    network: N,
+    path: PathBuf,
+    /// The kind of the listed peers, [`PeerKind::Trusted`] or [`PeerKind::Static`]
+    kind: PeerKind,
+    interval: Duration,
+    /// The peers of the file when it was last loaded
+    peers: HashMap<PeerId, TrustedPeer>,
+    /// The modification time of the file when it was last loaded
+    modified: Option<std::time::SystemTime>,
+}
+
+impl<N: Peers> PeerListWatcher<N> {
+    /// Creates a new watcher adding the peers listed in the file to the network with the given
+    /// kind.
+    pub fn new(network: N, path: impl Into<PathBuf>, kind: PeerKind) -> Self {
+        Self {
+            network,
+            path: path.into(),
+            kind,
+            interval: DEFAULT_PEER_LIST_RELOAD_INTERVAL,
+            peers: HashMap::default(),
+            modified: None,
+        }
+    }
+
+    /// Sets the interval at which the file is checked for changes.
+    pub const fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Loads the file and reloads it whenever it changes.
+    ///
+    /// This never returns and should be spawned.
+    pub async fn run(mut self) {
+        let mut interval = tokio::time::interval(self.interval);
+        loop {
+            interval.tick().await;
+            if let Err(err) = self.reload_if_modified().await {
+                warn!(target: "net::peers", path=?self.path, %err, "Failed to reload peer list");
+            }
+        }
+    }
+
+    /// Reloads the file if it was modified since it was last loaded.
+    pub async fn reload_if_modified(&mut self) -> Result<(), PeerListError> {
+        let modified = reth_fs_util::metadata(&self.path)?
+            .modified()
+            .map_err(|err| FsPathError::metadata(err, &self.path))?;
+        if self.modified == Some(modified) {
+            return Ok(())
+        }
+        // the file is reloaded on the next check if some peers couldn't be added
+        if self.reload().await? {
+            self.modified = Some(modified);
+        }
+        Ok(())
+    }
+
+    /// Reloads the file, adding the peers added to the file and removing the peers removed from
+    /// it.
+    ///
+    /// Returns `false` if some peers couldn't be added, they are added by the next reload.
+    pub async fn reload(&mut self) -> Result<bool, PeerListError> {
+        let peers = parse_peer_list(&reth_fs_util::read_to_string(&self.path)?)?;
+        let peers: HashMap<_, _> = peers.into_iter().map(|peer| (peer.id, peer)).collect();
+
+        for peer_id in self.peers.keys().filter(|peer_id| !peers.contains_key(*peer_id)) {
+            debug!(target: "net::peers", ?peer_id, kind=?self.kind, "Removing peer from list");
+            self.network.remove_peer(*peer_id, self.kind);
+        }
+        // only the added peers are recorded, so that the others are added by the next reload
+        let mut added = HashMap::default();
+        let mut complete = true;
+        for (peer_id, peer) in &peers {
+            let mut entry = Some(peer);
+            // peers whose address changed are added again
+            if self.peers.get(peer_id) != Some(peer) {
+                debug!(target: "net::peers", ?peer, kind=?self.kind, "Adding peer from list");
+                if !self.add_peer(peer).await {
+                    complete = false;
+                    // keep the previous address, so that the peer is still removed with the file
+                    // entry
+                    entry = self.peers.get(peer_id);
+                }
+            }
+            if let Some(entry) = entry {
+                added.insert(*peer_id, entry.clone());
+            }
+        }
+
+        info!(target: "net::peers", path=?self.path, kind=?self.kind, peers=peers.len(), "Loaded peer list");
+        self.peers = added;
+        Ok(complete)
+    }
+
+    /// Adds the peer to the network, returns `false` if its host couldn't be resolved.
+    async fn add_peer(&self, peer: &TrustedPeer) -> bool {
+        if self.kind.is_trusted() {
+            // resolved and dialed by the network, which also re-resolves domain names
+            self.network.add_trusted_peer_node(peer.clone());
+            return true
+        }
+
+        match peer.resolve().await {
+            Ok(record) => {
+                self.network.connect_peer_kind(
+                    record.id,
+                    self.kind,
+                    record.tcp_addr(),
+                    Some(record.udp_addr()),
+                );
+                true
+            }
+            Err(err) => {
+                warn!(target: "net::peers", ?peer, %err, "Failed to resolve peer from list");
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex;
+    use reth_eth_wire::DisconnectReason;
+    use reth_network_api::{NetworkError, PeerInfo, PeersInfo, Reputation, ReputationChangeKind};
+    use reth_network_peers::NodeRecord;
+    use std::{net::SocketAddr, sync::Arc};
+
+    /// Records the peers added to and removed from the network.
+    #[derive(Debug, Default)]
+    struct MockPeers {
+        added: parking_lot::Mutex<Vec<(PeerId, PeerKind)>>,
+        removed: parking_lot::Mutex<Vec<(PeerId, PeerKind)>>,
+    }
+
+    impl MockPeers {
+        fn take(&self) -> (Vec<(PeerId, PeerKind)>, Vec<(PeerId, PeerKind)>) {
+            (std::mem::take(&mut *self.added.lock()), std::mem::take(&mut *self.removed.lock()))
+        }
+    }
+
+    impl PeersInfo for MockPeers {
+        fn num_connected_peers(&self) -> usize {
+            unimplemented!()
+        }
+
+        fn local_node_record(&self) -> NodeRecord {
+            unimplemented!()
+        }
+
+        fn local_enr(&self) -> enr::Enr<enr::secp256k1::SecretKey> {
+            unimplemented!()
+        }
+    }
+
+    impl Peers for MockPeers {
+        fn add_trusted_peer_id(&self, _peer: PeerId) {
+            unimplemented!()
+        }
+
+        fn add_trusted_peer_node(&self, peer: TrustedPeer) {
+            self.added.lock().push((peer.id, PeerKind::Trusted));
+        }
+
+        fn add_peer_kind(
+            &self,
+            _peer: PeerId,
+            _kind: Option<PeerKind>,
+            _tcp_addr: SocketAddr,
+            _udp_addr: Option<SocketAddr>,
+        ) {
+            unimplemented!()
+        }
+
+        async fn get_peers_by_kind(&self, _kind: PeerKind) -> Result<Vec<PeerInfo>, NetworkError> {
+            unimplemented!()
+        }
+
+        async fn get_all_peers(&self) -> Result<Vec<PeerInfo>, NetworkError> {
+            unimplemented!()
+        }
+
+        async fn get_peer_by_id(&self, _peer_id: PeerId) -> Result<Option<PeerInfo>, NetworkError> {
+            unimplemented!()
+        }
+
+        async fn get_peers_by_id(
+            &self,
+            _peer_ids: Vec<PeerId>,
+        ) -> Result<Vec<PeerInfo>, NetworkError> {
+            unimplemented!()
+        }
+
+        fn remove_peer(&self, peer: PeerId, kind: PeerKind) {
+            self.removed.lock().push((peer, kind));
+        }
+
+        fn disconnect_peer(&self, _peer: PeerId) {
+            unimplemented!()
+        }
+
+        fn disconnect_peer_with_reason(&self, _peer: PeerId, _reason: DisconnectReason) {
+            unimplemented!()
+        }
+
+        fn ban_peer(&self, _peer: PeerId) {
+            unimplemented!()
+        }
+
+        fn unban_peer(&self, _peer: PeerId) {
+            unimplemented!()
+        }
+
+        fn connect_peer_kind(
+            &self,
+            peer: PeerId,
+            kind: PeerKind,
+            _tcp_addr: SocketAddr,
+            _udp_addr: Option<SocketAddr>,
+        ) {
+            self.added.lock().push((peer, kind));
+        }
+
+        fn reputation_change(&self, _peer_id: PeerId, _kind: ReputationChangeKind) {
+            unimplemented!()
+        }
+
+        async fn reputation_by_id(
+            &self,
+            _peer_id: PeerId,
+        ) -> Result<Option<Reputation>, NetworkError> {
+            unimplemented!()
+        }
+    }
+
+    fn enode(id: PeerId, host: &str) -> String {
+        format!("enode://{}@{host}:30303", hex::encode(id))
+    }
+
+    #[test]
+    fn parse_peers() {
+        let content = "
+            # bootnode
+            enode://6f8a80d14311c39f35f516fa664deaaaa13e85b2f7493f37f6144d86991ec012937307647bd3b9a82abe2974e1407241d54947bbb39763a4cac9f77166ad92a0@10.3.58.6:30303
+
+            enode://6f8a80d14311c39f35f516fa664deaaaa13e85b2f7493f37f6144d86991ec012937307647bd3b9a82abe2974e1407241d54947bbb39763a4cac9f77166ad92a0@localhost:30304?discport=30301
+        ";
+        let peers = parse_peer_list(content).unwrap();
+        assert_eq!(peers.len(), 2);
+        assert_eq!(peers[1].tcp_port, 30304);
+        assert_eq!(peers[1].udp_port, 30301);
+
+        assert!(matches!(
+            parse_peer_list("# peers\nenode://invalid"),
+            Err(PeerListError::InvalidPeer { line: 2, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn reload_static_peers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("static-peers");
+        let network = Arc::new(MockPeers::default());
+        let mut watcher = PeerListWatcher::new(network.clone(), &path, PeerKind::Static);
+        let (a, b, unresolved) = (PeerId::random(), PeerId::random(), PeerId::random());
+
+        std::fs::write(
+            &path,
+            [enode(a, "127.0.0.1"), enode(unresolved, "peer.invalid")].join("\n"),
+        )
+        .unwrap();
+        watcher.reload_if_modified().await.unwrap();
+        assert_eq!(network.take(), (vec![(a, PeerKind::Static)], vec![]));
+
+        // the unresolved peer is retried although the file is unchanged
+        assert!(watcher.modified.is_none());
+        watcher.reload_if_modified().await.unwrap();
+        assert_eq!(network.take(), (vec![], vec![]));
+
+        // the unresolved peer was never added, so only the removed peer is removed
+        std::fs::write(&path, enode(b, "127.0.0.1")).unwrap();
+        assert!(watcher.reload().await.unwrap());
+        assert_eq!(network.take(), (vec![(b, PeerKind::Static)], vec![(a, PeerKind::Static)]));
+
+        // peers whose address changed are added again
+        std::fs::write(&path, enode(b, "127.0.0.2")).unwrap();
+        assert!(watcher.reload().await.unwrap());
+        assert_eq!(network.take(), (vec![(b, PeerKind::Static)], vec![]));
+
+        // a peer whose new address can't be resolved is still removed with its entry
+        std::fs::write(&path, enode(b, "peer.invalid")).unwrap();
+        assert!(!watcher.reload().await.unwrap());
+        std::fs::write(&path, "").unwrap();
+        assert!(watcher.reload().await.unwrap());
+        assert_eq!(network.take(), (vec![], vec![(b, PeerKind::Static)]));
+    }
+
+    #[tokio::test]
+    async fn reload_trusted_peers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trusted-peers");
+        let network = Arc::new(MockPeers::default());
+        let mut watcher = PeerListWatcher::new(network.clone(), &path, PeerKind::Trusted);
+        let (a, b) = (PeerId::random(), PeerId::random());
+
+        // domain names of trusted peers are resolved by the network
+        std::fs::write(&path, [enode(a, "127.0.0.1"), enode(b, "peer.invalid")].join("\n"))
+            .unwrap();
+        watcher.reload_if_modified().await.unwrap();
+        let (added, removed) = network.take();
+        assert_eq!(added.len(), 2);
+        assert!(added.contains(&(a, PeerKind::Trusted)) && added.contains(&(b, PeerKind::Trusted)));
+        assert!(removed.is_empty());
+        assert!(watcher.modified.is_some());
+
+        std::fs::write(&path, enode(b, "peer.invalid")).unwrap();
+        assert!(watcher.reload().await.unwrap());
+        assert_eq!(network.take(), (vec![], vec![(a, PeerKind::Trusted)]));
+    }
+}
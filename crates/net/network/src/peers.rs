@@ -69,6 +69,8 @@ pub struct PeersManager {
     refill_slots_interval: Interval,
     /// How to weigh reputation changes
     reputation_weights: ReputationChangeWeights,
+    /// Reputation regained per second by connected peers with a negative reputation
+    reputation_recovery_rate: i32,
     /// Tracks current slot stats.
     connection_info: ConnectionInfo,
     /// Tracks unwanted ips/peer ids.
@@ -112,6 +114,7 @@ impl PeersManager {
             refill_slots_interval,
             connection_info,
             reputation_weights,
+            reputation_recovery_rate,
             ban_list,
             ban_duration,
             backoff_durations,
@@ -191,6 +194,7 @@ impl PeersManager {
             handle_rx: UnboundedReceiverStream::new(handle_rx),
             queued_actions: Default::default(),
             reputation_weights,
+            reputation_recovery_rate,
             refill_slots_interval: tokio::time::interval(refill_slots_interval),
             release_interval: tokio::time::interval_at(now + unban_interval, unban_interval),
             connection_info: ConnectionInfo::new(connection_info),
@@ -523,12 +527,15 @@ impl PeersManager {
             if self.last_tick > now { 0 } else { (now - self.last_tick).as_secs() as i32 };
         self.last_tick = now;
 
+        let recovered = secs_since_last_tick.saturating_mul(self.reputation_recovery_rate);
+
         // update reputation via seconds connected
         for peer in self.peers.iter_mut().filter(|(_, peer)| peer.state.is_connected()) {
             // update reputation via seconds connected, but keep the target _around_ the default
             // reputation.
             if peer.1.reputation < DEFAULT_REPUTATION {
-                peer.1.reputation += secs_since_last_tick;
+                peer.1.reputation =
+                    peer.1.reputation.saturating_add(recovered).min(DEFAULT_REPUTATION);
             }
         }
     }
@@ -2926,6 +2933,28 @@ mod tests {
         assert!(peer_manager.peers.get_mut(&peer_id).unwrap().reputation >= DEFAULT_REPUTATION);
     }
 
+    #[tokio::test]
+    async fn test_tick_reputation_recovery_rate() {
+        let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8008);
+        let config = PeersConfig::test().with_reputation_recovery_rate(100);
+        let mut peer_manager = PeersManager::new(config);
+        let peer_id = PeerId::random();
+        peer_manager.add_peer(peer_id, PeerAddr::from_tcp(socket_addr), None);
+
+        let peer = peer_manager.peers.get_mut(&peer_id).unwrap();
+        peer.state = PeerConnectionState::Out;
+        peer.reputation = -1000;
+
+        peer_manager.last_tick = Instant::now() - Duration::from_secs(2);
+        peer_manager.tick();
+        assert_eq!(peer_manager.peers.get(&peer_id).unwrap().reputation, -800);
+
+        // recovery stops at the default reputation
+        peer_manager.last_tick = Instant::now() - Duration::from_secs(10);
+        peer_manager.tick();
+        assert_eq!(peer_manager.peers.get(&peer_id).unwrap().reputation, DEFAULT_REPUTATION);
+    }
+
     #[tokio::test]
     async fn test_remove_incoming_after_disconnect() {
         let peer_id = PeerId::random();
@@ -6,6 +6,7 @@ use alloy_eips::BlockHashOrNumber;
 use alloy_primitives::{BlockNumber, B256};
 use derive_more::{Display, Error};
 use reth_consensus::ConsensusError;
+use reth_errors::{NetworkError, NetworkErrorKind, RethError};
 use reth_network_peers::WithPeerId;
 use reth_network_types::ReputationChangeKind;
 use reth_primitives_traits::{GotExpected, GotExpectedBoxed};
@@ -115,6 +116,25 @@ impl RequestError {
     }
 }
 
+impl From<RequestError> for NetworkError {
+    fn from(error: RequestError) -> Self {
+        let kind = match error {
+            RequestError::ChannelClosed => NetworkErrorKind::ChannelClosed,
+            RequestError::ConnectionDropped => NetworkErrorKind::PeerDisconnected,
+            RequestError::UnsupportedCapability => NetworkErrorKind::UnsupportedCapability,
+            RequestError::Timeout => NetworkErrorKind::Timeout,
+            RequestError::BadResponse => NetworkErrorKind::BadResponse,
+        };
+        Self::new(kind, error)
+    }
+}
+
+impl From<RequestError> for RethError {
+    fn from(error: RequestError) -> Self {
+        NetworkError::from(error).into()
+    }
+}
+
 impl<T> From<mpsc::error::SendError<T>> for RequestError {
     fn from(_: mpsc::error::SendError<T>) -> Self {
         Self::ChannelClosed
@@ -215,6 +235,28 @@ impl From<ProviderError> for DownloadError {
     }
 }
 
+impl From<DownloadError> for RethError {
+    fn from(error: DownloadError) -> Self {
+        let kind = match error {
+            DownloadError::Provider(error) => return error.into(),
+            DownloadError::RequestError(error) => return error.into(),
+            DownloadError::Timeout => NetworkErrorKind::Timeout,
+            DownloadError::HeaderValidation { .. } |
+            DownloadError::InvalidTip(_) |
+            DownloadError::InvalidTipNumber(_) |
+            DownloadError::HeadersResponseStartBlockMismatch(_) |
+            DownloadError::HeadersResponseTooShort(_) |
+            DownloadError::BodyValidation { .. } |
+            DownloadError::TooManyBodies(_) |
+            DownloadError::EmptyResponse => NetworkErrorKind::BadResponse,
+            DownloadError::MissingHeader { .. } | DownloadError::InvalidBodyRange { .. } => {
+                NetworkErrorKind::Download
+            }
+        };
+        NetworkError::new(kind, error).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloy_consensus::Header;
@@ -233,4 +275,24 @@ mod tests {
         let headers: Vec<Header> = vec![];
         assert!(Ok(headers).is_likely_bad_headers_response(&request));
     }
+
+    #[test]
+    fn test_into_reth_error() {
+        let error = RethError::from(DownloadError::RequestError(RequestError::ConnectionDropped));
+        assert!(matches!(
+            &error,
+            RethError::Network(err) if err.kind == NetworkErrorKind::PeerDisconnected
+        ));
+        assert!(error.is_retryable());
+
+        let error = RethError::from(DownloadError::EmptyResponse);
+        assert!(matches!(
+            &error,
+            RethError::Network(err) if err.kind == NetworkErrorKind::BadResponse
+        ));
+        assert!(!error.is_retryable());
+
+        let error = RethError::from(DownloadError::Provider(ProviderError::BestBlockNotFound));
+        assert!(matches!(error, RethError::Provider(ProviderError::BestBlockNotFound)));
+    }
 }
@@ -12,6 +12,7 @@ use reth_node_core::node_config::NodeConfig;
 use reth_node_types::{NodeTypes, NodeTypesWithDBAdapter, TxTy};
 use reth_payload_builder::PayloadBuilderHandle;
 use reth_provider::FullProvider;
+use reth_stages_api::{PipelineEventSubscriptions, PipelineHandle};
 use reth_tasks::TaskExecutor;
 use reth_tokio_util::EventSender;
 use reth_transaction_pool::{PoolTransaction, TransactionPool};
@@ -113,6 +114,10 @@ pub struct AddOnsContext<'a, N: FullNodeComponents> {
     pub beacon_engine_handle: ConsensusEngineHandle<<N::Types as NodeTypes>::Payload>,
     /// Notification channel for engine API events
     pub engine_events: EventSender<ConsensusEngineEvent<<N::Types as NodeTypes>::Primitives>>,
+    /// Subscriptions to the events of the sync pipeline.
+    pub pipeline_events: PipelineEventSubscriptions,
+    /// Handle to pause, resume or abort the sync pipeline.
+    pub pipeline_handle: PipelineHandle,
     /// JWT secret for the node.
     pub jwt_secret: JwtSecret,
 }
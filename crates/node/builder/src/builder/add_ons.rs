@@ -1,9 +1,21 @@
 //! Node add-ons. Depend on core [`NodeComponents`](crate::NodeComponents).
 
-use reth_node_api::{FullNodeComponents, NodeAddOns};
+use reth_db_api::database::Database;
+use reth_node_api::{FullNodeComponents, FullNodeTypes, NodeAddOns, NodeTypesWithDBAdapter};
+use reth_provider::DatabaseProvider;
+use reth_stages::{Stage, StageId};
 
 use crate::{exex::BoxedLaunchExEx, hooks::NodeHooks};
 
+/// The read-write database provider the stages of the node's pipeline operate on.
+pub type PipelineProvider<Node> = DatabaseProvider<
+    <<Node as FullNodeTypes>::DB as Database>::TXMut,
+    NodeTypesWithDBAdapter<<Node as FullNodeTypes>::Types, <Node as FullNodeTypes>::DB>,
+>;
+
+/// A custom stage of the node's pipeline.
+pub type BoxedPipelineStage<Node> = Box<dyn Stage<PipelineProvider<Node>>>;
+
 /// Additional node extensions.
 ///
 /// At this point we consider all necessary components defined.
@@ -12,6 +24,8 @@ pub struct AddOns<Node: FullNodeComponents, AddOns: NodeAddOns<Node>> {
     pub hooks: NodeHooks<Node, AddOns>,
     /// The `ExExs` (execution extensions) of the node.
     pub exexs: Vec<(String, Box<dyn BoxedLaunchExEx<Node>>)>,
+    /// Custom pipeline stages, each inserted after the stage with the given [`StageId`].
+    pub stages: Vec<(StageId, BoxedPipelineStage<Node>)>,
     /// Additional captured addons.
     pub add_ons: AddOns,
 }
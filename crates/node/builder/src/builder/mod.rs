@@ -7,8 +7,9 @@ use crate::{
     common::WithConfigs,
     components::NodeComponentsBuilder,
     node::FullNode,
-    rpc::{RethRpcAddOns, RethRpcServerHandles, RpcContext},
+    rpc::{NativeTracer, RethRpcAddOns, RethRpcServerHandles, RpcContext},
     BlockReaderFor, DebugNode, DebugNodeLauncher, EngineNodeLauncher, LaunchNode, Node,
+    PipelineProvider,
 };
 use alloy_eips::eip4844::env_settings::EnvKzgSettings;
 use futures::Future;
@@ -20,8 +21,9 @@ use reth_network::{
         config::{AnnouncementFilteringPolicy, StrictEthAnnouncementFilter},
         TransactionPropagationPolicy, TransactionsManagerConfig,
     },
+    types::PeerKind,
     NetworkBuilder, NetworkConfig, NetworkConfigBuilder, NetworkHandle, NetworkManager,
-    NetworkPrimitives,
+    NetworkPrimitives, PeerListWatcher,
 };
 use reth_node_api::{
     FullNodeTypes, FullNodeTypesAdapter, NodeAddOns, NodeTypes, NodeTypesWithDBAdapter,
@@ -36,6 +38,7 @@ use reth_provider::{
     providers::{BlockchainProvider, NodeTypesForProvider, RocksDBProvider},
     ChainSpecProvider, FullProvider,
 };
+use reth_stages::{Stage, StageId};
 use reth_tasks::TaskExecutor;
 use reth_transaction_pool::{PoolConfig, PoolTransaction, TransactionPool};
 use secp256k1::SecretKey;
@@ -230,6 +233,9 @@ impl<DB, ChainSpec> NodeBuilder<DB, ChainSpec> {
 
 impl<DB, ChainSpec: EthChainSpec> NodeBuilder<DB, ChainSpec> {
     /// Configures the underlying database that the node will use.
+    ///
+    /// Any [`Database`] implementation can be used, e.g. the MDBX `DatabaseEnv` or the
+    /// `InMemoryDatabase` of `reth_db::mem`, without changes to the providers.
     pub fn with_database<D>(self, database: D) -> NodeBuilder<D, ChainSpec> {
         NodeBuilder { config: self.config, database, rocksdb_provider: self.rocksdb_provider }
     }
@@ -637,6 +643,19 @@ where
         Self { builder: self.builder.extend_rpc_modules(hook), task_executor: self.task_executor }
     }
 
+    /// Registers a native tracer of the `debug` namespace, selectable by name with the `tracer`
+    /// field of the `debug_trace*` requests.
+    pub fn with_native_tracer(
+        self,
+        name: impl Into<String>,
+        tracer: impl NativeTracer<AO::EthApi>,
+    ) -> Self {
+        Self {
+            builder: self.builder.with_native_tracer(name, tracer),
+            task_executor: self.task_executor,
+        }
+    }
+
     /// Installs an `ExEx` (Execution Extension) in the node.
     ///
     /// # Note
@@ -672,6 +691,20 @@ where
         }
     }
 
+    /// Adds a custom stage to the node's pipeline, right after the stage with the given
+    /// [`StageId`].
+    ///
+    /// See [`NodeBuilderWithComponents::add_stage_after`].
+    pub fn add_stage_after<S>(self, after: StageId, stage: S) -> Self
+    where
+        S: Stage<PipelineProvider<NodeAdapter<T, CB::Components>>> + 'static,
+    {
+        Self {
+            builder: self.builder.add_stage_after(after, stage),
+            task_executor: self.task_executor,
+        }
+    }
+
     /// Launches the node with the given launcher.
     pub async fn launch_with<L>(self, launcher: L) -> eyre::Result<L::Node>
     where
@@ -948,6 +981,18 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
             },
         );
 
+        // keep the trusted and static peers in sync with their files
+        let peer_lists = [
+            (&self.config().network.trusted_peers_file, PeerKind::Trusted),
+            (&self.config().network.static_peers_file, PeerKind::Static),
+        ];
+        for (path, kind) in peer_lists {
+            if let Some(path) = path {
+                self.executor
+                    .spawn_task(PeerListWatcher::new(handle.clone(), path.clone(), kind).run());
+            }
+        }
+
         handle
     }
 
@@ -9,14 +9,15 @@ use crate::{
     components::{NodeComponents, NodeComponentsBuilder},
     hooks::NodeHooks,
     launch::LaunchNode,
-    rpc::{RethRpcAddOns, RethRpcServerHandles, RpcContext},
-    AddOns, ComponentsFor, FullNode,
+    rpc::{NativeTracer, RethRpcAddOns, RethRpcServerHandles, RpcContext},
+    AddOns, ComponentsFor, FullNode, PipelineProvider,
 };
 
 use reth_exex::ExExContext;
 use reth_node_api::{FullNodeComponents, FullNodeTypes, NodeAddOns, NodeTypes};
 use reth_node_core::node_config::NodeConfig;
 use reth_provider::providers::RocksDBProvider;
+use reth_stages::{Stage, StageId};
 use reth_tasks::TaskExecutor;
 use std::{fmt, fmt::Debug, future::Future};
 
@@ -52,7 +53,12 @@ impl<T: FullNodeTypes> NodeBuilderWithTypes<T> {
             adapter,
             rocksdb_provider,
             components_builder,
-            add_ons: AddOns { hooks: NodeHooks::default(), exexs: Vec::new(), add_ons: () },
+            add_ons: AddOns {
+                hooks: NodeHooks::default(),
+                exexs: Vec::new(),
+                stages: Vec::new(),
+                add_ons: (),
+            },
         }
     }
 }
@@ -181,7 +187,12 @@ where
             adapter,
             rocksdb_provider,
             components_builder,
-            add_ons: AddOns { hooks: NodeHooks::default(), exexs: Vec::new(), add_ons },
+            add_ons: AddOns {
+                hooks: NodeHooks::default(),
+                exexs: Vec::new(),
+                stages: Vec::new(),
+                add_ons,
+            },
         }
     }
 }
@@ -227,6 +238,19 @@ where
         self
     }
 
+    /// Adds a custom stage to the node's pipeline, right after the stage with the given
+    /// [`StageId`].
+    ///
+    /// The stage is checkpointed and unwound by the pipeline like the default stages, so its id
+    /// must be unique. Launching the node fails if the given stage is not part of the pipeline.
+    pub fn add_stage_after<S>(mut self, after: StageId, stage: S) -> Self
+    where
+        S: Stage<PipelineProvider<NodeAdapter<T, CB::Components>>> + 'static,
+    {
+        self.add_ons.stages.push((after, Box::new(stage)));
+        self
+    }
+
     /// Launches the node with the given closure.
     pub fn launch_with_fn<L, R>(self, launcher: L) -> R
     where
@@ -315,6 +339,19 @@ where
             add_ons
         })
     }
+
+    /// Registers a native tracer of the `debug` namespace, selectable by name with the `tracer`
+    /// field of the `debug_trace*` requests.
+    pub fn with_native_tracer(
+        self,
+        name: impl Into<String>,
+        tracer: impl NativeTracer<AO::EthApi>,
+    ) -> Self {
+        self.map_add_ons(|mut add_ons| {
+            add_ons.hooks_mut().native_tracers.register(name, tracer);
+            add_ons
+        })
+    }
 }
 
 #[cfg(test)]
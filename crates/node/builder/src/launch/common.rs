@@ -34,10 +34,12 @@ use crate::{
     hooks::OnComponentInitializedHook,
     BuilderContext, ExExLauncher, NodeAdapter, PrimitivesTy,
 };
+use alloy_consensus::{Transaction, TxReceipt};
 use alloy_eips::eip2124::Head;
 use alloy_primitives::{BlockNumber, B256};
 use eyre::Context;
 use rayon::ThreadPoolBuilder;
+use reth_chain_state::ForkChoiceSubscriptions;
 use reth_chainspec::{Chain, EthChainSpec, EthereumHardforks};
 use reth_config::{config::EtlConfig, PruneConfig};
 use reth_consensus::noop::NoopConsensus;
@@ -67,13 +69,22 @@ use reth_node_metrics::{
     storage::StorageSettingsInfo,
     version::VersionInfo,
 };
+use reth_primitives_traits::{BlockBody, SignedTransaction};
 use reth_provider::{
-    providers::{NodeTypesForProvider, ProviderNodeTypes, RocksDBProvider, StaticFileProvider},
-    BalConfig, BalStoreHandle, BlockHashReader, BlockNumReader, InMemoryBalStore, ProviderError,
-    ProviderFactory, ProviderResult, RocksDBProviderFactory, StageCheckpointReader,
-    StaticFileProviderBuilder, StaticFileProviderFactory, StorageSettingsCache,
+    growth::{available_disk_space, StorageGrowthTracker, StorageSample},
+    providers::{
+        DirectoryColdStorage, NodeTypesForProvider, ProviderNodeTypes, RocksDBProvider,
+        StaticFileProvider,
+    },
+    BalConfig, BalStoreHandle, BlobSidecarStage, BlockHashReader, BlockNumReader, BlockReader,
+    CanonStateSubscriptions, InMemoryBalStore, LogIndexProvider, LogIndexWriter, ProviderError,
+    ProviderFactory, ProviderResult, PruneCheckpointReader, ReceiptProvider,
+    RocksDBProviderFactory, StageCheckpointReader, StateProviderFactory, StaticFileProviderBuilder,
+    StaticFileProviderFactory, StorageSettingsCache, TraceAddressIndexProvider,
+    TraceAddressIndexWriter, TransactionVariant,
 };
 use reth_prune::{PruneModes, PrunerBuilder};
+use reth_rpc::trace_filter_addresses;
 use reth_rpc_builder::config::RethRpcServerConfig;
 use reth_rpc_layer::JwtSecret;
 use reth_stages::{
@@ -175,8 +186,9 @@ impl LaunchContext {
         toml_config.peers.trusted_nodes_only |= config.network.trusted_only;
 
         // Merge static file CLI arguments with config file, giving priority to CLI
-        toml_config.static_files =
-            config.static_files.merge_with_config(toml_config.static_files, config.pruning.minimal);
+        toml_config.static_files = config
+            .static_files
+            .merge_with_config(toml_config.static_files, config.pruning.is_minimal());
 
         Ok(toml_config)
     }
@@ -487,13 +499,52 @@ where
         let static_files_config = &self.toml_config().static_files;
         static_files_config.validate()?;
 
-        // Apply per-segment blocks_per_file configuration
-        let static_file_provider =
+        // Apply per-segment blocks_per_file and compression configuration
+        let mut static_file_provider_builder =
             StaticFileProviderBuilder::read_write(self.data_dir().static_files())
                 .with_metrics()
                 .with_blocks_per_file_for_segments(&static_files_config.as_blocks_per_file_map())
-                .with_genesis_block_number(self.chain_spec().genesis().number.unwrap_or_default())
-                .build()?;
+                .with_compression_for_segments(&static_files_config.as_compression_map())
+                .with_genesis_block_number(self.chain_spec().genesis().number.unwrap_or_default());
+        if self.node_config().static_files.verify_checksums {
+            static_file_provider_builder =
+                static_file_provider_builder.with_checksum_verification();
+        }
+        if static_files_config.compact_receipts {
+            static_file_provider_builder = static_file_provider_builder.with_compact_receipts();
+        }
+        if let Some(cold_storage) = &static_files_config.cold_storage {
+            static_file_provider_builder = static_file_provider_builder.with_cold_storage(
+                Arc::new(DirectoryColdStorage::new(&cold_storage.path)),
+                cold_storage.below_block,
+                cold_storage.max_cached_files,
+            );
+        }
+        let static_file_provider = static_file_provider_builder.build()?;
+
+        if static_files_config.cold_storage.is_some() {
+            // Moving static files to the secondary directory can take a while, don't block the
+            // launch on it.
+            let static_file_provider = static_file_provider.clone();
+            self.task_executor().spawn_blocking(move || {
+                match static_file_provider.offload_cold_static_files() {
+                    Ok(offloaded) => {
+                        info!(
+                            target: "reth::cli",
+                            offloaded,
+                            "Moved static files to the secondary directory"
+                        )
+                    }
+                    Err(err) => {
+                        warn!(
+                            target: "reth::cli",
+                            %err,
+                            "Failed to move static files to the secondary directory"
+                        )
+                    }
+                }
+            });
+        }
 
         // Use the provided RocksDB provider or create a new one
         let rocksdb_provider = if let Some(provider) = rocksdb_provider {
@@ -526,6 +577,12 @@ where
         .with_minimum_pruning_distance(prune_config.minimum_pruning_distance)
         .with_changeset_cache(changeset_cache)
         .with_bal_store(bal_store);
+        let factory = match self.toml_config().static_files.blob_sidecars {
+            Some(config) => {
+                factory.with_blob_sidecar_stage(BlobSidecarStage::new(config.retention_blocks))
+            }
+            None => factory,
+        };
 
         // Check consistency between the database and static files, returning
         // the unwind targets for each storage layer if inconsistencies are
@@ -591,6 +648,22 @@ where
             })?;
         }
 
+        // A pruning preset must not retain data that was already pruned from the database.
+        if let Some(preset) = self.node_config().pruning.preset() {
+            let provider = factory.provider()?;
+            self.prune_modes()
+                .ensure_pruned_data_not_retained(
+                    provider.best_block_number()?,
+                    &provider.get_prune_checkpoints()?,
+                )
+                .wrap_err_with(|| {
+                    format!(
+                        "Pruning preset `{}` is incompatible with the existing database",
+                        preset.as_str()
+                    )
+                })?;
+        }
+
         Ok(factory)
     }
 
@@ -1196,6 +1269,201 @@ where
 
         Ok(())
     }
+
+    /// Spawns a task that stages the blob sidecars of new canonical blocks, if configured.
+    ///
+    /// The sidecars are taken from the transaction pool, which keeps them until the block is
+    /// finalized. The engine persistence writes them into static files together with their blocks,
+    /// and prunes them once they fall out of the retention window.
+    pub fn spawn_blob_sidecars_persistence(&self) {
+        let Some(stage) = self.provider_factory().blob_sidecar_stage().cloned() else { return };
+
+        let pool = self.components().pool().clone();
+        let mut canon_state_notifications = self.blockchain_db().canonical_state_stream();
+
+        info!(target: "reth::cli", retention_blocks = stage.retention_blocks(), "Persisting blob sidecars");
+
+        self.task_executor().spawn_blocking_task(async move {
+            while let Some(notification) = canon_state_notifications.next().await {
+                let stage_sidecars = || -> eyre::Result<()> {
+                    for block in notification.committed().blocks_iter() {
+                        let tx_hashes = block
+                            .body()
+                            .transactions()
+                            .iter()
+                            .filter(|tx| tx.blob_versioned_hashes().is_some())
+                            .map(|tx| *tx.tx_hash())
+                            .collect::<Vec<_>>();
+                        let sidecars = if tx_hashes.is_empty() {
+                            Vec::new()
+                        } else {
+                            pool.get_all_blobs(tx_hashes)?
+                                .into_iter()
+                                .map(|(tx_hash, sidecar)| (tx_hash, Arc::unwrap_or_clone(sidecar)))
+                                .collect()
+                        };
+                        stage.insert(block.num_hash(), sidecars);
+                    }
+                    Ok(())
+                };
+
+                if let Err(err) = stage_sidecars() {
+                    warn!(target: "reth::cli", %err, "Failed to stage blob sidecars");
+                }
+            }
+        });
+    }
+
+    /// Spawns a task that maintains the `trace_filter` address index, if enabled.
+    ///
+    /// Only finalized blocks are indexed, so the index never has to be unwound. Indexing starts at
+    /// the earliest available block, or resumes where the index left off.
+    pub fn spawn_trace_address_index(&self) {
+        if !self.node_config().rpc.rpc_trace_filter_index {
+            return
+        }
+
+        /// Number of blocks indexed per database transaction.
+        const BATCH_SIZE: u64 = 100;
+
+        let provider_factory = self.provider_factory().clone();
+        let blockchain_db = self.blockchain_db().clone();
+        let evm_config = self.components().evm_config().clone();
+        let mut finalized_headers = self.blockchain_db().finalized_block_stream();
+
+        info!(target: "reth::cli", "Indexing trace_filter addresses of finalized blocks");
+
+        self.task_executor().spawn_blocking_task(async move {
+            while let Some(finalized) = finalized_headers.next().await {
+                let index = || -> eyre::Result<()> {
+                    let next = match blockchain_db.trace_address_index_range()? {
+                        Some(range) => range.end() + 1,
+                        None => blockchain_db.earliest_block_number()?,
+                    };
+
+                    for batch_start in (next..=finalized.number()).step_by(BATCH_SIZE as usize) {
+                        let batch_end = (batch_start + BATCH_SIZE - 1).min(finalized.number());
+                        let mut blocks = Vec::new();
+                        for number in batch_start..=batch_end {
+                            let block = blockchain_db
+                                .recovered_block(number.into(), TransactionVariant::NoHash)?
+                                .ok_or(ProviderError::HeaderNotFound(number.into()))?;
+                            // The genesis block has no transactions, so the state is never read.
+                            let state =
+                                blockchain_db.history_by_block_number(number.saturating_sub(1))?;
+                            blocks.push((number, trace_filter_addresses(&evm_config, state, &block)?));
+                        }
+
+                        let provider_rw = provider_factory.provider_rw()?;
+                        provider_rw.write_trace_address_index(blocks)?;
+                        provider_rw.commit()?;
+                        debug!(target: "reth::cli", block = batch_end, "Indexed trace_filter addresses");
+                    }
+                    Ok(())
+                };
+
+                if let Err(err) = index() {
+                    warn!(target: "reth::cli", %err, "Failed to index trace_filter addresses");
+                }
+            }
+        });
+    }
+
+    /// Spawns a task that maintains the log index used by `eth_getLogs`, if enabled.
+    ///
+    /// Only finalized blocks are indexed, so the index never has to be unwound. Indexing starts at
+    /// the earliest available block, or resumes where the index left off.
+    pub fn spawn_log_index(&self) {
+        if !self.node_config().rpc.rpc_log_index {
+            return
+        }
+
+        /// Number of blocks indexed per database transaction.
+        const BATCH_SIZE: u64 = 1_000;
+
+        let provider_factory = self.provider_factory().clone();
+        let blockchain_db = self.blockchain_db().clone();
+        let mut finalized_headers = self.blockchain_db().finalized_block_stream();
+
+        info!(target: "reth::cli", "Indexing logs of finalized blocks");
+
+        self.task_executor().spawn_blocking_task(async move {
+            while let Some(finalized) = finalized_headers.next().await {
+                let index = || -> eyre::Result<()> {
+                    let next = match blockchain_db.log_index_range()? {
+                        Some(range) => range.end() + 1,
+                        None => blockchain_db.earliest_block_number()?,
+                    };
+
+                    for batch_start in (next..=finalized.number()).step_by(BATCH_SIZE as usize) {
+                        let batch_end = (batch_start + BATCH_SIZE - 1).min(finalized.number());
+                        let receipts =
+                            blockchain_db.receipts_by_block_range(batch_start..=batch_end)?;
+                        let blocks = (batch_start..=batch_end)
+                            .zip(receipts)
+                            .map(|(number, receipts)| {
+                                (
+                                    number,
+                                    receipts.into_iter().flat_map(TxReceipt::into_logs).collect(),
+                                )
+                            })
+                            .collect();
+
+                        let provider_rw = provider_factory.provider_rw()?;
+                        provider_rw.write_log_index(blocks)?;
+                        provider_rw.commit()?;
+                        debug!(target: "reth::cli", block = batch_end, "Indexed logs");
+                    }
+                    Ok(())
+                };
+
+                if let Err(err) = index() {
+                    warn!(target: "reth::cli", %err, "Failed to index logs");
+                }
+            }
+        });
+    }
+
+    /// Spawns a task that periodically samples the storage sizes, reports the projected growth
+    /// as metrics and warns if the disk is projected to be full within the configured horizon.
+    pub fn spawn_storage_growth_forecast(&self) {
+        let provider_factory = self.provider_factory().clone();
+        let datadir = self.data_dir().data_dir().to_path_buf();
+        let horizon = self.node_config().metrics.disk_exhaustion_horizon;
+
+        // Sampling reads the database stats and walks the static files directory.
+        self.task_executor().spawn_blocking_task(async move {
+            let mut tracker = StorageGrowthTracker::default();
+            let mut interval = tokio::time::interval(Duration::from_secs(5 * 60));
+            loop {
+                interval.tick().await;
+
+                let sample = match StorageSample::collect(&provider_factory) {
+                    Ok(sample) => sample,
+                    Err(err) => {
+                        warn!(target: "reth::cli", %err, "Failed to sample storage sizes");
+                        continue
+                    }
+                };
+                tracker.record(sample);
+
+                let Some(forecast) = tracker.forecast(available_disk_space(&datadir)) else {
+                    continue
+                };
+                forecast.report_metrics();
+
+                if forecast.exhausted_within(horizon) {
+                    warn!(
+                        target: "reth::cli",
+                        eta = ?forecast.exhaustion_eta,
+                        available_bytes = ?forecast.available_space,
+                        growth_bytes_per_hour = (forecast.total_growth_rate * 3600.0) as u64,
+                        "Disk holding the datadir is projected to be full soon"
+                    );
+                }
+            }
+        });
+    }
 }
 
 /// Joins two attachments together, preserving access to both values.
@@ -1346,6 +1614,7 @@ mod tests {
                 pruning: PruningArgs {
                     full: true,
                     minimal: false,
+                    preset: None,
                     block_interval: None,
                     sender_recovery_full: false,
                     sender_recovery_distance: None,
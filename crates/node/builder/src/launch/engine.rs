@@ -35,15 +35,15 @@ use reth_node_core::{
 };
 use reth_node_events::node;
 use reth_provider::{
-    providers::{BlockchainProvider, NodeTypesForProvider},
-    BlockNumReader, StorageSettingsCache,
+    providers::{BlockchainProvider, NodeTypesForProvider, ProviderCacheConfig},
+    BlockNumReader, CanonStateSubscriptions, StorageSettingsCache,
 };
 use reth_tasks::TaskExecutor;
 use reth_tokio_util::EventSender;
 use reth_tracing::tracing::{debug, error, info};
 use reth_trie_db::ChangesetCache;
 use std::{future::Future, pin::Pin, sync::Arc};
-use tokio::sync::{mpsc::unbounded_channel, oneshot};
+use tokio::sync::{broadcast::error::RecvError, mpsc::unbounded_channel, oneshot};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
 /// The engine node launcher.
@@ -88,7 +88,7 @@ impl EngineNodeLauncher {
             adapter: NodeTypesAdapter { database },
             rocksdb_provider,
             components_builder,
-            add_ons: AddOns { hooks, exexs: installed_exex, add_ons },
+            add_ons: AddOns { hooks, exexs: installed_exex, stages: custom_stages, add_ons },
             config,
         } = target;
         let NodeHooks { on_component_initialized, on_node_started, .. } = hooks;
@@ -96,6 +96,7 @@ impl EngineNodeLauncher {
         // Create changeset cache that will be shared across the engine
         let changeset_cache = ChangesetCache::new();
         let disabled_stages = N::disabled_stages();
+        let provider_cache = config.rpc.rpc_state_cache.provider_cache;
 
         // setup the launch context
         let ctx = ctx
@@ -134,10 +135,30 @@ impl EngineNodeLauncher {
             // passing FullNodeTypes as type parameter here so that we can build
             // later the components.
             .with_blockchain_db::<T, _>(move |provider_factory| {
-                Ok(BlockchainProvider::new(provider_factory)?)
+                let provider = BlockchainProvider::new(provider_factory)?;
+                Ok(if provider_cache {
+                    provider.with_cache(ProviderCacheConfig::default())
+                } else {
+                    provider
+                })
             })?
             .with_components(components_builder, on_component_initialized).await?;
 
+        // invalidate the provider cache on canonical state changes
+        if let Some(cache) = ctx.blockchain_db().cache().cloned() {
+            let mut notifications = ctx.blockchain_db().subscribe_to_canonical_state();
+            ctx.task_executor().spawn_task(async move {
+                loop {
+                    match notifications.recv().await {
+                        Ok(notification) => cache.on_canon_state_notification(&notification),
+                        // missed notifications may have invalidated any entry
+                        Err(RecvError::Lagged(_)) => cache.clear(),
+                        Err(RecvError::Closed) => break,
+                    }
+                }
+            });
+        }
+
         // spawn exexs if any
         let maybe_exex_manager_handle = ctx.launch_exex(installed_exex).await?;
 
@@ -159,7 +180,7 @@ impl EngineNodeLauncher {
 
         let consensus = Arc::new(ctx.components().consensus().clone());
 
-        let pipeline = build_networked_pipeline(
+        let mut pipeline = build_networked_pipeline(
             &ctx.toml_config().stages,
             network_client.clone(),
             consensus.clone(),
@@ -173,12 +194,18 @@ impl EngineNodeLauncher {
             maybe_exex_manager_handle.clone().unwrap_or_else(ExExManagerHandle::empty),
             ctx.era_import_source(),
             disabled_stages,
+            custom_stages,
         )?;
+        // Reorgs that go through a pipeline unwind are limited like the ones of the engine tree.
+        pipeline.set_max_reorg_depth(engine_tree_config.max_reorg_depth());
 
         // The new engine writes directly to static files. This ensures that they're up to the tip.
         pipeline.move_to_static_files()?;
 
         let pipeline_events = pipeline.events();
+        let pipeline_subscriptions = pipeline.event_subscriptions();
+        // the pipeline is moved into the orchestrator, the handle controls it from the RPC
+        let pipeline_handle = pipeline.handle();
 
         let mut pruner_builder = ctx.pruner_builder();
         if let Some(exex_manager_handle) = &maybe_exex_manager_handle {
@@ -202,6 +229,8 @@ impl EngineNodeLauncher {
             beacon_engine_handle: beacon_engine_handle.clone(),
             jwt_secret,
             engine_events: event_sender.clone(),
+            pipeline_events: pipeline_subscriptions.clone(),
+            pipeline_handle,
         };
         let validator_builder = add_ons.engine_validator_builder();
         let state_trie_overlays =
@@ -277,6 +306,7 @@ impl EngineNodeLauncher {
         let events = stream_select!(
             event_sender.new_listener().map(Into::into),
             pipeline_events.map(Into::into),
+            pipeline_subscriptions.stage_events().map(Into::into),
             ctx.consensus_layer_events(),
             pruner_events.map(Into::into),
             static_file_producer_events.map(Into::into),
@@ -353,6 +383,12 @@ impl EngineNodeLauncher {
                             ChainEvent::BackfillSyncStarted => {
                                 network_handle.update_sync_state(SyncState::Syncing);
                             }
+                            ChainEvent::BackfillSyncAborted => {
+                                info!(target: "reth::cli", "Backfill sync aborted");
+                                if terminate_after_backfill {
+                                    break
+                                }
+                            }
                             ChainEvent::FatalError => {
                                 error!(target: "reth::cli", "Fatal error in consensus engine");
                                 res = Err(eyre::eyre!("Fatal error in consensus engine"));
@@ -441,6 +477,10 @@ impl EngineNodeLauncher {
         on_node_started.on_event(FullNode::clone(&full_node))?;
 
         ctx.spawn_ethstats(engine_events_for_ethstats).await?;
+        ctx.spawn_blob_sidecars_persistence();
+        ctx.spawn_storage_growth_forecast();
+        ctx.spawn_trace_address_index();
+        ctx.spawn_log_index();
 
         let handle = NodeHandle {
             node_exit_future: NodeExitFuture::new(async { rx.await? }),
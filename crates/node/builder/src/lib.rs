@@ -27,7 +27,10 @@ pub mod components;
 pub use components::{NodeComponents, NodeComponentsBuilder};
 
 mod builder;
-pub use builder::{add_ons::AddOns, *};
+pub use builder::{
+    add_ons::{AddOns, BoxedPipelineStage, PipelineProvider},
+    *,
+};
 
 mod launch;
 pub use launch::{
@@ -64,3 +67,6 @@ pub use reth_node_api::*;
 use aquamarine as _;
 
 use reth_rpc as _;
+
+// enables the `errors_total` metric
+use reth_errors as _;
@@ -6,9 +6,10 @@ pub use jsonrpsee::{
 };
 use reth_engine_tree::tree::WaitForCaches;
 pub use reth_engine_tree::tree::{BasicEngineValidator, EngineValidator};
+pub use reth_rpc::{NativeTracer, NativeTracers};
 pub use reth_rpc_builder::{
     middleware::{RethAuthHttpMiddleware, RethRpcMiddleware},
-    Identity, Stack,
+    Identity, RpcInterceptor, RpcInterceptors, RpcTransport, Stack,
 };
 pub use reth_trie_db::ChangesetCache;
 
@@ -16,8 +17,10 @@ use crate::{
     invalid_block_hook::InvalidBlockHookExt, ConfigureEngineEvm, ConsensusEngineEvent,
     ConsensusEngineHandle,
 };
+use alloy_primitives::U64;
 use alloy_rpc_types::engine::ClientVersionV1;
 use alloy_rpc_types_engine::ExecutionData;
+use futures::StreamExt;
 use jsonrpsee::RpcModule;
 use parking_lot::Mutex;
 use reth_chain_state::{CanonStateSubscriptions, StateTrieOverlayManager};
@@ -34,17 +37,22 @@ use reth_node_core::{
 use reth_payload_builder::{PayloadBuilderHandle, PayloadStore};
 use reth_rpc::{
     eth::{core::EthRpcConverterFor, DevSigner, EthApiTypes, FullEthApiServer},
-    AdminApi,
+    AdminApi, AdminPipelineApi, RethSyncApi,
+};
+use reth_rpc_api::{
+    eth::helpers::EthTransactions, AdminPipelineApiServer, IntoEngineApiRpcModule,
+    RethSyncApiServer, RethSyncStatus,
 };
-use reth_rpc_api::{eth::helpers::EthTransactions, IntoEngineApiRpcModule};
 use reth_rpc_builder::{
     auth::{AuthRpcModule, AuthServerHandle},
     config::RethRpcServerConfig,
-    RpcModuleBuilder, RpcRegistryInner, RpcServerConfig, RpcServerHandle, TransportRpcModules,
+    RethRpcModule, RpcModuleBuilder, RpcRegistryInner, RpcServerConfig, RpcServerHandle,
+    RpcServerLimits, TransportRpcModules,
 };
 use reth_rpc_engine_api::{capabilities::EngineCapabilities, EngineApi};
 use reth_rpc_eth_types::{cache::cache_new_blocks_task, EthConfig, EthStateCache};
-use reth_tokio_util::EventSender;
+use reth_stages::StageEvent;
+use reth_tokio_util::{EventSender, EventStream};
 use reth_tracing::tracing::{debug, info};
 use std::{
     fmt::{self, Debug},
@@ -52,7 +60,7 @@ use std::{
     ops::{Deref, DerefMut},
     sync::Arc,
 };
-use tokio::sync::oneshot;
+use tokio::sync::{oneshot, watch};
 
 /// Contains the handles to the spawned RPC servers.
 ///
@@ -71,6 +79,10 @@ pub struct RpcHooks<Node: FullNodeComponents, EthApi> {
     pub on_rpc_started: Box<dyn OnRpcStarted<Node, EthApi>>,
     /// Hooks to run to configure RPC server API.
     pub extend_rpc_modules: Box<dyn ExtendRpcModules<Node, EthApi>>,
+    /// Hooks intercepting the calls of the RPC servers.
+    pub rpc_interceptors: RpcInterceptors,
+    /// Custom tracers of the `debug` namespace.
+    pub native_tracers: NativeTracers<EthApi>,
 }
 
 impl<Node, EthApi> Default for RpcHooks<Node, EthApi>
@@ -79,7 +91,12 @@ where
     EthApi: EthApiTypes,
 {
     fn default() -> Self {
-        Self { on_rpc_started: Box::<()>::default(), extend_rpc_modules: Box::<()>::default() }
+        Self {
+            on_rpc_started: Box::<()>::default(),
+            extend_rpc_modules: Box::<()>::default(),
+            rpc_interceptors: RpcInterceptors::default(),
+            native_tracers: NativeTracers::default(),
+        }
     }
 }
 
@@ -136,6 +153,8 @@ where
         f.debug_struct("RpcHooks")
             .field("on_rpc_started", &"...")
             .field("extend_rpc_modules", &"...")
+            .field("rpc_interceptors", &self.rpc_interceptors)
+            .field("native_tracers", &self.native_tracers)
             .finish()
     }
 }
@@ -909,6 +928,36 @@ where
         self.hooks.set_extend_rpc_modules(hook);
         self
     }
+
+    /// Registers a hook intercepting the calls of the http, ws and ipc servers.
+    ///
+    /// Interceptors see the requests in registration order and the responses in reverse order,
+    /// see [`RpcInterceptors`].
+    pub fn with_rpc_interceptor(mut self, interceptor: impl RpcInterceptor) -> Self {
+        self.hooks.rpc_interceptors.add_interceptor(interceptor);
+        self
+    }
+
+    /// Registers a hook intercepting the calls of the given transports.
+    pub fn with_transport_rpc_interceptor(
+        mut self,
+        transports: impl IntoIterator<Item = RpcTransport>,
+        interceptor: impl RpcInterceptor,
+    ) -> Self {
+        self.hooks.rpc_interceptors.add_transport_interceptor(transports, interceptor);
+        self
+    }
+
+    /// Registers a native tracer of the `debug` namespace, selectable by name with the `tracer`
+    /// field of the `debug_trace*` requests.
+    pub fn with_native_tracer(
+        mut self,
+        name: impl Into<String>,
+        tracer: impl NativeTracer<EthB::EthApi>,
+    ) -> Self {
+        self.hooks.native_tracers.register(name, tracer);
+        self
+    }
 }
 
 impl<Node, EthB, EV, EB, Engine> Default
@@ -957,6 +1006,7 @@ where
         F: FnOnce(RpcModuleContainer<'_, N, EthB::EthApi>) -> eyre::Result<()>,
     {
         let rpc_middleware = self.rpc_middleware.clone();
+        let rpc_interceptors = self.hooks.rpc_interceptors.clone();
         let tokio_runtime = self.tokio_runtime.clone();
         let setup_ctx = self.setup_rpc_components(ctx, ext).await?;
         let RpcSetupContext {
@@ -975,6 +1025,8 @@ where
             .rpc
             .rpc_server_config()
             .set_rpc_middleware(rpc_middleware)
+            .with_rpc_interceptors(rpc_interceptors)
+            .with_server_limits(registry.server_limits().cloned())
             .with_tokio_runtime(tokio_runtime);
         let rpc_server_handle = Self::launch_rpc_server_internal(server_config, &modules).await?;
 
@@ -1028,6 +1080,7 @@ where
         F: FnOnce(RpcModuleContainer<'_, N, EthB::EthApi>) -> eyre::Result<()>,
     {
         let rpc_middleware = self.rpc_middleware.clone();
+        let rpc_interceptors = self.hooks.rpc_interceptors.clone();
         let auth_http_middleware = self.auth_http_middleware.clone();
         let tokio_runtime = self.tokio_runtime.clone();
         let setup_ctx = self.setup_rpc_components(ctx, ext).await?;
@@ -1047,6 +1100,8 @@ where
             .rpc
             .rpc_server_config()
             .set_rpc_middleware(rpc_middleware)
+            .with_rpc_interceptors(rpc_interceptors)
+            .with_server_limits(registry.server_limits().cloned())
             .with_tokio_runtime(tokio_runtime);
 
         let auth_config = auth_config.with_http_middleware(auth_http_middleware);
@@ -1097,9 +1152,18 @@ where
         F: FnOnce(RpcModuleContainer<'_, N, EthB::EthApi>) -> eyre::Result<()>,
     {
         let Self { eth_api_builder, engine_api_builder, hooks, .. } = self;
+        let RpcHooks { on_rpc_started, extend_rpc_modules, native_tracers, .. } = hooks;
 
         let engine_api = engine_api_builder.build_engine_api(&ctx).await?;
-        let AddOnsContext { node, config, beacon_engine_handle, jwt_secret, engine_events } = ctx;
+        let AddOnsContext {
+            node,
+            config,
+            beacon_engine_handle,
+            jwt_secret,
+            engine_events,
+            pipeline_events,
+            pipeline_handle,
+        } = ctx;
 
         info!(target: "reth::cli", "Engine API handler initialized");
 
@@ -1115,12 +1179,16 @@ where
             cache_new_blocks_task(c, new_canonical_blocks).await;
         });
 
+        // limits adjustable at runtime with the `admin_` namespace
+        let server_limits = RpcServerLimits::default();
+
         let eth_config = config.rpc.eth_config().max_batch_size(config.txpool.max_batch_size());
         let ctx = EthApiCtx {
             components: &node,
             config: eth_config,
             cache,
             engine_handle: beacon_engine_handle.clone(),
+            server_limits: server_limits.clone(),
         };
         let eth_api = eth_api_builder.build_eth_api(ctx).await?;
 
@@ -1128,20 +1196,34 @@ where
         let module_config = config.rpc.transport_rpc_module_config();
         debug!(target: "reth::cli", http=?module_config.http(), ws=?module_config.ws(), "Using RPC module config");
 
-        let (mut modules, mut auth_module, registry) = RpcModuleBuilder::default()
+        let mut registry = RpcModuleBuilder::default()
             .with_provider(node.provider().clone())
             .with_pool(node.pool().clone())
             .with_network(node.network().clone())
             .with_executor(node.task_executor().clone())
             .with_evm_config(node.evm_config().clone())
             .with_consensus(node.consensus().clone())
-            .build_with_auth_server(
-                module_config,
-                engine_api,
+            .with_server_limits(server_limits)
+            .into_registry(
+                module_config.config().cloned().unwrap_or_default(),
                 eth_api,
                 engine_events.clone(),
-                beacon_engine_handle.clone(),
             );
+        registry.set_native_tracers(native_tracers);
+        let mut modules = registry.create_transport_rpc_modules(module_config);
+        let mut auth_module = registry.create_auth_module(engine_api, beacon_engine_handle.clone());
+
+        let (sync_status_tx, sync_status_rx) = watch::channel(None);
+        node.task_executor()
+            .spawn_task(sync_status_task(pipeline_events.stage_events(), sync_status_tx));
+        modules.merge_if_module_configured(
+            RethRpcModule::Reth,
+            RethSyncApi::new(sync_status_rx).into_rpc(),
+        )?;
+        modules.merge_if_module_configured(
+            RethRpcModule::Admin,
+            AdminPipelineApi::new(pipeline_handle).into_rpc(),
+        )?;
 
         // in dev mode we generate 20 random dev-signer accounts
         if config.dev.dev {
@@ -1158,8 +1240,6 @@ where
             auth_module: &mut auth_module,
         };
 
-        let RpcHooks { on_rpc_started, extend_rpc_modules } = hooks;
-
         ext(RpcModuleContainer {
             modules: ctx.modules,
             auth_module: ctx.auth_module,
@@ -1293,6 +1373,8 @@ pub struct EthApiCtx<'a, N: FullNodeTypes> {
     pub cache: EthStateCache<PrimitivesTy<N::Types>>,
     /// Handle to the beacon consensus engine
     pub engine_handle: ConsensusEngineHandle<<N::Types as NodeTypes>::Payload>,
+    /// Server limits adjustable at runtime, overriding the gas cap
+    pub server_limits: RpcServerLimits,
 }
 
 impl<'a, N: FullNodeComponents<Types: NodeTypes<ChainSpec: Hardforks + EthereumHardforks>>>
@@ -1309,6 +1391,7 @@ impl<'a, N: FullNodeComponents<Types: NodeTypes<ChainSpec: Hardforks + EthereumH
             .eth_proof_window(self.config.eth_proof_window)
             .fee_history_cache_config(self.config.fee_history_cache)
             .proof_permits(self.config.proof_permits)
+            .proof_concurrency(self.config.proof_concurrency)
             .gas_oracle_config(self.config.gas_oracle)
             .max_batch_size(self.config.max_batch_size)
             .max_blocking_io_requests(self.config.max_blocking_io_requests)
@@ -1316,6 +1399,9 @@ impl<'a, N: FullNodeComponents<Types: NodeTypes<ChainSpec: Hardforks + EthereumH
             .raw_tx_forwarder(self.config.raw_tx_forwarder)
             .evm_memory_limit(self.config.rpc_evm_memory_limit)
             .force_blob_sidecar_upcasting(self.config.force_blob_sidecar_upcasting)
+            .pruned_state_replay_limit(self.config.pruned_state_replay_limit)
+            .call_cache_max_entries(self.config.call_cache_max_entries)
+            .server_limits(Some(self.server_limits))
     }
 }
 
@@ -1613,3 +1699,42 @@ pub struct EngineShutdownRequest {
     /// Channel to signal shutdown completion.
     pub done_tx: oneshot::Sender<()>,
 }
+
+/// Publishes the sync status served by `reth_syncing` from the stage events of the pipeline.
+async fn sync_status_task(
+    mut stage_events: EventStream<StageEvent>,
+    sync_status: watch::Sender<Option<RethSyncStatus>>,
+) {
+    while let Some(event) = stage_events.next().await {
+        sync_status.send_modify(|status| match event {
+            StageEvent::Started { stage_id, checkpoint, target } => {
+                *status = Some(RethSyncStatus {
+                    stage: stage_id.to_string(),
+                    current_block: U64::from(checkpoint.unwrap_or_default().block_number),
+                    highest_block: target.map(U64::from),
+                    stage_eta: None,
+                    eta: None,
+                });
+            }
+            StageEvent::Committed { stage_id, checkpoint, done, eta, .. } => {
+                if done && stage_id.is_finish() {
+                    *status = None;
+                } else if let Some(status) = status {
+                    status.current_block = U64::from(checkpoint.block_number);
+                    status.stage_eta = eta.stage.map(|eta| eta.as_secs());
+                    status.eta = eta.total.map(|eta| eta.as_secs());
+                }
+            }
+            StageEvent::Unwound { stage_id, checkpoint, .. } => {
+                *status = Some(RethSyncStatus {
+                    stage: stage_id.to_string(),
+                    current_block: U64::from(checkpoint.block_number),
+                    highest_block: None,
+                    stage_eta: None,
+                    eta: None,
+                });
+            }
+            StageEvent::Progressed { .. } => {}
+        });
+    }
+}
@@ -16,17 +16,22 @@ use reth_network_p2p::{
     bodies::downloader::BodyDownloader, headers::downloader::HeaderDownloader, BlockClient,
 };
 use reth_node_api::HeaderTy;
-use reth_provider::{providers::ProviderNodeTypes, ProviderFactory};
+use reth_provider::{
+    providers::ProviderNodeTypes, BalProvider, DatabaseProviderFactory, ProviderFactory,
+};
 use reth_stages::{
     prelude::DefaultStages,
-    stages::{EraImportSource, ExecutionStage},
-    Pipeline, StageId, StageSet,
+    stages::{BlockAccessListStage, EraImportSource, ExecutionStage, StatePrewarmer},
+    Pipeline, Stage, StageId, StageSet,
 };
 use reth_static_file::StaticFileProducer;
 use reth_tasks::TaskExecutor;
 use reth_tracing::tracing::debug;
 use tokio::sync::watch;
 
+/// A boxed stage of the [Pipeline] for the given node types.
+type BoxedStage<N> = Box<dyn Stage<<ProviderFactory<N> as DatabaseProviderFactory>::ProviderRW>>;
+
 /// Constructs a [Pipeline] that's wired to the network
 #[expect(clippy::too_many_arguments)]
 pub fn build_networked_pipeline<N, Client, Evm>(
@@ -43,6 +48,7 @@ pub fn build_networked_pipeline<N, Client, Evm>(
     exex_manager_handle: ExExManagerHandle<N::Primitives>,
     era_import_source: Option<EraImportSource>,
     disabled_stages: &[StageId],
+    custom_stages: Vec<(StageId, BoxedStage<N>)>,
 ) -> eyre::Result<Pipeline<N>>
 where
     N: ProviderNodeTypes,
@@ -72,6 +78,7 @@ where
         exex_manager_handle,
         era_import_source,
         disabled_stages,
+        custom_stages,
     )?;
 
     Ok(pipeline)
@@ -93,6 +100,7 @@ pub fn build_pipeline<N, H, B, Evm>(
     exex_manager_handle: ExExManagerHandle<N::Primitives>,
     era_import_source: Option<EraImportSource>,
     disabled_stages: &[StageId],
+    custom_stages: Vec<(StageId, BoxedStage<N>)>,
 ) -> eyre::Result<Pipeline<N>>
 where
     N: ProviderNodeTypes,
@@ -109,30 +117,66 @@ where
 
     let (tip_tx, tip_rx) = watch::channel(B256::ZERO);
 
+    let mut execution_stage = ExecutionStage::new(
+        evm_config.clone(),
+        Arc::clone(&consensus),
+        stage_config.execution.into(),
+        stage_config.execution_external_clean_threshold(),
+        exex_manager_handle,
+    );
+    if stage_config.execution.parallel {
+        execution_stage = execution_stage.with_bal_store(provider_factory.bal_store().clone());
+    }
+    if let Some(lookahead) = stage_config.execution.prewarm_lookahead {
+        execution_stage = execution_stage.with_state_prewarmer(StatePrewarmer::spawn(
+            provider_factory.clone(),
+            Some(provider_factory.bal_store().clone()),
+            lookahead,
+        ));
+    }
+
+    let mut stages = DefaultStages::new(
+        provider_factory.clone(),
+        tip_rx,
+        Arc::clone(&consensus),
+        header_downloader,
+        body_downloader,
+        evm_config.clone(),
+        stage_config.clone(),
+        prune_config.segments,
+        era_import_source,
+    )
+    .set(execution_stage);
+
+    // The block access list stage reads historical state, so it has to run after the history
+    // indexing stages.
+    if stage_config.block_access_list.enabled {
+        stages = stages.add_before(
+            BlockAccessListStage::new(evm_config, stage_config.block_access_list),
+            StageId::Prune,
+        );
+    }
+
+    for (after, stage) in custom_stages {
+        eyre::ensure!(
+            stages.stages().any(|id| id == after),
+            "Cannot add stage {} after stage {after}, which is not part of the pipeline",
+            stage.id()
+        );
+        stages = stages.add_after_boxed(stage, after);
+    }
+
+    builder = builder.with_retry_policy(stage_config.retry.default_policy());
+    for stage_id in stages.stages() {
+        builder =
+            builder.with_stage_retry_policy(stage_id, stage_config.retry.policy(stage_id.as_str()));
+    }
+
     let pipeline = builder
         .with_tip_sender(tip_tx)
         .with_metrics_tx(metrics_tx)
-        .add_stages(
-            DefaultStages::new(
-                provider_factory.clone(),
-                tip_rx,
-                Arc::clone(&consensus),
-                header_downloader,
-                body_downloader,
-                evm_config.clone(),
-                stage_config.clone(),
-                prune_config.segments,
-                era_import_source,
-            )
-            .set(ExecutionStage::new(
-                evm_config,
-                consensus,
-                stage_config.execution.into(),
-                stage_config.execution_external_clean_threshold(),
-                exex_manager_handle,
-            ))
-            .disable_all(disabled_stages),
-        )
+        .with_unwind_thresholds(stage_config.unwind.into())
+        .add_stages(stages.disable_all(disabled_stages))
         .build(provider_factory, static_file_producer);
 
     Ok(pipeline)
@@ -7,7 +7,8 @@ use clap::{
 use eyre::ensure;
 use reth_cli_util::{parse_duration_from_secs_or_ms, parsers::format_duration_as_secs_or_ms};
 use reth_engine_primitives::{
-    TreeConfig, DEFAULT_INVALID_HEADER_HIT_EVICTION_THRESHOLD, DEFAULT_MULTIPROOF_TASK_CHUNK_SIZE,
+    TreeConfig, DEFAULT_INVALID_HEADER_HIT_EVICTION_THRESHOLD,
+    DEFAULT_MULTIPROOF_CHUNKING_THRESHOLD, DEFAULT_MULTIPROOF_TASK_CHUNK_SIZE,
     DEFAULT_PERSISTENCE_BACKPRESSURE_THRESHOLD, DEFAULT_SPARSE_TRIE_MAX_HOT_ACCOUNTS,
     DEFAULT_SPARSE_TRIE_MAX_HOT_SLOTS,
 };
@@ -37,6 +38,8 @@ pub struct DefaultEngineValues {
     state_root_task_compare_updates: bool,
     accept_execution_requests_hash: bool,
     multiproof_chunk_size: usize,
+    multiproof_chunking_threshold: usize,
+    multiproof_chunking_disabled: bool,
     reserved_cpu_cores: usize,
     precompile_cache_disabled: bool,
     state_root_fallback: bool,
@@ -135,6 +138,18 @@ impl DefaultEngineValues {
         self
     }
 
+    /// Set the default number of pending proof targets above which they are always chunked
+    pub const fn with_multiproof_chunking_threshold(mut self, v: usize) -> Self {
+        self.multiproof_chunking_threshold = v;
+        self
+    }
+
+    /// Set whether to disable multiproof chunking by default
+    pub const fn with_multiproof_chunking_disabled(mut self, v: bool) -> Self {
+        self.multiproof_chunking_disabled = v;
+        self
+    }
+
     /// Set the default number of reserved CPU cores
     pub const fn with_reserved_cpu_cores(mut self, v: usize) -> Self {
         self.reserved_cpu_cores = v;
@@ -267,6 +282,8 @@ impl Default for DefaultEngineValues {
             state_root_task_compare_updates: false,
             accept_execution_requests_hash: false,
             multiproof_chunk_size: DEFAULT_MULTIPROOF_TASK_CHUNK_SIZE,
+            multiproof_chunking_threshold: DEFAULT_MULTIPROOF_CHUNKING_THRESHOLD,
+            multiproof_chunking_disabled: false,
             reserved_cpu_cores: DEFAULT_RESERVED_CPU_CORES,
             precompile_cache_disabled: false,
             state_root_fallback: false,
@@ -383,6 +400,24 @@ pub struct EngineArgs {
     #[arg(long = "engine.multiproof-chunk-size", default_value_t = DefaultEngineValues::get_global().multiproof_chunk_size, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
     pub multiproof_chunk_size: usize,
 
+    /// Number of pending proof targets above which they are chunked across proof workers, even
+    /// if none of the workers are idle.
+    #[arg(long = "engine.multiproof-chunking-threshold", default_value_t = DefaultEngineValues::get_global().multiproof_chunking_threshold, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    pub multiproof_chunking_threshold: usize,
+
+    /// Disable chunking of proof targets, dispatching all pending targets of the state root task
+    /// as a single proof.
+    #[arg(long = "engine.disable-multiproof-chunking", default_value_t = DefaultEngineValues::get_global().multiproof_chunking_disabled)]
+    pub multiproof_chunking_disabled: bool,
+
+    /// Maximum number of transactions ahead of execution for which prewarming prefetches the
+    /// proofs of the touched state.
+    ///
+    /// Proofs for transactions further ahead are fetched once they are executed. By default
+    /// proofs are prefetched for all prewarmed transactions.
+    #[arg(long = "engine.proof-prefetch-depth", value_name = "TRANSACTIONS")]
+    pub proof_prefetch_depth: Option<usize>,
+
     /// Configure the number of reserved CPU cores for non-reth processes
     #[arg(long = "engine.reserved-cpu-cores", default_value_t = DefaultEngineValues::get_global().reserved_cpu_cores)]
     pub reserved_cpu_cores: usize,
@@ -417,6 +452,19 @@ pub struct EngineArgs {
     #[arg(long = "engine.allow-unwind-canonical-header", default_value_t = DefaultEngineValues::get_global().allow_unwind_canonical_header)]
     pub allow_unwind_canonical_header: bool,
 
+    /// Refuse forkchoice updates that would unwind more than this many canonical blocks.
+    ///
+    /// Such a reorg is reported as syncing to the consensus client and is not applied until the
+    /// node is restarted with a higher limit, protecting against accidental deep rollbacks caused
+    /// by a misconfigured consensus client. Reorgs that require a backfill sync unwind fail the
+    /// backfill sync instead.
+    #[arg(
+        long = "engine.max-reorg-depth",
+        visible_alias = "max-reorg-depth",
+        value_name = "BLOCKS"
+    )]
+    pub max_reorg_depth: Option<u64>,
+
     /// Configure the number of storage proof workers in the Tokio blocking pool.
     /// If not specified, defaults to 2x available parallelism.
     #[arg(long = "engine.storage-worker-count", default_value = Resettable::from(DefaultEngineValues::get_global().storage_worker_count.map(|v| v.to_string().into())))]
@@ -560,6 +608,8 @@ impl Default for EngineArgs {
             state_root_task_compare_updates,
             accept_execution_requests_hash,
             multiproof_chunk_size,
+            multiproof_chunking_threshold,
+            multiproof_chunking_disabled,
             reserved_cpu_cores,
             precompile_cache_disabled,
             state_root_fallback,
@@ -596,12 +646,16 @@ impl Default for EngineArgs {
             cross_block_cache_size,
             accept_execution_requests_hash,
             multiproof_chunk_size,
+            multiproof_chunking_threshold,
+            multiproof_chunking_disabled,
+            proof_prefetch_depth: None,
             reserved_cpu_cores,
             precompile_cache_enabled: true,
             precompile_cache_disabled,
             state_root_fallback,
             always_process_payload_attributes_on_canonical_head,
             allow_unwind_canonical_header,
+            max_reorg_depth: None,
             storage_worker_count,
             account_worker_count,
             prewarming_threads,
@@ -666,6 +720,9 @@ impl EngineArgs {
             .with_always_compare_trie_updates(self.state_root_task_compare_updates)
             .with_cross_block_cache_size(self.cross_block_cache_size * 1024 * 1024)
             .with_multiproof_chunk_size(self.multiproof_chunk_size)
+            .with_multiproof_chunking_threshold(self.multiproof_chunking_threshold)
+            .without_multiproof_chunking(self.multiproof_chunking_disabled)
+            .with_proof_prefetch_depth(self.proof_prefetch_depth)
             .with_reserved_cpu_cores(self.reserved_cpu_cores)
             .without_precompile_cache(self.precompile_cache_disabled)
             .with_state_root_fallback(self.state_root_fallback)
@@ -673,6 +730,7 @@ impl EngineArgs {
                 self.always_process_payload_attributes_on_canonical_head,
             )
             .with_unwind_canonical_header(self.allow_unwind_canonical_header)
+            .with_max_reorg_depth(self.max_reorg_depth)
             .without_cache_metrics(self.cache_metrics_disabled)
             .with_sparse_trie_max_hot_slots(self.sparse_trie_max_hot_slots)
             .with_sparse_trie_max_hot_accounts(self.sparse_trie_max_hot_accounts)
@@ -787,12 +845,16 @@ mod tests {
             state_root_task_compare_updates: true,
             accept_execution_requests_hash: true,
             multiproof_chunk_size: 512,
+            multiproof_chunking_threshold: 1000,
+            multiproof_chunking_disabled: true,
+            proof_prefetch_depth: Some(16),
             reserved_cpu_cores: 4,
             precompile_cache_enabled: true,
             precompile_cache_disabled: true,
             state_root_fallback: true,
             always_process_payload_attributes_on_canonical_head: true,
             allow_unwind_canonical_header: true,
+            max_reorg_depth: Some(64),
             storage_worker_count: Some(16),
             account_worker_count: Some(8),
             prewarming_threads: Some(4),
@@ -832,12 +894,19 @@ mod tests {
             "--engine.accept-execution-requests-hash",
             "--engine.multiproof-chunk-size",
             "512",
+            "--engine.multiproof-chunking-threshold",
+            "1000",
+            "--engine.disable-multiproof-chunking",
+            "--engine.proof-prefetch-depth",
+            "16",
             "--engine.reserved-cpu-cores",
             "4",
             "--engine.disable-precompile-cache",
             "--engine.state-root-fallback",
             "--engine.always-process-payload-attributes-on-canonical-head",
             "--engine.allow-unwind-canonical-header",
+            "--engine.max-reorg-depth",
+            "64",
             "--engine.storage-worker-count",
             "16",
             "--engine.account-worker-count",
@@ -5,6 +5,9 @@ use std::{net::SocketAddr, time::Duration};
 /// Default push gateway interval in seconds.
 const DEFAULT_PUSH_GATEWAY_INTERVAL_SECS: u64 = 5;
 
+/// Default horizon within which a projected full disk is warned about, one week.
+const DEFAULT_DISK_EXHAUSTION_HORIZON: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
 /// Metrics configuration.
 #[derive(Debug, Clone, Parser)]
 pub struct MetricArgs {
@@ -35,6 +38,21 @@ pub struct MetricArgs {
         help_heading = "Metrics"
     )]
     pub push_gateway_interval: Duration,
+
+    /// Warn when the disk holding the datadir is projected to be full within this horizon.
+    ///
+    /// The projection is based on the growth of the database tables and static file segments
+    /// over the last day.
+    ///
+    /// Default: 7 days
+    #[arg(
+        long = "metrics.disk-exhaustion-horizon",
+        default_value = "7d",
+        value_parser = humantime::parse_duration,
+        value_name = "DURATION",
+        help_heading = "Metrics"
+    )]
+    pub disk_exhaustion_horizon: Duration,
 }
 
 impl Default for MetricArgs {
@@ -43,6 +61,7 @@ impl Default for MetricArgs {
             prometheus: None,
             push_gateway_url: None,
             push_gateway_interval: Duration::from_secs(DEFAULT_PUSH_GATEWAY_INTERVAL_SECS),
+            disk_exhaustion_horizon: DEFAULT_DISK_EXHAUSTION_HORIZON,
         }
     }
 }
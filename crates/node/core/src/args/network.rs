@@ -252,6 +252,20 @@ pub struct NetworkArgs {
     #[arg(long)]
     pub trusted_only: bool,
 
+    /// Path to a file listing trusted peers, one enode URL per line.
+    ///
+    /// The file is reloaded when it changes: added peers are dialed, removed peers are no longer
+    /// trusted.
+    #[arg(long, value_name = "FILE", verbatim_doc_comment)]
+    pub trusted_peers_file: Option<PathBuf>,
+
+    /// Path to a file listing static peers, one enode URL per line.
+    ///
+    /// The file is reloaded when it changes: added peers are dialed, removed peers are
+    /// disconnected.
+    #[arg(long, value_name = "FILE", verbatim_doc_comment)]
+    pub static_peers_file: Option<PathBuf>,
+
     /// Comma separated enode URLs for P2P discovery bootstrap.
     ///
     /// Will fall back to a network-specific default if not specified.
@@ -718,6 +732,8 @@ impl Default for NetworkArgs {
             discovery: DiscoveryArgs::default(),
             trusted_peers: vec![],
             trusted_only: false,
+            trusted_peers_file: None,
+            static_peers_file: None,
             bootnodes: None,
             dns_retries,
             peers_file: None,
@@ -2,7 +2,7 @@
 
 use crate::{args::error::ReceiptsLogError, primitives::EthereumHardfork};
 use alloy_primitives::{Address, BlockNumber};
-use clap::{builder::RangedU64ValueParser, Args};
+use clap::{builder::RangedU64ValueParser, Args, ValueEnum};
 use reth_chainspec::EthereumHardforks;
 use reth_config::config::PruneConfig;
 use reth_prune_types::{
@@ -91,6 +91,55 @@ impl Default for DefaultPruningValues {
     }
 }
 
+/// Named pruning preset, selectable with `--prune.preset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PrunePreset {
+    /// Archive node that retains all data.
+    Archive,
+    /// Full node that keeps the state history of recent blocks only.
+    Full,
+    /// Minimal storage node with maximum pruning and smaller static files.
+    Minimal,
+}
+
+impl PrunePreset {
+    /// Returns the string representation of the preset.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Archive => "archive",
+            Self::Full => "full",
+            Self::Minimal => "minimal",
+        }
+    }
+
+    /// Returns the prune modes the preset expands to.
+    pub fn prune_modes<ChainSpec>(self, chain_spec: &ChainSpec) -> PruneModes
+    where
+        ChainSpec: EthereumHardforks,
+    {
+        let defaults = DefaultPruningValues::get_global();
+        match self {
+            Self::Archive => PruneModes::default(),
+            Self::Full => {
+                let mut segments = defaults.full_prune_modes.clone();
+                if defaults.full_bodies_history_use_pre_merge {
+                    segments.bodies_history = chain_spec
+                        .ethereum_fork_activation(EthereumHardfork::Paris)
+                        .block_number()
+                        .map(PruneMode::Before);
+                }
+                segments
+            }
+            Self::Minimal => defaults.minimal_prune_modes.clone(),
+        }
+    }
+
+    /// Returns `true` if the preset uses smaller static files.
+    pub const fn is_minimal(self) -> bool {
+        matches!(self, Self::Minimal)
+    }
+}
+
 /// High-level pruning configuration profile.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PruneConfigKind {
@@ -157,6 +206,14 @@ pub struct PruningArgs {
     #[arg(long, default_value_t = false, conflicts_with = "full")]
     pub minimal: bool,
 
+    /// Run the node with a named pruning preset.
+    ///
+    /// `full` and `minimal` are equivalent to `--full` and `--minimal`. Explicitly set `--prune.*`
+    /// flags take precedence over the preset. The node refuses to start if the preset retains
+    /// data that was already pruned from the database.
+    #[arg(long = "prune.preset", value_name = "PRESET", conflicts_with_all = &["full", "minimal"])]
+    pub preset: Option<PrunePreset>,
+
     /// Minimum pruning interval measured in blocks.
     #[arg(long = "prune.block-interval", alias = "block-interval", value_parser = RangedU64ValueParser::<u64>::new().range(1..))]
     pub block_interval: Option<u64>,
@@ -252,6 +309,22 @@ pub struct PruningArgs {
 }
 
 impl PruningArgs {
+    /// Returns the selected pruning preset, if any.
+    pub const fn preset(&self) -> Option<PrunePreset> {
+        if self.full {
+            Some(PrunePreset::Full)
+        } else if self.minimal {
+            Some(PrunePreset::Minimal)
+        } else {
+            self.preset
+        }
+    }
+
+    /// Returns `true` if the minimal storage preset is selected.
+    pub const fn is_minimal(&self) -> bool {
+        matches!(self.preset(), Some(PrunePreset::Minimal))
+    }
+
     /// Returns pruning configuration.
     ///
     /// Returns [`None`] if no parameters are specified and default pruning configuration should be
@@ -263,30 +336,9 @@ impl PruningArgs {
         // Initialize with a default prune configuration.
         let mut config = PruneConfig::default();
 
-        // If --full is set, use full node defaults.
-        if self.full {
-            let defaults = DefaultPruningValues::get_global();
-            let mut segments = defaults.full_prune_modes.clone();
-            if defaults.full_bodies_history_use_pre_merge {
-                segments.bodies_history = chain_spec
-                    .ethereum_fork_activation(EthereumHardfork::Paris)
-                    .block_number()
-                    .map(PruneMode::Before);
-            }
-            config = PruneConfig {
-                block_interval: config.block_interval,
-                segments,
-                minimum_pruning_distance: config.minimum_pruning_distance,
-            }
-        }
-
-        // If --minimal is set, use minimal storage mode with aggressive pruning.
-        if self.minimal {
-            config = PruneConfig {
-                block_interval: config.block_interval,
-                segments: DefaultPruningValues::get_global().minimal_prune_modes.clone(),
-                minimum_pruning_distance: config.minimum_pruning_distance,
-            }
+        // If a preset is selected, start from its prune modes.
+        if let Some(preset) = self.preset() {
+            config.segments = preset.prune_modes(chain_spec);
         }
 
         // Override with any explicitly set prune.* flags.
@@ -510,6 +562,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn prune_preset_matches_flags() {
+        let chain_spec = MAINNET.as_ref();
+
+        let args =
+            CommandParser::<PruningArgs>::parse_from(["reth", "--prune.preset", "full"]).args;
+        assert_eq!(args.preset(), Some(PrunePreset::Full));
+        assert_eq!(
+            args.prune_config(chain_spec),
+            PruningArgs { full: true, ..Default::default() }.prune_config(chain_spec)
+        );
+
+        let args =
+            CommandParser::<PruningArgs>::parse_from(["reth", "--prune.preset", "minimal"]).args;
+        assert!(args.is_minimal());
+        assert_eq!(
+            args.prune_config(chain_spec),
+            PruningArgs { minimal: true, ..Default::default() }.prune_config(chain_spec)
+        );
+
+        let args =
+            CommandParser::<PruningArgs>::parse_from(["reth", "--prune.preset", "archive"]).args;
+        assert_eq!(args.preset(), Some(PrunePreset::Archive));
+        assert_eq!(args.prune_config(chain_spec), None);
+
+        assert!(CommandParser::<PruningArgs>::try_parse_from([
+            "reth",
+            "--full",
+            "--prune.preset",
+            "minimal"
+        ])
+        .is_err());
+    }
+
     #[test]
     fn parse_receiptslogfilter() {
         let default_args = PruningArgs::default();
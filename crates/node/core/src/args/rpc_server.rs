@@ -12,8 +12,10 @@ use clap::{
 };
 use rand::Rng;
 use reth_cli_util::{parse_duration_from_secs_or_ms, parse_ether_value};
-use reth_rpc_eth_types::builder::config::PendingBlockKind;
-use reth_rpc_server_types::{constants, RethRpcModule, RpcModuleSelection};
+use reth_rpc_eth_types::builder::config::{PendingBlockKind, SubscriptionLagPolicy};
+use reth_rpc_server_types::{
+    constants, RethRpcModule, RpcApiKeys, RpcMethodCost, RpcMethodRateLimit, RpcModuleSelection,
+};
 use std::{
     ffi::OsStr,
     net::{IpAddr, Ipv4Addr},
@@ -54,6 +56,7 @@ pub struct DefaultRpcServerArgs {
     http_addr: IpAddr,
     http_port: u16,
     http_disable_compression: bool,
+    http_compression_min_size: u16,
     http_api: Option<RpcModuleSelection>,
     http_corsdomain: Option<String>,
     ws: bool,
@@ -88,6 +91,7 @@ pub struct DefaultRpcServerArgs {
     rpc_compute_state_root_for_eth_simulate: bool,
     rpc_eth_proof_window: u64,
     rpc_proof_permits: usize,
+    rpc_proof_concurrency: usize,
     rpc_pending_block: PendingBlockKind,
     rpc_forwarder: Option<Url>,
     builder_disallow: Option<AddressSet>,
@@ -131,6 +135,12 @@ impl DefaultRpcServerArgs {
         self
     }
 
+    /// Set the default minimum size of compressed HTTP responses
+    pub const fn with_http_compression_min_size(mut self, v: u16) -> Self {
+        self.http_compression_min_size = v;
+        self
+    }
+
     /// Set the default HTTP API modules
     pub fn with_http_api(mut self, v: Option<RpcModuleSelection>) -> Self {
         self.http_api = v;
@@ -335,6 +345,12 @@ impl DefaultRpcServerArgs {
         self
     }
 
+    /// Set the default proof concurrency
+    pub const fn with_rpc_proof_concurrency(mut self, v: usize) -> Self {
+        self.rpc_proof_concurrency = v;
+        self
+    }
+
     /// Set the default pending block kind
     pub const fn with_rpc_pending_block(mut self, v: PendingBlockKind) -> Self {
         self.rpc_pending_block = v;
@@ -379,6 +395,7 @@ impl Default for DefaultRpcServerArgs {
             http_addr: Ipv4Addr::LOCALHOST.into(),
             http_port: constants::DEFAULT_HTTP_RPC_PORT,
             http_disable_compression: false,
+            http_compression_min_size: constants::DEFAULT_HTTP_COMPRESSION_MIN_SIZE,
             http_api: None,
             http_corsdomain: None,
             ws: false,
@@ -413,6 +430,7 @@ impl Default for DefaultRpcServerArgs {
             rpc_compute_state_root_for_eth_simulate: false,
             rpc_eth_proof_window: constants::DEFAULT_ETH_PROOF_WINDOW,
             rpc_proof_permits: constants::DEFAULT_PROOF_PERMITS,
+            rpc_proof_concurrency: constants::DEFAULT_PROOF_CONCURRENCY,
             rpc_pending_block: PendingBlockKind::Full,
             rpc_forwarder: None,
             builder_disallow: None,
@@ -444,6 +462,13 @@ pub struct RpcServerArgs {
     #[arg(long = "http.disable-compression", default_value_t = DefaultRpcServerArgs::get_global().http_disable_compression)]
     pub http_disable_compression: bool,
 
+    /// Minimum size of compressed HTTP responses in bytes, smaller responses are sent
+    /// uncompressed.
+    ///
+    /// WebSocket messages are never compressed.
+    #[arg(long = "http.compression-min-size", value_name = "BYTES", default_value_t = DefaultRpcServerArgs::get_global().http_compression_min_size)]
+    pub http_compression_min_size: u16,
+
     /// Rpc Modules to be configured for the HTTP server
     #[arg(long = "http.api", value_parser = RpcModuleSelectionValueParser::default(), default_value = Resettable::from(DefaultRpcServerArgs::get_global().http_api.as_ref().map(|v| v.to_string().into())))]
     pub http_api: Option<RpcModuleSelection>,
@@ -482,10 +507,33 @@ pub struct RpcServerArgs {
 
     /// Set the permissions for the IPC socket file, in octal format.
     ///
-    /// If not specified, the permissions will be set by the system's umask.
+    /// If not specified, the permissions will be set by the system's umask. The IPC server fails
+    /// to start if the permissions are invalid or can't be set.
     #[arg(long = "ipc.permissions", default_value = Resettable::from(DefaultRpcServerArgs::get_global().ipc_socket_permissions.as_ref().map(|v| v.to_string().into())))]
     pub ipc_socket_permissions: Option<String>,
 
+    /// Set the security descriptor of the IPC named pipe, in SDDL format, e.g. `D:(A;;GA;;;AU)`
+    /// to grant access to all authenticated users.
+    ///
+    /// Only used on Windows. If not specified, the pipe gets the default descriptor of the
+    /// process. The IPC server fails to start if the descriptor is invalid.
+    #[arg(long = "ipc.security-descriptor", value_name = "SDDL")]
+    pub ipc_security_descriptor: Option<String>,
+
+    /// Initial size of the read buffer of each IPC connection, in bytes.
+    ///
+    /// The buffer grows as needed, a larger size avoids reallocations for large requests. This is
+    /// not a size limit, requests are limited by `--rpc.max-request-size`.
+    #[arg(long = "ipc.read-buffer-size", value_name = "BYTES", default_value_t = constants::DEFAULT_IPC_BUFFER_CAPACITY)]
+    pub ipc_read_buffer_size: usize,
+
+    /// Initial size of the write buffer of each IPC connection, in bytes.
+    ///
+    /// The buffer grows as needed, a larger size avoids reallocations for large responses, e.g.
+    /// traces. This is not a size limit, responses are limited by `--rpc.max-response-size`.
+    #[arg(long = "ipc.write-buffer-size", value_name = "BYTES", default_value_t = constants::DEFAULT_IPC_BUFFER_CAPACITY)]
+    pub ipc_write_buffer_size: usize,
+
     /// Auth server address to listen on
     #[arg(long = "authrpc.addr", default_value_t = DefaultRpcServerArgs::get_global().auth_addr)]
     pub auth_addr: IpAddr,
@@ -636,6 +684,13 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.proof-permits", alias = "rpc-proof-permits", value_name = "COUNT", default_value_t = DefaultRpcServerArgs::get_global().rpc_proof_permits)]
     pub rpc_proof_permits: usize,
 
+    /// Maximum number of tasks a single getproof or `debug_executionWitness` request computes its
+    /// proofs with.
+    ///
+    /// Requests for many storage slots are split into chunks that are proven concurrently.
+    #[arg(long = "rpc.proof-concurrency", value_name = "COUNT", default_value_t = DefaultRpcServerArgs::get_global().rpc_proof_concurrency, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    pub rpc_proof_concurrency: usize,
+
     /// Configures the pending block behavior for RPC responses.
     ///
     /// Options: full (include all transactions), empty (header only), none (disable pending
@@ -691,6 +746,117 @@ pub struct RpcServerArgs {
     /// By default this is disabled, meaning transactions are submitted as-is.
     #[arg(long = "rpc.force-blob-sidecar-upcasting", default_value_t = false)]
     pub rpc_force_blob_sidecar_upcasting: bool,
+
+    /// Maximum number of blocks replayed to serve historical state that was pruned.
+    ///
+    /// When set, requests for state below the history prune horizon are served by re-executing
+    /// the blocks up to the requested block on top of the closest known state, the genesis state
+    /// or a recently reconstructed one, if at most this many blocks have to be replayed. By
+    /// default pruned historical state is unavailable.
+    #[arg(long = "rpc.pruned-state-replay-limit", value_name = "BLOCKS")]
+    pub rpc_pruned_state_replay_limit: Option<u64>,
+
+    /// Index the addresses involved in the traces of each finalized block.
+    ///
+    /// `trace_filter` requests filtering by address only replay the blocks the index reports for
+    /// them, which allows them to span wider block ranges. Indexing re-executes all blocks from
+    /// the earliest available block, so it requires their historical state.
+    #[arg(long = "rpc.trace-filter-index", default_value_t = false)]
+    pub rpc_trace_filter_index: bool,
+
+    /// Index the addresses and topics of the logs of each finalized block.
+    ///
+    /// `eth_getLogs` requests filtering by address or topic only read the blocks the index reports
+    /// for them, which cuts the latency of sparse queries over large block ranges.
+    #[arg(long = "rpc.log-index", default_value_t = false)]
+    pub rpc_log_index: bool,
+
+    /// Token bucket rate limit of RPC methods, as `<METHOD>=<RATE>[/<BURST>]` with the rate in
+    /// calls per second. Can be repeated.
+    ///
+    /// The method can end with `*` to limit all methods with the prefix, e.g. `debug_*=5/10`; an
+    /// exact match takes precedence over a prefix. Calls exceeding the limit are rejected with a
+    /// `-32005` error. The burst defaults to the rate, rounded up.
+    #[arg(long = "rpc.method-rate-limit", value_name = "METHOD=RATE[/BURST]")]
+    pub rpc_method_rate_limits: Vec<RpcMethodRateLimit>,
+
+    /// HTTP header identifying the client of a request, so that each client is rate limited
+    /// separately, e.g. `x-api-key`, or `x-forwarded-for` behind a proxy.
+    ///
+    /// Only relevant with `--rpc.method-rate-limit`.
+    #[arg(long = "rpc.rate-limit-key-header", value_name = "HEADER")]
+    pub rpc_rate_limit_key_header: Option<String>,
+
+    /// Log RPC calls taking longer than this duration, along with their method and error code.
+    ///
+    /// Calls are timed by the built-in RPC request metrics, so this has no effect with
+    /// `--rpc.disable-metrics`.
+    #[arg(
+        long = "rpc.slow-call-threshold",
+        value_name = "DURATION",
+        value_parser = parse_duration_from_secs_or_ms,
+    )]
+    pub rpc_slow_call_threshold: Option<Duration>,
+
+    /// Path to a JSON file of API keys restricting the namespaces that can be called over HTTP
+    /// and WS.
+    ///
+    /// The file maps each key to its namespaces, with `*` allowing all of them, and lists the
+    /// namespaces that can be called without a key, e.g.
+    /// `{"public":["eth","net"],"keys":{"<KEY>":["*"]}}`. The key is read from the `x-api-key`
+    /// header; calls to other namespaces are rejected. IPC calls are not restricted.
+    #[arg(long = "rpc.api-keys", value_name = "PATH", value_parser = reth_cli_util::parsers::read_json_from_file::<RpcApiKeys>)]
+    pub rpc_api_keys: Option<RpcApiKeys>,
+
+    /// Maximum total cost of the calls of a batch request.
+    ///
+    /// Calls of a batch are admitted in order until the next one would exceed the budget, that
+    /// call and the following ones are rejected with a `-32005` error. The first call of a batch
+    /// is always admitted. Tracing calls cost 10 and other calls 1, unless set with
+    /// `--rpc.method-cost`.
+    #[arg(long = "rpc.max-batch-cost", value_name = "COST")]
+    pub rpc_max_batch_cost: Option<u64>,
+
+    /// Cost of RPC methods in a batch, as `<METHOD>=<COST>`. Can be repeated.
+    ///
+    /// The method can end with `*` to set the cost of all methods with the prefix, e.g.
+    /// `debug_*=5`; an exact match takes precedence over a prefix. Only relevant with
+    /// `--rpc.max-batch-cost`.
+    #[arg(long = "rpc.method-cost", value_name = "METHOD=COST")]
+    pub rpc_method_costs: Vec<RpcMethodCost>,
+
+    /// Maximum number of `eth_call` and `eth_estimateGas` results cached at the latest block.
+    ///
+    /// Calls at the latest block without overrides are served from the cache until the next
+    /// block. Results are not cached if not set.
+    #[arg(long = "rpc.call-cache-size", value_name = "ENTRIES")]
+    pub rpc_call_cache_size: Option<u32>,
+
+    /// Maximum duration of the execution of `debug_` and `trace_` calls.
+    ///
+    /// Calls are aborted between transactions once it elapsed, reporting how many of their
+    /// transactions were executed. Calls are not limited if not set.
+    #[arg(
+        long = "rpc.tracing-timeout",
+        value_name = "DURATION",
+        value_parser = parse_duration_from_secs_or_ms,
+    )]
+    pub rpc_tracing_timeout: Option<Duration>,
+
+    /// Capacity of the queue of outbound messages of each WS and IPC connection, in
+    /// messages.
+    ///
+    /// Bounds the memory used by the subscriptions of a client that doesn't keep up with them.
+    #[arg(long = "rpc.subscription-buffer-size", value_name = "COUNT", default_value_t = constants::DEFAULT_MESSAGE_BUFFER_CAPACITY, value_parser = RangedU64ValueParser::<u32>::new().range(1..))]
+    pub rpc_subscription_buffer_size: u32,
+
+    /// Policy applied to the messages of `eth_subscribe` subscriptions once the outbound queue of
+    /// their connection is full.
+    ///
+    /// Options: block (wait for the client to catch up), drop (drop the messages that don't fit),
+    /// close (close the subscription).
+    #[arg(long = "rpc.subscription-lag-policy", default_value = "block", value_name = "POLICY")]
+    pub rpc_subscription_lag_policy: SubscriptionLagPolicy,
 }
 
 impl RpcServerArgs {
@@ -839,6 +1005,7 @@ impl Default for RpcServerArgs {
             http_addr,
             http_port,
             http_disable_compression,
+            http_compression_min_size,
             http_api,
             http_corsdomain,
             ws,
@@ -873,6 +1040,7 @@ impl Default for RpcServerArgs {
             rpc_compute_state_root_for_eth_simulate,
             rpc_eth_proof_window,
             rpc_proof_permits,
+            rpc_proof_concurrency,
             rpc_pending_block,
             rpc_forwarder,
             builder_disallow,
@@ -885,6 +1053,7 @@ impl Default for RpcServerArgs {
             http_addr,
             http_port,
             http_disable_compression,
+            http_compression_min_size,
             http_api,
             http_corsdomain,
             ws,
@@ -919,6 +1088,7 @@ impl Default for RpcServerArgs {
             rpc_compute_state_root_for_eth_simulate,
             rpc_eth_proof_window,
             rpc_proof_permits,
+            rpc_proof_concurrency,
             rpc_pending_block,
             rpc_forwarder,
             builder_disallow,
@@ -928,6 +1098,22 @@ impl Default for RpcServerArgs {
             testing_skip_invalid_transactions: true,
             testing_gas_limit: None,
             rpc_force_blob_sidecar_upcasting: false,
+            rpc_pruned_state_replay_limit: None,
+            rpc_trace_filter_index: false,
+            rpc_log_index: false,
+            rpc_method_rate_limits: Vec::new(),
+            rpc_rate_limit_key_header: None,
+            rpc_slow_call_threshold: None,
+            rpc_api_keys: None,
+            rpc_max_batch_cost: None,
+            rpc_method_costs: Vec::new(),
+            rpc_call_cache_size: None,
+            rpc_tracing_timeout: None,
+            rpc_subscription_buffer_size: constants::DEFAULT_MESSAGE_BUFFER_CAPACITY,
+            rpc_subscription_lag_policy: SubscriptionLagPolicy::Block,
+            ipc_security_descriptor: None,
+            ipc_read_buffer_size: constants::DEFAULT_IPC_BUFFER_CAPACITY,
+            ipc_write_buffer_size: constants::DEFAULT_IPC_BUFFER_CAPACITY,
         }
     }
 }
@@ -1058,6 +1244,7 @@ mod tests {
             http_addr: "127.0.0.1".parse().unwrap(),
             http_port: 8545,
             http_disable_compression: false,
+            http_compression_min_size: 32,
             http_api: Some(RpcModuleSelection::try_from_selection(["eth", "admin"]).unwrap()),
             http_corsdomain: Some("*".to_string()),
             ws: true,
@@ -1068,6 +1255,9 @@ mod tests {
             ipcdisable: false,
             ipcpath: "reth.ipc".to_string(),
             ipc_socket_permissions: Some("0o666".to_string()),
+            ipc_security_descriptor: Some("D:(A;;GA;;;AU)".to_string()),
+            ipc_read_buffer_size: 65536,
+            ipc_write_buffer_size: 1048576,
             auth_addr: "127.0.0.1".parse().unwrap(),
             auth_port: 8551,
             auth_jwtsecret: Some(std::path::PathBuf::from("/tmp/jwt.hex")),
@@ -1097,6 +1287,7 @@ mod tests {
             rpc_compute_state_root_for_eth_simulate: false,
             rpc_eth_proof_window: 100_000,
             rpc_proof_permits: 16,
+            rpc_proof_concurrency: 8,
             rpc_pending_block: PendingBlockKind::Full,
             rpc_forwarder: Some("http://localhost:8545".parse().unwrap()),
             builder_disallow: None,
@@ -1107,6 +1298,7 @@ mod tests {
                 max_bals: 1000,
                 max_concurrent_db_requests: 512,
                 max_cached_tx_hashes: 30_000,
+                provider_cache: false,
             },
             gas_price_oracle: GasPriceOracleArgs {
                 blocks: 20,
@@ -1119,6 +1311,22 @@ mod tests {
             testing_skip_invalid_transactions: true,
             testing_gas_limit: None,
             rpc_force_blob_sidecar_upcasting: false,
+            rpc_pruned_state_replay_limit: Some(10_000),
+            rpc_trace_filter_index: false,
+            rpc_log_index: false,
+            rpc_method_rate_limits: vec![
+                "debug_*=5/10".parse().unwrap(),
+                "eth_call=100".parse().unwrap(),
+            ],
+            rpc_rate_limit_key_header: Some("x-api-key".to_string()),
+            rpc_slow_call_threshold: Some(Duration::from_millis(500)),
+            rpc_api_keys: None,
+            rpc_max_batch_cost: Some(100),
+            rpc_method_costs: vec!["eth_call=2".parse().unwrap()],
+            rpc_call_cache_size: Some(1000),
+            rpc_tracing_timeout: Some(Duration::from_secs(10)),
+            rpc_subscription_buffer_size: 256,
+            rpc_subscription_lag_policy: SubscriptionLagPolicy::Close,
         };
 
         let parsed_args = CommandParser::<RpcServerArgs>::parse_from([
@@ -1145,6 +1353,12 @@ mod tests {
             "reth.ipc",
             "--ipc.permissions",
             "0o666",
+            "--ipc.security-descriptor",
+            "D:(A;;GA;;;AU)",
+            "--ipc.read-buffer-size",
+            "65536",
+            "--ipc.write-buffer-size",
+            "1048576",
             "--authrpc.addr",
             "127.0.0.1",
             "--authrpc.port",
@@ -1185,6 +1399,10 @@ mod tests {
             "100000",
             "--rpc.proof-permits",
             "16",
+            "--rpc.proof-concurrency",
+            "8",
+            "--rpc.pruned-state-replay-limit",
+            "10000",
             "--rpc.pending-block",
             "full",
             "--rpc.forwarder",
@@ -1210,6 +1428,26 @@ mod tests {
             "--rpc.send-raw-transaction-sync-timeout",
             "30s",
             "--testing.skip-invalid-transactions",
+            "--rpc.method-rate-limit",
+            "debug_*=5/10",
+            "--rpc.method-rate-limit",
+            "eth_call=100",
+            "--rpc.rate-limit-key-header",
+            "x-api-key",
+            "--rpc.slow-call-threshold",
+            "500ms",
+            "--rpc.max-batch-cost",
+            "100",
+            "--rpc.method-cost",
+            "eth_call=2",
+            "--rpc.call-cache-size",
+            "1000",
+            "--rpc.tracing-timeout",
+            "10s",
+            "--rpc.subscription-buffer-size",
+            "256",
+            "--rpc.subscription-lag-policy",
+            "close",
         ])
         .args;
 
@@ -50,6 +50,10 @@ pub struct RpcStateCacheArgs {
         default_value_t = DEFAULT_MAX_CACHED_TX_HASHES,
     )]
     pub max_cached_tx_hashes: u32,
+
+    /// Cache headers, blocks, receipts and reads of the latest state in the blockchain provider.
+    #[arg(long = "rpc-cache.provider-cache", default_value_t = false)]
+    pub provider_cache: bool,
 }
 
 impl RpcStateCacheArgs {
@@ -71,6 +75,7 @@ impl Default for RpcStateCacheArgs {
             max_bals: DEFAULT_BAL_CACHE_MAX_LEN,
             max_concurrent_db_requests: DEFAULT_CONCURRENT_DB_REQUESTS,
             max_cached_tx_hashes: DEFAULT_MAX_CACHED_TX_HASHES,
+            provider_cache: false,
         }
     }
 }
@@ -1,5 +1,6 @@
 //! Shared arguments related to stages
 use derive_more::Display;
+use reth_stages_types::StageId;
 
 /// Represents a specific stage within the data pipeline.
 ///
@@ -51,3 +52,22 @@ pub enum StageEnum {
     /// Manages historical data related to storage.
     StorageHistory,
 }
+
+impl StageEnum {
+    /// Returns the ids of the pipeline stages this variant refers to.
+    pub const fn stage_ids(&self) -> &'static [StageId] {
+        match self {
+            Self::Headers => &[StageId::Headers],
+            Self::Bodies => &[StageId::Bodies],
+            Self::Senders => &[StageId::SenderRecovery],
+            Self::Execution => &[StageId::Execution],
+            Self::AccountHashing => &[StageId::AccountHashing],
+            Self::StorageHashing => &[StageId::StorageHashing],
+            Self::Hashing => &[StageId::AccountHashing, StageId::StorageHashing],
+            Self::Merkle => &[StageId::MerkleUnwind, StageId::MerkleExecute],
+            Self::TxLookup => &[StageId::TransactionLookup],
+            Self::AccountHistory => &[StageId::IndexAccountHistory],
+            Self::StorageHistory => &[StageId::IndexStorageHistory],
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! clap [Args](clap::Args) for static files configuration
 
 use clap::Args;
-use reth_config::config::{BlocksPerFileConfig, StaticFilesConfig};
+use reth_config::config::{BlobSidecarsConfig, BlocksPerFileConfig, StaticFilesConfig};
 
 /// Blocks per static file when running in `--minimal` node.
 ///
@@ -35,6 +35,24 @@ pub struct StaticFilesArgs {
     /// Number of blocks per file for the storage changesets segment.
     #[arg(long = "static-files.blocks-per-file.storage-change-sets")]
     pub blocks_per_file_storage_change_sets: Option<u64>,
+
+    /// Number of blocks per file for the blob sidecars segment.
+    #[arg(long = "static-files.blocks-per-file.blob-sidecars")]
+    pub blocks_per_file_blob_sidecars: Option<u64>,
+
+    /// Persist the blob sidecars of canonical blocks into static files, keeping the given number
+    /// of most recent blocks.
+    ///
+    /// This allows serving `engine_getBlobs*` requests for blobs that already left the
+    /// transaction pool.
+    #[arg(long = "static-files.blob-sidecars.retention-blocks", value_name = "BLOCKS")]
+    pub blob_sidecars_retention_blocks: Option<u64>,
+
+    /// Verify the checksums of all sealed static files on startup.
+    ///
+    /// This reads every static file, so startup of a synced node takes considerably longer.
+    #[arg(long = "static-files.verify-checksums", default_value_t = false)]
+    pub verify_checksums: bool,
 }
 
 impl StaticFilesArgs {
@@ -42,7 +60,7 @@ impl StaticFilesArgs {
     /// args.
     ///
     /// If `minimal` is true, uses [`MINIMAL_BLOCKS_PER_FILE`] blocks per file as the default for
-    /// all segments.
+    /// all history segments.
     pub fn merge_with_config(&self, config: StaticFilesConfig, minimal: bool) -> StaticFilesConfig {
         let minimal_blocks_per_file = minimal.then_some(MINIMAL_BLOCKS_PER_FILE);
         StaticFilesConfig {
@@ -71,7 +89,16 @@ impl StaticFilesArgs {
                     .blocks_per_file_storage_change_sets
                     .or(minimal_blocks_per_file)
                     .or(config.blocks_per_file.storage_change_sets),
+                blob_sidecars: self
+                    .blocks_per_file_blob_sidecars
+                    .or(config.blocks_per_file.blob_sidecars),
+                block_access_lists: config.blocks_per_file.block_access_lists,
             },
+            blob_sidecars: self
+                .blob_sidecars_retention_blocks
+                .map(|retention_blocks| BlobSidecarsConfig { retention_blocks })
+                .or(config.blob_sidecars),
+            ..config
         }
     }
 }
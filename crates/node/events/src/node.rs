@@ -12,7 +12,9 @@ use reth_engine_primitives::{ConsensusEngineEvent, ForkchoiceStatus, SlowBlockIn
 use reth_network_api::PeersInfo;
 use reth_primitives_traits::{format_gas, format_gas_throughput, BlockBody, NodePrimitives};
 use reth_prune_types::PrunerEvent;
-use reth_stages::{EntitiesCheckpoint, ExecOutput, PipelineEvent, StageCheckpoint, StageId};
+use reth_stages::{
+    EntitiesCheckpoint, ExecOutput, PipelineEvent, StageCheckpoint, StageEvent, StageId, SyncEta,
+};
 use reth_static_file_types::StaticFileProducerEvent;
 use std::{
     fmt::{Display, Formatter},
@@ -140,7 +142,6 @@ impl NodeState {
                 if let Some(current_stage) = self.current_stage.as_mut() {
                     current_stage.checkpoint = checkpoint;
                     current_stage.entities_checkpoint = checkpoint.entities();
-                    current_stage.eta.update(stage_id, checkpoint);
 
                     let target = OptionalField(current_stage.target);
                     let stage_progress = current_stage
@@ -217,6 +218,16 @@ impl NodeState {
         }
     }
 
+    /// Processes a structured stage event emitted by the pipeline
+    fn handle_stage_event(&mut self, event: StageEvent) {
+        if let StageEvent::Committed { stage_id, eta, .. } = event &&
+            let Some(current_stage) = self.current_stage.as_mut() &&
+            current_stage.stage_id == stage_id
+        {
+            current_stage.eta.update(eta);
+        }
+    }
+
     fn handle_consensus_engine_event<N: NodePrimitives>(&mut self, event: ConsensusEngineEvent<N>) {
         match event {
             ConsensusEngineEvent::ForkchoiceUpdated(state, status) => {
@@ -427,6 +438,8 @@ struct CurrentStage {
 pub enum NodeEvent<N: NodePrimitives> {
     /// A sync pipeline event.
     Pipeline(PipelineEvent),
+    /// A structured stage event of the sync pipeline.
+    Stage(StageEvent),
     /// A consensus engine event.
     ConsensusEngine(ConsensusEngineEvent<N>),
     /// A Consensus Layer health event.
@@ -485,53 +498,19 @@ where
                 let stage_progress =
                     entities_checkpoint.and_then(|entities| entities.fmt_percentage());
                 let stage_eta = eta.fmt_for_stage(*stage_id);
+                let eta = eta.fmt_total();
 
-                match (stage_progress, stage_eta) {
-                    (Some(stage_progress), Some(stage_eta)) => {
-                        info!(
-                            target: "reth::cli",
-                            connected_peers = this.state.num_connected_peers(),
-                            stage = %stage_id,
-                            checkpoint = checkpoint.block_number,
-                            target = %OptionalField(*target),
-                            %stage_progress,
-                            %stage_eta,
-                            "Status"
-                        )
-                    }
-                    (Some(stage_progress), None) => {
-                        info!(
-                            target: "reth::cli",
-                            connected_peers = this.state.num_connected_peers(),
-                            stage = %stage_id,
-                            checkpoint = checkpoint.block_number,
-                            target = %OptionalField(*target),
-                            %stage_progress,
-                            "Status"
-                        )
-                    }
-                    (None, Some(stage_eta)) => {
-                        info!(
-                            target: "reth::cli",
-                            connected_peers = this.state.num_connected_peers(),
-                            stage = %stage_id,
-                            checkpoint = checkpoint.block_number,
-                            target = %OptionalField(*target),
-                            %stage_eta,
-                            "Status"
-                        )
-                    }
-                    (None, None) => {
-                        info!(
-                            target: "reth::cli",
-                            connected_peers = this.state.num_connected_peers(),
-                            stage = %stage_id,
-                            checkpoint = checkpoint.block_number,
-                            target = %OptionalField(*target),
-                            "Status"
-                        )
-                    }
-                }
+                info!(
+                    target: "reth::cli",
+                    connected_peers = this.state.num_connected_peers(),
+                    stage = %stage_id,
+                    checkpoint = checkpoint.block_number,
+                    target = %OptionalField(*target),
+                    stage_progress = stage_progress.as_deref(),
+                    stage_eta = stage_eta.as_deref(),
+                    eta = eta.as_deref(),
+                    "Status"
+                )
             } else {
                 let now =
                     SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
@@ -562,6 +541,9 @@ where
                 NodeEvent::Pipeline(event) => {
                     this.state.handle_pipeline_event(event);
                 }
+                NodeEvent::Stage(event) => {
+                    this.state.handle_stage_event(event);
+                }
                 NodeEvent::ConsensusEngine(event) => {
                     this.state.handle_consensus_engine_event(event);
                 }
@@ -584,87 +566,52 @@ where
     }
 }
 
-/// A container calculating the estimated time that a stage will complete in, based on stage
-/// checkpoints reported by the pipeline.
+/// The estimated time left until the current stage, and all stages after it, reach the pipeline
+/// target, as reported by the pipeline.
 ///
 /// One `Eta` is only valid for a single stage.
 #[derive(Default, Copy, Clone)]
 struct Eta {
-    /// The last stage checkpoint
-    last_checkpoint: EntitiesCheckpoint,
-    /// The last time the stage reported its checkpoint
-    last_checkpoint_time: Option<Instant>,
-    /// The current ETA
-    eta: Option<Duration>,
+    /// The last ETA reported by the pipeline
+    eta: SyncEta,
+    /// The time the last ETA was reported
+    updated_at: Option<Instant>,
 }
 
 impl Eta {
-    /// Update the ETA given the checkpoint, if possible.
-    fn update(&mut self, stage: StageId, checkpoint: StageCheckpoint) {
-        let Some(current) = checkpoint.entities() else { return };
-
-        if let Some(last_checkpoint_time) = &self.last_checkpoint_time {
-            let Some(processed_since_last) =
-                current.processed.checked_sub(self.last_checkpoint.processed)
-            else {
-                self.eta = None;
-                debug!(target: "reth::cli", %stage, ?current, ?self.last_checkpoint, "Failed to calculate the ETA: processed entities is less than the last checkpoint");
-                return
-            };
-            let elapsed = last_checkpoint_time.elapsed();
-            let per_second = processed_since_last as f64 / elapsed.as_secs_f64();
-
-            let Some(remaining) = current.total.checked_sub(current.processed) else {
-                self.eta = None;
-                debug!(target: "reth::cli", %stage, ?current, "Failed to calculate the ETA: total entities is less than processed entities");
-                return
-            };
-
-            self.eta = Duration::try_from_secs_f64(remaining as f64 / per_second).ok();
-        }
-
-        self.last_checkpoint = current;
-        self.last_checkpoint_time = Some(Instant::now());
-    }
-
-    /// Returns `true` if the ETA is available, i.e. at least one checkpoint has been reported.
-    fn is_available(&self) -> bool {
-        self.eta.zip(self.last_checkpoint_time).is_some()
+    /// Update the ETA with the one reported by the pipeline.
+    fn update(&mut self, eta: SyncEta) {
+        self.eta = eta;
+        self.updated_at = Some(Instant::now());
     }
 
     /// Format ETA for a given stage.
     ///
     /// NOTE: Currently ETA is enabled only for the stages that have predictable progress.
-    /// It's not the case for network-dependent ([`StageId::Headers`] and [`StageId::Bodies`]) and
-    /// [`StageId::Execution`] stages.
+    /// It's not the case for network-dependent ([`StageId::Headers`] and [`StageId::Bodies`])
+    /// stages.
     fn fmt_for_stage(&self, stage: StageId) -> Option<String> {
-        if !self.is_available() ||
-            matches!(stage, StageId::Headers | StageId::Bodies | StageId::Execution)
-        {
+        if matches!(stage, StageId::Headers | StageId::Bodies) {
             None
         } else {
-            Some(self.to_string())
+            self.fmt_remaining(self.eta.stage)
         }
     }
-}
 
-impl Display for Eta {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if let Some((eta, last_checkpoint_time)) = self.eta.zip(self.last_checkpoint_time) {
-            let remaining = eta.checked_sub(last_checkpoint_time.elapsed());
-
-            if let Some(remaining) = remaining {
-                return write!(
-                    f,
-                    "{}",
-                    humantime::format_duration(Duration::from_secs(remaining.as_secs()))
-                        .to_string()
-                        .replace(' ', "")
-                )
-            }
-        }
+    /// Format ETA until all stages reach the pipeline target.
+    fn fmt_total(&self) -> Option<String> {
+        self.fmt_remaining(self.eta.total)
+    }
 
-        write!(f, "unknown")
+    /// Formats the time left of the given ETA, accounting for the time passed since it was
+    /// reported.
+    fn fmt_remaining(&self, eta: Option<Duration>) -> Option<String> {
+        let remaining = eta?.checked_sub(self.updated_at?.elapsed())?;
+        Some(
+            humantime::format_duration(Duration::from_secs(remaining.as_secs()))
+                .to_string()
+                .replace(' ', ""),
+        )
     }
 }
 
@@ -675,16 +622,19 @@ mod tests {
     #[test]
     fn eta_display_no_milliseconds() {
         let eta = Eta {
-            last_checkpoint_time: Some(Instant::now()),
-            eta: Some(Duration::from_millis(
-                13 * 60 * 1000 + // Minutes
-                    37 * 1000 + // Seconds
-                    999, // Milliseconds
-            )),
-            ..Default::default()
-        }
-        .to_string();
+            eta: SyncEta {
+                stage: Some(Duration::from_millis(
+                    13 * 60 * 1000 + // Minutes
+                        37 * 1000 + // Seconds
+                        999, // Milliseconds
+                )),
+                total: None,
+            },
+            updated_at: Some(Instant::now()),
+        };
 
-        assert_eq!(eta, "13m37s");
+        assert_eq!(eta.fmt_for_stage(StageId::Execution).as_deref(), Some("13m37s"));
+        assert_eq!(eta.fmt_for_stage(StageId::Bodies), None);
+        assert_eq!(eta.fmt_total(), None);
     }
 }
@@ -1,4 +1,4 @@
-use reth_errors::{DatabaseError, RethError};
+use reth_errors::{DatabaseError, PruneError, RethError};
 use reth_provider::ProviderError;
 use reth_prune_types::PruneSegmentError;
 use thiserror::Error;
@@ -17,6 +17,10 @@ pub enum PrunerError {
 
     #[error(transparent)]
     Provider(#[from] ProviderError),
+
+    /// Pruning a segment failed, carrying the segment, target and progress reached.
+    #[error(transparent)]
+    Segment(Box<PruneError>),
 }
 
 impl From<PrunerError> for RethError {
@@ -25,6 +29,7 @@ impl From<PrunerError> for RethError {
             PrunerError::PruneSegment(_) | PrunerError::InconsistentData(_) => Self::other(err),
             PrunerError::Database(err) => Self::Database(err),
             PrunerError::Provider(err) => Self::Provider(err),
+            PrunerError::Segment(err) => Self::Prune(err),
         }
     }
 }
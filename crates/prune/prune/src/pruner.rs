@@ -5,6 +5,7 @@ use crate::{
     Metrics, PruneLimiter, PrunerError, PrunerEvent,
 };
 use alloy_primitives::BlockNumber;
+use reth_errors::PruneError;
 use reth_exex_types::FinishedExExHeight;
 use reth_primitives_traits::FastInstant as Instant;
 use reth_provider::{
@@ -240,10 +241,20 @@ where
 
                 let segment_start = Instant::now();
                 let previous_checkpoint = provider.get_prune_checkpoint(segment.segment())?;
-                let segment_output = segment.prune(
-                    provider,
-                    PruneInput { previous_checkpoint, to_block, limiter: limiter.clone() },
-                )?;
+                let segment_output = segment
+                    .prune(
+                        provider,
+                        PruneInput { previous_checkpoint, to_block, limiter: limiter.clone() },
+                    )
+                    .map_err(|err| {
+                        PrunerError::Segment(Box::new(PruneError {
+                            segment: segment.segment(),
+                            target: to_block,
+                            progress: previous_checkpoint
+                                .and_then(|checkpoint| checkpoint.block_number),
+                            source: err.into(),
+                        }))
+                    })?;
                 if let Some(checkpoint) = segment_output.checkpoint {
                     segment
                         .save_checkpoint(provider, checkpoint.as_prune_checkpoint(prune_mode))?;
@@ -381,6 +392,7 @@ fn is_stage_finished<Provider: StageCheckpointReader>(
 #[cfg(test)]
 mod tests {
     use crate::Pruner;
+    use reth_errors::PruneError;
     use reth_exex_types::FinishedExExHeight;
     use reth_provider::test_utils::create_test_provider_factory;
 
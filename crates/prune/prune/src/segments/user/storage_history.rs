@@ -200,7 +200,23 @@ impl StorageHistory {
         // additionally limited by the `max_reorg_depth`, so no OOM is expected here.
         let mut last_changeset_pruned_block = None;
         let mut highest_deleted_storages = FxHashMap::default();
-        let (pruned_changesets, done) =
+
+        // Packed changesets belong to the oldest blocks, so they're pruned first.
+        let (pruned_packed_changesets, packed_done) =
+            provider.tx_ref().prune_table_with_range::<tables::PackedStorageChangeSets>(
+                BlockNumberAddress::range(range.clone()),
+                &mut limiter,
+                |_| false,
+                |(BlockNumberAddress((block_number, address)), changeset)| {
+                    for entry in changeset.entries() {
+                        highest_deleted_storages.insert((address, entry.key), block_number);
+                    }
+                    last_changeset_pruned_block = Some(block_number);
+                },
+            )?;
+        trace!(target: "pruner", deleted = %pruned_packed_changesets, done = %packed_done, "Pruned storage history (packed changesets)");
+
+        let (mut pruned_changesets, done) = if packed_done {
             provider.tx_ref().prune_table_with_range::<tables::StorageChangeSets>(
                 BlockNumberAddress::range(range),
                 &mut limiter,
@@ -209,8 +225,12 @@ impl StorageHistory {
                     highest_deleted_storages.insert((address, entry.key), block_number);
                     last_changeset_pruned_block = Some(block_number);
                 },
-            )?;
+            )?
+        } else {
+            (0, false)
+        };
         trace!(target: "pruner", deleted = %pruned_changesets, %done, "Pruned storage history (changesets)");
+        pruned_changesets += pruned_packed_changesets;
 
         let result = HistoryPruneResult {
             highest_deleted: highest_deleted_storages,
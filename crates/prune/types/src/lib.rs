@@ -31,7 +31,8 @@ pub use pruner::{
 };
 pub use segment::{PrunePurpose, PruneSegment, PruneSegmentError};
 pub use target::{
-    PruneModes, UnwindTargetPrunedError, MINIMUM_DISTANCE, MINIMUM_UNWIND_SAFE_DISTANCE,
+    PruneModes, PrunedDataRetainedError, UnwindTargetPrunedError, MINIMUM_DISTANCE,
+    MINIMUM_UNWIND_SAFE_DISTANCE,
 };
 
 /// Configuration for pruning receipts not associated with logs emitted by the specified contracts.
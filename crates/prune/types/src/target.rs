@@ -33,6 +33,18 @@ pub enum UnwindTargetPrunedError {
     },
 }
 
+/// Error returned when a pruning configuration retains data that was already pruned from disk.
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+#[error("{segment} data is pruned up to block {pruned_block}, but the configured prune mode {mode:?} retains it")]
+pub struct PrunedDataRetainedError {
+    /// The segment whose data was already pruned.
+    pub segment: PruneSegment,
+    /// The highest pruned block of the segment.
+    pub pruned_block: BlockNumber,
+    /// The configured prune mode of the segment, or `None` if the segment is not pruned.
+    pub mode: Option<PruneMode>,
+}
+
 #[derive(Debug, Display, Clone, PartialEq, Eq)]
 pub enum HistoryType {
     /// Account history
@@ -121,6 +133,45 @@ impl PruneModes {
         }
     }
 
+    /// Returns an error if this configuration retains data that was already pruned according to the
+    /// given prune checkpoints, i.e. the data on disk can't satisfy it.
+    ///
+    /// Segments filtered by [`Self::receipts_log_filter`] are only checked for whether receipts are
+    /// pruned at all.
+    pub fn ensure_pruned_data_not_retained(
+        &self,
+        tip: BlockNumber,
+        checkpoints: &[(PruneSegment, PruneCheckpoint)],
+    ) -> Result<(), PrunedDataRetainedError> {
+        for (segment, checkpoint) in checkpoints {
+            let Some(pruned_block) = checkpoint.block_number else { continue };
+
+            #[expect(deprecated)]
+            let mode = match segment {
+                PruneSegment::SenderRecovery => self.sender_recovery,
+                PruneSegment::TransactionLookup => self.transaction_lookup,
+                PruneSegment::Receipts => self.receipts,
+                PruneSegment::ContractLogs if !self.receipts_log_filter.is_empty() => continue,
+                PruneSegment::ContractLogs => self.receipts,
+                PruneSegment::AccountHistory => self.account_history,
+                PruneSegment::StorageHistory => self.storage_history,
+                PruneSegment::Bodies => self.bodies_history,
+                PruneSegment::Headers |
+                PruneSegment::Transactions |
+                PruneSegment::MerkleChangeSets => continue,
+            };
+
+            // The tip may be lower than when the segment was pruned, e.g. after an unwind, so the
+            // prune target is computed from the tip at which the checkpoint was made.
+            let pruned_tip = prune_target_tip(checkpoint.prune_mode, *segment, pruned_block);
+            let target = mode.and_then(|mode| prune_target(mode, *segment, pruned_tip.max(tip)));
+            if !target.is_some_and(|target| target >= pruned_block) {
+                return Err(PrunedDataRetainedError { segment: *segment, pruned_block, mode })
+            }
+        }
+        Ok(())
+    }
+
     /// Returns an error if we can't unwind to the targeted block because the target block is
     /// outside the range.
     ///
@@ -170,6 +221,35 @@ impl PruneModes {
     }
 }
 
+/// Returns the highest block pruned by the prune mode at the given tip, ignoring whether the
+/// segment was pruned recently.
+const fn prune_target(
+    mode: PruneMode,
+    segment: PruneSegment,
+    tip: BlockNumber,
+) -> Option<BlockNumber> {
+    match mode {
+        PruneMode::Full => tip.checked_sub(segment.min_blocks()),
+        PruneMode::Distance(distance) => tip.checked_sub(distance),
+        PruneMode::Before(block) => block.checked_sub(1),
+    }
+}
+
+/// Returns the tip at which the prune mode prunes up to the given block, the inverse of
+/// [`prune_target`].
+const fn prune_target_tip(
+    mode: PruneMode,
+    segment: PruneSegment,
+    target: BlockNumber,
+) -> BlockNumber {
+    match mode {
+        PruneMode::Full => target.saturating_add(segment.min_blocks()),
+        PruneMode::Distance(distance) => target.saturating_add(distance),
+        // independent of the tip
+        PruneMode::Before(_) => target,
+    }
+}
+
 /// Deserializes [`Option<PruneMode>`] and validates that the value is not less than the const
 /// generic parameter `MIN_BLOCKS`. This parameter represents the number of blocks that needs to be
 /// left in database after the pruning.
@@ -246,6 +326,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ensure_pruned_data_not_retained() {
+        let checkpoint = |segment, block_number, prune_mode| {
+            (
+                segment,
+                PruneCheckpoint { block_number: Some(block_number), tx_number: None, prune_mode },
+            )
+        };
+        let checkpoints = vec![
+            checkpoint(PruneSegment::SenderRecovery, 900, PruneMode::Full),
+            checkpoint(PruneSegment::AccountHistory, 800, PruneMode::Distance(200)),
+        ];
+
+        // Nothing was pruned yet
+        assert!(PruneModes::default().ensure_pruned_data_not_retained(1000, &[]).is_ok());
+
+        // Same or more aggressive pruning
+        let prune_modes = PruneModes {
+            sender_recovery: Some(PruneMode::Full),
+            account_history: Some(PruneMode::Distance(100)),
+            ..Default::default()
+        };
+        assert!(prune_modes.ensure_pruned_data_not_retained(1000, &checkpoints).is_ok());
+
+        // Archive configuration on a pruned database
+        assert_eq!(
+            PruneModes::default().ensure_pruned_data_not_retained(1000, &checkpoints),
+            Err(PrunedDataRetainedError {
+                segment: PruneSegment::SenderRecovery,
+                pruned_block: 900,
+                mode: None,
+            })
+        );
+
+        // Longer history than what is left on disk
+        let prune_modes = PruneModes {
+            sender_recovery: Some(PruneMode::Full),
+            account_history: Some(PruneMode::Distance(500)),
+            ..Default::default()
+        };
+        assert_eq!(
+            prune_modes.ensure_pruned_data_not_retained(1000, &checkpoints),
+            Err(PrunedDataRetainedError {
+                segment: PruneSegment::AccountHistory,
+                pruned_block: 800,
+                mode: Some(PruneMode::Distance(500)),
+            })
+        );
+
+        // Restart at the same tip as the last pruning
+        let prune_modes = PruneModes {
+            sender_recovery: Some(PruneMode::Full),
+            account_history: Some(PruneMode::Distance(200)),
+            ..Default::default()
+        };
+        assert!(prune_modes.ensure_pruned_data_not_retained(1000, &checkpoints).is_ok());
+
+        // Restart after an unwind below the tip of the last pruning
+        assert!(prune_modes.ensure_pruned_data_not_retained(950, &checkpoints).is_ok());
+        assert!(prune_modes.ensure_pruned_data_not_retained(800, &checkpoints).is_ok());
+
+        // A longer distance is still rejected after an unwind
+        let prune_modes = PruneModes {
+            sender_recovery: Some(PruneMode::Full),
+            account_history: Some(PruneMode::Distance(300)),
+            ..Default::default()
+        };
+        assert_eq!(
+            prune_modes.ensure_pruned_data_not_retained(950, &checkpoints),
+            Err(PrunedDataRetainedError {
+                segment: PruneSegment::AccountHistory,
+                pruned_block: 800,
+                mode: Some(PruneMode::Distance(300)),
+            })
+        );
+
+        // Pruning before a block is independent of the tip
+        let checkpoints = vec![checkpoint(PruneSegment::Receipts, 499, PruneMode::Before(500))];
+        let prune_modes =
+            PruneModes { receipts: Some(PruneMode::Before(500)), ..Default::default() };
+        assert!(prune_modes.ensure_pruned_data_not_retained(100, &checkpoints).is_ok());
+        let prune_modes =
+            PruneModes { receipts: Some(PruneMode::Before(400)), ..Default::default() };
+        assert!(prune_modes.ensure_pruned_data_not_retained(1000, &checkpoints).is_err());
+    }
+
     #[test]
     fn test_unwind_target_unpruned() {
         // Test case 1: No pruning configured - should always succeed
@@ -1,8 +1,14 @@
 use alloc::vec::Vec;
 use alloy_primitives::{keccak256, Bytes, B256};
-use reth_trie::{ExecutionWitnessMode, HashedPostState, HashedStorage};
+use reth_trie::{ExecutionWitnessMode, HashedPostState, HashedStorage, TrieInput};
 use revm::database::State;
 
+/// Minimum number of storage slots in each chunk of an execution witness computed concurrently.
+///
+/// Smaller witnesses are computed in a single chunk, since each chunk walks the account trie
+/// again.
+const MIN_WITNESS_SLOTS_PER_CHUNK: usize = 16;
+
 /// Tracks state changes during execution.
 #[derive(Debug, Clone, Default)]
 pub struct ExecutionWitnessRecord {
@@ -100,7 +106,6 @@ impl ExecutionWitnessRecord {
     /// The `block_number` is the number of the block being witnessed. Ancestor headers are
     /// included based on the lowest block number referenced by BLOCKHASH opcodes during
     /// execution, or just the parent header if BLOCKHASH was not called.
-    #[cfg(feature = "witness")]
     pub fn into_execution_witness<SP, HP>(
         self,
         state_provider: &SP,
@@ -112,10 +117,29 @@ impl ExecutionWitnessRecord {
         SP: reth_storage_api::StateProofProvider + ?Sized,
         HP: reth_storage_api::HeaderProvider + ?Sized,
         HP::Header: alloy_rlp::Encodable,
+    {
+        self.into_execution_witness_with(headers_provider, block_number, |hashed_state| {
+            state_provider.witness(Default::default(), hashed_state, mode)
+        })
+    }
+
+    /// Converts this record into a complete [`alloy_rpc_types_debug::ExecutionWitness`], with the
+    /// trie nodes computed by `state_witness` from the recorded state.
+    fn into_execution_witness_with<HP>(
+        self,
+        headers_provider: &HP,
+        block_number: u64,
+        state_witness: impl FnOnce(
+            HashedPostState,
+        ) -> reth_storage_errors::provider::ProviderResult<Vec<Bytes>>,
+    ) -> reth_storage_errors::provider::ProviderResult<alloy_rpc_types_debug::ExecutionWitness>
+    where
+        HP: reth_storage_api::HeaderProvider + ?Sized,
+        HP::Header: alloy_rlp::Encodable,
     {
         let Self { hashed_state, codes, keys, lowest_block_number } = self;
 
-        let state = state_provider.witness(Default::default(), hashed_state, mode)?;
+        let state = state_witness(hashed_state)?;
         let mut exec_witness =
             alloy_rpc_types_debug::ExecutionWitness { state, codes, keys, ..Default::default() };
 
@@ -135,3 +159,217 @@ impl ExecutionWitnessRecord {
         Ok(exec_witness)
     }
 }
+
+/// Splits the recorded state into at most `chunks` targets, whose witnesses are computed
+/// concurrently and merged.
+///
+/// The storage of an account is never split. The first chunk contains all account changes and
+/// the storage of accounts that are empty after the block, so that the account trie is updated
+/// exactly as for the whole state. The storage of non-empty accounts is spread over the other
+/// chunks, whose account updates don't restructure the account trie.
+fn split_witness_targets(state: HashedPostState, chunks: usize) -> Vec<HashedPostState> {
+    let slots = state.storages.values().map(|storage| storage.storage.len()).sum::<usize>();
+    let chunks = chunks.min(slots.div_ceil(MIN_WITNESS_SLOTS_PER_CHUNK)).max(1);
+    if chunks == 1 {
+        return alloc::vec![state]
+    }
+
+    let HashedPostState { accounts, storages } = state;
+    let mut targets = alloc::vec![HashedPostState::default(); chunks];
+    let mut target_slots = alloc::vec![0; chunks];
+    for (hashed_address, storage) in storages {
+        let target = match accounts.get(&hashed_address) {
+            Some(Some(account)) if !account.is_empty() => {
+                let target = (1..chunks).min_by_key(|target| target_slots[*target]).unwrap_or(0);
+                targets[target].accounts.insert(hashed_address, Some(*account));
+                target
+            }
+            _ => 0,
+        };
+        target_slots[target] += storage.storage.len();
+        targets[target].storages.insert(hashed_address, storage);
+    }
+    targets[0].accounts = accounts;
+    targets.retain(|target| !target.is_empty());
+    targets
+}
+
+/// A provider that assembles the execution witness needed to statelessly execute a block, e.g. for
+/// zk provers or `debug_executionWitness`.
+///
+/// The witness is built from the state accessed while executing the block, as recorded in an
+/// [`ExecutionWitnessRecord`]. Trie nodes are proven against the state of the parent block, and
+/// the ancestor headers referenced by `BLOCKHASH` are read from the header provider.
+pub trait StateWitnessProvider {
+    /// Returns the execution witness of the block with the given header, from the recorded state
+    /// accessed while executing it.
+    ///
+    /// The trie nodes are computed with up to `concurrency` threads.
+    fn execution_witness<H: reth_primitives_traits::BlockHeader>(
+        &self,
+        header: &H,
+        record: ExecutionWitnessRecord,
+        mode: ExecutionWitnessMode,
+        concurrency: usize,
+    ) -> reth_storage_errors::provider::ProviderResult<alloy_rpc_types_debug::ExecutionWitness>;
+
+    /// Returns the execution witness of the block with the given header, from the state after
+    /// executing the block on top of its parent.
+    fn execution_witness_from_state<H: reth_primitives_traits::BlockHeader, DB>(
+        &self,
+        header: &H,
+        state: &State<DB>,
+        mode: ExecutionWitnessMode,
+        concurrency: usize,
+    ) -> reth_storage_errors::provider::ProviderResult<alloy_rpc_types_debug::ExecutionWitness>
+    {
+        self.execution_witness(
+            header,
+            ExecutionWitnessRecord::from_executed_state(state, mode),
+            mode,
+            concurrency,
+        )
+    }
+}
+
+impl<P> StateWitnessProvider for P
+where
+    P: reth_storage_api::StateProviderFactory
+        + reth_storage_api::HeaderProvider<Header: alloy_rlp::Encodable>
+        + Sync,
+{
+    fn execution_witness<H: reth_primitives_traits::BlockHeader>(
+        &self,
+        header: &H,
+        record: ExecutionWitnessRecord,
+        mode: ExecutionWitnessMode,
+        concurrency: usize,
+    ) -> reth_storage_errors::provider::ProviderResult<alloy_rpc_types_debug::ExecutionWitness>
+    {
+        let parent_state = self.state_by_block_hash(header.parent_hash())?;
+        record.into_execution_witness_with(self, header.number(), |hashed_state| {
+            let targets = split_witness_targets(hashed_state, concurrency);
+            if targets.len() == 1 {
+                let target = targets.into_iter().next().expect("one target");
+                return parent_state.witness(Default::default(), target, mode)
+            }
+
+            // Compute the input of the parent state, e.g. the reverts of historical state, once.
+            // Every thread opens its own read-only snapshot of the base state and computes the
+            // witness of its chunk on top of it.
+            let (base, input) = parent_state
+                .proof_input()?
+                .unwrap_or_else(|| (header.parent_hash(), TrieInput::default()));
+            drop(parent_state);
+
+            let witnesses = std::thread::scope(|scope| {
+                let handles = targets
+                    .into_iter()
+                    .map(|target| {
+                        let input = &input;
+                        scope.spawn(move || {
+                            let state = self.state_by_block_hash(base)?;
+                            state.witness(input.clone(), target, mode)
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                handles
+                    .into_iter()
+                    .map(|handle| {
+                        handle.join().unwrap_or_else(|err| std::panic::resume_unwind(err))
+                    })
+                    .collect::<reth_storage_errors::provider::ProviderResult<Vec<_>>>()
+            })?;
+
+            let mut nodes = witnesses.into_iter().flatten().collect::<Vec<_>>();
+            nodes.sort_unstable();
+            nodes.dedup();
+            Ok(nodes)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::Header;
+    use alloy_primitives::{Address, U256};
+    use reth_primitives_traits::Account;
+    use reth_storage_api::noop::NoopProvider;
+    use revm::state::AccountInfo;
+
+    fn executed_state() -> State<revm::database::EmptyDB> {
+        let mut state = State::builder().with_bundle_update().build();
+        state.insert_account_with_storage(
+            Address::with_last_byte(1),
+            AccountInfo { balance: U256::from(1), ..Default::default() },
+            [(U256::from(2), U256::from(3))].into_iter().collect(),
+        );
+        state
+    }
+
+    #[test]
+    fn records_executed_state() {
+        let address = Address::with_last_byte(1);
+        let record =
+            ExecutionWitnessRecord::from_executed_state(&executed_state(), Default::default());
+
+        let hashed_address = keccak256(address);
+        assert!(record.hashed_state.accounts[&hashed_address].is_some());
+        let slot = B256::from(U256::from(2));
+        assert_eq!(
+            record.hashed_state.storages[&hashed_address].storage[&keccak256(slot)],
+            U256::from(3)
+        );
+        assert_eq!(record.keys, [Bytes::from(address.to_vec()), slot.into()]);
+        assert_eq!(record.lowest_block_number, None);
+    }
+
+    #[test]
+    fn witness_from_state() {
+        let header = Header { number: 1, ..Default::default() };
+        let witness = NoopProvider::default()
+            .execution_witness_from_state(&header, &executed_state(), Default::default(), 4)
+            .unwrap();
+
+        let slot = B256::from(U256::from(2));
+        assert_eq!(witness.keys, [Bytes::from(Address::with_last_byte(1).to_vec()), slot.into()]);
+    }
+
+    #[test]
+    fn split_witness_targets_by_account() {
+        let account = Account { nonce: 1, ..Default::default() };
+        let storage = || {
+            HashedStorage::from_iter(
+                false,
+                (0..32).map(|slot| (B256::with_last_byte(slot), U256::from(1))),
+            )
+        };
+        let state = HashedPostState {
+            accounts: [
+                (B256::with_last_byte(1), Some(account)),
+                (B256::with_last_byte(2), Some(account)),
+                (B256::with_last_byte(3), None),
+            ]
+            .into_iter()
+            .collect(),
+            storages: (1..=3).map(|address| (B256::with_last_byte(address), storage())).collect(),
+        };
+
+        assert_eq!(split_witness_targets(state.clone(), 1), [state.clone()]);
+
+        let targets = split_witness_targets(state.clone(), 4);
+        assert_eq!(targets.len(), 3);
+        // the first chunk updates all accounts, and the storage of the destroyed account
+        assert_eq!(targets[0].accounts, state.accounts);
+        assert!(targets[0].storages.contains_key(&B256::with_last_byte(3)));
+        for target in &targets[1..] {
+            assert_eq!(target.accounts.len(), 1);
+            assert!(target.accounts.keys().all(|address| target.storages.contains_key(address)));
+        }
+        for (address, storage) in &state.storages {
+            let chunks = targets.iter().filter_map(|target| target.storages.get(address));
+            assert_eq!(chunks.collect::<Vec<_>>(), [storage]);
+        }
+    }
+}
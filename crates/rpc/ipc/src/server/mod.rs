@@ -31,7 +31,7 @@ use tokio::{
     sync::oneshot,
 };
 use tower::{layer::util::Identity, Layer, Service};
-use tracing::{debug, instrument, trace, warn, Instrument};
+use tracing::{debug, instrument, trace, Instrument};
 // re-export so can be used during builder setup
 use crate::{
     server::{connection::IpcConnDriver, rpc_service::RpcServiceCfg},
@@ -39,6 +39,7 @@ use crate::{
 };
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
+use tokio_util::codec::Framed;
 use tower::layer::{util::Stack, LayerFn};
 
 mod connection;
@@ -121,6 +122,44 @@ where
         Ok(server_handle)
     }
 
+    /// Creates the listener of the endpoint with the configured security settings.
+    ///
+    /// Fails if the socket permissions or the security descriptor are invalid or can't be applied,
+    /// instead of listening with the defaults.
+    fn create_listener(&self) -> io::Result<LocalSocketListener> {
+        #[cfg(unix)]
+        let mode = self
+            .cfg
+            .ipc_socket_permissions
+            .as_deref()
+            .map(|perms| {
+                u32::from_str_radix(perms.trim_start_matches("0o"), 8).map_err(|err| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("invalid IPC socket permissions `{perms}`: {err}"),
+                    )
+                })
+            })
+            .transpose()?;
+
+        let name = self.endpoint.as_str().to_fs_name::<GenericFilePath>()?;
+        let listener = self.cfg.listener_options(name)?.create_tokio()?;
+
+        #[cfg(unix)]
+        if let Some(mode) = mode {
+            use std::os::unix::fs::PermissionsExt;
+            if let Err(err) =
+                std::fs::set_permissions(&self.endpoint, std::fs::Permissions::from_mode(mode))
+            {
+                drop(listener);
+                let _ = std::fs::remove_file(&self.endpoint);
+                return Err(err)
+            }
+        }
+
+        Ok(listener)
+    }
+
     async fn start_inner(
         self,
         methods: Methods,
@@ -136,26 +175,8 @@ where
             }
         }
 
-        let listener = match self
-            .endpoint
-            .as_str()
-            .to_fs_name::<GenericFilePath>()
-            .and_then(|name| ListenerOptions::new().name(name).create_tokio())
-        {
-            Ok(listener) => {
-                #[cfg(unix)]
-                {
-                    // set permissions only on unix
-                    use std::os::unix::fs::PermissionsExt;
-                    if let Some(perms_str) = &self.cfg.ipc_socket_permissions &&
-                        let Ok(mode) = u32::from_str_radix(&perms_str.replace("0o", ""), 8)
-                    {
-                        let perms = std::fs::Permissions::from_mode(mode);
-                        let _ = std::fs::set_permissions(&self.endpoint, perms);
-                    }
-                }
-                listener
-            }
+        let listener = match self.create_listener() {
+            Ok(listener) => listener,
             Err(err) => {
                 on_ready
                     .send(Err(IpcServerStartError { endpoint: self.endpoint.clone(), source: err }))
@@ -475,10 +496,13 @@ fn process_connection<RpcMiddleware, HttpMiddleware>(
         local_socket_stream,
     } = params;
 
-    let ipc = IpcConn(tokio_util::codec::Decoder::framed(
-        StreamCodec::stream_incoming(),
+    let mut framed = Framed::with_capacity(
         local_socket_stream,
-    ));
+        StreamCodec::stream_incoming(),
+        server_cfg.read_buffer_capacity,
+    );
+    framed.write_buffer_mut().reserve(server_cfg.write_buffer_capacity);
+    let ipc = IpcConn(framed);
 
     let (tx, rx) = mpsc::channel::<Box<JsonRawValue>>(server_cfg.message_buffer_capacity as usize);
     let method_sink = MethodSink::new_with_limit(tx, server_cfg.max_response_body_size);
@@ -567,6 +591,47 @@ pub struct Settings {
     tokio_runtime: Option<tokio::runtime::Handle>,
     /// The permissions to create the IPC socket with.
     ipc_socket_permissions: Option<String>,
+    /// Security descriptor of the named pipe, in SDDL format. Only used on windows.
+    ipc_security_descriptor: Option<String>,
+    /// Initial capacity in bytes of the read buffer of each connection.
+    read_buffer_capacity: usize,
+    /// Initial capacity in bytes of the write buffer of each connection.
+    write_buffer_capacity: usize,
+}
+
+impl Settings {
+    /// Returns the options of the listener of the endpoint.
+    ///
+    /// Fails if the security descriptor is invalid.
+    fn listener_options<'a>(
+        &self,
+        name: interprocess::local_socket::Name<'a>,
+    ) -> io::Result<ListenerOptions<'a>> {
+        let options = ListenerOptions::new().name(name);
+        let Some(sddl) = self.ipc_security_descriptor.as_deref() else { return Ok(options) };
+
+        #[cfg(windows)]
+        {
+            use interprocess::os::windows::{
+                local_socket::ListenerOptionsExt, security_descriptor::SecurityDescriptor,
+            };
+            let descriptor = widestring::U16CString::from_str(sddl)
+                .map_err(io::Error::other)
+                .and_then(|sddl| SecurityDescriptor::deserialize(&sddl))
+                .map_err(|err| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("invalid IPC security descriptor `{sddl}`: {err}"),
+                    )
+                })?;
+            return Ok(options.security_descriptor(descriptor))
+        }
+        #[cfg(not(windows))]
+        {
+            debug!(%sddl, "ignoring IPC security descriptor, only supported on windows");
+            Ok(options)
+        }
+    }
 }
 
 impl Default for Settings {
@@ -580,6 +645,9 @@ impl Default for Settings {
             message_buffer_capacity: 1024,
             tokio_runtime: None,
             ipc_socket_permissions: None,
+            ipc_security_descriptor: None,
+            read_buffer_capacity: 8 * 1024,
+            write_buffer_capacity: 8 * 1024,
         }
     }
 }
@@ -666,12 +734,43 @@ impl<HttpMiddleware, RpcMiddleware> Builder<HttpMiddleware, RpcMiddleware> {
         self
     }
 
-    /// Sets the permissions for the IPC socket file.
+    /// Sets the permissions for the IPC socket file, in octal format.
+    ///
+    /// Starting the server fails if the permissions are invalid or can't be set.
     pub fn set_ipc_socket_permissions(mut self, permissions: Option<String>) -> Self {
         self.settings.ipc_socket_permissions = permissions;
         self
     }
 
+    /// Sets the security descriptor of the named pipe, in SDDL format, e.g. `D:(A;;GA;;;AU)` to
+    /// grant access to all authenticated users.
+    ///
+    /// Only used on windows, where the pipe otherwise gets the default descriptor of the process.
+    /// Starting the server fails if the descriptor is invalid.
+    pub fn set_ipc_security_descriptor(mut self, descriptor: Option<String>) -> Self {
+        self.settings.ipc_security_descriptor = descriptor;
+        self
+    }
+
+    /// Set the initial capacity in bytes of the read buffer of each connection. Default is 8 KiB.
+    ///
+    /// The buffer grows as needed, a larger capacity avoids reallocations for large requests. This
+    /// is not a limit, the size of requests is limited by [`Self::max_request_body_size`].
+    pub const fn set_read_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.settings.read_buffer_capacity = capacity;
+        self
+    }
+
+    /// Set the initial capacity in bytes of the write buffer of each connection. Default is 8 KiB.
+    ///
+    /// The buffer grows as needed, a larger capacity avoids reallocations for large responses,
+    /// e.g. traces. This is not a limit, the size of responses is limited by
+    /// [`Self::max_response_body_size`].
+    pub const fn set_write_buffer_capacity(mut self, capacity: usize) -> Self {
+        self.settings.write_buffer_capacity = capacity;
+        self
+    }
+
     /// Configure custom `subscription ID` provider for the server to use
     /// to when getting new subscription calls.
     ///
@@ -806,6 +905,17 @@ mod tests {
         assert_eq!(perms.mode() & 0o777, 0o777);
     }
 
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn test_invalid_ipc_socket_permissions() {
+        let endpoint = &dummy_name();
+        let server = Builder::default()
+            .set_ipc_socket_permissions(Some("rwx".to_string()))
+            .build(endpoint.clone());
+        let err = server.start(RpcModule::new(())).await.unwrap_err();
+        assert!(err.to_string().contains("invalid IPC socket permissions"), "{err}");
+    }
+
     async fn pipe_from_stream_with_bounded_buffer(
         pending: PendingSubscriptionSink,
         stream: BroadcastStream<usize>,
@@ -942,6 +1052,26 @@ mod tests {
         assert_eq!(response, msg);
     }
 
+    #[tokio::test]
+    async fn test_small_buffer_capacities() {
+        init_test_tracing();
+        let endpoint = &dummy_name();
+        let server = Builder::default()
+            .set_read_buffer_capacity(16)
+            .set_write_buffer_capacity(16)
+            .build(endpoint.clone());
+        let mut module = RpcModule::new(());
+        module.register_method("echo", |params, _, _| params.one::<String>().unwrap()).unwrap();
+        let handle = server.start(module).await.unwrap();
+        tokio::spawn(handle.stopped());
+
+        // the buffers grow beyond their initial capacity
+        let msg = "a".repeat(64 * 1024);
+        let client = IpcClientBuilder::default().build(endpoint).await.unwrap();
+        let response: String = client.request("echo", rpc_params![msg.clone()]).await.unwrap();
+        assert_eq!(response, msg);
+    }
+
     #[tokio::test]
     async fn test_batch_request() {
         let endpoint = &dummy_name();
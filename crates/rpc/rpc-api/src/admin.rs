@@ -1,6 +1,7 @@
 use alloy_rpc_types_admin::{NodeInfo, PeerInfo};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use reth_network_peers::{AnyNode, NodeRecord};
+use reth_rpc_server_types::{RpcMethodRateLimit, RpcServerLimitOverrides};
 
 /// Admin namespace rpc interface that gives access to several non-standard RPC methods.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
@@ -35,6 +36,21 @@ pub trait AdminApi {
     #[method(name = "unbanPeer")]
     fn unban_peer(&self, record: AnyNode) -> RpcResult<bool>;
 
+    /// Returns the reputation of a remote node, or `null` if it isn't in the peer set.
+    #[method(name = "peerReputation")]
+    async fn peer_reputation(&self, record: AnyNode) -> RpcResult<Option<i32>>;
+
+    /// Adds the change, positive or negative, to the reputation of a remote node.
+    ///
+    /// The node is banned if its reputation falls below the ban threshold, and unbanned if it
+    /// rises above it.
+    #[method(name = "changePeerReputation")]
+    fn change_peer_reputation(&self, record: AnyNode, change: i32) -> RpcResult<bool>;
+
+    /// Resets the reputation of a remote node to the default reputation.
+    #[method(name = "resetPeerReputation")]
+    fn reset_peer_reputation(&self, record: AnyNode) -> RpcResult<bool>;
+
     /// The peers administrative property can be queried for all the information known about the
     /// connected remote nodes at the networking granularity. These include general information
     /// about the nodes themselves as participants of the devp2p P2P overlay protocol, as well as
@@ -58,4 +74,48 @@ pub trait AdminApi {
     /// Returns the number of transactions that were removed from the pool.
     #[method(name = "clearTxpool")]
     async fn clear_txpool(&self) -> RpcResult<u64>;
+
+    /// Returns the server limits overridden at runtime.
+    #[method(name = "serverLimits")]
+    fn server_limits(&self) -> RpcResult<RpcServerLimitOverrides>;
+
+    /// Sets the maximum number of concurrent HTTP and WS connections, at most the limit the
+    /// server was started with.
+    ///
+    /// Open connections are not closed if the maximum is lowered.
+    #[method(name = "setMaxConnections")]
+    fn set_max_connections(&self, max_connections: u32) -> RpcResult<bool>;
+
+    /// Sets the maximum number of concurrent tracing requests.
+    #[method(name = "setMaxTracingRequests")]
+    fn set_max_tracing_requests(&self, max_tracing_requests: usize) -> RpcResult<bool>;
+
+    /// Sets the gas cap of `eth_call` and related methods.
+    #[method(name = "setGasCap")]
+    fn set_gas_cap(&self, gas_cap: u64) -> RpcResult<bool>;
+
+    /// Replaces the per-method rate limits, formatted as `<method>=<rate>[/<burst>]`.
+    #[method(name = "setRateLimits")]
+    fn set_rate_limits(&self, rate_limits: Vec<RpcMethodRateLimit>) -> RpcResult<bool>;
+}
+
+/// Admin namespace rpc interface to control the sync pipeline, e.g. for maintenance windows.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "admin"))]
+pub trait AdminPipelineApi {
+    /// Pauses the sync pipeline after the current stage finished.
+    ///
+    /// Has no effect if the pipeline was aborted.
+    #[method(name = "pausePipeline")]
+    fn pause_pipeline(&self) -> RpcResult<bool>;
+
+    /// Resumes a paused or aborted sync pipeline.
+    #[method(name = "resumePipeline")]
+    fn resume_pipeline(&self) -> RpcResult<bool>;
+
+    /// Stops the sync pipeline at the next checkpoint.
+    ///
+    /// The pipeline doesn't run again until it is resumed.
+    #[method(name = "abortPipeline")]
+    fn abort_pipeline(&self) -> RpcResult<bool>;
 }
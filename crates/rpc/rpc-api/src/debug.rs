@@ -87,6 +87,22 @@ pub trait DebugApi<TxReq: RpcObject> {
         opts: Option<GethDebugTracingOptions>,
     ) -> RpcResult<Vec<TraceResult>>;
 
+    /// Replays the block like `debug_traceBlockByHash` and `debug_traceBlockByNumber`, but streams
+    /// the trace of each transaction as a notification of the subscription once it's executed,
+    /// instead of returning the traces of the whole block at once.
+    ///
+    /// The subscription is closed after the trace of the last transaction.
+    #[subscription(
+        name = "subscribeTraceBlock",
+        unsubscribe = "unsubscribeTraceBlock",
+        item = TraceResult
+    )]
+    async fn debug_subscribe_trace_block(
+        &self,
+        block: BlockId,
+        opts: Option<GethDebugTracingOptions>,
+    ) -> jsonrpsee::core::SubscriptionResult;
+
     /// The `debug_traceTransaction` debugging method will attempt to run the transaction in the
     /// exact same manner as it was executed on the network. It will replay any transaction that
     /// may have been executed prior to this one before it will finally attempt to execute the
@@ -0,0 +1,58 @@
+use alloy_json_rpc::RpcObject;
+use alloy_primitives::{B256, U64};
+use alloy_rpc_types_eth::{Filter, Log};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+
+/// Erigon rpc interface.
+///
+/// Provides the commonly used `erigon_` methods, so tooling written against Erigon works with
+/// reth.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "erigon"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "erigon"))]
+pub trait ErigonApi<B: RpcObject, R: RpcObject> {
+    /// Returns the latest logs matching the filter, scanning backwards from the filter's
+    /// `toBlock`, or the latest block.
+    ///
+    /// The scan stops once the number of logs or blocks with matching logs requested by the
+    /// options is reached. The logs are returned in ascending order.
+    #[method(name = "getLatestLogs")]
+    async fn get_latest_logs(
+        &self,
+        filter: Filter,
+        options: Option<ErigonLogFilterOptions>,
+    ) -> RpcResult<Vec<Log>>;
+
+    /// Returns the latest block with a timestamp at or below the given timestamp.
+    ///
+    /// Returns `null` if all available blocks are newer.
+    #[method(name = "getBlockByTimestamp")]
+    async fn get_block_by_timestamp(&self, timestamp: U64, full: bool) -> RpcResult<Option<B>>;
+
+    /// Returns the receipts of the canonical block with the given hash.
+    #[method(name = "getBlockReceiptsByBlockHash")]
+    async fn get_block_receipts_by_block_hash(&self, hash: B256) -> RpcResult<Option<Vec<R>>>;
+}
+
+/// Options of `erigon_getLatestLogs`.
+///
+/// At most one of `logCount` and `blockCount` can be set. If neither is set, the latest
+/// [`ErigonLogFilterOptions::DEFAULT_LOG_COUNT`] logs are returned.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErigonLogFilterOptions {
+    /// Maximum number of logs to return.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_count: Option<u64>,
+    /// Maximum number of blocks with matching logs to return the logs of.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_count: Option<u64>,
+    /// Whether the topics of the filter match at any position of the log topics.
+    #[serde(default)]
+    pub ignore_topics_order: bool,
+}
+
+impl ErigonLogFilterOptions {
+    /// Number of logs returned if neither `logCount` nor `blockCount` is set, same as Erigon.
+    pub const DEFAULT_LOG_COUNT: u64 = 20;
+}
@@ -18,6 +18,7 @@ mod admin;
 mod anvil;
 mod debug;
 mod engine;
+mod erigon;
 mod hardhat;
 mod mev;
 mod miner;
@@ -32,8 +33,10 @@ mod txpool;
 mod validation;
 mod web3;
 
-pub use reth::RethJitAction;
+pub use erigon::ErigonLogFilterOptions;
+pub use reth::{ChainNotificationOptions, RethJitAction, RethSyncStatus};
 pub use testing::{TestingBuildBlockRequestV1, TESTING_BUILD_BLOCK_V1, TESTING_COMMIT_BLOCK_V1};
+pub use txpool::{TxpoolContentFilter, TxpoolSenderSummary, TxpoolSubpool, TxpoolSummary};
 
 /// re-export of all server traits
 pub use servers::*;
@@ -41,16 +44,17 @@ pub use servers::*;
 /// Aggregates all server traits.
 pub mod servers {
     pub use crate::{
-        admin::AdminApiServer,
+        admin::{AdminApiServer, AdminPipelineApiServer},
         anvil::AnvilApiServer,
         debug::DebugApiServer,
         engine::{EngineApiServer, EngineEthApiServer, IntoEngineApiRpcModule},
+        erigon::ErigonApiServer,
         hardhat::HardhatApiServer,
         mev::{MevFullApiServer, MevSimApiServer},
         miner::MinerApiServer,
         net::NetApiServer,
         otterscan::OtterscanServer,
-        reth::RethApiServer,
+        reth::{RethApiServer, RethSyncApiServer},
         reth_engine::{RethEngineApiServer, RethNewPayloadInput, RethPayloadStatus},
         rpc::RpcApiServer,
         testing::TestingApiServer,
@@ -73,16 +77,17 @@ pub use clients::*;
 #[cfg(feature = "client")]
 pub mod clients {
     pub use crate::{
-        admin::AdminApiClient,
+        admin::{AdminApiClient, AdminPipelineApiClient},
         anvil::AnvilApiClient,
         debug::DebugApiClient,
         engine::{EngineApiClient, EngineEthApiClient},
+        erigon::ErigonApiClient,
         hardhat::HardhatApiClient,
         mev::{MevFullApiClient, MevSimApiClient},
         miner::MinerApiClient,
         net::NetApiClient,
         otterscan::OtterscanClient,
-        reth::RethApiClient,
+        reth::{RethApiClient, RethSyncApiClient},
         reth_engine::RethEngineApiClient,
         rpc::RpcApiClient,
         testing::TestingApiClient,
@@ -34,12 +34,18 @@ pub trait RethApi {
     async fn reth_jit(&self, action: RethJitAction) -> RpcResult<()>;
 
     /// Subscribe to json `ChainNotifications`
+    ///
+    /// Emits the committed and reverted chain segments with their receipts, and their state diffs
+    /// unless disabled with [`ChainNotificationOptions::include_state_diffs`].
     #[subscription(
         name = "subscribeChainNotifications",
         unsubscribe = "unsubscribeChainNotifications",
         item = reth_chain_state::CanonStateNotification
     )]
-    async fn reth_subscribe_chain_notifications(&self) -> jsonrpsee::core::SubscriptionResult;
+    async fn reth_subscribe_chain_notifications(
+        &self,
+        options: Option<ChainNotificationOptions>,
+    ) -> jsonrpsee::core::SubscriptionResult;
 
     /// Subscribe to persisted block notifications.
     ///
@@ -65,6 +71,50 @@ pub trait RethApi {
     ) -> jsonrpsee::core::SubscriptionResult;
 }
 
+/// Reth API namespace for the sync progress of the node.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethSyncApi {
+    /// Returns the progress of the sync pipeline, similar to `eth_syncing`, including the
+    /// estimated time left until the pipeline reaches its target.
+    ///
+    /// Returns `None` if the pipeline isn't running.
+    #[method(name = "syncing")]
+    async fn reth_syncing(&self) -> RpcResult<Option<RethSyncStatus>>;
+}
+
+/// Progress of the sync pipeline returned by `reth_syncing`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RethSyncStatus {
+    /// Name of the stage the pipeline is currently running.
+    pub stage: String,
+    /// Checkpoint of the current stage.
+    pub current_block: U64,
+    /// Block the pipeline is syncing to, if known.
+    pub highest_block: Option<U64>,
+    /// Estimated seconds until the current stage reaches the highest block.
+    pub stage_eta: Option<u64>,
+    /// Estimated seconds until all stages reach the highest block.
+    ///
+    /// Only includes the stages that made progress since the node was started.
+    pub eta: Option<u64>,
+}
+
+/// Options of the `reth_subscribeChainNotifications` subscription.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ChainNotificationOptions {
+    /// Whether to include the state diffs of the chain segments, included by default.
+    pub include_state_diffs: bool,
+}
+
+impl Default for ChainNotificationOptions {
+    fn default() -> Self {
+        Self { include_state_diffs: true }
+    }
+}
+
 /// Supported `reth_jit` control actions.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
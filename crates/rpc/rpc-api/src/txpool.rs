@@ -1,7 +1,9 @@
 use alloy_json_rpc::RpcObject;
-use alloy_primitives::Address;
+use alloy_primitives::{Address, U256};
 use alloy_rpc_types_txpool::{TxpoolContent, TxpoolContentFrom, TxpoolInspect, TxpoolStatus};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Txpool rpc interface.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "txpool"))]
@@ -34,4 +36,75 @@ pub trait TxPoolApi<T: RpcObject> {
     /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_content) for more details
     #[method(name = "content")]
     async fn txpool_content(&self) -> RpcResult<TxpoolContent<T>>;
+
+    /// Returns the details of the transactions matching the filter, in the same format as
+    /// `txpool_content`.
+    #[method(name = "contentFiltered")]
+    async fn txpool_content_filtered(
+        &self,
+        filter: TxpoolContentFilter,
+    ) -> RpcResult<TxpoolContent<T>>;
+
+    /// Returns per-sender aggregates of the pending and queued transactions.
+    #[method(name = "summary")]
+    async fn txpool_summary(&self) -> RpcResult<TxpoolSummary>;
+}
+
+/// Filter of `txpool_contentFiltered`.
+///
+/// A transaction is returned if it matches all the criteria that are set.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolContentFilter {
+    /// Senders to return the transactions of, all if empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub senders: Vec<Address>,
+    /// Subpool to return the transactions of, both if unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subpool: Option<TxpoolSubpool>,
+    /// Whether only EIP-4844 blob transactions are returned.
+    #[serde(default)]
+    pub blob_only: bool,
+    /// Maximum number of transactions to return, pending ones first.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub max_results: Option<u64>,
+}
+
+/// Subpool of the txpool, as reported by `txpool_content`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TxpoolSubpool {
+    /// Transactions that can be included in the next block.
+    Pending,
+    /// Transactions that are scheduled for future execution only.
+    Queued,
+}
+
+/// Response of `txpool_summary`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TxpoolSummary {
+    /// Summaries of the pending transactions by sender.
+    pub pending: BTreeMap<Address, TxpoolSenderSummary>,
+    /// Summaries of the queued transactions by sender.
+    pub queued: BTreeMap<Address, TxpoolSenderSummary>,
+}
+
+/// Aggregates of the transactions of a sender in a subpool.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxpoolSenderSummary {
+    /// Number of transactions.
+    #[serde(with = "alloy_serde::quantity")]
+    pub count: u64,
+    /// Lowest nonce of the transactions.
+    #[serde(with = "alloy_serde::quantity")]
+    pub min_nonce: u64,
+    /// Highest nonce of the transactions.
+    #[serde(with = "alloy_serde::quantity")]
+    pub max_nonce: u64,
+    /// Sum of the gas limits of the transactions.
+    #[serde(with = "alloy_serde::quantity")]
+    pub total_gas_limit: u64,
+    /// Sum of the maximum costs of the transactions, including their value.
+    pub total_cost: U256,
 }
@@ -0,0 +1,223 @@
+//! API key authorization of the calls to the HTTP and WS servers.
+
+use http::HeaderName;
+use jsonrpsee::{
+    core::middleware::{Batch, BatchEntry, BatchEntryErr, Notification},
+    server::middleware::rpc::RpcServiceT,
+    types::{error::ErrorCode, ErrorObject, ErrorObjectOwned, Request},
+    MethodResponse,
+};
+use reth_rpc_server_types::{api_keys::API_KEY_HEADER, RpcApiKeys};
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+
+/// Namespaces the calls of a request can be made to.
+///
+/// Inserted into the extensions of the HTTP requests by [`RpcApiKeyLayer`], which are inherited
+/// by the calls of the request, or of the WS connection.
+#[derive(Debug, Clone, Default)]
+pub struct RpcAllowedNamespaces(Arc<[String]>);
+
+impl RpcAllowedNamespaces {
+    /// Returns `true` if the method is in one of the allowed namespaces.
+    pub fn allows(&self, method: &str) -> bool {
+        let namespace = method.split_once('_').map_or(method, |(namespace, _)| namespace);
+        self.0.iter().any(|allowed| allowed == "*" || allowed == namespace)
+    }
+}
+
+/// HTTP layer resolving the namespaces a request can call from its API key, read from the
+/// `x-api-key` header.
+///
+/// The namespaces are enforced by the [`RpcNamespaceGuardLayer`] RPC middleware.
+#[derive(Debug, Clone)]
+pub struct RpcApiKeyLayer {
+    inner: Arc<RpcApiKeyLayerInner>,
+}
+
+impl RpcApiKeyLayer {
+    /// Creates a new layer with the given API keys.
+    pub fn new(api_keys: RpcApiKeys) -> Self {
+        let RpcApiKeys { public, keys } = api_keys;
+        Self {
+            inner: Arc::new(RpcApiKeyLayerInner {
+                public: RpcAllowedNamespaces(public.into()),
+                keys: keys
+                    .into_iter()
+                    .map(|(key, namespaces)| (key, RpcAllowedNamespaces(namespaces.into())))
+                    .collect(),
+            }),
+        }
+    }
+
+    /// Returns the namespaces that can be called with the API key, or without one if `None`.
+    fn allowed_namespaces(&self, api_key: Option<&str>) -> RpcAllowedNamespaces {
+        match api_key {
+            // unknown API keys can't call any method
+            Some(api_key) => self.inner.keys.get(api_key).cloned().unwrap_or_default(),
+            None => self.inner.public.clone(),
+        }
+    }
+}
+
+impl<S> Layer<S> for RpcApiKeyLayer {
+    type Service = RpcApiKeyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcApiKeyService { inner, api_keys: self.clone() }
+    }
+}
+
+#[derive(Debug)]
+struct RpcApiKeyLayerInner {
+    /// Namespaces that can be called without an API key
+    public: RpcAllowedNamespaces,
+    /// Namespaces that can be called with each API key
+    keys: HashMap<String, RpcAllowedNamespaces>,
+}
+
+/// HTTP service inserting the [`RpcAllowedNamespaces`] into the request extensions.
+#[derive(Debug, Clone)]
+pub struct RpcApiKeyService<S> {
+    inner: S,
+    api_keys: RpcApiKeyLayer,
+}
+
+impl<S, B> Service<http::Request<B>> for RpcApiKeyService<S>
+where
+    S: Service<http::Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: http::Request<B>) -> Self::Future {
+        // a header that isn't valid UTF-8 is treated as an unknown API key
+        let api_key = req
+            .headers()
+            .get(HeaderName::from_static(API_KEY_HEADER))
+            .map(|value| value.to_str().unwrap_or_default());
+        let namespaces = self.api_keys.allowed_namespaces(api_key);
+        req.extensions_mut().insert(namespaces);
+        self.inner.call(req)
+    }
+}
+
+/// RPC middleware rejecting the calls to namespaces that aren't in the [`RpcAllowedNamespaces`]
+/// of their request.
+///
+/// Calls without [`RpcAllowedNamespaces`], e.g. IPC calls, are not restricted.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RpcNamespaceGuardLayer;
+
+impl<S> Layer<S> for RpcNamespaceGuardLayer {
+    type Service = RpcNamespaceGuardService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcNamespaceGuardService { inner }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that enforces the [`RpcAllowedNamespaces`] of the calls.
+#[derive(Debug, Clone)]
+pub struct RpcNamespaceGuardService<S> {
+    inner: S,
+}
+
+/// Returns the error the call is rejected with, if its method isn't allowed.
+fn check_namespace(req: &Request<'_>) -> Result<(), ErrorObjectOwned> {
+    match req.extensions().get::<RpcAllowedNamespaces>() {
+        Some(namespaces) if !namespaces.allows(req.method_name()) => Err(ErrorObject::owned(
+            ErrorCode::MethodNotFound.code(),
+            format!("method {} is not allowed for this API key", req.method_name()),
+            None::<()>,
+        )),
+        _ => Ok(()),
+    }
+}
+
+impl<S> RpcServiceT for RpcNamespaceGuardService<S>
+where
+    S: RpcServiceT<MethodResponse = MethodResponse> + Send + Sync + Clone + 'static,
+{
+    type MethodResponse = S::MethodResponse;
+    type NotificationResponse = S::NotificationResponse;
+    type BatchResponse = S::BatchResponse;
+
+    fn call<'a>(&self, req: Request<'a>) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+        let call = match check_namespace(&req) {
+            Ok(()) => Ok(self.inner.call(req)),
+            Err(err) => Err(MethodResponse::error(req.id(), err)),
+        };
+        async move {
+            match call {
+                Ok(fut) => fut.await,
+                Err(response) => response,
+            }
+        }
+    }
+
+    fn batch<'a>(
+        &self,
+        mut batch: Batch<'a>,
+    ) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+        // the calls of a batch are dispatched by the inner service directly, so they're checked
+        // here and the rejected ones replaced by their error
+        for entry in batch.iter_mut() {
+            let rejected = match entry {
+                Ok(BatchEntry::Call(req)) => {
+                    check_namespace(req).err().map(|err| BatchEntryErr::new(req.id(), err))
+                }
+                _ => None,
+            };
+            if let Some(err) = rejected {
+                *entry = Err(err);
+            }
+        }
+        self.inner.batch(batch)
+    }
+
+    fn notification<'a>(
+        &self,
+        n: Notification<'a>,
+    ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+        self.inner.notification(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allowed_namespaces() {
+        let layer = RpcApiKeyLayer::new(RpcApiKeys {
+            public: vec!["eth".to_string(), "net".to_string()],
+            keys: HashMap::from([
+                ("tracer".to_string(), vec!["eth".to_string(), "debug".to_string()]),
+                ("admin".to_string(), vec!["*".to_string()]),
+            ]),
+        });
+
+        let public = layer.allowed_namespaces(None);
+        assert!(public.allows("eth_call"));
+        assert!(public.allows("net_version"));
+        assert!(!public.allows("debug_traceBlockByNumber"));
+
+        let tracer = layer.allowed_namespaces(Some("tracer"));
+        assert!(tracer.allows("debug_traceBlockByNumber"));
+        assert!(!tracer.allows("net_version"));
+
+        assert!(layer.allowed_namespaces(Some("admin")).allows("admin_peers"));
+        assert!(!layer.allowed_namespaces(Some("unknown")).allows("eth_call"));
+    }
+}
@@ -1,3 +1,4 @@
+use http::HeaderName;
 use jsonrpsee::server::ServerConfigBuilder;
 use reth_node_core::{args::RpcServerArgs, utils::get_or_create_jwt_secret_from_path};
 use reth_rpc::ValidationApiConfig;
@@ -9,8 +10,10 @@ use tower::layer::util::Identity;
 use tracing::{debug, warn};
 
 use crate::{
-    auth::AuthServerConfig, error::RpcError, IpcServerBuilder, RpcModuleConfig, RpcServerConfig,
-    TransportRpcModuleConfig,
+    auth::AuthServerConfig,
+    error::RpcError,
+    rate_limiter::{RpcBatchCostLimiter, RpcMethodRateLimiter},
+    IpcServerBuilder, RpcModuleConfig, RpcServerConfig, TransportRpcModuleConfig,
 };
 
 /// A trait that provides a configured RPC server.
@@ -108,10 +111,15 @@ impl RethRpcServerConfig for RpcServerArgs {
             .state_cache(self.state_cache_config())
             .gpo_config(self.gas_price_oracle_config())
             .proof_permits(self.rpc_proof_permits)
+            .proof_concurrency(self.rpc_proof_concurrency)
             .pending_block_kind(self.rpc_pending_block)
             .raw_tx_forwarder(self.rpc_forwarder.clone())
             .rpc_evm_memory_limit(self.rpc_evm_memory_limit)
             .force_blob_sidecar_upcasting(self.rpc_force_blob_sidecar_upcasting)
+            .pruned_state_replay_limit(self.rpc_pruned_state_replay_limit)
+            .call_cache_max_entries(self.rpc_call_cache_size)
+            .tracing_timeout(self.rpc_tracing_timeout)
+            .subscription_lag_policy(self.rpc_subscription_lag_policy)
     }
 
     fn flashbots_config(&self) -> ValidationApiConfig {
@@ -177,6 +185,7 @@ impl RethRpcServerConfig for RpcServerArgs {
             .max_request_body_size(self.rpc_max_request_size_bytes())
             .max_response_body_size(self.rpc_max_response_size_bytes())
             .max_subscriptions_per_connection(self.rpc_max_subscriptions_per_connection.get())
+            .set_message_buffer_capacity(self.rpc_subscription_buffer_size)
     }
 
     fn ipc_server_builder(&self) -> IpcServerBuilder<Identity, Identity> {
@@ -186,12 +195,39 @@ impl RethRpcServerConfig for RpcServerArgs {
             .max_response_body_size(self.rpc_max_response_size_bytes())
             .max_connections(self.rpc_max_connections.get())
             .set_ipc_socket_permissions(self.ipc_socket_permissions.clone())
+            .set_ipc_security_descriptor(self.ipc_security_descriptor.clone())
+            .set_read_buffer_capacity(self.ipc_read_buffer_size)
+            .set_write_buffer_capacity(self.ipc_write_buffer_size)
+            .set_message_buffer_capacity(self.rpc_subscription_buffer_size)
     }
 
     fn rpc_server_config(&self) -> RpcServerConfig {
         let mut config = RpcServerConfig::default()
             .with_jwt_secret(self.rpc_secret_key())
-            .with_rpc_metrics_enabled(self.rpc_metrics_enabled());
+            .with_rpc_metrics_enabled(self.rpc_metrics_enabled())
+            .with_rpc_slow_call_threshold(self.rpc_slow_call_threshold)
+            .with_rpc_api_keys(self.rpc_api_keys.clone());
+
+        if !self.rpc_method_rate_limits.is_empty() {
+            let client_key_header = self.rpc_rate_limit_key_header.as_deref().and_then(|header| {
+                HeaderName::try_from(header)
+                    .inspect_err(|err| {
+                        warn!(target: "reth::cli", %header, %err, "Invalid rate limit key header");
+                    })
+                    .ok()
+            });
+            config = config.with_rpc_rate_limiter(Some(RpcMethodRateLimiter::new(
+                self.rpc_method_rate_limits.clone(),
+                client_key_header,
+            )));
+        }
+
+        if let Some(max_cost) = self.rpc_max_batch_cost {
+            config = config.with_rpc_batch_cost_limiter(Some(RpcBatchCostLimiter::new(
+                max_cost,
+                self.rpc_method_costs.clone(),
+            )));
+        }
 
         if self.http_api.is_some() && !self.http {
             warn!(
@@ -213,7 +249,8 @@ impl RethRpcServerConfig for RpcServerArgs {
                 .with_http_address(socket_address)
                 .with_http(self.http_ws_server_builder())
                 .with_http_cors(self.http_corsdomain.clone())
-                .with_http_disable_compression(self.http_disable_compression);
+                .with_http_disable_compression(self.http_disable_compression)
+                .with_http_compression_min_size(self.http_compression_min_size);
         }
 
         if self.ws {
@@ -0,0 +1,120 @@
+//! Maximum number of connections to the HTTP and WS servers, adjustable at runtime.
+
+use http::StatusCode;
+use jsonrpsee::server::{HttpBody, HttpResponse};
+use reth_rpc_server_types::RpcServerLimits;
+use std::{
+    future::{ready, Ready},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+use tower::{util::Either, Layer, Service};
+
+/// HTTP layer rejecting the requests of connections above the maximum number of connections
+/// overridden with [`RpcServerLimits`].
+///
+/// The servers create their HTTP middleware once per connection, so a connection is counted until
+/// its service is dropped. Connections opened while the maximum is reached are answered with
+/// `503 Service Unavailable`, open connections are not closed if the maximum is lowered.
+///
+/// The override is bounded by the maximum the servers were started with, which they enforce
+/// themselves.
+#[derive(Debug, Clone)]
+pub struct RpcConnectionLimitLayer {
+    limits: RpcServerLimits,
+    /// Number of open connections, shared by all servers the layer is installed in
+    connections: Arc<AtomicU32>,
+}
+
+impl RpcConnectionLimitLayer {
+    /// Creates a new layer enforcing the overridden maximum number of connections.
+    pub fn new(limits: RpcServerLimits) -> Self {
+        Self { limits, connections: Default::default() }
+    }
+}
+
+impl<S> Layer<S> for RpcConnectionLimitLayer {
+    type Service = RpcConnectionLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        let connections = self.connections.fetch_add(1, Ordering::Relaxed) + 1;
+        let rejected = self.limits.max_connections().is_some_and(|max| connections > max);
+        RpcConnectionLimitService {
+            inner,
+            connection: Arc::new(OpenConnection(self.connections.clone())),
+            rejected,
+        }
+    }
+}
+
+/// Decrements the number of open connections once the connection is closed.
+#[derive(Debug)]
+struct OpenConnection(Arc<AtomicU32>);
+
+impl Drop for OpenConnection {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// HTTP service of a connection, rejecting its requests if it exceeded the maximum.
+#[derive(Debug, Clone)]
+pub struct RpcConnectionLimitService<S> {
+    inner: S,
+    /// Shared by the clones of the service made for each request of the connection
+    connection: Arc<OpenConnection>,
+    rejected: bool,
+}
+
+impl<S, B> Service<http::Request<B>> for RpcConnectionLimitService<S>
+where
+    S: Service<http::Request<B>, Response = HttpResponse>,
+{
+    type Response = HttpResponse;
+    type Error = S::Error;
+    type Future = Either<S::Future, Ready<Result<HttpResponse, S::Error>>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        if self.rejected {
+            let mut response = HttpResponse::new(HttpBody::from("too many connections"));
+            *response.status_mut() = StatusCode::SERVICE_UNAVAILABLE;
+            return Either::Right(ready(Ok(response)))
+        }
+        Either::Left(self.inner.call(req))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_connections() {
+        let limits = RpcServerLimits::default();
+        let layer = RpcConnectionLimitLayer::new(limits.clone());
+
+        let first = layer.layer(());
+        let second = layer.layer(());
+        assert!(!first.rejected && !second.rejected);
+        assert_eq!(layer.connections.load(Ordering::Relaxed), 2);
+
+        limits.set_max_connections(2);
+        assert!(layer.layer(()).rejected);
+
+        // clones of a service belong to the same connection
+        let clone = first.clone();
+        drop(first);
+        assert_eq!(layer.connections.load(Ordering::Relaxed), 2);
+        drop(clone);
+        assert!(!layer.layer(()).rejected);
+        drop(second);
+        assert_eq!(layer.connections.load(Ordering::Relaxed), 0);
+    }
+}
@@ -24,7 +24,8 @@ where
     pub fn bootstrap(config: EthConfig, executor: Runtime, eth_api: EthApi) -> Self {
         let filter = EthFilter::new(eth_api.clone(), config.filter_config(), executor.clone());
 
-        let pubsub = EthPubSub::new(eth_api.clone(), executor);
+        let pubsub =
+            EthPubSub::with_lag_policy(eth_api.clone(), executor, config.subscription_lag_policy);
 
         Self { api: eth_api, filter, pubsub }
     }
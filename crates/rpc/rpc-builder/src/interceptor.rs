@@ -0,0 +1,380 @@
+//! Typed hooks intercepting the RPC calls of the http, ws and ipc servers.
+
+pub use crate::metrics::RpcTransport;
+use jsonrpsee::{
+    core::middleware::{Batch, BatchEntry, BatchEntryErr, Notification},
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObjectOwned, Request},
+    MethodResponse,
+};
+use std::{fmt, future::Future, sync::Arc};
+use tower::Layer;
+
+/// Hook observing, rewriting or denying the RPC calls, e.g. for authorization, audit logging or
+/// request rewriting.
+///
+/// The hooks are called synchronously on the connection of the call, so they should not block.
+pub trait RpcInterceptor: Send + Sync + 'static {
+    /// Called before the call is dispatched, with the request that can be rewritten.
+    ///
+    /// Returning an error denies the call, which is answered with the error instead.
+    fn on_request(
+        &self,
+        transport: RpcTransport,
+        request: &mut Request<'_>,
+    ) -> Result<(), ErrorObjectOwned> {
+        let _ = (transport, request);
+        Ok(())
+    }
+
+    /// Called with the response of a call, before it's sent, returning the response to send.
+    fn on_response(
+        &self,
+        transport: RpcTransport,
+        method: &str,
+        response: MethodResponse,
+    ) -> MethodResponse {
+        let _ = (transport, method);
+        response
+    }
+
+    /// Called with the response of a batch, before it's sent, returning the response to send.
+    ///
+    /// The calls of a batch are passed to [`Self::on_request`] one by one, but they're answered
+    /// with a single response, so [`Self::on_response`] is not called for them.
+    fn on_batch_response(
+        &self,
+        transport: RpcTransport,
+        response: MethodResponse,
+    ) -> MethodResponse {
+        let _ = transport;
+        response
+    }
+}
+
+/// Ordered set of [`RpcInterceptor`]s, each registered for all or some transports.
+///
+/// The request hooks are called in registration order, and the response hooks in reverse order,
+/// so the first registered interceptor sees the request first and the response last. If a call is
+/// denied, only the interceptors before the one denying it see its response.
+///
+/// Notifications are not intercepted.
+#[derive(Clone, Default)]
+pub struct RpcInterceptors {
+    interceptors: Vec<RegisteredInterceptor>,
+}
+
+impl RpcInterceptors {
+    /// Creates an empty set of interceptors.
+    pub const fn new() -> Self {
+        Self { interceptors: Vec::new() }
+    }
+
+    /// Returns `true` if no interceptor is registered.
+    pub const fn is_empty(&self) -> bool {
+        self.interceptors.is_empty()
+    }
+
+    /// Registers the interceptor for all transports, after the already registered ones.
+    pub fn with_interceptor(mut self, interceptor: impl RpcInterceptor) -> Self {
+        self.push(interceptor, None);
+        self
+    }
+
+    /// Registers the interceptor for the given transports, after the already registered ones.
+    ///
+    /// Note: the calls of a server running http and ws on the same port are intercepted as
+    /// [`RpcTransport::Http`] calls.
+    pub fn with_transport_interceptor(
+        mut self,
+        transports: impl IntoIterator<Item = RpcTransport>,
+        interceptor: impl RpcInterceptor,
+    ) -> Self {
+        self.push(interceptor, Some(transports.into_iter().collect()));
+        self
+    }
+
+    /// Registers the interceptor for all transports, after the already registered ones.
+    pub fn add_interceptor(&mut self, interceptor: impl RpcInterceptor) -> &mut Self {
+        self.push(interceptor, None);
+        self
+    }
+
+    /// Registers the interceptor for the given transports, after the already registered ones.
+    pub fn add_transport_interceptor(
+        &mut self,
+        transports: impl IntoIterator<Item = RpcTransport>,
+        interceptor: impl RpcInterceptor,
+    ) -> &mut Self {
+        self.push(interceptor, Some(transports.into_iter().collect()));
+        self
+    }
+
+    fn push(&mut self, interceptor: impl RpcInterceptor, transports: Option<Vec<RpcTransport>>) {
+        self.interceptors
+            .push(RegisteredInterceptor { interceptor: Arc::new(interceptor), transports })
+    }
+
+    /// Returns the layer applying the interceptors of the transport, if any are registered for it.
+    pub fn layer(&self, transport: RpcTransport) -> Option<RpcInterceptorLayer> {
+        let interceptors = self
+            .interceptors
+            .iter()
+            .filter(|registered| {
+                registered
+                    .transports
+                    .as_ref()
+                    .is_none_or(|transports| transports.contains(&transport))
+            })
+            .map(|registered| registered.interceptor.clone())
+            .collect::<Arc<[_]>>();
+        (!interceptors.is_empty()).then_some(RpcInterceptorLayer { transport, interceptors })
+    }
+}
+
+impl fmt::Debug for RpcInterceptors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RpcInterceptors").field("len", &self.interceptors.len()).finish()
+    }
+}
+
+/// An interceptor and the transports it's registered for, all if `None`.
+#[derive(Clone)]
+struct RegisteredInterceptor {
+    interceptor: Arc<dyn RpcInterceptor>,
+    transports: Option<Vec<RpcTransport>>,
+}
+
+/// Layer applying the [`RpcInterceptors`] of a transport.
+#[derive(Clone)]
+pub struct RpcInterceptorLayer {
+    transport: RpcTransport,
+    interceptors: Arc<[Arc<dyn RpcInterceptor>]>,
+}
+
+impl fmt::Debug for RpcInterceptorLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RpcInterceptorLayer")
+            .field("transport", &self.transport)
+            .field("len", &self.interceptors.len())
+            .finish()
+    }
+}
+
+impl<S> Layer<S> for RpcInterceptorLayer {
+    type Service = RpcInterceptorService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcInterceptorService { inner, layer: self.clone() }
+    }
+}
+
+/// A [`RpcServiceT`] middleware calling the [`RpcInterceptor`]s of a transport.
+#[derive(Debug, Clone)]
+pub struct RpcInterceptorService<S> {
+    layer: RpcInterceptorLayer,
+    /// The inner service being wrapped
+    inner: S,
+}
+
+impl<S> RpcInterceptorService<S> {
+    /// Calls the request hooks in order until one denies the request.
+    ///
+    /// Returns the number of interceptors that admitted the request, and the error it's denied
+    /// with.
+    fn intercept_request(
+        &self,
+        request: &mut Request<'_>,
+    ) -> (usize, Result<(), ErrorObjectOwned>) {
+        for (admitted, interceptor) in self.layer.interceptors.iter().enumerate() {
+            if let Err(err) = interceptor.on_request(self.layer.transport, request) {
+                return (admitted, Err(err))
+            }
+        }
+        (self.layer.interceptors.len(), Ok(()))
+    }
+}
+
+impl<S> RpcServiceT for RpcInterceptorService<S>
+where
+    S: RpcServiceT<MethodResponse = MethodResponse, BatchResponse = MethodResponse>
+        + Send
+        + Sync
+        + Clone
+        + 'static,
+{
+    type MethodResponse = S::MethodResponse;
+    type NotificationResponse = S::NotificationResponse;
+    type BatchResponse = S::BatchResponse;
+
+    fn call<'a>(
+        &self,
+        mut req: Request<'a>,
+    ) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+        let (admitted, result) = self.intercept_request(&mut req);
+        let method = req.method_name().to_owned();
+        let call = match result {
+            Ok(()) => Ok(self.inner.call(req)),
+            Err(err) => Err(MethodResponse::error(req.id(), err)),
+        };
+        let layer = self.layer.clone();
+        async move {
+            let mut response = match call {
+                Ok(fut) => fut.await,
+                Err(response) => response,
+            };
+            for interceptor in layer.interceptors[..admitted].iter().rev() {
+                response = interceptor.on_response(layer.transport, &method, response);
+            }
+            response
+        }
+    }
+
+    fn batch<'a>(
+        &self,
+        mut batch: Batch<'a>,
+    ) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+        // the calls of a batch are dispatched by the inner service directly, so they're
+        // intercepted here and the denied ones replaced by their error
+        for entry in batch.iter_mut() {
+            let denied = match entry {
+                Ok(BatchEntry::Call(req)) => {
+                    self.intercept_request(req).1.err().map(|err| BatchEntryErr::new(req.id(), err))
+                }
+                _ => None,
+            };
+            if let Some(err) = denied {
+                *entry = Err(err);
+            }
+        }
+        let fut = self.inner.batch(batch);
+        let layer = self.layer.clone();
+        async move {
+            let mut response = fut.await;
+            for interceptor in layer.interceptors.iter().rev() {
+                response = interceptor.on_batch_response(layer.transport, response);
+            }
+            response
+        }
+    }
+
+    fn notification<'a>(
+        &self,
+        n: Notification<'a>,
+    ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+        self.inner.notification(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::types::{ErrorObject, Id};
+    use std::{borrow::Cow, sync::Mutex};
+
+    /// Records the hooks it's called with.
+    struct Recorder {
+        name: &'static str,
+        calls: Arc<Mutex<Vec<String>>>,
+        deny: Option<&'static str>,
+    }
+
+    impl RpcInterceptor for Recorder {
+        fn on_request(
+            &self,
+            _transport: RpcTransport,
+            request: &mut Request<'_>,
+        ) -> Result<(), ErrorObjectOwned> {
+            self.calls.lock().unwrap().push(format!("{} request {}", self.name, request.method));
+            if self.deny == Some(request.method_name()) {
+                return Err(ErrorObject::owned(-32001, "denied", None::<()>))
+            }
+            if request.method == "eth_chainId" {
+                request.method = Cow::Borrowed("eth_blockNumber");
+            }
+            Ok(())
+        }
+
+        fn on_response(
+            &self,
+            _transport: RpcTransport,
+            method: &str,
+            response: MethodResponse,
+        ) -> MethodResponse {
+            self.calls.lock().unwrap().push(format!("{} response {method}", self.name));
+            response
+        }
+    }
+
+    /// Answers calls with their method.
+    #[derive(Clone)]
+    struct Echo;
+
+    impl RpcServiceT for Echo {
+        type MethodResponse = MethodResponse;
+        type NotificationResponse = MethodResponse;
+        type BatchResponse = MethodResponse;
+
+        fn call<'a>(
+            &self,
+            req: Request<'a>,
+        ) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+            let response = MethodResponse::response(
+                req.id(),
+                jsonrpsee::ResponsePayload::success(req.method_name().to_owned()),
+                usize::MAX,
+            );
+            async move { response }
+        }
+
+        fn batch<'a>(&self, _: Batch<'a>) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+            async { unreachable!() }
+        }
+
+        fn notification<'a>(
+            &self,
+            _: Notification<'a>,
+        ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+            async { unreachable!() }
+        }
+    }
+
+    #[tokio::test]
+    async fn intercept_in_order() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorder = |name, deny| Recorder { name, calls: calls.clone(), deny };
+        let interceptors = RpcInterceptors::new()
+            .with_interceptor(recorder("first", None))
+            .with_transport_interceptor([RpcTransport::Http], recorder("second", Some("eth_call")))
+            .with_interceptor(recorder("third", None));
+        let service = interceptors.layer(RpcTransport::Http).unwrap().layer(Echo);
+
+        let request = Request::borrowed("eth_chainId", None, Id::Number(1));
+        let response = service.call(request).await;
+        assert!(response.is_success());
+        assert_eq!(
+            std::mem::take(&mut *calls.lock().unwrap()),
+            [
+                "first request eth_chainId",
+                "second request eth_blockNumber",
+                "third request eth_blockNumber",
+                "third response eth_blockNumber",
+                "second response eth_blockNumber",
+                "first response eth_blockNumber",
+            ]
+        );
+
+        // only the interceptors before the denying one see the response
+        let response = service.call(Request::borrowed("eth_call", None, Id::Number(2))).await;
+        assert!(response.is_error());
+        assert_eq!(
+            std::mem::take(&mut *calls.lock().unwrap()),
+            ["first request eth_call", "second request eth_call", "first response eth_call"]
+        );
+
+        // interceptors registered for other transports are skipped
+        let service = interceptors.layer(RpcTransport::Ipc).unwrap().layer(Echo);
+        assert!(!service.call(Request::borrowed("eth_call", None, Id::Number(3))).await.is_error());
+        assert!(RpcInterceptors::new().layer(RpcTransport::Ipc).is_none());
+    }
+}
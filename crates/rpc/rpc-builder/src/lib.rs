@@ -19,7 +19,14 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
-use crate::{auth::AuthRpcModule, error::WsHttpSamePortError, metrics::RpcRequestMetrics};
+use crate::{
+    api_keys::{RpcApiKeyLayer, RpcNamespaceGuardLayer},
+    auth::AuthRpcModule,
+    connection_limit::RpcConnectionLimitLayer,
+    error::WsHttpSamePortError,
+    metrics::RpcRequestMetrics,
+    rate_limiter::{RpcBatchCostLimiter, RpcMethodRateLimiter},
+};
 use alloy_network::{Ethereum, IntoWallet};
 use alloy_provider::{fillers::RecommendedFillers, Provider, ProviderBuilder};
 use core::marker::PhantomData;
@@ -38,15 +45,16 @@ use reth_network_api::{noop::NoopNetwork, NetworkInfo, Peers};
 use reth_payload_primitives::PayloadTypes;
 use reth_primitives_traits::{NodePrimitives, TxTy};
 use reth_rpc::{
-    AdminApi, DebugApi, EngineEthApi, EthApi, EthApiBuilder, EthBundle, MinerApi, NetApi,
-    OtterscanApi, RPCApi, RethApi, TraceApi, TxPoolApi, Web3Api,
+    AdminApi, DebugApi, EngineEthApi, ErigonApi, EthApi, EthApiBuilder, EthBundle, MinerApi,
+    NativeTracer, NativeTracers, NetApi, OtterscanApi, RPCApi, RethApi, TraceApi, TxPoolApi,
+    Web3Api,
 };
 use reth_rpc_api::servers::*;
 use reth_rpc_engine_api::RethEngineApi;
 use reth_rpc_eth_api::{
     helpers::{
         pending_block::PendingEnvBuilder, Call, EthApiSpec, EthTransactions, LoadPendingBlock,
-        TraceExt,
+        LoadReceipt, SpawnBlocking, TraceExt,
     },
     node::RpcNodeCoreAdapter,
     EthApiServer, EthApiTypes, FullEthApiServer, FullEthApiTypes, RpcBlock, RpcConvert,
@@ -56,8 +64,8 @@ use reth_rpc_eth_types::{receipt::EthReceiptConverter, EthConfig, EthSubscriptio
 use reth_rpc_layer::{AuthLayer, Claims, CompressionLayer, JwtAuthValidator, JwtSecret};
 pub use reth_rpc_server_types::RethRpcModule;
 use reth_storage_api::{
-    AccountReader, BlockReader, ChangeSetReader, FullRpcProvider, NodePrimitivesProvider,
-    StateProviderFactory,
+    AccountReader, BlockChangesetReader, BlockReader, ChangeSetReader, FullRpcProvider,
+    NodePrimitivesProvider, StateProviderFactory,
 };
 use reth_tasks::{pool::BlockingTaskGuard, Runtime};
 use reth_tokio_util::EventSender;
@@ -79,15 +87,25 @@ use jsonrpsee::server::ServerConfigBuilder;
 pub use reth_ipc::server::{
     Builder as IpcServerBuilder, RpcServiceBuilder as IpcRpcServiceBuilder,
 };
-pub use reth_rpc_server_types::{constants, RpcModuleSelection};
+pub use reth_rpc_server_types::{constants, RpcApiKeys, RpcModuleSelection, RpcServerLimits};
 pub use tower::layer::util::{Identity, Stack};
 
+/// API key authorization of the RPC calls.
+pub mod api_keys;
+
 /// Auth server utilities.
 pub mod auth;
 
 /// RPC server utilities.
 pub mod config;
 
+/// Maximum number of connections adjustable at runtime.
+pub mod connection_limit;
+
+/// Typed hooks intercepting the RPC calls.
+pub mod interceptor;
+pub use interceptor::{RpcInterceptor, RpcInterceptors, RpcTransport};
+
 /// Utils for installing Rpc middleware
 pub mod middleware;
 
@@ -130,6 +148,8 @@ pub struct RpcModuleBuilder<N, Provider, Pool, Network, EvmConfig, Consensus> {
     evm_config: EvmConfig,
     /// The consensus implementation.
     consensus: Consensus,
+    /// Server limits adjustable at runtime with the `admin_` namespace.
+    server_limits: Option<RpcServerLimits>,
     /// Node data primitives.
     _primitives: PhantomData<N>,
 }
@@ -155,6 +175,7 @@ impl<N, Provider, Pool, Network, EvmConfig, Consensus>
             executor: Some(executor),
             evm_config,
             consensus,
+            server_limits: None,
             _primitives: PhantomData,
         }
     }
@@ -164,8 +185,19 @@ impl<N, Provider, Pool, Network, EvmConfig, Consensus>
         self,
         provider: P,
     ) -> RpcModuleBuilder<N, P, Pool, Network, EvmConfig, Consensus> {
-        let Self { pool, network, executor, evm_config, consensus, _primitives, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, evm_config, consensus, _primitives }
+        let Self {
+            pool, network, executor, evm_config, consensus, _primitives, server_limits, ..
+        } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            evm_config,
+            consensus,
+            server_limits,
+            _primitives,
+        }
     }
 
     /// Configure the transaction pool instance.
@@ -173,8 +205,26 @@ impl<N, Provider, Pool, Network, EvmConfig, Consensus>
         self,
         pool: P,
     ) -> RpcModuleBuilder<N, Provider, P, Network, EvmConfig, Consensus> {
-        let Self { provider, network, executor, evm_config, consensus, _primitives, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, evm_config, consensus, _primitives }
+        let Self {
+            provider,
+            network,
+            executor,
+            evm_config,
+            consensus,
+            _primitives,
+            server_limits,
+            ..
+        } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            evm_config,
+            consensus,
+            server_limits,
+            _primitives,
+        }
     }
 
     /// Configure a [`NoopTransactionPool`] instance.
@@ -185,7 +235,16 @@ impl<N, Provider, Pool, Network, EvmConfig, Consensus>
     pub fn with_noop_pool(
         self,
     ) -> RpcModuleBuilder<N, Provider, NoopTransactionPool, Network, EvmConfig, Consensus> {
-        let Self { provider, executor, network, evm_config, consensus, _primitives, .. } = self;
+        let Self {
+            provider,
+            executor,
+            network,
+            evm_config,
+            consensus,
+            _primitives,
+            server_limits,
+            ..
+        } = self;
         RpcModuleBuilder {
             provider,
             executor,
@@ -193,6 +252,7 @@ impl<N, Provider, Pool, Network, EvmConfig, Consensus>
             evm_config,
             pool: NoopTransactionPool::default(),
             consensus,
+            server_limits,
             _primitives,
         }
     }
@@ -202,8 +262,26 @@ impl<N, Provider, Pool, Network, EvmConfig, Consensus>
         self,
         network: Net,
     ) -> RpcModuleBuilder<N, Provider, Pool, Net, EvmConfig, Consensus> {
-        let Self { provider, pool, executor, evm_config, consensus, _primitives, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, evm_config, consensus, _primitives }
+        let Self {
+            provider,
+            pool,
+            executor,
+            evm_config,
+            consensus,
+            _primitives,
+            server_limits,
+            ..
+        } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            evm_config,
+            consensus,
+            server_limits,
+            _primitives,
+        }
     }
 
     /// Configure a [`NoopNetwork`] instance.
@@ -214,7 +292,16 @@ impl<N, Provider, Pool, Network, EvmConfig, Consensus>
     pub fn with_noop_network(
         self,
     ) -> RpcModuleBuilder<N, Provider, Pool, NoopNetwork, EvmConfig, Consensus> {
-        let Self { provider, pool, executor, evm_config, consensus, _primitives, .. } = self;
+        let Self {
+            provider,
+            pool,
+            executor,
+            evm_config,
+            consensus,
+            _primitives,
+            server_limits,
+            ..
+        } = self;
         RpcModuleBuilder {
             provider,
             pool,
@@ -222,13 +309,16 @@ impl<N, Provider, Pool, Network, EvmConfig, Consensus>
             network: NoopNetwork::default(),
             evm_config,
             consensus,
+            server_limits,
             _primitives,
         }
     }
 
     /// Configure the task executor to use for additional tasks.
     pub fn with_executor(self, executor: Runtime) -> Self {
-        let Self { pool, network, provider, evm_config, consensus, _primitives, .. } = self;
+        let Self {
+            pool, network, provider, evm_config, consensus, _primitives, server_limits, ..
+        } = self;
         Self {
             provider,
             network,
@@ -236,6 +326,7 @@ impl<N, Provider, Pool, Network, EvmConfig, Consensus>
             executor: Some(executor),
             evm_config,
             consensus,
+            server_limits,
             _primitives,
         }
     }
@@ -245,8 +336,19 @@ impl<N, Provider, Pool, Network, EvmConfig, Consensus>
         self,
         evm_config: E,
     ) -> RpcModuleBuilder<N, Provider, Pool, Network, E, Consensus> {
-        let Self { provider, pool, executor, network, consensus, _primitives, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, evm_config, consensus, _primitives }
+        let Self {
+            provider, pool, executor, network, consensus, _primitives, server_limits, ..
+        } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            evm_config,
+            consensus,
+            server_limits,
+            _primitives,
+        }
     }
 
     /// Configure the consensus implementation.
@@ -254,8 +356,28 @@ impl<N, Provider, Pool, Network, EvmConfig, Consensus>
         self,
         consensus: C,
     ) -> RpcModuleBuilder<N, Provider, Pool, Network, EvmConfig, C> {
-        let Self { provider, network, pool, executor, evm_config, _primitives, .. } = self;
-        RpcModuleBuilder { provider, network, pool, executor, evm_config, consensus, _primitives }
+        let Self {
+            provider, network, pool, executor, evm_config, _primitives, server_limits, ..
+        } = self;
+        RpcModuleBuilder {
+            provider,
+            network,
+            pool,
+            executor,
+            evm_config,
+            consensus,
+            server_limits,
+            _primitives,
+        }
+    }
+
+    /// Configure the server limits adjustable at runtime with the `admin_` namespace.
+    ///
+    /// The same limits should be passed to the [`EthApiBuilder`] and the [`RpcServerConfig`] to
+    /// also override the gas cap and the limits enforced by the server.
+    pub fn with_server_limits(mut self, server_limits: RpcServerLimits) -> Self {
+        self.server_limits = Some(server_limits);
+        self
     }
 
     /// Instantiates a new [`EthApiBuilder`] from the configured components.
@@ -317,7 +439,8 @@ where
         + ForkChoiceSubscriptions<Header = N::BlockHeader>
         + PersistedBlockSubscriptions
         + AccountReader
-        + ChangeSetReader,
+        + ChangeSetReader
+        + BlockChangesetReader,
     Pool: TransactionPool + Clone + 'static,
     Network: NetworkInfo + Peers + Clone + 'static,
     EvmConfig: ConfigureEvm<Primitives = N> + 'static,
@@ -367,10 +490,12 @@ where
     where
         EthApi: FullEthApiServer<Provider = Provider, Pool = Pool>,
     {
-        let Self { provider, pool, network, executor, consensus, evm_config, .. } = self;
+        let Self {
+            provider, pool, network, executor, consensus, evm_config, server_limits, ..
+        } = self;
         let executor =
             executor.expect("RpcModuleBuilder requires a Runtime to be set via `with_executor`");
-        RpcRegistryInner::new(
+        let mut registry = RpcRegistryInner::new(
             provider,
             pool,
             network,
@@ -380,7 +505,9 @@ where
             evm_config,
             eth,
             engine_events,
-        )
+        );
+        registry.server_limits = server_limits;
+        registry
     }
 
     /// Configures all [`RpcModule`]s specific to the given [`TransportRpcModuleConfig`] which can
@@ -413,6 +540,7 @@ impl<N: NodePrimitives> Default for RpcModuleBuilder<N, (), (), (), (), ()> {
             executor: None,
             evm_config: (),
             consensus: (),
+            server_limits: None,
             _primitives: PhantomData,
         }
     }
@@ -499,10 +627,14 @@ pub struct RpcRegistryInner<Provider, Pool, Network, EthApi: EthApiTypes, EvmCon
     eth: EthHandlers<EthApi>,
     /// to put trace calls behind semaphore
     blocking_pool_guard: BlockingTaskGuard,
+    /// Server limits adjustable at runtime with the `admin_` namespace, if any
+    server_limits: Option<RpcServerLimits>,
     /// Contains the [Methods] of a module
     modules: HashMap<RethRpcModule, Methods>,
     /// eth config settings
     eth_config: EthConfig,
+    /// Custom tracers of the `debug` namespace
+    native_tracers: NativeTracers<EthApi>,
     /// Notification channel for engine API events
     engine_events:
         EventSender<ConsensusEngineEvent<<EthApi::RpcConvert as RpcConvert>::Primitives>>,
@@ -556,7 +688,9 @@ where
             consensus,
             modules: Default::default(),
             blocking_pool_guard,
+            server_limits: None,
             eth_config: config.eth,
+            native_tracers: NativeTracers::default(),
             evm_config,
             engine_events,
         }
@@ -588,6 +722,41 @@ where
         &self.executor
     }
 
+    /// Returns the server limits adjustable at runtime, if any.
+    pub const fn server_limits(&self) -> Option<&RpcServerLimits> {
+        self.server_limits.as_ref()
+    }
+
+    /// Returns the native tracers of the `debug` namespace.
+    pub const fn native_tracers(&self) -> &NativeTracers<EthApi> {
+        &self.native_tracers
+    }
+
+    /// Registers a native tracer of the `debug` namespace, selectable by name with the `tracer`
+    /// field of the `debug_trace*` requests.
+    ///
+    /// The tracer is only available in `debug` modules created afterwards, see
+    /// [`Self::set_native_tracers`].
+    pub fn register_native_tracer(
+        &mut self,
+        name: impl Into<String>,
+        tracer: impl NativeTracer<EthApi>,
+    ) -> &mut Self {
+        let mut native_tracers = self.native_tracers.clone();
+        native_tracers.register(name, tracer);
+        self.set_native_tracers(native_tracers)
+    }
+
+    /// Sets the native tracers of the `debug` namespace, replacing the registered tracers.
+    ///
+    /// The installed `debug` module is discarded, so that modules created afterwards, e.g. with
+    /// [`Self::create_transport_rpc_modules`], use the tracers.
+    pub fn set_native_tracers(&mut self, native_tracers: NativeTracers<EthApi>) -> &mut Self {
+        self.native_tracers = native_tracers;
+        self.modules.remove(&RethRpcModule::Debug);
+        self
+    }
+
     /// Returns a reference to the provider
     pub const fn provider(&self) -> &Provider {
         &self.provider
@@ -627,7 +796,12 @@ where
         Network: Peers,
         Pool: TransactionPool + Clone + 'static,
     {
-        AdminApi::new(self.network.clone(), self.provider.chain_spec(), self.pool.clone())
+        let admin =
+            AdminApi::new(self.network.clone(), self.provider.chain_spec(), self.pool.clone());
+        match self.server_limits.clone() {
+            Some(limits) => admin.with_server_limits(limits, self.blocking_pool_guard.clone()),
+            None => admin,
+        }
     }
 
     /// Instantiates `Web3Api`
@@ -665,6 +839,7 @@ where
             Transaction = N::SignedTx,
         > + AccountReader
         + ChangeSetReader
+        + BlockChangesetReader
         + CanonStateSubscriptions<Primitives = N>
         + ForkChoiceSubscriptions<Header = N::BlockHeader>
         + PersistedBlockSubscriptions,
@@ -704,6 +879,20 @@ where
         self
     }
 
+    /// Register Erigon Namespace
+    ///
+    /// # Panics
+    ///
+    /// If called outside of the tokio runtime. See also [`Self::eth_api`]
+    pub fn register_erigon(&mut self) -> &mut Self
+    where
+        EthApi: LoadReceipt<Primitives = N> + SpawnBlocking,
+    {
+        let erigon_api = self.erigon_api();
+        self.modules.insert(RethRpcModule::Erigon, erigon_api.into_rpc().into());
+        self
+    }
+
     /// Register Debug Namespace
     ///
     /// # Panics
@@ -770,6 +959,15 @@ where
         let eth_api = self.eth_api().clone();
         OtterscanApi::new(eth_api)
     }
+
+    /// Instantiates `ErigonApi`
+    ///
+    /// # Panics
+    ///
+    /// If called outside of the tokio runtime. See also [`Self::eth_api`]
+    pub fn erigon_api(&self) -> ErigonApi<EthApi> {
+        ErigonApi::new(self.eth_api().clone(), self.eth_config.filter_config())
+    }
 }
 
 impl<N, Provider, Pool, Network, EthApi, EvmConfig, Consensus>
@@ -782,7 +980,8 @@ where
             Transaction = N::SignedTx,
             Receipt = N::Receipt,
         > + AccountReader
-        + ChangeSetReader,
+        + ChangeSetReader
+        + BlockChangesetReader,
     Network: NetworkInfo + Peers + Clone + 'static,
     EthApi: EthApiTypes,
     EvmConfig: ConfigureEvm<Primitives = N>,
@@ -828,6 +1027,9 @@ where
             self.tasks(),
             self.engine_events.new_listener(),
         )
+        .with_tracing_timeout(self.eth_config.tracing_timeout)
+        .with_witness_concurrency(self.eth_config.proof_concurrency)
+        .with_native_tracers(self.native_tracers.clone())
     }
 
     /// Instantiates `NetApi`
@@ -863,7 +1065,8 @@ where
         + ForkChoiceSubscriptions<Header = N::BlockHeader>
         + PersistedBlockSubscriptions
         + AccountReader
-        + ChangeSetReader,
+        + ChangeSetReader
+        + BlockChangesetReader,
     Pool: TransactionPool + Clone + 'static,
     Network: NetworkInfo + Peers + Clone + 'static,
     EthApi: FullEthApiServer,
@@ -959,19 +1162,29 @@ where
                 self.modules
                     .entry(namespace.clone())
                     .or_insert_with(|| match namespace.clone() {
-                        RethRpcModule::Admin => AdminApi::new(
-                            self.network.clone(),
-                            self.provider.chain_spec(),
-                            self.pool.clone(),
-                        )
-                        .into_rpc()
-                        .into(),
+                        RethRpcModule::Admin => {
+                            let admin = AdminApi::new(
+                                self.network.clone(),
+                                self.provider.chain_spec(),
+                                self.pool.clone(),
+                            );
+                            match self.server_limits.clone() {
+                                Some(limits) => admin
+                                    .with_server_limits(limits, self.blocking_pool_guard.clone()),
+                                None => admin,
+                            }
+                            .into_rpc()
+                            .into()
+                        }
                         RethRpcModule::Debug => DebugApi::new(
                             eth_api.clone(),
                             self.blocking_pool_guard.clone(),
                             &self.executor,
                             self.engine_events.new_listener(),
                         )
+                        .with_tracing_timeout(self.eth_config.tracing_timeout)
+                        .with_witness_concurrency(self.eth_config.proof_concurrency)
+                        .with_native_tracers(self.native_tracers.clone())
                         .into_rpc()
                         .into(),
                         RethRpcModule::Eth => {
@@ -1017,6 +1230,11 @@ where
                         .into_rpc()
                         .into(),
                         RethRpcModule::Ots => OtterscanApi::new(eth_api.clone()).into_rpc().into(),
+                        RethRpcModule::Erigon => {
+                            ErigonApi::new(eth_api.clone(), self.eth_config.filter_config())
+                                .into_rpc()
+                                .into()
+                        }
                         RethRpcModule::Reth => RethApi::new(
                             self.provider.clone(),
                             self.evm_config.clone(),
@@ -1064,8 +1282,10 @@ where
             consensus: self.consensus.clone(),
             eth: self.eth.clone(),
             blocking_pool_guard: self.blocking_pool_guard.clone(),
+            server_limits: self.server_limits.clone(),
             modules: self.modules.clone(),
             eth_config: self.eth_config.clone(),
+            native_tracers: self.native_tracers.clone(),
             engine_events: self.engine_events.clone(),
         }
     }
@@ -1092,6 +1312,8 @@ pub struct RpcServerConfig<RpcMiddleware = Identity> {
     http_addr: Option<SocketAddr>,
     /// Control whether http responses should be compressed
     http_disable_compression: bool,
+    /// Minimum size of the compressed http responses, in bytes
+    http_compression_min_size: u16,
     /// Configs for WS server
     ws_server_config: Option<ServerConfigBuilder>,
     /// Allowed CORS Domains for ws.
@@ -1106,6 +1328,18 @@ pub struct RpcServerConfig<RpcMiddleware = Identity> {
     jwt_secret: Option<JwtSecret>,
     /// Whether RPC request metrics are enabled.
     rpc_metrics_enabled: bool,
+    /// Duration above which calls are logged, if RPC request metrics are enabled.
+    rpc_slow_call_threshold: Option<Duration>,
+    /// Per-method rate limits of the calls, if any.
+    rpc_rate_limiter: Option<RpcMethodRateLimiter>,
+    /// Cost budget of the calls of a batch, if any.
+    rpc_batch_cost_limiter: Option<RpcBatchCostLimiter>,
+    /// API keys restricting the namespaces of the http and ws calls, if any.
+    rpc_api_keys: Option<RpcApiKeyLayer>,
+    /// Server limits adjustable at runtime, if any.
+    server_limits: Option<RpcServerLimits>,
+    /// Hooks intercepting the calls of the http, ws and ipc servers.
+    rpc_interceptors: RpcInterceptors,
     /// Configurable RPC middleware
     rpc_middleware: RpcMiddleware,
 }
@@ -1120,6 +1354,7 @@ impl Default for RpcServerConfig<Identity> {
             http_cors_domains: None,
             http_addr: None,
             http_disable_compression: false,
+            http_compression_min_size: constants::DEFAULT_HTTP_COMPRESSION_MIN_SIZE,
             ws_server_config: None,
             ws_cors_domains: None,
             ws_addr: None,
@@ -1127,6 +1362,12 @@ impl Default for RpcServerConfig<Identity> {
             ipc_endpoint: None,
             jwt_secret: None,
             rpc_metrics_enabled: true,
+            rpc_slow_call_threshold: None,
+            rpc_rate_limiter: None,
+            rpc_batch_cost_limiter: None,
+            rpc_api_keys: None,
+            server_limits: None,
+            rpc_interceptors: Default::default(),
             rpc_middleware: Default::default(),
         }
     }
@@ -1185,6 +1426,7 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             http_cors_domains: self.http_cors_domains,
             http_addr: self.http_addr,
             http_disable_compression: self.http_disable_compression,
+            http_compression_min_size: self.http_compression_min_size,
             ws_server_config: self.ws_server_config,
             ws_cors_domains: self.ws_cors_domains,
             ws_addr: self.ws_addr,
@@ -1192,6 +1434,12 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             ipc_endpoint: self.ipc_endpoint,
             jwt_secret: self.jwt_secret,
             rpc_metrics_enabled: self.rpc_metrics_enabled,
+            rpc_slow_call_threshold: self.rpc_slow_call_threshold,
+            rpc_rate_limiter: self.rpc_rate_limiter,
+            rpc_batch_cost_limiter: self.rpc_batch_cost_limiter,
+            rpc_api_keys: self.rpc_api_keys,
+            server_limits: self.server_limits,
+            rpc_interceptors: self.rpc_interceptors,
             rpc_middleware,
         }
     }
@@ -1202,6 +1450,53 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         self
     }
 
+    /// Configures the duration above which calls are logged as slow.
+    ///
+    /// Calls are timed by the built-in RPC request metrics layer, so this has no effect if it's
+    /// disabled.
+    pub const fn with_rpc_slow_call_threshold(mut self, threshold: Option<Duration>) -> Self {
+        self.rpc_slow_call_threshold = threshold;
+        self
+    }
+
+    /// Configures the per-method rate limits of the http, ws and ipc servers.
+    pub fn with_rpc_rate_limiter(mut self, rate_limiter: Option<RpcMethodRateLimiter>) -> Self {
+        self.rpc_rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Configures the cost budget of the batches of the http, ws and ipc servers.
+    pub fn with_rpc_batch_cost_limiter(mut self, limiter: Option<RpcBatchCostLimiter>) -> Self {
+        self.rpc_batch_cost_limiter = limiter;
+        self
+    }
+
+    /// Configures the API keys restricting the namespaces that can be called over http and ws.
+    ///
+    /// Calls over ipc are not restricted.
+    pub fn with_rpc_api_keys(mut self, api_keys: Option<RpcApiKeys>) -> Self {
+        self.rpc_api_keys = api_keys.map(RpcApiKeyLayer::new);
+        self
+    }
+
+    /// Configures the server limits adjustable at runtime with the `admin_` namespace.
+    ///
+    /// The overridden rate limits apply to the http, ws and ipc servers, the maximum number of
+    /// connections to the http and ws servers.
+    pub fn with_server_limits(mut self, server_limits: Option<RpcServerLimits>) -> Self {
+        self.server_limits = server_limits;
+        self
+    }
+
+    /// Configures the hooks intercepting the calls of the http, ws and ipc servers.
+    ///
+    /// The interceptors see the calls after the RPC request metrics, and before the API key,
+    /// batch cost and rate limit checks and the configured RPC middleware.
+    pub fn with_rpc_interceptors(mut self, interceptors: RpcInterceptors) -> Self {
+        self.rpc_interceptors = interceptors;
+        self
+    }
+
     /// Configure the cors domains for http _and_ ws
     pub fn with_cors(self, cors_domain: Option<String>) -> Self {
         self.with_http_cors(cors_domain.clone()).with_ws_cors(cors_domain)
@@ -1219,6 +1514,12 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         self
     }
 
+    /// Configure the minimum size of the compressed HTTP responses, in bytes
+    pub const fn with_http_compression_min_size(mut self, min_size: u16) -> Self {
+        self.http_compression_min_size = min_size;
+        self
+    }
+
     /// Configure the cors domains for HTTP
     pub fn with_http_cors(mut self, cors_domain: Option<String>) -> Self {
         self.http_cors_domains = cors_domain;
@@ -1335,11 +1636,14 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
 
     /// Returns a [`CompressionLayer`] that adds compression support (gzip, deflate, brotli, zstd)
     /// based on the client's `Accept-Encoding` header
-    fn maybe_compression_layer(disable_compression: bool) -> Option<CompressionLayer> {
+    fn maybe_compression_layer(
+        disable_compression: bool,
+        min_size: u16,
+    ) -> Option<CompressionLayer> {
         if disable_compression {
             None
         } else {
-            Some(CompressionLayer::new())
+            Some(CompressionLayer::with_min_size(min_size))
         }
     }
 
@@ -1367,17 +1671,39 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         )));
 
         let rpc_metrics_enabled = self.rpc_metrics_enabled;
+        let slow_call_threshold = self.rpc_slow_call_threshold;
+        // rate limits adjustable at runtime need the rate limiter, even without configured limits
+        let rate_limiter = match self.server_limits.clone() {
+            Some(server_limits) => Some(
+                self.rpc_rate_limiter
+                    .clone()
+                    .unwrap_or_else(|| RpcMethodRateLimiter::new([], None))
+                    .with_server_limits(server_limits),
+            ),
+            None => self.rpc_rate_limiter.clone(),
+        };
+        let connection_limit = self.server_limits.clone().map(RpcConnectionLimitLayer::new);
+        let client_key_layer =
+            rate_limiter.as_ref().and_then(RpcMethodRateLimiter::client_key_layer);
+        let namespace_guard = self.rpc_api_keys.is_some().then_some(RpcNamespaceGuardLayer);
         let ipc_path =
             self.ipc_endpoint.clone().unwrap_or_else(|| constants::DEFAULT_IPC_ENDPOINT.into());
 
         if let Some(builder) = self.ipc_server_config {
             let ipc = builder
                 .set_rpc_middleware(
-                    IpcRpcServiceBuilder::new().option_layer(
-                        rpc_metrics_enabled
-                            .then(|| modules.ipc.as_ref().map(RpcRequestMetrics::ipc))
-                            .flatten(),
-                    ),
+                    IpcRpcServiceBuilder::new()
+                        .option_layer(
+                            rpc_metrics_enabled
+                                .then(|| modules.ipc.as_ref().map(RpcRequestMetrics::ipc))
+                                .flatten()
+                                .map(|metrics| {
+                                    metrics.with_slow_call_threshold(slow_call_threshold)
+                                }),
+                        )
+                        .option_layer(self.rpc_interceptors.layer(RpcTransport::Ipc))
+                        .option_layer(self.rpc_batch_cost_limiter.clone())
+                        .option_layer(rate_limiter.clone()),
                 )
                 .build(ipc_path);
             ipc_handle = Some(ipc.start(modules.ipc.clone().expect("ipc server error")).await?);
@@ -1410,11 +1736,15 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                 let server = ServerBuilder::new()
                     .set_http_middleware(
                         tower::ServiceBuilder::new()
+                            .option_layer(connection_limit.clone())
                             .option_layer(Self::maybe_cors_layer(cors)?)
                             .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
                             .option_layer(Self::maybe_compression_layer(
                                 self.http_disable_compression,
-                            )),
+                                self.http_compression_min_size,
+                            ))
+                            .option_layer(client_key_layer.clone())
+                            .option_layer(self.rpc_api_keys.clone()),
                     )
                     .set_rpc_middleware(
                         RpcServiceBuilder::default()
@@ -1427,8 +1757,15 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                                             .or(modules.ws.as_ref())
                                             .map(RpcRequestMetrics::same_port)
                                     })
-                                    .flatten(),
+                                    .flatten()
+                                    .map(|metrics| {
+                                        metrics.with_slow_call_threshold(slow_call_threshold)
+                                    }),
                             )
+                            .option_layer(self.rpc_interceptors.layer(RpcTransport::Http))
+                            .option_layer(namespace_guard)
+                            .option_layer(self.rpc_batch_cost_limiter.clone())
+                            .option_layer(rate_limiter.clone())
                             .layer(self.rpc_middleware.clone()),
                     )
                     .set_config(config.build())
@@ -1467,16 +1804,26 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                 .set_config(config.ws_only().build())
                 .set_http_middleware(
                     tower::ServiceBuilder::new()
+                        .option_layer(connection_limit.clone())
                         .option_layer(Self::maybe_cors_layer(self.ws_cors_domains.clone())?)
-                        .option_layer(Self::maybe_jwt_layer(self.jwt_secret)),
+                        .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
+                        .option_layer(client_key_layer.clone())
+                        .option_layer(self.rpc_api_keys.clone()),
                 )
                 .set_rpc_middleware(
                     RpcServiceBuilder::default()
                         .option_layer(
                             rpc_metrics_enabled
                                 .then(|| modules.ws.as_ref().map(RpcRequestMetrics::ws))
-                                .flatten(),
+                                .flatten()
+                                .map(|metrics| {
+                                    metrics.with_slow_call_threshold(slow_call_threshold)
+                                }),
                         )
+                        .option_layer(self.rpc_interceptors.layer(RpcTransport::WebSocket))
+                        .option_layer(namespace_guard)
+                        .option_layer(self.rpc_batch_cost_limiter.clone())
+                        .option_layer(rate_limiter.clone())
                         .layer(self.rpc_middleware.clone()),
                 )
                 .build(ws_socket_addr)
@@ -1496,17 +1843,30 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                 .set_config(config.http_only().build())
                 .set_http_middleware(
                     tower::ServiceBuilder::new()
+                        .option_layer(connection_limit.clone())
                         .option_layer(Self::maybe_cors_layer(self.http_cors_domains.clone())?)
                         .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
-                        .option_layer(Self::maybe_compression_layer(self.http_disable_compression)),
+                        .option_layer(Self::maybe_compression_layer(
+                            self.http_disable_compression,
+                            self.http_compression_min_size,
+                        ))
+                        .option_layer(client_key_layer.clone())
+                        .option_layer(self.rpc_api_keys.clone()),
                 )
                 .set_rpc_middleware(
                     RpcServiceBuilder::default()
                         .option_layer(
                             rpc_metrics_enabled
                                 .then(|| modules.http.as_ref().map(RpcRequestMetrics::http))
-                                .flatten(),
+                                .flatten()
+                                .map(|metrics| {
+                                    metrics.with_slow_call_threshold(slow_call_threshold)
+                                }),
                         )
+                        .option_layer(self.rpc_interceptors.layer(RpcTransport::Http))
+                        .option_layer(namespace_guard)
+                        .option_layer(self.rpc_batch_cost_limiter.clone())
+                        .option_layer(rate_limiter.clone())
                         .layer(self.rpc_middleware.clone()),
                 )
                 .build(http_socket_addr)
@@ -2423,6 +2783,7 @@ mod tests {
                 "rpc" => RethRpcModule::Rpc,
                 "ots" => RethRpcModule::Ots,
                 "reth" => RethRpcModule::Reth,
+                "erigon" => RethRpcModule::Erigon,
             );
     }
 
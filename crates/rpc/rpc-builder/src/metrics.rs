@@ -15,8 +15,10 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
+    time::Duration,
 };
 use tower::Layer;
+use tracing::warn;
 
 /// Metrics for the RPC server.
 ///
@@ -24,10 +26,14 @@ use tower::Layer;
 /// - Connection metrics: metrics for the connection (e.g. number of connections opened, relevant
 ///   for WS and IPC)
 /// - Request metrics: metrics for each RPC method (e.g. number of calls started, time taken to
-///   process a call)
+///   process a call), labeled by method, namespace and outcome
+///
+/// Calls taking longer than the optional slow call threshold are logged.
 #[derive(Default, Debug, Clone)]
 pub(crate) struct RpcRequestMetrics {
     inner: Arc<RpcServerMetricsInner>,
+    /// Duration above which calls are logged
+    slow_call_threshold: Option<Duration>,
 }
 
 impl RpcRequestMetrics {
@@ -37,14 +43,19 @@ impl RpcRequestMetrics {
                 connection_metrics: transport.connection_metrics(),
                 call_metrics: module
                     .method_names()
-                    .map(|method| {
-                        (method, RpcServerCallMetrics::new_with_labels(&[("method", method)]))
-                    })
+                    .map(|method| (method, RpcServerMethodMetrics::new(method)))
                     .collect(),
             }),
+            slow_call_threshold: None,
         }
     }
 
+    /// Logs the calls taking longer than the threshold.
+    pub(crate) const fn with_slow_call_threshold(mut self, threshold: Option<Duration>) -> Self {
+        self.slow_call_threshold = threshold;
+        self
+    }
+
     /// Creates a new instance of the metrics layer for HTTP.
     pub(crate) fn http(module: &RpcModule<()>) -> Self {
         Self::new(module, RpcTransport::Http)
@@ -82,7 +93,39 @@ struct RpcServerMetricsInner {
     /// Connection metrics per transport type
     connection_metrics: RpcServerConnectionMetrics,
     /// Call metrics per RPC method
-    call_metrics: HashMap<&'static str, RpcServerCallMetrics>,
+    call_metrics: HashMap<&'static str, RpcServerMethodMetrics>,
+}
+
+/// All metrics of the calls of a RPC method.
+#[derive(Clone, Debug)]
+struct RpcServerMethodMetrics {
+    /// Metrics of all calls
+    calls: RpcServerCallMetrics,
+    /// Metrics of the successful calls
+    successful: RpcServerCallOutcomeMetrics,
+    /// Metrics of the failed calls
+    failed: RpcServerCallOutcomeMetrics,
+}
+
+impl RpcServerMethodMetrics {
+    fn new(method: &'static str) -> Self {
+        let namespace = method.split_once('_').map_or(method, |(namespace, _)| namespace);
+        let outcome_metrics = |outcome: &'static str| {
+            RpcServerCallOutcomeMetrics::new_with_labels(&[
+                ("method", method),
+                ("namespace", namespace),
+                ("outcome", outcome),
+            ])
+        };
+        Self {
+            calls: RpcServerCallMetrics::new_with_labels(&[
+                ("method", method),
+                ("namespace", namespace),
+            ]),
+            successful: outcome_metrics("success"),
+            failed: outcome_metrics("error"),
+        }
+    }
 }
 
 /// A [`RpcServiceT`] middleware that captures RPC metrics for the server.
@@ -116,7 +159,7 @@ where
         self.metrics.inner.connection_metrics.requests_started_total.increment(1);
         let call_metrics = self.metrics.inner.call_metrics.get_key_value(req.method.as_ref());
         if let Some((_, call_metrics)) = &call_metrics {
-            call_metrics.started_total.increment(1);
+            call_metrics.calls.started_total.increment(1);
         }
         MeteredRequestFuture {
             fut: self.inner.call(req),
@@ -132,7 +175,7 @@ where
         for batch_entry in req.iter().flatten() {
             let method_name = batch_entry.method_name();
             if let Some(call_metrics) = self.metrics.inner.call_metrics.get(method_name) {
-                call_metrics.started_total.increment(1);
+                call_metrics.calls.started_total.increment(1);
             }
         }
 
@@ -185,22 +228,37 @@ impl<F: Future<Output = MethodResponse>> Future for MeteredRequestFuture<F> {
 
         let res = this.fut.poll(cx);
         if let Poll::Ready(resp) = &res {
-            let elapsed = this.started_at.elapsed().as_secs_f64();
+            let elapsed = this.started_at.elapsed();
+            let elapsed_secs = elapsed.as_secs_f64();
 
             // update transport metrics
             this.metrics.inner.connection_metrics.requests_finished_total.increment(1);
-            this.metrics.inner.connection_metrics.request_time_seconds.record(elapsed);
+            this.metrics.inner.connection_metrics.request_time_seconds.record(elapsed_secs);
 
             // update call metrics
             if let Some(call_metrics) =
                 this.method.and_then(|method| this.metrics.inner.call_metrics.get(method))
             {
-                call_metrics.time_seconds.record(elapsed);
-                if resp.is_success() {
-                    call_metrics.successful_total.increment(1);
+                call_metrics.calls.time_seconds.record(elapsed_secs);
+                let outcome_metrics = if resp.is_success() {
+                    call_metrics.calls.successful_total.increment(1);
+                    &call_metrics.successful
                 } else {
-                    call_metrics.failed_total.increment(1);
-                }
+                    call_metrics.calls.failed_total.increment(1);
+                    &call_metrics.failed
+                };
+                outcome_metrics.calls_total.increment(1);
+                outcome_metrics.time_seconds.record(elapsed_secs);
+            }
+
+            if this.metrics.slow_call_threshold.is_some_and(|threshold| elapsed > threshold) {
+                warn!(
+                    target: "rpc::server",
+                    method = this.method.unwrap_or("unknown"),
+                    ?elapsed,
+                    error_code = ?resp.as_error_code(),
+                    "Slow RPC call"
+                );
             }
         }
         res
@@ -245,15 +303,18 @@ where
 
 /// The transport protocol used for the RPC connection.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub(crate) enum RpcTransport {
+pub enum RpcTransport {
+    /// HTTP.
     Http,
+    /// `WebSocket`.
     WebSocket,
+    /// IPC.
     Ipc,
 }
 
 impl RpcTransport {
     /// Returns the string representation of the transport protocol.
-    pub(crate) const fn as_str(&self) -> &'static str {
+    pub const fn as_str(&self) -> &'static str {
         match self {
             Self::Http => "http",
             Self::WebSocket => "ws",
@@ -302,3 +363,122 @@ struct RpcServerCallMetrics {
     /// Response for a single call
     time_seconds: Histogram,
 }
+
+/// Metrics for the RPC calls with an outcome, either success or error
+#[derive(Metrics, Clone)]
+#[metrics(scope = "rpc_server.call_outcomes")]
+struct RpcServerCallOutcomeMetrics {
+    /// The number of calls with the outcome
+    calls_total: Counter,
+    /// Response time of the calls with the outcome
+    time_seconds: Histogram,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::types::{ErrorObject, Id};
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    /// Fails the calls of `eth_fail`, and answers all other calls.
+    #[derive(Clone)]
+    struct FailingService;
+
+    impl RpcServiceT for FailingService {
+        type MethodResponse = MethodResponse;
+        type NotificationResponse = MethodResponse;
+        type BatchResponse = MethodResponse;
+
+        fn call<'a>(
+            &self,
+            req: Request<'a>,
+        ) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+            let response = if req.method_name() == "eth_fail" {
+                MethodResponse::error(req.id(), ErrorObject::owned(-32000, "failed", None::<()>))
+            } else {
+                MethodResponse::response(
+                    req.id(),
+                    jsonrpsee::ResponsePayload::success(req.method_name().to_owned()),
+                    usize::MAX,
+                )
+            };
+            async move { response }
+        }
+
+        fn batch<'a>(&self, _: Batch<'a>) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+            async { unreachable!() }
+        }
+
+        fn notification<'a>(
+            &self,
+            _: Notification<'a>,
+        ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+            async { unreachable!() }
+        }
+    }
+
+    #[tokio::test]
+    async fn call_metrics_by_outcome() {
+        let mut module = RpcModule::new(());
+        module.register_method("eth_ok", |_, _, _| "ok").unwrap();
+        module.register_method("eth_fail", |_, _, _| "fails").unwrap();
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let metrics = metrics::with_local_recorder(&recorder, || RpcRequestMetrics::http(&module));
+        let service = metrics.layer(FailingService);
+
+        assert!(service.call(Request::borrowed("eth_ok", None, Id::Number(1))).await.is_success());
+        assert!(service.call(Request::borrowed("eth_ok", None, Id::Number(2))).await.is_success());
+        assert!(service.call(Request::borrowed("eth_fail", None, Id::Number(3))).await.is_error());
+        // calls of unknown methods are only counted per transport
+        assert!(service
+            .call(Request::borrowed("eth_other", None, Id::Number(4)))
+            .await
+            .is_success());
+
+        let counters = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .filter_map(|(key, _, _, value)| {
+                let DebugValue::Counter(value) = value else { return None };
+                let mut labels = key
+                    .key()
+                    .labels()
+                    .map(|label| format!("{}={}", label.key(), label.value()))
+                    .collect::<Vec<_>>();
+                labels.sort();
+                Some((format!("{}{{{}}}", key.key().name(), labels.join(",")), value))
+            })
+            .collect::<HashMap<_, _>>();
+        let counter = |name: &str| counters.get(name).copied().unwrap_or_default();
+
+        assert_eq!(counter("rpc_server.connections.requests_started_total{transport=http}"), 4);
+        assert_eq!(counter("rpc_server.connections.requests_finished_total{transport=http}"), 4);
+        assert_eq!(counter("rpc_server.calls.started_total{method=eth_ok,namespace=eth}"), 2);
+        assert_eq!(counter("rpc_server.calls.successful_total{method=eth_ok,namespace=eth}"), 2);
+        assert_eq!(counter("rpc_server.calls.failed_total{method=eth_ok,namespace=eth}"), 0);
+        assert_eq!(counter("rpc_server.calls.started_total{method=eth_fail,namespace=eth}"), 1);
+        assert_eq!(counter("rpc_server.calls.failed_total{method=eth_fail,namespace=eth}"), 1);
+        assert_eq!(
+            counter(
+                "rpc_server.call_outcomes.calls_total{method=eth_ok,namespace=eth,outcome=success}"
+            ),
+            2
+        );
+        assert_eq!(
+            counter(
+                "rpc_server.call_outcomes.calls_total{method=eth_ok,namespace=eth,outcome=error}"
+            ),
+            0
+        );
+        assert_eq!(
+            counter(
+                "rpc_server.call_outcomes.calls_total{method=eth_fail,namespace=eth,outcome=error}"
+            ),
+            1
+        );
+        assert!(!counters.keys().any(|name| name.contains("eth_other")));
+    }
+}
@@ -1,15 +1,28 @@
 //! [`jsonrpsee`] helper layer for rate limiting certain methods.
 
-use jsonrpsee::{server::middleware::rpc::RpcServiceT, types::Request};
+use http::HeaderName;
+use jsonrpsee::{
+    core::middleware::{Batch, BatchEntry, BatchEntryErr, Notification},
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObject, ErrorObjectOwned, Request},
+    MethodResponse,
+};
+use reth_metrics::{metrics::Counter, Metrics};
+use reth_rpc_server_types::{
+    constants::DEFAULT_TRACING_CALL_COST, error::codes::LIMIT_EXCEEDED, RpcMethodCost,
+    RpcMethodRateLimit, RpcServerLimits,
+};
+use schnellru::{ByLength, LruMap};
 use std::{
     future::Future,
     pin::Pin,
-    sync::Arc,
+    sync::{Arc, Mutex},
     task::{ready, Context, Poll},
+    time::{Duration, Instant},
 };
 use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tokio_util::sync::PollSemaphore;
-use tower::Layer;
+use tower::{Layer, Service};
 
 /// Rate limiter for the RPC server.
 ///
@@ -130,3 +143,581 @@ impl<F: Future> Future for RateLimitingRequestFuture<F> {
         res
     }
 }
+
+/// Maximum number of token buckets, above which the least recently used bucket is evicted,
+/// bounding the memory used for per-client limits.
+const MAX_TOKEN_BUCKETS: u32 = 10_000;
+
+/// Token bucket rate limiter of RPC methods.
+///
+/// Each call is limited by the most specific [`RpcMethodRateLimit`] matching its method: an exact
+/// match, or else the longest matching prefix. Calls exceeding the limit are rejected with a
+/// [`LIMIT_EXCEEDED`] error carrying the time until the next call is admitted, instead of being
+/// queued, so that a single expensive method can't starve the others.
+///
+/// If the calls carry a [`RpcClientKey`], each client gets its own buckets.
+///
+/// The limits can be replaced at runtime by overriding them with [`RpcServerLimits`].
+#[derive(Debug, Clone)]
+pub struct RpcMethodRateLimiter {
+    inner: Arc<RpcMethodRateLimiterInner>,
+    /// Overrides of the limits, if they are adjustable at runtime
+    server_limits: Option<RpcServerLimits>,
+}
+
+impl RpcMethodRateLimiter {
+    /// Creates a new rate limiter with the given limits.
+    ///
+    /// If a header is given, each client is limited separately, identified by the value of the
+    /// header, e.g. `x-api-key`, or `x-forwarded-for` behind a proxy. This requires the
+    /// [`RpcClientKeyLayer`] returned by [`Self::client_key_layer`] to be installed as HTTP
+    /// middleware.
+    pub fn new(
+        limits: impl IntoIterator<Item = RpcMethodRateLimit>,
+        client_key_header: Option<HeaderName>,
+    ) -> Self {
+        let limits = Self::with_metrics(limits);
+        Self {
+            inner: Arc::new(RpcMethodRateLimiterInner {
+                limits: limits.clone(),
+                client_key_header,
+                active: Mutex::new(ActiveRateLimits { limits, overridden: None }),
+                buckets: Mutex::new(LruMap::new(ByLength::new(MAX_TOKEN_BUCKETS))),
+            }),
+            server_limits: None,
+        }
+    }
+
+    /// Applies the rate limits overridden with the [`RpcServerLimits`] instead of the configured
+    /// ones, once they are set.
+    pub fn with_server_limits(mut self, server_limits: RpcServerLimits) -> Self {
+        self.server_limits = Some(server_limits);
+        self
+    }
+
+    fn with_metrics(limits: impl IntoIterator<Item = RpcMethodRateLimit>) -> LimitsWithMetrics {
+        limits
+            .into_iter()
+            .map(|limit| {
+                let metrics =
+                    RpcMethodRateLimitMetrics::new_with_labels(&[("method", limit.method.clone())]);
+                (limit, metrics)
+            })
+            .collect()
+    }
+
+    /// Returns the limits in effect, the overridden ones if set.
+    fn limits(&self) -> LimitsWithMetrics {
+        let Some(server_limits) = &self.server_limits else { return self.inner.limits.clone() };
+        let overridden = server_limits.rate_limits();
+
+        let mut active = self.inner.active.lock().unwrap_or_else(|err| err.into_inner());
+        let unchanged = match (&active.overridden, &overridden) {
+            (Some(active), Some(overridden)) => Arc::ptr_eq(active, overridden),
+            (active, overridden) => active.is_none() && overridden.is_none(),
+        };
+        if !unchanged {
+            active.limits = match &overridden {
+                Some(limits) => Self::with_metrics(limits.iter().cloned()),
+                None => self.inner.limits.clone(),
+            };
+            active.overridden = overridden;
+            // the buckets are keyed by the index of their limit
+            self.inner.buckets.lock().unwrap_or_else(|err| err.into_inner()).clear();
+        }
+        active.limits.clone()
+    }
+
+    /// Returns the HTTP layer extracting the [`RpcClientKey`] of the requests, if the limits are
+    /// per client.
+    pub fn client_key_layer(&self) -> Option<RpcClientKeyLayer> {
+        self.inner.client_key_header.clone().map(RpcClientKeyLayer::new)
+    }
+
+    /// Takes a token from the bucket of the call, or returns the error the call is rejected with.
+    fn check(&self, method: &str, client: Option<&RpcClientKey>) -> Result<(), ErrorObjectOwned> {
+        let limits = self.limits();
+        let Some((index, (limit, metrics))) = limits
+            .iter()
+            .enumerate()
+            .filter(|(_, (limit, _))| limit.matches(method))
+            .max_by_key(|(_, (limit, _))| (!limit.method.ends_with('*'), limit.method.len()))
+        else {
+            return Ok(())
+        };
+
+        let now = Instant::now();
+        let mut buckets = self.inner.buckets.lock().unwrap_or_else(|err| err.into_inner());
+        let result = buckets
+            .get_or_insert((index, client.cloned()), || TokenBucket::full(limit, now))
+            .map_or(Ok(()), |bucket| bucket.try_take(limit, now));
+        drop(buckets);
+
+        match result {
+            Ok(()) => {
+                metrics.admitted_calls.increment(1);
+                Ok(())
+            }
+            Err(retry_after) => {
+                metrics.rejected_calls.increment(1);
+                Err(ErrorObject::owned(
+                    LIMIT_EXCEEDED,
+                    format!("rate limit exceeded for {method}"),
+                    Some(RateLimitExceededData {
+                        retry_after_ms: retry_after.as_millis().max(1) as u64,
+                    }),
+                ))
+            }
+        }
+    }
+}
+
+impl<S> Layer<S> for RpcMethodRateLimiter {
+    type Service = RpcMethodRateLimitingService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcMethodRateLimitingService { inner, rate_limiter: self.clone() }
+    }
+}
+
+/// Rate limits and their metrics.
+type LimitsWithMetrics = Arc<[(RpcMethodRateLimit, RpcMethodRateLimitMetrics)]>;
+
+#[derive(Debug)]
+struct RpcMethodRateLimiterInner {
+    /// The configured limits and their metrics
+    limits: LimitsWithMetrics,
+    /// Header identifying the client of a request, if the limits are per client
+    client_key_header: Option<HeaderName>,
+    /// The limits in effect, if they are adjustable at runtime
+    active: Mutex<ActiveRateLimits>,
+    /// Buckets keyed by the index of their limit and the client key, the least recently used
+    /// evicted first
+    buckets: Mutex<LruMap<(usize, Option<RpcClientKey>), TokenBucket>>,
+}
+
+/// The rate limits in effect and the override they were created from.
+#[derive(Debug)]
+struct ActiveRateLimits {
+    limits: LimitsWithMetrics,
+    overridden: Option<Arc<[RpcMethodRateLimit]>>,
+}
+
+/// Metrics of a [`RpcMethodRateLimit`].
+#[derive(Metrics, Clone)]
+#[metrics(scope = "rpc_server.rate_limit")]
+struct RpcMethodRateLimitMetrics {
+    /// The number of calls admitted by the limit
+    admitted_calls: Counter,
+    /// The number of calls rejected because the limit was exceeded
+    rejected_calls: Counter,
+}
+
+/// Data of the error returned for rejected calls.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RateLimitExceededData {
+    /// Milliseconds until the next call is admitted.
+    retry_after_ms: u64,
+}
+
+/// Token bucket refilled at the rate of its limit, up to the burst.
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn full(limit: &RpcMethodRateLimit, now: Instant) -> Self {
+        Self { tokens: limit.burst as f64, refilled_at: now }
+    }
+
+    fn refill(&mut self, limit: &RpcMethodRateLimit, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.refilled_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * limit.per_second).min(limit.burst as f64);
+        self.refilled_at = now;
+    }
+
+    /// Takes a token, or returns the time until one is available.
+    fn try_take(&mut self, limit: &RpcMethodRateLimit, now: Instant) -> Result<(), Duration> {
+        self.refill(limit, now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return Ok(())
+        }
+        Err(Duration::from_secs_f64((1.0 - self.tokens) / limit.per_second))
+    }
+}
+
+/// A [`RpcServiceT`] middleware that rejects calls exceeding the [`RpcMethodRateLimiter`] limits.
+#[derive(Debug, Clone)]
+pub struct RpcMethodRateLimitingService<S> {
+    /// The rate limiter of the calls
+    rate_limiter: RpcMethodRateLimiter,
+    /// The inner service being wrapped
+    inner: S,
+}
+
+impl<S> RpcServiceT for RpcMethodRateLimitingService<S>
+where
+    S: RpcServiceT<MethodResponse = MethodResponse> + Send + Sync + Clone + 'static,
+{
+    type MethodResponse = S::MethodResponse;
+    type NotificationResponse = S::NotificationResponse;
+    type BatchResponse = S::BatchResponse;
+
+    fn call<'a>(&self, req: Request<'a>) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+        let call = match self
+            .rate_limiter
+            .check(req.method_name(), req.extensions().get::<RpcClientKey>())
+        {
+            Ok(()) => Ok(self.inner.call(req)),
+            Err(err) => Err(MethodResponse::error(req.id(), err)),
+        };
+        async move {
+            match call {
+                Ok(fut) => fut.await,
+                Err(response) => response,
+            }
+        }
+    }
+
+    fn batch<'a>(
+        &self,
+        mut batch: Batch<'a>,
+    ) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+        // the calls of a batch are dispatched by the inner service directly, so they're checked
+        // here and the rejected ones replaced by their error
+        for entry in batch.iter_mut() {
+            let rejected = match entry {
+                Ok(BatchEntry::Call(req)) => self
+                    .rate_limiter
+                    .check(req.method_name(), req.extensions().get::<RpcClientKey>())
+                    .err()
+                    .map(|err| BatchEntryErr::new(req.id(), err)),
+                _ => None,
+            };
+            if let Some(err) = rejected {
+                *entry = Err(err);
+            }
+        }
+        self.inner.batch(batch)
+    }
+
+    fn notification<'a>(
+        &self,
+        n: Notification<'a>,
+    ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+        self.inner.notification(n)
+    }
+}
+
+/// Limiter of the total cost of the calls in a batch.
+///
+/// Each call costs the most specific [`RpcMethodCost`] matching its method: an exact match, or else
+/// the longest matching prefix. Tracing calls cost [`DEFAULT_TRACING_CALL_COST`] and other calls 1,
+/// unless configured otherwise.
+///
+/// The calls of a batch are admitted in order until the next one would exceed the budget, that
+/// call and all following ones are rejected with a [`LIMIT_EXCEEDED`] error. The first call is
+/// always admitted, so a batch is never more limited than sending its calls separately.
+#[derive(Debug, Clone)]
+pub struct RpcBatchCostLimiter {
+    inner: Arc<RpcBatchCostLimiterInner>,
+}
+
+impl RpcBatchCostLimiter {
+    /// Creates a new limiter with the given budget and costs of the methods.
+    pub fn new(max_cost: u64, costs: impl IntoIterator<Item = RpcMethodCost>) -> Self {
+        let costs = costs
+            .into_iter()
+            .chain([
+                RpcMethodCost::new("debug_trace*", DEFAULT_TRACING_CALL_COST),
+                RpcMethodCost::new("trace_*", DEFAULT_TRACING_CALL_COST),
+            ])
+            .collect();
+        Self {
+            inner: Arc::new(RpcBatchCostLimiterInner {
+                max_cost,
+                costs,
+                metrics: Default::default(),
+            }),
+        }
+    }
+
+    /// Returns the cost of a call to the method.
+    fn cost(&self, method: &str) -> u64 {
+        // the configured costs come first, so they take precedence over the defaults for equally
+        // specific patterns
+        self.inner
+            .costs
+            .iter()
+            .enumerate()
+            .filter(|(_, cost)| cost.matches(method))
+            .max_by_key(|(index, cost)| {
+                (!cost.method.ends_with('*'), cost.method.len(), std::cmp::Reverse(*index))
+            })
+            .map_or(1, |(_, cost)| cost.cost)
+    }
+
+    /// Returns the index of the first call of the batch exceeding the budget, if any.
+    fn over_budget<'a>(&self, methods: impl IntoIterator<Item = &'a str>) -> Option<usize> {
+        let mut total = 0u64;
+        for (index, method) in methods.into_iter().enumerate() {
+            total = total.saturating_add(self.cost(method));
+            if index > 0 && total > self.inner.max_cost {
+                return Some(index)
+            }
+        }
+        None
+    }
+}
+
+impl<S> Layer<S> for RpcBatchCostLimiter {
+    type Service = RpcBatchCostLimitingService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcBatchCostLimitingService { inner, limiter: self.clone() }
+    }
+}
+
+#[derive(Debug)]
+struct RpcBatchCostLimiterInner {
+    /// Maximum total cost of the calls of a batch
+    max_cost: u64,
+    /// Costs of the methods, the configured ones followed by the defaults
+    costs: Vec<RpcMethodCost>,
+    /// Metrics of the limiter
+    metrics: RpcBatchCostMetrics,
+}
+
+/// Metrics of the [`RpcBatchCostLimiter`].
+#[derive(Metrics, Clone)]
+#[metrics(scope = "rpc_server.batch_cost")]
+struct RpcBatchCostMetrics {
+    /// The number of calls rejected because their batch exceeded the cost budget
+    rejected_calls: Counter,
+}
+
+/// A [`RpcServiceT`] middleware that rejects the calls of a batch exceeding the
+/// [`RpcBatchCostLimiter`] budget.
+#[derive(Debug, Clone)]
+pub struct RpcBatchCostLimitingService<S> {
+    /// The cost limiter of the batches
+    limiter: RpcBatchCostLimiter,
+    /// The inner service being wrapped
+    inner: S,
+}
+
+impl<S> RpcServiceT for RpcBatchCostLimitingService<S>
+where
+    S: RpcServiceT + Send + Sync + Clone + 'static,
+{
+    type MethodResponse = S::MethodResponse;
+    type NotificationResponse = S::NotificationResponse;
+    type BatchResponse = S::BatchResponse;
+
+    fn call<'a>(&self, req: Request<'a>) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+        self.inner.call(req)
+    }
+
+    fn batch<'a>(
+        &self,
+        mut batch: Batch<'a>,
+    ) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+        let over_budget = self.limiter.over_budget(batch.iter().filter_map(|entry| match entry {
+            Ok(BatchEntry::Call(req)) => Some(req.method_name()),
+            _ => None,
+        }));
+        if let Some(over_budget) = over_budget {
+            let max_cost = self.limiter.inner.max_cost;
+            for entry in batch
+                .iter_mut()
+                .filter(|entry| matches!(entry, Ok(BatchEntry::Call(_))))
+                .skip(over_budget)
+            {
+                if let Ok(BatchEntry::Call(req)) = entry {
+                    let err = ErrorObject::owned(
+                        LIMIT_EXCEEDED,
+                        format!("batch exceeds max cost {max_cost}"),
+                        None::<()>,
+                    );
+                    *entry = Err(BatchEntryErr::new(req.id(), err));
+                    self.limiter.inner.metrics.rejected_calls.increment(1);
+                }
+            }
+        }
+        self.inner.batch(batch)
+    }
+
+    fn notification<'a>(
+        &self,
+        n: Notification<'a>,
+    ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+        self.inner.notification(n)
+    }
+}
+
+/// Key identifying the client of an RPC call, see [`RpcMethodRateLimiter::new`].
+///
+/// Inserted into the extensions of the HTTP requests by [`RpcClientKeyLayer`], which are inherited
+/// by the calls of the request, or of the WS connection.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RpcClientKey(pub String);
+
+/// HTTP layer extracting the [`RpcClientKey`] of the requests from a header.
+///
+/// If the header holds a comma separated list, as `x-forwarded-for` does, the first entry is used.
+#[derive(Debug, Clone)]
+pub struct RpcClientKeyLayer {
+    header: HeaderName,
+}
+
+impl RpcClientKeyLayer {
+    /// Creates a new layer reading the client key from the given header.
+    pub const fn new(header: HeaderName) -> Self {
+        Self { header }
+    }
+}
+
+impl<S> Layer<S> for RpcClientKeyLayer {
+    type Service = RpcClientKeyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcClientKeyService { inner, header: self.header.clone() }
+    }
+}
+
+/// HTTP service inserting the [`RpcClientKey`] into the request extensions.
+#[derive(Debug, Clone)]
+pub struct RpcClientKeyService<S> {
+    inner: S,
+    header: HeaderName,
+}
+
+impl<S, B> Service<http::Request<B>> for RpcClientKeyService<S>
+where
+    S: Service<http::Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: http::Request<B>) -> Self::Future {
+        let key = req
+            .headers()
+            .get(&self.header)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(',').next())
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+            .map(|key| RpcClientKey(key.to_string()));
+        if let Some(key) = key {
+            req.extensions_mut().insert(key);
+        }
+        self.inner.call(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn method_rate_limit() {
+        let limiter = RpcMethodRateLimiter::new(
+            ["debug_*=1/2".parse().unwrap(), "debug_traceBlockByNumber=1/1".parse().unwrap()],
+            None,
+        );
+
+        // the exact limit takes precedence over the prefix
+        assert!(limiter.check("debug_traceBlockByNumber", None).is_ok());
+        let err = limiter.check("debug_traceBlockByNumber", None).unwrap_err();
+        assert_eq!(err.code(), LIMIT_EXCEEDED);
+
+        assert!(limiter.check("debug_traceBlockByHash", None).is_ok());
+        assert!(limiter.check("debug_traceTransaction", None).is_ok());
+        assert!(limiter.check("debug_traceCall", None).is_err());
+
+        // clients have separate buckets
+        let client = RpcClientKey("client".to_string());
+        assert!(limiter.check("debug_traceCall", Some(&client)).is_ok());
+
+        // unlimited methods
+        for _ in 0..10 {
+            assert!(limiter.check("eth_call", None).is_ok());
+        }
+    }
+
+    #[test]
+    fn override_rate_limits() {
+        let server_limits = RpcServerLimits::default();
+        let limiter = RpcMethodRateLimiter::new(["debug_*=1/1".parse().unwrap()], None)
+            .with_server_limits(server_limits.clone());
+        assert!(limiter.check("debug_traceCall", None).is_ok());
+        assert!(limiter.check("debug_traceCall", None).is_err());
+        assert!(limiter.check("eth_call", None).is_ok());
+
+        // the override replaces the configured limits and their buckets
+        server_limits.set_rate_limits(vec!["eth_call=1/1".parse().unwrap()]);
+        assert!(limiter.check("debug_traceCall", None).is_ok());
+        assert!(limiter.check("debug_traceCall", None).is_ok());
+        assert!(limiter.check("eth_call", None).is_ok());
+        assert!(limiter.check("eth_call", None).is_err());
+    }
+
+    #[test]
+    fn batch_cost() {
+        let limiter = RpcBatchCostLimiter::new(
+            20,
+            ["eth_call=2".parse().unwrap(), "debug_traceCall=5".parse().unwrap()],
+        );
+        assert_eq!(limiter.cost("eth_blockNumber"), 1);
+        assert_eq!(limiter.cost("eth_call"), 2);
+        assert_eq!(limiter.cost("debug_traceCall"), 5);
+        assert_eq!(limiter.cost("debug_traceTransaction"), DEFAULT_TRACING_CALL_COST);
+        assert_eq!(limiter.cost("trace_block"), DEFAULT_TRACING_CALL_COST);
+
+        assert_eq!(limiter.over_budget(["eth_call"; 10]), None);
+        assert_eq!(limiter.over_budget(["eth_call"; 11]), Some(10));
+        assert_eq!(limiter.over_budget(["debug_traceTransaction"; 3]), Some(2));
+        // the first call is always admitted
+        let limiter = RpcBatchCostLimiter::new(5, []);
+        assert_eq!(limiter.over_budget(["debug_traceTransaction"]), None);
+        assert_eq!(limiter.over_budget(["debug_traceTransaction", "eth_chainId"]), Some(1));
+    }
+
+    #[test]
+    fn token_bucket_refill() {
+        let limit: RpcMethodRateLimit = "eth_call=10/2".parse().unwrap();
+        let now = Instant::now();
+        let mut bucket = TokenBucket::full(&limit, now);
+        assert!(bucket.try_take(&limit, now).is_ok());
+        assert!(bucket.try_take(&limit, now).is_ok());
+        assert_eq!(bucket.try_take(&limit, now), Err(Duration::from_millis(100)));
+        assert!(bucket.try_take(&limit, now + Duration::from_millis(100)).is_ok());
+        bucket.refill(&limit, now + Duration::from_secs(1));
+        assert_eq!(bucket.tokens, 2.0);
+    }
+
+    #[test]
+    fn evict_least_recently_used_bucket() {
+        let limiter = RpcMethodRateLimiter::new(["debug_*=1/1".parse().unwrap()], None);
+        let first = RpcClientKey("first".to_string());
+        assert!(limiter.check("debug_traceCall", Some(&first)).is_ok());
+        assert!(limiter.check("debug_traceCall", Some(&first)).is_err());
+
+        for client in 0..MAX_TOKEN_BUCKETS {
+            let client = RpcClientKey(client.to_string());
+            assert!(limiter.check("debug_traceCall", Some(&client)).is_ok());
+        }
+        let buckets = limiter.inner.buckets.lock().unwrap();
+        assert_eq!(buckets.len(), MAX_TOKEN_BUCKETS as usize);
+        assert!(buckets.peek(&(0, Some(first))).is_none());
+    }
+}
@@ -155,6 +155,9 @@ where
     AdminApiClient::remove_trusted_peer(client, node.into()).await.unwrap();
     AdminApiClient::ban_peer(client, node.into()).await.unwrap();
     AdminApiClient::unban_peer(client, node.into()).await.unwrap();
+    AdminApiClient::peer_reputation(client, node.into()).await.unwrap();
+    AdminApiClient::change_peer_reputation(client, node.into(), -1024).await.unwrap();
+    AdminApiClient::reset_peer_reputation(client, node.into()).await.unwrap();
     AdminApiClient::node_info(client).await.unwrap();
 }
 
@@ -5,6 +5,7 @@ use alloy_eips::{
     eip1898::BlockHashOrNumber,
     eip4844::{BlobAndProofV1, BlobAndProofV2, BlobCellsAndProofsV1},
     eip4895::Withdrawals,
+    eip7594::BlobTransactionSidecarVariant,
     eip7685::RequestsOrHash,
 };
 use alloy_primitives::{BlockHash, BlockNumber, Bytes, Sealable, B128, B256, U64};
@@ -27,7 +28,9 @@ use reth_payload_primitives::{
 };
 use reth_primitives_traits::{Block, BlockBody};
 use reth_rpc_api::{EngineApiServer, IntoEngineApiRpcModule};
-use reth_storage_api::{BalProvider, BlockReader, HeaderProvider, StateProviderFactory};
+use reth_storage_api::{
+    BalProvider, BlobSidecarProvider, BlockReader, HeaderProvider, StateProviderFactory,
+};
 use reth_tasks::Runtime;
 use reth_transaction_pool::TransactionPool;
 use std::{
@@ -82,7 +85,12 @@ impl<Provider, PayloadT: PayloadTypes, Pool, Validator, ChainSpec>
 impl<Provider, PayloadT, Pool, Validator, ChainSpec>
     EngineApi<Provider, PayloadT, Pool, Validator, ChainSpec>
 where
-    Provider: HeaderProvider + BlockReader + StateProviderFactory + BalProvider + 'static,
+    Provider: HeaderProvider
+        + BlockReader
+        + StateProviderFactory
+        + BalProvider
+        + BlobSidecarProvider
+        + 'static,
     PayloadT: PayloadTypes,
     Pool: TransactionPool + 'static,
     Validator: EngineApiValidator<PayloadT>,
@@ -301,7 +309,12 @@ where
 impl<Provider, EngineT, Pool, Validator, ChainSpec>
     EngineApi<Provider, EngineT, Pool, Validator, ChainSpec>
 where
-    Provider: HeaderProvider + BlockReader + StateProviderFactory + BalProvider + 'static,
+    Provider: HeaderProvider
+        + BlockReader
+        + StateProviderFactory
+        + BalProvider
+        + BlobSidecarProvider
+        + 'static,
     EngineT: EngineTypes,
     Pool: TransactionPool + 'static,
     Validator: EngineApiValidator<EngineT>,
@@ -968,10 +981,63 @@ where
             return Err(EngineApiError::BlobRequestTooLarge { len: versioned_hashes.len() })
         }
 
-        self.inner
+        let mut blobs = self
+            .inner
             .tx_pool
             .get_blobs_for_versioned_hashes_v1(&versioned_hashes)
-            .map_err(|err| EngineApiError::Internal(Box::new(err)))
+            .map_err(|err| EngineApiError::Internal(Box::new(err)))?;
+        self.fill_persisted_blobs(&versioned_hashes, &mut blobs, |sidecar, versioned_hashes| {
+            sidecar
+                .as_eip4844()
+                .map(|sidecar| sidecar.match_versioned_hashes(versioned_hashes).collect())
+                .unwrap_or_default()
+        })?;
+        Ok(blobs)
+    }
+
+    /// Fills the blobs missing from the transaction pool with the blob sidecars persisted for
+    /// canonical blocks, which are kept for longer than the blobs in the pool.
+    fn fill_persisted_blobs<T>(
+        &self,
+        versioned_hashes: &[B256],
+        blobs: &mut [Option<T>],
+        match_blobs: impl Fn(&BlobTransactionSidecarVariant, &[B256]) -> Vec<(usize, T)>,
+    ) -> EngineApiResult<()> {
+        for (idx, versioned_hash) in versioned_hashes.iter().enumerate() {
+            if blobs[idx].is_some() {
+                continue
+            }
+
+            let Some(sidecar) = self
+                .inner
+                .provider
+                .blob_sidecar_by_versioned_hash(*versioned_hash)
+                .map_err(|err| EngineApiError::Internal(Box::new(err)))?
+            else {
+                continue
+            };
+
+            // A sidecar may hold several of the requested blobs.
+            for (idx, blob) in match_blobs(&sidecar, versioned_hashes) {
+                blobs[idx].get_or_insert(blob);
+            }
+        }
+        Ok(())
+    }
+
+    /// Fills the EIP-7594 blobs missing from the transaction pool with the persisted blob
+    /// sidecars, see [`Self::fill_persisted_blobs`].
+    fn fill_persisted_blobs_v2(
+        &self,
+        versioned_hashes: &[B256],
+        blobs: &mut [Option<BlobAndProofV2>],
+    ) -> EngineApiResult<()> {
+        self.fill_persisted_blobs(versioned_hashes, blobs, |sidecar, versioned_hashes| {
+            sidecar
+                .as_eip7594()
+                .map(|sidecar| sidecar.match_versioned_hashes(versioned_hashes).collect())
+                .unwrap_or_default()
+        })
     }
 
     /// Metered version of `get_blobs_v1`.
@@ -1012,10 +1078,24 @@ where
             return Err(EngineApiError::BlobRequestTooLarge { len: versioned_hashes.len() })
         }
 
-        self.inner
+        if let Some(blobs) = self
+            .inner
             .tx_pool
             .get_blobs_for_versioned_hashes_v2(&versioned_hashes)
-            .map_err(|err| EngineApiError::Internal(Box::new(err)))
+            .map_err(|err| EngineApiError::Internal(Box::new(err)))?
+        {
+            return Ok(Some(blobs))
+        }
+
+        // Blobs are only returned if all of them are available, so look up the ones that already
+        // left the pool.
+        let mut blobs = self
+            .inner
+            .tx_pool
+            .get_blobs_for_versioned_hashes_v3(&versioned_hashes)
+            .map_err(|err| EngineApiError::Internal(Box::new(err)))?;
+        self.fill_persisted_blobs_v2(&versioned_hashes, &mut blobs)?;
+        Ok(blobs.into_iter().collect())
     }
 
     fn get_blobs_v3(
@@ -1040,11 +1120,13 @@ where
             return Ok(None)
         }
 
-        self.inner
+        let mut blobs = self
+            .inner
             .tx_pool
             .get_blobs_for_versioned_hashes_v3(&versioned_hashes)
-            .map(Some)
-            .map_err(|err| EngineApiError::Internal(Box::new(err)))
+            .map_err(|err| EngineApiError::Internal(Box::new(err)))?;
+        self.fill_persisted_blobs_v2(&versioned_hashes, &mut blobs)?;
+        Ok(Some(blobs))
     }
 
     fn get_blobs_v4(
@@ -1161,7 +1243,12 @@ where
 impl<Provider, EngineT, Pool, Validator, ChainSpec> EngineApiServer<EngineT>
     for EngineApi<Provider, EngineT, Pool, Validator, ChainSpec>
 where
-    Provider: HeaderProvider + BlockReader + StateProviderFactory + BalProvider + 'static,
+    Provider: HeaderProvider
+        + BlockReader
+        + StateProviderFactory
+        + BalProvider
+        + BlobSidecarProvider
+        + 'static,
     EngineT: EngineTypes<ExecutionData = ExecutionData>,
     Pool: TransactionPool + 'static,
     Validator: EngineApiValidator<EngineT>,
@@ -39,6 +39,14 @@ pub trait EthFilterApi<T: RpcObject> {
     /// Returns logs matching given filter object.
     #[method(name = "getLogs")]
     async fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>>;
+
+    /// Streams the logs matching given filter object, like `eth_getLogs`, as notifications of
+    /// the subscription, which is closed after the last log.
+    ///
+    /// The block range is queried in chunks, so the logs aren't subject to the maximum number of
+    /// logs per response.
+    #[subscription(name = "subscribeGetLogs", unsubscribe = "unsubscribeGetLogs", item = Log)]
+    async fn subscribe_logs(&self, filter: Filter) -> jsonrpsee::core::SubscriptionResult;
 }
 
 /// Limits for logs queries
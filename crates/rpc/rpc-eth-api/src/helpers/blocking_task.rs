@@ -2,7 +2,7 @@
 //! are executed on the `tokio` runtime.
 
 use futures::Future;
-use reth_rpc_eth_types::EthApiError;
+use reth_rpc_eth_types::{EthApiError, TracingCancellation};
 use reth_tasks::{
     pool::{BlockingTaskGuard, BlockingTaskPool},
     Runtime,
@@ -154,6 +154,9 @@ pub trait SpawnBlocking: EthApiTypes + Clone + Send + Sync + 'static {
 
     /// Executes the future on a new blocking task.
     ///
+    /// The [`TracingCancellation`] of the calling task, if any, is carried over to the blocking
+    /// task.
+    ///
     /// Note: This is expected for futures that are dominated by blocking IO operations, for tracing
     /// or CPU bound operations in general use [`spawn_tracing`](Self::spawn_tracing).
     fn spawn_blocking_io<F, R>(&self, f: F) -> impl Future<Output = Result<R, Self::Error>> + Send
@@ -163,8 +166,9 @@ pub trait SpawnBlocking: EthApiTypes + Clone + Send + Sync + 'static {
     {
         let (tx, rx) = oneshot::channel();
         let this = self.clone();
+        let cancellation = TracingCancellation::current().unwrap_or_default();
         self.io_task_spawner().spawn_blocking_task(async move {
-            let res = f(this);
+            let res = cancellation.enter(|| f(this));
             let _ = tx.send(res);
         });
 
@@ -173,6 +177,9 @@ pub trait SpawnBlocking: EthApiTypes + Clone + Send + Sync + 'static {
 
     /// Executes the future on a new blocking task.
     ///
+    /// The [`TracingCancellation`] of the calling task, if any, is carried over to the blocking
+    /// task.
+    ///
     /// Note: This is expected for futures that are dominated by blocking IO operations, for tracing
     /// or CPU bound operations in general use [`spawn_tracing`](Self::spawn_tracing).
     fn spawn_blocking_io_fut<F, R, Fut>(
@@ -186,8 +193,9 @@ pub trait SpawnBlocking: EthApiTypes + Clone + Send + Sync + 'static {
     {
         let (tx, rx) = oneshot::channel();
         let this = self.clone();
+        let cancellation = TracingCancellation::current().unwrap_or_default();
         self.io_task_spawner().spawn_blocking_task(async move {
-            let res = f(this).await;
+            let res = cancellation.instrument(f(this)).await;
             let _ = tx.send(res);
         });
 
@@ -196,6 +204,9 @@ pub trait SpawnBlocking: EthApiTypes + Clone + Send + Sync + 'static {
 
     /// Executes a blocking task on the tracing pool.
     ///
+    /// The [`TracingCancellation`] of the calling task, if any, is carried over to the blocking
+    /// task.
+    ///
     /// Note: This is expected for futures that are predominantly CPU bound, as it uses `rayon`
     /// under the hood, for blocking IO futures use
     /// [`spawn_blocking_task`](Self::spawn_blocking_io). See <https://ryhl.io/blog/async-what-is-blocking/>.
@@ -205,7 +216,8 @@ pub trait SpawnBlocking: EthApiTypes + Clone + Send + Sync + 'static {
         R: Send + 'static,
     {
         let this = self.clone();
-        let fut = self.tracing_task_pool().spawn(move || f(this));
+        let cancellation = TracingCancellation::current().unwrap_or_default();
+        let fut = self.tracing_task_pool().spawn(move || cancellation.enter(|| f(this)));
         async move { fut.await.map_err(|_| EthApiError::InternalBlockingTaskError)? }
     }
 }
@@ -8,7 +8,7 @@ use crate::{
     helpers::estimate::EstimateCall, FromEvmError, FullEthApiTypes, RpcBlock, RpcNodeCore,
 };
 use alloy_consensus::{transaction::TxHashRef, BlockHeader};
-use alloy_eips::eip2930::AccessListResult;
+use alloy_eips::{eip2930::AccessListResult, BlockNumHash};
 use alloy_evm::overrides::{apply_block_overrides, apply_state_overrides, OverrideBlockHashes};
 use alloy_network::TransactionBuilder;
 use alloy_primitives::{Bytes, B256, U256};
@@ -36,9 +36,10 @@ use reth_rpc_eth_types::{
     cache::db::StateProviderTraitObjWrapper,
     error::{AsEthApiError, FromEthApiError},
     simulate::{self, EthSimulateError},
-    EthApiError, StateCacheDb,
+    state_override::override_state,
+    CancellableInspector, EthApiError, EthCallCache, StateCacheDb, TracingCancellation,
 };
-use reth_storage_api::{BlockIdReader, ProviderTx, StateProviderBox};
+use reth_storage_api::{BlockIdReader, BlockNumReader, ProviderTx, StateProviderBox};
 use revm::{
     context::Block,
     context_interface::{result::ResultAndState, Transaction},
@@ -54,6 +55,28 @@ pub type SimulatedBlocksResult<N, E> = Result<Vec<SimulatedBlock<RpcBlock<N>>>,
 /// Execution related functions for the [`EthApiServer`](crate::EthApiServer) trait in
 /// the `eth_` namespace.
 pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthApiTypes {
+    /// Returns the cache of `eth_call` and `eth_estimateGas` results at the latest block.
+    ///
+    /// Results are not cached if `None`, which is the default.
+    fn call_cache(&self) -> Option<&EthCallCache> {
+        None
+    }
+
+    /// Returns the call cache and the latest block, if the results of calls at the [`BlockId`]
+    /// with the overrides are cached.
+    fn call_cache_at(
+        &self,
+        at: BlockId,
+        overrides: &EvmOverrides,
+    ) -> Result<Option<(&EthCallCache, BlockNumHash)>, Self::Error> {
+        let Some(cache) = self.call_cache() else { return Ok(None) };
+        if !at.is_latest() || overrides.state.is_some() || overrides.block.is_some() {
+            return Ok(None)
+        }
+        let info = self.provider().chain_info().map_err(Self::Error::from_eth_err)?;
+        Ok(Some((cache, BlockNumHash::new(info.best_number, info.best_hash))))
+    }
+
     /// Estimate gas needed for execution of the `request` at the [`BlockId`].
     fn estimate_gas_at(
         &self,
@@ -61,7 +84,23 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
         at: BlockId,
         overrides: EvmOverrides,
     ) -> impl Future<Output = Result<U256, Self::Error>> + Send {
-        EstimateCall::estimate_gas_at(self, request, at, overrides)
+        async move {
+            let Some((cache, block)) = self.call_cache_at(at, &overrides)? else {
+                return EstimateCall::estimate_gas_at(self, request, at, overrides).await
+            };
+            let key = EthCallCache::estimate_key(&request);
+            if let Some(gas) = key.and_then(|key| cache.get_estimate(block, key)) {
+                return Ok(gas)
+            }
+
+            // the estimate is made at the block it's cached for
+            let gas =
+                EstimateCall::estimate_gas_at(self, request, block.hash.into(), overrides).await?;
+            if let Some(key) = key {
+                cache.insert_estimate(block, key, gas);
+            }
+            Ok(gas)
+        }
     }
 
     /// `eth_simulateV1` executes an arbitrary number of transactions on top of the requested state.
@@ -101,11 +140,6 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
             let max_simulate_blocks = self.max_simulate_blocks();
 
             self.spawn_with_state_at_block(block, move |this, db| {
-                let state_provider = db.database.0 .0;
-                let mut db = State::builder()
-                    .with_database(StateProviderDatabase::new(&state_provider))
-                    .with_bundle_update()
-                    .build();
                 let mut parent = parent;
 
                 let chain_id = this.provider().chain_spec().chain_id();
@@ -121,13 +155,24 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
                     max_simulate_blocks,
                 )?;
 
+                // The state overrides of the first block are applied to the state provider, so
+                // they are included in the state root. Later blocks run on top of the changes of
+                // the previous blocks, their overrides are applied to the database.
+                let state_provider =
+                    override_state(db.database.0 .0, block_state_calls[0].state_overrides.clone())
+                        .map_err(Self::Error::from_eth_err)?;
+                let mut db = State::builder()
+                    .with_database(StateProviderDatabase::new(&state_provider))
+                    .with_bundle_update()
+                    .build();
+
                 let mut blocks: Vec<SimulatedBlock<RpcBlock<Self::NetworkTypes>>> =
                     Vec::with_capacity(block_state_calls.len());
 
                 let call_gas_limit = this.call_gas_limit();
                 let mut remaining_call_gas_limit = (call_gas_limit > 0).then_some(call_gas_limit);
 
-                for block in block_state_calls {
+                for (index, block) in block_state_calls.into_iter().enumerate() {
                     let SimBlock { block_overrides, state_overrides, calls } = block;
 
                     let attributes = this
@@ -178,7 +223,9 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
                             evm_env.block_env.inner_mut(),
                         );
                     }
-                    if let Some(ref state_overrides) = state_overrides {
+                    if index > 0 &&
+                        let Some(ref state_overrides) = state_overrides
+                    {
                         apply_state_overrides(state_overrides.clone(), &mut db)
                             .map_err(Self::Error::from_eth_err)?;
                     }
@@ -279,11 +326,25 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
         overrides: EvmOverrides,
     ) -> impl Future<Output = Result<Bytes, Self::Error>> + Send {
         async move {
+            let at = block_number.unwrap_or_default();
+            let cached = self.call_cache_at(at, &overrides)?;
+            let key = cached.and_then(|_| EthCallCache::call_key(&request));
+            if let Some(((cache, block), key)) = cached.zip(key) &&
+                let Some(output) = cache.get_call(block, key)
+            {
+                return Ok(output)
+            }
+
             let _permit = self.acquire_owned_blocking_io().await;
-            let res =
-                self.transact_call_at(request, block_number.unwrap_or_default(), overrides).await?;
+            // the call is executed at the block it's cached for
+            let at = cached.map_or(at, |(_, block)| block.hash.into());
+            let res = self.transact_call_at(request, at, overrides).await?;
 
-            Self::Error::ensure_success(res.result)
+            let output = Self::Error::ensure_success(res.result)?;
+            if let Some(((cache, block), key)) = cached.zip(key) {
+                cache.insert_call(block, key, output.clone());
+            }
+            Ok(output)
         }
     }
 
@@ -592,6 +653,8 @@ pub trait Call:
 
     /// Executes the [`reth_evm::EvmEnv`] against the given [Database] without committing state
     /// changes.
+    ///
+    /// The execution is aborted once the current [`TracingCancellation`] is cancelled.
     fn transact_with_inspector<DB, I>(
         &self,
         db: DB,
@@ -603,8 +666,11 @@ pub trait Call:
         DB: Database<Error = EvmDatabaseError<ProviderError>> + fmt::Debug,
         I: InspectorFor<Self::Evm, DB>,
     {
+        let inspector = CancellableInspector::new(inspector);
         let mut evm = self.evm_config().evm_with_env_and_inspector(db, evm_env, inspector);
         let res = evm.transact(tx_env).map_err(Self::Error::from_evm_err)?;
+        // the execution is halted if the call was cancelled
+        TracingCancellation::check_current().map_err(Self::Error::from_eth_err)?;
 
         Ok(res)
     }
@@ -698,9 +764,20 @@ pub trait Call:
     {
         async move {
             let (evm_env, at) = self.evm_env_at(at).await?;
-            self.spawn_with_state_at_block(at, move |this, mut db| {
-                let (evm_env, tx_env) =
-                    this.prepare_call_env(evm_env, request, &mut db, overrides)?;
+            self.spawn_blocking_io_fut(async move |this| {
+                let EvmOverrides { state, block } = overrides;
+                let state = override_state(this.state_at_block_id(at).await?, state)
+                    .map_err(Self::Error::from_eth_err)?;
+                let mut db = State::builder()
+                    .with_database(StateProviderDatabase::new(StateProviderTraitObjWrapper(state)))
+                    .build();
+
+                let (evm_env, tx_env) = this.prepare_call_env(
+                    evm_env,
+                    request,
+                    &mut db,
+                    EvmOverrides::new(None, block),
+                )?;
 
                 f(&mut db, evm_env, tx_env)
             })
@@ -797,6 +874,7 @@ pub trait Call:
                 // reached the target transaction
                 break
             }
+            TracingCancellation::check_current().map_err(Self::Error::from_eth_err)?;
 
             let tx_env = self.evm_config().tx_env(tx);
             evm.transact_commit(tx_env).map_err(Self::Error::from_evm_err)?;
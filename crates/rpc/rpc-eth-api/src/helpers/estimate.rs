@@ -23,6 +23,7 @@ use reth_rpc_eth_types::{
         api::{FromEvmHalt, FromRevert},
         FromEvmError,
     },
+    state_override::override_state,
     EthApiError, RpcInvalidTransactionError,
 };
 use reth_rpc_server_types::constants::gas_oracle::{CALL_STIPEND_GAS, ESTIMATE_GAS_ERROR_RATIO};
@@ -317,8 +318,16 @@ pub trait EstimateCall: Call {
             let (evm_env, at) = self.evm_env_at(at).await?;
 
             self.spawn_blocking_io_fut(async move |this| {
-                let state = this.state_at_block_id(at).await?;
-                EstimateCall::estimate_gas_with(&this, evm_env, request, state, overrides)
+                let EvmOverrides { state, block } = overrides;
+                let state = override_state(this.state_at_block_id(at).await?, state)
+                    .map_err(Self::Error::from_eth_err)?;
+                EstimateCall::estimate_gas_with(
+                    &this,
+                    evm_env,
+                    request,
+                    state,
+                    EvmOverrides::new(None, block),
+                )
             })
             .await
         }
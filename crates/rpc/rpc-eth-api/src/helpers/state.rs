@@ -12,23 +12,46 @@ use futures::Future;
 use reth_errors::RethError;
 use reth_evm::{ConfigureEvm, EvmEnvFor};
 use reth_primitives_traits::{BlockTy, RecoveredBlock, SealedHeaderFor};
+use reth_prune_types::PruneSegment;
 use reth_rpc_convert::{RpcConvert, RpcTxReq};
 use reth_rpc_eth_types::{
     error::{FromEvmError, IntoEthApiError},
-    EthApiError, PendingBlockEnv, RpcInvalidTransactionError, SignError,
+    replay::PrunedStateReplay,
+    EthApiError, EthProofMetrics, PendingBlockEnv, RpcInvalidTransactionError, SignError,
+};
+use reth_rpc_server_types::constants::{
+    DEFAULT_MAX_STORAGE_VALUES_SLOTS, DEFAULT_PROOF_CONCURRENCY,
 };
-use reth_rpc_server_types::constants::DEFAULT_MAX_STORAGE_VALUES_SLOTS;
 use reth_storage_api::{
-    BlockIdReader, BlockReaderIdExt, StateProvider, StateProviderBox, StateProviderFactory,
+    BlockIdReader, BlockReaderIdExt, PruneCheckpointReader, StateProvider, StateProviderBox,
+    StateProviderFactory,
 };
 use reth_transaction_pool::TransactionPool;
-use std::{collections::HashMap, sync::Arc};
+use reth_trie_common::TrieInput;
+use std::{collections::HashMap, sync::Arc, time::Instant};
+use tracing::debug;
+
+/// Minimum number of storage slots proven by each task of a [`EthState::get_proof`] call.
+///
+/// Smaller requests are proven in a single task, since each task walks the account trie again.
+const MIN_PROOF_SLOTS_PER_TASK: usize = 16;
 
 /// Helper methods for `eth_` methods relating to state (accounts).
 pub trait EthState: LoadState + SpawnBlocking {
     /// Returns the maximum number of blocks into the past for generating state proofs.
     fn max_proof_window(&self) -> u64;
 
+    /// Returns the maximum number of tasks a single [`Self::get_proof`] call computes its storage
+    /// proofs with.
+    ///
+    /// Defaults to [`DEFAULT_PROOF_CONCURRENCY`].
+    fn proof_concurrency(&self) -> usize {
+        DEFAULT_PROOF_CONCURRENCY
+    }
+
+    /// Returns the metrics of [`Self::get_proof`] calls.
+    fn proof_metrics(&self) -> &EthProofMetrics;
+
     /// Validates that the given block is within the configured proof window.
     ///
     /// Returns an error if the distance between the chain tip and the requested block exceeds
@@ -165,28 +188,96 @@ pub trait EthState: LoadState + SpawnBlocking {
         Self: EthApiSpec,
     {
         Ok(async move {
+            let metrics = self.proof_metrics();
+            let started_at = Instant::now();
             let _permit = self
                 .acquire_owned_tracing()
                 .await
                 .map_err(RethError::other)
                 .map_err(EthApiError::Internal)?;
+            metrics.permit_wait_duration.record(started_at.elapsed());
 
             let block_id = block_id.unwrap_or_default();
             self.ensure_within_proof_window(block_id)?;
 
-            self.spawn_blocking_io_fut(async move |this| {
-                let state = this.state_at_block_id(block_id).await?;
-                let storage_keys = keys.iter().map(|key| key.as_b256()).collect::<Vec<_>>();
-                let proof = state
-                    .proof(Default::default(), address, &storage_keys)
-                    .map_err(Self::Error::from_eth_err)?;
-                Ok(proof.into_eip1186_response(keys))
-            })
-            .await
+            let storage_keys = keys.iter().map(|key| key.as_b256()).collect::<Vec<_>>();
+            let tasks = self
+                .proof_concurrency()
+                .min(storage_keys.len().div_ceil(MIN_PROOF_SLOTS_PER_TASK))
+                .max(1);
+            metrics.storage_slots.record(storage_keys.len() as f64);
+
+            // Resolve the block first, so that all tasks prove the same state. The pending state
+            // only exists in memory and is proven in a single task.
+            let block_hash = if tasks > 1 && !block_id.is_pending() {
+                self.provider().block_hash_for_id(block_id).map_err(Self::Error::from_eth_err)?
+            } else {
+                None
+            };
+            let proving_started_at = Instant::now();
+            let Some(block_hash) = block_hash else {
+                metrics.tasks.record(1.0);
+                let response = self
+                    .spawn_blocking_io_fut(async move |this| {
+                        let state = this.state_at_block_id(block_id).await?;
+                        let proof = state
+                            .proof(Default::default(), address, &storage_keys)
+                            .map_err(Self::Error::from_eth_err)?;
+                        Ok(proof.into_eip1186_response(keys))
+                    })
+                    .await?;
+                let elapsed = proving_started_at.elapsed();
+                metrics.task_duration.record(elapsed);
+                metrics.proof_duration.record(elapsed);
+                return Ok(response)
+            };
+
+            // Compute the input of the state, e.g. the reverts of historical state, once. Every
+            // task opens its own read-only snapshot of the base state and proves the input on
+            // top of it.
+            let (base, input) = self
+                .spawn_blocking_io_fut(async move |this| {
+                    let state = this.state_at_block_id(block_hash.into()).await?;
+                    let input = state.proof_input().map_err(Self::Error::from_eth_err)?;
+                    Ok(input.unwrap_or_else(|| (block_hash, TrieInput::default())))
+                })
+                .await?;
+            let input = Arc::new(input);
+
+            let chunks = storage_keys.chunks(storage_keys.len().div_ceil(tasks));
+            metrics.tasks.record(chunks.len() as f64);
+            let proofs = futures::future::try_join_all(chunks.map(|slots| {
+                let slots = slots.to_vec();
+                let input = input.clone();
+                let task_duration = metrics.task_duration.clone();
+                self.spawn_blocking_io_fut(async move |this| {
+                    let task_started_at = Instant::now();
+                    let state = this.state_at_block_id(base.into()).await?;
+                    let proof = state
+                        .proof(TrieInput::clone(&input), address, &slots)
+                        .map_err(Self::Error::from_eth_err)?;
+                    task_duration.record(task_started_at.elapsed());
+                    Ok(proof)
+                })
+            }))
+            .await?;
+            metrics.proof_duration.record(proving_started_at.elapsed());
+
+            // The account proof is the same for all tasks, only the storage proofs differ.
+            let mut proofs = proofs.into_iter();
+            let mut proof = proofs.next().expect("at least one task");
+            for chunk in proofs {
+                proof.storage_proofs.extend(chunk.storage_proofs);
+            }
+            Ok(proof.into_eip1186_response(keys))
         })
     }
 
     /// Returns the account at the given address for the provided block identifier.
+    ///
+    /// The storage root is computed from the storage trie instead of a proof. Computing it for
+    /// historical state reverts the storage changes since the block, so blocks outside of the proof
+    /// window are rejected, unless the account doesn't exist.
     fn get_account(
         &self,
         address: Address,
@@ -195,28 +286,25 @@ pub trait EthState: LoadState + SpawnBlocking {
     where
         Self: EthApiSpec,
     {
-        async move {
-            self.ensure_within_proof_window(block_id)?;
+        self.spawn_blocking_io_fut(async move |this| {
+            let state = this.state_at_block_id(block_id).await?;
+            let account = state.basic_account(&address).map_err(Self::Error::from_eth_err)?;
+            let Some(account) = account else { return Ok(None) };
 
-            self.spawn_blocking_io_fut(async move |this| {
-                let state = this.state_at_block_id(block_id).await?;
-                let account = state.basic_account(&address).map_err(Self::Error::from_eth_err)?;
-                let Some(account) = account else { return Ok(None) };
+            this.ensure_within_proof_window(block_id)?;
 
-                let balance = account.balance;
-                let nonce = account.nonce;
-                let code_hash = account.bytecode_hash.unwrap_or(KECCAK_EMPTY);
+            let balance = account.balance;
+            let nonce = account.nonce;
+            let code_hash = account.bytecode_hash.unwrap_or(KECCAK_EMPTY);
 
-                // Provide a default `HashedStorage` value in order to
-                // get the storage root hash of the current state.
-                let storage_root = state
-                    .storage_root(address, Default::default())
-                    .map_err(Self::Error::from_eth_err)?;
+            // Provide a default `HashedStorage` value in order to
+            // get the storage root hash of the current state.
+            let storage_root = state
+                .storage_root(address, Default::default())
+                .map_err(Self::Error::from_eth_err)?;
 
-                Ok(Some(Account { balance, nonce, code_hash, storage_root }))
-            })
-            .await
-        }
+            Ok(Some(Account { balance, nonce, code_hash, storage_root }))
+        })
     }
 
     /// Retrieves the account's balance, nonce, and code for a given address.
@@ -282,10 +370,80 @@ pub trait LoadState:
                 return Ok(state)
             }
 
+            if let Some(state) = self.replay_pruned_state(at).await? {
+                return Ok(state)
+            }
+
             self.provider().state_by_block_id(at).map_err(Self::Error::from_eth_err)
         }
     }
 
+    /// Returns the reconstruction of historical state below the history prune horizon, if
+    /// enabled.
+    ///
+    /// Pruned historical state is unavailable if `None`, which is the default.
+    fn pruned_state_replay(&self) -> Option<&PrunedStateReplay> {
+        None
+    }
+
+    /// Reconstructs the state at the given [`BlockId`] with [`Self::pruned_state_replay`], if its
+    /// history was pruned.
+    ///
+    /// Returns `None` if the history of the block is available. Fails with
+    /// [`ProviderError::StateAtBlockPruned`](reth_errors::ProviderError::StateAtBlockPruned) if it
+    /// was pruned and more blocks than the replay limit would have to be replayed.
+    fn replay_pruned_state(
+        &self,
+        at: BlockId,
+    ) -> impl Future<Output = Result<Option<StateProviderBox>, Self::Error>> + Send
+    where
+        Self: SpawnBlocking,
+    {
+        async move {
+            let Some(replay) = self.pruned_state_replay() else { return Ok(None) };
+
+            let provider = self.provider();
+            let Some(block_number) =
+                provider.block_number_for_id(at).map_err(Self::Error::from_eth_err)?
+            else {
+                return Ok(None)
+            };
+
+            // The state after the block needs the changesets of all later blocks, which are gone
+            // if the history was pruned past the block.
+            let mut pruned = false;
+            for segment in [PruneSegment::AccountHistory, PruneSegment::StorageHistory] {
+                pruned |= provider
+                    .get_prune_checkpoint(segment)
+                    .map_err(Self::Error::from_eth_err)?
+                    .and_then(|checkpoint| checkpoint.block_number)
+                    .is_some_and(|pruned| pruned > block_number);
+            }
+            if !pruned {
+                return Ok(None)
+            }
+
+            // fail before waiting for a permit if the state is too far from any known state
+            replay
+                .ensure_within_limit(provider, block_number)
+                .map_err(Self::Error::from_eth_err)?;
+
+            // replaying blocks is as expensive as tracing them
+            let _permit = self.acquire_owned_tracing().await;
+            debug!(target: "rpc::eth", block_number, "Replaying blocks to reconstruct pruned state");
+            self.spawn_tracing(move |this| {
+                let replay = this
+                    .pruned_state_replay()
+                    .ok_or_else(|| Self::Error::from_eth_err(EthApiError::InternalEthError))?;
+                replay
+                    .replay(this.provider().clone(), this.evm_config(), block_number)
+                    .map_err(Self::Error::from_eth_err)
+            })
+            .await
+            .map(Some)
+        }
+    }
+
     /// Returns the _latest_ state
     fn latest_state(&self) -> Result<StateProviderBox, Self::Error> {
         self.provider().latest().map_err(Self::Error::from_eth_err)
@@ -16,7 +16,7 @@ use reth_revm::{
     database::StateProviderDatabase,
     db::{bal::EvmDatabaseError, State},
 };
-use reth_rpc_eth_types::cache::db::StateCacheDb;
+use reth_rpc_eth_types::{cache::db::StateCacheDb, CancellableInspector, TracingCancellation};
 use reth_storage_api::{ProviderBlock, ProviderTx};
 use revm::{context::Block, context_interface::result::ResultAndState};
 use revm_inspectors::tracing::{TracingInspector, TracingInspectorConfig};
@@ -26,6 +26,8 @@ use std::sync::Arc;
 pub trait Trace: LoadState<Error: FromEvmError<Self::Evm>> + Call {
     /// Executes the [`TxEnvFor`] with [`reth_evm::EvmEnv`] against the given [Database] without
     /// committing state changes.
+    ///
+    /// The execution is aborted once the current [`TracingCancellation`] is cancelled.
     fn inspect<DB, I>(
         &self,
         db: DB,
@@ -37,8 +39,12 @@ pub trait Trace: LoadState<Error: FromEvmError<Self::Evm>> + Call {
         DB: Database<Error = EvmDatabaseError<ProviderError>>,
         I: InspectorFor<Self::Evm, DB>,
     {
+        let inspector = CancellableInspector::new(inspector);
         let mut evm = self.evm_config().evm_with_env_and_inspector(db, evm_env, inspector);
-        evm.transact(tx_env).map_err(Self::Error::from_evm_err)
+        let res = evm.transact(tx_env).map_err(Self::Error::from_evm_err)?;
+        // the execution is halted if the call was cancelled
+        TracingCancellation::check_current().map_err(Self::Error::from_eth_err)?;
+        Ok(res)
     }
 
     /// Executes the transaction on top of the given [`BlockId`] with a tracer configured by the
@@ -298,6 +304,7 @@ pub trait Trace: LoadState<Error: FromEvmError<Self::Evm>> + Call {
                     },
                 );
 
+                let total = max_transactions.min(block.body().transaction_count());
                 let mut idx = 0;
 
                 let results = this
@@ -313,6 +320,9 @@ pub trait Trace: LoadState<Error: FromEvmError<Self::Evm>> + Call {
                             block_timestamp: Some(block_timestamp),
                             base_fee: Some(base_fee),
                         };
+                        // the remaining transactions are skipped once the call is cancelled
+                        TracingCancellation::check_current_progress(idx as usize, total)
+                            .map_err(Self::Error::from_eth_err)?;
                         idx += 1;
 
                         f(tx_info, ctx)
@@ -8,8 +8,8 @@ use reth_node_api::{FullNodeComponents, NodePrimitives, PrimitivesTy};
 use reth_primitives_traits::{BlockTy, HeaderTy, ReceiptTy, TxTy};
 use reth_rpc_eth_types::EthStateCache;
 use reth_storage_api::{
-    BalProvider, BlockReader, BlockReaderIdExt, PruneCheckpointReader, StageCheckpointReader,
-    StateProviderFactory,
+    BalProvider, BlockReader, BlockReaderIdExt, LogIndexProvider, PruneCheckpointReader,
+    StageCheckpointReader, StateProviderFactory, TraceAddressIndexProvider,
 };
 use reth_transaction_pool::{PoolTransaction, TransactionPool};
 
@@ -40,6 +40,8 @@ pub trait RpcNodeCore: Clone + Send + Sync + Unpin + 'static {
         + StageCheckpointReader
         + PruneCheckpointReader
         + BalProvider
+        + TraceAddressIndexProvider
+        + LogIndexProvider
         + Send
         + Sync
         + Clone
@@ -135,6 +137,8 @@ where
         + StageCheckpointReader
         + PruneCheckpointReader
         + BalProvider
+        + TraceAddressIndexProvider
+        + LogIndexProvider
         + Send
         + Sync
         + Unpin
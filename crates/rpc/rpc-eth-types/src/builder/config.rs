@@ -10,7 +10,7 @@ use reqwest::Url;
 use reth_rpc_server_types::constants::{
     default_max_tracing_requests, DEFAULT_ETH_PROOF_WINDOW, DEFAULT_MAX_BLOCKING_IO_REQUEST,
     DEFAULT_MAX_BLOCKS_PER_FILTER, DEFAULT_MAX_LOGS_PER_RESPONSE, DEFAULT_MAX_SIMULATE_BLOCKS,
-    DEFAULT_MAX_TRACE_FILTER_BLOCKS, DEFAULT_PROOF_PERMITS,
+    DEFAULT_MAX_TRACE_FILTER_BLOCKS, DEFAULT_PROOF_CONCURRENCY, DEFAULT_PROOF_PERMITS,
     RPC_DEFAULT_SEND_RAW_TX_SYNC_TIMEOUT_SECS,
 };
 use serde::{Deserialize, Serialize};
@@ -58,6 +58,36 @@ impl PendingBlockKind {
     }
 }
 
+/// Policy applied to the messages of an `eth_subscribe` subscription once the queue of outbound
+/// messages of its connection is full, i.e. the client doesn't keep up with its subscriptions.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SubscriptionLagPolicy {
+    /// Wait until the queue has capacity, which applies backpressure to the subscription (default
+    /// behavior)
+    #[default]
+    Block,
+    /// Drop the messages the queue has no capacity for
+    Drop,
+    /// Close the subscription
+    Close,
+}
+
+impl std::str::FromStr for SubscriptionLagPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "block" => Ok(Self::Block),
+            "drop" => Ok(Self::Drop),
+            "close" => Ok(Self::Close),
+            _ => Err(format!(
+                "Invalid subscription lag policy: {s}. Valid options are: block, drop, close"
+            )),
+        }
+    }
+}
+
 /// Additional config values for the eth namespace.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct EthConfig {
@@ -99,6 +129,9 @@ pub struct EthConfig {
     pub fee_history_cache: FeeHistoryCacheConfig,
     /// The maximum number of getproof calls that can be executed concurrently.
     pub proof_permits: usize,
+    /// The maximum number of tasks a single getproof or `debug_executionWitness` call computes its
+    /// proofs with.
+    pub proof_concurrency: usize,
     /// Maximum batch size for transaction pool insertions.
     pub max_batch_size: usize,
     /// Controls how pending blocks are built when requested via RPC methods
@@ -114,6 +147,22 @@ pub struct EthConfig {
     /// This is disabled by default, allowing blob transactions with EIP-4844 sidecars to be
     /// submitted without automatic conversion.
     pub force_blob_sidecar_upcasting: bool,
+    /// Maximum number of blocks replayed on top of the genesis state to serve historical state
+    /// below the history prune horizon.
+    ///
+    /// Pruned historical state is unavailable if `None`.
+    pub pruned_state_replay_limit: Option<u64>,
+    /// Maximum number of `eth_call` and `eth_estimateGas` results cached at the latest block.
+    ///
+    /// Results are not cached if `None`.
+    pub call_cache_max_entries: Option<u32>,
+    /// Maximum duration of the execution of `debug_` and `trace_` calls.
+    ///
+    /// Calls are not limited if `None`.
+    pub tracing_timeout: Option<Duration>,
+    /// Policy applied to the messages of `eth_subscribe` subscriptions once the outbound queue
+    /// of their connection is full.
+    pub subscription_lag_policy: SubscriptionLagPolicy,
 }
 
 impl EthConfig {
@@ -143,12 +192,17 @@ impl Default for EthConfig {
             stale_filter_ttl: DEFAULT_STALE_FILTER_TTL,
             fee_history_cache: FeeHistoryCacheConfig::default(),
             proof_permits: DEFAULT_PROOF_PERMITS,
+            proof_concurrency: DEFAULT_PROOF_CONCURRENCY,
             max_batch_size: 1,
             pending_block_kind: PendingBlockKind::Full,
             raw_tx_forwarder: ForwardConfig::default(),
             send_raw_transaction_sync_timeout: RPC_DEFAULT_SEND_RAW_TX_SYNC_TIMEOUT_SECS,
             rpc_evm_memory_limit: (1 << 32) - 1,
             force_blob_sidecar_upcasting: false,
+            pruned_state_replay_limit: None,
+            call_cache_max_entries: None,
+            tracing_timeout: None,
+            subscription_lag_policy: SubscriptionLagPolicy::Block,
         }
     }
 }
@@ -226,6 +280,13 @@ impl EthConfig {
         self
     }
 
+    /// Configures the maximum number of tasks a single getproof or `debug_executionWitness`
+    /// request is split into
+    pub const fn proof_concurrency(mut self, concurrency: usize) -> Self {
+        self.proof_concurrency = concurrency;
+        self
+    }
+
     /// Configures the maximum batch size for transaction pool insertions
     pub const fn max_batch_size(mut self, max_batch_size: usize) -> Self {
         self.max_batch_size = max_batch_size;
@@ -263,6 +324,31 @@ impl EthConfig {
         self.force_blob_sidecar_upcasting = force;
         self
     }
+    /// Configures the maximum number of blocks replayed to serve pruned historical state.
+    pub const fn pruned_state_replay_limit(mut self, limit: Option<u64>) -> Self {
+        self.pruned_state_replay_limit = limit;
+        self
+    }
+
+    /// Configures the maximum number of `eth_call` and `eth_estimateGas` results cached at the
+    /// latest block.
+    pub const fn call_cache_max_entries(mut self, max_entries: Option<u32>) -> Self {
+        self.call_cache_max_entries = max_entries;
+        self
+    }
+
+    /// Configures the maximum duration of the execution of `debug_` and `trace_` calls.
+    pub const fn tracing_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.tracing_timeout = timeout;
+        self
+    }
+
+    /// Configures the policy applied to the messages of `eth_subscribe` subscriptions once the
+    /// outbound queue of their connection is full.
+    pub const fn subscription_lag_policy(mut self, policy: SubscriptionLagPolicy) -> Self {
+        self.subscription_lag_policy = policy;
+        self
+    }
 }
 
 /// Config for the filter
@@ -105,6 +105,10 @@ impl reth_storage_api::StateProofProvider for StateProviderTraitObjWrapper {
     ) -> reth_errors::ProviderResult<Vec<alloy_primitives::Bytes>> {
         self.0.witness(input, target, mode)
     }
+
+    fn proof_input(&self) -> ProviderResult<Option<(B256, reth_trie::TrieInput)>> {
+        self.0.proof_input()
+    }
 }
 
 impl reth_storage_api::AccountReader for StateProviderTraitObjWrapper {
@@ -0,0 +1,173 @@
+//! Cache of `eth_call` and `eth_estimateGas` results at the latest block.
+
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{keccak256, Bytes, B256, U256};
+use reth_metrics::{
+    metrics::{Counter, Gauge},
+    Metrics,
+};
+use schnellru::{ByLength, LruMap};
+use serde::Serialize;
+use std::sync::Mutex;
+
+/// Cache of `eth_call` and `eth_estimateGas` results, keyed by the call request and the hash of
+/// the block the call was executed at.
+///
+/// Only calls at the latest block without overrides are cached, which are identical for all
+/// callers until the next block. The cache is cleared once a call is made at a new latest block,
+/// so it only ever holds results of a single block.
+#[derive(Debug)]
+pub struct EthCallCache {
+    inner: Mutex<EthCallCacheInner>,
+    metrics: EthCallCacheMetrics,
+}
+
+impl EthCallCache {
+    /// Creates a new cache holding at most `max_entries` results.
+    pub fn new(max_entries: u32) -> Self {
+        Self {
+            inner: Mutex::new(EthCallCacheInner {
+                block: BlockNumHash::default(),
+                entries: LruMap::new(ByLength::new(max_entries)),
+            }),
+            metrics: Default::default(),
+        }
+    }
+
+    /// Returns the cache key of the `eth_call` request, the hash of its JSON encoding.
+    pub fn call_key(request: &impl Serialize) -> Option<B256> {
+        Self::key(CallKind::Call, request)
+    }
+
+    /// Returns the cache key of the `eth_estimateGas` request, the hash of its JSON encoding.
+    pub fn estimate_key(request: &impl Serialize) -> Option<B256> {
+        Self::key(CallKind::EstimateGas, request)
+    }
+
+    /// Returns the cached output of the `eth_call` request with the key at the block.
+    pub fn get_call(&self, block: BlockNumHash, key: B256) -> Option<Bytes> {
+        match self.get(block, key)? {
+            CachedResult::Output(output) => Some(output),
+            CachedResult::Gas(_) => None,
+        }
+    }
+
+    /// Caches the output of the `eth_call` request with the key at the block.
+    pub fn insert_call(&self, block: BlockNumHash, key: B256, output: Bytes) {
+        self.insert(block, key, CachedResult::Output(output))
+    }
+
+    /// Returns the cached gas estimate of the `eth_estimateGas` request with the key at the block.
+    pub fn get_estimate(&self, block: BlockNumHash, key: B256) -> Option<U256> {
+        match self.get(block, key)? {
+            CachedResult::Gas(gas) => Some(gas),
+            CachedResult::Output(_) => None,
+        }
+    }
+
+    /// Caches the gas estimate of the `eth_estimateGas` request with the key at the block.
+    pub fn insert_estimate(&self, block: BlockNumHash, key: B256, gas: U256) {
+        self.insert(block, key, CachedResult::Gas(gas))
+    }
+
+    fn get(&self, block: BlockNumHash, key: B256) -> Option<CachedResult> {
+        let mut inner = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+        let result = (inner.block == block).then(|| inner.entries.get(&key).cloned()).flatten();
+        drop(inner);
+
+        if result.is_some() {
+            self.metrics.hits_total.increment(1);
+        } else {
+            self.metrics.misses_total.increment(1);
+        }
+        result
+    }
+
+    fn insert(&self, block: BlockNumHash, key: B256, result: CachedResult) {
+        let mut inner = self.inner.lock().unwrap_or_else(|err| err.into_inner());
+        if inner.block != block {
+            // results of calls that started before a newer block was seen are dropped
+            if block.number < inner.block.number {
+                return
+            }
+            inner.block = block;
+            inner.entries.clear();
+        }
+        inner.entries.insert(key, result);
+        self.metrics.cached_count.set(inner.entries.len() as f64);
+    }
+
+    fn key(kind: CallKind, request: &impl Serialize) -> Option<B256> {
+        let mut encoded = serde_json::to_vec(request).ok()?;
+        encoded.push(kind as u8);
+        Some(keccak256(encoded))
+    }
+}
+
+#[derive(Debug)]
+struct EthCallCacheInner {
+    /// The block of the cached results
+    block: BlockNumHash,
+    /// The cached results keyed by the hash of their request
+    entries: LruMap<B256, CachedResult, ByLength>,
+}
+
+/// The method of a cached call.
+#[derive(Debug, Clone, Copy)]
+enum CallKind {
+    Call,
+    EstimateGas,
+}
+
+/// A cached call result.
+#[derive(Debug, Clone)]
+enum CachedResult {
+    Output(Bytes),
+    Gas(U256),
+}
+
+/// Metrics of the [`EthCallCache`].
+#[derive(Metrics)]
+#[metrics(scope = "rpc.eth_call_cache")]
+struct EthCallCacheMetrics {
+    /// The number of cached results.
+    cached_count: Gauge,
+    /// The number of cache hits.
+    hits_total: Counter,
+    /// The number of cache misses.
+    misses_total: Counter,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_latest_block() {
+        let cache = EthCallCache::new(10);
+        let block = BlockNumHash::new(1, B256::with_last_byte(1));
+        let request = serde_json::json!({"to": "0x0000000000000000000000000000000000000001"});
+        let call = EthCallCache::call_key(&request).unwrap();
+        let estimate = EthCallCache::estimate_key(&request).unwrap();
+        assert_ne!(call, estimate);
+
+        assert_eq!(cache.get_call(block, call), None);
+        cache.insert_call(block, call, Bytes::from_static(&[1]));
+        cache.insert_estimate(block, estimate, U256::from(21_000));
+        assert_eq!(cache.get_call(block, call), Some(Bytes::from_static(&[1])));
+        assert_eq!(cache.get_estimate(block, estimate), Some(U256::from(21_000)));
+
+        // results of older blocks are not cached
+        let parent = BlockNumHash::new(0, B256::ZERO);
+        cache.insert_call(parent, call, Bytes::from_static(&[0]));
+        assert_eq!(cache.get_call(parent, call), None);
+        assert_eq!(cache.get_call(block, call), Some(Bytes::from_static(&[1])));
+
+        // a new block clears the cache
+        let next = BlockNumHash::new(2, B256::with_last_byte(2));
+        cache.insert_call(next, call, Bytes::from_static(&[2]));
+        assert_eq!(cache.get_call(block, call), None);
+        assert_eq!(cache.get_estimate(next, estimate), None);
+        assert_eq!(cache.get_call(next, call), Some(Bytes::from_static(&[2])));
+    }
+}
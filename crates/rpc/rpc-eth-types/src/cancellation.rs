@@ -0,0 +1,334 @@
+//! Cooperative cancellation of tracing calls.
+
+use crate::EthApiError;
+use revm::{
+    inspector::Inspector,
+    interpreter::{
+        interpreter::EthInterpreter, CallInputs, CallOutcome, CreateInputs, CreateOutcome,
+        InstructionResult, Interpreter,
+    },
+    primitives::{Address, Log, U256},
+};
+use std::{
+    cell::RefCell,
+    future::{poll_fn, Future},
+    pin::pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// Number of instructions a [`CancellableInspector`] executes between checks of the cancellation.
+const CANCELLATION_CHECK_INTERVAL: u64 = 4096;
+
+thread_local! {
+    /// The cancellation of the call executed by the current thread, if any.
+    static CURRENT: RefCell<Option<TracingCancellation>> = const { RefCell::new(None) };
+}
+
+/// Cancellation of a tracing call, checked cooperatively by the loops executing its transactions,
+/// and within a transaction by the [`CancellableInspector`].
+///
+/// A call is cancelled once its timeout elapsed, or once the future it was instrumented with is
+/// dropped before completing, e.g. because the client disconnected.
+///
+/// The cancellation is the current one of the thread while the instrumented future is polled, and
+/// is carried over to the blocking tasks the call spawns, so the execution loops can check it with
+/// [`TracingCancellation::check_current`] without it being passed down.
+#[derive(Debug, Clone, Default)]
+pub struct TracingCancellation {
+    inner: Arc<TracingCancellationInner>,
+}
+
+impl TracingCancellation {
+    /// Creates a new cancellation, timing out after the timeout if any.
+    pub fn new(timeout: Option<Duration>) -> Self {
+        Self {
+            inner: Arc::new(TracingCancellationInner {
+                cancelled: AtomicBool::new(false),
+                timeout,
+                deadline: timeout.and_then(|timeout| Instant::now().checked_add(timeout)),
+            }),
+        }
+    }
+
+    /// Returns the timeout of the call.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.inner.timeout
+    }
+
+    /// Cancels the call.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the call is cancelled or timed out.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::Relaxed) || self.is_timed_out()
+    }
+
+    fn is_timed_out(&self) -> bool {
+        self.inner.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Returns the error the call is aborted with, if it's cancelled or timed out.
+    pub fn check(&self) -> Result<(), EthApiError> {
+        if let Some(timeout) = self.inner.timeout &&
+            self.is_timed_out()
+        {
+            return Err(EthApiError::ExecutionTimedOut(timeout))
+        }
+        if self.inner.cancelled.load(Ordering::Relaxed) {
+            return Err(EthApiError::ExecutionCancelled)
+        }
+        Ok(())
+    }
+
+    /// Same as [`Self::check`], but reports the number of transactions executed if the call timed
+    /// out.
+    pub fn check_progress(&self, executed: usize, total: usize) -> Result<(), EthApiError> {
+        self.check().map_err(|err| match err {
+            EthApiError::ExecutionTimedOut(timeout) => {
+                EthApiError::PartialExecutionTimedOut { timeout, executed, total }
+            }
+            err => err,
+        })
+    }
+
+    /// Returns the cancellation of the call executed by the current thread, if any.
+    pub fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().clone())
+    }
+
+    /// Checks the cancellation of the call executed by the current thread, if any.
+    pub fn check_current() -> Result<(), EthApiError> {
+        CURRENT.with(|current| current.borrow().as_ref().map_or(Ok(()), Self::check))
+    }
+
+    /// Checks the cancellation of the call executed by the current thread, if any, reporting the
+    /// number of transactions executed if the call timed out.
+    pub fn check_current_progress(executed: usize, total: usize) -> Result<(), EthApiError> {
+        CURRENT.with(|current| {
+            current.borrow().as_ref().map_or(Ok(()), |this| this.check_progress(executed, total))
+        })
+    }
+
+    /// Runs the closure with this cancellation as the current one of the thread.
+    pub fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        let _reset = ResetCurrent(previous);
+        f()
+    }
+
+    /// Makes this cancellation the current one while the future is polled, and cancels it if the
+    /// future is dropped before completing.
+    pub fn instrument<F: Future>(self, fut: F) -> impl Future<Output = F::Output> {
+        async move {
+            let mut guard = CancelOnDrop { cancellation: self, armed: true };
+            let mut fut = pin!(fut);
+            let output = poll_fn(|cx| guard.cancellation.enter(|| fut.as_mut().poll(cx))).await;
+            guard.armed = false;
+            output
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct TracingCancellationInner {
+    /// Whether the call was cancelled
+    cancelled: AtomicBool,
+    /// The timeout of the call, if any
+    timeout: Option<Duration>,
+    /// The instant the call times out, if any
+    deadline: Option<Instant>,
+}
+
+/// Restores the previous cancellation of the thread, also if the closure panics.
+struct ResetCurrent(Option<TracingCancellation>);
+
+impl Drop for ResetCurrent {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// An inspector halting the execution once the current [`TracingCancellation`] of the thread is
+/// cancelled, checked every [`CANCELLATION_CHECK_INTERVAL`] instructions, so a single long running
+/// transaction can be aborted as well.
+///
+/// All hooks are forwarded to the wrapped inspector. The execution is halted as if it ran out of
+/// gas, callers are expected to check the cancellation after the execution and discard the result.
+#[derive(Debug)]
+pub struct CancellableInspector<I> {
+    inner: I,
+    cancellation: Option<TracingCancellation>,
+    steps: u64,
+    cancelled: bool,
+}
+
+impl<I> CancellableInspector<I> {
+    /// Wraps the inspector, halting the execution once the current cancellation of the thread, if
+    /// any, is cancelled.
+    pub fn new(inner: I) -> Self {
+        Self { inner, cancellation: TracingCancellation::current(), steps: 0, cancelled: false }
+    }
+
+    /// Returns the wrapped inspector.
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+
+    /// Returns `true` if the execution should be halted, checking the cancellation every
+    /// [`CANCELLATION_CHECK_INTERVAL`] steps. Once cancelled, all later steps are halted.
+    fn should_halt(&mut self) -> bool {
+        if self.cancelled {
+            return true
+        }
+        let Some(cancellation) = &self.cancellation else { return false };
+        self.steps += 1;
+        if self.steps.is_multiple_of(CANCELLATION_CHECK_INTERVAL) {
+            self.cancelled = cancellation.is_cancelled();
+        }
+        self.cancelled
+    }
+}
+
+impl<CTX, I> Inspector<CTX, EthInterpreter> for CancellableInspector<I>
+where
+    I: Inspector<CTX, EthInterpreter>,
+{
+    fn initialize_interp(&mut self, interp: &mut Interpreter<EthInterpreter>, context: &mut CTX) {
+        self.inner.initialize_interp(interp, context)
+    }
+
+    fn step(&mut self, interp: &mut Interpreter<EthInterpreter>, context: &mut CTX) {
+        if self.should_halt() {
+            interp.halt(InstructionResult::OutOfGas);
+            return
+        }
+        self.inner.step(interp, context)
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter<EthInterpreter>, context: &mut CTX) {
+        self.inner.step_end(interp, context)
+    }
+
+    fn log(&mut self, context: &mut CTX, log: Log) {
+        self.inner.log(context, log)
+    }
+
+    fn log_full(&mut self, interp: &mut Interpreter<EthInterpreter>, context: &mut CTX, log: Log) {
+        self.inner.log_full(interp, context, log)
+    }
+
+    fn call(&mut self, context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+        self.inner.call(context, inputs)
+    }
+
+    fn call_end(&mut self, context: &mut CTX, inputs: &CallInputs, outcome: &mut CallOutcome) {
+        self.inner.call_end(context, inputs, outcome)
+    }
+
+    fn create(&mut self, context: &mut CTX, inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        self.inner.create(context, inputs)
+    }
+
+    fn create_end(
+        &mut self,
+        context: &mut CTX,
+        inputs: &CreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        self.inner.create_end(context, inputs, outcome)
+    }
+
+    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
+        self.inner.selfdestruct(contract, target, value)
+    }
+}
+
+/// Cancels the call if the instrumented future is dropped before completing.
+struct CancelOnDrop {
+    cancellation: TracingCancellation,
+    armed: bool,
+}
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        if self.armed {
+            self.cancellation.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current_cancellation() {
+        assert!(TracingCancellation::check_current().is_ok());
+
+        let cancellation = TracingCancellation::new(Some(Duration::ZERO));
+        cancellation.enter(|| {
+            assert!(matches!(
+                TracingCancellation::check_current_progress(1, 2),
+                Err(EthApiError::PartialExecutionTimedOut { executed: 1, total: 2, .. })
+            ));
+
+            let nested = TracingCancellation::new(None);
+            nested.enter(|| assert!(TracingCancellation::check_current().is_ok()));
+            nested.cancel();
+            assert!(matches!(
+                nested.enter(TracingCancellation::check_current),
+                Err(EthApiError::ExecutionCancelled)
+            ));
+
+            assert!(matches!(
+                TracingCancellation::check_current(),
+                Err(EthApiError::ExecutionTimedOut(_))
+            ));
+        });
+        assert!(TracingCancellation::current().is_none());
+    }
+
+    #[test]
+    fn cancellable_inspector_halts_once_cancelled() {
+        let cancellation = TracingCancellation::new(None);
+        let mut inspector = cancellation.enter(|| CancellableInspector::new(()));
+        assert!(!CancellableInspector::new(()).should_halt(), "no current cancellation");
+
+        for _ in 0..CANCELLATION_CHECK_INTERVAL {
+            assert!(!inspector.should_halt());
+        }
+
+        cancellation.cancel();
+        for _ in 1..CANCELLATION_CHECK_INTERVAL {
+            assert!(!inspector.should_halt(), "only checked every interval");
+        }
+        assert!(inspector.should_halt());
+        assert!(inspector.should_halt());
+    }
+
+    #[tokio::test]
+    async fn cancel_dropped_call() {
+        let cancellation = TracingCancellation::new(None);
+        let call = cancellation.clone().instrument(async {
+            assert!(TracingCancellation::current().is_some());
+        });
+        call.await;
+        assert!(!cancellation.is_cancelled());
+
+        let call = cancellation.clone().instrument(std::future::pending::<()>());
+        drop(call);
+        assert!(!cancellation.is_cancelled(), "a call that was never polled is not cancelled");
+
+        let mut call = Box::pin(cancellation.clone().instrument(std::future::pending::<()>()));
+        assert!(futures::poll!(call.as_mut()).is_pending());
+        drop(call);
+        assert!(cancellation.is_cancelled());
+    }
+}
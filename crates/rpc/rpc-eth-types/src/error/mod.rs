@@ -13,8 +13,11 @@ use reth_errors::{BlockExecutionError, BlockValidationError, RethError};
 use reth_primitives_traits::transaction::{error::InvalidTransactionError, signed::RecoveryError};
 use reth_revm::db::bal::EvmDatabaseError;
 use reth_rpc_convert::{CallFeesError, EthTxEnvError, TransactionConversionError};
-use reth_rpc_server_types::result::{
-    block_id_to_str, internal_rpc_err, invalid_params_rpc_err, rpc_err, rpc_error_with_code,
+use reth_rpc_server_types::{
+    error::{codes, reth_rpc_err},
+    result::{
+        block_id_to_str, internal_rpc_err, invalid_params_rpc_err, rpc_err, rpc_error_with_code,
+    },
 };
 use reth_transaction_pool::error::{
     Eip4844PoolTransactionError, Eip7702PoolTransactionError, InvalidPoolTransactionError,
@@ -159,6 +162,20 @@ pub enum EthApiError {
     /// Error thrown when a (tracing) call exceeds the configured timeout
     #[error("execution aborted (timeout = {0:?})")]
     ExecutionTimedOut(Duration),
+    /// Error thrown when a tracing call exceeds the configured timeout after executing only a part
+    /// of its transactions
+    #[error("execution aborted (timeout = {timeout:?}) after {executed} of {total} transactions")]
+    PartialExecutionTimedOut {
+        /// The configured timeout
+        timeout: Duration,
+        /// Number of transactions executed before the call was aborted
+        executed: usize,
+        /// Number of transactions of the call
+        total: usize,
+    },
+    /// Error thrown when a (tracing) call is cancelled because its caller is gone
+    #[error("execution cancelled")]
+    ExecutionCancelled,
     /// Internal Error thrown by the javascript tracer
     #[error("{0}")]
     InternalJsTracerError(String),
@@ -297,8 +314,8 @@ impl From<EthApiError> for jsonrpsee_types::error::ErrorObject<'static> {
             EthApiError::PrevrandaoNotSet |
             EthApiError::ExcessBlobGasNotSet |
             EthApiError::InvalidBlockData(_) |
-            EthApiError::Internal(_) |
             EthApiError::EvmCustom(_) => internal_rpc_err(error.to_string()),
+            EthApiError::Internal(err) => reth_rpc_err(&err),
             EthApiError::UnknownBlockOrTxIndex | EthApiError::TransactionNotFound => {
                 rpc_error_with_code(EthRpcErrorCode::ResourceNotFound.code(), error.to_string())
             }
@@ -320,10 +337,12 @@ impl From<EthApiError> for jsonrpsee_types::error::ErrorObject<'static> {
                 EthRpcErrorCode::TransactionConfirmationTimeout.code(),
                 err.to_string(),
             ),
-            EthApiError::Unsupported(msg) => internal_rpc_err(msg),
+            EthApiError::Unsupported(msg) => rpc_error_with_code(codes::METHOD_NOT_SUPPORTED, msg),
             EthApiError::InternalJsTracerError(msg) => internal_rpc_err(msg),
             EthApiError::InvalidParams(msg) => invalid_params_rpc_err(msg),
-            err @ EthApiError::ExecutionTimedOut(_) => rpc_error_with_code(
+            err @ (EthApiError::ExecutionTimedOut(_) |
+            EthApiError::PartialExecutionTimedOut { .. } |
+            EthApiError::ExecutionCancelled) => rpc_error_with_code(
                 jsonrpsee_types::error::CALL_EXECUTION_FAILED_CODE,
                 err.to_string(),
             ),
@@ -331,7 +350,9 @@ impl From<EthApiError> for jsonrpsee_types::error::ErrorObject<'static> {
                 internal_rpc_err(err.to_string())
             }
             err @ EthApiError::TransactionInputError(_) => invalid_params_rpc_err(err.to_string()),
-            EthApiError::PrunedHistoryUnavailable => rpc_error_with_code(4444, error.to_string()),
+            EthApiError::PrunedHistoryUnavailable => {
+                rpc_error_with_code(codes::PRUNED_HISTORY_UNAVAILABLE, error.to_string())
+            }
             EthApiError::Other(err) => err.to_rpc_error(),
             EthApiError::MuxTracerError(msg) => internal_rpc_err(msg.to_string()),
             EthApiError::BatchTxRecvError(err) => internal_rpc_err(err.to_string()),
@@ -349,7 +370,7 @@ impl From<EthApiError> for jsonrpsee_types::error::ErrorObject<'static> {
                 )
             }
             EthApiError::BlockAccessListNotAvailablePreAmsterdam => {
-                rpc_error_with_code(4445, error.to_string())
+                rpc_error_with_code(codes::BLOCK_ACCESS_LIST_UNAVAILABLE, error.to_string())
             }
         }
     }
@@ -515,7 +536,7 @@ impl From<reth_errors::ProviderError> for EthApiError {
             ProviderError::BlockNumberForTransactionIndexNotFound => Self::UnknownBlockOrTxIndex,
             ProviderError::FinalizedBlockNotFound => Self::HeaderNotFound(BlockId::finalized()),
             ProviderError::SafeBlockNotFound => Self::HeaderNotFound(BlockId::safe()),
-            ProviderError::BlockExpired { .. } => Self::PrunedHistoryUnavailable,
+            err if err.is_pruned() => Self::PrunedHistoryUnavailable,
             err => Self::Internal(err.into()),
         }
     }
@@ -1152,6 +1173,13 @@ mod tests {
     fn timed_out_error() {
         let err = EthApiError::ExecutionTimedOut(Duration::from_secs(10));
         assert_eq!(err.to_string(), "execution aborted (timeout = 10s)");
+
+        let err = EthApiError::PartialExecutionTimedOut {
+            timeout: Duration::from_secs(10),
+            executed: 3,
+            total: 5,
+        };
+        assert_eq!(err.to_string(), "execution aborted (timeout = 10s) after 3 of 5 transactions");
     }
 
     #[test]
@@ -1236,4 +1264,20 @@ mod tests {
         let msg = err.to_string();
         assert_eq!(msg, "execution reverted: test_revert_reason");
     }
+
+    #[test]
+    fn internal_error_codes() {
+        let err: jsonrpsee_types::error::ErrorObject<'static> =
+            EthApiError::from(reth_errors::ProviderError::StateAtBlockPruned(1)).into();
+        assert_eq!(err.code(), codes::PRUNED_HISTORY_UNAVAILABLE);
+        assert!(err.data().is_some());
+
+        let err: jsonrpsee_types::error::ErrorObject<'static> =
+            EthApiError::Unsupported("unsupported tracer").into();
+        assert_eq!(err.code(), codes::METHOD_NOT_SUPPORTED);
+
+        let err: jsonrpsee_types::error::ErrorObject<'static> =
+            EthApiError::BlockAccessListNotAvailablePreAmsterdam.into();
+        assert_eq!(err.code(), codes::BLOCK_ACCESS_LIST_UNAVAILABLE);
+    }
 }
@@ -159,7 +159,9 @@ where
                 .map(|(_, fee_entry)| fee_entry.clone())
                 .collect::<Vec<_>>();
 
-            if result.is_empty() {
+            // the range can have gaps if blocks couldn't be fetched, in which case it's read from
+            // disk
+            if result.len() as u64 != end_block - start_block + 1 {
                 return None
             }
 
@@ -232,9 +234,27 @@ pub async fn fee_history_cache_new_blocks_task<St, Provider, N>(
     let mut missing_blocks = VecDeque::new();
     let mut fetch_missing_block = Fuse::terminated();
 
+    // Backfill the cache with the latest blocks newest first, so requests for many blocks are
+    // served from memory right after startup and not only once enough new blocks were committed.
+    let max_blocks = fee_history_cache.config().max_blocks;
+    let mut backfill_blocks = provider
+        .best_block_number()
+        .map(|best| (best.saturating_sub(max_blocks.saturating_sub(1))..=best).rev().collect())
+        .unwrap_or_else(|_| VecDeque::new());
+
     loop {
-        if fetch_missing_block.is_terminated() &&
-            let Some(block_number) = missing_blocks.pop_front()
+        // blocks that can't be found are skipped
+        while fetch_missing_block.is_terminated() &&
+            let Some(block_number) = missing_blocks.pop_front().or_else(|| {
+                let block_number = backfill_blocks.pop_front()?;
+                if block_number + max_blocks <= fee_history_cache.upper_bound() {
+                    // the remaining blocks are older and fell out of the cache window while
+                    // backfilling
+                    backfill_blocks.clear();
+                    return None
+                }
+                Some(block_number)
+            })
         {
             trace!(target: "rpc::fee", ?block_number, "Fetching missing block for fee history cache");
             if let Ok(Some(hash)) = provider.block_hash(block_number) {
@@ -419,7 +439,10 @@ mod tests {
     use super::*;
     use alloy_consensus::{TxEip1559, TxType};
     use alloy_primitives::Signature;
-    use reth_ethereum_primitives::{Receipt, Transaction as EthTransaction, TransactionSigned};
+    use reth_chainspec::MAINNET;
+    use reth_ethereum_primitives::{
+        Block as EthBlock, Receipt, Transaction as EthTransaction, TransactionSigned,
+    };
 
     #[test]
     fn reward_percentiles_use_receipt_gas_weight_for_eip8037() {
@@ -440,6 +463,27 @@ mod tests {
         assert_eq!(rewards, vec![low_tip]);
     }
 
+    #[tokio::test]
+    async fn history_with_gap_is_not_served_from_cache() {
+        let cache = FeeHistoryCache::<Header>::new(FeeHistoryCacheConfig::default());
+        let blocks = [1, 2, 4].map(|number| {
+            SealedBlock::seal_slow(EthBlock {
+                header: Header { number, gas_limit: 30_000_000, ..Default::default() },
+                body: Default::default(),
+            })
+        });
+        cache.insert_blocks(blocks.iter().map(|block| (block, &[] as &[Receipt])), &*MAINNET).await;
+        assert_eq!((cache.lower_bound(), cache.upper_bound()), (1, 4));
+
+        // block 3 is missing, so the range is read from disk
+        assert!(cache.get_history(1, 4).await.is_none());
+        assert!(cache.get_history(2, 3).await.is_none());
+
+        let history = cache.get_history(1, 2).await.unwrap();
+        assert_eq!(history.iter().map(|entry| entry.header.number).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(cache.get_history(4, 4).await.unwrap().len(), 1);
+    }
+
     fn eip1559_transaction(tip: u128, base_fee: u64) -> TransactionSigned {
         TransactionSigned::new_unhashed(
             EthTransaction::Eip1559(TxEip1559 {
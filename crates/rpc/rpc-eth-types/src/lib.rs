@@ -14,6 +14,8 @@ use url as _;
 pub mod block;
 pub mod builder;
 pub mod cache;
+pub mod call_cache;
+pub mod cancellation;
 pub mod capabilities;
 pub mod error;
 pub mod fee_history;
@@ -21,8 +23,12 @@ pub mod gas_oracle;
 pub mod id_provider;
 pub mod logs_utils;
 pub mod pending_block;
+pub mod proof;
+pub mod pubsub;
 pub mod receipt;
+pub mod replay;
 pub mod simulate;
+pub mod state_override;
 pub mod transaction;
 pub mod tx_forward;
 pub mod utils;
@@ -34,6 +40,8 @@ pub use cache::{
     config::EthStateCacheConfig, db::StateCacheDb, multi_consumer::MultiConsumerLruCache,
     EthStateCache,
 };
+pub use call_cache::EthCallCache;
+pub use cancellation::{CancellableInspector, TracingCancellation};
 pub use capabilities::{EthCapabilities, EthCapabilitiesHead, EthCapabilitiesResource};
 pub use error::{EthApiError, EthResult, RevertError, RpcInvalidTransactionError, SignError};
 pub use fee_history::{FeeHistoryCache, FeeHistoryCacheConfig, FeeHistoryEntry};
@@ -42,5 +50,7 @@ pub use gas_oracle::{
 };
 pub use id_provider::EthSubscriptionIdProvider;
 pub use pending_block::{PendingBlock, PendingBlockEnv, PendingBlockEnvOrigin};
+pub use proof::EthProofMetrics;
+pub use pubsub::{EthSubscriptionParams, PendingTransactionFilter};
 pub use transaction::TransactionSource;
 pub use tx_forward::ForwardConfig;
@@ -0,0 +1,19 @@
+//! Metrics of `eth_getProof` requests.
+
+use reth_metrics::{metrics::Histogram, Metrics};
+
+/// Metrics of `eth_getProof` requests.
+#[derive(Metrics, Clone)]
+#[metrics(scope = "rpc.eth_proof")]
+pub struct EthProofMetrics {
+    /// Time spent waiting for a proof permit, bounding the number of concurrent requests.
+    pub permit_wait_duration: Histogram,
+    /// Time spent generating the proof of a request, across all of its tasks.
+    pub proof_duration: Histogram,
+    /// Time spent by a single task proving its share of the requested storage slots.
+    pub task_duration: Histogram,
+    /// Number of storage slots requested.
+    pub storage_slots: Histogram,
+    /// Number of tasks the storage proofs of a request were split into.
+    pub tasks: Histogram,
+}
@@ -0,0 +1,180 @@
+//! Params of `eth_subscribe`.
+
+use alloy_consensus::Transaction;
+use alloy_primitives::Address;
+use alloy_rpc_types_eth::pubsub::Params;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+
+/// Params of `eth_subscribe`.
+///
+/// Extends the standard [`Params`] with a [`PendingTransactionFilter`] for
+/// `newPendingTransactions` subscriptions. An object is parsed as the filter if it has any of its
+/// fields.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum EthSubscriptionParams {
+    /// Standard params.
+    Params(Params),
+    /// Filter of a `newPendingTransactions` subscription.
+    PendingTransactions(PendingTransactionFilter),
+}
+
+impl Default for EthSubscriptionParams {
+    fn default() -> Self {
+        Self::Params(Params::None)
+    }
+}
+
+impl From<Params> for EthSubscriptionParams {
+    fn from(params: Params) -> Self {
+        Self::Params(params)
+    }
+}
+
+impl From<PendingTransactionFilter> for EthSubscriptionParams {
+    fn from(filter: PendingTransactionFilter) -> Self {
+        Self::PendingTransactions(filter)
+    }
+}
+
+impl<'de> Deserialize<'de> for EthSubscriptionParams {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let is_pending_filter = value.as_object().is_some_and(|object| {
+            object.keys().any(|key| PendingTransactionFilter::FIELDS.contains(&key.as_str()))
+        });
+        if is_pending_filter {
+            serde_json::from_value(value).map(Self::PendingTransactions).map_err(D::Error::custom)
+        } else {
+            serde_json::from_value(value).map(Self::Params).map_err(D::Error::custom)
+        }
+    }
+}
+
+/// Server side filter of a `newPendingTransactions` subscription.
+///
+/// A transaction is sent if it matches all the criteria that are set, so subscribers don't have
+/// to receive the entire pending stream.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PendingTransactionFilter {
+    /// Senders to match, any if empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub from: Vec<Address>,
+    /// Recipients to match, any if empty. Contract creations don't match a non-empty list.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub to: Vec<Address>,
+    /// Minimum max fee per gas, or gas price of legacy transactions.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub min_gas_price: Option<u128>,
+    /// Minimum max priority fee per gas, or gas price of legacy transactions.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub min_priority_fee: Option<u128>,
+    /// Whether only EIP-4844 blob transactions match.
+    #[serde(default)]
+    pub blob_only: bool,
+    /// Whether full transaction objects are sent instead of hashes.
+    #[serde(default)]
+    pub full_transactions: bool,
+}
+
+impl PendingTransactionFilter {
+    /// Names of the fields of the filter.
+    pub const FIELDS: [&str; 6] =
+        ["from", "to", "minGasPrice", "minPriorityFee", "blobOnly", "fullTransactions"];
+
+    /// Returns `true` if any transaction matches the filter.
+    pub fn matches_all(&self) -> bool {
+        self.from.is_empty() &&
+            self.to.is_empty() &&
+            self.min_gas_price.is_none() &&
+            self.min_priority_fee.is_none() &&
+            !self.blob_only
+    }
+
+    /// Returns `true` if the transaction sent by the sender matches the filter.
+    pub fn matches<T: Transaction>(&self, sender: Address, tx: &T) -> bool {
+        (self.from.is_empty() || self.from.contains(&sender)) &&
+            (self.to.is_empty() || tx.to().is_some_and(|to| self.to.contains(&to))) &&
+            self.min_gas_price.is_none_or(|min| tx.max_fee_per_gas() >= min) &&
+            self.min_priority_fee.is_none_or(|min| tx.priority_fee_or_price() >= min) &&
+            (!self.blob_only || tx.is_eip4844())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{TxEip1559, TxLegacy};
+    use alloy_primitives::{address, TxKind};
+
+    #[test]
+    fn deserialize_params() {
+        let params: EthSubscriptionParams = serde_json::from_str("true").unwrap();
+        assert_eq!(params, Params::Bool(true).into());
+
+        let params: EthSubscriptionParams =
+            serde_json::from_str(r#"{"address":"0x0000000000000000000000000000000000000001"}"#)
+                .unwrap();
+        assert!(matches!(params, EthSubscriptionParams::Params(Params::Logs(_))));
+
+        let params: EthSubscriptionParams = serde_json::from_str(
+            r#"{"to":["0x0000000000000000000000000000000000000001"],"minPriorityFee":"0x3b9aca00","fullTransactions":true}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            params,
+            PendingTransactionFilter {
+                to: vec![address!("0x0000000000000000000000000000000000000001")],
+                min_priority_fee: Some(1_000_000_000),
+                full_transactions: true,
+                ..Default::default()
+            }
+            .into()
+        );
+
+        // unknown fields of the filter are rejected
+        assert!(
+            serde_json::from_str::<EthSubscriptionParams>(r#"{"to":[],"toBlock":"0x1"}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn filter_pending_transactions() {
+        let sender = address!("0x0000000000000000000000000000000000000001");
+        let recipient = address!("0x0000000000000000000000000000000000000002");
+        let tx = TxEip1559 {
+            to: TxKind::Call(recipient),
+            max_fee_per_gas: 20,
+            max_priority_fee_per_gas: 2,
+            ..Default::default()
+        };
+        let create = TxLegacy { gas_price: 10, ..Default::default() };
+
+        let filter = PendingTransactionFilter::default();
+        assert!(filter.matches_all());
+        assert!(filter.matches(sender, &tx));
+
+        let filter = PendingTransactionFilter { to: vec![recipient], ..Default::default() };
+        assert!(!filter.matches_all());
+        assert!(filter.matches(sender, &tx));
+        assert!(!filter.matches(sender, &create));
+
+        let filter = PendingTransactionFilter { from: vec![recipient], ..Default::default() };
+        assert!(!filter.matches(sender, &tx));
+
+        let filter = PendingTransactionFilter {
+            min_gas_price: Some(10),
+            min_priority_fee: Some(2),
+            ..Default::default()
+        };
+        assert!(filter.matches(sender, &tx));
+        assert!(filter.matches(sender, &create));
+
+        let filter = PendingTransactionFilter { min_priority_fee: Some(3), ..Default::default() };
+        assert!(!filter.matches(sender, &tx));
+
+        let filter = PendingTransactionFilter { blob_only: true, ..Default::default() };
+        assert!(!filter.matches(sender, &tx));
+    }
+}
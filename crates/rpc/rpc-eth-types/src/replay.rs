@@ -0,0 +1,337 @@
+//! Reconstruction of pruned historical state by replaying blocks on top of an older state.
+
+use crate::EthApiError;
+use alloy_primitives::{Address, BlockNumber, Bytes, StorageKey, StorageValue, B256, U256};
+use reth_chain_state::{StateOverrideProvider, StateOverrides};
+use reth_chainspec::{ChainSpecProvider, EthChainSpec};
+use reth_errors::{ProviderError, ProviderResult};
+use reth_evm::{execute::Executor, ConfigureEvm};
+use reth_primitives_traits::{Account, BlockTy, Bytecode};
+use reth_revm::database::StateProviderDatabase;
+use reth_storage_api::{
+    AccountReader, BlockHashReader, BlockReader, BytecodeReader, HashedPostStateProvider,
+    StateProofProvider, StateProvider, StateProviderBox, StateRootProvider, StorageRootProvider,
+    TransactionVariant,
+};
+use reth_trie::{
+    updates::TrieUpdates, AccountProof, HashedPostState, HashedStorage, KeccakKeyHasher,
+    MultiProof, MultiProofTargets, StorageMultiProof, TrieInput,
+};
+use revm::database::BundleState;
+use schnellru::{ByLength, LruMap};
+use std::sync::{Arc, Mutex};
+
+/// Maximum number of reconstructed states kept by [`PrunedStateReplay`].
+const MAX_REPLAYED_STATES: u32 = 16;
+
+/// Reconstructs historical state below the history prune horizon, where the changesets needed to
+/// look it up are no longer available, by executing blocks on top of an older state.
+///
+/// Replays start from the closest state at or below the requested block that is known, the genesis
+/// state or a recently reconstructed one, and replay at most [`Self::limit`] blocks.
+///
+/// The reconstructed state only supports account, storage, bytecode and block hash lookups. State
+/// roots and proofs fail with [`ProviderError::UnsupportedProvider`].
+#[derive(Debug)]
+pub struct PrunedStateReplay {
+    limit: u64,
+    /// Recently reconstructed states by block number, with the hash of the block.
+    states: Mutex<LruMap<BlockNumber, (B256, Arc<StateOverrides>), ByLength>>,
+}
+
+impl PrunedStateReplay {
+    /// Creates a new instance replaying at most `limit` blocks per reconstructed state.
+    pub fn new(limit: u64) -> Self {
+        Self { limit, states: Mutex::new(LruMap::new(ByLength::new(MAX_REPLAYED_STATES))) }
+    }
+
+    /// Returns the maximum number of blocks replayed to reconstruct a state.
+    pub const fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Fails with [`ProviderError::StateAtBlockPruned`] if more than [`Self::limit`] blocks would
+    /// have to be replayed to reconstruct the state after the given block.
+    pub fn ensure_within_limit<P>(
+        &self,
+        provider: &P,
+        block_number: BlockNumber,
+    ) -> Result<(), EthApiError>
+    where
+        P: BlockHashReader + ChainSpecProvider,
+    {
+        let from = match self.closest_state(provider, block_number)? {
+            Some((from, _)) => from,
+            None => provider.chain_spec().genesis().number.unwrap_or_default(),
+        };
+        if block_number.saturating_sub(from) > self.limit {
+            return Err(ProviderError::StateAtBlockPruned(block_number).into())
+        }
+        Ok(())
+    }
+
+    /// Returns the state after the given block, reconstructed by executing the blocks following the
+    /// closest known state.
+    ///
+    /// Fails with [`ProviderError::StateAtBlockPruned`] if more than [`Self::limit`] blocks would
+    /// have to be replayed.
+    pub fn replay<P, Evm>(
+        &self,
+        provider: P,
+        evm_config: &Evm,
+        block_number: BlockNumber,
+    ) -> Result<StateProviderBox, EthApiError>
+    where
+        P: BlockReader<Block = BlockTy<Evm::Primitives>>
+            + ChainSpecProvider
+            + Clone
+            + Sync
+            + 'static,
+        Evm: ConfigureEvm,
+    {
+        let (from, overrides) = match self.closest_state(&provider, block_number)? {
+            Some(state) => state,
+            None => {
+                let genesis = provider.chain_spec().genesis().number.unwrap_or_default();
+                (genesis, genesis_state(provider.clone())?)
+            }
+        };
+        if block_number.saturating_sub(from) > self.limit {
+            return Err(ProviderError::StateAtBlockPruned(block_number).into())
+        }
+
+        let mut state =
+            StateOverrideProvider::with_overrides(BlockHashesOnly(provider.clone()), overrides);
+        if from < block_number {
+            let mut executor = evm_config.batch_executor(StateProviderDatabase::new(&state));
+            for number in from + 1..=block_number {
+                let block = provider
+                    .recovered_block(number.into(), TransactionVariant::WithHash)?
+                    .ok_or_else(|| EthApiError::HeaderNotFound(number.into()))?;
+                executor.execute_one(&block).map_err(|err| EthApiError::Internal(err.into()))?;
+            }
+            let bundle = executor.into_state().take_bundle();
+            apply_bundle(&mut state, &bundle)?;
+
+            if let Some(hash) = provider.block_hash(block_number)? {
+                self.states
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner())
+                    .insert(block_number, (hash, state.overrides().clone()));
+            }
+        }
+
+        Ok(state.boxed())
+    }
+
+    /// Returns the closest reconstructed state at or below the block that is still canonical.
+    fn closest_state<P: BlockHashReader>(
+        &self,
+        provider: &P,
+        block_number: BlockNumber,
+    ) -> ProviderResult<Option<(BlockNumber, Arc<StateOverrides>)>> {
+        let mut states = self.states.lock().unwrap_or_else(|err| err.into_inner());
+        let mut closest = None;
+        for (number, (hash, overrides)) in states.iter() {
+            if *number <= block_number &&
+                closest.as_ref().is_none_or(|(closest, ..)| number > closest)
+            {
+                closest = Some((*number, *hash, overrides.clone()));
+            }
+        }
+        let Some((number, hash, overrides)) = closest else { return Ok(None) };
+
+        // drop states of blocks that were reorged out
+        if provider.block_hash(number)? != Some(hash) {
+            states.remove(&number);
+            return Ok(None)
+        }
+        // mark the state as recently used
+        states.get(&number);
+        Ok(Some((number, overrides)))
+    }
+}
+
+/// Returns the genesis state of the chain as overrides.
+fn genesis_state<P>(provider: P) -> ProviderResult<Arc<StateOverrides>>
+where
+    P: BlockHashReader + ChainSpecProvider + Send + Sync,
+{
+    let chain_spec = provider.chain_spec();
+    let mut state = StateOverrideProvider::new(BlockHashesOnly(provider));
+    for (address, account) in &chain_spec.genesis().alloc {
+        state.set_account(*address, Some(Account::from(account)));
+        if let Some(code) = &account.code {
+            state.set_code(*address, Bytecode::new_raw(code.clone()))?;
+        }
+        for (key, value) in account.storage.iter().flatten() {
+            state.set_storage(*address, *key, U256::from_be_bytes(value.0));
+        }
+    }
+    Ok(state.overrides().clone())
+}
+
+/// Applies the state changes of the bundle as overrides of the state.
+fn apply_bundle<S: StateProvider>(
+    state: &mut StateOverrideProvider<S>,
+    bundle: &BundleState,
+) -> ProviderResult<()> {
+    for (address, account) in &bundle.state {
+        if account.status.is_not_modified() {
+            continue
+        }
+
+        let Some(info) = &account.info else {
+            state.set_account(*address, None);
+            continue
+        };
+
+        let slots = account.storage.iter().map(|(key, slot)| ((*key).into(), slot.present_value));
+        if account.was_destroyed() {
+            state.replace_storage(*address, slots);
+        } else {
+            for (key, value) in slots {
+                state.set_storage(*address, key, value);
+            }
+        }
+
+        state.set_account(
+            *address,
+            Some(Account {
+                nonce: info.nonce,
+                balance: info.balance,
+                bytecode_hash: (!info.is_empty_code_hash()).then_some(info.code_hash),
+            }),
+        );
+        if let Some(code) = bundle.contracts.get(&info.code_hash) {
+            state.set_code(*address, Bytecode(code.clone()))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// A state without any accounts that serves the canonical block hashes of the underlying provider.
+///
+/// Base of the states reconstructed by [`PrunedStateReplay`], which override every account that
+/// exists on top of it.
+#[derive(Debug, Clone)]
+struct BlockHashesOnly<P>(P);
+
+impl<P: BlockHashReader> BlockHashReader for BlockHashesOnly<P> {
+    fn block_hash(&self, number: BlockNumber) -> ProviderResult<Option<B256>> {
+        self.0.block_hash(number)
+    }
+
+    fn canonical_hashes_range(
+        &self,
+        start: BlockNumber,
+        end: BlockNumber,
+    ) -> ProviderResult<Vec<B256>> {
+        self.0.canonical_hashes_range(start, end)
+    }
+}
+
+impl<P: Send + Sync> AccountReader for BlockHashesOnly<P> {
+    fn basic_account(&self, _address: &Address) -> ProviderResult<Option<Account>> {
+        Ok(None)
+    }
+}
+
+impl<P: Send + Sync> BytecodeReader for BlockHashesOnly<P> {
+    fn bytecode_by_hash(&self, _code_hash: &B256) -> ProviderResult<Option<Bytecode>> {
+        Ok(None)
+    }
+}
+
+impl<P: Send + Sync> StateRootProvider for BlockHashesOnly<P> {
+    fn state_root(&self, _state: HashedPostState) -> ProviderResult<B256> {
+        Err(ProviderError::UnsupportedProvider)
+    }
+
+    fn state_root_from_nodes(&self, _input: TrieInput) -> ProviderResult<B256> {
+        Err(ProviderError::UnsupportedProvider)
+    }
+
+    fn state_root_with_updates(
+        &self,
+        _state: HashedPostState,
+    ) -> ProviderResult<(B256, TrieUpdates)> {
+        Err(ProviderError::UnsupportedProvider)
+    }
+
+    fn state_root_from_nodes_with_updates(
+        &self,
+        _input: TrieInput,
+    ) -> ProviderResult<(B256, TrieUpdates)> {
+        Err(ProviderError::UnsupportedProvider)
+    }
+}
+
+impl<P: Send + Sync> StorageRootProvider for BlockHashesOnly<P> {
+    fn storage_root(&self, _address: Address, _storage: HashedStorage) -> ProviderResult<B256> {
+        Err(ProviderError::UnsupportedProvider)
+    }
+
+    fn storage_proof(
+        &self,
+        _address: Address,
+        _slot: B256,
+        _storage: HashedStorage,
+    ) -> ProviderResult<reth_trie::StorageProof> {
+        Err(ProviderError::UnsupportedProvider)
+    }
+
+    fn storage_multiproof(
+        &self,
+        _address: Address,
+        _slots: &[B256],
+        _storage: HashedStorage,
+    ) -> ProviderResult<StorageMultiProof> {
+        Err(ProviderError::UnsupportedProvider)
+    }
+}
+
+impl<P: Send + Sync> StateProofProvider for BlockHashesOnly<P> {
+    fn proof(
+        &self,
+        _input: TrieInput,
+        _address: Address,
+        _slots: &[B256],
+    ) -> ProviderResult<AccountProof> {
+        Err(ProviderError::UnsupportedProvider)
+    }
+
+    fn multiproof(
+        &self,
+        _input: TrieInput,
+        _targets: MultiProofTargets,
+    ) -> ProviderResult<MultiProof> {
+        Err(ProviderError::UnsupportedProvider)
+    }
+
+    fn witness(
+        &self,
+        _input: TrieInput,
+        _target: HashedPostState,
+        _mode: reth_trie::ExecutionWitnessMode,
+    ) -> ProviderResult<Vec<Bytes>> {
+        Err(ProviderError::UnsupportedProvider)
+    }
+}
+
+impl<P: Send + Sync> HashedPostStateProvider for BlockHashesOnly<P> {
+    fn hashed_post_state(&self, bundle_state: &BundleState) -> HashedPostState {
+        HashedPostState::from_bundle_state::<KeccakKeyHasher>(bundle_state.state())
+    }
+}
+
+impl<P: BlockHashReader + Send + Sync> StateProvider for BlockHashesOnly<P> {
+    fn storage(
+        &self,
+        _account: Address,
+        _storage_key: StorageKey,
+    ) -> ProviderResult<Option<StorageValue>> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,114 @@
+//! Applies the state overrides of `eth_call` and related methods to a [`StateOverrideProvider`].
+
+use crate::EthApiError;
+use alloy_primitives::U256;
+use alloy_rpc_types_eth::state::StateOverride;
+use reth_chain_state::StateOverrideProvider;
+use reth_primitives_traits::Bytecode;
+use reth_storage_api::{StateProvider, StateProviderBox};
+
+/// Applies the account overrides to the state.
+///
+/// Moving precompiles with `movePrecompileToAddress` doesn't change the state and is not applied
+/// here, see [`apply_precompile_overrides`](crate::simulate::apply_precompile_overrides).
+pub fn apply_state_overrides<S: StateProvider>(
+    overrides: StateOverride,
+    state: &mut StateOverrideProvider<S>,
+) -> Result<(), EthApiError> {
+    for (address, account) in overrides {
+        if account.state.is_some() && account.state_diff.is_some() {
+            return Err(EthApiError::BothStateAndStateDiffInOverride(address))
+        }
+
+        if let Some(balance) = account.balance {
+            state.set_balance(address, balance)?;
+        }
+        if let Some(nonce) = account.nonce {
+            state.set_nonce(address, nonce)?;
+        }
+        if let Some(code) = account.code {
+            let code = revm::bytecode::Bytecode::new_raw_checked(code)
+                .map_err(|err| EthApiError::InvalidBytecode(err.to_string()))?;
+            state.set_code(address, Bytecode(code))?;
+        }
+        if let Some(slots) = account.state {
+            state.replace_storage(
+                address,
+                slots.into_iter().map(|(key, value)| (key, U256::from_be_bytes(value.0))),
+            );
+        }
+        for (key, value) in account.state_diff.into_iter().flatten() {
+            state.set_storage(address, key, U256::from_be_bytes(value.0));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the state with the given overrides applied on top of it.
+pub fn override_state(
+    state: StateProviderBox,
+    overrides: Option<StateOverride>,
+) -> Result<StateProviderBox, EthApiError> {
+    let Some(overrides) = overrides else { return Ok(state) };
+
+    let mut state = StateOverrideProvider::new(state);
+    apply_state_overrides(overrides, &mut state)?;
+    Ok(state.boxed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, map::B256Map, Bytes, B256};
+    use alloy_rpc_types_eth::state::AccountOverride;
+    use reth_storage_api::{noop::NoopProvider, AccountReader, BytecodeReader};
+
+    #[test]
+    fn apply_account_overrides() {
+        let address = address!("0x00000000000000000000000000000000000000aa");
+        let code = Bytes::from_static(&[0x60, 0x00]);
+        let overrides = StateOverride::from_iter([(
+            address,
+            AccountOverride {
+                balance: Some(U256::from(10)),
+                nonce: Some(2),
+                code: Some(code.clone()),
+                state_diff: Some(B256Map::from_iter([(
+                    B256::with_last_byte(1),
+                    B256::with_last_byte(7),
+                )])),
+                ..Default::default()
+            },
+        )]);
+
+        let mut state = StateOverrideProvider::new(NoopProvider::default());
+        apply_state_overrides(overrides, &mut state).unwrap();
+
+        let account = state.basic_account(&address).unwrap().unwrap();
+        assert_eq!(account.balance, U256::from(10));
+        assert_eq!(account.nonce, 2);
+        let bytecode = state.bytecode_by_hash(&account.bytecode_hash.unwrap()).unwrap().unwrap();
+        assert_eq!(bytecode.original_bytes(), code);
+        assert_eq!(state.storage(address, B256::with_last_byte(1)).unwrap(), Some(U256::from(7)));
+    }
+
+    #[test]
+    fn reject_state_and_state_diff() {
+        let address = address!("0x00000000000000000000000000000000000000aa");
+        let overrides = StateOverride::from_iter([(
+            address,
+            AccountOverride {
+                state: Some(B256Map::default()),
+                state_diff: Some(B256Map::default()),
+                ..Default::default()
+            },
+        )]);
+
+        let mut state = StateOverrideProvider::new(NoopProvider::default());
+        assert!(matches!(
+            apply_state_overrides(overrides, &mut state),
+            Err(EthApiError::BothStateAndStateDiffInOverride(err)) if err == address
+        ));
+    }
+}
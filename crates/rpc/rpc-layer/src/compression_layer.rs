@@ -5,22 +5,47 @@ use std::{
     task::{Context, Poll},
 };
 use tower::{Layer, Service};
-use tower_http::compression::{Compression, CompressionLayer as TowerCompressionLayer};
+use tower_http::compression::{
+    predicate::{And, DefaultPredicate, Predicate, SizeAbove},
+    Compression, CompressionLayer as TowerCompressionLayer,
+};
+
+/// Default minimum size of the compressed responses in bytes, same as [`DefaultPredicate`].
+const DEFAULT_MIN_SIZE: u16 = 32;
+
+/// Predicate of the compressed responses.
+type CompressionPredicate = And<SizeAbove, DefaultPredicate>;
 
 /// This layer is a wrapper around [`tower_http::compression::CompressionLayer`] that integrates
 /// with jsonrpsee's HTTP types. It automatically compresses responses based on the client's
 /// Accept-Encoding header.
+///
+/// Only HTTP responses are compressed: the jsonrpsee server doesn't negotiate the
+/// `permessage-deflate` extension, so WebSocket messages are always sent uncompressed.
 #[expect(missing_debug_implementations)]
 #[derive(Clone)]
 pub struct CompressionLayer {
-    inner_layer: TowerCompressionLayer,
+    inner_layer: TowerCompressionLayer<CompressionPredicate>,
 }
 
 impl CompressionLayer {
     /// Creates a new compression layer with zstd, gzip, brotli and deflate enabled.
     pub fn new() -> Self {
+        Self::with_min_size(DEFAULT_MIN_SIZE)
+    }
+
+    /// Creates a new compression layer with zstd, gzip, brotli and deflate enabled, that only
+    /// compresses responses of at least the given size in bytes.
+    ///
+    /// Compressing small responses costs more CPU time than it saves in transfer time.
+    pub fn with_min_size(min_size: u16) -> Self {
         Self {
-            inner_layer: TowerCompressionLayer::new().gzip(true).br(true).deflate(true).zstd(true),
+            inner_layer: TowerCompressionLayer::new()
+                .gzip(true)
+                .br(true)
+                .deflate(true)
+                .zstd(true)
+                .compress_when(SizeAbove::new(min_size).and(DefaultPredicate::new())),
         }
     }
 }
@@ -47,7 +72,7 @@ impl<S> Layer<S> for CompressionLayer {
 #[expect(missing_debug_implementations)]
 #[derive(Clone)]
 pub struct CompressionService<S> {
-    compression: Compression<S>,
+    compression: Compression<S, CompressionPredicate>,
 }
 
 impl<S> Service<HttpRequest> for CompressionService<S>
@@ -166,4 +191,20 @@ mod tests {
             "Response size ({response_size}) should equal original size ({uncompressed_len})"
         );
     }
+
+    #[tokio::test]
+    async fn test_no_compression_below_min_size() {
+        let uncompressed_len = TEST_DATA.repeat(REPEAT_COUNT).len();
+        let mut service =
+            CompressionLayer::with_min_size(uncompressed_len as u16 + 1).layer(MockRequestService);
+        let request =
+            HttpRequest::builder().header(ACCEPT_ENCODING, "gzip").body(HttpBody::empty()).unwrap();
+
+        let response = service.call(request).await.unwrap();
+        assert!(
+            response.headers().get(CONTENT_ENCODING).is_none(),
+            "Response below the minimum size should not be compressed"
+        );
+        assert_eq!(get_response_size(response).await, uncompressed_len);
+    }
 }
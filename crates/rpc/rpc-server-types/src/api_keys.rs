@@ -0,0 +1,22 @@
+//! API keys of the regular RPC servers.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Name of the HTTP header carrying the API key of a request.
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// API keys of the HTTP and WS servers, and the namespaces they can call.
+///
+/// Namespaces are the method prefixes, e.g. `eth` or `debug`, and `*` allows all namespaces.
+/// Requests with an unknown API key can't call any method.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RpcApiKeys {
+    /// Namespaces that can be called without an API key.
+    #[serde(default)]
+    pub public: Vec<String>,
+    /// Namespaces that can be called with each API key.
+    #[serde(default)]
+    pub keys: HashMap<String, Vec<String>>,
+}
@@ -46,9 +46,26 @@ pub fn default_max_tracing_requests() -> usize {
 /// The default number of getproof calls we are allowing to run concurrently.
 pub const DEFAULT_PROOF_PERMITS: usize = 25;
 
+/// The default number of tasks a single getproof call can split its storage proofs into.
+pub const DEFAULT_PROOF_CONCURRENCY: usize = 4;
+
+/// The default cost of the tracing calls in a batch, see [`crate::RpcMethodCost`].
+///
+/// Other calls cost 1 unless configured otherwise.
+pub const DEFAULT_TRACING_CALL_COST: u64 = 10;
+
+/// The default minimum size of compressed HTTP responses, in bytes.
+pub const DEFAULT_HTTP_COMPRESSION_MIN_SIZE: u16 = 32;
+
 /// The default IPC endpoint
 pub const DEFAULT_IPC_ENDPOINT: &str = "/tmp/reth.ipc";
 
+/// The default initial capacity of the read and write buffers of IPC connections, in bytes.
+pub const DEFAULT_IPC_BUFFER_CAPACITY: usize = 8 * 1024;
+
+/// The default capacity of the queue of outbound messages of a connection, in messages.
+pub const DEFAULT_MESSAGE_BUFFER_CAPACITY: u32 = 1024;
+
 /// The `engine_api` IPC endpoint
 pub const DEFAULT_ENGINE_API_IPC_ENDPOINT: &str = "/tmp/reth_engine_api.ipc";
 
@@ -0,0 +1,137 @@
+//! Mapping of [`RethError`]s onto [EIP-1474](https://eips.ethereum.org/EIPS/eip-1474) JSON-RPC
+//! errors.
+
+use jsonrpsee_types::error::{ErrorObject, INTERNAL_ERROR_CODE};
+use reth_errors::{
+    BlockExecutionError, BlockValidationError, DatabaseError, ProviderError, RethError,
+};
+use serde::Serialize;
+
+/// JSON-RPC error codes defined by EIP-1474, and the non-standard codes used by reth.
+pub mod codes {
+    /// Missing or invalid parameters.
+    pub const INVALID_INPUT: i32 = -32000;
+    /// Requested resource not found.
+    pub const RESOURCE_NOT_FOUND: i32 = -32001;
+    /// Requested resource not available, the request may succeed when retried.
+    pub const RESOURCE_UNAVAILABLE: i32 = -32002;
+    /// Transaction creation failed.
+    pub const TRANSACTION_REJECTED: i32 = -32003;
+    /// Method is not implemented.
+    pub const METHOD_NOT_SUPPORTED: i32 = -32004;
+    /// Request exceeds a defined limit, e.g. a rate limit.
+    pub const LIMIT_EXCEEDED: i32 = -32005;
+    /// Requested historical data has been pruned.
+    pub const PRUNED_HISTORY_UNAVAILABLE: i32 = 4444;
+    /// Requested block access list doesn't exist, because the block is before Amsterdam.
+    pub const BLOCK_ACCESS_LIST_UNAVAILABLE: i32 = 4445;
+}
+
+/// Data attached to JSON-RPC errors created from a [`RethError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RethErrorData {
+    /// The stable reth error code, see [`RethError::error_code`].
+    pub reth_code: u32,
+    /// The name of the [`RethError`] variant.
+    pub variant: &'static str,
+    /// Whether the request may succeed when retried.
+    pub retryable: bool,
+}
+
+impl From<&RethError> for RethErrorData {
+    fn from(error: &RethError) -> Self {
+        Self {
+            reth_code: error.error_code(),
+            variant: error.variant_name(),
+            retryable: error.is_retryable(),
+        }
+    }
+}
+
+/// Returns the JSON-RPC error code for the given [`RethError`].
+pub fn reth_error_code(error: &RethError) -> i32 {
+    match error {
+        RethError::Execution(BlockExecutionError::Validation(
+            BlockValidationError::InvalidTx { .. },
+        )) => codes::TRANSACTION_REJECTED,
+        RethError::Execution(BlockExecutionError::Validation(_)) | RethError::Consensus(_) => {
+            codes::INVALID_INPUT
+        }
+        RethError::Execution(BlockExecutionError::Internal(_)) |
+        RethError::Unwind(_) |
+        RethError::Prune(_) |
+        RethError::Other(_) => INTERNAL_ERROR_CODE,
+        RethError::Database(err) => database_error_code(err),
+        RethError::Provider(err) => provider_error_code(err),
+        RethError::Network(err) if err.is_retryable() => codes::RESOURCE_UNAVAILABLE,
+        RethError::Network(_) => INTERNAL_ERROR_CODE,
+        RethError::WithContext(err) => reth_error_code(&err.error),
+    }
+}
+
+/// Returns the JSON-RPC error code for the given [`ProviderError`].
+pub fn provider_error_code(error: &ProviderError) -> i32 {
+    match error {
+        ProviderError::Database(err) => database_error_code(err),
+        ProviderError::BlockHashNotFound(_) |
+        ProviderError::BlockBodyIndicesNotFound(_) |
+        ProviderError::HeaderNotFound(_) |
+        ProviderError::TransactionNotFound(_) |
+        ProviderError::ReceiptNotFound(_) |
+        ProviderError::BestBlockNotFound |
+        ProviderError::FinalizedBlockNotFound |
+        ProviderError::SafeBlockNotFound |
+        ProviderError::UnknownBlockHash(_) |
+        ProviderError::StateForHashNotFound(_) |
+        ProviderError::StateForNumberNotFound(_) |
+        ProviderError::BlockNumberForTransactionIndexNotFound => codes::RESOURCE_NOT_FOUND,
+        err if err.is_pruned() => codes::PRUNED_HISTORY_UNAVAILABLE,
+        ProviderError::UnsupportedProvider => codes::METHOD_NOT_SUPPORTED,
+        err if err.is_retryable() => codes::RESOURCE_UNAVAILABLE,
+        _ => INTERNAL_ERROR_CODE,
+    }
+}
+
+/// Returns the JSON-RPC error code for the given [`DatabaseError`].
+pub fn database_error_code(error: &DatabaseError) -> i32 {
+    if error.is_retryable() {
+        codes::RESOURCE_UNAVAILABLE
+    } else {
+        INTERNAL_ERROR_CODE
+    }
+}
+
+/// Converts the given [`RethError`] into a JSON-RPC error object.
+///
+/// The code is chosen according to EIP-1474, the data carries the [`RethErrorData`].
+pub fn reth_rpc_err(error: &RethError) -> ErrorObject<'static> {
+    ErrorObject::owned(reth_error_code(error), error.to_string(), Some(RethErrorData::from(error)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_errors::{ConsensusError, ErrorContext};
+
+    #[test]
+    fn maps_error_codes() {
+        let not_found = RethError::from(ProviderError::BestBlockNotFound);
+        assert_eq!(reth_error_code(&not_found), codes::RESOURCE_NOT_FOUND);
+
+        let pruned = RethError::from(ProviderError::StateAtBlockPruned(1))
+            .with_context(ErrorContext::new().with_block_number(1));
+        assert_eq!(reth_error_code(&pruned), codes::PRUNED_HISTORY_UNAVAILABLE);
+
+        let invalid = RethError::from(ConsensusError::BaseFeeMissing);
+        assert_eq!(reth_error_code(&invalid), codes::INVALID_INPUT);
+
+        let err = reth_rpc_err(&RethError::msg("boom"));
+        assert_eq!(err.code(), INTERNAL_ERROR_CODE);
+        assert_eq!(err.message(), "boom");
+        assert_eq!(
+            err.data().unwrap().get(),
+            r#"{"rethCode":9000,"variant":"Other","retryable":false}"#
+        );
+    }
+}
@@ -8,8 +8,12 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
+pub mod api_keys;
 /// Common RPC constants.
 pub mod constants;
+pub mod error;
+pub mod limits;
+pub mod rate_limit;
 pub mod result;
 
 mod module;
@@ -18,4 +22,7 @@ pub use module::{
     RpcModuleValidator,
 };
 
+pub use api_keys::RpcApiKeys;
+pub use limits::{RpcServerLimitOverrides, RpcServerLimits};
+pub use rate_limit::{RpcMethodCost, RpcMethodRateLimit};
 pub use result::ToRpcResult;
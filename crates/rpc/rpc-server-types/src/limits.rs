@@ -0,0 +1,96 @@
+//! Server limits adjustable at runtime.
+
+use crate::RpcMethodRateLimit;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+
+/// Shared handle to the overrides of the server limits, set at runtime with the `admin_`
+/// namespace.
+///
+/// The components enforcing a limit read its override on use and fall back to the configured
+/// limit if it isn't overridden. Overrides are kept in memory until the node is restarted.
+#[derive(Debug, Clone, Default)]
+pub struct RpcServerLimits {
+    inner: Arc<RwLock<RpcServerLimitsInner>>,
+}
+
+impl RpcServerLimits {
+    /// Returns the current overrides.
+    pub fn overrides(&self) -> RpcServerLimitOverrides {
+        let inner = self.inner.read().unwrap_or_else(|err| err.into_inner());
+        RpcServerLimitOverrides {
+            max_connections: inner.max_connections,
+            max_tracing_requests: inner.max_tracing_requests,
+            gas_cap: inner.gas_cap,
+            rate_limits: inner.rate_limits.as_deref().map(<[_]>::to_vec),
+        }
+    }
+
+    /// Returns the overridden maximum number of connections.
+    pub fn max_connections(&self) -> Option<u32> {
+        self.inner.read().unwrap_or_else(|err| err.into_inner()).max_connections
+    }
+
+    /// Returns the overridden maximum number of concurrent tracing requests.
+    pub fn max_tracing_requests(&self) -> Option<usize> {
+        self.inner.read().unwrap_or_else(|err| err.into_inner()).max_tracing_requests
+    }
+
+    /// Returns the overridden gas cap of `eth_call` and related methods.
+    pub fn gas_cap(&self) -> Option<u64> {
+        self.inner.read().unwrap_or_else(|err| err.into_inner()).gas_cap
+    }
+
+    /// Returns the overridden per-method rate limits.
+    pub fn rate_limits(&self) -> Option<Arc<[RpcMethodRateLimit]>> {
+        self.inner.read().unwrap_or_else(|err| err.into_inner()).rate_limits.clone()
+    }
+
+    /// Overrides the maximum number of connections.
+    pub fn set_max_connections(&self, max_connections: u32) {
+        self.update(|inner| inner.max_connections = Some(max_connections))
+    }
+
+    /// Overrides the maximum number of concurrent tracing requests.
+    pub fn set_max_tracing_requests(&self, max_tracing_requests: usize) {
+        self.update(|inner| inner.max_tracing_requests = Some(max_tracing_requests))
+    }
+
+    /// Overrides the gas cap of `eth_call` and related methods.
+    pub fn set_gas_cap(&self, gas_cap: u64) {
+        self.update(|inner| inner.gas_cap = Some(gas_cap))
+    }
+
+    /// Overrides the per-method rate limits, replacing all configured limits.
+    pub fn set_rate_limits(&self, rate_limits: impl Into<Arc<[RpcMethodRateLimit]>>) {
+        let rate_limits = rate_limits.into();
+        self.update(|inner| inner.rate_limits = Some(rate_limits))
+    }
+
+    fn update(&self, f: impl FnOnce(&mut RpcServerLimitsInner)) {
+        f(&mut self.inner.write().unwrap_or_else(|err| err.into_inner()))
+    }
+}
+
+#[derive(Debug, Default)]
+struct RpcServerLimitsInner {
+    max_connections: Option<u32>,
+    max_tracing_requests: Option<usize>,
+    gas_cap: Option<u64>,
+    /// Shared with the rate limiters, which detect a new override by pointer
+    rate_limits: Option<Arc<[RpcMethodRateLimit]>>,
+}
+
+/// Server limits overridden at runtime, unset limits use their configured value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcServerLimitOverrides {
+    /// Maximum number of concurrent HTTP and WS connections.
+    pub max_connections: Option<u32>,
+    /// Maximum number of concurrent tracing requests.
+    pub max_tracing_requests: Option<usize>,
+    /// Gas cap of `eth_call` and related methods.
+    pub gas_cap: Option<u64>,
+    /// Per-method rate limits, replacing all configured limits.
+    pub rate_limits: Option<Vec<RpcMethodRateLimit>>,
+}
@@ -325,6 +325,8 @@ pub enum RethRpcModule {
     Mev,
     /// `testing_` module
     Testing,
+    /// `erigon_` module
+    Erigon,
     /// Custom RPC module not part of the standard set
     #[strum(default)]
     #[serde(untagged)]
@@ -350,6 +352,7 @@ impl RethRpcModule {
         Self::Miner,
         Self::Mev,
         Self::Testing,
+        Self::Erigon,
     ];
 
     /// Returns the number of standard variants (excludes Other)
@@ -410,6 +413,7 @@ impl AsRef<str> for RethRpcModule {
             Self::Miner => "miner",
             Self::Mev => "mev",
             Self::Testing => "testing",
+            Self::Erigon => "erigon",
         }
     }
 }
@@ -433,6 +437,7 @@ impl FromStr for RethRpcModule {
             "miner" => Self::Miner,
             "mev" => Self::Mev,
             "testing" => Self::Testing,
+            "erigon" => Self::Erigon,
             // Any unknown module becomes Other
             other => Self::Other(other.to_string()),
         })
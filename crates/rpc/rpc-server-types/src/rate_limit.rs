@@ -0,0 +1,182 @@
+//! Per-method RPC rate limits and call costs.
+
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+/// Token bucket rate limit of the RPC methods matching a pattern.
+///
+/// Parsed from `<method>=<rate>[/<burst>]`, where the method can end with `*` to match all methods
+/// starting with the prefix, e.g. `debug_*=5/10`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RpcMethodRateLimit {
+    /// Name of the limited method, or prefix of the limited methods if it ends with `*`.
+    pub method: String,
+    /// Number of calls the bucket is refilled with per second, a positive finite number.
+    pub per_second: f64,
+    /// Maximum number of calls that can be made at once.
+    ///
+    /// Defaults to the rate, rounded up.
+    pub burst: u32,
+}
+
+impl RpcMethodRateLimit {
+    /// Returns `true` if the limit applies to the method.
+    pub fn matches(&self, method: &str) -> bool {
+        matches_method(&self.method, method)
+    }
+}
+
+// the rate is never NaN, it's a positive finite number
+impl Eq for RpcMethodRateLimit {}
+
+impl FromStr for RpcMethodRateLimit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (method, limit) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected <method>=<rate>[/<burst>], got '{s}'"))?;
+        let method = parse_method_pattern(method)?;
+
+        let (per_second, burst) = match limit.split_once('/') {
+            Some((per_second, burst)) => (per_second, Some(burst)),
+            None => (limit, None),
+        };
+        let per_second = per_second
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|per_second| per_second.is_finite() && *per_second > 0.0)
+            .ok_or_else(|| format!("invalid rate '{per_second}', expected calls per second"))?;
+        let burst = match burst {
+            Some(burst) => burst
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|burst| *burst > 0)
+                .ok_or_else(|| format!("invalid burst '{burst}'"))?,
+            None => per_second.ceil() as u32,
+        };
+
+        Ok(Self { method, per_second, burst })
+    }
+}
+
+impl fmt::Display for RpcMethodRateLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}/{}", self.method, self.per_second, self.burst)
+    }
+}
+
+impl Serialize for RpcMethodRateLimit {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for RpcMethodRateLimit {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+    }
+}
+
+/// Cost of the RPC methods matching a pattern, counted against the cost budget of a batch.
+///
+/// Parsed from `<method>=<cost>`, where the method can end with `*` to match all methods starting
+/// with the prefix, e.g. `debug_trace*=10`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcMethodCost {
+    /// Name of the method, or prefix of the methods if it ends with `*`.
+    pub method: String,
+    /// Cost of a call.
+    pub cost: u64,
+}
+
+impl RpcMethodCost {
+    /// Creates a new cost of the methods matching the pattern.
+    pub fn new(method: impl Into<String>, cost: u64) -> Self {
+        Self { method: method.into(), cost }
+    }
+
+    /// Returns `true` if the cost applies to the method.
+    pub fn matches(&self, method: &str) -> bool {
+        matches_method(&self.method, method)
+    }
+}
+
+impl FromStr for RpcMethodCost {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (method, cost) =
+            s.split_once('=').ok_or_else(|| format!("expected <method>=<cost>, got '{s}'"))?;
+        let method = parse_method_pattern(method)?;
+        let cost = cost.trim().parse::<u64>().map_err(|_| format!("invalid cost '{cost}'"))?;
+        Ok(Self { method, cost })
+    }
+}
+
+impl fmt::Display for RpcMethodCost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.method, self.cost)
+    }
+}
+
+/// Returns `true` if the method matches the pattern, a method name or a prefix ending with `*`.
+fn matches_method(pattern: &str, method: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => method.starts_with(prefix),
+        None => pattern == method,
+    }
+}
+
+/// Parses a method pattern, which can only contain `*` at the end.
+fn parse_method_pattern(method: &str) -> Result<String, String> {
+    let method = method.trim();
+    if method.is_empty() || method[..method.len() - 1].contains('*') {
+        return Err(format!("invalid method pattern '{method}'"))
+    }
+    Ok(method.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rate_limit() {
+        let limit: RpcMethodRateLimit = "debug_traceBlockByNumber=2.5".parse().unwrap();
+        assert_eq!(limit.method, "debug_traceBlockByNumber");
+        assert_eq!(limit.per_second, 2.5);
+        assert_eq!(limit.burst, 3);
+        assert!(limit.matches("debug_traceBlockByNumber"));
+        assert!(!limit.matches("debug_traceBlockByHash"));
+
+        let limit: RpcMethodRateLimit = "debug_*=5/10".parse().unwrap();
+        assert_eq!(limit.burst, 10);
+        assert!(limit.matches("debug_traceBlockByHash"));
+        assert!(!limit.matches("eth_call"));
+        assert_eq!(limit.to_string().parse::<RpcMethodRateLimit>().unwrap(), limit);
+        assert_eq!(serde_json::to_string(&limit).unwrap(), r#""debug_*=5/10""#);
+        assert_eq!(serde_json::from_str::<RpcMethodRateLimit>(r#""debug_*=5/10""#).unwrap(), limit);
+
+        assert!("eth_call".parse::<RpcMethodRateLimit>().is_err());
+        assert!("eth_call=0".parse::<RpcMethodRateLimit>().is_err());
+        assert!("eth_call=1/0".parse::<RpcMethodRateLimit>().is_err());
+        assert!("=1".parse::<RpcMethodRateLimit>().is_err());
+        assert!("*_call=1".parse::<RpcMethodRateLimit>().is_err());
+    }
+
+    #[test]
+    fn parse_method_cost() {
+        let cost: RpcMethodCost = "debug_trace*=10".parse().unwrap();
+        assert_eq!(cost, RpcMethodCost::new("debug_trace*", 10));
+        assert!(cost.matches("debug_traceTransaction"));
+        assert!(!cost.matches("debug_getRawBlock"));
+        assert_eq!(cost.to_string().parse::<RpcMethodCost>().unwrap(), cost);
+
+        assert!("eth_call".parse::<RpcMethodCost>().is_err());
+        assert!("eth_call=-1".parse::<RpcMethodCost>().is_err());
+        assert!("*_call=1".parse::<RpcMethodCost>().is_err());
+    }
+}
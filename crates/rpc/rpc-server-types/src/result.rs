@@ -45,10 +45,26 @@ pub trait ToRpcResult<Ok, Err>: Sized {
 }
 
 /// A macro that implements the `ToRpcResult` for a specific error type
+///
+/// By default [`ToRpcResult::to_rpc_result`] returns an internal error, an optional function
+/// converting the error into a [`jsonrpsee_types::error::ErrorObject`] overrides it.
 #[macro_export]
 macro_rules! impl_to_rpc_result {
     ($err:ty) => {
+        $crate::impl_to_rpc_result!(@impl $err, {});
+    };
+    ($err:ty, $to_rpc_err:expr) => {
+        $crate::impl_to_rpc_result!(@impl $err, {
+            #[inline]
+            fn to_rpc_result(self) -> jsonrpsee_core::RpcResult<Ok> {
+                self.map_err($to_rpc_err)
+            }
+        });
+    };
+    (@impl $err:ty, { $($to_rpc_result:tt)* }) => {
         impl<Ok> ToRpcResult<Ok, $err> for Result<Ok, $err> {
+            $($to_rpc_result)*
+
             #[inline]
             fn map_rpc_err<'a, F, M>(self, op: F) -> jsonrpsee_core::RpcResult<Ok>
             where
@@ -104,8 +120,8 @@ macro_rules! impl_to_rpc_result {
 
 impl_to_rpc_result!(PayloadError);
 impl_to_rpc_result!(ConsensusError);
-impl_to_rpc_result!(reth_errors::RethError);
-impl_to_rpc_result!(reth_errors::ProviderError);
+impl_to_rpc_result!(reth_errors::RethError, |err| crate::error::reth_rpc_err(&err));
+impl_to_rpc_result!(reth_errors::ProviderError, |err| crate::error::reth_rpc_err(&err.into()));
 impl_to_rpc_result!(reth_network_api::NetworkError);
 
 /// Constructs an invalid params JSON-RPC error.
@@ -169,7 +185,8 @@ pub fn block_id_to_str(id: BlockId) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use reth_errors::{RethError, RethResult};
+    use crate::error::codes;
+    use reth_errors::{ProviderError, ProviderResult, RethError, RethResult};
 
     const fn assert_rpc_result<T, E, TRR: ToRpcResult<T, E>>() {}
 
@@ -182,4 +199,19 @@ mod tests {
         let val = rpc_res.unwrap();
         assert_eq!(val, 100);
     }
+
+    #[test]
+    fn maps_reth_errors() {
+        let res = ProviderResult::<()>::Err(ProviderError::StateAtBlockPruned(1));
+        assert_eq!(res.to_rpc_result().unwrap_err().code(), codes::PRUNED_HISTORY_UNAVAILABLE);
+
+        let res = RethResult::<()>::Err(ProviderError::BestBlockNotFound.into());
+        assert_eq!(res.to_rpc_result().unwrap_err().code(), codes::RESOURCE_NOT_FOUND);
+
+        let res = Result::<(), _>::Err(ConsensusError::BaseFeeMissing);
+        assert_eq!(
+            res.to_rpc_result().unwrap_err().code(),
+            jsonrpsee_types::error::INTERNAL_ERROR_CODE
+        );
+    }
 }
@@ -11,9 +11,14 @@ use jsonrpsee::core::RpcResult;
 use reth_chainspec::{EthChainSpec, EthereumHardfork, EthereumHardforks, ForkCondition};
 use reth_network_api::{NetworkInfo, Peers};
 use reth_network_peers::{AnyNode, NodeRecord};
-use reth_network_types::PeerKind;
-use reth_rpc_api::AdminApiServer;
-use reth_rpc_server_types::ToRpcResult;
+use reth_network_types::{PeerKind, ReputationChangeKind};
+use reth_rpc_api::{AdminApiServer, AdminPipelineApiServer};
+use reth_rpc_server_types::{
+    result::{internal_rpc_err, invalid_params_rpc_err},
+    RpcMethodRateLimit, RpcServerLimitOverrides, RpcServerLimits, ToRpcResult,
+};
+use reth_stages_api::PipelineHandle;
+use reth_tasks::pool::BlockingTaskGuard;
 use reth_transaction_pool::TransactionPool;
 
 /// `admin` API implementation.
@@ -26,12 +31,35 @@ pub struct AdminApi<N, ChainSpec, Pool> {
     chain_spec: Arc<ChainSpec>,
     /// The transaction pool
     pool: Pool,
+    /// Server limits adjustable at runtime, if any
+    server_limits: Option<RpcServerLimits>,
+    /// Guard limiting the concurrent tracing requests
+    tracing_guard: Option<BlockingTaskGuard>,
 }
 
 impl<N, ChainSpec, Pool> AdminApi<N, ChainSpec, Pool> {
     /// Creates a new instance of `AdminApi`.
     pub const fn new(network: N, chain_spec: Arc<ChainSpec>, pool: Pool) -> Self {
-        Self { network, chain_spec, pool }
+        Self { network, chain_spec, pool, server_limits: None, tracing_guard: None }
+    }
+
+    /// Allows adjusting the server limits at runtime, where the tracing guard limits the
+    /// concurrent tracing requests.
+    pub fn with_server_limits(
+        mut self,
+        server_limits: RpcServerLimits,
+        tracing_guard: BlockingTaskGuard,
+    ) -> Self {
+        self.server_limits = Some(server_limits);
+        self.tracing_guard = Some(tracing_guard);
+        self
+    }
+
+    /// Returns the server limits, or an error if they aren't adjustable.
+    fn adjustable_server_limits(&self) -> RpcResult<&RpcServerLimits> {
+        self.server_limits
+            .as_ref()
+            .ok_or_else(|| internal_rpc_err("server limits are not adjustable at runtime"))
     }
 }
 
@@ -89,6 +117,23 @@ where
         Ok(true)
     }
 
+    /// Handler for `admin_peerReputation`
+    async fn peer_reputation(&self, record: AnyNode) -> RpcResult<Option<i32>> {
+        self.network.reputation_by_id(record.peer_id()).await.to_rpc_result()
+    }
+
+    /// Handler for `admin_changePeerReputation`
+    fn change_peer_reputation(&self, record: AnyNode, change: i32) -> RpcResult<bool> {
+        self.network.reputation_change(record.peer_id(), ReputationChangeKind::Other(change));
+        Ok(true)
+    }
+
+    /// Handler for `admin_resetPeerReputation`
+    fn reset_peer_reputation(&self, record: AnyNode) -> RpcResult<bool> {
+        self.network.reputation_change(record.peer_id(), ReputationChangeKind::Reset);
+        Ok(true)
+    }
+
     /// Handler for `admin_peers`
     async fn peers(&self) -> RpcResult<Vec<PeerInfo>> {
         let peers = self.network.get_all_peers().await.to_rpc_result()?;
@@ -211,6 +256,45 @@ where
         let _ = self.pool.remove_transactions(all_hashes);
         Ok(count)
     }
+
+    /// Handler for `admin_serverLimits`
+    fn server_limits(&self) -> RpcResult<RpcServerLimitOverrides> {
+        Ok(self.adjustable_server_limits()?.overrides())
+    }
+
+    /// Handler for `admin_setMaxConnections`
+    fn set_max_connections(&self, max_connections: u32) -> RpcResult<bool> {
+        if max_connections == 0 {
+            return Err(invalid_params_rpc_err("max connections must be positive"))
+        }
+        self.adjustable_server_limits()?.set_max_connections(max_connections);
+        Ok(true)
+    }
+
+    /// Handler for `admin_setMaxTracingRequests`
+    fn set_max_tracing_requests(&self, max_tracing_requests: usize) -> RpcResult<bool> {
+        if max_tracing_requests == 0 {
+            return Err(invalid_params_rpc_err("max tracing requests must be positive"))
+        }
+        let server_limits = self.adjustable_server_limits()?;
+        if let Some(tracing_guard) = &self.tracing_guard {
+            tracing_guard.set_max_blocking_tasks(max_tracing_requests);
+        }
+        server_limits.set_max_tracing_requests(max_tracing_requests);
+        Ok(true)
+    }
+
+    /// Handler for `admin_setGasCap`
+    fn set_gas_cap(&self, gas_cap: u64) -> RpcResult<bool> {
+        self.adjustable_server_limits()?.set_gas_cap(gas_cap);
+        Ok(true)
+    }
+
+    /// Handler for `admin_setRateLimits`
+    fn set_rate_limits(&self, rate_limits: Vec<RpcMethodRateLimit>) -> RpcResult<bool> {
+        self.adjustable_server_limits()?.set_rate_limits(rate_limits);
+        Ok(true)
+    }
 }
 
 impl<N, ChainSpec, Pool> std::fmt::Debug for AdminApi<N, ChainSpec, Pool> {
@@ -218,3 +302,37 @@ impl<N, ChainSpec, Pool> std::fmt::Debug for AdminApi<N, ChainSpec, Pool> {
         f.debug_struct("AdminApi").finish_non_exhaustive()
     }
 }
+
+/// `admin` API implementation to control the sync pipeline.
+#[derive(Debug, Clone)]
+pub struct AdminPipelineApi {
+    /// Handle to the sync pipeline
+    pipeline: PipelineHandle,
+}
+
+impl AdminPipelineApi {
+    /// Creates a new instance of `AdminPipelineApi` controlling the pipeline of the given handle.
+    pub const fn new(pipeline: PipelineHandle) -> Self {
+        Self { pipeline }
+    }
+}
+
+impl AdminPipelineApiServer for AdminPipelineApi {
+    /// Handler for `admin_pausePipeline`
+    fn pause_pipeline(&self) -> RpcResult<bool> {
+        self.pipeline.pause();
+        Ok(true)
+    }
+
+    /// Handler for `admin_resumePipeline`
+    fn resume_pipeline(&self) -> RpcResult<bool> {
+        self.pipeline.resume();
+        Ok(true)
+    }
+
+    /// Handler for `admin_abortPipeline`
+    fn abort_pipeline(&self) -> RpcResult<bool> {
+        self.pipeline.abort();
+        Ok(true)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::{eth::pubsub::pipe_from_stream, NativeTracer, NativeTracers};
 use alloy_consensus::{constants::KECCAK_EMPTY, transaction::TxHashRef, BlockHeader};
 use alloy_eips::{eip2718::Encodable2718, BlockId, BlockNumberOrTag};
 use alloy_evm::{env::BlockEnvironment, Evm};
@@ -10,27 +11,32 @@ use alloy_rpc_types_eth::{
     state::EvmOverrides, Account, AccountInfo, BlockError, Bundle, Index, StateContext,
 };
 use alloy_rpc_types_trace::geth::{
-    BlockTraceResult, GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace, TraceResult,
+    BlockTraceResult, GethDebugTracerConfig, GethDebugTracerType, GethDebugTracingCallOptions,
+    GethDebugTracingOptions, GethTrace, TraceResult,
 };
 use async_trait::async_trait;
-use futures::Stream;
-use jsonrpsee::core::RpcResult;
+use futures::{channel::mpsc, Stream};
+use jsonrpsee::{core::RpcResult, PendingSubscriptionSink, SubscriptionSink};
 use parking_lot::RwLock;
 use reth_chainspec::{ChainSpecProvider, EthChainSpec, EthereumHardforks};
 use reth_engine_primitives::ConsensusEngineEvent;
 use reth_errors::RethError;
-use reth_evm::{block::BlockExecutor, execute::Executor, ConfigureEvm, EvmEnvFor};
+use reth_evm::{
+    block::BlockExecutor, execute::Executor, ConfigureEvm, EvmEnvFor, HaltReasonFor, TxEnvFor,
+};
 use reth_primitives_traits::{
     Block as BlockTrait, BlockBody, BlockTy, ReceiptWithBloom, RecoveredBlock,
 };
-use reth_revm::{db::State, witness::ExecutionWitnessRecord};
+use reth_revm::{database::StateProviderDatabase, db::State, witness::StateWitnessProvider};
 use reth_rpc_api::DebugApiServer;
 use reth_rpc_convert::RpcTxReq;
 use reth_rpc_eth_api::{
     helpers::{EthTransactions, TraceExt},
     FromEthApiError, FromEvmError, RpcConvert, RpcNodeCore,
 };
-use reth_rpc_eth_types::{EthApiError, StateCacheDb};
+use reth_rpc_eth_types::{
+    cache::db::StateProviderTraitObjWrapper, EthApiError, StateCacheDb, TracingCancellation,
+};
 use reth_rpc_server_types::{result::internal_rpc_err, ToRpcResult};
 use reth_storage_api::{
     BlockIdReader, BlockReaderIdExt, HashedPostStateProvider, HeaderProvider, ProviderBlock,
@@ -42,18 +48,41 @@ use reth_transaction_pool::TransactionPool;
 use reth_trie_common::{
     updates::TrieUpdates, ExecutionWitnessMode, HashedPostState, HashedStorage,
 };
-use revm::{database::states::bundle_state::BundleRetention, Database, DatabaseCommit};
+use revm::{
+    context_interface::result::ResultAndState, database::states::bundle_state::BundleRetention,
+    Database, DatabaseCommit,
+};
 use revm_inspectors::tracing::{DebugInspector, TransactionContext};
 use serde::{Deserialize, Serialize};
-use std::{collections::VecDeque, sync::Arc};
+use std::{collections::VecDeque, future::Future, sync::Arc, time::Duration};
 use tokio::sync::{AcquireError, OwnedSemaphorePermit};
 use tokio_stream::StreamExt;
 
+/// Number of block traces buffered by `debug_subscribeTraceBlock` before tracing waits for the
+/// client to receive them.
+const TRACE_STREAM_BUFFER: usize = 16;
+
+/// How long `debug_subscribeTraceBlock` waits for the client to receive a trace once
+/// [`TRACE_STREAM_BUFFER`] traces are pending, before tracing is cancelled.
+const TRACE_STREAM_SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Name of the `debug_traceCall` tracer returning the block access list of the call.
+const BLOCK_ACCESS_LIST_TRACER: &str = "blockAccessList";
+
 /// `debug` API implementation.
 ///
 /// This type provides the functionality for handling `debug` related requests.
+///
+/// Besides the built-in tracers, the `debug_trace*` methods can use the [`NativeTracer`]s
+/// registered with [`DebugApi::with_native_tracer`].
 pub struct DebugApi<Eth: RpcNodeCore> {
     inner: Arc<DebugApiInner<Eth>>,
+    /// Maximum duration of the execution of tracing calls, if any
+    tracing_timeout: Option<Duration>,
+    /// Tracers selectable by name besides the built-in tracers
+    native_tracers: NativeTracers<Eth>,
+    /// Maximum number of threads the trie nodes of an execution witness are computed with
+    witness_concurrency: usize,
 }
 
 impl<Eth> DebugApi<Eth>
@@ -72,6 +101,7 @@ where
             eth_api,
             blocking_task_guard,
             bad_block_store: bad_block_store.clone(),
+            task_spawner: executor.clone(),
         });
 
         // Spawn a task caching bad blocks
@@ -85,7 +115,47 @@ where
             }
         });
 
-        Self { inner }
+        Self {
+            inner,
+            tracing_timeout: None,
+            native_tracers: NativeTracers::default(),
+            witness_concurrency: 1,
+        }
+    }
+
+    /// Aborts the execution of tracing calls once the timeout elapsed.
+    pub const fn with_tracing_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.tracing_timeout = timeout;
+        self
+    }
+
+    /// Computes the trie nodes of execution witnesses with up to `concurrency` threads.
+    pub const fn with_witness_concurrency(mut self, concurrency: usize) -> Self {
+        self.witness_concurrency = concurrency;
+        self
+    }
+
+    /// Registers a native tracer, selectable by name with the `tracer` field of the `debug_trace*`
+    /// requests.
+    pub fn with_native_tracer(
+        mut self,
+        name: impl Into<String>,
+        tracer: impl NativeTracer<Eth>,
+    ) -> Self {
+        self.native_tracers.register(name, tracer);
+        self
+    }
+
+    /// Sets the registry of native tracers, replacing the registered tracers.
+    pub fn with_native_tracers(mut self, native_tracers: NativeTracers<Eth>) -> Self {
+        self.native_tracers = native_tracers;
+        self
+    }
+
+    /// Runs the tracing call, cancelling its execution once the tracing timeout elapsed or the
+    /// call is dropped, e.g. because the client disconnected.
+    async fn traced<R>(&self, call: impl Future<Output = R>) -> R {
+        TracingCancellation::new(self.tracing_timeout).instrument(call).await
     }
 
     /// Access the underlying `Eth` API.
@@ -117,52 +187,103 @@ where
         evm_env: EvmEnvFor<Eth::Evm>,
         opts: GethDebugTracingOptions,
     ) -> Result<Vec<TraceResult>, Eth::Error> {
+        let results = Vec::with_capacity(block.body().transactions().len());
+        self.trace_block_with(block, evm_env, opts, results, |results, trace| {
+            results.push(trace);
+            Ok(())
+        })
+        .await
+    }
+
+    /// Traces the transactions of the block, passing the trace of each transaction to the closure
+    /// together with the accumulator once it's executed.
+    async fn trace_block_with<A>(
+        &self,
+        block: Arc<RecoveredBlock<ProviderBlock<Eth::Provider>>>,
+        evm_env: EvmEnvFor<Eth::Evm>,
+        opts: GethDebugTracingOptions,
+        mut acc: A,
+        mut on_trace: impl FnMut(&mut A, TraceResult) -> Result<(), Eth::Error> + Send + 'static,
+    ) -> Result<A, Eth::Error>
+    where
+        A: Send + 'static,
+    {
+        let native_tracer = self.native_tracers.get(&opts);
         self.eth_api()
             .spawn_with_state_at_block(block.parent_hash(), move |eth_api, mut db| {
-                let mut results = Vec::with_capacity(block.body().transactions().len());
-
                 eth_api.apply_pre_execution_changes(&block, &mut db)?;
 
                 let mut transactions = block.transactions_recovered().enumerate().peekable();
-                let mut inspector = DebugInspector::new(opts).map_err(Eth::Error::from_eth_err)?;
+                let mut tracer = CallTracer::new(native_tracer, opts)?;
                 while let Some((index, tx)) = transactions.next() {
+                    TracingCancellation::check_current_progress(
+                        index,
+                        block.body().transactions().len(),
+                    )
+                    .map_err(Eth::Error::from_eth_err)?;
                     let tx_hash = *tx.tx_hash();
                     let tx_env = eth_api.evm_config().tx_env(tx);
 
-                    let res = eth_api.inspect(
+                    let (result, res) = tracer.trace(
+                        &eth_api,
                         &mut db,
-                        evm_env.clone(),
-                        tx_env.clone(),
-                        &mut inspector,
+                        &evm_env,
+                        tx_env,
+                        Some(TransactionContext {
+                            block_hash: Some(block.hash()),
+                            tx_hash: Some(tx_hash),
+                            tx_index: Some(index),
+                        }),
                     )?;
-                    let result = inspector
-                        .get_result(
-                            Some(TransactionContext {
-                                block_hash: Some(block.hash()),
-                                tx_hash: Some(tx_hash),
-                                tx_index: Some(index),
-                            }),
-                            &tx_env,
-                            &evm_env.block_env,
-                            &res,
-                            &mut db,
-                        )
-                        .map_err(Eth::Error::from_eth_err)?;
-
-                    results.push(TraceResult::Success { result, tx_hash: Some(tx_hash) });
+
+                    on_trace(&mut acc, TraceResult::Success { result, tx_hash: Some(tx_hash) })?;
                     if transactions.peek().is_some() {
-                        inspector.fuse().map_err(Eth::Error::from_eth_err)?;
+                        tracer.fuse()?;
                         // need to apply the state changes of this transaction before executing the
                         // next transaction
                         db.commit(res.state)
                     }
                 }
 
-                Ok(results)
+                Ok(acc)
             })
             .await
     }
 
+    /// Replays the block and streams the trace of each transaction to the subscription once it's
+    /// executed, closing the subscription after the last transaction.
+    ///
+    /// Tracing waits for the client to receive the traces once [`TRACE_STREAM_BUFFER`] traces are
+    /// pending, so the traces of the block aren't buffered whole. Tracing stops if the client
+    /// unsubscribes or disconnects, or doesn't receive a trace within
+    /// [`TRACE_STREAM_SEND_TIMEOUT`], releasing the tracing permit.
+    async fn stream_trace_block(
+        &self,
+        sink: SubscriptionSink,
+        block: Arc<RecoveredBlock<ProviderBlock<Eth::Provider>>>,
+        evm_env: EvmEnvFor<Eth::Evm>,
+        opts: GethDebugTracingOptions,
+    ) {
+        let (sender, receiver) = mpsc::channel(TRACE_STREAM_BUFFER);
+        let trace = async {
+            let _permit = self.acquire_trace_permit().await;
+            self.traced(self.trace_block_with(block, evm_env, opts, sender, |sender, trace| {
+                // runs on a blocking task
+                if send_with_timeout(sender, trace, TRACE_STREAM_SEND_TIMEOUT) {
+                    Ok(())
+                } else {
+                    Err(Eth::Error::from_eth_err(EthApiError::ExecutionCancelled))
+                }
+            }))
+            .await
+        };
+
+        let (res, _) = futures::join!(trace, pipe_from_stream(sink, receiver));
+        if let Err(err) = res {
+            tracing::debug!(target: "rpc::debug", %err, "Failed to stream block traces");
+        }
+    }
+
     /// Replays the given block and returns the trace of each transaction.
     ///
     /// This expects a rlp encoded block
@@ -230,6 +351,7 @@ where
         // block the transaction is included in
         let state_at: BlockId = block.parent_hash().into();
         let block_hash = block.hash();
+        let native_tracer = self.native_tracers.get(&opts);
 
         self.eth_api()
             .spawn_with_state_at_block(state_at, move |eth_api, mut db| {
@@ -250,22 +372,18 @@ where
 
                 let tx_env = eth_api.evm_config().tx_env(&tx);
 
-                let mut inspector = DebugInspector::new(opts).map_err(Eth::Error::from_eth_err)?;
-                let res =
-                    eth_api.inspect(&mut db, evm_env.clone(), tx_env.clone(), &mut inspector)?;
-                let trace = inspector
-                    .get_result(
-                        Some(TransactionContext {
-                            block_hash: Some(block_hash),
-                            tx_index: Some(index),
-                            tx_hash: Some(*tx.tx_hash()),
-                        }),
-                        &tx_env,
-                        &evm_env.block_env,
-                        &res,
-                        &mut db,
-                    )
-                    .map_err(Eth::Error::from_eth_err)?;
+                let mut tracer = CallTracer::new(native_tracer, opts)?;
+                let (trace, _) = tracer.trace(
+                    &eth_api,
+                    &mut db,
+                    &evm_env,
+                    tx_env,
+                    Some(TransactionContext {
+                        block_hash: Some(block_hash),
+                        tx_index: Some(index),
+                        tx_hash: Some(*tx.tx_hash()),
+                    }),
+                )?;
 
                 Ok(trace)
             })
@@ -279,6 +397,12 @@ where
     /// transactions up to the specified index within the block (0-indexed).
     /// If not provided, then uses the post-state (default behavior).
     ///
+    /// Block overrides (e.g. number, timestamp, basefee and blob basefee) are applied to the block
+    /// context of the call together with the state overrides.
+    ///
+    /// Besides the built-in tracers, the `blockAccessList` tracer returns the block access list of
+    /// the call, see [`Self::debug_trace_call_block_access_list`].
+    ///
     /// Differences compare to `eth_call`:
     ///  - `debug_traceCall` executes with __enabled__ basefee check, `eth_call` does not: <https://github.com/paradigmxyz/reth/issues/6240>
     pub async fn debug_trace_call(
@@ -296,6 +420,20 @@ where
         } = opts;
         let overrides = EvmOverrides::new(state_overrides, block_overrides.map(Box::new));
 
+        if matches!(
+            &tracing_options.tracer,
+            Some(GethDebugTracerType::JsTracer(tracer)) if tracer == BLOCK_ACCESS_LIST_TRACER
+        ) {
+            return self
+                .debug_trace_call_block_access_list(
+                    call,
+                    at,
+                    tx_index.map(|tx_index| tx_index as usize),
+                    overrides,
+                )
+                .await
+        }
+
         // Check if we need to replay transactions for a specific tx_index
         if let Some(tx_idx) = tx_index {
             return self
@@ -304,19 +442,11 @@ where
         }
 
         let this = self.clone();
+        let native_tracer = self.native_tracers.get(&tracing_options);
         self.eth_api()
             .spawn_with_call_at(call, at, overrides, move |db, evm_env, tx_env| {
-                let mut inspector =
-                    DebugInspector::new(tracing_options).map_err(Eth::Error::from_eth_err)?;
-                let res = this.eth_api().inspect(
-                    &mut *db,
-                    evm_env.clone(),
-                    tx_env.clone(),
-                    &mut inspector,
-                )?;
-                let trace = inspector
-                    .get_result(None, &tx_env, &evm_env.block_env, &res, db)
-                    .map_err(Eth::Error::from_eth_err)?;
+                let mut tracer = CallTracer::new(native_tracer, tracing_options)?;
+                let (trace, _) = tracer.trace(this.eth_api(), db, &evm_env, tx_env, None)?;
                 Ok(trace)
             })
             .await
@@ -333,6 +463,44 @@ where
         tracing_options: GethDebugTracingOptions,
         overrides: EvmOverrides,
     ) -> Result<GethTrace, Eth::Error> {
+        let block = self.block_with_tx_index(block_id, tx_index).await?;
+        let evm_env = self.eth_api().evm_env_for_header(block.sealed_block().sealed_header())?;
+
+        // execute after the parent block, replaying `tx_index` transactions
+        let state_at = block.parent_hash();
+        let native_tracer = self.native_tracers.get(&tracing_options);
+
+        self.eth_api()
+            .spawn_with_state_at_block(state_at, move |eth_api, mut db| {
+                // 1. apply pre-execution changes
+                eth_api.apply_pre_execution_changes(&block, &mut db)?;
+
+                // 2. replay the required number of transactions
+                eth_api.replay_transactions_until(
+                    &mut db,
+                    evm_env.clone(),
+                    block.transactions_recovered(),
+                    *block.body().transactions()[tx_index].tx_hash(),
+                )?;
+
+                // 3. now execute the trace call on this state
+                let (evm_env, tx_env) =
+                    eth_api.prepare_call_env(evm_env, call, &mut db, overrides)?;
+
+                let mut tracer = CallTracer::new(native_tracer, tracing_options)?;
+                let (trace, _) = tracer.trace(&eth_api, &mut db, &evm_env, tx_env, None)?;
+
+                Ok(trace)
+            })
+            .await
+    }
+
+    /// Returns the block a call is traced in at the given transaction index.
+    async fn block_with_tx_index(
+        &self,
+        block_id: BlockId,
+        tx_index: usize,
+    ) -> Result<Arc<RecoveredBlock<ProviderBlock<Eth::Provider>>>, Eth::Error> {
         // Get the target block to check transaction count
         let block = self
             .eth_api()
@@ -350,37 +518,67 @@ where
             .into())
         }
 
-        let evm_env = self.eth_api().evm_env_for_header(block.sealed_block().sealed_header())?;
+        Ok(block)
+    }
 
-        // execute after the parent block, replaying `tx_index` transactions
-        let state_at = block.parent_hash();
+    /// Executes the call on a state recording its block access list ([EIP-7928]) and returns the
+    /// list, for the `blockAccessList` tracer of `debug_traceCall`.
+    ///
+    /// The call is recorded at the index it would have in the block. If `tx_index` is provided, the
+    /// call is executed after the pre-execution changes and the transactions preceding it, which
+    /// are part of the list as well.
+    ///
+    /// [EIP-7928]: https://eips.ethereum.org/EIPS/eip-7928
+    async fn debug_trace_call_block_access_list(
+        &self,
+        call: RpcTxReq<Eth::NetworkTypes>,
+        block_id: BlockId,
+        tx_index: Option<usize>,
+        overrides: EvmOverrides,
+    ) -> Result<GethTrace, Eth::Error> {
+        let (evm_env, state_at, replay) = match tx_index {
+            Some(tx_index) => {
+                let block = self.block_with_tx_index(block_id, tx_index).await?;
+                let evm_env =
+                    self.eth_api().evm_env_for_header(block.sealed_block().sealed_header())?;
+                (evm_env, block.parent_hash().into(), Some((block, tx_index)))
+            }
+            None => {
+                let (evm_env, at) = self.eth_api().evm_env_at(block_id).await?;
+                (evm_env, at, None)
+            }
+        };
 
         self.eth_api()
-            .spawn_with_state_at_block(state_at, move |eth_api, mut db| {
-                // 1. apply pre-execution changes
-                eth_api.apply_pre_execution_changes(&block, &mut db)?;
-
-                // 2. replay the required number of transactions
-                eth_api.replay_transactions_until(
-                    &mut db,
-                    evm_env.clone(),
-                    block.transactions_recovered(),
-                    *block.body().transactions()[tx_index].tx_hash(),
-                )?;
+            .spawn_blocking_io_fut(async move |eth_api| {
+                let state = eth_api.state_at_block_id(state_at).await?;
+                let mut db = State::builder()
+                    .with_database(StateProviderDatabase::new(StateProviderTraitObjWrapper(state)))
+                    .with_bal_builder()
+                    .build();
+
+                if let Some((block, tx_index)) = replay {
+                    eth_api.apply_pre_execution_changes(&block, &mut db)?;
+                    for tx in block.transactions_recovered().take(tx_index) {
+                        TracingCancellation::check_current().map_err(Eth::Error::from_eth_err)?;
+                        db.bump_bal_index();
+                        let tx_env = eth_api.evm_config().tx_env(tx);
+                        let res = eth_api.transact(&mut db, evm_env.clone(), tx_env)?;
+                        db.commit(res.state);
+                    }
+                }
+                db.bump_bal_index();
 
-                // 3. now execute the trace call on this state
                 let (evm_env, tx_env) =
                     eth_api.prepare_call_env(evm_env, call, &mut db, overrides)?;
+                let res = eth_api.transact(&mut db, evm_env, tx_env)?;
+                db.commit(res.state);
 
-                let mut inspector =
-                    DebugInspector::new(tracing_options).map_err(Eth::Error::from_eth_err)?;
-                let res =
-                    eth_api.inspect(&mut db, evm_env.clone(), tx_env.clone(), &mut inspector)?;
-                let trace = inspector
-                    .get_result(None, &tx_env, &evm_env.block_env, &res, &mut db)
+                let bal = db.take_built_alloy_bal().unwrap_or_default();
+                let bal = serde_json::to_value(bal)
+                    .map_err(|err| EthApiError::Internal(RethError::other(err)))
                     .map_err(Eth::Error::from_eth_err)?;
-
-                Ok(trace)
+                Ok(GethTrace::JS(bal))
             })
             .await
     }
@@ -428,6 +626,7 @@ where
             at = block.hash();
             replay_block_txs = false;
         }
+        let native_tracer = self.native_tracers.get(&tracing_options);
 
         self.eth_api()
             .spawn_with_state_at_block(at, move |eth_api, mut db| {
@@ -443,6 +642,7 @@ where
 
                     // Execute all transactions until index
                     for tx in transactions {
+                        TracingCancellation::check_current().map_err(Eth::Error::from_eth_err)?;
                         let tx_env = eth_api.evm_config().tx_env(tx);
                         let res = eth_api.transact(&mut db, evm_env.clone(), tx_env)?;
                         db.commit(res.state);
@@ -451,8 +651,7 @@ where
 
                 // Trace all bundles
                 let mut bundles = bundles.into_iter().peekable();
-                let mut inspector = DebugInspector::new(tracing_options.clone())
-                    .map_err(Eth::Error::from_eth_err)?;
+                let mut tracer = CallTracer::new(native_tracer, tracing_options)?;
                 while let Some(bundle) = bundles.next() {
                     let mut results = Vec::with_capacity(bundle.transactions.len());
                     let Bundle { transactions, block_override } = bundle;
@@ -461,6 +660,7 @@ where
 
                     let mut transactions = transactions.into_iter().peekable();
                     while let Some(tx) = transactions.next() {
+                        TracingCancellation::check_current().map_err(Eth::Error::from_eth_err)?;
                         // apply state overrides only once, before the first transaction
                         let state_overrides = state_overrides.take();
                         let overrides = EvmOverrides::new(state_overrides, block_overrides.clone());
@@ -468,20 +668,13 @@ where
                         let (evm_env, tx_env) =
                             eth_api.prepare_call_env(evm_env.clone(), tx, &mut db, overrides)?;
 
-                        let res = eth_api.inspect(
-                            &mut db,
-                            evm_env.clone(),
-                            tx_env.clone(),
-                            &mut inspector,
-                        )?;
-                        let trace = inspector
-                            .get_result(None, &tx_env, &evm_env.block_env, &res, &mut db)
-                            .map_err(Eth::Error::from_eth_err)?;
+                        let (trace, res) =
+                            tracer.trace(&eth_api, &mut db, &evm_env, tx_env, None)?;
 
                         // If there is more transactions, commit the database
                         // If there is no transactions, but more bundles, commit to the database too
                         if transactions.peek().is_some() || bundles.peek().is_some() {
-                            inspector.fuse().map_err(Eth::Error::from_eth_err)?;
+                            tracer.fuse()?;
                             db.commit(res.state);
                         }
                         results.push(trace);
@@ -539,22 +732,24 @@ where
         block: Arc<RecoveredBlock<ProviderBlock<Eth::Provider>>>,
         mode: ExecutionWitnessMode,
     ) -> Result<ExecutionWitness, Eth::Error> {
-        let block_number = block.header().number();
+        let concurrency = self.witness_concurrency;
         self.eth_api()
             .spawn_with_state_at_block(block.parent_hash(), move |eth_api, mut db| {
                 let block_executor = eth_api.evm_config().executor(&mut db);
 
-                let mut witness_record = ExecutionWitnessRecord::default();
-
+                let mut witness = None;
                 let _ = block_executor
                     .execute_with_state_closure(&block, |statedb: &State<_>| {
-                        witness_record.record_executed_state(statedb, mode);
+                        witness = Some(eth_api.provider().execution_witness_from_state(
+                            block.header(),
+                            statedb,
+                            mode,
+                            concurrency,
+                        ));
                     })
                     .map_err(|err| EthApiError::Internal(err.into()))?;
 
-                Ok(witness_record
-                    .into_execution_witness(&db.database.0, eth_api.provider(), block_number, mode)
-                    .map_err(EthApiError::from)?)
+                Ok(witness.expect("state closure is called").map_err(EthApiError::from)?)
             })
             .await
     }
@@ -873,7 +1068,7 @@ where
         opts: Option<GethDebugTracingOptions>,
     ) -> RpcResult<Vec<TraceResult>> {
         let _permit = self.acquire_trace_permit().await;
-        Self::debug_trace_raw_block(self, rlp_block, opts.unwrap_or_default())
+        self.traced(Self::debug_trace_raw_block(self, rlp_block, opts.unwrap_or_default()))
             .await
             .map_err(Into::into)
     }
@@ -885,7 +1080,7 @@ where
         opts: Option<GethDebugTracingOptions>,
     ) -> RpcResult<Vec<TraceResult>> {
         let _permit = self.acquire_trace_permit().await;
-        Self::debug_trace_block(self, block.into(), opts.unwrap_or_default())
+        self.traced(Self::debug_trace_block(self, block.into(), opts.unwrap_or_default()))
             .await
             .map_err(Into::into)
     }
@@ -897,11 +1092,47 @@ where
         opts: Option<GethDebugTracingOptions>,
     ) -> RpcResult<Vec<TraceResult>> {
         let _permit = self.acquire_trace_permit().await;
-        Self::debug_trace_block(self, block.into(), opts.unwrap_or_default())
+        self.traced(Self::debug_trace_block(self, block.into(), opts.unwrap_or_default()))
             .await
             .map_err(Into::into)
     }
 
+    /// Handler for `debug_subscribeTraceBlock`
+    async fn debug_subscribe_trace_block(
+        &self,
+        pending: PendingSubscriptionSink,
+        block_id: BlockId,
+        opts: Option<GethDebugTracingOptions>,
+    ) -> jsonrpsee::core::SubscriptionResult {
+        let block = match self.eth_api().recovered_block(block_id).await {
+            Ok(Some(block)) => block,
+            Ok(None) => {
+                pending.reject(EthApiError::HeaderNotFound(block_id)).await;
+                return Ok(())
+            }
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(())
+            }
+        };
+        let evm_env = match self.eth_api().evm_env_for_header(block.sealed_block().sealed_header())
+        {
+            Ok(evm_env) => evm_env,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(())
+            }
+        };
+
+        let sink = pending.accept().await?;
+        let this = self.clone();
+        self.inner.task_spawner.spawn_task(async move {
+            this.stream_trace_block(sink, block, evm_env, opts.unwrap_or_default()).await
+        });
+
+        Ok(())
+    }
+
     /// Handler for `debug_traceTransaction`
     async fn debug_trace_transaction(
         &self,
@@ -909,7 +1140,7 @@ where
         opts: Option<GethDebugTracingOptions>,
     ) -> RpcResult<GethTrace> {
         let _permit = self.acquire_trace_permit().await;
-        Self::debug_trace_transaction(self, tx_hash, opts.unwrap_or_default())
+        self.traced(Self::debug_trace_transaction(self, tx_hash, opts.unwrap_or_default()))
             .await
             .map_err(Into::into)
     }
@@ -922,7 +1153,7 @@ where
         opts: Option<GethDebugTracingCallOptions>,
     ) -> RpcResult<GethTrace> {
         let _permit = self.acquire_trace_permit().await;
-        Self::debug_trace_call(self, request, block_id, opts.unwrap_or_default())
+        self.traced(Self::debug_trace_call(self, request, block_id, opts.unwrap_or_default()))
             .await
             .map_err(Into::into)
     }
@@ -934,7 +1165,9 @@ where
         opts: Option<GethDebugTracingCallOptions>,
     ) -> RpcResult<Vec<Vec<GethTrace>>> {
         let _permit = self.acquire_trace_permit().await;
-        Self::debug_trace_call_many(self, bundles, state_context, opts).await.map_err(Into::into)
+        self.traced(Self::debug_trace_call_many(self, bundles, state_context, opts))
+            .await
+            .map_err(Into::into)
     }
 
     /// Handler for `debug_executionWitness`
@@ -944,7 +1177,7 @@ where
         mode: Option<ExecutionWitnessMode>,
     ) -> RpcResult<ExecutionWitness> {
         let _permit = self.acquire_trace_permit().await;
-        Self::debug_execution_witness(self, block, mode).await.map_err(Into::into)
+        self.traced(Self::debug_execution_witness(self, block, mode)).await.map_err(Into::into)
     }
 
     /// Handler for `debug_executionWitnessByBlockHash`
@@ -954,7 +1187,9 @@ where
         mode: Option<ExecutionWitnessMode>,
     ) -> RpcResult<ExecutionWitness> {
         let _permit = self.acquire_trace_permit().await;
-        Self::debug_execution_witness_by_block_hash(self, hash, mode).await.map_err(Into::into)
+        self.traced(Self::debug_execution_witness_by_block_hash(self, hash, mode))
+            .await
+            .map_err(Into::into)
     }
 
     /// Handler for `debug_accountAt`
@@ -965,7 +1200,9 @@ where
         address: Address,
     ) -> RpcResult<Option<Account>> {
         let _permit = self.acquire_trace_permit().await;
-        Self::debug_account_at(self, block_id, tx_index, address).await.map_err(Into::into)
+        self.traced(Self::debug_account_at(self, block_id, tx_index, address))
+            .await
+            .map_err(Into::into)
     }
 
     /// Handler for `debug_accountInfoAt`
@@ -976,7 +1213,9 @@ where
         address: Address,
     ) -> RpcResult<Option<AccountInfo>> {
         let _permit = self.acquire_trace_permit().await;
-        Self::debug_account_info_at(self, block_id, tx_index, address).await.map_err(Into::into)
+        self.traced(Self::debug_account_info_at(self, block_id, tx_index, address))
+            .await
+            .map_err(Into::into)
     }
 
     async fn debug_account_range(
@@ -1095,7 +1334,7 @@ where
         _opts: Option<GethDebugTracingCallOptions>,
     ) -> RpcResult<Vec<B256>> {
         let _permit = self.acquire_trace_permit().await;
-        self.intermediate_roots(block_hash).await.map_err(Into::into)
+        self.traced(self.intermediate_roots(block_hash)).await.map_err(Into::into)
     }
 
     async fn debug_mem_stats(&self) -> RpcResult<()> {
@@ -1181,7 +1420,7 @@ where
             .to_rpc_result()?;
 
         let opts = opts.map(|o| o.tracing_options).unwrap_or_default();
-        self.trace_block(entry.block.clone(), evm_env, opts).await.map_err(Into::into)
+        self.traced(self.trace_block(entry.block.clone(), evm_env, opts)).await.map_err(Into::into)
     }
 }
 
@@ -1193,7 +1432,69 @@ impl<Eth: RpcNodeCore> std::fmt::Debug for DebugApi<Eth> {
 
 impl<Eth: RpcNodeCore> Clone for DebugApi<Eth> {
     fn clone(&self) -> Self {
-        Self { inner: Arc::clone(&self.inner) }
+        Self {
+            inner: Arc::clone(&self.inner),
+            tracing_timeout: self.tracing_timeout,
+            native_tracers: self.native_tracers.clone(),
+            witness_concurrency: self.witness_concurrency,
+        }
+    }
+}
+
+/// Tracer of a `debug_trace*` call, a built-in tracer or a registered [`NativeTracer`].
+enum CallTracer<Eth> {
+    /// One of the built-in tracers
+    BuiltIn(Box<DebugInspector>),
+    /// A registered tracer, with the `tracerConfig` of the request
+    Native(Arc<dyn NativeTracer<Eth>>, GethDebugTracerConfig),
+}
+
+impl<Eth: TraceExt> CallTracer<Eth> {
+    /// Creates the tracer selected by the options, the registered tracer if any.
+    fn new(
+        native_tracer: Option<Arc<dyn NativeTracer<Eth>>>,
+        opts: GethDebugTracingOptions,
+    ) -> Result<Self, Eth::Error> {
+        match native_tracer {
+            Some(tracer) => Ok(Self::Native(tracer, opts.tracer_config)),
+            None => Ok(Self::BuiltIn(Box::new(
+                DebugInspector::new(opts).map_err(Eth::Error::from_eth_err)?,
+            ))),
+        }
+    }
+
+    /// Traces the transaction, returning the trace and the result of the execution.
+    fn trace(
+        &mut self,
+        eth_api: &Eth,
+        db: &mut StateCacheDb,
+        evm_env: &EvmEnvFor<Eth::Evm>,
+        tx_env: TxEnvFor<Eth::Evm>,
+        tx_ctx: Option<TransactionContext>,
+    ) -> Result<(GethTrace, ResultAndState<HaltReasonFor<Eth::Evm>>), Eth::Error> {
+        match self {
+            Self::BuiltIn(inspector) => {
+                let res =
+                    eth_api.inspect(&mut *db, evm_env.clone(), tx_env.clone(), &mut **inspector)?;
+                let trace = inspector
+                    .get_result(tx_ctx, &tx_env, &evm_env.block_env, &res, db)
+                    .map_err(Eth::Error::from_eth_err)?;
+                Ok((trace, res))
+            }
+            Self::Native(tracer, config) => {
+                let (trace, res) =
+                    tracer.trace(eth_api, db, evm_env.clone(), tx_env, config, tx_ctx)?;
+                Ok((GethTrace::JS(trace), res))
+            }
+        }
+    }
+
+    /// Resets the tracer before tracing the next transaction.
+    fn fuse(&mut self) -> Result<(), Eth::Error> {
+        if let Self::BuiltIn(inspector) = self {
+            inspector.fuse().map_err(Eth::Error::from_eth_err)?;
+        }
+        Ok(())
     }
 }
 
@@ -1204,6 +1505,8 @@ struct DebugApiInner<Eth: RpcNodeCore> {
     blocking_task_guard: BlockingTaskGuard,
     /// Cache for bad blocks.
     bad_block_store: BadBlockStore<BlockTy<Eth::Primitives>>,
+    /// The type that can spawn tasks.
+    task_spawner: Runtime,
 }
 
 /// A bounded, deduplicating store of recently observed bad blocks.
@@ -1261,3 +1564,40 @@ impl<B: BlockTrait> Default for BadBlockStore<B> {
         Self::new(64)
     }
 }
+
+/// Sends the item to the channel, blocking the current thread while the channel is full.
+///
+/// Returns `false` if the receiver was dropped, or the channel stayed full for the timeout.
+fn send_with_timeout<T>(sender: &mut mpsc::Sender<T>, mut item: T, timeout: Duration) -> bool {
+    let deadline = std::time::Instant::now() + timeout;
+    loop {
+        match sender.try_send(item) {
+            Ok(()) => return true,
+            Err(err) if err.is_full() && std::time::Instant::now() < deadline => {
+                item = err.into_inner();
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            Err(_) => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt as _;
+
+    #[test]
+    fn send_with_timeout_waits_for_receiver() {
+        let (mut sender, mut receiver) = mpsc::channel(0);
+        assert!(send_with_timeout(&mut sender, 1, Duration::ZERO));
+
+        // the channel is full until the first item is received
+        assert!(!send_with_timeout(&mut sender, 2, Duration::from_millis(50)));
+        assert_eq!(futures::executor::block_on(receiver.next()), Some(1));
+        assert!(send_with_timeout(&mut sender, 3, Duration::from_millis(50)));
+
+        drop(receiver);
+        assert!(!send_with_timeout(&mut sender, 4, Duration::from_secs(10)));
+    }
+}
@@ -0,0 +1,457 @@
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::{Bloom, BloomInput, B256, U64};
+use alloy_rpc_types_eth::{Filter, FilterBlockOption, Log};
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_primitives_traits::TxTy;
+use reth_rpc_api::{ErigonApiServer, ErigonLogFilterOptions, EthApiServer};
+use reth_rpc_convert::RpcTxReq;
+use reth_rpc_eth_api::{
+    helpers::{LoadReceipt, SpawnBlocking},
+    FromEthApiError, RpcBlock, RpcHeader, RpcNodeCore, RpcReceipt, RpcTransaction,
+};
+use reth_rpc_eth_types::{
+    logs_utils::{append_matching_block_logs, ProviderOrBlock},
+    utils::binary_search,
+    EthApiError, EthFilterConfig,
+};
+use reth_storage_api::{
+    BlockHashReader, BlockIdReader, BlockNumReader, HeaderProvider, ReceiptProvider,
+};
+
+/// Number of headers read at once while scanning backwards for the latest logs.
+const LATEST_LOGS_HEADERS_CHUNK_SIZE: u64 = 1_000;
+
+/// `erigon` API implementation.
+///
+/// This type provides the commonly used Erigon-specific methods.
+#[derive(Debug, Clone)]
+pub struct ErigonApi<Eth> {
+    eth: Eth,
+    filter_config: EthFilterConfig,
+}
+
+impl<Eth> ErigonApi<Eth> {
+    /// Creates a new instance of `ErigonApi`.
+    ///
+    /// The limits of the filter config also apply to `erigon_getLatestLogs`.
+    pub const fn new(eth: Eth, filter_config: EthFilterConfig) -> Self {
+        Self { eth, filter_config }
+    }
+}
+
+impl<Eth> ErigonApi<Eth>
+where
+    Eth: LoadReceipt + SpawnBlocking,
+{
+    /// Scans the blocks of the range backwards and returns the logs of the latest matching ones,
+    /// in ascending order.
+    fn latest_logs(
+        eth: &Eth,
+        filter: Filter,
+        range: (u64, u64),
+        log_count: Option<u64>,
+        block_count: Option<u64>,
+        ignore_topics_order: bool,
+        max_logs: Option<usize>,
+    ) -> Result<Vec<Log>, Eth::Error> {
+        let provider = eth.provider();
+        let (from, to) = range;
+
+        // if the topics order is ignored, the topics are matched against the logs separately
+        let mut filter = Filter { block_option: FilterBlockOption::default(), ..filter };
+        let topics = if ignore_topics_order {
+            std::mem::take(&mut filter.topics)
+        } else {
+            Default::default()
+        };
+        let matches_topics = |topics_bloom: &Bloom| {
+            topics.iter().all(|topics| {
+                topics.is_empty() ||
+                    topics.iter().any(|topic| {
+                        topics_bloom.contains_input(BloomInput::Raw(topic.as_slice()))
+                    })
+            })
+        };
+
+        let mut blocks = Vec::new();
+        let mut total = 0;
+        let mut end = to;
+        'scan: loop {
+            let start = end.saturating_sub(LATEST_LOGS_HEADERS_CHUNK_SIZE - 1).max(from);
+            let headers =
+                provider.sealed_headers_range(start..=end).map_err(Eth::Error::from_eth_err)?;
+            for header in headers.into_iter().rev() {
+                if !filter.matches_bloom(header.logs_bloom()) ||
+                    !matches_topics(&header.logs_bloom())
+                {
+                    continue
+                }
+
+                let receipts = provider
+                    .receipts_by_block(header.number().into())
+                    .map_err(Eth::Error::from_eth_err)?
+                    .unwrap_or_default();
+                let mut logs = Vec::new();
+                append_matching_block_logs(
+                    &mut logs,
+                    ProviderOrBlock::Provider(provider),
+                    &filter,
+                    header.num_hash(),
+                    &receipts,
+                    false,
+                    header.timestamp(),
+                )
+                .map_err(Eth::Error::from_eth_err)?;
+                logs.retain(|log| {
+                    topics.iter().all(|topics| {
+                        topics.is_empty() || log.topics().iter().any(|topic| topics.matches(topic))
+                    })
+                });
+                if logs.is_empty() {
+                    continue
+                }
+
+                if let Some(log_count) = log_count {
+                    let remaining = (log_count - total) as usize;
+                    if logs.len() > remaining {
+                        logs.drain(..logs.len() - remaining);
+                    }
+                }
+                total += logs.len() as u64;
+                if let Some(max_logs) = max_logs &&
+                    total > max_logs as u64
+                {
+                    return Err(Eth::Error::from_eth_err(EthApiError::InvalidParams(format!(
+                        "query exceeds max results {max_logs}"
+                    ))))
+                }
+                blocks.push(logs);
+
+                if log_count.is_some_and(|count| total >= count) ||
+                    block_count.is_some_and(|count| blocks.len() as u64 >= count)
+                {
+                    break 'scan
+                }
+            }
+
+            if start == from {
+                break
+            }
+            end = start - 1;
+        }
+
+        Ok(blocks.into_iter().rev().flatten().collect())
+    }
+}
+
+#[async_trait]
+impl<Eth> ErigonApiServer<RpcBlock<Eth::NetworkTypes>, RpcReceipt<Eth::NetworkTypes>>
+    for ErigonApi<Eth>
+where
+    Eth: EthApiServer<
+            RpcTxReq<Eth::NetworkTypes>,
+            RpcTransaction<Eth::NetworkTypes>,
+            RpcBlock<Eth::NetworkTypes>,
+            RpcReceipt<Eth::NetworkTypes>,
+            RpcHeader<Eth::NetworkTypes>,
+            TxTy<Eth::Primitives>,
+        > + LoadReceipt
+        + SpawnBlocking
+        + 'static,
+{
+    /// Handler for `erigon_getLatestLogs`
+    async fn get_latest_logs(
+        &self,
+        filter: Filter,
+        options: Option<ErigonLogFilterOptions>,
+    ) -> RpcResult<Vec<Log>> {
+        let ErigonLogFilterOptions { log_count, block_count, ignore_topics_order } =
+            options.unwrap_or_default();
+        // zero counts are treated as unset, same as Erigon
+        let (log_count, block_count) =
+            match (log_count.filter(|count| *count > 0), block_count.filter(|count| *count > 0)) {
+                (Some(_), Some(_)) => {
+                    return Err(EthApiError::InvalidParams(
+                        "only one of logCount and blockCount can be set".to_string(),
+                    )
+                    .into())
+                }
+                (None, None) => (Some(ErigonLogFilterOptions::DEFAULT_LOG_COUNT), None),
+                counts => counts,
+            };
+
+        let provider = self.eth.provider();
+        let range = match filter.block_option {
+            FilterBlockOption::AtBlockHash(hash) => {
+                let number = provider
+                    .block_number(hash)
+                    .map_err(EthApiError::from)?
+                    .ok_or(EthApiError::HeaderNotFound(hash.into()))?;
+                (number, number)
+            }
+            FilterBlockOption::Range { from_block, to_block } => {
+                let best = provider.best_block_number().map_err(EthApiError::from)?;
+                let convert = |block: Option<BlockNumberOrTag>| {
+                    block
+                        .map(|block| provider.convert_block_number(block))
+                        .transpose()
+                        .map(Option::flatten)
+                        .map_err(EthApiError::from)
+                };
+                let to = convert(to_block)?.unwrap_or(best).min(best);
+                let from = match convert(from_block)? {
+                    Some(from) => from,
+                    // without a lower bound, at most the configured number of blocks is scanned
+                    None => self
+                        .filter_config
+                        .max_blocks_per_filter
+                        .map_or(0, |max_blocks| to.saturating_sub(max_blocks)),
+                };
+                if let Some(max_blocks) = self.filter_config.max_blocks_per_filter &&
+                    to.saturating_sub(from) > max_blocks
+                {
+                    return Err(EthApiError::InvalidParams(format!(
+                        "query exceeds max block range {max_blocks}"
+                    ))
+                    .into())
+                }
+                let earliest = provider.earliest_block_number().map_err(EthApiError::from)?;
+                if from > to || to < earliest {
+                    return Ok(Vec::new())
+                }
+                (from.max(earliest), to)
+            }
+        };
+
+        let max_logs = self.filter_config.max_logs_per_response;
+        self.eth
+            .spawn_blocking_io(move |eth| {
+                Self::latest_logs(
+                    &eth,
+                    filter,
+                    range,
+                    log_count,
+                    block_count,
+                    ignore_topics_order,
+                    max_logs,
+                )
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Handler for `erigon_getBlockByTimestamp`
+    async fn get_block_by_timestamp(
+        &self,
+        timestamp: U64,
+        full: bool,
+    ) -> RpcResult<Option<RpcBlock<Eth::NetworkTypes>>> {
+        let timestamp = timestamp.to::<u64>();
+        let provider = self.eth.provider();
+        let earliest = provider.earliest_block_number().map_err(EthApiError::from)?;
+        let best = provider.best_block_number().map_err(EthApiError::from)?;
+
+        let header_timestamp = |number: u64| async move {
+            provider
+                .header_by_number(number)
+                .map_err(EthApiError::from)?
+                .map(|header| header.timestamp())
+                .ok_or(EthApiError::HeaderNotFound(number.into()))
+        };
+
+        if header_timestamp(earliest).await? > timestamp {
+            return Ok(None)
+        }
+
+        // find the first block newer than the timestamp, the block before is the latest one at or
+        // below it
+        let number = if header_timestamp(best).await? <= timestamp {
+            best
+        } else {
+            let newer = binary_search(earliest, best, |number| async move {
+                Ok::<_, EthApiError>(header_timestamp(number).await? > timestamp)
+            })
+            .await?;
+            newer.saturating_sub(1).max(earliest)
+        };
+
+        self.eth.block_by_number(number.into(), full).await
+    }
+
+    /// Handler for `erigon_getBlockReceiptsByBlockHash`
+    async fn get_block_receipts_by_block_hash(
+        &self,
+        hash: B256,
+    ) -> RpcResult<Option<Vec<RpcReceipt<Eth::NetworkTypes>>>> {
+        let provider = self.eth.provider();
+        let Some(number) = provider.block_number(hash).map_err(EthApiError::from)? else {
+            return Ok(None)
+        };
+        if provider.block_hash(number).map_err(EthApiError::from)? != Some(hash) {
+            return Err(EthApiError::InvalidParams(format!("block {hash} is not canonical")).into())
+        }
+
+        self.eth.block_receipts(hash.into()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{eth::EthApi, EthApiBuilder};
+    use alloy_consensus::TxLegacy;
+    use alloy_network::Ethereum;
+    use alloy_primitives::{Address, Bytes, LogData, Signature, TxKind, U256};
+    use reth_chainspec::{ChainSpec, ChainSpecProvider};
+    use reth_db_api::models::StoredBlockBodyIndices;
+    use reth_ethereum_primitives::{Block, BlockBody, Receipt, TransactionSigned, TxType};
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_network_api::noop::NoopNetwork;
+    use reth_provider::test_utils::MockEthProvider;
+    use reth_rpc_convert::RpcConverter;
+    use reth_rpc_eth_api::node::RpcNodeCoreAdapter;
+    use reth_rpc_eth_types::receipt::EthReceiptConverter;
+    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+
+    type TestEthApi = EthApi<
+        RpcNodeCoreAdapter<MockEthProvider, TestPool, NoopNetwork, EthEvmConfig>,
+        RpcConverter<Ethereum, EthEvmConfig, EthReceiptConverter<ChainSpec>>,
+    >;
+
+    /// Returns the API over blocks `0..=4` with timestamps `10, 20, .., 50` and a single log each,
+    /// and the block hashes.
+    fn test_api() -> (ErigonApi<TestEthApi>, Vec<B256>) {
+        let provider = MockEthProvider::default();
+        let tx = TransactionSigned::new_unhashed(
+            TxLegacy {
+                chain_id: Some(1),
+                gas_price: 21_000,
+                gas_limit: 21_000,
+                to: TxKind::Call(Address::ZERO),
+                value: U256::ZERO,
+                ..Default::default()
+            }
+            .into(),
+            Signature::test_signature(),
+        );
+        let receipt = Receipt {
+            tx_type: TxType::Legacy,
+            cumulative_gas_used: 21_000,
+            logs: vec![alloy_primitives::Log {
+                address: Address::ZERO,
+                data: LogData::new_unchecked(vec![B256::with_last_byte(1)], Bytes::new()),
+            }],
+            success: true,
+        };
+
+        let mut hashes = Vec::new();
+        let mut parent_hash = B256::ZERO;
+        for number in 0..=4u64 {
+            let header = alloy_consensus::Header {
+                number,
+                parent_hash,
+                timestamp: (number + 1) * 10,
+                logs_bloom: Bloom::from([0xff; 256]),
+                ..Default::default()
+            };
+            parent_hash = header.hash_slow();
+            hashes.push(parent_hash);
+            provider.add_block(
+                parent_hash,
+                Block {
+                    header,
+                    body: BlockBody { transactions: vec![tx.clone()], ..Default::default() },
+                },
+            );
+            provider.add_receipts(number, vec![receipt.clone()]);
+            provider.add_block_body_indices(
+                number,
+                StoredBlockBodyIndices { first_tx_num: number, tx_count: 1 },
+            );
+        }
+
+        let eth_api = EthApiBuilder::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork::default(),
+            EthEvmConfig::new(provider.chain_spec()),
+        )
+        .build();
+        (ErigonApi::new(eth_api, EthFilterConfig::default()), hashes)
+    }
+
+    /// Block numbers of the logs.
+    fn log_blocks(logs: &[Log]) -> Vec<u64> {
+        logs.iter().map(|log| log.block_number.unwrap()).collect()
+    }
+
+    #[tokio::test]
+    async fn block_by_timestamp() {
+        let (api, _) = test_api();
+        let block_at = |timestamp: u64| {
+            let api = &api;
+            async move {
+                api.get_block_by_timestamp(U64::from(timestamp), false)
+                    .await
+                    .unwrap()
+                    .map(|block| block.header.number)
+            }
+        };
+
+        // all blocks are newer
+        assert_eq!(block_at(0).await, None);
+        assert_eq!(block_at(9).await, None);
+        // exact timestamps of the first, a middle and the last block
+        assert_eq!(block_at(10).await, Some(0));
+        assert_eq!(block_at(30).await, Some(2));
+        assert_eq!(block_at(50).await, Some(4));
+        // between blocks, the older one is returned
+        assert_eq!(block_at(29).await, Some(1));
+        assert_eq!(block_at(31).await, Some(2));
+        // all blocks are older
+        assert_eq!(block_at(1_000).await, Some(4));
+    }
+
+    #[tokio::test]
+    async fn latest_logs() {
+        let (api, _) = test_api();
+
+        let logs = api.get_latest_logs(Filter::default(), None).await.unwrap();
+        assert_eq!(log_blocks(&logs), vec![0, 1, 2, 3, 4]);
+
+        let options = |log_count, block_count| {
+            Some(ErigonLogFilterOptions { log_count, block_count, ignore_topics_order: false })
+        };
+        let logs = api.get_latest_logs(Filter::default(), options(Some(2), None)).await.unwrap();
+        assert_eq!(log_blocks(&logs), vec![3, 4]);
+        let logs = api.get_latest_logs(Filter::default(), options(None, Some(1))).await.unwrap();
+        assert_eq!(log_blocks(&logs), vec![4]);
+        assert!(api.get_latest_logs(Filter::default(), options(Some(1), Some(1))).await.is_err());
+
+        // scans backwards from the end of the range
+        let filter = Filter::new().from_block(1).to_block(2);
+        let logs = api.get_latest_logs(filter, None).await.unwrap();
+        assert_eq!(log_blocks(&logs), vec![1, 2]);
+
+        // topics are matched at any position if the order is ignored
+        let filter = Filter::new().topic1(B256::with_last_byte(1));
+        let logs = api.get_latest_logs(filter.clone(), None).await.unwrap();
+        assert!(logs.is_empty());
+        let options = ErigonLogFilterOptions { ignore_topics_order: true, ..Default::default() };
+        let logs = api.get_latest_logs(filter, Some(options)).await.unwrap();
+        assert_eq!(log_blocks(&logs), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn block_receipts_by_block_hash() {
+        let (api, hashes) = test_api();
+
+        let receipts = api.get_block_receipts_by_block_hash(hashes[2]).await.unwrap().unwrap();
+        assert_eq!(receipts.len(), 1);
+        assert_eq!(receipts[0].block_hash, Some(hashes[2]));
+
+        assert!(api.get_block_receipts_by_block_hash(B256::random()).await.unwrap().is_none());
+    }
+}
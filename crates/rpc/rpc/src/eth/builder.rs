@@ -14,9 +14,12 @@ use reth_rpc_eth_types::{
     receipt::EthReceiptConverter, EthStateCache, EthStateCacheConfig, FeeHistoryCache,
     FeeHistoryCacheConfig, ForwardConfig, GasCap, GasPriceOracle, GasPriceOracleConfig,
 };
-use reth_rpc_server_types::constants::{
-    DEFAULT_ETH_PROOF_WINDOW, DEFAULT_MAX_BLOCKING_IO_REQUEST, DEFAULT_MAX_SIMULATE_BLOCKS,
-    DEFAULT_PROOF_PERMITS,
+use reth_rpc_server_types::{
+    constants::{
+        DEFAULT_ETH_PROOF_WINDOW, DEFAULT_MAX_BLOCKING_IO_REQUEST, DEFAULT_MAX_SIMULATE_BLOCKS,
+        DEFAULT_PROOF_CONCURRENCY, DEFAULT_PROOF_PERMITS,
+    },
+    RpcServerLimits,
 };
 use reth_tasks::{pool::BlockingTaskPool, Runtime};
 use std::{sync::Arc, time::Duration};
@@ -35,6 +38,7 @@ pub struct EthApiBuilder<N: RpcNodeCore, Rpc, NextEnv = ()> {
     eth_proof_window: u64,
     fee_history_cache_config: FeeHistoryCacheConfig,
     proof_permits: usize,
+    proof_concurrency: usize,
     eth_state_cache_config: EthStateCacheConfig,
     eth_cache: Option<EthStateCache<N::Primitives>>,
     gas_oracle_config: GasPriceOracleConfig,
@@ -49,6 +53,9 @@ pub struct EthApiBuilder<N: RpcNodeCore, Rpc, NextEnv = ()> {
     send_raw_transaction_sync_timeout: Duration,
     evm_memory_limit: u64,
     force_blob_sidecar_upcasting: bool,
+    pruned_state_replay_limit: Option<u64>,
+    call_cache_max_entries: Option<u32>,
+    server_limits: Option<RpcServerLimits>,
 }
 
 impl<Provider, Pool, Network, EvmConfig, ChainSpec>
@@ -89,6 +96,7 @@ impl<N: RpcNodeCore, Rpc, NextEnv> EthApiBuilder<N, Rpc, NextEnv> {
             eth_proof_window,
             fee_history_cache_config,
             proof_permits,
+            proof_concurrency,
             eth_state_cache_config,
             eth_cache,
             gas_oracle_config,
@@ -103,6 +111,9 @@ impl<N: RpcNodeCore, Rpc, NextEnv> EthApiBuilder<N, Rpc, NextEnv> {
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
             force_blob_sidecar_upcasting,
+            pruned_state_replay_limit,
+            call_cache_max_entries,
+            server_limits,
         } = self;
         EthApiBuilder {
             components,
@@ -113,6 +124,7 @@ impl<N: RpcNodeCore, Rpc, NextEnv> EthApiBuilder<N, Rpc, NextEnv> {
             eth_proof_window,
             fee_history_cache_config,
             proof_permits,
+            proof_concurrency,
             eth_state_cache_config,
             eth_cache,
             gas_oracle_config,
@@ -127,6 +139,9 @@ impl<N: RpcNodeCore, Rpc, NextEnv> EthApiBuilder<N, Rpc, NextEnv> {
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
             force_blob_sidecar_upcasting,
+            pruned_state_replay_limit,
+            call_cache_max_entries,
+            server_limits,
         }
     }
 }
@@ -151,6 +166,7 @@ where
             blocking_task_pool: None,
             fee_history_cache_config: FeeHistoryCacheConfig::default(),
             proof_permits: DEFAULT_PROOF_PERMITS,
+            proof_concurrency: DEFAULT_PROOF_CONCURRENCY,
             task_spawner: Runtime::test(),
             gas_oracle_config: Default::default(),
             eth_state_cache_config: Default::default(),
@@ -162,6 +178,9 @@ where
             send_raw_transaction_sync_timeout: Duration::from_secs(30),
             evm_memory_limit: (1 << 32) - 1,
             force_blob_sidecar_upcasting: false,
+            pruned_state_replay_limit: None,
+            call_cache_max_entries: None,
+            server_limits: None,
         }
     }
 }
@@ -190,6 +209,7 @@ where
             eth_proof_window,
             fee_history_cache_config,
             proof_permits,
+            proof_concurrency,
             eth_state_cache_config,
             eth_cache,
             gas_oracle,
@@ -204,6 +224,9 @@ where
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
             force_blob_sidecar_upcasting,
+            pruned_state_replay_limit,
+            call_cache_max_entries,
+            server_limits,
         } = self;
         EthApiBuilder {
             components,
@@ -214,6 +237,7 @@ where
             eth_proof_window,
             fee_history_cache_config,
             proof_permits,
+            proof_concurrency,
             eth_state_cache_config,
             eth_cache,
             gas_oracle,
@@ -228,6 +252,9 @@ where
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
             force_blob_sidecar_upcasting,
+            pruned_state_replay_limit,
+            call_cache_max_entries,
+            server_limits,
         }
     }
 
@@ -245,6 +272,7 @@ where
             eth_proof_window,
             fee_history_cache_config,
             proof_permits,
+            proof_concurrency,
             eth_state_cache_config,
             eth_cache,
             gas_oracle,
@@ -259,6 +287,9 @@ where
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
             force_blob_sidecar_upcasting,
+            pruned_state_replay_limit,
+            call_cache_max_entries,
+            server_limits,
         } = self;
         EthApiBuilder {
             components,
@@ -269,6 +300,7 @@ where
             eth_proof_window,
             fee_history_cache_config,
             proof_permits,
+            proof_concurrency,
             eth_state_cache_config,
             eth_cache,
             gas_oracle,
@@ -283,6 +315,9 @@ where
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
             force_blob_sidecar_upcasting,
+            pruned_state_replay_limit,
+            call_cache_max_entries,
+            server_limits,
         }
     }
 
@@ -360,6 +395,13 @@ where
         self
     }
 
+    /// Sets the maximum number of tasks a single `eth_getProof` call computes its storage proofs
+    /// with.
+    pub const fn proof_concurrency(mut self, proof_concurrency: usize) -> Self {
+        self.proof_concurrency = proof_concurrency;
+        self
+    }
+
     /// Sets the max batch size for batching transaction insertions.
     pub const fn max_batch_size(mut self, max_batch_size: usize) -> Self {
         self.max_batch_size = max_batch_size;
@@ -414,6 +456,16 @@ where
         self.proof_permits
     }
 
+    /// Returns the proof concurrency.
+    pub const fn get_proof_concurrency(&self) -> usize {
+        self.proof_concurrency
+    }
+
+    /// Returns the maximum number of blocks replayed to reconstruct pruned historical state.
+    pub const fn get_pruned_state_replay_limit(&self) -> Option<u64> {
+        self.pruned_state_replay_limit
+    }
+
     /// Returns a reference to the ETH state cache config.
     pub const fn get_eth_state_cache_config(&self) -> &EthStateCacheConfig {
         &self.eth_state_cache_config
@@ -522,6 +574,7 @@ where
             blocking_task_pool,
             fee_history_cache_config,
             proof_permits,
+            proof_concurrency,
             task_spawner,
             next_env,
             max_batch_size,
@@ -531,6 +584,9 @@ where
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
             force_blob_sidecar_upcasting,
+            pruned_state_replay_limit,
+            call_cache_max_entries,
+            server_limits,
         } = self;
 
         let provider = components.provider().clone();
@@ -575,6 +631,7 @@ where
             fee_history_cache,
             task_spawner,
             proof_permits,
+            proof_concurrency,
             rpc_converter,
             next_env,
             max_batch_size,
@@ -584,6 +641,9 @@ where
             send_raw_transaction_sync_timeout,
             evm_memory_limit,
             force_blob_sidecar_upcasting,
+            pruned_state_replay_limit,
+            call_cache_max_entries,
+            server_limits,
         )
     }
 
@@ -620,4 +680,28 @@ where
         self.force_blob_sidecar_upcasting = force;
         self
     }
+
+    /// Sets the maximum number of blocks replayed on top of the closest known state, the genesis
+    /// state or a recently reconstructed one, to serve historical state that was pruned.
+    ///
+    /// Pruned historical state is unavailable if `None`.
+    pub const fn pruned_state_replay_limit(mut self, limit: Option<u64>) -> Self {
+        self.pruned_state_replay_limit = limit;
+        self
+    }
+
+    /// Sets the maximum number of `eth_call` and `eth_estimateGas` results cached at the latest
+    /// block.
+    ///
+    /// Results are not cached if `None`.
+    pub const fn call_cache_max_entries(mut self, max_entries: Option<u32>) -> Self {
+        self.call_cache_max_entries = max_entries;
+        self
+    }
+
+    /// Sets the server limits adjustable at runtime, overriding the gas cap.
+    pub fn server_limits(mut self, server_limits: Option<RpcServerLimits>) -> Self {
+        self.server_limits = server_limits;
+        self
+    }
 }
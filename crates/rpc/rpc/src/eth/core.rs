@@ -21,9 +21,11 @@ use reth_rpc_eth_api::{
     EthApiTypes, RpcNodeCore,
 };
 use reth_rpc_eth_types::{
-    builder::config::PendingBlockKind, receipt::EthReceiptConverter, EthApiError, EthStateCache,
-    FeeHistoryCache, GasCap, GasPriceOracle, PendingBlock,
+    builder::config::PendingBlockKind, receipt::EthReceiptConverter, replay::PrunedStateReplay,
+    EthApiError, EthCallCache, EthProofMetrics, EthStateCache, FeeHistoryCache, GasCap,
+    GasPriceOracle, PendingBlock,
 };
+use reth_rpc_server_types::RpcServerLimits;
 use reth_storage_api::{noop::NoopProvider, BlockReaderIdExt, ProviderHeader};
 use reth_tasks::{
     pool::{BlockingTaskGuard, BlockingTaskPool},
@@ -253,6 +255,10 @@ pub struct EthApiInner<N: RpcNodeCore, Rpc: RpcConvert> {
 
     /// Guard for getproof calls
     blocking_task_guard: BlockingTaskGuard,
+    /// Maximum number of tasks a single getproof call computes its storage proofs with.
+    proof_concurrency: usize,
+    /// Metrics of getproof calls.
+    proof_metrics: EthProofMetrics,
 
     /// Semaphore to limit concurrent blocking IO requests (`eth_call`, `eth_estimateGas`, etc.)
     blocking_io_request_semaphore: Arc<Semaphore>,
@@ -287,6 +293,15 @@ pub struct EthApiInner<N: RpcNodeCore, Rpc: RpcConvert> {
 
     /// Whether to force upcasting EIP-4844 blob sidecars to EIP-7594 format when Osaka is active.
     force_blob_sidecar_upcasting: bool,
+
+    /// Reconstruction of pruned historical state, if enabled.
+    pruned_state_replay: Option<PrunedStateReplay>,
+
+    /// Cache of `eth_call` and `eth_estimateGas` results at the latest block, if enabled.
+    call_cache: Option<EthCallCache>,
+
+    /// Server limits adjustable at runtime, overriding the gas cap.
+    server_limits: Option<RpcServerLimits>,
 }
 
 impl<N, Rpc> EthApiInner<N, Rpc>
@@ -308,6 +323,7 @@ where
         fee_history_cache: FeeHistoryCache<ProviderHeader<N::Provider>>,
         task_spawner: Runtime,
         proof_permits: usize,
+        proof_concurrency: usize,
         converter: Rpc,
         next_env: impl PendingEnvBuilder<N::Evm>,
         max_batch_size: usize,
@@ -317,6 +333,9 @@ where
         send_raw_transaction_sync_timeout: Duration,
         evm_memory_limit: u64,
         force_blob_sidecar_upcasting: bool,
+        pruned_state_replay_limit: Option<u64>,
+        call_cache_max_entries: Option<u32>,
+        server_limits: Option<RpcServerLimits>,
     ) -> Self {
         let signers = parking_lot::RwLock::new(Default::default());
         // get the block number of the latest block
@@ -352,6 +371,8 @@ where
             blocking_task_pool,
             fee_history_cache,
             blocking_task_guard: BlockingTaskGuard::new(proof_permits),
+            proof_concurrency,
+            proof_metrics: EthProofMetrics::default(),
             blocking_io_request_semaphore: Arc::new(Semaphore::new(max_blocking_io_requests)),
             raw_tx_sender,
             raw_tx_forwarder,
@@ -363,6 +384,9 @@ where
             blob_sidecar_converter: BlobSidecarConverter::new(),
             evm_memory_limit,
             force_blob_sidecar_upcasting,
+            pruned_state_replay: pruned_state_replay_limit.map(PrunedStateReplay::new),
+            call_cache: call_cache_max_entries.map(EthCallCache::new),
+            server_limits,
         }
     }
 }
@@ -429,10 +453,10 @@ where
         self.components.pool()
     }
 
-    /// Returns the gas cap.
+    /// Returns the gas cap, or its override if it was adjusted at runtime.
     #[inline]
-    pub const fn gas_cap(&self) -> u64 {
-        self.gas_cap
+    pub fn gas_cap(&self) -> u64 {
+        self.server_limits.as_ref().and_then(RpcServerLimits::gas_cap).unwrap_or(self.gas_cap)
     }
 
     /// Returns the `max_simulate_blocks`.
@@ -483,6 +507,18 @@ where
         self.eth_proof_window
     }
 
+    /// The maximum number of tasks a single getproof call computes its storage proofs with.
+    #[inline]
+    pub const fn proof_concurrency(&self) -> usize {
+        self.proof_concurrency
+    }
+
+    /// Returns the metrics of getproof calls.
+    #[inline]
+    pub const fn proof_metrics(&self) -> &EthProofMetrics {
+        &self.proof_metrics
+    }
+
     /// Returns reference to [`BlockingTaskGuard`].
     #[inline]
     pub const fn blocking_task_guard(&self) -> &BlockingTaskGuard {
@@ -567,6 +603,18 @@ where
     pub const fn force_blob_sidecar_upcasting(&self) -> bool {
         self.force_blob_sidecar_upcasting
     }
+
+    /// Returns the reconstruction of pruned historical state, if enabled.
+    #[inline]
+    pub const fn pruned_state_replay(&self) -> Option<&PrunedStateReplay> {
+        self.pruned_state_replay.as_ref()
+    }
+
+    /// Returns the cache of `eth_call` and `eth_estimateGas` results, if enabled.
+    #[inline]
+    pub const fn call_cache(&self) -> Option<&EthCallCache> {
+        self.call_cache.as_ref()
+    }
 }
 
 #[cfg(test)]
@@ -589,7 +637,10 @@ mod tests {
         PruneCheckpointReader, StageCheckpointReader,
     };
     use reth_rpc_eth_api::{node::RpcNodeCoreAdapter, EthApiServer};
-    use reth_storage_api::{BalProvider, BlockReader, BlockReaderIdExt, StateProviderFactory};
+    use reth_storage_api::{
+        BalProvider, BlockReader, BlockReaderIdExt, LogIndexProvider, StateProviderFactory,
+        TraceAddressIndexProvider,
+    };
     use reth_testing_utils::generators;
     use reth_transaction_pool::test_utils::{testing_pool, TestPool};
 
@@ -611,6 +662,8 @@ mod tests {
             + StageCheckpointReader
             + PruneCheckpointReader
             + BalProvider
+            + TraceAddressIndexProvider
+            + LogIndexProvider
             + Unpin
             + Clone
             + 'static,
@@ -9,12 +9,14 @@ use alloy_rpc_types_eth::{
 };
 use async_trait::async_trait;
 use futures::{
-    future::TryFutureExt,
-    stream::{FuturesOrdered, StreamExt},
-    Future,
+    future::{ready, Either, TryFutureExt},
+    stream::{self, FuturesOrdered, StreamExt},
+    Future, Stream,
 };
 use itertools::Itertools;
-use jsonrpsee::{core::RpcResult, server::IdProvider};
+use jsonrpsee::{
+    core::RpcResult, server::IdProvider, PendingSubscriptionSink, SubscriptionMessage,
+};
 use reth_errors::ProviderError;
 use reth_primitives_traits::{NodePrimitives, SealedHeader};
 use reth_rpc_eth_api::{
@@ -28,8 +30,8 @@ use reth_rpc_eth_types::{
 };
 use reth_rpc_server_types::{result::rpc_error_with_code, ToRpcResult};
 use reth_storage_api::{
-    BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, HeaderProvider, ProviderBlock,
-    ProviderReceipt, ReceiptProvider,
+    BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, HeaderProvider, LogIndexProvider,
+    ProviderBlock, ProviderReceipt, ReceiptProvider,
 };
 use reth_tasks::Runtime;
 use reth_transaction_pool::{NewSubpoolTransactionStream, PoolTransaction, TransactionPool};
@@ -327,6 +329,79 @@ where
     ) -> Result<Vec<Log>, EthFilterError> {
         self.inner.clone().logs_for_filter(filter, limits).await
     }
+
+    /// Returns a stream of the logs matching given filter object.
+    ///
+    /// The block range of the filter is queried in chunks of at most `max_headers_range` blocks,
+    /// the next chunk being queried once the logs of the previous one were consumed, so only the
+    /// logs of a single chunk are buffered. The range is subject to the maximum number of blocks
+    /// per filter, but not to the maximum number of logs per response.
+    ///
+    /// If querying a chunk fails, the stream ends with the error.
+    pub async fn logs_stream(
+        &self,
+        filter: Filter,
+    ) -> Result<
+        impl Stream<Item = Result<Log, EthFilterError>> + Send + Unpin + 'static,
+        EthFilterError,
+    > {
+        let (from_block, to_block) = match filter.block_option {
+            FilterBlockOption::Range { from_block, to_block }
+                if !from_block.is_some_and(|b| b.is_pending()) =>
+            {
+                (from_block, to_block)
+            }
+            _ => {
+                // a single block
+                let logs = self.logs_for_filter(filter, self.inner.query_limits).await?;
+                return Ok(Either::Left(stream::iter(logs.into_iter().map(Ok))))
+            }
+        };
+
+        let Some((from_block, to_block)) = self.inner.filter_block_range(from_block, to_block)?
+        else {
+            return Ok(Either::Left(stream::iter(Vec::new().into_iter().map(Ok))))
+        };
+        if let Some(max_blocks_per_filter) = self
+            .inner
+            .query_limits
+            .max_blocks_per_filter
+            .filter(|limit| to_block - from_block > *limit)
+        {
+            return Err(EthFilterError::QueryExceedsMaxBlocks(max_blocks_per_filter))
+        }
+
+        let inner = self.inner.clone();
+        let chunks = BlockRangeInclusiveIter::new(from_block..=to_block, inner.max_headers_range);
+        let logs = stream::iter(chunks).then(move |(from, to)| {
+            inner.clone().get_logs_in_block_range(
+                filter.clone(),
+                from,
+                to,
+                QueryLimits::no_limits(),
+            )
+        });
+
+        Ok(Either::Right(Box::pin(flatten_log_chunks(logs))))
+    }
+}
+
+/// Flattens the logs of the queried block range chunks, ending the stream with the error of the
+/// first chunk that failed.
+fn flatten_log_chunks<E>(
+    chunks: impl Stream<Item = Result<Vec<Log>, E>>,
+) -> impl Stream<Item = Result<Log, E>> {
+    chunks
+        .scan(false, |failed, res| {
+            ready((!*failed).then(|| {
+                *failed = res.is_err();
+                res
+            }))
+        })
+        .flat_map(|res| match res {
+            Ok(logs) => Either::Left(stream::iter(logs.into_iter().map(Ok))),
+            Err(err) => Either::Right(stream::once(ready(Err(err)))),
+        })
 }
 
 #[async_trait]
@@ -415,6 +490,50 @@ where
         trace!(target: "rpc::eth", "Serving eth_getLogs");
         Ok(self.logs_for_filter(filter, self.inner.query_limits).await?)
     }
+
+    /// Streams logs matching given filter object.
+    ///
+    /// Handler for `eth_subscribeGetLogs`
+    async fn subscribe_logs(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: Filter,
+    ) -> jsonrpsee::core::SubscriptionResult {
+        trace!(target: "rpc::eth", "Serving eth_subscribeGetLogs");
+        let mut logs = match self.logs_stream(filter).await {
+            Ok(logs) => logs,
+            Err(err) => {
+                pending.reject(err).await;
+                return Ok(())
+            }
+        };
+
+        let sink = pending.accept().await?;
+        loop {
+            tokio::select! {
+                _ = sink.closed() => return Ok(()),
+                log = logs.next() => match log {
+                    Some(Ok(log)) => {
+                        let msg = SubscriptionMessage::new(
+                            sink.method_name(),
+                            sink.subscription_id(),
+                            &log,
+                        )?;
+                        if sink.send(msg).await.is_err() {
+                            return Ok(())
+                        }
+                    }
+                    // closes the subscription with the error, so the client can tell a truncated
+                    // stream from a complete one
+                    Some(Err(err)) => {
+                        debug!(target: "rpc::eth::filter", %err, "Failed to stream logs");
+                        return Err(err.into())
+                    }
+                    None => return Ok(()),
+                },
+            }
+        }
+    }
 }
 
 impl<Eth> std::fmt::Debug for EthFilter<Eth>
@@ -549,42 +668,11 @@ where
                     }
                 }
 
-                let info = self.provider().chain_info()?;
-                let start_block = info.best_number;
-                let from = from_block
-                    .map(|num| self.provider().convert_block_number(num))
-                    .transpose()?
-                    .flatten();
-                let to = to_block
-                    .map(|num| self.provider().convert_block_number(num))
-                    .transpose()?
-                    .flatten();
-
-                // Return error if toBlock exceeds current head
-                if let Some(t) = to &&
-                    t > info.best_number
-                {
-                    return Err(EthFilterError::BlockRangeExceedsHead {
-                        requested: t,
-                        head: info.best_number,
-                    });
-                }
-
-                if let Some(f) = from &&
-                    f > info.best_number
-                {
-                    // start block higher than local head, can return empty
-                    return Ok(Vec::new());
-                }
-
-                let (from_block_number, to_block_number) =
-                    logs_utils::get_filter_block_range(from, to, start_block, info)?;
-
-                // Check if the requested range overlaps with pruned history (EIP-4444)
-                let earliest_block = self.provider().earliest_block_number()?;
-                if from_block_number < earliest_block {
-                    return Err(EthApiError::PrunedHistoryUnavailable.into());
-                }
+                let Some((from_block_number, to_block_number)) =
+                    self.filter_block_range(from_block, to_block)?
+                else {
+                    return Ok(Vec::new())
+                };
 
                 self.get_logs_in_block_range(filter, from_block_number, to_block_number, limits)
                     .await
@@ -592,6 +680,50 @@ where
         }
     }
 
+    /// Resolves the _inclusive_ block range of a range filter.
+    ///
+    /// Returns `None` if the range starts after the current head.
+    fn filter_block_range(
+        &self,
+        from_block: Option<BlockNumberOrTag>,
+        to_block: Option<BlockNumberOrTag>,
+    ) -> Result<Option<(u64, u64)>, EthFilterError> {
+        let info = self.provider().chain_info()?;
+        let start_block = info.best_number;
+        let from =
+            from_block.map(|num| self.provider().convert_block_number(num)).transpose()?.flatten();
+        let to =
+            to_block.map(|num| self.provider().convert_block_number(num)).transpose()?.flatten();
+
+        // Return error if toBlock exceeds current head
+        if let Some(t) = to &&
+            t > info.best_number
+        {
+            return Err(EthFilterError::BlockRangeExceedsHead {
+                requested: t,
+                head: info.best_number,
+            });
+        }
+
+        if let Some(f) = from &&
+            f > info.best_number
+        {
+            // start block higher than local head, can return empty
+            return Ok(None);
+        }
+
+        let (from_block_number, to_block_number) =
+            logs_utils::get_filter_block_range(from, to, start_block, info)?;
+
+        // Check if the requested range overlaps with pruned history (EIP-4444)
+        let earliest_block = self.provider().earliest_block_number()?;
+        if from_block_number < earliest_block {
+            return Err(EthApiError::PrunedHistoryUnavailable.into());
+        }
+
+        Ok(Some((from_block_number, to_block_number)))
+    }
+
     /// Installs a new filter and returns the new identifier.
     async fn install_filter(
         &self,
@@ -674,8 +806,10 @@ where
         let chain_tip = self.provider().best_block_number()?;
 
         // first collect all headers that match the bloom filter for cached mode decision
-        for (from, to) in
-            BlockRangeInclusiveIter::new(from_block..=to_block, self.max_headers_range)
+        for (from, to) in self
+            .candidate_block_ranges(filter, from_block, to_block)?
+            .into_iter()
+            .flat_map(|range| BlockRangeInclusiveIter::new(range, self.max_headers_range))
         {
             let headers = self.provider().headers_range(from..=to)?;
 
@@ -758,6 +892,63 @@ where
 
         Ok(all_logs)
     }
+
+    /// Returns the ranges of blocks in `from_block..=to_block` whose headers have to be checked
+    /// for logs matching the filter.
+    ///
+    /// If the log index covers `from_block` and the filter restricts the addresses or topics,
+    /// only the blocks the index reports for them are returned, followed by all blocks above the
+    /// indexed range.
+    fn candidate_block_ranges(
+        &self,
+        filter: &Filter,
+        from_block: u64,
+        to_block: u64,
+    ) -> Result<Vec<RangeInclusive<u64>>, EthFilterError> {
+        let Some(indexed) =
+            self.provider().log_index_range()?.filter(|range| range.contains(&from_block))
+        else {
+            return Ok(vec![from_block..=to_block])
+        };
+        let indexed_range = from_block..=(*indexed.end()).min(to_block);
+
+        let mut candidates: Option<Vec<u64>> = None;
+        let mut intersect = |blocks: Vec<u64>| match &mut candidates {
+            Some(candidates) => candidates.retain(|block| blocks.binary_search(block).is_ok()),
+            None => candidates = Some(blocks),
+        };
+        if !filter.address.is_empty() {
+            let addresses = filter.address.iter().copied().collect::<Vec<_>>();
+            intersect(self.provider().log_address_blocks(&addresses, indexed_range.clone())?);
+        }
+        for topics in filter.topics.iter().filter(|topics| !topics.is_empty()) {
+            let topics = topics.iter().copied().collect::<Vec<_>>();
+            intersect(self.provider().log_topic_blocks(&topics, indexed_range.clone())?);
+        }
+
+        let Some(candidates) = candidates else { return Ok(vec![from_block..=to_block]) };
+        Ok(indexed_block_ranges(candidates, *indexed_range.end(), to_block))
+    }
+}
+
+/// Coalesces the ascending blocks the log index reports into ranges, and appends the blocks in
+/// `indexed_end + 1..=to_block` that aren't covered by the index.
+fn indexed_block_ranges(
+    blocks: impl IntoIterator<Item = u64>,
+    indexed_end: u64,
+    to_block: u64,
+) -> Vec<RangeInclusive<u64>> {
+    let mut ranges: Vec<RangeInclusive<u64>> = Vec::new();
+    for block in blocks {
+        match ranges.last_mut() {
+            Some(range) if *range.end() + 1 == block => *range = *range.start()..=block,
+            _ => ranges.push(block..=block),
+        }
+    }
+    if indexed_end < to_block {
+        ranges.push(indexed_end + 1..=to_block);
+    }
+    ranges
 }
 
 /// All active filters
@@ -1386,6 +1577,14 @@ mod tests {
         assert_eq!(end, *range.end());
     }
 
+    #[test]
+    fn test_indexed_block_ranges() {
+        assert_eq!(indexed_block_ranges([3, 4, 5, 8, 10, 11], 20, 20), vec![3..=5, 8..=8, 10..=11]);
+        assert_eq!(indexed_block_ranges([3, 4], 10, 15), vec![3..=4, 11..=15]);
+        assert_eq!(indexed_block_ranges([], 10, 15), vec![11..=15]);
+        assert_eq!(indexed_block_ranges([], 10, 10), Vec::<RangeInclusive<u64>>::new());
+    }
+
     // Helper function to create a test EthApi instance
     #[expect(clippy::type_complexity)]
     fn build_test_eth_api(
@@ -2004,4 +2203,19 @@ mod tests {
         assert_eq!(logs[0].block_hash, Some(expected_hashes[0])); // block 100
         assert_eq!(logs[1].block_hash, Some(expected_hashes[2])); // block 102
     }
+
+    #[tokio::test]
+    async fn test_flatten_log_chunks_ends_with_error() {
+        let log = Log { block_number: Some(1), ..Default::default() };
+        let chunks = stream::iter(vec![
+            Ok(vec![log.clone(), log.clone()]),
+            Err(EthFilterError::InternalError),
+            Ok(vec![log.clone()]),
+        ]);
+
+        let items = flatten_log_chunks(chunks).collect::<Vec<_>>().await;
+        assert_eq!(items.len(), 3);
+        assert!(items[..2].iter().all(|item| item.as_ref().is_ok_and(|l| *l == log)));
+        assert!(matches!(items[2], Err(EthFilterError::InternalError)));
+    }
 }
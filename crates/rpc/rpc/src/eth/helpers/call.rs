@@ -6,7 +6,7 @@ use reth_rpc_eth_api::{
     helpers::{estimate::EstimateCall, Call, EthCall},
     FromEvmError, RpcNodeCore,
 };
-use reth_rpc_eth_types::EthApiError;
+use reth_rpc_eth_types::{EthApiError, EthCallCache};
 
 impl<N, Rpc> EthCall for EthApi<N, Rpc>
 where
@@ -14,6 +14,10 @@ where
     EthApiError: FromEvmError<N::Evm>,
     Rpc: RpcConvert<Primitives = N::Primitives, Error = EthApiError, Evm = N::Evm>,
 {
+    #[inline]
+    fn call_cache(&self) -> Option<&EthCallCache> {
+        self.inner.call_cache()
+    }
 }
 
 impl<N, Rpc> Call for EthApi<N, Rpc>
@@ -6,7 +6,7 @@ use reth_rpc_eth_api::{
     helpers::{EthState, LoadPendingBlock, LoadState},
     RpcNodeCore,
 };
-use reth_rpc_eth_types::EthApiError;
+use reth_rpc_eth_types::{replay::PrunedStateReplay, EthApiError, EthProofMetrics};
 
 impl<N, Rpc> EthState for EthApi<N, Rpc>
 where
@@ -17,6 +17,14 @@ where
     fn max_proof_window(&self) -> u64 {
         self.inner.eth_proof_window()
     }
+
+    fn proof_concurrency(&self) -> usize {
+        self.inner.proof_concurrency()
+    }
+
+    fn proof_metrics(&self) -> &EthProofMetrics {
+        self.inner.proof_metrics()
+    }
 }
 
 impl<N, Rpc> LoadState for EthApi<N, Rpc>
@@ -25,6 +33,9 @@ where
     Rpc: RpcConvert<Primitives = N::Primitives>,
     Self: LoadPendingBlock,
 {
+    fn pruned_state_replay(&self) -> Option<&PrunedStateReplay> {
+        self.inner.pruned_state_replay()
+    }
 }
 
 #[cfg(test)]
@@ -32,10 +43,14 @@ mod tests {
     use crate::eth::helpers::types::EthRpcConverter;
 
     use super::*;
+    use alloy_consensus::{Header, EMPTY_ROOT_HASH};
+    use alloy_eips::BlockId;
     use alloy_primitives::{
+        keccak256,
         map::{AddressMap, B256Map},
-        Address, StorageKey, StorageValue, U256,
+        Address, Bytes, StorageKey, StorageValue, B256, U256,
     };
+    use alloy_rpc_types_eth::{Account, AccountInfo};
     use reth_chainspec::ChainSpec;
     use reth_evm_ethereum::EthEvmConfig;
     use reth_network_api::noop::NoopNetwork;
@@ -103,4 +118,55 @@ mod tests {
         let account = eth_api.get_account(address, Default::default()).await.unwrap();
         assert!(account.is_none());
     }
+
+    #[tokio::test]
+    async fn test_get_account() {
+        let address = Address::random();
+        let code = Bytes::from_static(&[0x60, 0x00]);
+        let accounts = AddressMap::from_iter([(
+            address,
+            ExtendedAccount::new(1, U256::from(42)).with_bytecode(code.clone()),
+        )]);
+        let eth_api = mock_eth_api(accounts);
+        eth_api.provider().add_header(B256::with_last_byte(1), Header::default());
+
+        let account = eth_api.get_account(address, Default::default()).await.unwrap();
+        assert_eq!(
+            account,
+            Some(Account {
+                balance: U256::from(42),
+                nonce: 1,
+                code_hash: keccak256(&code),
+                storage_root: EMPTY_ROOT_HASH,
+            })
+        );
+
+        let info = eth_api.get_account_info(address, Default::default()).await.unwrap();
+        assert_eq!(info, AccountInfo { balance: U256::from(42), nonce: 1, code });
+
+        // missing accounts have no code
+        let info = eth_api.get_account_info(Address::random(), Default::default()).await.unwrap();
+        assert_eq!(info, AccountInfo { balance: U256::ZERO, nonce: 0, code: Bytes::new() });
+    }
+
+    #[tokio::test]
+    async fn test_get_account_outside_proof_window() {
+        let address = Address::random();
+        let mock_provider = MockEthProvider::default();
+        mock_provider.extend_accounts([(address, ExtendedAccount::new(1, U256::from(42)))]);
+        mock_provider.add_header(B256::with_last_byte(1), Header::default());
+        mock_provider
+            .add_header(B256::with_last_byte(2), Header { number: 10, ..Default::default() });
+        let evm_config = EthEvmConfig::new(mock_provider.chain_spec());
+        let eth_api =
+            EthApi::builder(mock_provider, testing_pool(), NoopNetwork::default(), evm_config)
+                .eth_proof_window(1)
+                .build();
+
+        let block_id = BlockId::number(0);
+        assert!(eth_api.get_account(address, block_id).await.is_err());
+        // missing accounts are returned without checking the window
+        assert_eq!(eth_api.get_account(Address::random(), block_id).await.unwrap(), None);
+        assert!(eth_api.get_account(address, BlockId::number(9)).await.unwrap().is_some());
+    }
 }
@@ -12,16 +12,25 @@ use alloy_rpc_types_eth::{
 };
 use futures::StreamExt;
 use jsonrpsee::{
-    server::SubscriptionMessage, types::ErrorObject, PendingSubscriptionSink, SubscriptionSink,
+    server::{SubscriptionMessage, TrySendError},
+    types::ErrorObject,
+    PendingSubscriptionSink, SubscriptionSink,
 };
 use reth_chain_state::CanonStateSubscriptions;
+use reth_metrics::{
+    metrics::{Counter, Histogram},
+    Metrics,
+};
 use reth_network_api::NetworkInfo;
 use reth_primitives_traits::TransactionMeta;
 use reth_rpc_convert::{transaction::ConvertReceiptInput, RpcHeader};
 use reth_rpc_eth_api::{
     pubsub::EthPubSubApiServer, EthApiTypes, RpcConvert, RpcNodeCore, RpcTransaction,
 };
-use reth_rpc_eth_types::logs_utils;
+use reth_rpc_eth_types::{
+    builder::config::SubscriptionLagPolicy, logs_utils, EthSubscriptionParams,
+    PendingTransactionFilter,
+};
 use reth_rpc_server_types::result::{internal_rpc_err, invalid_params_rpc_err};
 use reth_storage_api::BlockNumReader;
 use reth_tasks::Runtime;
@@ -31,7 +40,7 @@ use tokio_stream::{
     wrappers::{BroadcastStream, ReceiverStream},
     Stream,
 };
-use tracing::error;
+use tracing::{debug, error};
 
 /// `Eth` pubsub RPC implementation.
 ///
@@ -47,7 +56,17 @@ pub struct EthPubSub<Eth> {
 impl<Eth> EthPubSub<Eth> {
     /// Creates a new, shareable instance.
     pub fn new(eth_api: Eth, subscription_task_spawner: Runtime) -> Self {
-        let inner = EthPubSubInner { eth_api, subscription_task_spawner };
+        Self::with_lag_policy(eth_api, subscription_task_spawner, Default::default())
+    }
+
+    /// Creates a new, shareable instance applying the given policy to the messages of
+    /// subscriptions once the outbound queue of their connection is full.
+    pub fn with_lag_policy(
+        eth_api: Eth,
+        subscription_task_spawner: Runtime,
+        lag_policy: SubscriptionLagPolicy,
+    ) -> Self {
+        let inner = EthPubSubInner { eth_api, subscription_task_spawner, lag_policy };
         Self { inner: Arc::new(inner) }
     }
 }
@@ -88,61 +107,107 @@ where
         &self,
         accepted_sink: SubscriptionSink,
         kind: SubscriptionKind,
-        params: Option<Params>,
+        params: Option<EthSubscriptionParams>,
     ) -> Result<(), ErrorObject<'static>> {
         #[allow(unreachable_patterns)]
         match kind {
             SubscriptionKind::NewHeads => {
-                pipe_from_stream(accepted_sink, self.new_headers_stream()).await
+                pipe_with_lag_policy(
+                    accepted_sink,
+                    self.new_headers_stream(),
+                    self.inner.lag_policy,
+                    "newHeads",
+                )
+                .await
             }
             SubscriptionKind::Logs => {
                 // if no params are provided, used default filter params
                 let filter = match params {
-                    Some(Params::Logs(filter)) => *filter,
-                    Some(Params::Bool(_)) => {
-                        return Err(invalid_params_rpc_err("Invalid params for logs"))
-                    }
+                    Some(EthSubscriptionParams::Params(Params::Logs(filter))) => *filter,
+                    Some(
+                        EthSubscriptionParams::Params(Params::Bool(_)) |
+                        EthSubscriptionParams::PendingTransactions(_),
+                    ) => return Err(invalid_params_rpc_err("Invalid params for logs")),
                     _ => Default::default(),
                 };
-                pipe_from_stream(accepted_sink, self.log_stream(filter)).await
+                pipe_with_lag_policy(
+                    accepted_sink,
+                    self.log_stream(filter),
+                    self.inner.lag_policy,
+                    "logs",
+                )
+                .await
             }
             SubscriptionKind::NewPendingTransactions => {
-                if let Some(params) = params {
-                    match params {
-                        Params::Bool(true) => {
-                            // full transaction objects requested
-                            let stream = self.full_pending_transaction_stream().filter_map(|tx| {
-                                let tx_value = match self
-                                    .inner
-                                    .eth_api
-                                    .converter()
-                                    .fill_pending(tx.transaction.to_consensus())
-                                {
-                                    Ok(tx) => Some(tx),
-                                    Err(err) => {
-                                        error!(target = "rpc",
-                                            %err,
-                                            "Failed to fill transaction with block context"
-                                        );
-                                        None
-                                    }
-                                };
-                                std::future::ready(tx_value)
-                            });
-                            return pipe_from_stream(accepted_sink, stream).await
-                        }
-                        Params::Bool(false) | Params::None => {
-                            // only hashes requested
-                        }
-                        _ => {
-                            return Err(invalid_params_rpc_err(
-                                "Invalid params for newPendingTransactions",
-                            ))
-                        }
+                let filter = match params {
+                    None | Some(EthSubscriptionParams::Params(Params::None)) => {
+                        PendingTransactionFilter::default()
+                    }
+                    Some(EthSubscriptionParams::Params(Params::Bool(full_transactions))) => {
+                        PendingTransactionFilter { full_transactions, ..Default::default() }
                     }
+                    Some(EthSubscriptionParams::PendingTransactions(filter)) => filter,
+                    Some(EthSubscriptionParams::Params(_)) => {
+                        return Err(invalid_params_rpc_err(
+                            "Invalid params for newPendingTransactions",
+                        ))
+                    }
+                };
+
+                if !filter.full_transactions && filter.matches_all() {
+                    // only hashes requested
+                    return pipe_with_lag_policy(
+                        accepted_sink,
+                        self.pending_transaction_hashes_stream(),
+                        self.inner.lag_policy,
+                        "newPendingTransactions",
+                    )
+                    .await
+                }
+
+                let full_transactions = filter.full_transactions;
+                let stream = self.full_pending_transaction_stream().filter(move |tx| {
+                    std::future::ready(
+                        filter.matches(tx.transaction.sender(), &tx.transaction.transaction),
+                    )
+                });
+                if !full_transactions {
+                    let stream = stream.map(|tx| *tx.transaction.hash());
+                    return pipe_with_lag_policy(
+                        accepted_sink,
+                        stream,
+                        self.inner.lag_policy,
+                        "newPendingTransactions",
+                    )
+                    .await
                 }
 
-                pipe_from_stream(accepted_sink, self.pending_transaction_hashes_stream()).await
+                // full transaction objects requested
+                let stream = stream.filter_map(|tx| {
+                    let tx_value = match self
+                        .inner
+                        .eth_api
+                        .converter()
+                        .fill_pending(tx.transaction.to_consensus())
+                    {
+                        Ok(tx) => Some(tx),
+                        Err(err) => {
+                            error!(target = "rpc",
+                                %err,
+                                "Failed to fill transaction with block context"
+                            );
+                            None
+                        }
+                    };
+                    std::future::ready(tx_value)
+                });
+                pipe_with_lag_policy(
+                    accepted_sink,
+                    stream,
+                    self.inner.lag_policy,
+                    "newPendingTransactions",
+                )
+                .await
             }
             SubscriptionKind::Syncing => {
                 // get new block subscription
@@ -191,8 +256,12 @@ where
             }
             SubscriptionKind::TransactionReceipts => {
                 let filter = match params {
-                    Some(Params::TransactionReceipts(filter)) => filter,
-                    None | Some(Params::None) => TransactionReceiptsParams::default(),
+                    Some(EthSubscriptionParams::Params(Params::TransactionReceipts(filter))) => {
+                        filter
+                    }
+                    None | Some(EthSubscriptionParams::Params(Params::None)) => {
+                        TransactionReceiptsParams::default()
+                    }
                     _ => {
                         return Err(invalid_params_rpc_err("Invalid params for transactionReceipts"))
                     }
@@ -277,7 +346,13 @@ where
                     },
                 );
 
-                pipe_from_stream(accepted_sink, stream).await
+                pipe_with_lag_policy(
+                    accepted_sink,
+                    stream,
+                    self.inner.lag_policy,
+                    "transactionReceipts",
+                )
+                .await
             }
             _ => Err(invalid_params_rpc_err("Unsupported subscription kind")),
         }
@@ -294,7 +369,7 @@ where
         &self,
         pending: PendingSubscriptionSink,
         kind: SubscriptionKind,
-        params: Option<Params>,
+        params: Option<EthSubscriptionParams>,
     ) -> jsonrpsee::core::SubscriptionResult {
         let sink = pending.accept().await?;
         let pubsub = self.clone();
@@ -323,8 +398,72 @@ impl From<SubscriptionSerializeError> for ErrorObject<'static> {
     }
 }
 
+/// Metrics of the `eth_subscribe` subscriptions of a kind.
+#[derive(Metrics)]
+#[metrics(scope = "rpc.eth_subscriptions")]
+struct SubscriptionMetrics {
+    /// Number of messages queued for the connection when a message is sent
+    queued_messages: Histogram,
+    /// Number of messages that waited for capacity in the outbound queue of the connection
+    delayed_messages: Counter,
+    /// Number of messages dropped because the outbound queue of the connection was full
+    dropped_messages: Counter,
+    /// Number of subscriptions closed because the outbound queue of the connection was full
+    closed_subscriptions: Counter,
+}
+
+/// Pipes all stream items to the subscription sink, applying the [`SubscriptionLagPolicy`] to the
+/// items the outbound queue of the connection has no capacity for.
+async fn pipe_with_lag_policy<T, St>(
+    mut sink: SubscriptionSink,
+    mut stream: St,
+    lag_policy: SubscriptionLagPolicy,
+    kind: &'static str,
+) -> Result<(), ErrorObject<'static>>
+where
+    St: Stream<Item = T> + Unpin,
+    T: Serialize,
+{
+    let metrics = SubscriptionMetrics::new_with_labels(&[("kind", kind)]);
+    loop {
+        let maybe_item = tokio::select! {
+            _ = sink.closed() => {
+                // connection dropped
+                break Ok(())
+            },
+            maybe_item = stream.next() => maybe_item,
+        };
+        let Some(item) = maybe_item else {
+            // stream ended
+            break Ok(())
+        };
+        let msg = SubscriptionMessage::new(sink.method_name(), sink.subscription_id(), &item)
+            .map_err(SubscriptionSerializeError::new)?;
+
+        metrics.queued_messages.record((sink.max_capacity() - sink.capacity()) as f64);
+        match sink.try_send(msg) {
+            Ok(()) => {}
+            Err(TrySendError::Closed(_)) => break Ok(()),
+            Err(TrySendError::Full(msg)) => match lag_policy {
+                SubscriptionLagPolicy::Block => {
+                    metrics.delayed_messages.increment(1);
+                    if sink.send(msg).await.is_err() {
+                        break Ok(())
+                    }
+                }
+                SubscriptionLagPolicy::Drop => metrics.dropped_messages.increment(1),
+                SubscriptionLagPolicy::Close => {
+                    metrics.closed_subscriptions.increment(1);
+                    debug!(target: "rpc::eth", kind, "Closing lagging subscription");
+                    break Ok(())
+                }
+            },
+        }
+    }
+}
+
 /// Pipes all stream items to the subscription sink.
-async fn pipe_from_stream<T, St>(
+pub(crate) async fn pipe_from_stream<T, St>(
     sink: SubscriptionSink,
     mut stream: St,
 ) -> Result<(), ErrorObject<'static>>
@@ -373,6 +512,8 @@ struct EthPubSubInner<EthApi> {
     eth_api: EthApi,
     /// The type that's used to spawn subscription tasks.
     subscription_task_spawner: Runtime,
+    /// Policy applied to the messages of subscriptions lagging behind.
+    lag_policy: SubscriptionLagPolicy,
 }
 
 // == impl EthPubSubInner ===
@@ -464,3 +605,65 @@ where
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::{
+        core::{EmptyServerParams, SubscriptionResult},
+        RpcModule,
+    };
+
+    const ITEMS: u64 = 100;
+
+    async fn pipe_items(
+        pending: PendingSubscriptionSink,
+        lag_policy: SubscriptionLagPolicy,
+    ) -> SubscriptionResult {
+        let sink = pending.accept().await?;
+        pipe_with_lag_policy(sink, futures::stream::iter(0..ITEMS), lag_policy, "test").await?;
+        Ok(())
+    }
+
+    /// Subscribes to [`ITEMS`] items piped with the lag policy, with an outbound queue of a
+    /// single message, and returns the items received until the subscription ended.
+    async fn received_items(lag_policy: SubscriptionLagPolicy) -> Vec<u64> {
+        let mut module = RpcModule::new(());
+        module
+            .register_subscription(
+                "subscribe",
+                "notification",
+                "unsubscribe",
+                move |_, pending, _, _| pipe_items(pending, lag_policy),
+            )
+            .unwrap();
+
+        let mut sub = module.subscribe("subscribe", EmptyServerParams::new(), 1).await.unwrap();
+        let mut items = Vec::new();
+        while let Some(Ok((item, _))) = sub.next::<u64>().await {
+            items.push(item);
+        }
+        items
+    }
+
+    #[tokio::test]
+    async fn block_lag_policy_delivers_all_messages() {
+        let items = received_items(SubscriptionLagPolicy::Block).await;
+        assert_eq!(items, (0..ITEMS).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn drop_lag_policy_drops_messages() {
+        let items = received_items(SubscriptionLagPolicy::Drop).await;
+        assert!(items.len() < ITEMS as usize);
+        assert!(items.is_sorted());
+    }
+
+    #[tokio::test]
+    async fn close_lag_policy_closes_subscription() {
+        let items = received_items(SubscriptionLagPolicy::Close).await;
+        assert!(items.len() < ITEMS as usize);
+        // no message is dropped before the subscription is closed
+        assert_eq!(items, (0..items.len() as u64).collect::<Vec<_>>());
+    }
+}
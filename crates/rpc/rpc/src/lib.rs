@@ -29,8 +29,10 @@ mod admin;
 mod aliases;
 mod debug;
 mod engine;
+mod erigon;
 pub mod eth;
 mod miner;
+mod native_tracer;
 mod net;
 mod otterscan;
 mod reth;
@@ -41,19 +43,21 @@ mod txpool;
 mod validation;
 mod web3;
 
-pub use admin::AdminApi;
+pub use admin::{AdminApi, AdminPipelineApi};
 pub use aliases::*;
 pub use debug::DebugApi;
 pub use engine::{EngineApi, EngineEthApi};
+pub use erigon::ErigonApi;
 pub use eth::{helpers::SyncListener, EthApi, EthApiBuilder, EthBundle, EthFilter, EthPubSub};
 pub use miner::MinerApi;
+pub use native_tracer::{NativeTracer, NativeTracers};
 pub use net::NetApi;
 pub use otterscan::OtterscanApi;
-pub use reth::RethApi;
+pub use reth::{RethApi, RethSyncApi};
 pub use reth_rpc_convert::RpcTypes;
 pub use rpc::RPCApi;
 pub use testing::TestingApi;
-pub use trace::TraceApi;
+pub use trace::{trace_filter_addresses, TraceApi};
 pub use txpool::TxPoolApi;
 pub use validation::{ValidationApi, ValidationApiConfig};
 pub use web3::Web3Api;
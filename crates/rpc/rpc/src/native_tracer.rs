@@ -0,0 +1,95 @@
+//! Custom native tracers of the `debug_trace*` methods.
+
+use alloy_rpc_types_trace::geth::{
+    GethDebugTracerConfig, GethDebugTracerType, GethDebugTracingOptions,
+};
+use reth_evm::{EvmEnvFor, HaltReasonFor, TxEnvFor};
+use reth_rpc_eth_api::helpers::TraceExt;
+use reth_rpc_eth_types::StateCacheDb;
+use revm::context_interface::result::ResultAndState;
+use revm_inspectors::tracing::TransactionContext;
+use std::{collections::HashMap, fmt, sync::Arc};
+
+/// A tracer implemented as a revm inspector, selectable by name with the `tracer` field of the
+/// `debug_trace*` requests, like the built-in `callTracer` and `prestateTracer`.
+///
+/// A tracer traces a single transaction: it executes the transaction with its inspector, e.g. with
+/// [`Trace::inspect`](reth_rpc_eth_api::helpers::Trace::inspect), and returns the trace together
+/// with the result of the execution. The trace is returned to the client as is. When tracing the
+/// transactions of a block, the caller commits the state changes of each transaction before
+/// tracing the next one.
+pub trait NativeTracer<Eth>: Send + Sync + 'static {
+    /// Traces the transaction on the database, configured with the `tracerConfig` of the request.
+    ///
+    /// The context is set if the transaction is part of a block.
+    fn trace(
+        &self,
+        eth_api: &Eth,
+        db: &mut StateCacheDb,
+        evm_env: EvmEnvFor<Eth::Evm>,
+        tx_env: TxEnvFor<Eth::Evm>,
+        config: &GethDebugTracerConfig,
+        tx_ctx: Option<TransactionContext>,
+    ) -> Result<(serde_json::Value, ResultAndState<HaltReasonFor<Eth::Evm>>), Eth::Error>
+    where
+        Eth: TraceExt;
+}
+
+/// Registry of the [`NativeTracer`]s of the `debug` API, by name.
+///
+/// Names of built-in tracers, and the `blockAccessList` tracer of `debug_traceCall`, can't be
+/// registered, requests selecting them always use the built-in tracer.
+pub struct NativeTracers<Eth> {
+    tracers: HashMap<String, Arc<dyn NativeTracer<Eth>>>,
+}
+
+impl<Eth> NativeTracers<Eth> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self { tracers: HashMap::new() }
+    }
+
+    /// Registers the tracer under the name, replacing the tracer registered under the same name,
+    /// if any.
+    pub fn register(&mut self, name: impl Into<String>, tracer: impl NativeTracer<Eth>) {
+        self.tracers.insert(name.into(), Arc::new(tracer));
+    }
+
+    /// Registers the tracer under the name, see [`Self::register`].
+    pub fn with_tracer(mut self, name: impl Into<String>, tracer: impl NativeTracer<Eth>) -> Self {
+        self.register(name, tracer);
+        self
+    }
+
+    /// Returns `true` if no tracer is registered.
+    pub fn is_empty(&self) -> bool {
+        self.tracers.is_empty()
+    }
+
+    /// Returns the registered tracer selected by the options, if any.
+    pub fn get(&self, opts: &GethDebugTracingOptions) -> Option<Arc<dyn NativeTracer<Eth>>> {
+        // names that aren't built-in tracers are parsed as JS tracers
+        match &opts.tracer {
+            Some(GethDebugTracerType::JsTracer(name)) => self.tracers.get(name).cloned(),
+            _ => None,
+        }
+    }
+}
+
+impl<Eth> Default for NativeTracers<Eth> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Eth> Clone for NativeTracers<Eth> {
+    fn clone(&self) -> Self {
+        Self { tracers: self.tracers.clone() }
+    }
+}
+
+impl<Eth> fmt::Debug for NativeTracers<Eth> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeTracers").field("tracers", &self.tracers.keys()).finish()
+    }
+}
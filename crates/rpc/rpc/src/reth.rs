@@ -10,18 +10,20 @@ use reth_chain_state::{
     CanonStateNotification, CanonStateSubscriptions, ForkChoiceSubscriptions,
     PersistedBlockSubscriptions,
 };
-use reth_errors::{RethError, RethResult};
+use reth_errors::RethError;
 use reth_evm::{execute::Executor, ConfigureEvm};
-use reth_execution_types::ExecutionOutcome;
+use reth_execution_types::{Chain, ExecutionOutcome};
 use reth_primitives_traits::{NodePrimitives, SealedHeader};
-use reth_rpc_api::{RethApiServer, RethJitAction};
+use reth_rpc_api::{
+    ChainNotificationOptions, RethApiServer, RethJitAction, RethSyncApiServer, RethSyncStatus,
+};
 use reth_rpc_eth_types::{EthApiError, EthResult};
 use reth_storage_api::{
-    BlockReader, BlockReaderIdExt, ChangeSetReader, StateProviderFactory, TransactionVariant,
+    BlockChangesetReader, BlockReader, BlockReaderIdExt, StateProviderFactory, TransactionVariant,
 };
 use reth_tasks::{pool::BlockingTaskGuard, Runtime};
 use serde::Serialize;
-use tokio::sync::oneshot;
+use tokio::sync::{oneshot, watch};
 
 /// `reth` API implementation.
 ///
@@ -58,7 +60,7 @@ impl<Provider, EvmConfig> RethApi<Provider, EvmConfig> {
 
 impl<Provider, EvmConfig> RethApi<Provider, EvmConfig>
 where
-    Provider: BlockReaderIdExt + ChangeSetReader + StateProviderFactory + 'static,
+    Provider: BlockReaderIdExt + BlockChangesetReader + StateProviderFactory + 'static,
     EvmConfig: Send + Sync + 'static,
 {
     /// Executes the future on a new blocking task.
@@ -88,20 +90,17 @@ where
             return Err(EthApiError::HeaderNotFound(block_id))
         };
 
-        let state = self.provider().state_by_block_id(block_id)?;
-        let accounts_before = self.provider().account_block_changeset(block_number)?;
-        let hash_map = accounts_before.iter().try_fold(
-            AddressMap::default(),
-            |mut hash_map, account_before| -> RethResult<_> {
-                let current_balance = state.account_balance(&account_before.address)?;
-                let prev_balance = account_before.info.map(|info| info.balance);
-                if current_balance != prev_balance {
-                    hash_map.insert(account_before.address, current_balance.unwrap_or_default());
-                }
-                Ok(hash_map)
-            },
-        )?;
-        Ok(hash_map)
+        let changeset = self.provider().block_changeset(block_number)?;
+        let balance_changes = changeset
+            .accounts
+            .into_iter()
+            .filter_map(|change| {
+                let post_balance = change.post.map(|account| account.balance);
+                (post_balance != change.pre.map(|account| account.balance))
+                    .then(|| (change.address, post_balance.unwrap_or_default()))
+            })
+            .collect();
+        Ok(balance_changes)
     }
 }
 
@@ -109,7 +108,7 @@ impl<N, Provider, EvmConfig> RethApi<Provider, EvmConfig>
 where
     N: NodePrimitives,
     Provider: BlockReaderIdExt
-        + ChangeSetReader
+        + BlockChangesetReader
         + StateProviderFactory
         + BlockReader<Block = N::Block>
         + CanonStateSubscriptions<Primitives = N>
@@ -189,7 +188,7 @@ where
 impl<Provider, EvmConfig> RethApiServer for RethApi<Provider, EvmConfig>
 where
     Provider: BlockReaderIdExt
-        + ChangeSetReader
+        + BlockChangesetReader
         + StateProviderFactory
         + BlockReader<Block = <Provider::Primitives as NodePrimitives>::Block>
         + CanonStateSubscriptions
@@ -249,9 +248,17 @@ where
     async fn reth_subscribe_chain_notifications(
         &self,
         pending: PendingSubscriptionSink,
+        options: Option<ChainNotificationOptions>,
     ) -> jsonrpsee::core::SubscriptionResult {
         let sink = pending.accept().await?;
-        let stream = self.provider().canonical_state_stream();
+        let ChainNotificationOptions { include_state_diffs } = options.unwrap_or_default();
+        let stream = self.provider().canonical_state_stream().map(move |notification| {
+            if include_state_diffs {
+                notification
+            } else {
+                without_state_diffs(notification)
+            }
+        });
         self.inner.task_spawner.spawn_task(pipe_from_stream(sink, stream));
 
         Ok(())
@@ -287,6 +294,27 @@ where
     }
 }
 
+/// Drops the state diffs of the chain segments of the notification, keeping their blocks and
+/// receipts.
+fn without_state_diffs<N: NodePrimitives>(
+    notification: CanonStateNotification<N>,
+) -> CanonStateNotification<N> {
+    let strip = |chain: Arc<Chain<N>>| {
+        let mut chain = Arc::unwrap_or_clone(chain);
+        chain.execution_outcome_mut().bundle = Default::default();
+        chain.clear_trie_data();
+        Arc::new(chain)
+    };
+    match notification {
+        CanonStateNotification::Commit { new } => {
+            CanonStateNotification::Commit { new: strip(new) }
+        }
+        CanonStateNotification::Reorg { old, new } => {
+            CanonStateNotification::Reorg { old: strip(old), new: strip(new) }
+        }
+    }
+}
+
 /// Pipes all stream items to the subscription sink.
 async fn pipe_from_stream<S, T>(sink: SubscriptionSink, mut stream: S)
 where
@@ -404,3 +432,84 @@ struct RethApiInner<Provider, EvmConfig> {
     /// The type that can spawn tasks which would otherwise block.
     task_spawner: Runtime,
 }
+
+/// `reth` API implementation for the sync progress of the node.
+///
+/// Serves the latest [`RethSyncStatus`] published by the sync pipeline.
+#[derive(Debug, Clone)]
+pub struct RethSyncApi {
+    status: watch::Receiver<Option<RethSyncStatus>>,
+}
+
+impl RethSyncApi {
+    /// Create a new instance of the [`RethSyncApi`] that serves the sync status published on the
+    /// given channel.
+    pub const fn new(status: watch::Receiver<Option<RethSyncStatus>>) -> Self {
+        Self { status }
+    }
+}
+
+#[async_trait]
+impl RethSyncApiServer for RethSyncApi {
+    /// Handler for `reth_syncing`
+    async fn reth_syncing(&self) -> RpcResult<Option<RethSyncStatus>> {
+        Ok(self.status.borrow().clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Address;
+    use reth_ethereum_primitives::{Receipt, TxType};
+    use reth_primitives_traits::RecoveredBlock;
+    use reth_trie_common::LazyTrieData;
+    use revm::{database::BundleState, state::AccountInfo};
+
+    fn chain(number: u64) -> Arc<Chain> {
+        let mut block: RecoveredBlock<reth_ethereum_primitives::Block> = Default::default();
+        block.set_block_number(number);
+        let execution_outcome = ExecutionOutcome {
+            bundle: BundleState::builder(number..=number)
+                .state_present_account_info(Address::with_last_byte(1), AccountInfo::default())
+                .build(),
+            receipts: vec![vec![Receipt { tx_type: TxType::Eip1559, ..Default::default() }]],
+            first_block: number,
+            ..Default::default()
+        };
+        Arc::new(Chain::from_block(block, execution_outcome, LazyTrieData::default()))
+    }
+
+    #[test]
+    fn chain_notification_options() {
+        let options = serde_json::from_str::<ChainNotificationOptions>("{}").unwrap();
+        assert!(options.include_state_diffs);
+        assert_eq!(options, ChainNotificationOptions::default());
+
+        let options =
+            serde_json::from_str::<ChainNotificationOptions>(r#"{"includeStateDiffs":false}"#)
+                .unwrap();
+        assert!(!options.include_state_diffs);
+    }
+
+    #[test]
+    fn strip_state_diffs() {
+        let (old, new) = (chain(1), chain(1));
+        let notification = CanonStateNotification::Reorg { old: old.clone(), new: new.clone() };
+
+        let CanonStateNotification::Reorg { old: stripped_old, new: stripped_new } =
+            without_state_diffs(notification)
+        else {
+            panic!("expected a reorg")
+        };
+        for (chain, stripped) in [(old, stripped_old), (new, stripped_new)] {
+            assert!(!chain.execution_outcome().bundle.state().is_empty());
+            assert!(chain.trie_data_at(1).is_some());
+
+            assert!(stripped.execution_outcome().bundle.state().is_empty());
+            assert!(stripped.trie_data_at(1).is_none());
+            assert_eq!(stripped.blocks(), chain.blocks());
+            assert_eq!(stripped.execution_outcome().receipts, chain.execution_outcome().receipts);
+        }
+    }
+}
@@ -10,7 +10,7 @@ use alloy_rpc_types_eth::{
     BlockOverrides, Index,
 };
 use alloy_rpc_types_trace::{
-    filter::TraceFilter,
+    filter::{TraceFilter, TraceFilterMode},
     opcode::{BlockOpcodeGas, TransactionOpcodeGas},
     parity::*,
     tracerequest::TraceCallRequest,
@@ -19,16 +19,21 @@ use async_trait::async_trait;
 use futures::StreamExt;
 use jsonrpsee::core::RpcResult;
 use reth_chainspec::{ChainSpecProvider, EthereumHardforks};
-use reth_evm::ConfigureEvm;
-use reth_primitives_traits::{BlockBody, BlockHeader};
+use reth_evm::{block::BlockExecutor, execute::BlockExecutionError, ConfigureEvm, Evm as _};
+use reth_primitives_traits::{BlockBody, BlockHeader, BlockTy, RecoveredBlock};
+use reth_revm::{database::StateProviderDatabase, db::State};
 use reth_rpc_api::TraceApiServer;
 use reth_rpc_convert::RpcTxReq;
 use reth_rpc_eth_api::{
     helpers::{Call, LoadPendingBlock, LoadTransaction, Trace, TraceExt},
     FromEthApiError, RpcNodeCore,
 };
-use reth_rpc_eth_types::{error::EthApiError, utils::recover_raw_transaction, EthConfig};
-use reth_storage_api::{BlockNumReader, BlockReader};
+use reth_rpc_eth_types::{
+    error::EthApiError, utils::recover_raw_transaction, EthConfig, TracingCancellation,
+};
+use reth_storage_api::{
+    BlockNumReader, BlockReader, StateProvider, TraceAddressIndexProvider, TransactionVariant,
+};
 use reth_tasks::pool::BlockingTaskGuard;
 use reth_transaction_pool::{PoolPooledTx, PoolTransaction, TransactionPool};
 use revm::DatabaseCommit;
@@ -38,7 +43,7 @@ use revm_inspectors::{
     tracing::{parity::populate_state_diff, TracingInspector, TracingInspectorConfig},
 };
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::{collections::BTreeSet, future::Future, sync::Arc};
 use tokio::sync::{AcquireError, OwnedSemaphorePermit};
 
 /// Maximum number of `trace_filter` blocks replayed concurrently.
@@ -73,6 +78,12 @@ impl<Eth> TraceApi<Eth> {
         self.inner.blocking_task_guard.clone().acquire_owned().await
     }
 
+    /// Runs the tracing call, cancelling its execution once the configured tracing timeout elapsed
+    /// or the call is dropped, e.g. because the client disconnected.
+    async fn traced<R>(&self, call: impl Future<Output = R>) -> R {
+        TracingCancellation::new(self.inner.eth_config.tracing_timeout).instrument(call).await
+    }
+
     /// Access the underlying `Eth` API.
     pub fn eth_api(&self) -> &Eth {
         &self.inner.eth_api
@@ -157,10 +168,13 @@ where
         // execute all transactions on top of each other and record the traces
         self.eth_api()
             .spawn_with_state_at_block(at, move |eth_api, mut db| {
-                let mut results = Vec::with_capacity(calls.len());
+                let total = calls.len();
+                let mut results = Vec::with_capacity(total);
                 let mut calls = calls.into_iter().peekable();
 
                 while let Some((call, trace_types)) = calls.next() {
+                    TracingCancellation::check_current_progress(results.len(), total)
+                        .map_err(Eth::Error::from_eth_err)?;
                     let (evm_env, tx_env) = eth_api.prepare_call_env(
                         evm_env.clone(),
                         call,
@@ -353,7 +367,8 @@ where
     ) -> Result<Vec<LocalizedTransactionTrace>, Eth::Error> {
         // We'll reuse the matcher across multiple blocks that are traced in parallel
         let matcher = Arc::new(filter.matcher());
-        let TraceFilter { from_block, to_block, mut after, count, .. } = filter;
+        let TraceFilter { from_block, to_block, from_address, to_address, mode, mut after, count } =
+            filter;
         let start = from_block.unwrap_or(0);
 
         let latest_block = self.provider().best_block_number().map_err(Eth::Error::from_eth_err)?;
@@ -380,31 +395,56 @@ where
             .into())
         }
 
-        // ensure that the range is not too large, since every block in the range may be replayed
-        let distance = end.saturating_sub(start);
-        if distance > self.inner.eth_config.max_trace_filter_blocks {
+        // skip the blocks that can't contain matching traces if the trace address index covers
+        // them, otherwise every block in the range is replayed
+        let indexed_blocks = match trace_filter_index_addresses(from_address, to_address, mode) {
+            Some(addresses) => self.indexed_trace_filter_blocks(&addresses, start, end)?,
+            None => None,
+        };
+
+        // ensure that the number of replayed blocks is not too large
+        let replayed = indexed_blocks
+            .as_ref()
+            .map_or(end.saturating_sub(start), |blocks| blocks.len().saturating_sub(1) as u64);
+        if replayed > self.inner.eth_config.max_trace_filter_blocks {
             return Err(EthApiError::InvalidParams(format!(
                 "Block range too large; currently limited to {} blocks",
                 self.inner.eth_config.max_trace_filter_blocks
             ))
             .into())
         }
+        let block_numbers = indexed_blocks.unwrap_or_else(|| (start..=end).collect());
 
         let mut all_traces = Vec::new();
         let block_buffer_size =
             self.inner.eth_config.max_tracing_requests.clamp(1, TRACE_FILTER_BLOCK_BUFFER_SIZE);
         let mut include_reward_traces = true;
 
-        for chunk_start in (start..=end).step_by(TRACE_FILTER_FETCH_CHUNK_SIZE) {
-            let chunk_end = (chunk_start + TRACE_FILTER_FETCH_CHUNK_SIZE as u64 - 1).min(end);
+        for chunk in block_numbers.chunks(TRACE_FILTER_FETCH_CHUNK_SIZE) {
+            let chunk = chunk.to_vec();
 
             let blocks = self
                 .eth_api()
                 .spawn_blocking_io(move |this| {
-                    let blocks = this
-                        .provider()
-                        .recovered_block_range(chunk_start..=chunk_end)
-                        .map_err(Eth::Error::from_eth_err)?;
+                    let (chunk_start, chunk_end) = (chunk[0], chunk[chunk.len() - 1]);
+                    let blocks = if chunk_end - chunk_start + 1 == chunk.len() as u64 {
+                        this.provider()
+                            .recovered_block_range(chunk_start..=chunk_end)
+                            .map_err(Eth::Error::from_eth_err)?
+                    } else {
+                        // the blocks skipped via the trace address index leave gaps
+                        chunk
+                            .into_iter()
+                            .map(|number| {
+                                this.provider()
+                                    .recovered_block(number.into(), TransactionVariant::WithHash)
+                                    .map_err(Eth::Error::from_eth_err)?
+                                    .ok_or_else(|| {
+                                        EthApiError::HeaderNotFound(number.into()).into()
+                                    })
+                            })
+                            .collect::<Result<Vec<_>, Eth::Error>>()?
+                    };
 
                     Ok(blocks.into_iter().map(Arc::new).collect::<Vec<_>>())
                 })
@@ -493,6 +533,35 @@ where
         Ok(all_traces)
     }
 
+    /// Returns the blocks in `start..=end` whose traces involve any of the addresses, according to
+    /// the trace address index.
+    ///
+    /// Blocks above the indexed range are all returned, since the index only lags behind the
+    /// chain. Returns `None` if the index doesn't cover the start of the range.
+    fn indexed_trace_filter_blocks(
+        &self,
+        addresses: &[Address],
+        start: u64,
+        end: u64,
+    ) -> Result<Option<Vec<u64>>, Eth::Error> {
+        let Some(indexed) =
+            self.provider().trace_address_index_range().map_err(Eth::Error::from_eth_err)?
+        else {
+            return Ok(None)
+        };
+        if !indexed.contains(&start) {
+            return Ok(None)
+        }
+
+        let indexed_end = end.min(*indexed.end());
+        let mut blocks = self
+            .provider()
+            .trace_address_blocks(addresses, start..=indexed_end)
+            .map_err(Eth::Error::from_eth_err)?;
+        blocks.extend(indexed_end + 1..=end);
+        Ok(Some(blocks))
+    }
+
     /// Returns traces created at given block.
     pub async fn trace_block(
         &self,
@@ -646,6 +715,21 @@ where
     }
 }
 
+/// Returns the addresses a block's traces must involve to match the filter, or `None` if the
+/// filter matches traces of any address.
+fn trace_filter_index_addresses(
+    from_address: Vec<Address>,
+    to_address: Vec<Address>,
+    mode: TraceFilterMode,
+) -> Option<Vec<Address>> {
+    // an empty address set matches every trace
+    let unrestricted = match mode {
+        TraceFilterMode::Union => from_address.is_empty() || to_address.is_empty(),
+        TraceFilterMode::Intersection => from_address.is_empty() && to_address.is_empty(),
+    };
+    (!unrestricted).then(|| from_address.into_iter().chain(to_address).collect())
+}
+
 fn apply_trace_filter_pagination(
     all_traces: &mut Vec<LocalizedTransactionTrace>,
     after: &mut Option<u64>,
@@ -693,7 +777,7 @@ where
         let _permit = self.acquire_trace_permit().await;
         let request =
             TraceCallRequest { call, trace_types, block_id, state_overrides, block_overrides };
-        Ok(Self::trace_call(self, request).await.map_err(Into::into)?)
+        Ok(self.traced(Self::trace_call(self, request)).await.map_err(Into::into)?)
     }
 
     /// Handler for `trace_callMany`
@@ -703,7 +787,7 @@ where
         block_id: Option<BlockId>,
     ) -> RpcResult<Vec<TraceResults>> {
         let _permit = self.acquire_trace_permit().await;
-        Ok(Self::trace_call_many(self, calls, block_id).await.map_err(Into::into)?)
+        Ok(self.traced(Self::trace_call_many(self, calls, block_id)).await.map_err(Into::into)?)
     }
 
     /// Handler for `trace_rawTransaction`
@@ -714,7 +798,8 @@ where
         block_id: Option<BlockId>,
     ) -> RpcResult<TraceResults> {
         let _permit = self.acquire_trace_permit().await;
-        Ok(Self::trace_raw_transaction(self, data, trace_types, block_id)
+        Ok(self
+            .traced(Self::trace_raw_transaction(self, data, trace_types, block_id))
             .await
             .map_err(Into::into)?)
     }
@@ -726,7 +811,8 @@ where
         trace_types: HashSet<TraceType>,
     ) -> RpcResult<Option<Vec<TraceResultsWithTransactionHash>>> {
         let _permit = self.acquire_trace_permit().await;
-        Ok(Self::replay_block_transactions(self, block_id, trace_types)
+        Ok(self
+            .traced(Self::replay_block_transactions(self, block_id, trace_types))
             .await
             .map_err(Into::into)?)
     }
@@ -738,7 +824,10 @@ where
         trace_types: HashSet<TraceType>,
     ) -> RpcResult<TraceResults> {
         let _permit = self.acquire_trace_permit().await;
-        Ok(Self::replay_transaction(self, transaction, trace_types).await.map_err(Into::into)?)
+        Ok(self
+            .traced(Self::replay_transaction(self, transaction, trace_types))
+            .await
+            .map_err(Into::into)?)
     }
 
     /// Handler for `trace_block`
@@ -747,7 +836,7 @@ where
         block_id: BlockId,
     ) -> RpcResult<Option<Vec<LocalizedTransactionTrace>>> {
         let _permit = self.acquire_trace_permit().await;
-        Ok(Self::trace_block(self, block_id).await.map_err(Into::into)?)
+        Ok(self.traced(Self::trace_block(self, block_id)).await.map_err(Into::into)?)
     }
 
     /// Handler for `trace_filter`
@@ -757,7 +846,7 @@ where
     /// # Limitations
     /// This currently requires block filter fields, since reth does not have address indices yet.
     async fn trace_filter(&self, filter: TraceFilter) -> RpcResult<Vec<LocalizedTransactionTrace>> {
-        Ok(Self::trace_filter(self, filter).await.map_err(Into::into)?)
+        Ok(self.traced(Self::trace_filter(self, filter)).await.map_err(Into::into)?)
     }
 
     /// Returns transaction trace at given index.
@@ -768,7 +857,8 @@ where
         indices: Vec<Index>,
     ) -> RpcResult<Option<LocalizedTransactionTrace>> {
         let _permit = self.acquire_trace_permit().await;
-        Ok(Self::trace_get(self, hash, indices.into_iter().map(Into::into).collect())
+        Ok(self
+            .traced(Self::trace_get(self, hash, indices.into_iter().map(Into::into).collect()))
             .await
             .map_err(Into::into)?)
     }
@@ -779,7 +869,7 @@ where
         hash: B256,
     ) -> RpcResult<Option<Vec<LocalizedTransactionTrace>>> {
         let _permit = self.acquire_trace_permit().await;
-        Ok(Self::trace_transaction(self, hash).await.map_err(Into::into)?)
+        Ok(self.traced(Self::trace_transaction(self, hash)).await.map_err(Into::into)?)
     }
 
     /// Handler for `trace_transactionOpcodeGas`
@@ -788,13 +878,16 @@ where
         tx_hash: B256,
     ) -> RpcResult<Option<TransactionOpcodeGas>> {
         let _permit = self.acquire_trace_permit().await;
-        Ok(Self::trace_transaction_opcode_gas(self, tx_hash).await.map_err(Into::into)?)
+        Ok(self
+            .traced(Self::trace_transaction_opcode_gas(self, tx_hash))
+            .await
+            .map_err(Into::into)?)
     }
 
     /// Handler for `trace_blockOpcodeGas`
     async fn trace_block_opcode_gas(&self, block_id: BlockId) -> RpcResult<Option<BlockOpcodeGas>> {
         let _permit = self.acquire_trace_permit().await;
-        Ok(Self::trace_block_opcode_gas(self, block_id).await.map_err(Into::into)?)
+        Ok(self.traced(Self::trace_block_opcode_gas(self, block_id)).await.map_err(Into::into)?)
     }
 }
 
@@ -867,6 +960,52 @@ fn reward_trace<H: BlockHeader>(
     }
 }
 
+/// Replays the transactions of the block on top of the state of its parent and returns the
+/// addresses [`TraceApi::trace_filter`] matches its traces by: the senders and recipients of all
+/// calls, creations and selfdestructs, and the authors of the block and ommer rewards.
+///
+/// This is what the trace address index is built from.
+pub fn trace_filter_addresses<E, DB>(
+    evm_config: &E,
+    db: DB,
+    block: &RecoveredBlock<BlockTy<E::Primitives>>,
+) -> Result<Vec<Address>, BlockExecutionError>
+where
+    E: ConfigureEvm,
+    DB: StateProvider,
+{
+    let mut addresses = BTreeSet::from([block.header().beneficiary()]);
+    if let Some(ommers) = block.body().ommers() {
+        addresses.extend(ommers.iter().map(|ommer| ommer.beneficiary()));
+    }
+    if block.body().transactions().is_empty() {
+        return Ok(addresses.into_iter().collect())
+    }
+
+    let mut db = State::builder().with_database(StateProviderDatabase::new(db)).build();
+    let evm_env = evm_config.evm_env(block.header()).map_err(BlockExecutionError::other)?;
+    let ctx =
+        evm_config.context_for_block(block.sealed_block()).map_err(BlockExecutionError::other)?;
+    let inspector = TracingInspector::new(TracingInspectorConfig::default_parity());
+    let evm = evm_config.evm_with_env_and_inspector(&mut db, evm_env, inspector);
+    let mut executor = evm_config.create_executor(evm, ctx);
+
+    executor.apply_pre_execution_changes()?;
+    for tx in block.transactions_recovered() {
+        // drop the traces of the previous transaction or the pre-execution system calls
+        executor.evm_mut().inspector_mut().fuse();
+        executor.execute_transaction(tx)?;
+
+        let traces = executor.evm_mut().inspector_mut().traces();
+        for node in traces.nodes() {
+            addresses.extend([node.trace.caller, node.trace.address]);
+            addresses.extend(node.trace.selfdestruct_refund_target);
+        }
+    }
+
+    Ok(addresses.into_iter().collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -932,4 +1071,26 @@ mod tests {
 
         assert_eq!(trace_order(&paginated), vec![(1, None, true)]);
     }
+
+    #[test]
+    fn trace_filter_index_addresses_by_mode() {
+        let (a, b) = (Address::with_last_byte(1), Address::with_last_byte(2));
+
+        assert_eq!(trace_filter_index_addresses(vec![], vec![], TraceFilterMode::Union), None);
+        assert_eq!(
+            trace_filter_index_addresses(vec![], vec![], TraceFilterMode::Intersection),
+            None
+        );
+
+        // a union with an empty address set matches every trace
+        assert_eq!(trace_filter_index_addresses(vec![a], vec![], TraceFilterMode::Union), None);
+        assert_eq!(
+            trace_filter_index_addresses(vec![a], vec![b], TraceFilterMode::Union),
+            Some(vec![a, b])
+        );
+        assert_eq!(
+            trace_filter_index_addresses(vec![], vec![b], TraceFilterMode::Intersection),
+            Some(vec![b])
+        );
+    }
 }
@@ -1,5 +1,8 @@
 use core::fmt;
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 
 use alloy_consensus::Transaction;
 use alloy_primitives::Address;
@@ -9,11 +12,13 @@ use alloy_rpc_types_txpool::{
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use reth_primitives_traits::NodePrimitives;
-use reth_rpc_api::TxPoolApiServer;
+use reth_rpc_api::{
+    TxPoolApiServer, TxpoolContentFilter, TxpoolSenderSummary, TxpoolSubpool, TxpoolSummary,
+};
 use reth_rpc_convert::{RpcConvert, RpcTypes};
 use reth_rpc_eth_api::RpcTransaction;
 use reth_transaction_pool::{
-    AllPoolTransactions, PoolConsensusTx, PoolTransaction, TransactionPool,
+    AllPoolTransactions, PoolConsensusTx, PoolTransaction, TransactionPool, ValidPoolTransaction,
 };
 use tracing::trace;
 
@@ -40,6 +45,13 @@ where
     Eth: RpcConvert<Primitives: NodePrimitives<SignedTx = PoolConsensusTx<Pool>>>,
 {
     fn content(&self) -> Result<TxpoolContent<RpcTransaction<Eth::Network>>, Eth::Error> {
+        self.content_of(self.pool.all_transactions())
+    }
+
+    fn content_of(
+        &self,
+        transactions: AllPoolTransactions<Pool::Transaction>,
+    ) -> Result<TxpoolContent<RpcTransaction<Eth::Network>>, Eth::Error> {
         #[inline]
         fn insert<Tx, RpcTxB>(
             tx: &Tx,
@@ -61,7 +73,7 @@ where
             Ok(())
         }
 
-        let AllPoolTransactions { pending, queued } = self.pool.all_transactions();
+        let AllPoolTransactions { pending, queued } = transactions;
 
         let mut content = TxpoolContent::default();
         for pending in pending {
@@ -73,6 +85,70 @@ where
 
         Ok(content)
     }
+
+    /// Returns the transactions matching the filter, only querying the subpools and senders it
+    /// selects.
+    fn filtered_transactions(
+        &self,
+        filter: TxpoolContentFilter,
+    ) -> AllPoolTransactions<Pool::Transaction> {
+        let TxpoolContentFilter { senders, subpool, blob_only, max_results } = filter;
+        let senders = senders.into_iter().collect::<BTreeSet<_>>();
+
+        let mut pending = Vec::new();
+        if subpool.is_none_or(|subpool| subpool == TxpoolSubpool::Pending) {
+            pending = if senders.is_empty() {
+                self.pool.pending_transactions()
+            } else {
+                senders
+                    .iter()
+                    .flat_map(|sender| self.pool.get_pending_transactions_by_sender(*sender))
+                    .collect()
+            };
+        }
+        let mut queued = Vec::new();
+        if subpool.is_none_or(|subpool| subpool == TxpoolSubpool::Queued) {
+            queued = if senders.is_empty() {
+                self.pool.queued_transactions()
+            } else {
+                senders
+                    .iter()
+                    .flat_map(|sender| self.pool.get_queued_transactions_by_sender(*sender))
+                    .collect()
+            };
+        }
+
+        if blob_only {
+            pending.retain(|tx| tx.transaction.is_eip4844());
+            queued.retain(|tx| tx.transaction.is_eip4844());
+        }
+        if let Some(max_results) = max_results {
+            let max_results = max_results as usize;
+            pending.truncate(max_results);
+            queued.truncate(max_results - pending.len());
+        }
+
+        AllPoolTransactions { pending, queued }
+    }
+}
+
+/// Aggregates the transactions by sender.
+fn summarize<T: PoolTransaction>(
+    transactions: &[Arc<ValidPoolTransaction<T>>],
+) -> BTreeMap<Address, TxpoolSenderSummary> {
+    let mut summary = BTreeMap::<_, TxpoolSenderSummary>::new();
+    for tx in transactions {
+        let tx = &tx.transaction;
+        let sender = summary
+            .entry(tx.sender())
+            .or_insert_with(|| TxpoolSenderSummary { min_nonce: u64::MAX, ..Default::default() });
+        sender.count += 1;
+        sender.min_nonce = sender.min_nonce.min(tx.nonce());
+        sender.max_nonce = sender.max_nonce.max(tx.nonce());
+        sender.total_gas_limit = sender.total_gas_limit.saturating_add(tx.gas_limit());
+        sender.total_cost = sender.total_cost.saturating_add(*tx.cost());
+    }
+    summary
 }
 
 #[async_trait]
@@ -147,6 +223,22 @@ where
         trace!(target: "rpc::eth", "Serving txpool_content");
         Ok(self.content().map_err(Into::into)?)
     }
+
+    /// Handler for `txpool_contentFiltered`
+    async fn txpool_content_filtered(
+        &self,
+        filter: TxpoolContentFilter,
+    ) -> RpcResult<TxpoolContent<RpcTransaction<Eth::Network>>> {
+        trace!(target: "rpc::eth", ?filter, "Serving txpool_contentFiltered");
+        Ok(self.content_of(self.filtered_transactions(filter)).map_err(Into::into)?)
+    }
+
+    /// Handler for `txpool_summary`
+    async fn txpool_summary(&self) -> RpcResult<TxpoolSummary> {
+        trace!(target: "rpc::eth", "Serving txpool_summary");
+        let AllPoolTransactions { pending, queued } = self.pool.all_transactions();
+        Ok(TxpoolSummary { pending: summarize(&pending), queued: summarize(&queued) })
+    }
 }
 
 impl<Pool, Eth> fmt::Debug for TxPoolApi<Pool, Eth> {
@@ -154,3 +246,111 @@ impl<Pool, Eth> fmt::Debug for TxPoolApi<Pool, Eth> {
         f.debug_struct("TxpoolApi").finish_non_exhaustive()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::helpers::types::EthRpcConverter;
+    use alloy_primitives::U256;
+    use reth_chainspec::{ChainSpec, MAINNET};
+    use reth_rpc_eth_types::receipt::EthReceiptConverter;
+    use reth_transaction_pool::{
+        test_utils::{testing_pool, MockTransaction, TestPool},
+        TransactionOrigin,
+    };
+
+    /// Returns the API of a pool with two pending and one queued transactions of `alice`, and a
+    /// pending transaction of `bob`.
+    async fn txpool_api(
+        alice: Address,
+        bob: Address,
+    ) -> TxPoolApi<TestPool, EthRpcConverter<ChainSpec>> {
+        let pool = testing_pool();
+        let alice_tx = |nonce| {
+            MockTransaction::eip1559().with_sender(alice).with_nonce(nonce).with_gas_limit(21_000)
+        };
+        let bob_tx = MockTransaction::eip1559().with_sender(bob).with_gas_limit(50_000);
+        for tx in [alice_tx(0), alice_tx(1), alice_tx(3), bob_tx] {
+            pool.add_transaction(TransactionOrigin::External, tx).await.unwrap();
+        }
+        TxPoolApi::new(pool, EthRpcConverter::new(EthReceiptConverter::new(MAINNET.clone())))
+    }
+
+    #[tokio::test]
+    async fn content_filtered() {
+        let (alice, bob) = (Address::random(), Address::random());
+        let api = txpool_api(alice, bob).await;
+        let nonces = |content: &BTreeMap<Address, BTreeMap<String, _>>, sender| {
+            content.get(&sender).map(|txs| txs.keys().cloned().collect::<Vec<_>>())
+        };
+
+        let content = api.txpool_content_filtered(TxpoolContentFilter::default()).await.unwrap();
+        assert_eq!(nonces(&content.pending, alice), Some(vec!["0".to_string(), "1".to_string()]));
+        assert_eq!(nonces(&content.queued, alice), Some(vec!["3".to_string()]));
+        assert_eq!(nonces(&content.pending, bob), Some(vec!["0".to_string()]));
+
+        let filter = TxpoolContentFilter { senders: vec![alice], ..Default::default() };
+        let content = api.txpool_content_filtered(filter).await.unwrap();
+        assert_eq!(content.pending.len(), 1);
+        assert_eq!(nonces(&content.queued, alice), Some(vec!["3".to_string()]));
+
+        let filter =
+            TxpoolContentFilter { subpool: Some(TxpoolSubpool::Queued), ..Default::default() };
+        let content = api.txpool_content_filtered(filter).await.unwrap();
+        assert!(content.pending.is_empty());
+        assert_eq!(nonces(&content.queued, alice), Some(vec!["3".to_string()]));
+
+        // pending transactions are returned first
+        let filter = TxpoolContentFilter {
+            senders: vec![alice],
+            max_results: Some(2),
+            ..Default::default()
+        };
+        let content = api.txpool_content_filtered(filter).await.unwrap();
+        assert_eq!(nonces(&content.pending, alice), Some(vec!["0".to_string(), "1".to_string()]));
+        assert!(content.queued.is_empty());
+
+        let filter = TxpoolContentFilter { blob_only: true, ..Default::default() };
+        let content = api.txpool_content_filtered(filter).await.unwrap();
+        assert!(content.pending.is_empty() && content.queued.is_empty());
+    }
+
+    #[tokio::test]
+    async fn summary() {
+        let (alice, bob) = (Address::random(), Address::random());
+        let api = txpool_api(alice, bob).await;
+
+        let summary = api.txpool_summary().await.unwrap();
+        assert_eq!(
+            summary.pending.get(&alice),
+            Some(&TxpoolSenderSummary {
+                count: 2,
+                min_nonce: 0,
+                max_nonce: 1,
+                total_gas_limit: 42_000,
+                total_cost: U256::ZERO,
+            })
+        );
+        assert_eq!(
+            summary.queued.get(&alice),
+            Some(&TxpoolSenderSummary {
+                count: 1,
+                min_nonce: 3,
+                max_nonce: 3,
+                total_gas_limit: 21_000,
+                total_cost: U256::ZERO,
+            })
+        );
+        assert_eq!(
+            summary.pending.get(&bob),
+            Some(&TxpoolSenderSummary {
+                count: 1,
+                min_nonce: 0,
+                max_nonce: 0,
+                total_gas_limit: 50_000,
+                total_cost: U256::ZERO,
+            })
+        );
+        assert!(!summary.queued.contains_key(&bob));
+    }
+}
@@ -749,7 +749,8 @@ impl From<ValidationApiError> for ErrorObject<'static> {
 
             ValidationApiError::Consensus(
                 error @ (ConsensusError::BlockAccessListCostMoreThanGasLimit(_) |
-                ConsensusError::BlockAccessListHashMismatch(_)),
+                ConsensusError::BlockAccessListHashMismatch(_) |
+                ConsensusError::BlockAccessListMismatch(_)),
             ) => invalid_params_rpc_err(error.to_string()),
             ValidationApiError::MissingLatestBlock |
             ValidationApiError::MissingParentBlock |
@@ -135,6 +135,25 @@ impl StageError {
                 Self::Fatal(_)
         )
     }
+
+    /// Returns `true` if running the stage again may succeed, e.g. because the database was busy
+    /// or a network request timed out.
+    ///
+    /// See [`RethError::is_retryable`].
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Database(err) => err.is_retryable(),
+            Self::DatabaseIntegrity(err) => err.is_retryable(),
+            Self::Internal(err) => err.is_retryable(),
+            Self::Download(err) => match err {
+                DownloadError::Timeout => true,
+                DownloadError::RequestError(err) => err.is_retryable(),
+                DownloadError::Provider(err) => err.is_retryable(),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
 }
 
 impl From<std::io::Error> for StageError {
@@ -173,4 +192,15 @@ pub enum PipelineError {
     /// Unwind target pruned error.
     #[error(transparent)]
     UnwindTargetPruned(#[from] UnwindTargetPrunedError),
+    /// The pipeline was aborted through its [`PipelineHandle`](crate::PipelineHandle).
+    #[error("pipeline aborted")]
+    Aborted,
+    /// A reorg would unwind more blocks than the configured maximum reorg depth.
+    #[error("reorg of {depth} blocks exceeds the maximum reorg depth of {max_reorg_depth} blocks")]
+    ReorgTooDeep {
+        /// The number of blocks the reorg would unwind.
+        depth: u64,
+        /// The configured maximum reorg depth.
+        max_reorg_depth: u64,
+    },
 }
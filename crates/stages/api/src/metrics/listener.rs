@@ -32,6 +32,19 @@ pub enum MetricEvent {
         /// The duration of stage iteration including database commit.
         elapsed: Duration,
     },
+    /// Stage executed and committed a single iteration.
+    StageRun {
+        /// Stage ID.
+        stage_id: StageId,
+        /// Stage checkpoint before the iteration.
+        prev_checkpoint: Option<StageCheckpoint>,
+        /// Stage checkpoint after the iteration.
+        checkpoint: StageCheckpoint,
+        /// The duration of the stage execution, excluding the database commit.
+        execution_duration: Duration,
+        /// The duration of the database commit.
+        commit_duration: Duration,
+    },
 }
 
 /// Metrics routine that listens to new metric events on the `events_rx` receiver.
@@ -71,6 +84,35 @@ impl MetricsListener {
                     stage_metrics.entities_total.set(total as f64);
                 }
             }
+            MetricEvent::StageRun {
+                stage_id,
+                prev_checkpoint,
+                checkpoint,
+                execution_duration,
+                commit_duration,
+            } => {
+                let stage_metrics = self.sync_metrics.get_stage_metrics(stage_id);
+
+                stage_metrics.execution_duration.record(execution_duration.as_secs_f64());
+                stage_metrics.commit_duration.record(commit_duration.as_secs_f64());
+
+                let elapsed = (execution_duration + commit_duration).as_secs_f64();
+                if elapsed > 0.0 {
+                    let prev_checkpoint = prev_checkpoint.unwrap_or_default();
+                    let blocks =
+                        checkpoint.block_number.saturating_sub(prev_checkpoint.block_number);
+                    stage_metrics.blocks_per_second.set(blocks as f64 / elapsed);
+
+                    if let Some(entities) = checkpoint.entities() {
+                        let prev_processed = prev_checkpoint
+                            .entities()
+                            .map(|entities| entities.processed)
+                            .unwrap_or_default();
+                        let processed = entities.processed.saturating_sub(prev_processed);
+                        stage_metrics.entities_per_second.set(processed as f64 / elapsed);
+                    }
+                }
+            }
         }
     }
 
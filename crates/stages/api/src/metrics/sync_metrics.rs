@@ -1,5 +1,8 @@
 use crate::StageId;
-use reth_metrics::{metrics::Gauge, Metrics};
+use reth_metrics::{
+    metrics::{Gauge, Histogram},
+    Metrics,
+};
 use std::collections::HashMap;
 
 #[derive(Debug, Default)]
@@ -29,4 +32,12 @@ pub(crate) struct StageMetrics {
     pub(crate) entities_total: Gauge,
     /// The number of seconds spent executing the stage and committing the data.
     pub(crate) total_elapsed: Gauge,
+    /// The number of seconds spent executing a single stage iteration, excluding the commit.
+    pub(crate) execution_duration: Histogram,
+    /// The number of seconds spent committing a single stage iteration.
+    pub(crate) commit_duration: Histogram,
+    /// The number of blocks per second processed by the last stage iteration.
+    pub(crate) blocks_per_second: Gauge,
+    /// The number of entities per second processed by the last stage iteration, if applicable.
+    pub(crate) entities_per_second: Gauge,
 }
@@ -1,7 +1,12 @@
-use crate::{pipeline::BoxedStage, MetricEventsSender, Pipeline, Stage, StageId, StageSet};
+use crate::{
+    pipeline::BoxedStage, MetricEventsSender, Pipeline, PipelineHandle, Stage, StageId,
+    StageRetryPolicy, StageSet, UnwindStageThresholds,
+};
 use alloy_primitives::{BlockNumber, B256};
 use reth_provider::{providers::ProviderNodeTypes, DatabaseProviderFactory, ProviderFactory};
+use reth_prune::PruneModes;
 use reth_static_file::StaticFileProducer;
+use std::collections::HashMap;
 use tokio::sync::watch;
 
 /// Builds a [`Pipeline`].
@@ -15,6 +20,16 @@ pub struct PipelineBuilder<Provider> {
     tip_tx: Option<watch::Sender<B256>>,
     metrics_tx: Option<MetricEventsSender>,
     fail_on_unwind: bool,
+    /// The thresholds at which stages commit while unwinding.
+    unwind_thresholds: UnwindStageThresholds,
+    /// Prune modes overriding the ones of the provider factory and static file producer.
+    prune_modes: Option<PruneModes>,
+    /// The retry policy of stages without an override.
+    retry_policy: StageRetryPolicy,
+    /// Retry policies of individual stages.
+    stage_retry_policies: HashMap<StageId, StageRetryPolicy>,
+    /// The maximum number of blocks a reorg is allowed to unwind.
+    max_reorg_depth: Option<u64>,
 }
 
 impl<Provider> PipelineBuilder<Provider> {
@@ -41,6 +56,28 @@ impl<Provider> PipelineBuilder<Provider> {
         self
     }
 
+    /// Add a stage to the pipeline, right after the stage with the given [`StageId`].
+    ///
+    /// This can be used to insert custom stages into an existing set of stages, e.g. the default
+    /// ones, without rebuilding the whole set. The inserted stage is checkpointed and unwound by
+    /// the pipeline like any other stage, so its id must be unique.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no stage with the given [`StageId`] has been added.
+    pub fn add_stage_after<S>(mut self, after: StageId, stage: S) -> Self
+    where
+        S: Stage<Provider> + 'static,
+    {
+        let index = self
+            .stages
+            .iter()
+            .position(|stage| stage.id() == after)
+            .unwrap_or_else(|| panic!("Stage {after} does not exist in the pipeline"));
+        self.stages.insert(index + 1, Box::new(stage));
+        self
+    }
+
     /// Set the target block.
     ///
     /// Once this block is reached, the pipeline will stop.
@@ -67,6 +104,48 @@ impl<Provider> PipelineBuilder<Provider> {
         self
     }
 
+    /// Set the thresholds at which stages that support chunked unwinds commit while unwinding.
+    pub const fn with_unwind_thresholds(mut self, thresholds: UnwindStageThresholds) -> Self {
+        self.unwind_thresholds = thresholds;
+        self
+    }
+
+    /// Override the prune modes for the runs of this pipeline, e.g. to disable receipt pruning
+    /// during a replay.
+    ///
+    /// The prune modes are applied to the provider factory and the static file producer the
+    /// pipeline is built with, without affecting other users of the static file producer. Stages
+    /// that are configured with prune modes of their own must be constructed with the same ones.
+    pub fn with_prune_modes(mut self, prune_modes: PruneModes) -> Self {
+        self.prune_modes = Some(prune_modes);
+        self
+    }
+
+    /// Set the policy for retrying stages that failed with a transient error.
+    pub const fn with_retry_policy(mut self, policy: StageRetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Set the policy for retrying the stage with the given [`StageId`] if it failed with a
+    /// transient error, overriding the one set with [`Self::with_retry_policy`].
+    pub fn with_stage_retry_policy(mut self, stage_id: StageId, policy: StageRetryPolicy) -> Self {
+        self.stage_retry_policies.insert(stage_id, policy);
+        self
+    }
+
+    /// Set the maximum number of blocks an unwind triggered by a detached head, i.e. a reorg, is
+    /// allowed to unwind.
+    ///
+    /// Deeper unwinds fail the pipeline with [`PipelineError::ReorgTooDeep`] instead of being
+    /// applied.
+    ///
+    /// [`PipelineError::ReorgTooDeep`]: crate::PipelineError::ReorgTooDeep
+    pub const fn with_max_reorg_depth(mut self, max_reorg_depth: Option<u64>) -> Self {
+        self.max_reorg_depth = max_reorg_depth;
+        self
+    }
+
     /// Builds the final [`Pipeline`] using the given database.
     pub fn build<N>(
         self,
@@ -77,7 +156,22 @@ impl<Provider> PipelineBuilder<Provider> {
         N: ProviderNodeTypes,
         ProviderFactory<N>: DatabaseProviderFactory<ProviderRW = Provider>,
     {
-        let Self { stages, max_block, tip_tx, metrics_tx, fail_on_unwind } = self;
+        let Self {
+            stages,
+            max_block,
+            tip_tx,
+            metrics_tx,
+            fail_on_unwind,
+            unwind_thresholds,
+            prune_modes,
+            retry_policy,
+            stage_retry_policies,
+            max_reorg_depth,
+        } = self;
+        let provider_factory = match &prune_modes {
+            Some(prune_modes) => provider_factory.with_prune_modes(prune_modes.clone()),
+            None => provider_factory,
+        };
         Pipeline {
             provider_factory,
             stages,
@@ -85,11 +179,19 @@ impl<Provider> PipelineBuilder<Provider> {
             static_file_producer,
             tip_tx,
             event_sender: Default::default(),
+            stage_event_sender: Default::default(),
             progress: Default::default(),
+            eta: Default::default(),
             metrics_tx,
             fail_on_unwind,
+            unwind_thresholds,
+            prune_modes,
+            retry_policy,
+            stage_retry_policies,
             last_detached_head_unwind_target: None,
             detached_head_attempts: 0,
+            max_reorg_depth,
+            handle: PipelineHandle::new(),
         }
     }
 }
@@ -102,6 +204,11 @@ impl<Provider> Default for PipelineBuilder<Provider> {
             tip_tx: None,
             metrics_tx: None,
             fail_on_unwind: false,
+            unwind_thresholds: UnwindStageThresholds::default(),
+            prune_modes: None,
+            retry_policy: StageRetryPolicy::default(),
+            stage_retry_policies: HashMap::default(),
+            max_reorg_depth: None,
         }
     }
 }
@@ -112,6 +219,11 @@ impl<Provider> std::fmt::Debug for PipelineBuilder<Provider> {
             .field("stages", &self.stages.iter().map(|stage| stage.id()).collect::<Vec<StageId>>())
             .field("max_block", &self.max_block)
             .field("fail_on_unwind", &self.fail_on_unwind)
+            .field("unwind_thresholds", &self.unwind_thresholds)
+            .field("prune_modes", &self.prune_modes)
+            .field("retry_policy", &self.retry_policy)
+            .field("stage_retry_policies", &self.stage_retry_policies)
+            .field("max_reorg_depth", &self.max_reorg_depth)
             .finish()
     }
 }
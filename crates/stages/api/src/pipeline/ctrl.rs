@@ -1,6 +1,40 @@
 use alloy_eips::eip1898::BlockWithParent;
 use alloy_primitives::BlockNumber;
 
+/// The outcome of moving data to static files, see
+/// [`Pipeline::move_to_static_files_with_interrupt`](crate::Pipeline::move_to_static_files_with_interrupt).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum StaticFileMoveOutcome {
+    /// All applicable data was moved to static files.
+    Completed,
+    /// The move was interrupted, the data that was already moved stays in the static files.
+    Interrupted,
+}
+
+impl StaticFileMoveOutcome {
+    /// Returns true if the move was interrupted.
+    pub const fn is_interrupted(&self) -> bool {
+        matches!(self, Self::Interrupted)
+    }
+}
+
+/// The outcome of an unwind, see
+/// [`Pipeline::unwind_with_interrupt`](crate::Pipeline::unwind_with_interrupt).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum UnwindOutcome {
+    /// All stages were unwound to the target.
+    Completed,
+    /// The unwind was interrupted, the chunks that were already unwound stay committed.
+    Interrupted,
+}
+
+impl UnwindOutcome {
+    /// Returns true if the unwind was interrupted.
+    pub const fn is_interrupted(&self) -> bool {
+        matches!(self, Self::Interrupted)
+    }
+}
+
 /// Determines the control flow during pipeline execution.
 ///
 /// See [`Pipeline::run_loop`](crate::Pipeline::run_loop) for more information.
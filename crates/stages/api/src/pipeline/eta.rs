@@ -0,0 +1,188 @@
+use crate::{StageCheckpoint, StageId};
+use alloy_primitives::BlockNumber;
+use std::{collections::HashMap, time::Duration};
+
+/// Weight of the most recent throughput sample in the exponential moving average.
+const SMOOTHING_FACTOR: f64 = 0.3;
+
+/// Estimated time left until the stages of a [Pipeline][crate::Pipeline] reach the target block.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SyncEta {
+    /// Time left until the stage reaches the target block.
+    pub stage: Option<Duration>,
+    /// Time left until the stage and all stages after it reach the target block.
+    ///
+    /// Stages without a throughput estimate yet, i.e. stages that didn't make progress since the
+    /// pipeline was started, are not included, so this is a lower bound until every stage ran
+    /// once.
+    pub total: Option<Duration>,
+}
+
+/// Exponential moving average of a rate.
+#[derive(Debug, Default, Clone, Copy)]
+struct MovingAverage(Option<f64>);
+
+impl MovingAverage {
+    /// Adds a sample of `processed` units in `elapsed` time.
+    fn record(&mut self, processed: u64, elapsed: Duration) {
+        if processed == 0 || elapsed.is_zero() {
+            return
+        }
+
+        let sample = processed as f64 / elapsed.as_secs_f64();
+        self.0 = Some(self.0.map_or(sample, |average| {
+            SMOOTHING_FACTOR * sample + (1.0 - SMOOTHING_FACTOR) * average
+        }));
+    }
+
+    /// Time needed to process `remaining` units at the current rate.
+    fn eta(&self, remaining: u64) -> Option<Duration> {
+        if remaining == 0 {
+            return Some(Duration::ZERO)
+        }
+
+        self.0
+            .filter(|rate| *rate > 0.0)
+            .and_then(|rate| Duration::try_from_secs_f64(remaining as f64 / rate).ok())
+    }
+}
+
+/// Throughput and last known checkpoint of a stage.
+#[derive(Debug, Default, Clone, Copy)]
+struct StageThroughput {
+    /// Last known checkpoint of the stage.
+    checkpoint: StageCheckpoint,
+    /// Blocks processed per second.
+    blocks: MovingAverage,
+    /// Entities processed per second, for stages reporting an [`EntitiesCheckpoint`].
+    ///
+    /// [`EntitiesCheckpoint`]: crate::EntitiesCheckpoint
+    entities: MovingAverage,
+}
+
+impl StageThroughput {
+    /// Time left until the stage reaches `target`.
+    ///
+    /// Uses the entities progress of the stage if reported, since some stages only advance their
+    /// block checkpoint once they are done.
+    fn eta(&self, target: BlockNumber) -> Option<Duration> {
+        self.checkpoint
+            .entities()
+            .and_then(|entities| {
+                self.entities.eta(entities.total.saturating_sub(entities.processed))
+            })
+            .or_else(|| self.blocks_eta(target))
+    }
+
+    /// Time left until the stage reaches `target`, based on the block throughput only.
+    fn blocks_eta(&self, target: BlockNumber) -> Option<Duration> {
+        self.blocks.eta(target.saturating_sub(self.checkpoint.block_number))
+    }
+}
+
+/// Tracks the throughput of the pipeline stages to estimate the time left until they reach the
+/// target block.
+#[derive(Debug, Default)]
+pub(crate) struct EtaTracker {
+    stages: HashMap<StageId, StageThroughput>,
+}
+
+impl EtaTracker {
+    /// Sets the checkpoint of the stage without recording a throughput sample, e.g. when the stage
+    /// starts or was unwound.
+    pub(crate) fn set_checkpoint(&mut self, stage_id: StageId, checkpoint: StageCheckpoint) {
+        self.stages.entry(stage_id).or_default().checkpoint = checkpoint;
+    }
+
+    /// Records that the stage advanced to `checkpoint` in `elapsed` time.
+    pub(crate) fn record(
+        &mut self,
+        stage_id: StageId,
+        checkpoint: StageCheckpoint,
+        elapsed: Duration,
+    ) {
+        let stage = self.stages.entry(stage_id).or_default();
+
+        stage
+            .blocks
+            .record(checkpoint.block_number.saturating_sub(stage.checkpoint.block_number), elapsed);
+        if let Some((previous, current)) = stage.checkpoint.entities().zip(checkpoint.entities()) {
+            stage.entities.record(current.processed.saturating_sub(previous.processed), elapsed);
+        }
+
+        stage.checkpoint = checkpoint;
+    }
+
+    /// Returns the estimated time left until `stage_id` and the `next_stages` reach `target`.
+    pub(crate) fn eta(
+        &self,
+        stage_id: StageId,
+        next_stages: impl IntoIterator<Item = StageId>,
+        target: Option<BlockNumber>,
+    ) -> SyncEta {
+        let Some(target) = target else { return SyncEta::default() };
+
+        let stage = self.stages.get(&stage_id).and_then(|stage| stage.eta(target));
+        // The entities checkpoints of the next stages belong to their previous run, so only their
+        // block throughput is used.
+        let total = stage
+            .into_iter()
+            .chain(next_stages.into_iter().filter_map(|stage_id| {
+                self.stages.get(&stage_id).and_then(|stage| stage.blocks_eta(target))
+            }))
+            .reduce(|total, eta| total + eta);
+
+        SyncEta { stage, total }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EntitiesCheckpoint;
+
+    #[test]
+    fn moving_average_eta() {
+        let mut tracker = EtaTracker::default();
+        let (a, b, c) = (StageId::Other("A"), StageId::Other("B"), StageId::Other("C"));
+
+        // No throughput known yet.
+        tracker.set_checkpoint(a, StageCheckpoint::new(0));
+        assert_eq!(tracker.eta(a, [b, c], Some(100)), SyncEta::default());
+
+        // 10 blocks per second, 90 blocks left.
+        tracker.record(a, StageCheckpoint::new(10), Duration::from_secs(1));
+        assert_eq!(tracker.eta(a, [b, c], Some(100)).stage, Some(Duration::from_secs(9)));
+
+        // 20 blocks per second sample moves the average to 13 blocks per second.
+        tracker.record(a, StageCheckpoint::new(30), Duration::from_secs(1));
+        let eta = tracker.eta(a, [b, c], Some(100)).stage.unwrap();
+        assert_eq!(eta.as_secs(), 70 / 13);
+
+        // The total includes the next stages with a known throughput only.
+        tracker.set_checkpoint(b, StageCheckpoint::new(0));
+        tracker.record(b, StageCheckpoint::new(50), Duration::from_secs(1));
+        let eta = tracker.eta(a, [b, c], Some(100));
+        assert_eq!(eta.total, Some(eta.stage.unwrap() + Duration::from_secs(1)));
+
+        // Stages at the target are done.
+        assert_eq!(tracker.eta(b, [], Some(50)).stage, Some(Duration::ZERO));
+        assert_eq!(tracker.eta(a, [b], None), SyncEta::default());
+    }
+
+    #[test]
+    fn entities_eta() {
+        let mut tracker = EtaTracker::default();
+        let stage_id = StageId::AccountHashing;
+        let checkpoint = |processed| {
+            StageCheckpoint::new(0)
+                .with_entities_stage_checkpoint(EntitiesCheckpoint { processed, total: 100 })
+        };
+
+        // The block checkpoint doesn't move, the entities progress is used instead.
+        tracker.set_checkpoint(stage_id, checkpoint(0));
+        tracker.record(stage_id, checkpoint(20), Duration::from_secs(1));
+        let eta = tracker.eta(stage_id, [], Some(10));
+        assert_eq!(eta, SyncEta { stage: Some(Duration::from_secs(4)), total: eta.stage });
+    }
+}
@@ -1,9 +1,13 @@
 use crate::{
     stage::{ExecOutput, UnwindInput, UnwindOutput},
-    StageCheckpoint, StageId,
+    StageCheckpoint, StageId, SyncEta,
 };
 use alloy_primitives::BlockNumber;
-use std::fmt::{Display, Formatter};
+use reth_tokio_util::{EventSender, EventStream};
+use std::{
+    fmt::{Display, Formatter},
+    time::Duration,
+};
 
 /// An event emitted by a [Pipeline][crate::Pipeline].
 ///
@@ -75,6 +79,91 @@ pub enum PipelineEvent {
     },
 }
 
+/// A structured event describing the progress of a single stage, emitted by a
+/// [Pipeline][crate::Pipeline].
+///
+/// Unlike [`PipelineEvent`], every event carries the checkpoint of the stage and the time spent, so
+/// consumers like the `eth_syncing` RPC, metrics or `ExExes` don't have to track stage state
+/// themselves.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum StageEvent {
+    /// Emitted when the pipeline started running a stage towards its target.
+    Started {
+        /// The stage that started.
+        stage_id: StageId,
+        /// The checkpoint of the stage before it started.
+        checkpoint: Option<StageCheckpoint>,
+        /// The block number up to which the stage is running, if known.
+        target: Option<BlockNumber>,
+    },
+    /// Emitted when a stage executed a batch, before the result is committed.
+    Progressed {
+        /// The stage that made progress.
+        stage_id: StageId,
+        /// The checkpoint the stage reached.
+        checkpoint: StageCheckpoint,
+        /// Time spent executing the batch.
+        elapsed: Duration,
+    },
+    /// Emitted when the result of a stage batch was committed to the database.
+    Committed {
+        /// The stage that committed.
+        stage_id: StageId,
+        /// The committed checkpoint.
+        checkpoint: StageCheckpoint,
+        /// Whether the stage reached its target.
+        done: bool,
+        /// Time spent executing and committing the batch.
+        elapsed: Duration,
+        /// Estimated time left until the stage, and the pipeline, reach the target block, based on
+        /// the moving average of the recent throughput of the stages.
+        eta: SyncEta,
+    },
+    /// Emitted when a stage unwind step was committed to the database.
+    Unwound {
+        /// The stage that was unwound.
+        stage_id: StageId,
+        /// The checkpoint the stage was unwound to.
+        checkpoint: StageCheckpoint,
+        /// Time spent unwinding and committing.
+        elapsed: Duration,
+    },
+}
+
+impl StageEvent {
+    /// Returns the stage this event belongs to.
+    pub const fn stage_id(&self) -> StageId {
+        match self {
+            Self::Started { stage_id, .. } |
+            Self::Progressed { stage_id, .. } |
+            Self::Committed { stage_id, .. } |
+            Self::Unwound { stage_id, .. } => *stage_id,
+        }
+    }
+}
+
+/// A cloneable handle to subscribe to the events of a [Pipeline][crate::Pipeline].
+///
+/// Can be handed to any number of consumers, each subscription receives all events emitted after
+/// it was created.
+#[derive(Debug, Clone)]
+pub struct PipelineEventSubscriptions {
+    pub(crate) events: EventSender<PipelineEvent>,
+    pub(crate) stage_events: EventSender<StageEvent>,
+}
+
+impl PipelineEventSubscriptions {
+    /// Subscribes to [`PipelineEvent`]s.
+    pub fn events(&self) -> EventStream<PipelineEvent> {
+        self.events.new_listener()
+    }
+
+    /// Subscribes to [`StageEvent`]s.
+    pub fn stage_events(&self) -> EventStream<StageEvent> {
+        self.stage_events.new_listener()
+    }
+}
+
 /// Pipeline stages progress.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct PipelineStagesProgress {
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+use tokio::sync::watch;
+
+/// Run state of a [`Pipeline`](crate::Pipeline) requested through a [`PipelineHandle`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PipelineRunState {
+    /// The pipeline runs normally.
+    #[default]
+    Running,
+    /// The pipeline pauses after the current stage finished.
+    Paused,
+    /// The pipeline stops at the next checkpoint.
+    Aborted,
+}
+
+/// A cloneable handle to pause, resume or abort a [`Pipeline`](crate::Pipeline) while it runs,
+/// e.g. for maintenance windows.
+///
+/// Requests are picked up by the pipeline at safe points:
+/// - a pause takes effect after the current stage finished, the pipeline then waits until it is
+///   resumed or aborted.
+/// - an abort takes effect after the next checkpoint was committed, the pipeline run then returns
+///   [`PipelineError::Aborted`](crate::PipelineError::Aborted).
+#[derive(Debug, Clone)]
+pub struct PipelineHandle {
+    state: Arc<watch::Sender<PipelineRunState>>,
+}
+
+impl PipelineHandle {
+    /// Creates a new handle in the [`PipelineRunState::Running`] state.
+    pub(crate) fn new() -> Self {
+        Self { state: Arc::new(watch::Sender::new(PipelineRunState::Running)) }
+    }
+
+    /// Pauses the pipeline after the current stage.
+    ///
+    /// Has no effect if an abort was already requested.
+    pub fn pause(&self) {
+        self.state.send_if_modified(|state| {
+            let modified = *state == PipelineRunState::Running;
+            if modified {
+                *state = PipelineRunState::Paused;
+            }
+            modified
+        });
+    }
+
+    /// Resumes a paused pipeline.
+    ///
+    /// This also clears a previous abort request, so the pipeline can be run again.
+    pub fn resume(&self) {
+        self.state.send_replace(PipelineRunState::Running);
+    }
+
+    /// Requests the pipeline to stop at the next checkpoint.
+    pub fn abort(&self) {
+        self.state.send_replace(PipelineRunState::Aborted);
+    }
+
+    /// Returns the currently requested run state.
+    pub fn state(&self) -> PipelineRunState {
+        *self.state.borrow()
+    }
+
+    /// Returns `true` if an abort was requested.
+    pub fn is_aborted(&self) -> bool {
+        self.state() == PipelineRunState::Aborted
+    }
+
+    /// Waits until the pipeline is no longer paused and returns the new run state.
+    pub(crate) async fn wait_unpaused(&self) -> PipelineRunState {
+        let mut rx = self.state.subscribe();
+        // The sender is owned by `self`, so the channel can't be closed.
+        rx.wait_for(|state| *state != PipelineRunState::Paused)
+            .await
+            .map(|state| *state)
+            .unwrap_or(PipelineRunState::Aborted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn pause_resume_abort() {
+        let handle = PipelineHandle::new();
+        assert_eq!(handle.state(), PipelineRunState::Running);
+
+        handle.pause();
+        assert_eq!(handle.state(), PipelineRunState::Paused);
+
+        let waiter = tokio::spawn({
+            let handle = handle.clone();
+            async move { handle.wait_unpaused().await }
+        });
+        handle.resume();
+        assert_eq!(waiter.await.unwrap(), PipelineRunState::Running);
+
+        handle.abort();
+        handle.pause();
+        assert!(handle.is_aborted());
+        assert_eq!(handle.wait_unpaused().await, PipelineRunState::Aborted);
+    }
+}
@@ -1,20 +1,28 @@
 mod ctrl;
+mod eta;
 mod event;
-pub use crate::pipeline::ctrl::ControlFlow;
-use crate::{PipelineTarget, StageCheckpoint, StageId};
+mod handle;
+pub use crate::pipeline::{
+    ctrl::{ControlFlow, StaticFileMoveOutcome, UnwindOutcome},
+    eta::SyncEta,
+};
+use crate::{pipeline::eta::EtaTracker, PipelineTarget, StageCheckpoint, StageId};
 use alloy_primitives::{BlockNumber, B256};
 pub use event::*;
 use futures_util::Future;
+pub use handle::{PipelineHandle, PipelineRunState};
 use reth_primitives_traits::constants::BEACON_CONSENSUS_REORG_UNWIND_DEPTH;
 use reth_provider::{
     providers::ProviderNodeTypes, BlockHashReader, BlockNumReader, ChainStateBlockReader,
     ChainStateBlockWriter, DBProvider, DatabaseProviderFactory, ProviderFactory,
     PruneCheckpointReader, StageCheckpointReader, StageCheckpointWriter, StorageSettingsCache,
 };
-use reth_prune::PrunerBuilder;
-use reth_static_file::StaticFileProducer;
+use reth_prune::{PruneModes, PrunerBuilder};
+use reth_static_file::{StaticFileProducer, StaticFileProducerInner};
+use reth_static_file_types::{HighestStaticFiles, StaticFileTargets};
 use reth_tokio_util::{EventSender, EventStream};
 use std::{
+    collections::HashMap,
     pin::Pin,
     time::{Duration, Instant},
 };
@@ -22,16 +30,18 @@ use tokio::sync::watch;
 use tracing::*;
 
 mod builder;
+mod plan;
 mod progress;
 mod set;
 
 use crate::{
     BlockErrorKind, ExecInput, ExecOutput, MetricEvent, MetricEventsSender, PipelineError, Stage,
-    StageError, StageExt, UnwindInput,
+    StageError, StageExt, StageRetryPolicy, UnwindInput, UnwindStageThresholds,
 };
 pub use builder::*;
+pub use plan::*;
 use progress::*;
-use reth_errors::RethResult;
+use reth_errors::{RethError, RethResult};
 pub use set::*;
 
 /// A container for a queued stage.
@@ -76,8 +86,12 @@ pub struct Pipeline<N: ProviderNodeTypes> {
     static_file_producer: StaticFileProducer<ProviderFactory<N>>,
     /// Sender for events the pipeline emits.
     event_sender: EventSender<PipelineEvent>,
+    /// Sender for structured stage events the pipeline emits.
+    stage_event_sender: EventSender<StageEvent>,
     /// Keeps track of the progress of the pipeline.
     progress: PipelineProgress,
+    /// Keeps track of the throughput of the stages to estimate the time left until the target.
+    eta: EtaTracker,
     /// A Sender for the current chain tip to sync to.
     ///
     /// This is used to notify the headers stage about a new sync target.
@@ -86,12 +100,27 @@ pub struct Pipeline<N: ProviderNodeTypes> {
     /// Whether an unwind should fail the syncing process. Should only be set when downloading
     /// blocks from trusted sources and expecting them to be valid.
     fail_on_unwind: bool,
+    /// The thresholds at which stages that support chunked unwinds commit while unwinding.
+    unwind_thresholds: UnwindStageThresholds,
+    /// Prune modes overriding the ones of the static file producer for the runs of this pipeline.
+    ///
+    /// The provider factory is already configured with them.
+    prune_modes: Option<PruneModes>,
+    /// The retry policy of stages without an override.
+    retry_policy: StageRetryPolicy,
+    /// Retry policies of individual stages.
+    stage_retry_policies: HashMap<StageId, StageRetryPolicy>,
     /// Block that was chosen as a target of the last unwind triggered by
     /// [`StageError::DetachedHead`] error.
     last_detached_head_unwind_target: Option<B256>,
     /// Number of consecutive unwind attempts due to [`StageError::DetachedHead`] for the current
     /// fork.
     detached_head_attempts: u64,
+    /// Maximum number of blocks an unwind triggered by [`StageError::DetachedHead`] is allowed to
+    /// unwind. If `None`, unwinds of any depth are applied.
+    max_reorg_depth: Option<u64>,
+    /// Handle to pause, resume or abort the pipeline.
+    handle: PipelineHandle,
 }
 
 impl<N: ProviderNodeTypes> Pipeline<N> {
@@ -115,11 +144,36 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
         });
     }
 
+    /// Set the maximum number of blocks an unwind triggered by a detached head, i.e. a reorg, is
+    /// allowed to unwind, see [`PipelineBuilder::with_max_reorg_depth`].
+    pub const fn set_max_reorg_depth(&mut self, max_reorg_depth: Option<u64>) {
+        self.max_reorg_depth = max_reorg_depth;
+    }
+
     /// Listen for events on the pipeline.
     pub fn events(&self) -> EventStream<PipelineEvent> {
         self.event_sender.new_listener()
     }
 
+    /// Listen for structured stage events on the pipeline.
+    pub fn stage_events(&self) -> EventStream<StageEvent> {
+        self.stage_event_sender.new_listener()
+    }
+
+    /// Returns a cloneable handle to subscribe to the pipeline events, which can be handed to
+    /// multiple consumers.
+    pub fn event_subscriptions(&self) -> PipelineEventSubscriptions {
+        PipelineEventSubscriptions {
+            events: self.event_sender.clone(),
+            stage_events: self.stage_event_sender.clone(),
+        }
+    }
+
+    /// Returns a handle to pause, resume or abort the pipeline while it runs.
+    pub fn handle(&self) -> PipelineHandle {
+        self.handle.clone()
+    }
+
     /// Get a mutable reference to a stage by index.
     pub fn stage(
         &mut self,
@@ -225,6 +279,8 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
 
         let mut previous_stage = None;
         for stage_index in 0..self.stages.len() {
+            self.ensure_running().await?;
+
             let stage = &self.stages[stage_index];
             let stage_id = stage.id();
 
@@ -258,6 +314,25 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
         Ok(self.progress.next_ctrl())
     }
 
+    /// Waits while the pipeline is paused through its [`PipelineHandle`].
+    ///
+    /// Returns [`PipelineError::Aborted`] if an abort was requested.
+    async fn ensure_running(&self) -> Result<(), PipelineError> {
+        if self.handle.state() == PipelineRunState::Paused {
+            info!(target: "sync::pipeline", "Pipeline paused");
+            if self.handle.wait_unpaused().await == PipelineRunState::Running {
+                info!(target: "sync::pipeline", "Pipeline resumed");
+            }
+        }
+
+        if self.handle.is_aborted() {
+            info!(target: "sync::pipeline", "Pipeline aborted");
+            return Err(PipelineError::Aborted)
+        }
+
+        Ok(())
+    }
+
     /// Run [static file producer](StaticFileProducer) and [pruner](reth_prune::Pruner) to **move**
     /// all data from the database to static files for corresponding
     /// [segments](reth_static_file_types::StaticFileSegment), according to their [stage
@@ -275,13 +350,30 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
     /// CAUTION: This method locks the static file producer Mutex, hence can block the thread if the
     /// lock is occupied.
     pub fn move_to_static_files(&self) -> RethResult<()> {
+        self.move_to_static_files_with_interrupt(|| false).map(|_| ())
+    }
+
+    /// Same as [`Self::move_to_static_files`], but stops copying data to static files once
+    /// `interrupted` returns `true`.
+    ///
+    /// Data that was already copied is still deleted from the database, so the database and static
+    /// files stay consistent and a subsequent call resumes where this one stopped.
+    ///
+    /// Returns [`StaticFileMoveOutcome::Interrupted`] if `interrupted` returned `true`.
+    pub fn move_to_static_files_with_interrupt(
+        &self,
+        interrupted: impl Fn() -> bool,
+    ) -> RethResult<StaticFileMoveOutcome> {
         if self.provider_factory.cached_storage_settings().is_v2() {
-            return Ok(())
+            return Ok(StaticFileMoveOutcome::Completed)
         }
 
         // Copies data from database to static files
-        let lowest_static_file_height =
-            self.static_file_producer.lock().copy_to_static_files()?.min_block_num();
+        let lowest_static_file_height = self
+            .with_static_file_producer(|producer| {
+                producer.copy_to_static_files_with_interrupt(&interrupted)
+            })?
+            .min_block_num();
 
         // Deletes data which has been copied to static files.
         if let Some(prune_tip) = lowest_static_file_height {
@@ -294,7 +386,103 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
             pruner.run(prune_tip)?;
         }
 
-        Ok(())
+        if interrupted() {
+            Ok(StaticFileMoveOutcome::Interrupted)
+        } else {
+            Ok(StaticFileMoveOutcome::Completed)
+        }
+    }
+
+    /// Locks the static file producer and calls `f` with it, using the prune modes of this
+    /// pipeline if they are overridden.
+    ///
+    /// The prune modes of the producer are restored afterwards, so other users of the shared
+    /// producer are unaffected.
+    fn with_static_file_producer<R>(
+        &self,
+        f: impl FnOnce(&StaticFileProducerInner<ProviderFactory<N>>) -> R,
+    ) -> R {
+        let mut producer = self.static_file_producer.lock();
+        let Some(prune_modes) = &self.prune_modes else { return f(&producer) };
+
+        let previous = producer.set_prune_modes(prune_modes.clone());
+        let result = f(&producer);
+        producer.set_prune_modes(previous);
+        result
+    }
+
+    /// Computes which stages would run over which ranges to reach the given target, and which
+    /// data would be moved to static files beforehand, without executing anything.
+    ///
+    /// For a [`PipelineTarget::Sync`] target that is not known locally, the ranges end at the
+    /// chain tip, unless a maximum block is set.
+    pub fn plan(&self, target: PipelineTarget) -> Result<PipelinePlan, PipelineError> {
+        let provider = self.provider_factory.provider()?;
+
+        let (static_files, prune_tip) = if self.provider_factory.cached_storage_settings().is_v2() {
+            (StaticFileTargets { receipts: None }, None)
+        } else {
+            let execution_checkpoint =
+                provider.get_stage_checkpoint(StageId::Execution)?.map(|c| c.block_number);
+            let static_files = self.with_static_file_producer(|producer| {
+                producer
+                    .get_static_file_targets(HighestStaticFiles { receipts: execution_checkpoint })
+            })?;
+            (static_files, execution_checkpoint)
+        };
+
+        let mut stages = Vec::with_capacity(self.stages.len());
+        match target {
+            PipelineTarget::Sync(tip) => {
+                let mut previous_stage = provider.block_number(tip)?;
+                for stage in &self.stages {
+                    let stage_id = stage.id();
+                    let checkpoint = provider.get_stage_checkpoint(stage_id)?.unwrap_or_default();
+                    let target = self.max_block.or(previous_stage);
+
+                    let action = match target {
+                        Some(target) if target <= checkpoint.block_number => {
+                            previous_stage = Some(checkpoint.block_number);
+                            StagePlanAction::Skip
+                        }
+                        to => {
+                            previous_stage = to;
+                            StagePlanAction::Execute { from: checkpoint.block_number + 1, to }
+                        }
+                    };
+                    stages.push(StagePlan { stage_id, checkpoint, action });
+                }
+            }
+            PipelineTarget::Unwind(to) => {
+                let (latest_block, prune_modes, checkpoints) = (
+                    provider.last_block_number()?,
+                    provider.prune_modes_ref().clone(),
+                    provider.get_prune_checkpoints()?,
+                );
+                prune_modes.ensure_unwind_target_unpruned(latest_block, to, &checkpoints)?;
+
+                for stage in self.stages.iter().rev() {
+                    let stage_id = stage.id();
+                    let checkpoint = provider.get_stage_checkpoint(stage_id)?.unwrap_or_default();
+
+                    let action = if checkpoint.block_number > to {
+                        let blocks = checkpoint.block_number - to;
+                        let chunks = match self.unwind_thresholds.max_blocks {
+                            Some(max_blocks) if stage.supports_chunked_unwind() => {
+                                blocks.div_ceil(max_blocks.max(1))
+                            }
+                            _ => 1,
+                        };
+                        StagePlanAction::Unwind { from: checkpoint.block_number, to, chunks }
+                    } else {
+                        StagePlanAction::Skip
+                    };
+                    stages.push(StagePlan { stage_id, checkpoint, action });
+                }
+            }
+        }
+
+        Ok(PipelinePlan { target, static_files, prune_tip, stages })
     }
 
     /// Unwind the stages to the target block (exclusive).
@@ -305,6 +493,20 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
         to: BlockNumber,
         bad_block: Option<BlockNumber>,
     ) -> Result<(), PipelineError> {
+        self.unwind_with_interrupt(to, bad_block, || false).map(|_| ())
+    }
+
+    /// Same as [`Self::unwind`], but stops before the next unwind chunk once `interrupted` returns
+    /// `true`.
+    ///
+    /// Every chunk is committed with its stage checkpoint, so a subsequent unwind to the same
+    /// target resumes where this one stopped.
+    pub fn unwind_with_interrupt(
+        &mut self,
+        to: BlockNumber,
+        bad_block: Option<BlockNumber>,
+        interrupted: impl Fn() -> bool,
+    ) -> Result<UnwindOutcome, PipelineError> {
         // Add validation before starting unwind
         let (latest_block, prune_modes, checkpoints) = {
             let provider = self.provider_factory.provider()?;
@@ -352,8 +554,25 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
                 "Starting unwind"
             );
             while checkpoint.block_number > to {
+                if interrupted() {
+                    info!(
+                        target: "sync::pipeline",
+                        stage = %stage_id,
+                        progress = checkpoint.block_number,
+                        %to,
+                        "Unwind interrupted"
+                    );
+                    return Ok(UnwindOutcome::Interrupted)
+                }
+
                 let unwind_started_at = Instant::now();
-                let input = UnwindInput { checkpoint, unwind_to: to, bad_block };
+                // Stages that support it are unwound in chunks, committing after each of them.
+                let unwind_to = if stage.supports_chunked_unwind() {
+                    self.unwind_thresholds.chunk_target(checkpoint.block_number, to)
+                } else {
+                    to
+                };
+                let input = UnwindInput { checkpoint, unwind_to, bad_block };
                 self.event_sender.notify(PipelineEvent::Unwind { stage_id, input });
 
                 let output = stage.unwind(&provider_rw, input);
@@ -414,18 +633,30 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
 
                         stage.post_unwind_commit()?;
 
+                        self.eta.set_checkpoint(stage_id, checkpoint);
+                        self.stage_event_sender.notify(StageEvent::Unwound {
+                            stage_id,
+                            checkpoint,
+                            elapsed: unwind_started_at.elapsed(),
+                        });
+
                         provider_rw = self.provider_factory.unwind_provider_rw()?;
                     }
                     Err(err) => {
                         self.event_sender.notify(PipelineEvent::Error { stage_id });
 
-                        return Err(PipelineError::Stage(StageError::Fatal(Box::new(err))))
+                        return Err(PipelineError::Internal(RethError::unwind(
+                            stage_id,
+                            to,
+                            checkpoint.block_number,
+                            err,
+                        )))
                     }
                 }
             }
         }
 
-        Ok(())
+        Ok(UnwindOutcome::Completed)
     }
 
     async fn execute_stage_to_completion(
@@ -437,6 +668,8 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
 
         let stage_id = self.stage(stage_index).id();
         let mut made_progress = false;
+        let mut started = false;
+        let mut retries = 0;
         let target = self.max_block.or(previous_stage);
 
         loop {
@@ -475,6 +708,9 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
 
             if let Err(err) = self.stage(stage_index).execute_ready(exec_input).await {
                 self.event_sender.notify(PipelineEvent::Error { stage_id });
+                if self.backoff_retry(stage_id, &err, err.is_retryable(), &mut retries).await {
+                    continue
+                }
                 match self.on_stage_error(stage_id, prev_checkpoint, err)? {
                     Some(ctrl) => return Ok(ctrl),
                     None => continue,
@@ -482,7 +718,15 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
             }
 
             let stage_started_at = Instant::now();
-            let provider_rw = self.provider_factory.database_provider_rw()?;
+            let provider_rw = match self.provider_factory.database_provider_rw() {
+                Ok(provider_rw) => provider_rw,
+                Err(err) => {
+                    if self.backoff_retry(stage_id, &err, err.is_retryable(), &mut retries).await {
+                        continue
+                    }
+                    return Err(err.into())
+                }
+            };
 
             self.event_sender.notify(PipelineEvent::Run {
                 pipeline_stages_progress: PipelineStagesProgress {
@@ -493,14 +737,33 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
                 checkpoint: prev_checkpoint,
                 target,
             });
+            if !started {
+                started = true;
+                self.eta.set_checkpoint(stage_id, prev_checkpoint.unwrap_or_default());
+                self.stage_event_sender.notify(StageEvent::Started {
+                    stage_id,
+                    checkpoint: prev_checkpoint,
+                    target,
+                });
+            }
 
             match self.stage(stage_index).execute(&provider_rw, exec_input) {
                 Ok(out @ ExecOutput { checkpoint, done }) => {
+                    retries = 0;
+                    let execution_duration = stage_started_at.elapsed();
+                    self.stage_event_sender.notify(StageEvent::Progressed {
+                        stage_id,
+                        checkpoint,
+                        elapsed: execution_duration,
+                    });
+
                     // Update stage checkpoint.
+                    let commit_started_at = Instant::now();
                     provider_rw.save_stage_checkpoint(stage_id, checkpoint)?;
 
                     // Commit processed data to the database.
                     provider_rw.commit()?;
+                    let commit_duration = commit_started_at.elapsed();
 
                     // Invoke stage post commit hook.
                     self.stage(stage_index).post_execute_commit()?;
@@ -514,6 +777,19 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
                         stage_id,
                         result: out.clone(),
                     });
+                    self.eta.record(stage_id, checkpoint, stage_started_at.elapsed());
+                    let eta = self.eta.eta(
+                        stage_id,
+                        self.stages[stage_index + 1..].iter().map(|stage| stage.id()),
+                        target,
+                    );
+                    self.stage_event_sender.notify(StageEvent::Committed {
+                        stage_id,
+                        checkpoint,
+                        done,
+                        elapsed: stage_started_at.elapsed(),
+                        eta,
+                    });
                     if let Some(metrics_tx) = &mut self.metrics_tx {
                         let _ = metrics_tx.send(MetricEvent::StageCheckpoint {
                             stage_id,
@@ -521,6 +797,13 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
                             max_block_number: target,
                             elapsed: stage_started_at.elapsed(),
                         });
+                        let _ = metrics_tx.send(MetricEvent::StageRun {
+                            stage_id,
+                            prev_checkpoint,
+                            checkpoint,
+                            execution_duration,
+                            commit_duration,
+                        });
                     }
 
                     let block_number = checkpoint.block_number;
@@ -533,11 +816,20 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
                             ControlFlow::NoProgress { block_number: Some(block_number) }
                         })
                     }
+
+                    // The checkpoint is committed, so the stage can be stopped here.
+                    if self.handle.is_aborted() {
+                        info!(target: "sync::pipeline", stage = %stage_id, checkpoint = %block_number, "Pipeline aborted");
+                        return Err(PipelineError::Aborted)
+                    }
                 }
                 Err(err) => {
                     drop(provider_rw);
                     self.event_sender.notify(PipelineEvent::Error { stage_id });
 
+                    if self.backoff_retry(stage_id, &err, err.is_retryable(), &mut retries).await {
+                        continue
+                    }
                     if let Some(ctrl) = self.on_stage_error(stage_id, prev_checkpoint, err)? {
                         return Ok(ctrl)
                     }
@@ -546,6 +838,40 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
         }
     }
 
+    /// Waits for the backoff of the retry policy of the stage, if the error is transient and the
+    /// policy allows another attempt after the `attempts` consecutive retries so far.
+    ///
+    /// Returns `true` if the stage should be run again.
+    async fn backoff_retry(
+        &self,
+        stage_id: StageId,
+        error: &(dyn std::fmt::Display + Sync),
+        retryable: bool,
+        attempts: &mut u32,
+    ) -> bool {
+        if !retryable {
+            return false
+        }
+
+        let policy = self.stage_retry_policies.get(&stage_id).unwrap_or(&self.retry_policy);
+        let Some(backoff) = policy.backoff(*attempts) else {
+            error!(target: "sync::pipeline", stage = %stage_id, attempts = *attempts, "Stage exhausted its retries");
+            return false
+        };
+
+        *attempts += 1;
+        warn!(
+            target: "sync::pipeline",
+            stage = %stage_id,
+            attempt = *attempts,
+            max_attempts = policy.max_attempts,
+            ?backoff,
+            "Stage encountered a transient error: {error}. Retrying..."
+        );
+        tokio::time::sleep(backoff).await;
+        true
+    }
+
     fn on_stage_error(
         &mut self,
         stage_id: StageId,
@@ -576,6 +902,21 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
                 )
                 .max(1);
 
+            let depth = local_head.block.number.saturating_sub(unwind_to);
+            if let Some(max_reorg_depth) = self.max_reorg_depth &&
+                depth > max_reorg_depth
+            {
+                error!(
+                    target: "sync::pipeline",
+                    depth,
+                    max_reorg_depth,
+                    ?local_head,
+                    "Refusing to unwind the detached head, raise --engine.max-reorg-depth or unwind \
+                     the node manually"
+                );
+                return Err(PipelineError::ReorgTooDeep { depth, max_reorg_depth })
+            }
+
             self.last_detached_head_unwind_target = self.provider_factory.block_hash(unwind_to)?;
             Ok(Some(ControlFlow::Unwind { target: unwind_to, bad_block: local_head }))
         } else if let StageError::Block { block, error } = err {
@@ -644,6 +985,17 @@ impl<N: ProviderNodeTypes> Pipeline<N> {
                 target: block.block.number.saturating_sub(1),
                 bad_block: block,
             }))
+        } else if let StageError::DatabaseIntegrity(provider_error) = &err &&
+            provider_error.is_pruned()
+        {
+            // Retrying or unwinding can't bring back pruned data, the prune configuration of the
+            // node doesn't fit the stages it runs.
+            error!(
+                target: "sync::pipeline",
+                stage = %stage_id,
+                "Stage requires data that was pruned, check the prune configuration: {provider_error}"
+            );
+            Err(err.into())
         } else if err.is_fatal() {
             error!(target: "sync::pipeline", stage = %stage_id, "Stage encountered a fatal error: {err}");
             Err(err.into())
@@ -667,6 +1019,11 @@ impl<N: ProviderNodeTypes> std::fmt::Debug for Pipeline<N> {
             .field("max_block", &self.max_block)
             .field("event_sender", &self.event_sender)
             .field("fail_on_unwind", &self.fail_on_unwind)
+            .field("unwind_thresholds", &self.unwind_thresholds)
+            .field("prune_modes", &self.prune_modes)
+            .field("retry_policy", &self.retry_policy)
+            .field("stage_retry_policies", &self.stage_retry_policies)
+            .field("handle", &self.handle)
             .finish()
     }
 }
@@ -680,6 +1037,7 @@ mod tests {
     use assert_matches::assert_matches;
     use reth_consensus::ConsensusError;
     use reth_errors::ProviderError;
+    use reth_network_p2p::error::DownloadError;
     use reth_provider::test_utils::{create_test_provider_factory, MockNodeTypesWithDB};
     use reth_prune::PruneModes;
     use reth_testing_utils::generators::{self, random_block_with_parent};
@@ -712,6 +1070,31 @@ mod tests {
         assert_eq!(progress.next_ctrl(), ControlFlow::Continue { block_number: 1 });
     }
 
+    #[test]
+    fn add_stage_after() {
+        let provider_factory = create_test_provider_factory();
+
+        let pipeline = Pipeline::<MockNodeTypesWithDB>::builder()
+            .add_stage(TestStage::new(StageId::Other("A")))
+            .add_stage(TestStage::new(StageId::Other("B")))
+            .add_stage_after(StageId::Other("A"), TestStage::new(StageId::Other("C")))
+            .add_stage_after(StageId::Other("B"), TestStage::new(StageId::Other("D")))
+            .build(
+                provider_factory.clone(),
+                StaticFileProducer::new(provider_factory, PruneModes::default()),
+            );
+
+        assert_eq!(
+            pipeline.stages.iter().map(|stage| stage.id()).collect::<Vec<_>>(),
+            vec![
+                StageId::Other("A"),
+                StageId::Other("C"),
+                StageId::Other("B"),
+                StageId::Other("D")
+            ]
+        );
+    }
+
     /// Runs a simple pipeline.
     #[tokio::test]
     async fn run_pipeline() {
@@ -790,6 +1173,120 @@ mod tests {
         assert_eq!(post_unwind_commit_counter_b.load(Ordering::Relaxed), 0);
     }
 
+    /// Runs a simple pipeline and checks the stage events received by multiple subscribers.
+    #[tokio::test]
+    async fn stage_events() {
+        let provider_factory = create_test_provider_factory();
+
+        let mut pipeline = Pipeline::<MockNodeTypesWithDB>::builder()
+            .add_stage(
+                TestStage::new(StageId::Other("A"))
+                    .add_exec(Ok(ExecOutput { checkpoint: StageCheckpoint::new(5), done: false }))
+                    .add_exec(Ok(ExecOutput { checkpoint: StageCheckpoint::new(10), done: true })),
+            )
+            .with_max_block(10)
+            .build(
+                provider_factory.clone(),
+                StaticFileProducer::new(provider_factory.clone(), PruneModes::default()),
+            );
+        let subscriptions = pipeline.event_subscriptions();
+        let first = subscriptions.stage_events();
+        let second = pipeline.stage_events();
+
+        tokio::spawn(async move {
+            pipeline.run().await.unwrap();
+        });
+
+        let first = first.collect::<Vec<StageEvent>>().await;
+        assert_eq!(first, second.collect::<Vec<StageEvent>>().await);
+
+        let events = first
+            .into_iter()
+            .map(|event| match event {
+                StageEvent::Progressed { stage_id, checkpoint, .. } => {
+                    (stage_id, "progressed", Some(checkpoint))
+                }
+                StageEvent::Committed { stage_id, checkpoint, .. } => {
+                    (stage_id, "committed", Some(checkpoint))
+                }
+                StageEvent::Started { stage_id, checkpoint, .. } => {
+                    (stage_id, "started", checkpoint)
+                }
+                StageEvent::Unwound { stage_id, checkpoint, .. } => {
+                    (stage_id, "unwound", Some(checkpoint))
+                }
+            })
+            .collect::<Vec<_>>();
+        let stage = StageId::Other("A");
+        assert_eq!(
+            events,
+            vec![
+                (stage, "started", None),
+                (stage, "progressed", Some(StageCheckpoint::new(5))),
+                (stage, "committed", Some(StageCheckpoint::new(5))),
+                (stage, "progressed", Some(StageCheckpoint::new(10))),
+                (stage, "committed", Some(StageCheckpoint::new(10))),
+            ]
+        );
+    }
+
+    /// Aborts a pipeline through its handle and runs it again after resuming.
+    #[tokio::test]
+    async fn abort_pipeline() {
+        let provider_factory = create_test_provider_factory();
+
+        let mut pipeline = Pipeline::<MockNodeTypesWithDB>::builder()
+            .add_stage(
+                TestStage::new(StageId::Other("A"))
+                    .add_exec(Ok(ExecOutput { checkpoint: StageCheckpoint::new(10), done: true })),
+            )
+            .with_max_block(10)
+            .build(
+                provider_factory.clone(),
+                StaticFileProducer::new(provider_factory.clone(), PruneModes::default()),
+            );
+        let handle = pipeline.handle();
+
+        handle.abort();
+        assert_matches!(pipeline.run().await, Err(PipelineError::Aborted));
+        assert_eq!(provider_factory.get_stage_checkpoint(StageId::Other("A")).unwrap(), None);
+
+        handle.resume();
+        assert_matches!(pipeline.run().await, Ok(()));
+        assert_eq!(
+            provider_factory.get_stage_checkpoint(StageId::Other("A")).unwrap(),
+            Some(StageCheckpoint::new(10))
+        );
+    }
+
+    /// Refuses to unwind a detached head deeper than the maximum reorg depth.
+    #[tokio::test]
+    async fn detached_head_exceeds_max_reorg_depth() {
+        let provider_factory = create_test_provider_factory();
+        let mut rng = generators::rng();
+
+        let mut pipeline = Pipeline::<MockNodeTypesWithDB>::builder()
+            .add_stage(TestStage::new(StageId::Other("A")).add_exec(Err(
+                StageError::DetachedHead {
+                    local_head: Box::new(random_block_with_parent(&mut rng, 100, None)),
+                    header: Box::new(random_block_with_parent(&mut rng, 101, None)),
+                    error: Box::new(ConsensusError::BaseFeeMissing),
+                },
+            )))
+            .with_max_block(10)
+            .with_max_reorg_depth(Some(0))
+            .build(
+                provider_factory.clone(),
+                StaticFileProducer::new(provider_factory.clone(), PruneModes::default()),
+            );
+
+        assert_matches!(
+            pipeline.run().await,
+            Err(PipelineError::ReorgTooDeep { depth, max_reorg_depth: 0 })
+                if depth == BEACON_CONSENSUS_REORG_UNWIND_DEPTH
+        );
+    }
+
     /// Unwinds a simple pipeline.
     #[tokio::test]
     async fn unwind_pipeline() {
@@ -939,6 +1436,196 @@ mod tests {
         assert_eq!(post_unwind_commit_counter_c.load(Ordering::Relaxed), 1);
     }
 
+    /// Unwinds stages that support it in chunks bounded by the unwind thresholds.
+    #[tokio::test]
+    async fn unwind_pipeline_in_chunks() {
+        let provider_factory = create_test_provider_factory();
+
+        let stage_a = TestStage::new(StageId::Other("A"))
+            .with_chunked_unwind()
+            .add_exec(Ok(ExecOutput { checkpoint: StageCheckpoint::new(100), done: true }))
+            .add_unwind(Ok(UnwindOutput { checkpoint: StageCheckpoint::new(60) }))
+            .add_unwind(Ok(UnwindOutput { checkpoint: StageCheckpoint::new(20) }))
+            .add_unwind(Ok(UnwindOutput { checkpoint: StageCheckpoint::new(1) }));
+        let (stage_a, post_unwind_commit_counter_a) = stage_a.with_post_unwind_commit_counter();
+
+        let stage_b = TestStage::new(StageId::Other("B"))
+            .add_exec(Ok(ExecOutput { checkpoint: StageCheckpoint::new(100), done: true }))
+            .add_unwind(Ok(UnwindOutput { checkpoint: StageCheckpoint::new(1) }));
+        let (stage_b, post_unwind_commit_counter_b) = stage_b.with_post_unwind_commit_counter();
+
+        let mut pipeline = Pipeline::<MockNodeTypesWithDB>::builder()
+            .add_stage(stage_a)
+            .add_stage(stage_b)
+            .with_max_block(100)
+            .with_unwind_thresholds(UnwindStageThresholds { max_blocks: Some(40) })
+            .build(
+                provider_factory.clone(),
+                StaticFileProducer::new(provider_factory.clone(), PruneModes::default()),
+            );
+        let events = pipeline.events();
+
+        tokio::spawn(async move {
+            pipeline.run().await.expect("Could not run pipeline");
+            pipeline.unwind(1, None).expect("Could not unwind pipeline");
+        });
+
+        let unwinds = events
+            .filter_map(|event| match event {
+                PipelineEvent::Unwind { stage_id, input } => Some((stage_id, input)),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .await;
+        let input = |checkpoint, unwind_to| UnwindInput {
+            checkpoint: StageCheckpoint::new(checkpoint),
+            unwind_to,
+            bad_block: None,
+        };
+        assert_eq!(
+            unwinds,
+            vec![
+                (StageId::Other("B"), input(100, 1)),
+                (StageId::Other("A"), input(100, 60)),
+                (StageId::Other("A"), input(60, 20)),
+                (StageId::Other("A"), input(20, 1)),
+            ]
+        );
+
+        assert_eq!(post_unwind_commit_counter_a.load(Ordering::Relaxed), 3);
+        assert_eq!(post_unwind_commit_counter_b.load(Ordering::Relaxed), 1);
+        assert_eq!(
+            provider_factory.get_stage_checkpoint(StageId::Other("A")).unwrap(),
+            Some(StageCheckpoint::new(1))
+        );
+    }
+
+    /// Interrupts a chunked unwind between chunks, and resumes it.
+    #[tokio::test]
+    async fn unwind_pipeline_interrupted() {
+        let provider_factory = create_test_provider_factory();
+
+        let stage = TestStage::new(StageId::Other("A"))
+            .with_chunked_unwind()
+            .add_exec(Ok(ExecOutput { checkpoint: StageCheckpoint::new(100), done: true }))
+            .add_unwind(Ok(UnwindOutput { checkpoint: StageCheckpoint::new(60) }))
+            .add_unwind(Ok(UnwindOutput { checkpoint: StageCheckpoint::new(20) }))
+            .add_unwind(Ok(UnwindOutput { checkpoint: StageCheckpoint::new(1) }));
+
+        let mut pipeline = Pipeline::<MockNodeTypesWithDB>::builder()
+            .add_stage(stage)
+            .with_max_block(100)
+            .with_unwind_thresholds(UnwindStageThresholds { max_blocks: Some(40) })
+            .build(
+                provider_factory.clone(),
+                StaticFileProducer::new(provider_factory.clone(), PruneModes::default()),
+            );
+        pipeline.run().await.expect("Could not run pipeline");
+
+        // interrupted after the first chunk
+        let chunks = std::sync::atomic::AtomicUsize::new(0);
+        let outcome = pipeline
+            .unwind_with_interrupt(1, None, || chunks.fetch_add(1, Ordering::Relaxed) >= 1)
+            .expect("Could not unwind pipeline");
+        assert_eq!(outcome, UnwindOutcome::Interrupted);
+        assert_eq!(
+            provider_factory.get_stage_checkpoint(StageId::Other("A")).unwrap(),
+            Some(StageCheckpoint::new(60))
+        );
+
+        let outcome =
+            pipeline.unwind_with_interrupt(1, None, || false).expect("Could not unwind pipeline");
+        assert_eq!(outcome, UnwindOutcome::Completed);
+        assert_eq!(
+            provider_factory.get_stage_checkpoint(StageId::Other("A")).unwrap(),
+            Some(StageCheckpoint::new(1))
+        );
+    }
+
+    /// Plans syncing and unwinding a pipeline without running the stages.
+    #[tokio::test]
+    async fn plan_pipeline() {
+        let provider_factory = create_test_provider_factory();
+
+        let stage_a = TestStage::new(StageId::Other("A"))
+            .with_chunked_unwind()
+            .add_exec(Ok(ExecOutput { checkpoint: StageCheckpoint::new(10), done: true }));
+        let stage_b = TestStage::new(StageId::Other("B"))
+            .add_exec(Ok(ExecOutput { checkpoint: StageCheckpoint::new(10), done: true }));
+
+        let mut pipeline = Pipeline::<MockNodeTypesWithDB>::builder()
+            .add_stage(stage_a)
+            .add_stage(stage_b)
+            .with_max_block(10)
+            .with_unwind_thresholds(UnwindStageThresholds { max_blocks: Some(4) })
+            .build(
+                provider_factory.clone(),
+                StaticFileProducer::new(provider_factory.clone(), PruneModes::default()),
+            );
+        pipeline.run().await.expect("Could not run pipeline");
+
+        let plan = pipeline.plan(PipelineTarget::Unwind(1)).unwrap();
+        assert_eq!(
+            plan.stages,
+            vec![
+                StagePlan {
+                    stage_id: StageId::Other("B"),
+                    checkpoint: StageCheckpoint::new(10),
+                    action: StagePlanAction::Unwind { from: 10, to: 1, chunks: 1 },
+                },
+                StagePlan {
+                    stage_id: StageId::Other("A"),
+                    checkpoint: StageCheckpoint::new(10),
+                    action: StagePlanAction::Unwind { from: 10, to: 1, chunks: 3 },
+                },
+            ]
+        );
+
+        let plan = pipeline.plan(PipelineTarget::Sync(B256::with_last_byte(1))).unwrap();
+        assert!(plan.is_noop());
+
+        pipeline.max_block = None;
+        let plan = pipeline.plan(PipelineTarget::Sync(B256::with_last_byte(1))).unwrap();
+        assert_eq!(
+            plan.stages.iter().map(|stage| stage.action).collect::<Vec<_>>(),
+            vec![
+                StagePlanAction::Execute { from: 11, to: None },
+                StagePlanAction::Execute { from: 11, to: None },
+            ]
+        );
+
+        // Planning doesn't touch the stage checkpoints.
+        assert_eq!(
+            provider_factory.get_stage_checkpoint(StageId::Other("A")).unwrap(),
+            Some(StageCheckpoint::new(10))
+        );
+    }
+
+    /// Overrides the prune modes for the runs of a pipeline only.
+    #[test]
+    fn pipeline_prune_modes_override() {
+        let provider_factory = create_test_provider_factory();
+        let static_file_producer =
+            StaticFileProducer::new(provider_factory.clone(), PruneModes::default());
+
+        let prune_modes =
+            PruneModes { receipts: Some(reth_prune::PruneMode::Full), ..Default::default() };
+        let pipeline = Pipeline::<MockNodeTypesWithDB>::builder()
+            .with_prune_modes(prune_modes.clone())
+            .build(provider_factory.clone(), static_file_producer.clone());
+
+        assert_eq!(pipeline.provider_factory.provider().unwrap().prune_modes_ref(), &prune_modes);
+        assert_eq!(
+            pipeline.with_static_file_producer(|producer| producer.prune_modes().clone()),
+            prune_modes
+        );
+
+        // The shared static file producer and the original provider factory are unaffected.
+        pipeline.move_to_static_files().unwrap();
+        assert_eq!(static_file_producer.lock().prune_modes(), &PruneModes::default());
+        assert_eq!(provider_factory.provider().unwrap().prune_modes_ref(), &PruneModes::default());
+    }
+
     /// Unwinds a pipeline with intermediate progress.
     #[tokio::test]
     async fn unwind_pipeline_with_intermediate_progress() {
@@ -1197,4 +1884,48 @@ mod tests {
             )))
         );
     }
+
+    /// Retries stages that failed with a transient error according to their retry policy.
+    #[tokio::test]
+    async fn pipeline_retries_transient_errors() {
+        let policy = |max_attempts| StageRetryPolicy {
+            max_attempts,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        };
+        let stage = || {
+            TestStage::new(StageId::Other("Transient"))
+                .add_exec(Err(StageError::Download(DownloadError::Timeout)))
+                .add_exec(Err(StageError::Download(DownloadError::Timeout)))
+                .add_exec(Ok(ExecOutput { checkpoint: StageCheckpoint::new(10), done: true }))
+        };
+
+        // Retried until the stage succeeds
+        let provider_factory = create_test_provider_factory();
+        let mut pipeline = Pipeline::<MockNodeTypesWithDB>::builder()
+            .add_stage(stage())
+            .with_max_block(10)
+            .with_retry_policy(policy(2))
+            .build(
+                provider_factory.clone(),
+                StaticFileProducer::new(provider_factory.clone(), PruneModes::default()),
+            );
+        assert_matches!(pipeline.run().await, Ok(()));
+
+        // The stage override takes precedence and runs out of retries
+        let provider_factory = create_test_provider_factory();
+        let mut pipeline = Pipeline::<MockNodeTypesWithDB>::builder()
+            .add_stage(stage())
+            .with_max_block(10)
+            .with_retry_policy(policy(2))
+            .with_stage_retry_policy(StageId::Other("Transient"), policy(1))
+            .build(
+                provider_factory.clone(),
+                StaticFileProducer::new(provider_factory.clone(), PruneModes::default()),
+            );
+        assert_matches!(
+            pipeline.run().await,
+            Err(PipelineError::Stage(StageError::Download(DownloadError::Timeout)))
+        );
+    }
 }
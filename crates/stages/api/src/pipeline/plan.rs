@@ -0,0 +1,102 @@
+use crate::{PipelineTarget, StageCheckpoint, StageId};
+use alloy_primitives::BlockNumber;
+use reth_static_file_types::StaticFileTargets;
+use std::fmt::{Display, Formatter};
+
+/// The actions the pipeline would take to reach a target, without executing them.
+///
+/// See [`Pipeline::plan`](crate::Pipeline::plan).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipelinePlan {
+    /// The target the plan was computed for.
+    pub target: PipelineTarget,
+    /// The data that would be copied from the database to static files before the stages run.
+    pub static_files: StaticFileTargets,
+    /// The block up to which data copied to static files would be pruned from the database, if
+    /// any.
+    pub prune_tip: Option<BlockNumber>,
+    /// The stages in the order they would run.
+    pub stages: Vec<StagePlan>,
+}
+
+impl PipelinePlan {
+    /// Returns `true` if none of the stages would run.
+    pub fn is_noop(&self) -> bool {
+        self.stages.iter().all(|stage| stage.action == StagePlanAction::Skip)
+    }
+}
+
+impl Display for PipelinePlan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.target {
+            PipelineTarget::Sync(hash) => writeln!(f, "Pipeline plan to sync to {hash}")?,
+            PipelineTarget::Unwind(block) => writeln!(f, "Pipeline plan to unwind to {block}")?,
+        }
+
+        match &self.static_files.receipts {
+            Some(range) => writeln!(
+                f,
+                "  Move receipts of blocks {}..={} to static files",
+                range.start(),
+                range.end()
+            )?,
+            None => writeln!(f, "  No data to move to static files")?,
+        }
+        if let Some(prune_tip) = self.prune_tip {
+            writeln!(f, "  Prune data moved to static files up to block {prune_tip}")?;
+        }
+
+        for stage in &self.stages {
+            write!(f, "  {}: checkpoint {}, ", stage.stage_id, stage.checkpoint.block_number)?;
+            match stage.action {
+                StagePlanAction::Skip => writeln!(f, "skip")?,
+                StagePlanAction::Execute { from, to: Some(to) } => {
+                    writeln!(f, "execute blocks {from}..={to}")?
+                }
+                StagePlanAction::Execute { from, to: None } => {
+                    writeln!(f, "execute blocks from {from} to the chain tip")?
+                }
+                StagePlanAction::Unwind { from, to, chunks } => {
+                    writeln!(f, "unwind from {from} to {to} in {chunks} chunk(s)")?
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The planned action of a single stage, see [`PipelinePlan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StagePlan {
+    /// The stage.
+    pub stage_id: StageId,
+    /// The current checkpoint of the stage.
+    pub checkpoint: StageCheckpoint,
+    /// What the stage would do.
+    pub action: StagePlanAction,
+}
+
+/// The action a stage would take as part of a [`PipelinePlan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StagePlanAction {
+    /// The stage is already at the target and would not run.
+    Skip,
+    /// The stage would execute a range of blocks.
+    Execute {
+        /// The first block to execute.
+        from: BlockNumber,
+        /// The last block to execute, or `None` if it is only known once the chain tip has been
+        /// downloaded.
+        to: Option<BlockNumber>,
+    },
+    /// The stage would unwind a range of blocks.
+    Unwind {
+        /// The block the stage unwinds from.
+        from: BlockNumber,
+        /// The block the stage unwinds to (exclusive).
+        to: BlockNumber,
+        /// The number of chunks the unwind is committed in.
+        chunks: u64,
+    },
+}
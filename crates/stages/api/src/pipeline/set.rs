@@ -179,10 +179,20 @@ impl<Provider> StageSetBuilder<Provider> {
     /// # Panics
     ///
     /// Panics if the dependency stage is not in this set.
-    pub fn add_after<S: Stage<Provider> + 'static>(mut self, stage: S, after: StageId) -> Self {
+    pub fn add_after<S: Stage<Provider> + 'static>(self, stage: S, after: StageId) -> Self {
+        self.add_after_boxed(Box::new(stage), after)
+    }
+
+    /// Adds the given boxed [`Stage`] after the stage with the given [`StageId`], see
+    /// [`Self::add_after`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dependency stage is not in this set.
+    pub fn add_after_boxed(mut self, stage: Box<dyn Stage<Provider>>, after: StageId) -> Self {
         let target_index = self.index_of(after) + 1;
         self.order.insert(target_index, stage.id());
-        self.upsert_stage_state(Box::new(stage), target_index);
+        self.upsert_stage_state(stage, target_index);
         self
     }
 
@@ -305,6 +305,16 @@ pub trait Stage<Provider>: Send {
     fn post_unwind_commit(&mut self) -> Result<(), StageError> {
         Ok(())
     }
+
+    /// Returns `true` if the stage can be unwound in chunks.
+    ///
+    /// If it can, the pipeline splits large unwinds according to its
+    /// [`UnwindStageThresholds`](crate::UnwindStageThresholds) and commits after every
+    /// chunk. Stages which can only be unwound to the final target at once, e.g. because they
+    /// validate their result against the target block, must return `false`.
+    fn supports_chunked_unwind(&self) -> bool {
+        false
+    }
 }
 
 /// [Stage] trait extension.
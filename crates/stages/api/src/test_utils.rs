@@ -19,6 +19,7 @@ pub struct TestStage {
     unwind_outputs: VecDeque<Result<UnwindOutput, StageError>>,
     post_execute_commit_counter: Arc<AtomicUsize>,
     post_unwind_commit_counter: Arc<AtomicUsize>,
+    chunked_unwind: bool,
 }
 
 impl TestStage {
@@ -29,6 +30,7 @@ impl TestStage {
             unwind_outputs: VecDeque::new(),
             post_execute_commit_counter: Arc::new(AtomicUsize::new(0)),
             post_unwind_commit_counter: Arc::new(AtomicUsize::new(0)),
+            chunked_unwind: false,
         }
     }
 
@@ -66,6 +68,11 @@ impl TestStage {
         self.post_unwind_commit_counter = counter.clone();
         (self, counter)
     }
+
+    pub const fn with_chunked_unwind(mut self) -> Self {
+        self.chunked_unwind = true;
+        self
+    }
 }
 
 impl<Provider> Stage<Provider> for TestStage {
@@ -96,4 +103,8 @@ impl<Provider> Stage<Provider> for TestStage {
 
         Ok(())
     }
+
+    fn supports_chunked_unwind(&self) -> bool {
+        self.chunked_unwind
+    }
 }
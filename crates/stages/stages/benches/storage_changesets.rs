@@ -0,0 +1,107 @@
+#![allow(missing_docs, unreachable_pub)]
+use alloy_primitives::{Address, B256, U256};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::Rng;
+use reth_db_api::{database::Database, table::Table, tables, transaction::DbTxMut};
+use reth_primitives_traits::StorageEntry;
+use reth_provider::{StorageChangeSetReader, StorageSettings, StorageSettingsCache};
+use reth_stages::test_utils::TestStageDB;
+use reth_testing_utils::generators;
+
+const BLOCKS: u64 = 1_000;
+const ACCOUNTS_PER_BLOCK: usize = 20;
+
+/// Creates a database with the storage changesets of [`BLOCKS`] blocks, each changing a few slots
+/// of the fixed storage layout and a few mapping slots of every account, optionally moving them
+/// to the packed table.
+fn changesets_db(packed: bool) -> (TestStageDB, Vec<Address>) {
+    let db = TestStageDB::default();
+    db.factory.set_storage_settings_cache(StorageSettings::v1());
+
+    let mut rng = generators::rng();
+    let addresses = (0..ACCOUNTS_PER_BLOCK).map(|_| rng.random()).collect::<Vec<Address>>();
+    db.commit(|tx| {
+        for block in 0..BLOCKS {
+            for address in &addresses {
+                let fixed = (0..4u64).map(|slot| B256::from(U256::from(slot)));
+                let mappings = (0..8).map(|_| rng.random::<B256>()).collect::<Vec<_>>();
+                for key in fixed.chain(mappings) {
+                    let value = U256::from(rng.random::<u64>());
+                    tx.put::<tables::StorageChangeSets>(
+                        (block, *address).into(),
+                        StorageEntry { key, value },
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    })
+    .unwrap();
+
+    if packed {
+        db.commit_with_provider(|provider| {
+            provider.pack_storage_changesets(0..=BLOCKS - 1)?;
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    (db, addresses)
+}
+
+/// Returns the size of the table on disk.
+fn table_size<T: Table>(db: &TestStageDB) -> usize {
+    db.factory
+        .db_ref()
+        .view(|tx| {
+            let table = tx.inner().open_db(Some(T::NAME)).unwrap();
+            let stats = tx.inner().db_stat(table.dbi()).unwrap();
+            stats.page_size() as usize *
+                (stats.leaf_pages() + stats.branch_pages() + stats.overflow_pages())
+        })
+        .unwrap()
+}
+
+/// Compares the disk usage and read throughput of storage changesets stored in the
+/// `StorageChangeSets` table against the prefix-compressed `PackedStorageChangeSets` table.
+fn bench_storage_changesets(c: &mut Criterion) {
+    let (dupsort, addresses) = changesets_db(false);
+    let (packed, _) = changesets_db(true);
+
+    let dupsort_size = table_size::<tables::StorageChangeSets>(&dupsort);
+    let packed_size = table_size::<tables::PackedStorageChangeSets>(&packed);
+    println!(
+        "StorageChangeSets: {dupsort_size} bytes, PackedStorageChangeSets: {packed_size} bytes \
+         ({:.1}% smaller)",
+        100.0 - packed_size as f64 * 100.0 / dupsort_size as f64
+    );
+
+    let mut group = c.benchmark_group("StorageChangeSets");
+    group.sample_size(10);
+
+    for (name, db) in [("dupsort", &dupsort), ("packed", &packed)] {
+        let provider = db.factory.provider().unwrap();
+
+        group.bench_function(BenchmarkId::new("range", name), |b| {
+            b.iter(|| provider.storage_changesets_range(0..BLOCKS).unwrap())
+        });
+
+        group.bench_function(BenchmarkId::new("lookup", name), |b| {
+            b.iter(|| {
+                for block in (0..BLOCKS).step_by(10) {
+                    for address in &addresses {
+                        provider
+                            .get_storage_before_block(block, *address, B256::with_last_byte(2))
+                            .unwrap()
+                            .unwrap();
+                    }
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_storage_changesets);
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+#![allow(missing_docs, unreachable_pub)]
+use alloy_primitives::B256;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use reth_provider::TransactionsProviderExt;
+use reth_stages::test_utils::{StorageKind, TestStageDB};
+use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+
+/// Compares hashing the transactions of the tx-lookup stage on a single worker against hashing
+/// them in parallel with different chunk sizes.
+fn bench_transaction_hashes(c: &mut Criterion) {
+    let db = TestStageDB::default();
+    let blocks = random_block_range(
+        &mut generators::rng(),
+        0..=1_000,
+        BlockRangeParams { parent: Some(B256::ZERO), tx_count: 50..100, ..Default::default() },
+    );
+    db.insert_blocks(blocks.iter(), StorageKind::Static).expect("insert blocks");
+
+    let tx_count = blocks.iter().map(|block| block.transaction_count()).sum::<usize>();
+    let provider = db.factory.provider().unwrap();
+
+    let mut group = c.benchmark_group("TransactionHashes");
+    group.sample_size(10);
+
+    // A single chunk covering the whole range is hashed by a single worker.
+    group.bench_function(BenchmarkId::new("serial", tx_count), |b| {
+        b.iter(|| {
+            provider.transaction_hashes_by_range_with_chunk_size(0..tx_count as u64, tx_count)
+        })
+    });
+
+    for chunk_size in [10, 100, 1_000] {
+        group.bench_function(
+            BenchmarkId::new(format!("parallel/chunk_{chunk_size}"), tx_count),
+            |b| {
+                b.iter(|| {
+                    provider
+                        .transaction_hashes_by_range_with_chunk_size(0..tx_count as u64, chunk_size)
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_transaction_hashes);
+criterion_main!(benches);
@@ -254,7 +254,10 @@ where
         HeaderStage<P, H>: Stage<Provider>,
     {
         StageSetBuilder::default()
-            .add_stage(HeaderStage::new(provider, header_downloader, tip, stages_config.etl))
+            .add_stage(
+                HeaderStage::new(provider, header_downloader, tip, stages_config.etl)
+                    .with_commit_threshold(stages_config.headers.commit_threshold),
+            )
             .add_stage(bodies)
     }
 }
@@ -278,12 +281,15 @@ where
         }
 
         builder
-            .add_stage(HeaderStage::new(
-                self.provider,
-                self.header_downloader,
-                self.tip,
-                self.stages_config.etl.clone(),
-            ))
+            .add_stage(
+                HeaderStage::new(
+                    self.provider,
+                    self.header_downloader,
+                    self.tip,
+                    self.stages_config.etl.clone(),
+                )
+                .with_commit_threshold(self.stages_config.headers.commit_threshold),
+            )
             .add_stage(BodyStage::new(self.body_downloader))
     }
 }
@@ -438,6 +444,7 @@ where
             .add_stage(MerkleStage::new_execution(
                 self.stages_config.merkle.rebuild_threshold,
                 self.stages_config.merkle.incremental_threshold,
+                self.stages_config.merkle.rebuild_strategy,
             ))
     }
 }
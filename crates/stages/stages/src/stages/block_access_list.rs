@@ -0,0 +1,284 @@
+use alloy_consensus::BlockHeader;
+use alloy_primitives::BlockNumber;
+use reth_config::config::BlockAccessListConfig;
+use reth_consensus::ConsensusError;
+use reth_db_api::models::StoredBlockAccessList;
+use reth_evm::{block::BlockExecutor, ConfigureEvm, Evm};
+use reth_primitives_traits::{GotExpected, NodePrimitives};
+use reth_provider::{
+    providers::{StaticFileProvider, StaticFileWriter},
+    BlockHashReader, BlockNumReader, BlockReader, DBProvider, HistoricalStateProviderRef,
+    NodePrimitivesProvider, PruneCheckpointReader, RawBal, RocksDBProviderFactory,
+    StageCheckpointReader, StaticFileProviderFactory, StorageSettingsCache, TransactionVariant,
+};
+use reth_revm::{database::StateProviderDatabase, State};
+use reth_stages_api::{
+    BlockErrorKind, BlockRangeOutput, ExecInput, ExecOutput, Stage, StageCheckpoint, StageError,
+    StageId, UnwindInput, UnwindOutput,
+};
+use reth_static_file_types::StaticFileSegment;
+use reth_storage_api::{ChangeSetReader, StorageChangeSetReader};
+use reth_storage_errors::provider::ProviderError;
+use reth_trie_db::ChangesetCache;
+use tracing::*;
+
+/// The block access list stage.
+///
+/// This stage re-executes already executed blocks on top of their historical parent state and
+/// stores the derived block access lists in the [`StaticFileSegment::BlockAccessLists`] segment,
+/// which starts at the first block the stage executed. Blocks without a block access list hash in
+/// their header (pre-Amsterdam) are not executed and get an empty row, for all other blocks the
+/// derived access list is checked against the header.
+///
+/// On unwind, the block access lists of the unwound blocks are removed from the segment.
+///
+/// The historical state is read through the account and storage history indices, so the stage must
+/// run after [`IndexAccountHistoryStage`](crate::stages::IndexAccountHistoryStage) and
+/// [`IndexStorageHistoryStage`](crate::stages::IndexStorageHistoryStage).
+///
+/// The stage is optional and only added to the pipeline if enabled in [`BlockAccessListConfig`].
+#[derive(Debug)]
+pub struct BlockAccessListStage<E> {
+    /// The EVM configuration used to re-execute blocks.
+    evm_config: E,
+    /// The maximum number of blocks to process before committing progress.
+    commit_threshold: u64,
+}
+
+impl<E> BlockAccessListStage<E> {
+    /// Create new instance of [`BlockAccessListStage`].
+    pub const fn new(evm_config: E, config: BlockAccessListConfig) -> Self {
+        Self { evm_config, commit_threshold: config.commit_threshold }
+    }
+}
+
+impl<E, Provider> Stage<Provider> for BlockAccessListStage<E>
+where
+    E: ConfigureEvm,
+    Provider: DBProvider
+        + BlockReader<Block = <E::Primitives as NodePrimitives>::Block>
+        + BlockNumReader
+        + BlockHashReader
+        + ChangeSetReader
+        + StorageChangeSetReader
+        + PruneCheckpointReader
+        + StageCheckpointReader
+        + StorageSettingsCache
+        + RocksDBProviderFactory
+        + StaticFileProviderFactory
+        + NodePrimitivesProvider<Primitives = E::Primitives>,
+{
+    /// Return the id of the stage
+    fn id(&self) -> StageId {
+        StageId::BlockAccessList
+    }
+
+    /// Derive and store the block access lists of the executed blocks.
+    fn execute(&mut self, provider: &Provider, input: ExecInput) -> Result<ExecOutput, StageError> {
+        if input.target_reached() {
+            return Ok(ExecOutput::done(input.checkpoint()))
+        }
+
+        let BlockRangeOutput { block_range: range, is_final_range } =
+            input.next_block_range_with_threshold(self.commit_threshold);
+        let changeset_cache = ChangesetCache::new();
+
+        // Remove the block access lists above the checkpoint, left behind if the stage's progress
+        // wasn't committed to the database.
+        let static_file_provider = provider.static_file_provider();
+        remove_block_access_lists_above(&static_file_provider, input.checkpoint().block_number)?;
+
+        debug!(target: "sync::stages::block_access_list", ?range, "Deriving block access lists");
+
+        let segment = StaticFileSegment::BlockAccessLists;
+        let mut writer = match static_file_provider.get_highest_static_file_block(segment) {
+            Some(highest) if highest + 1 == *range.start() => {
+                static_file_provider.latest_writer(segment)?
+            }
+            Some(highest) => {
+                return Err(StageError::Fatal(
+                    format!(
+                        "block access lists segment ends at block {highest}, but the stage \
+                         resumes at block {}",
+                        range.start()
+                    )
+                    .into(),
+                ))
+            }
+            None => {
+                // Start the segment at the first block instead of the beginning of its file range.
+                let mut writer = static_file_provider.get_writer(*range.start(), segment)?;
+                if writer.current_block_number().is_none() {
+                    writer.user_header_mut().set_expected_block_start(*range.start());
+                }
+                writer
+            }
+        };
+
+        for block_number in range.clone() {
+            let block = provider
+                .recovered_block(block_number.into(), TransactionVariant::NoHash)?
+                .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+
+            let Some(expected) = block.header().block_access_list_hash() else {
+                writer.append_block_access_list(block_number, &StoredBlockAccessList::default())?;
+                continue
+            };
+
+            let block_error = |error: BlockErrorKind| StageError::Block {
+                block: Box::new(block.block_with_parent()),
+                error,
+            };
+
+            // State at the beginning of the block.
+            let state =
+                HistoricalStateProviderRef::new(provider, block_number, changeset_cache.clone());
+            let mut db = State::builder()
+                .with_database(StateProviderDatabase::new(state))
+                .with_bal_builder()
+                .build();
+
+            {
+                let mut executor = self
+                    .evm_config
+                    .executor_for_block(&mut db, block.sealed_block())
+                    .map_err(|err| StageError::Fatal(Box::new(err)))?;
+
+                executor
+                    .apply_pre_execution_changes()
+                    .map_err(|err| block_error(BlockErrorKind::Execution(err)))?;
+                executor.evm_mut().db_mut().bump_bal_index();
+
+                for tx in block.transactions_recovered() {
+                    executor
+                        .execute_transaction(tx)
+                        .map_err(|err| block_error(BlockErrorKind::Execution(err)))?;
+                    executor.evm_mut().db_mut().bump_bal_index();
+                }
+
+                executor
+                    .apply_post_execution_changes()
+                    .map_err(|err| block_error(BlockErrorKind::Execution(err)))?;
+            }
+
+            let bal = db.take_built_alloy_bal().unwrap_or_default();
+            let raw_bal = RawBal::new(alloy_rlp::encode(bal).into());
+
+            let got = raw_bal.hash();
+            if got != expected {
+                return Err(block_error(BlockErrorKind::Validation(
+                    ConsensusError::BlockAccessListMismatch(GotExpected { got, expected }.into()),
+                )))
+            }
+
+            trace!(target: "sync::stages::block_access_list", number = block_number, "Derived block access list");
+            writer.append_block_access_list(
+                block_number,
+                &StoredBlockAccessList::new(raw_bal.as_raw().clone()),
+            )?;
+        }
+
+        Ok(ExecOutput { checkpoint: StageCheckpoint::new(*range.end()), done: is_final_range })
+    }
+
+    /// Unwind the stage, removing the block access lists of the unwound blocks.
+    fn unwind(
+        &mut self,
+        provider: &Provider,
+        input: UnwindInput,
+    ) -> Result<UnwindOutput, StageError> {
+        remove_block_access_lists_above(&provider.static_file_provider(), input.unwind_to)?;
+        Ok(UnwindOutput { checkpoint: StageCheckpoint::new(input.unwind_to) })
+    }
+}
+
+/// Removes the block access lists of the blocks above `block` from the static files.
+///
+/// The removal is committed right away, so that the block access lists of the following blocks
+/// can be appended.
+fn remove_block_access_lists_above<N: NodePrimitives>(
+    static_file_provider: &StaticFileProvider<N>,
+    block: BlockNumber,
+) -> Result<(), StageError> {
+    let segment = StaticFileSegment::BlockAccessLists;
+    let Some(highest) = static_file_provider.get_highest_static_file_block(segment) else {
+        return Ok(())
+    };
+    if highest <= block {
+        return Ok(())
+    }
+
+    debug!(target: "sync::stages::block_access_list", from = highest, to = block, "Removing block access lists");
+    if static_file_provider.get_lowest_range_start(segment).is_none_or(|lowest| block < lowest) {
+        static_file_provider.delete_segment(segment)?;
+    } else {
+        let mut writer = static_file_provider.latest_writer(segment)?;
+        writer.prune_block_access_lists(highest - block)?;
+        writer.commit()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::hex_literal::hex;
+    use alloy_rlp::Decodable;
+    use reth_chainspec::MAINNET;
+    use reth_ethereum_primitives::Block;
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_primitives_traits::SealedBlock;
+    use reth_provider::{test_utils::create_test_provider_factory, BlockWriter};
+
+    #[test]
+    fn skips_blocks_without_block_access_list() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let mut genesis_rlp = hex!("f901faf901f5a00000000000000000000000000000000000000000000000000000000000000000a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347942adc25665018aa1fe0e6bc666dac8fc2697ff9baa045571b40ae66ca7480791bbb2887286e4e4c4b1b298b191c889d6959023a32eda056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000083020000808502540be400808000a00000000000000000000000000000000000000000000000000000000000000000880000000000000000c0c0").as_slice();
+        let genesis = SealedBlock::<Block>::decode(&mut genesis_rlp).unwrap();
+        let mut block_rlp = hex!("f90262f901f9a075c371ba45999d87f4542326910a11af515897aebce5265d3f6acd1f1161f82fa01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347942adc25665018aa1fe0e6bc666dac8fc2697ff9baa098f2dcd87c8ae4083e7017a05456c14eea4b1db2032126e27b3b1563d57d7cc0a08151d548273f6683169524b66ca9fe338b9ce42bc3540046c828fd939ae23bcba03f4e5c2ec5b2170b711d97ee755c160457bb58d8daa338e835ec02ae6860bbabb901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000083020000018502540be40082a8798203e800a00000000000000000000000000000000000000000000000000000000000000000880000000000000000f863f861800a8405f5e10094100000000000000000000000000000000000000080801ba07e09e26678ed4fac08a249ebe8ed680bf9051a5e14ad223e4b2b9d26e0208f37a05f6e3f188e3e6eab7d7d3b6568f5eac7d687b08d307d3154ccd8c87b4630509bc0").as_slice();
+        let block = SealedBlock::<Block>::decode(&mut block_rlp).unwrap();
+        provider.insert_block(&genesis.try_recover().unwrap()).unwrap();
+        provider.insert_block(&block.try_recover().unwrap()).unwrap();
+        provider
+            .static_file_provider()
+            .latest_writer(StaticFileSegment::Headers)
+            .unwrap()
+            .commit()
+            .unwrap();
+        provider.commit().unwrap();
+
+        let mut stage = BlockAccessListStage::new(
+            EthEvmConfig::ethereum(MAINNET.clone()),
+            BlockAccessListConfig { enabled: true, commit_threshold: 1 },
+        );
+
+        let execute_input =
+            ExecInput { target: Some(1), checkpoint: Some(StageCheckpoint::new(0)) };
+        let provider = factory.provider_rw().unwrap();
+        let output = stage.execute(&provider, execute_input).unwrap();
+        assert_eq!(output, ExecOutput { checkpoint: StageCheckpoint::new(1), done: true });
+        provider.commit().unwrap();
+
+        // pre-Amsterdam blocks are stored without a block access list
+        let static_file_provider = factory.static_file_provider();
+        let stored = static_file_provider.block_access_list(1).unwrap().unwrap();
+        assert_eq!(stored.bal(), None);
+
+        let provider = factory.provider_rw().unwrap();
+        let input =
+            UnwindInput { checkpoint: StageCheckpoint::new(1), unwind_to: 0, bad_block: None };
+        let output = stage.unwind(&provider, input).unwrap();
+        assert_eq!(output, UnwindOutput { checkpoint: StageCheckpoint::new(0) });
+        provider.commit().unwrap();
+        assert_eq!(static_file_provider.block_access_list(1).unwrap(), None);
+
+        // the unwound blocks can be executed again
+        let provider = factory.provider_rw().unwrap();
+        let output = stage.execute(&provider, execute_input).unwrap();
+        assert_eq!(output, ExecOutput { checkpoint: StageCheckpoint::new(1), done: true });
+        provider.commit().unwrap();
+        assert!(static_file_provider.block_access_list(1).unwrap().is_some());
+    }
+}
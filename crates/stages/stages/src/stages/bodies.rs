@@ -156,6 +156,10 @@ where
         StageId::Bodies
     }
 
+    fn supports_chunked_unwind(&self) -> bool {
+        true
+    }
+
     fn poll_execute_ready(
         &mut self,
         cx: &mut Context<'_>,
@@ -0,0 +1,100 @@
+use reth_metrics::{metrics::Gauge, Metrics};
+use std::time::Duration;
+
+/// Weight of the most recent batch in the moving averages.
+const SMOOTHING_FACTOR: f64 = 0.5;
+
+/// Execution stage batch metrics.
+#[derive(Metrics)]
+#[metrics(scope = "sync.execution")]
+struct BatchMetrics {
+    /// The amount of gas per second processed by the last batch, including fetching the blocks
+    /// and writing the state changes.
+    batch_gas_per_second: Gauge,
+    /// The maximum number of blocks of the next batch, adapted to the target batch duration.
+    batch_max_blocks: Gauge,
+}
+
+/// Measures the gas throughput of the execution stage across batches, and adapts the number of
+/// blocks per batch to it, so that batches take about a target duration.
+///
+/// A static block limit is either too small for the large blocks of a recent chain or too large
+/// for the small blocks of an early one. The time it takes to process a unit of gas is a lot more
+/// stable, so the limit is derived from the gas that can be processed within the target duration
+/// and the gas used by the recently executed blocks.
+#[derive(Debug, Default)]
+pub(super) struct BatchSizer {
+    /// The target duration of a batch, if the batch size is adapted.
+    target: Option<Duration>,
+    /// Average amount of gas processed per second.
+    gas_per_second: Option<f64>,
+    /// Average amount of gas used per block.
+    gas_per_block: Option<f64>,
+    metrics: BatchMetrics,
+}
+
+impl BatchSizer {
+    /// Creates a new batch sizer, adapting the batch size to the given target duration if set.
+    pub(super) fn new(target: Option<Duration>) -> Self {
+        Self { target, ..Default::default() }
+    }
+
+    /// Returns the maximum number of blocks of the next batch, or `None` if it isn't limited,
+    /// because no target is set or no gas has been measured yet.
+    pub(super) fn max_blocks(&self) -> Option<u64> {
+        let target = self.target?;
+        let (gas_per_second, gas_per_block) = (self.gas_per_second?, self.gas_per_block?);
+        if gas_per_second <= 0.0 || gas_per_block <= 0.0 {
+            return None
+        }
+
+        let max_blocks = (gas_per_second * target.as_secs_f64() / gas_per_block).max(1.0) as u64;
+        self.metrics.batch_max_blocks.set(max_blocks as f64);
+        Some(max_blocks)
+    }
+
+    /// Records that a batch of `blocks` blocks using `gas` gas took `elapsed`.
+    pub(super) fn record(&mut self, blocks: u64, gas: u64, elapsed: Duration) {
+        if blocks == 0 || gas == 0 || elapsed.is_zero() {
+            return
+        }
+
+        let gas_per_second = gas as f64 / elapsed.as_secs_f64();
+        self.metrics.batch_gas_per_second.set(gas_per_second);
+        update_average(&mut self.gas_per_second, gas_per_second);
+        update_average(&mut self.gas_per_block, gas as f64 / blocks as f64);
+    }
+}
+
+/// Adds a sample to the exponential moving average.
+fn update_average(average: &mut Option<f64>, sample: f64) {
+    *average =
+        Some(average.map_or(sample, |average| {
+            SMOOTHING_FACTOR * sample + (1.0 - SMOOTHING_FACTOR) * average
+        }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adapts_to_block_size() {
+        let mut sizer = BatchSizer::new(Some(Duration::from_secs(60)));
+        assert_eq!(sizer.max_blocks(), None);
+
+        // 1000 blocks of 1M gas in 10 seconds: 100M gas per second.
+        sizer.record(1_000, 1_000_000_000, Duration::from_secs(10));
+        assert_eq!(sizer.max_blocks(), Some(6_000));
+
+        // Blocks ten times as large at the same throughput fit ten times less into a batch.
+        let mut sizer = BatchSizer::new(Some(Duration::from_secs(60)));
+        sizer.record(100, 1_000_000_000, Duration::from_secs(10));
+        assert_eq!(sizer.max_blocks(), Some(600));
+
+        // Without a target, the batch size isn't limited.
+        let mut sizer = BatchSizer::new(None);
+        sizer.record(1_000, 1_000_000_000, Duration::from_secs(10));
+        assert_eq!(sizer.max_blocks(), None);
+    }
+}
@@ -1,20 +1,26 @@
 use crate::stages::MERKLE_STAGE_DEFAULT_INCREMENTAL_THRESHOLD;
 use alloy_consensus::BlockHeader;
+use alloy_eip7928::BlockAccessList;
 use alloy_primitives::BlockNumber;
+use alloy_rlp::Decodable;
 use num_traits::Zero;
 use reth_chainspec::{ChainSpecProvider, EthereumHardforks};
 use reth_config::config::ExecutionConfig;
 use reth_consensus::FullConsensus;
 use reth_db::{static_file::HeaderMask, tables};
+use reth_errors::{ErrorContext, RethError};
 use reth_evm::{execute::Executor, metrics::ExecutorMetrics, ConfigureEvm};
 use reth_execution_types::Chain;
 use reth_exex::{ExExManagerHandle, ExExNotification, ExExNotificationSource};
-use reth_primitives_traits::{format_gas_throughput, BlockBody, NodePrimitives};
+use reth_primitives_traits::{
+    format_gas_throughput, Block, BlockBody, NodePrimitives, RecoveredBlock,
+};
 use reth_provider::{
     providers::{StaticFileProvider, StaticFileWriter},
-    BlockHashReader, BlockReader, DBProvider, EitherWriter, ExecutionOutcome, HeaderProvider,
-    LatestStateProviderRef, OriginalValuesKnown, ProviderError, StateWriteConfig, StateWriter,
-    StaticFileProviderFactory, StatsReader, StoragePath, StorageSettingsCache, TransactionVariant,
+    BalStoreHandle, BlockHashReader, BlockReader, DBProvider, EitherWriter, ExecutionOutcome,
+    HeaderProvider, LatestStateProviderRef, OriginalValuesKnown, ProviderError, ProviderResult,
+    StateWriteConfig, StateWriter, StaticFileProviderFactory, StatsReader, StoragePath,
+    StorageSettingsCache, TransactionVariant,
 };
 use reth_revm::database::StateProviderDatabase;
 use reth_stages_api::{
@@ -36,6 +42,12 @@ use tracing::*;
 
 use super::missing_static_data_error;
 
+mod batch;
+use batch::BatchSizer;
+
+mod prewarm;
+pub use prewarm::StatePrewarmer;
+
 /// Slot-preimage database for recovering plain storage keys from hashed keys during
 /// pre-Cancun `SELFDESTRUCT` handling.
 pub mod slot_preimages;
@@ -96,6 +108,13 @@ where
     exex_manager_handle: ExExManagerHandle<E::Primitives>,
     /// Executor metrics.
     metrics: ExecutorMetrics,
+    /// Measures the gas throughput of batches and adapts their size to the target batch duration.
+    batch_sizer: BatchSizer,
+    /// Store of block access lists, if set blocks with a stored block access list are executed in
+    /// parallel.
+    bal_store: Option<BalStoreHandle>,
+    /// Prewarms the state of the blocks ahead of the executed one, if set.
+    prewarmer: Option<StatePrewarmer>,
 }
 
 impl<E> ExecutionStage<E>
@@ -111,6 +130,7 @@ where
         exex_manager_handle: ExExManagerHandle<E::Primitives>,
     ) -> Self {
         Self {
+            batch_sizer: BatchSizer::new(thresholds.target_batch_duration),
             external_clean_threshold,
             evm_config,
             consensus,
@@ -119,9 +139,26 @@ where
             post_unwind_commit_input: None,
             exex_manager_handle,
             metrics: ExecutorMetrics::default(),
+            bal_store: None,
+            prewarmer: None,
         }
     }
 
+    /// Enables parallel execution of the blocks whose block access list is in the given store.
+    ///
+    /// See [`Executor::execute_one_with_bal`].
+    pub fn with_bal_store(mut self, bal_store: BalStoreHandle) -> Self {
+        self.bal_store = Some(bal_store);
+        self
+    }
+
+    /// Sets the [`StatePrewarmer`] that reads the state of upcoming blocks while the current
+    /// batch executes.
+    pub fn with_state_prewarmer(mut self, prewarmer: StatePrewarmer) -> Self {
+        self.prewarmer = Some(prewarmer);
+        self
+    }
+
     /// Create an execution stage with the provided executor.
     ///
     /// The commit threshold will be set to [`MERKLE_STAGE_DEFAULT_INCREMENTAL_THRESHOLD`].
@@ -227,8 +264,9 @@ where
             // It can be equal when it's a chain of empty blocks, but we still need to update the
             // last block in the range.
             Ordering::Greater | Ordering::Equal => {
-                let mut static_file_producer =
-                    static_file_provider.latest_writer(StaticFileSegment::Receipts)?;
+                let mut static_file_producer = static_file_provider
+                    .latest_writer(StaticFileSegment::Receipts)
+                    .map_err(|err| execution_error(err, checkpoint))?;
                 static_file_producer.prune_receipts(
                     next_static_file_receipt_num.saturating_sub(next_receipt_num),
                     checkpoint,
@@ -282,6 +320,10 @@ where
         StageId::Execution
     }
 
+    fn supports_chunked_unwind(&self) -> bool {
+        true
+    }
+
     fn poll_execute_ready(
         &mut self,
         cx: &mut Context<'_>,
@@ -330,10 +372,18 @@ where
         // Execute block range
         let mut cumulative_gas = 0;
         let batch_start = Instant::now();
+        let batch_max_blocks = self.batch_sizer.max_blocks();
+        if batch_max_blocks.is_some() {
+            debug!(target: "sync::stages::execution", ?batch_max_blocks, "Adapted batch size to the target batch duration");
+        }
 
         let mut blocks = Vec::new();
         let mut results = Vec::new();
         for block_number in start_block..=max_block {
+            if let Some(prewarmer) = &mut self.prewarmer {
+                prewarmer.on_block(block_number, max_block);
+            }
+
             // Fetch the block
             let fetch_block_start = Instant::now();
 
@@ -349,11 +399,30 @@ where
             // Configure the executor to use the current state.
             trace!(target: "sync::stages::execution", number = block_number, txs = block.body().transactions().len(), "Executing block");
 
+            let bal = match &self.bal_store {
+                Some(bal_store) => stored_block_access_list(bal_store, &block)?,
+                None => None,
+            };
+
             // Execute the block
             let execute_start = Instant::now();
 
             let result = self.metrics.metered_one(&block, |input| {
-                executor.execute_one(input).map_err(|error| StageError::Block {
+                let result = match &bal {
+                    Some(bal) => executor.execute_one_with_bal(input, bal),
+                    None => Ok(None),
+                };
+                match result {
+                    Ok(Some(result)) => Ok(result),
+                    Ok(None) => {
+                        if bal.is_some() {
+                            trace!(target: "sync::stages::execution", number = block_number, "Falling back to serial execution");
+                        }
+                        executor.execute_one(input)
+                    }
+                    Err(error) => Err(error),
+                }
+                .map_err(|error| StageError::Block {
                     block: Box::new(block.block_with_parent()),
                     error: BlockErrorKind::Execution(error),
                 })
@@ -401,7 +470,10 @@ where
                 executor.size_hint() as u64,
                 cumulative_gas,
                 batch_start.elapsed(),
-            ) {
+                executor.memory_size_hint() as u64,
+            ) || batch_max_blocks
+                .is_some_and(|max_blocks| block_number - start_block + 1 >= max_blocks)
+            {
                 break
             }
         }
@@ -502,6 +574,11 @@ where
         }
 
         let db_write_duration = time.elapsed();
+        self.batch_sizer.record(
+            stage_progress - start_block + 1,
+            cumulative_gas,
+            batch_start.elapsed(),
+        );
         debug!(
             target: "sync::stages::execution",
             block_fetch = ?fetch_block_duration,
@@ -555,6 +632,10 @@ where
         // This also updates `PlainStorageState` and `PlainAccountState`.
         let bundle_state_with_receipts = provider.take_state_above(unwind_to)?;
 
+        if let Some(prewarmer) = &mut self.prewarmer {
+            prewarmer.on_unwind(unwind_to);
+        }
+
         // Prepare the input for post unwind commit hook, where an `ExExNotification` will be sent.
         if self.exex_manager_handle.has_exexs() {
             // Get the blocks for the unwound range.
@@ -626,21 +707,51 @@ where
     let unwind_to_is_cancun =
         provider.chain_spec().is_cancun_active_at_timestamp(unwind_to_header.timestamp());
     if checkpoint_is_cancun && !unwind_to_is_cancun {
-        return Err(StageError::Fatal(
-            std::io::Error::other(format!(
+        return Err(StageError::Fatal(Box::new(
+            RethError::msg(format!(
                 "execution unwind across Cancun activation boundary is not allowed: checkpoint \
                  block #{checkpoint_block} (ts={}) is Cancun-active but unwind target \
                  #{unwind_to} (ts={}) is pre-Cancun",
                 checkpoint_header.timestamp(),
                 unwind_to_header.timestamp()
             ))
-            .into(),
-        ))
+            .with_context(execution_context(checkpoint_block)),
+        )))
     }
 
     Ok(())
 }
 
+/// Returns the [`ErrorContext`] of the execution stage at the given block.
+const fn execution_context(block_number: BlockNumber) -> ErrorContext {
+    ErrorContext::new().with_stage(StageId::Execution).with_block_number(block_number)
+}
+
+/// Converts `error` into a [`StageError`] carrying the execution stage and the given block as
+/// context.
+fn execution_error(error: impl Into<RethError>, block_number: BlockNumber) -> StageError {
+    StageError::Internal(error.into().with_context(execution_context(block_number)))
+}
+
+/// Returns the block access list of the given block from the store, if the block has one and it
+/// is stored.
+fn stored_block_access_list<B: Block>(
+    bal_store: &BalStoreHandle,
+    block: &RecoveredBlock<B>,
+) -> ProviderResult<Option<BlockAccessList>> {
+    if block.header().block_access_list_hash().is_none() {
+        return Ok(None)
+    }
+
+    let Some(Some(raw)) = bal_store.get_by_hashes(&[block.hash()])?.pop() else { return Ok(None) };
+    match BlockAccessList::decode(&mut raw.as_ref()) {
+        Ok(bal) => Ok(Some(bal)),
+        Err(err) => {
+            debug!(target: "sync::stages::execution", number = block.header().number(), %err, "Failed to decode block access list");
+            Ok(None)
+        }
+    }
+}
 fn execution_checkpoint<N>(
     provider: &StaticFileProvider<N>,
     start_block: BlockNumber,
@@ -786,6 +897,8 @@ mod tests {
                 max_changes: None,
                 max_cumulative_gas: None,
                 max_duration: None,
+                max_memory: None,
+                target_batch_duration: None,
             },
             MERKLE_STAGE_DEFAULT_REBUILD_THRESHOLD,
             ExExManagerHandle::empty(),
@@ -1254,8 +1367,12 @@ mod tests {
             )
             .unwrap_err();
 
-        assert_matches!(err, StageError::Fatal(_));
         assert!(err.to_string().contains("across Cancun activation boundary"));
+        let StageError::Fatal(err) = err else { panic!("expected a fatal error, got {err:?}") };
+        assert_eq!(
+            err.downcast_ref::<RethError>().and_then(RethError::context),
+            Some(&execution_context(2))
+        );
     }
 
     #[tokio::test]
@@ -0,0 +1,192 @@
+use super::stored_block_access_list;
+use alloy_consensus::{BlockHeader, Transaction};
+use alloy_primitives::BlockNumber;
+use reth_primitives_traits::{Block, RecoveredBlock};
+use reth_provider::{
+    AccountReader, BalStoreHandle, BlockHashReader, BlockReader, DatabaseProviderFactory,
+    LatestStateProviderRef, ProviderResult, StateProvider, StorageSettingsCache,
+    TransactionVariant,
+};
+use std::{
+    ops::RangeInclusive,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc, Arc,
+    },
+};
+use tracing::*;
+
+/// Prewarms the state read by upcoming blocks on a background thread.
+///
+/// For every requested block, the accounts of the beneficiary, the transaction senders and
+/// recipients, the bytecode of the recipients and, if a block access list is stored for the block,
+/// all listed accounts and storage slots are read through a separate read-only database
+/// transaction. The values are discarded: the reads only pull the database pages into the page
+/// cache, so that the execution of the block doesn't have to wait on disk reads.
+///
+/// The prewarm thread exits once the prewarmer is dropped.
+#[derive(Debug)]
+pub struct StatePrewarmer {
+    /// Number of blocks to prewarm ahead of the executed block.
+    lookahead: u64,
+    /// Highest block that was requested to be prewarmed.
+    last_requested: Option<BlockNumber>,
+    /// Block that is currently executed, blocks up to it are skipped by the prewarm thread.
+    executing: Arc<AtomicU64>,
+    /// Sends the block ranges to prewarm to the prewarm thread.
+    range_tx: mpsc::Sender<RangeInclusive<BlockNumber>>,
+}
+
+impl StatePrewarmer {
+    /// Spawns the prewarm thread, which reads through read-only providers of the given factory.
+    ///
+    /// Blocks are prewarmed up to `lookahead` blocks ahead of the executed block.
+    pub fn spawn<F>(factory: F, bal_store: Option<BalStoreHandle>, lookahead: u64) -> Self
+    where
+        F: DatabaseProviderFactory<Provider: BlockReader + BlockHashReader + StorageSettingsCache>
+            + 'static,
+    {
+        let (range_tx, range_rx) = mpsc::channel::<RangeInclusive<BlockNumber>>();
+        let executing = Arc::new(AtomicU64::new(0));
+
+        let executing_block = Arc::clone(&executing);
+        reth_tasks::spawn_os_thread("state-prewarm", move || {
+            while let Ok(range) = range_rx.recv() {
+                if let Err(err) =
+                    prewarm_range(&factory, bal_store.as_ref(), range.clone(), &executing_block)
+                {
+                    debug!(target: "sync::stages::execution::prewarm", ?range, %err, "Failed to prewarm state");
+                }
+            }
+        });
+
+        Self { lookahead, last_requested: None, executing, range_tx }
+    }
+
+    /// Marks `block_number` as executing and requests prewarming of the blocks after it, up to
+    /// the lookahead and at most up to `max_block`.
+    pub(crate) fn on_block(&mut self, block_number: BlockNumber, max_block: BlockNumber) {
+        self.executing.store(block_number, Ordering::Relaxed);
+
+        let start = self.last_requested.map_or(block_number, |last| last.max(block_number)) + 1;
+        let end = block_number.saturating_add(self.lookahead).min(max_block);
+        if start > end {
+            return
+        }
+
+        if self.range_tx.send(start..=end).is_ok() {
+            self.last_requested = Some(end);
+        }
+    }
+
+    /// Forgets the blocks above `unwind_to` that were requested, so that they are prewarmed again
+    /// once they are re-executed.
+    pub(crate) fn on_unwind(&mut self, unwind_to: BlockNumber) {
+        self.executing.store(unwind_to, Ordering::Relaxed);
+        self.last_requested = self.last_requested.map(|last| last.min(unwind_to));
+    }
+}
+
+/// Reads the state accessed by the blocks in the given range, skipping blocks that are already
+/// executing.
+fn prewarm_range<F>(
+    factory: &F,
+    bal_store: Option<&BalStoreHandle>,
+    range: RangeInclusive<BlockNumber>,
+    executing: &AtomicU64,
+) -> ProviderResult<()>
+where
+    F: DatabaseProviderFactory<Provider: BlockReader + BlockHashReader + StorageSettingsCache>,
+{
+    let provider = factory.database_provider_ro()?;
+    let state = LatestStateProviderRef::new(&provider);
+
+    for block_number in range {
+        if block_number <= executing.load(Ordering::Relaxed) {
+            continue
+        }
+
+        let Some(block) =
+            provider.recovered_block(block_number.into(), TransactionVariant::NoHash)?
+        else {
+            break
+        };
+        prewarm_block(&state, &block)?;
+
+        let bal = match bal_store {
+            Some(bal_store) => stored_block_access_list(bal_store, &block)?,
+            None => None,
+        };
+        for account in bal.iter().flatten() {
+            state.basic_account(&account.address)?;
+            let slots = account
+                .storage_changes
+                .iter()
+                .map(|changes| changes.slot)
+                .chain(account.storage_reads.iter().copied());
+            for slot in slots {
+                state.storage(account.address, slot.into())?;
+            }
+        }
+
+        trace!(target: "sync::stages::execution::prewarm", number = block_number, "Prewarmed block state");
+    }
+
+    Ok(())
+}
+
+/// Reads the accounts of the beneficiary, the transaction senders and recipients, and the
+/// bytecode of the recipients.
+fn prewarm_block<S, B>(state: &S, block: &RecoveredBlock<B>) -> ProviderResult<()>
+where
+    S: StateProvider,
+    B: Block,
+{
+    state.basic_account(&block.header().beneficiary())?;
+    for tx in block.transactions_recovered() {
+        state.basic_account(tx.signer())?;
+        if let Some(to) = tx.to() &&
+            let Some(account) = state.basic_account(&to)? &&
+            let Some(code_hash) = account.bytecode_hash
+        {
+            state.bytecode_by_hash(&code_hash)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_lookahead_ranges() {
+        let (range_tx, range_rx) = mpsc::channel();
+        let mut prewarmer = StatePrewarmer {
+            lookahead: 4,
+            last_requested: None,
+            executing: Arc::default(),
+            range_tx,
+        };
+
+        prewarmer.on_block(1, 100);
+        assert_eq!(range_rx.try_recv(), Ok(2..=5));
+
+        // Only the blocks that entered the lookahead window are requested.
+        prewarmer.on_block(2, 100);
+        assert_eq!(range_rx.try_recv(), Ok(6..=6));
+
+        // The window is capped at the last block of the range.
+        prewarmer.on_block(99, 100);
+        assert_eq!(range_rx.try_recv(), Ok(100..=100));
+        prewarmer.on_block(100, 100);
+        assert!(range_rx.try_recv().is_err());
+        assert_eq!(prewarmer.executing.load(Ordering::Relaxed), 100);
+
+        // The unwound blocks are requested again once they are re-executed.
+        prewarmer.on_unwind(90);
+        assert_eq!(prewarmer.executing.load(Ordering::Relaxed), 90);
+        prewarmer.on_block(91, 100);
+        assert_eq!(range_rx.try_recv(), Ok(92..=95));
+    }
+}
@@ -153,6 +153,10 @@ where
         StageId::AccountHashing
     }
 
+    fn supports_chunked_unwind(&self) -> bool {
+        true
+    }
+
     /// Execute the stage.
     ///
     /// When `use_hashed_state` is enabled, this stage is a no-op because the execution stage
@@ -86,6 +86,10 @@ where
         StageId::StorageHashing
     }
 
+    fn supports_chunked_unwind(&self) -> bool {
+        true
+    }
+
     /// Execute the stage.
     fn execute(&mut self, provider: &Provider, input: ExecInput) -> Result<ExecOutput, StageError> {
         let tx = provider.tx_ref();
@@ -183,14 +187,23 @@ where
                 input.next_block_range_with_threshold(self.commit_threshold);
             let (from_block, to_block) = block_range.into_inner();
 
+            let mut packed_cursor = tx.cursor_read::<tables::PackedStorageChangeSets>()?;
             let mut changeset_cursor = tx.cursor_read::<tables::StorageChangeSets>()?;
             let mut changed: BTreeMap<Address, BTreeSet<B256>> = BTreeMap::new();
             let mut total_entries = 0u64;
             let mut last_block = from_block;
 
-            for entry in
-                changeset_cursor.walk_range(BlockNumberAddress::range(from_block..=to_block))?
-            {
+            // Packed changesets always belong to blocks below the ones in `StorageChangeSets`.
+            let packed = packed_cursor
+                .walk_range(BlockNumberAddress::range(from_block..=to_block))?
+                .map_ok(|(key, changeset)| {
+                    changeset.into_entries().into_iter().map(move |entry| (key, entry))
+                })
+                .flatten_ok();
+            let changesets =
+                changeset_cursor.walk_range(BlockNumberAddress::range(from_block..=to_block))?;
+
+            for entry in packed.chain(changesets) {
                 let (BlockNumberAddress((block_number, address)), storage_entry) = entry?;
 
                 // Check the entry limit only at block boundaries so we never
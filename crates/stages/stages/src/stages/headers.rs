@@ -1,8 +1,8 @@
 use alloy_consensus::BlockHeader;
-use alloy_primitives::{BlockHash, BlockNumber, Bytes, B256};
+use alloy_primitives::{BlockHash, BlockNumber, B256};
 use alloy_rlp::Decodable;
 use futures_util::StreamExt;
-use reth_config::config::EtlConfig;
+use reth_config::config::{EtlConfig, HeadersConfig};
 use reth_db_api::{
     cursor::{DbCursorRO, DbCursorRW},
     table::Value,
@@ -18,7 +18,7 @@ use reth_network_p2p::headers::{
 use reth_primitives_traits::{FullBlockHeader, HeaderTy, NodePrimitives, SealedHeader};
 use reth_provider::{
     providers::StaticFileWriter, BlockHashReader, DBProvider, HeaderSyncGapProvider,
-    StaticFileProviderFactory,
+    StageCheckpointReader, StageCheckpointWriter, StaticFileProviderFactory,
 };
 use reth_stages_api::{
     CheckpointBlockRange, EntitiesCheckpoint, ExecInput, ExecOutput, HeadersCheckpoint, Stage,
@@ -38,9 +38,13 @@ use tracing::*;
 /// The headers are processed and data is inserted into static files, as well as into the
 /// [`HeaderNumbers`][reth_db_api::tables::HeaderNumbers] table.
 ///
-/// NOTE: This stage downloads headers in reverse and pushes them to the ETL [`Collector`]. It then
-/// proceeds to push them sequentially to static files. The stage checkpoint is not updated until
-/// this stage is done.
+/// NOTE: This stage downloads headers in reverse. Every `commit_threshold` headers, the downloaded
+/// headers are committed to the
+/// [`HeadersDownloadBuffer`][reth_db_api::tables::HeadersDownloadBuffer] table and the lowest
+/// downloaded header is saved as an intra-stage checkpoint, so that an interrupted download resumes
+/// below it instead of from the tip. Once the download reaches the local head, the headers are
+/// pushed sequentially to static files. The stage checkpoint is not updated until this stage is
+/// done.
 #[derive(Debug)]
 pub struct HeaderStage<Provider, Downloader: HeaderDownloader> {
     /// Database handle.
@@ -55,9 +59,15 @@ pub struct HeaderStage<Provider, Downloader: HeaderDownloader> {
     sync_gap: Option<HeaderSyncGap<Downloader::Header>>,
     /// ETL collector with `HeaderHash` -> `BlockNumber`
     hash_collector: Collector<BlockHash, BlockNumber>,
-    /// ETL collector with `BlockNumber` -> `RLP-encoded SealedHeader`
-    header_collector: Collector<BlockNumber, Bytes>,
-    /// Returns true if the ETL collector has all necessary headers to fill the gap.
+    /// Headers downloaded since the last commit, in descending order.
+    downloaded_headers: Vec<SealedHeader<Downloader::Header>>,
+    /// The lowest header in the download buffer, `None` if the buffer is empty.
+    lowest_buffered_header: Option<SealedHeader<Downloader::Header>>,
+    /// Whether [`Self::lowest_buffered_header`] was loaded from the intra-stage checkpoint.
+    is_download_checkpoint_loaded: bool,
+    /// The maximum number of headers to download before committing them to the download buffer.
+    commit_threshold: u64,
+    /// Returns true if all headers necessary to fill the gap are downloaded.
     is_etl_ready: bool,
 }
 
@@ -79,23 +89,108 @@ where
             downloader,
             tip,
             sync_gap: None,
-            hash_collector: Collector::new(etl_config.file_size / 2, etl_config.dir.clone()),
-            header_collector: Collector::new(etl_config.file_size / 2, etl_config.dir),
+            hash_collector: Collector::new(etl_config.file_size, etl_config.dir),
+            downloaded_headers: Vec::new(),
+            lowest_buffered_header: None,
+            is_download_checkpoint_loaded: false,
+            commit_threshold: HeadersConfig::default().commit_threshold,
             is_etl_ready: false,
         }
     }
 
+    /// Set the maximum number of headers to download before committing them to the download
+    /// buffer.
+    pub const fn with_commit_threshold(mut self, commit_threshold: u64) -> Self {
+        self.commit_threshold = commit_threshold;
+        self
+    }
+
     /// Clear all ETL state. Called on error paths to prevent buffer pollution on retry.
+    ///
+    /// Headers that were already committed to the download buffer are kept.
     fn clear_etl_state(&mut self) {
         self.sync_gap = None;
         self.hash_collector.clear();
-        self.header_collector.clear();
+        self.downloaded_headers.clear();
         self.is_etl_ready = false;
     }
 
-    /// Write downloaded headers to storage from ETL.
+    /// Returns the lowest downloaded header of an interrupted download.
+    fn get_download_checkpoint(
+        &self,
+    ) -> Result<Option<SealedHeader<Downloader::Header>>, StageError>
+    where
+        Provider: StageCheckpointReader,
+        Downloader::Header: Decodable,
+    {
+        let buf =
+            self.provider.get_stage_checkpoint_progress(StageId::Headers)?.unwrap_or_default();
+
+        if buf.is_empty() {
+            return Ok(None)
+        }
+
+        let header = Decodable::decode(&mut buf.as_slice())
+            .map_err(|err| StageError::Fatal(Box::new(err)))?;
+        Ok(Some(SealedHeader::seal_slow(header)))
+    }
+
+    /// Saves the lowest downloaded header, `None` once the download is done.
+    fn save_download_checkpoint(
+        provider: &impl StageCheckpointWriter,
+        lowest_header: Option<&SealedHeader<Downloader::Header>>,
+    ) -> Result<(), StageError> {
+        let buf =
+            lowest_header.map(|header| alloy_rlp::encode(header.header())).unwrap_or_default();
+        Ok(provider.save_stage_checkpoint_progress(StageId::Headers, buf)?)
+    }
+
+    /// Commits the headers downloaded since the last commit to the download buffer and saves the
+    /// lowest of them as the intra-stage checkpoint.
+    ///
+    /// Returns the range of buffered headers.
+    fn buffer_downloaded_headers<P>(
+        &mut self,
+        provider: &P,
+    ) -> Result<Option<(BlockNumber, BlockNumber)>, StageError>
+    where
+        P: DBProvider<Tx: DbTxMut> + StageCheckpointWriter,
+        Downloader: HeaderDownloader<Header = <P::Primitives as NodePrimitives>::BlockHeader>,
+        <P::Primitives as NodePrimitives>::BlockHeader: Value,
+    {
+        let mut cursor = provider
+            .tx_ref()
+            .cursor_write::<tables::HeadersDownloadBuffer<HeaderTy<P::Primitives>>>()?;
+
+        if let Some(lowest) = self.downloaded_headers.last().cloned() {
+            for header in self.downloaded_headers.drain(..) {
+                cursor.upsert(header.number(), header.header())?;
+            }
+            Self::save_download_checkpoint(provider, Some(&lowest))?;
+            self.lowest_buffered_header = Some(lowest);
+        }
+
+        let lowest = self.lowest_buffered_header.as_ref().map(|header| header.number());
+        let highest = cursor.last()?.map(|(number, _)| number);
+        Ok(lowest.zip(highest))
+    }
+
+    /// Clears the download buffer and the intra-stage checkpoint.
+    fn clear_download_buffer<P>(&mut self, provider: &P) -> Result<(), StageError>
+    where
+        P: DBProvider<Tx: DbTxMut> + StageCheckpointWriter,
+        <P::Primitives as NodePrimitives>::BlockHeader: Value,
+    {
+        self.lowest_buffered_header = None;
+        self.is_download_checkpoint_loaded = true;
+        provider.tx_ref().clear::<tables::HeadersDownloadBuffer<HeaderTy<P::Primitives>>>()?;
+        Self::save_download_checkpoint(provider, None)
+    }
+
+    /// Write downloaded headers to storage.
     ///
-    /// Writes to static files ( `Header | HeaderTD | HeaderHash` ) and [`tables::HeaderNumbers`]
+    /// Writes the headers downloaded since the last commit, followed by the headers of the download
+    /// buffer, to static files ( `Header | HeaderTD | HeaderHash` ) and [`tables::HeaderNumbers`]
     /// database table.
     fn write_headers<P>(&mut self, provider: &P) -> Result<BlockNumber, StageError>
     where
@@ -103,7 +198,14 @@ where
         Downloader: HeaderDownloader<Header = <P::Primitives as NodePrimitives>::BlockHeader>,
         <P::Primitives as NodePrimitives>::BlockHeader: Value + FullBlockHeader,
     {
-        let total_headers = self.header_collector.len();
+        let downloaded_headers = std::mem::take(&mut self.downloaded_headers);
+        let mut buffer_cursor = provider
+            .tx_ref()
+            .cursor_read::<tables::HeadersDownloadBuffer<HeaderTy<P::Primitives>>>()?;
+        let total_headers = downloaded_headers.len() +
+            provider
+                .tx_ref()
+                .entries::<tables::HeadersDownloadBuffer<HeaderTy<P::Primitives>>>()?;
 
         info!(target: "sync::stages::headers", total = total_headers, "Writing headers");
 
@@ -115,22 +217,22 @@ where
             .get_highest_static_file_block(StaticFileSegment::Headers)
             .unwrap_or_default();
 
-        // Although headers were downloaded in reverse order, the collector iterates it in ascending
-        // order
+        // Headers were downloaded in reverse order, and all headers downloaded since the last
+        // commit are below the buffered ones, which are iterated in ascending order
+        let buffered_headers = buffer_cursor
+            .walk(None)?
+            .map(|entry| entry.map(|(_, header)| SealedHeader::seal_slow(header)));
+        let headers = downloaded_headers.into_iter().rev().map(Ok).chain(buffered_headers);
+
         let mut writer = static_file_provider.latest_writer(StaticFileSegment::Headers)?;
         let interval = (total_headers / 10).max(1);
-        for (index, header) in self.header_collector.iter()?.enumerate() {
-            let (_, header_buf) = header?;
+        for (index, sealed_header) in headers.enumerate() {
+            let sealed_header = sealed_header?;
 
             if index > 0 && index.is_multiple_of(interval) && total_headers > 100 {
                 info!(target: "sync::stages::headers", progress = %format!("{:.2}%", (index as f64 / total_headers as f64) * 100.0), "Writing headers");
             }
 
-            let sealed_header: SealedHeader<Downloader::Header> = SealedHeader::new_unhashed(
-                Decodable::decode(&mut header_buf.as_slice())
-                    .map_err(|err| StageError::Fatal(Box::new(err)))?,
-            );
-
             let (header, header_hash) = sealed_header.split_ref();
             if header.number() == 0 {
                 continue
@@ -139,6 +241,7 @@ where
 
             // Append to Headers segment
             writer.append_header(header, header_hash)?;
+            self.hash_collector.insert(*header_hash, header.number())?;
         }
 
         info!(target: "sync::stages::headers", total = total_headers, "Writing headers hash index");
@@ -186,8 +289,9 @@ where
 
 impl<Provider, P, D> Stage<Provider> for HeaderStage<P, D>
 where
-    Provider: DBProvider<Tx: DbTxMut> + StaticFileProviderFactory,
-    P: HeaderSyncGapProvider<Header = <Provider::Primitives as NodePrimitives>::BlockHeader>,
+    Provider: DBProvider<Tx: DbTxMut> + StaticFileProviderFactory + StageCheckpointWriter,
+    P: HeaderSyncGapProvider<Header = <Provider::Primitives as NodePrimitives>::BlockHeader>
+        + StageCheckpointReader,
     D: HeaderDownloader<Header = <Provider::Primitives as NodePrimitives>::BlockHeader>,
     <Provider::Primitives as NodePrimitives>::BlockHeader: FullBlockHeader + Value,
 {
@@ -196,6 +300,10 @@ where
         StageId::Headers
     }
 
+    fn supports_chunked_unwind(&self) -> bool {
+        true
+    }
+
     fn poll_execute_ready(
         &mut self,
         cx: &mut Context<'_>,
@@ -203,14 +311,29 @@ where
     ) -> Poll<Result<(), StageError>> {
         let current_checkpoint = input.checkpoint();
 
-        // Return if stage has already completed the gap on the ETL files
+        // Return if stage has already downloaded all headers of the gap
         if self.is_etl_ready {
             return Poll::Ready(Ok(()))
         }
 
-        // Lookup the head and tip of the sync range
+        if !self.is_download_checkpoint_loaded {
+            self.lowest_buffered_header = self.get_download_checkpoint()?;
+            self.is_download_checkpoint_loaded = true;
+        }
+
+        // Lookup the head and tip of the sync range. A download in progress keeps its target,
+        // otherwise an interrupted download is resumed below its lowest downloaded header.
         let local_head = self.provider.local_tip_header(current_checkpoint.block_number)?;
-        let target = SyncTarget::Tip(*self.tip.borrow());
+        let target = match &self.sync_gap {
+            Some(gap) if gap.local_head == local_head => gap.target.clone(),
+            _ => match self.downloaded_headers.last().or(self.lowest_buffered_header.as_ref()) {
+                Some(lowest) => {
+                    debug!(target: "sync::stages::headers", lowest = lowest.number(), "Resuming download");
+                    SyncTarget::Gap(lowest.block_with_parent())
+                }
+                None => SyncTarget::Tip(*self.tip.borrow()),
+            },
+        };
         let gap = HeaderSyncGap { local_head, target };
         let tip = gap.target.tip();
 
@@ -236,17 +359,14 @@ where
             self.downloader.update_sync_gap(gap.local_head, gap.target);
         }
 
-        // We only want to stop once we have all the headers on ETL filespace (disk).
+        // We only want to stop once we have all the headers, or enough headers to commit them.
         loop {
             match ready!(self.downloader.poll_next_unpin(cx)) {
                 Some(Ok(headers)) => {
                     info!(target: "sync::stages::headers", total = headers.len(), from_block = headers.first().map(|h| h.number()), to_block = headers.last().map(|h| h.number()), "Received headers");
                     for header in headers {
                         let header_number = header.number();
-
-                        self.hash_collector.insert(header.hash(), header_number)?;
-                        self.header_collector
-                            .insert(header_number, Bytes::from(alloy_rlp::encode(&*header)))?;
+                        self.downloaded_headers.push(header);
 
                         // Headers are downloaded in reverse, so if we reach here, we know we have
                         // filled the gap.
@@ -255,6 +375,10 @@ where
                             return Poll::Ready(Ok(()))
                         }
                     }
+
+                    if self.downloaded_headers.len() as u64 >= self.commit_threshold {
+                        return Poll::Ready(Ok(()))
+                    }
                 }
                 Some(Err(HeadersDownloaderError::DetachedHead { local_head, header, error })) => {
                     error!(target: "sync::stages::headers", %error, "Cannot attach header to head");
@@ -278,26 +402,54 @@ where
     fn execute(&mut self, provider: &Provider, input: ExecInput) -> Result<ExecOutput, StageError> {
         let current_checkpoint = input.checkpoint();
 
-        if self.sync_gap.take().ok_or(StageError::MissingSyncGap)?.is_closed() {
+        let gap = self.sync_gap.as_ref().ok_or(StageError::MissingSyncGap)?;
+        if gap.is_closed() &&
+            self.downloaded_headers.is_empty() &&
+            self.lowest_buffered_header.is_none()
+        {
+            self.sync_gap = None;
             self.is_etl_ready = false;
             return Ok(ExecOutput::done(current_checkpoint))
         }
 
-        // We should be here only after we have downloaded all headers into the disk buffer (ETL).
+        // The download is not done yet, commit the downloaded headers to the download buffer, so
+        // that they're not downloaded again if the stage is interrupted.
         if !self.is_etl_ready {
-            return Err(StageError::MissingDownloadBuffer)
+            if self.downloaded_headers.is_empty() {
+                return Err(StageError::MissingDownloadBuffer)
+            }
+
+            let Some((lowest, highest)) = self.buffer_downloaded_headers(provider)? else {
+                return Err(StageError::MissingDownloadBuffer)
+            };
+            info!(target: "sync::stages::headers", lowest, highest, "Committed downloaded headers");
+
+            return Ok(ExecOutput {
+                checkpoint: current_checkpoint.with_headers_stage_checkpoint(HeadersCheckpoint {
+                    block_range: CheckpointBlockRange {
+                        from: current_checkpoint.block_number,
+                        to: highest,
+                    },
+                    progress: EntitiesCheckpoint {
+                        processed: current_checkpoint.block_number + (highest - lowest + 1),
+                        total: highest,
+                    },
+                }),
+                done: false,
+            })
         }
 
-        // Reset flag
+        // Reset flags
         self.is_etl_ready = false;
+        self.sync_gap = None;
 
-        // Write the headers and related tables to DB from ETL space
-        let to_be_processed = self.hash_collector.len() as u64;
+        // Write the headers and related tables to DB from the download buffer and ETL space
         let last_header_number = self.write_headers(provider)?;
+        let to_be_processed = last_header_number.saturating_sub(current_checkpoint.block_number);
 
-        // Clear ETL collectors
+        // Clear the download buffer and ETL collectors
+        self.clear_download_buffer(provider)?;
         self.hash_collector.clear();
-        self.header_collector.clear();
 
         Ok(ExecOutput {
             checkpoint: StageCheckpoint::new(last_header_number).with_headers_stage_checkpoint(
@@ -312,8 +464,8 @@ where
                     },
                 },
             ),
-            // We only reach here if all headers have been downloaded by ETL, and pushed to DB all
-            // in one stage run.
+            // We only reach here if all headers have been downloaded, and pushed to DB all in one
+            // stage run.
             done: true,
         })
     }
@@ -326,6 +478,9 @@ where
     ) -> Result<UnwindOutput, StageError> {
         self.clear_etl_state();
 
+        // Buffered headers of an interrupted download might not connect to the new local head
+        self.clear_download_buffer(provider)?;
+
         // First unwind the db tables, until the unwind_to block number. use the walker to unwind
         // HeaderNumbers based on the index in CanonicalHeaders
         // unwind from the next block number since the unwind_to block is exclusive
@@ -413,6 +568,7 @@ mod tests {
             pub(crate) client: TestHeadersClient,
             channel: (watch::Sender<B256>, watch::Receiver<B256>),
             downloader_factory: Box<dyn Fn() -> D + Send + Sync + 'static>,
+            commit_threshold: u64,
             db: TestStageDB,
         }
 
@@ -426,6 +582,7 @@ mod tests {
                     downloader_factory: Box::new(move || {
                         TestHeaderDownloader::new(client.clone(), 1000, 1000)
                     }),
+                    commit_threshold: HeadersConfig::default().commit_threshold,
                     db: TestStageDB::default(),
                 }
             }
@@ -447,6 +604,7 @@ mod tests {
                     self.channel.1.clone(),
                     EtlConfig::default(),
                 )
+                .with_commit_threshold(self.commit_threshold)
             }
         }
 
@@ -528,15 +686,22 @@ mod tests {
 
         impl HeadersTestRunner<ReverseHeadersDownloader<TestHeadersClient>> {
             pub(crate) fn with_linear_downloader() -> Self {
+                Self::with_commit_threshold(500)
+            }
+
+            /// Creates a runner whose stage commits the downloaded headers every
+            /// `commit_threshold` headers.
+            pub(crate) fn with_commit_threshold(commit_threshold: u64) -> Self {
                 let client = TestHeadersClient::default();
                 Self {
                     client: client.clone(),
                     channel: watch::channel(B256::ZERO),
                     downloader_factory: Box::new(move || {
                         ReverseHeadersDownloaderBuilder::default()
-                            .stream_batch_size(500)
+                            .stream_batch_size(commit_threshold as usize)
                             .build(client.clone(), Arc::new(TestConsensus::default()))
                     }),
+                    commit_threshold,
                     db: TestStageDB::default(),
                 }
             }
@@ -602,7 +767,7 @@ mod tests {
         );
         assert!(runner.validate_execution(input, result.ok()).is_ok(), "validation failed");
         assert!(runner.stage().hash_collector.is_empty());
-        assert!(runner.stage().header_collector.is_empty());
+        assert!(runner.stage().downloaded_headers.is_empty());
 
         // let's insert some blocks using append_blocks_with_state
         let sealed_headers = random_header_range(
@@ -674,6 +839,56 @@ mod tests {
         );
         assert!(runner.validate_execution(input, result.ok()).is_ok(), "validation failed");
         assert!(runner.stage().hash_collector.is_empty());
-        assert!(runner.stage().header_collector.is_empty());
+        assert!(runner.stage().downloaded_headers.is_empty());
+    }
+
+    /// Interrupt the stage after the first commit of downloaded headers and ensure that the
+    /// download resumes below the lowest downloaded header.
+    #[tokio::test]
+    async fn execute_resumes_interrupted_download() {
+        let mut runner = HeadersTestRunner::with_commit_threshold(100);
+        let (checkpoint, previous_stage) = (1000, 1200);
+        let input = ExecInput {
+            target: Some(previous_stage),
+            checkpoint: Some(StageCheckpoint::new(checkpoint)),
+        };
+        let headers = runner.seed_execution(input).expect("failed to seed execution");
+        let tip = headers.last().unwrap();
+        runner.client.extend(headers.iter().rev().map(|h| h.clone_header())).await;
+        runner.send_tip(tip.hash());
+
+        // The first run only commits the first batch of downloaded headers to the buffer
+        let result = runner.execute(input).await.unwrap();
+        assert_matches!(result, Ok(ExecOutput {
+            checkpoint: StageCheckpoint { block_number, .. },
+            done: false
+        }) if block_number == checkpoint);
+        let lowest = headers[101].clone();
+        let provider = runner.db().factory.provider().unwrap();
+        assert_eq!(
+            provider.tx_ref().entries::<tables::HeadersDownloadBuffer>().unwrap(),
+            (tip.number - lowest.number + 1) as usize
+        );
+        drop(provider);
+        assert_eq!(
+            runner.stage().get_download_checkpoint().unwrap().map(|header| header.hash()),
+            Some(lowest.hash())
+        );
+
+        // A new stage only downloads the headers below the lowest buffered header
+        runner.client.clear().await;
+        runner.client.extend(headers[1..=100].iter().rev().map(|h| h.clone_header())).await;
+        let result = runner.execute(input).await.unwrap();
+        runner.db().factory.static_file_provider().commit().unwrap();
+        assert_matches!(result, Ok(ExecOutput {
+            checkpoint: StageCheckpoint { block_number, .. },
+            done: true
+        }) if block_number == tip.number);
+        assert!(runner.validate_execution(input, result.ok()).is_ok(), "validation failed");
+
+        let provider = runner.db().factory.provider().unwrap();
+        assert_eq!(provider.tx_ref().entries::<tables::HeadersDownloadBuffer>().unwrap(), 0);
+        drop(provider);
+        assert!(runner.stage().get_download_checkpoint().unwrap().is_none());
     }
 }
@@ -60,6 +60,10 @@ where
         StageId::IndexAccountHistory
     }
 
+    fn supports_chunked_unwind(&self) -> bool {
+        true
+    }
+
     /// Execute the stage.
     fn execute(
         &mut self,
@@ -1,4 +1,7 @@
-use super::{collect_history_indices, collect_storage_history_indices};
+use super::{
+    collect_history_indices, collect_packed_storage_history_indices,
+    collect_storage_history_indices,
+};
 use crate::{stages::utils::load_storage_history, StageCheckpoint, StageId};
 use reth_config::config::{EtlConfig, IndexHistoryConfig};
 use reth_db_api::{
@@ -65,6 +68,10 @@ where
         StageId::IndexStorageHistory
     }
 
+    fn supports_chunked_unwind(&self) -> bool {
+        true
+    }
+
     /// Execute the stage.
     fn execute(
         &mut self,
@@ -127,7 +134,12 @@ where
         let collector = if provider.cached_storage_settings().storage_v2 {
             collect_storage_history_indices(provider, range.clone(), &self.etl_config)?
         } else {
-            collect_history_indices::<_, tables::StorageChangeSets, tables::StoragesHistory, _>(
+            let mut collector = collect_history_indices::<
+                _,
+                tables::StorageChangeSets,
+                tables::StoragesHistory,
+                _,
+            >(
                 provider,
                 BlockNumberAddress::range(range.clone()),
                 |AddressStorageKey((address, storage_key)), highest_block_number| {
@@ -135,7 +147,9 @@ where
                 },
                 |(key, value)| (key.block_number(), AddressStorageKey((key.address(), value.key))),
                 &self.etl_config,
-            )?
+            )?;
+            collect_packed_storage_history_indices(provider, range.clone(), &mut collector)?;
+            collector
         };
 
         info!(target: "sync::stages::index_storage_history::exec", "Loading indices into database");
@@ -1,6 +1,7 @@
 use alloy_consensus::{constants::KECCAK_EMPTY, BlockHeader};
 use alloy_primitives::{BlockNumber, Sealable, B256};
 use reth_codecs::Compact;
+use reth_config::config::MerkleRebuildStrategy;
 use reth_consensus::ConsensusError;
 use reth_db_api::{
     tables,
@@ -18,7 +19,11 @@ use reth_stages_api::{
 use reth_trie::{IntermediateStateRootState, StateRoot, StateRootProgress, StoredSubNode};
 use reth_trie_db::DatabaseStateRoot;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, ops::RangeInclusive, time::Instant};
+
+mod rebuild;
+pub use rebuild::MerkleRebuildPolicy;
+use rebuild::RangeEstimate;
 
 type DbStateRoot<'a, TX, A> = StateRoot<
     reth_trie_db::DatabaseTrieCursorFactory<&'a TX, A>,
@@ -54,6 +59,10 @@ pub const MERKLE_STAGE_DEFAULT_REBUILD_THRESHOLD: u64 = 100_000;
 /// number.
 pub const MERKLE_STAGE_DEFAULT_INCREMENTAL_THRESHOLD: u64 = 7_000;
 
+/// The maximum number of blocks whose changesets are read to estimate the changeset density of a
+/// range of blocks.
+const CHANGESET_DENSITY_SAMPLES: u64 = 16;
+
 /// The merkle hashing stage uses input from
 /// [`AccountHashingStage`][crate::stages::AccountHashingStage] and
 /// [`StorageHashingStage`][crate::stages::StorageHashingStage] to calculate intermediate hashes
@@ -88,6 +97,8 @@ pub enum MerkleStage {
         /// incremental mode will calculate the state root by calculating the new state root for
         /// some number of blocks, repeating until we reach the desired block number.
         incremental_threshold: u64,
+        /// Decides between incremental trie building and whole rebuild.
+        rebuild_policy: MerkleRebuildPolicy,
     },
     /// The unwind portion of the merkle stage.
     Unwind,
@@ -101,6 +112,8 @@ pub enum MerkleStage {
         /// incremental mode will calculate the state root by calculating the new state root for
         /// some number of blocks, repeating until we reach the desired block number.
         incremental_threshold: u64,
+        /// Decides between incremental trie building and whole rebuild.
+        rebuild_policy: MerkleRebuildPolicy,
     },
 }
 
@@ -110,6 +123,7 @@ impl MerkleStage {
         Self::Execution {
             rebuild_threshold: MERKLE_STAGE_DEFAULT_REBUILD_THRESHOLD,
             incremental_threshold: MERKLE_STAGE_DEFAULT_INCREMENTAL_THRESHOLD,
+            rebuild_policy: MerkleRebuildPolicy::new(MerkleRebuildStrategy::Adaptive),
         }
     }
 
@@ -119,8 +133,77 @@ impl MerkleStage {
     }
 
     /// Create new instance of [`MerkleStage::Execution`].
-    pub const fn new_execution(rebuild_threshold: u64, incremental_threshold: u64) -> Self {
-        Self::Execution { rebuild_threshold, incremental_threshold }
+    pub const fn new_execution(
+        rebuild_threshold: u64,
+        incremental_threshold: u64,
+        rebuild_strategy: MerkleRebuildStrategy,
+    ) -> Self {
+        Self::Execution {
+            rebuild_threshold,
+            incremental_threshold,
+            rebuild_policy: MerkleRebuildPolicy::new(rebuild_strategy),
+        }
+    }
+
+    /// Returns the rebuild policy of the execution portion of the stage.
+    const fn rebuild_policy(&self) -> Option<&MerkleRebuildPolicy> {
+        match self {
+            Self::Execution { rebuild_policy, .. } => Some(rebuild_policy),
+            Self::Unwind => None,
+            #[cfg(any(test, feature = "test-utils"))]
+            Self::Both { rebuild_policy, .. } => Some(rebuild_policy),
+        }
+    }
+
+    /// Returns the mutable rebuild policy of the execution portion of the stage.
+    const fn rebuild_policy_mut(&mut self) -> Option<&mut MerkleRebuildPolicy> {
+        match self {
+            Self::Execution { rebuild_policy, .. } => Some(rebuild_policy),
+            Self::Unwind => None,
+            #[cfg(any(test, feature = "test-utils"))]
+            Self::Both { rebuild_policy, .. } => Some(rebuild_policy),
+        }
+    }
+
+    /// Decides whether the trie should be rebuilt for the given range instead of being updated
+    /// incrementally.
+    ///
+    /// Returns the estimated changeset density of the range as well, if it was needed for the
+    /// decision.
+    fn rebuild_decision<Provider>(
+        &self,
+        provider: &Provider,
+        range: &RangeInclusive<BlockNumber>,
+        rebuild_threshold: u64,
+    ) -> Result<(bool, Option<RangeEstimate>), StageError>
+    where
+        Provider: StageCheckpointReader + ChangeSetReader + StorageChangeSetReader + StatsReader,
+    {
+        // A rebuild in progress has to be finished, since the trie tables are incomplete
+        if self
+            .get_execution_checkpoint(provider)?
+            .is_some_and(|checkpoint| checkpoint.target_block == *range.end())
+        {
+            return Ok((true, None))
+        }
+
+        let blocks = range.end() - range.start();
+        let Some(policy) = self.rebuild_policy() else {
+            return Ok((blocks > rebuild_threshold, None))
+        };
+
+        let estimate = policy.is_adaptive().then(|| estimate_range(provider, range)).transpose()?;
+        let rebuild = policy.should_rebuild(blocks, rebuild_threshold, estimate.as_ref());
+        debug!(
+            target: "sync::stages::merkle::exec",
+            ?range,
+            strategy = ?policy.strategy(),
+            ?estimate,
+            rebuild,
+            "Decided how to update the trie"
+        );
+
+        Ok((rebuild, estimate))
     }
 
     /// Gets the hashing progress
@@ -187,11 +270,11 @@ where
                 info!(target: "sync::stages::merkle::unwind", "Stage is always skipped");
                 return Ok(ExecOutput::done(StageCheckpoint::new(input.target())))
             }
-            Self::Execution { rebuild_threshold, incremental_threshold } => {
+            Self::Execution { rebuild_threshold, incremental_threshold, .. } => {
                 (*rebuild_threshold, *incremental_threshold)
             }
             #[cfg(any(test, feature = "test-utils"))]
-            Self::Both { rebuild_threshold, incremental_threshold } => {
+            Self::Both { rebuild_threshold, incremental_threshold, .. } => {
                 (*rebuild_threshold, *incremental_threshold)
             }
         };
@@ -205,12 +288,21 @@ where
             .ok_or_else(|| ProviderError::HeaderNotFound(to_block.into()))?;
         let target_block_root = target_block.state_root();
 
+        // Without a trie, it has to be built from scratch
+        let (rebuild, estimate) = if from_block == 1 {
+            (true, None)
+        } else if range.is_empty() {
+            (false, None)
+        } else {
+            self.rebuild_decision(provider, &range, threshold)?
+        };
+
         let (trie_root, entities_checkpoint) = if range.is_empty() {
             (target_block_root, input.checkpoint().entities_stage_checkpoint().unwrap_or_default())
-        } else if to_block - from_block > threshold || from_block == 1 {
+        } else if rebuild {
             let mut checkpoint = self.get_execution_checkpoint(provider)?;
 
-            // if there are more blocks than threshold it is faster to rebuild the trie
+            // if the range is expensive to update incrementally, it is faster to rebuild the trie
             let mut entities_checkpoint = if let Some(checkpoint) =
                 checkpoint.as_ref().filter(|c| c.target_block == to_block)
             {
@@ -247,6 +339,7 @@ where
             });
 
             let tx = provider.tx_ref();
+            let started_at = Instant::now();
             let progress = reth_trie_db::with_adapter!(provider, |A| {
                 DbStateRoot::<_, A>::from_tx(tx)
                     .with_intermediate_state(checkpoint.map(IntermediateStateRootState::from))
@@ -256,8 +349,12 @@ where
                 error!(target: "sync::stages::merkle", %e, ?current_block_number, ?to_block, "State root with progress failed! {INVALID_STATE_ROOT_ERROR_MESSAGE}");
                 StageError::Fatal(Box::new(e))
             })?;
+            let elapsed = started_at.elapsed();
             match progress {
                 StateRootProgress::Progress(state, hashed_entries_walked, updates) => {
+                    if let Some(policy) = self.rebuild_policy_mut() {
+                        policy.record_rebuild(hashed_entries_walked as u64, elapsed);
+                    }
                     provider.write_trie_updates(updates)?;
 
                     let mut checkpoint = MerkleCheckpoint::new(
@@ -301,6 +398,9 @@ where
                     })
                 }
                 StateRootProgress::Complete(root, hashed_entries_walked, updates) => {
+                    if let Some(policy) = self.rebuild_policy_mut() {
+                        policy.record_rebuild(hashed_entries_walked as u64, elapsed);
+                    }
                     provider.write_trie_updates(updates)?;
 
                     entities_checkpoint.processed += hashed_entries_walked as u64;
@@ -309,34 +409,44 @@ where
                 }
             }
         } else {
-            debug!(target: "sync::stages::merkle::exec", current = ?current_block_number, target = ?to_block, "Updating trie in chunks");
-            let mut final_root = None;
-            for start_block in range.step_by(incremental_threshold as usize) {
-                let chunk_to = std::cmp::min(start_block + incremental_threshold - 1, to_block);
-                let chunk_range = start_block..=chunk_to;
-                debug!(
-                    target: "sync::stages::merkle::exec",
-                    current = ?current_block_number,
-                    target = ?to_block,
-                    incremental_threshold,
-                    chunk_range = ?chunk_range,
-                    "Processing chunk"
-                );
-                let (root, updates) = reth_trie_db::with_adapter!(provider, |A| {
-                    DbStateRoot::<_, A>::incremental_root_with_updates(provider, chunk_range)
-                })
-                .map_err(|e| {
-                    error!(target: "sync::stages::merkle", %e, ?current_block_number, ?to_block, "Incremental state root failed! {INVALID_STATE_ROOT_ERROR_MESSAGE}");
-                    StageError::Fatal(Box::new(e))
-                })?;
-                provider.write_trie_updates(updates)?;
-                final_root = Some(root);
+            // Only one chunk is processed per invocation, so that the progress is committed after
+            // every chunk and a restart resumes from the next chunk instead of the beginning of
+            // the range.
+            let chunk_to = std::cmp::min(from_block + incremental_threshold - 1, to_block);
+            let chunk_range = from_block..=chunk_to;
+            debug!(
+                target: "sync::stages::merkle::exec",
+                current = ?current_block_number,
+                target = ?to_block,
+                incremental_threshold,
+                chunk_range = ?chunk_range,
+                "Updating trie chunk"
+            );
+            let started_at = Instant::now();
+            let (root, updates) = reth_trie_db::with_adapter!(provider, |A| {
+                DbStateRoot::<_, A>::incremental_root_with_updates(provider, chunk_range)
+            })
+            .map_err(|e| {
+                error!(target: "sync::stages::merkle", %e, ?current_block_number, ?to_block, "Incremental state root failed! {INVALID_STATE_ROOT_ERROR_MESSAGE}");
+                StageError::Fatal(Box::new(e))
+            })?;
+            if let Some((policy, estimate)) = self.rebuild_policy_mut().zip(estimate) {
+                let changes = estimate.changes_per_block * (chunk_to - from_block + 1) as f64;
+                policy.record_incremental(changes, started_at.elapsed());
             }
+            provider.write_trie_updates(updates)?;
 
-            // if we had no final root, we must have not looped above, which should not be possible
-            let final_root = final_root.ok_or(StageError::Fatal(
-                "Incremental merkle hashing did not produce a final root".into(),
-            ))?;
+            if chunk_to < to_block {
+                // The hashed state is already at the target block, so the intermediate root is
+                // not the state root of the chunk's last block and can't be validated. The
+                // remaining chunks update all keys that changed after it.
+                return Ok(ExecOutput {
+                    checkpoint: StageCheckpoint::new(chunk_to).with_entities_stage_checkpoint(
+                        input.checkpoint().entities_stage_checkpoint().unwrap_or_default(),
+                    ),
+                    done: false,
+                })
+            }
 
             let total_hashed_entries = (provider.count_entries::<tables::HashedAccounts>()? +
                 provider.count_entries::<tables::HashedStorages>()?)
@@ -350,7 +460,7 @@ where
                 total: total_hashed_entries,
             };
             // Save the checkpoint
-            (final_root, entities_checkpoint)
+            (root, entities_checkpoint)
         };
 
         // Reset the checkpoint
@@ -432,6 +542,34 @@ where
     }
 }
 
+/// Estimates the work to bring the trie up to date for the given range, by reading the changesets
+/// of up to [`CHANGESET_DENSITY_SAMPLES`] evenly spaced blocks of the range.
+fn estimate_range<Provider>(
+    provider: &Provider,
+    range: &RangeInclusive<BlockNumber>,
+) -> Result<RangeEstimate, StageError>
+where
+    Provider: ChangeSetReader + StorageChangeSetReader + StatsReader,
+{
+    let blocks = range.end() - range.start() + 1;
+    let samples = blocks.min(CHANGESET_DENSITY_SAMPLES);
+    let step = blocks / samples;
+
+    let mut changes = 0;
+    for sample in 0..samples {
+        let block_number = range.start() + sample * step;
+        changes += provider.account_block_changeset(block_number)?.len() +
+            provider.storage_changeset(block_number)?.len();
+    }
+
+    Ok(RangeEstimate {
+        changes_per_block: changes as f64 / samples as f64,
+        blocks,
+        hashed_entries: (provider.count_entries::<tables::HashedAccounts>()? +
+            provider.count_entries::<tables::HashedStorages>()?) as u64,
+    })
+}
+
 /// Check that the computed state root matches the root in the expected header.
 #[inline]
 fn validate_state_root<H: BlockHeader + Sealable + Debug>(
@@ -566,19 +704,30 @@ mod tests {
         let mut runner =
             MerkleTestRunner { db: TestStageDB::default(), clean_threshold, incremental_threshold };
 
-        let input = ExecInput {
+        let mut input = ExecInput {
             target: Some(previous_stage),
             checkpoint: Some(StageCheckpoint::new(stage_progress)),
         };
 
         runner.seed_execution(input).expect("failed to seed execution");
-        let rx = runner.execute(input);
+
+        // Every invocation processes and commits a single chunk
+        let result = loop {
+            let output = runner.execute(input).await.unwrap().unwrap();
+            if output.done {
+                break output
+            }
+            assert_eq!(
+                output.checkpoint.block_number,
+                input.checkpoint().block_number + incremental_threshold
+            );
+            input.checkpoint = Some(output.checkpoint);
+        };
 
         // Assert the successful result
-        let result = rx.await.unwrap();
         assert_matches!(
             result,
-            Ok(ExecOutput {
+            ExecOutput {
                 checkpoint: StageCheckpoint {
                     block_number,
                     stage_checkpoint: Some(StageUnitCheckpoint::Entities(EntitiesCheckpoint {
@@ -587,7 +736,7 @@ mod tests {
                     }))
                 },
                 done: true
-            }) if block_number == previous_stage && processed == total &&
+            } if block_number == previous_stage && processed == total &&
                 total == (
                     runner.db.count_entries::<tables::HashedAccounts>().unwrap() +
                     runner.db.count_entries::<tables::HashedStorages>().unwrap()
@@ -645,6 +794,7 @@ mod tests {
             Self::S::Both {
                 rebuild_threshold: self.clean_threshold,
                 incremental_threshold: self.incremental_threshold,
+                rebuild_policy: MerkleRebuildPolicy::new(MerkleRebuildStrategy::Threshold),
             }
         }
     }
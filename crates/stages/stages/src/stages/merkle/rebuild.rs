@@ -0,0 +1,150 @@
+use reth_config::config::MerkleRebuildStrategy;
+use std::time::Duration;
+
+/// How many times slower an incremental update of a changed key is assumed to be than walking a
+/// hashed entry during a rebuild, until both were measured.
+///
+/// Incremental updates read the trie at random, while a rebuild walks the hashed state
+/// sequentially. With roughly 1500 changes per block on mainnet, this ratio makes a range of
+/// around 100k blocks the break-even point, which matches the default rebuild threshold.
+const DEFAULT_INCREMENTAL_COST_RATIO: f64 = 10.0;
+
+/// Weight of the most recent timing sample in the exponential moving averages.
+const SMOOTHING_FACTOR: f64 = 0.3;
+
+/// Estimated amount of work to bring the trie up to date for a range of blocks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct RangeEstimate {
+    /// Estimated number of changed accounts and storage slots per block, based on the changesets
+    /// of sampled blocks of the range.
+    pub(crate) changes_per_block: f64,
+    /// Number of blocks in the range.
+    pub(crate) blocks: u64,
+    /// Number of hashed accounts and storage slots walked by a full rebuild.
+    pub(crate) hashed_entries: u64,
+}
+
+impl RangeEstimate {
+    /// Estimated number of changed accounts and storage slots of the range.
+    pub(crate) fn changes(&self) -> f64 {
+        self.changes_per_block * self.blocks as f64
+    }
+}
+
+/// Decides between updating the trie incrementally and rebuilding it from scratch.
+///
+/// With [`MerkleRebuildStrategy::Adaptive`], the decision compares the estimated cost of both
+/// paths: the number of changes of the range, estimated from the changeset density of sampled
+/// blocks, against the number of hashed entries walked by a rebuild, weighted by the timings
+/// measured in previous runs of the stage.
+#[derive(Debug, Clone, Default)]
+pub struct MerkleRebuildPolicy {
+    strategy: MerkleRebuildStrategy,
+    /// Average seconds spent per changed key of incremental updates.
+    incremental_secs_per_change: Option<f64>,
+    /// Average seconds spent per hashed entry walked by rebuilds.
+    rebuild_secs_per_entry: Option<f64>,
+}
+
+impl MerkleRebuildPolicy {
+    /// Creates a new policy with the given strategy.
+    pub const fn new(strategy: MerkleRebuildStrategy) -> Self {
+        Self { strategy, incremental_secs_per_change: None, rebuild_secs_per_entry: None }
+    }
+
+    /// Returns the strategy of the policy.
+    pub const fn strategy(&self) -> MerkleRebuildStrategy {
+        self.strategy
+    }
+
+    /// Returns `true` if the changeset density of the range is needed to make a decision or to
+    /// record timings.
+    pub(crate) const fn is_adaptive(&self) -> bool {
+        matches!(self.strategy, MerkleRebuildStrategy::Adaptive)
+    }
+
+    /// Returns `true` if the trie should be rebuilt for a range of `blocks` blocks.
+    ///
+    /// The `estimate` is only required by the adaptive strategy.
+    pub(crate) fn should_rebuild(
+        &self,
+        blocks: u64,
+        rebuild_threshold: u64,
+        estimate: Option<&RangeEstimate>,
+    ) -> bool {
+        match self.strategy {
+            MerkleRebuildStrategy::Threshold => blocks > rebuild_threshold,
+            MerkleRebuildStrategy::Incremental => false,
+            MerkleRebuildStrategy::Rebuild => true,
+            MerkleRebuildStrategy::Adaptive => estimate.map_or(blocks > rebuild_threshold, |e| {
+                e.changes() * self.incremental_cost_ratio() > e.hashed_entries as f64
+            }),
+        }
+    }
+
+    /// How many times slower an incremental update of a changed key is than walking a hashed
+    /// entry during a rebuild.
+    fn incremental_cost_ratio(&self) -> f64 {
+        match (self.incremental_secs_per_change, self.rebuild_secs_per_entry) {
+            (Some(incremental), Some(rebuild)) if rebuild > 0.0 => incremental / rebuild,
+            _ => DEFAULT_INCREMENTAL_COST_RATIO,
+        }
+    }
+
+    /// Records that an incremental update of `changes` changed keys took `elapsed`.
+    pub(crate) fn record_incremental(&mut self, changes: f64, elapsed: Duration) {
+        record(&mut self.incremental_secs_per_change, changes, elapsed);
+    }
+
+    /// Records that walking `entries` hashed entries during a rebuild took `elapsed`.
+    pub(crate) fn record_rebuild(&mut self, entries: u64, elapsed: Duration) {
+        record(&mut self.rebuild_secs_per_entry, entries as f64, elapsed);
+    }
+}
+
+/// Adds a sample of `elapsed` time for `units` units of work to the moving average.
+fn record(average: &mut Option<f64>, units: f64, elapsed: Duration) {
+    if units < 1.0 || elapsed.is_zero() {
+        return
+    }
+
+    let sample = elapsed.as_secs_f64() / units;
+    *average =
+        Some(average.map_or(sample, |average| {
+            SMOOTHING_FACTOR * sample + (1.0 - SMOOTHING_FACTOR) * average
+        }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adaptive_rebuild_decision() {
+        let mut policy = MerkleRebuildPolicy::new(MerkleRebuildStrategy::Adaptive);
+        let sparse = RangeEstimate {
+            changes_per_block: 10.0,
+            blocks: 200_000,
+            hashed_entries: 1_000_000_000,
+        };
+        let dense = RangeEstimate { changes_per_block: 1_000.0, ..sparse };
+
+        // Long ranges with few changes are updated incrementally, unlike with a static threshold.
+        assert!(!policy.should_rebuild(sparse.blocks, 100_000, Some(&sparse)));
+        assert!(policy.should_rebuild(dense.blocks, 100_000, Some(&dense)));
+        // Without an estimate the threshold is used.
+        assert!(policy.should_rebuild(sparse.blocks, 100_000, None));
+
+        // Slow incremental updates make the rebuild more attractive.
+        policy.record_rebuild(1_000_000, Duration::from_secs(1));
+        policy.record_incremental(1_000.0, Duration::from_secs(1));
+        assert!(policy.should_rebuild(sparse.blocks, 100_000, Some(&sparse)));
+
+        // The other strategies ignore the estimate.
+        let estimate = Some(&dense);
+        let policy = MerkleRebuildPolicy::new;
+        assert!(!policy(MerkleRebuildStrategy::Threshold).should_rebuild(10, 100, estimate));
+        assert!(!policy(MerkleRebuildStrategy::Incremental).should_rebuild(1_000, 100, estimate));
+        assert!(policy(MerkleRebuildStrategy::Rebuild).should_rebuild(10, 100, estimate));
+    }
+}
@@ -1,3 +1,5 @@
+/// The block access list stage.
+mod block_access_list;
 /// The bodies stage.
 mod bodies;
 mod era;
@@ -23,6 +25,7 @@ mod sender_recovery;
 /// The transaction lookup stage
 mod tx_lookup;
 
+pub use block_access_list::*;
 pub use bodies::*;
 pub use era::*;
 pub use execution::*;
@@ -164,6 +167,8 @@ mod tests {
                     max_changes: None,
                     max_cumulative_gas: None,
                     max_duration: None,
+                    max_memory: None,
+                    target_batch_duration: None,
                 },
                 MERKLE_STAGE_DEFAULT_REBUILD_THRESHOLD,
                 ExExManagerHandle::empty(),
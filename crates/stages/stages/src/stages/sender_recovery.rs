@@ -23,7 +23,11 @@ use reth_stages_api::{
     StageId, UnwindInput, UnwindOutput,
 };
 use reth_static_file_types::StaticFileSegment;
-use std::{fmt::Debug, ops::Range, sync::mpsc};
+use std::{
+    fmt::Debug,
+    ops::Range,
+    sync::{mpsc, Arc},
+};
 use thiserror::Error;
 use tracing::*;
 
@@ -50,18 +54,44 @@ pub struct SenderRecoveryStage {
     /// fast-forward its checkpoint to skip all work, since senders will be recovered
     /// inline by the execution stage instead.
     pub prune_mode: Option<PruneMode>,
+    /// The number of threads used to recover senders. Uses the global rayon pool if not set.
+    pub workers: Option<usize>,
+    /// Dedicated thread pool for sender recovery, built on first use if `workers` is set.
+    pool: Option<Arc<rayon::ThreadPool>>,
 }
 
 impl SenderRecoveryStage {
     /// Create new instance of [`SenderRecoveryStage`].
     pub const fn new(config: SenderRecoveryConfig, prune_mode: Option<PruneMode>) -> Self {
-        Self { commit_threshold: config.commit_threshold, prune_mode }
+        Self {
+            commit_threshold: config.commit_threshold,
+            prune_mode,
+            workers: config.workers,
+            pool: None,
+        }
+    }
+
+    /// Returns the dedicated thread pool for sender recovery, building it if necessary.
+    ///
+    /// Returns `None` if no worker count is configured, in which case the global rayon pool is
+    /// used.
+    fn pool(&mut self) -> Result<Option<Arc<rayon::ThreadPool>>, StageError> {
+        let Some(workers) = self.workers else { return Ok(None) };
+        if self.pool.is_none() {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(workers)
+                .thread_name(|idx| format!("sender-recovery-{idx}"))
+                .build()
+                .map_err(|err| StageError::Fatal(err.into()))?;
+            self.pool = Some(Arc::new(pool));
+        }
+        Ok(self.pool.clone())
     }
 }
 
 impl Default for SenderRecoveryStage {
     fn default() -> Self {
-        Self { commit_threshold: 5_000_000, prune_mode: None }
+        Self::new(SenderRecoveryConfig::default(), None)
     }
 }
 
@@ -80,6 +110,10 @@ where
         StageId::SenderRecovery
     }
 
+    fn supports_chunked_unwind(&self) -> bool {
+        true
+    }
+
     /// Retrieve the range of transactions to iterate over by querying
     /// [`BlockBodyIndices`][reth_db_api::tables::BlockBodyIndices],
     /// collect transactions within that range, recover signer for each transaction and store
@@ -160,7 +194,7 @@ where
             .map(|start| start..std::cmp::min(start + BATCH_SIZE as u64, range_output.tx_range.end))
             .collect::<Vec<Range<u64>>>();
 
-        let tx_batch_sender = setup_range_recovery(provider);
+        let tx_batch_sender = setup_range_recovery(provider, self.pool()?);
 
         let start = Instant::now();
         let block_body_indices =
@@ -325,9 +359,10 @@ where
 
 /// Spawns a thread to handle the recovery of transaction senders for
 /// specified chunks of a given batch. It processes incoming ranges, fetching and recovering
-/// transactions in parallel using global rayon pool
+/// transactions in parallel using the given rayon pool, or the global rayon pool if not set.
 fn setup_range_recovery<Provider>(
     provider: &Provider,
+    pool: Option<Arc<rayon::ThreadPool>>,
 ) -> mpsc::Sender<Vec<(Range<u64>, RecoveryResultSender)>>
 where
     Provider: DBProvider
@@ -369,10 +404,10 @@ where
                     }
                 };
 
-                // Spawn the task onto the global rayon pool
+                // Spawn the task onto the rayon pool
                 // This task will send the results through the channel after it has read the
                 // transaction and calculated the sender.
-                rayon::spawn(move || {
+                let task = move || {
                     let mut rlp_buf = Vec::with_capacity(128);
                     for (number, tx) in chunk {
                         let res = tx
@@ -391,7 +426,11 @@ where
                             break
                         }
                     }
-                });
+                };
+                match &pool {
+                    Some(pool) => pool.spawn(task),
+                    None => rayon::spawn(task),
+                }
             }
         }
     });
@@ -580,10 +619,22 @@ mod tests {
     /// Execute the stage twice with input range that exceeds the commit threshold
     #[tokio::test]
     async fn execute_intermediate_commit() {
+        execute_intermediate_commit_with_runner(SenderRecoveryTestRunner::default()).await;
+    }
+
+    /// Execute the stage twice with input range that exceeds the commit threshold, recovering
+    /// senders on a dedicated thread pool
+    #[tokio::test]
+    async fn execute_intermediate_commit_with_workers() {
+        let mut runner = SenderRecoveryTestRunner::default();
+        runner.set_workers(2);
+        execute_intermediate_commit_with_runner(runner).await;
+    }
+
+    async fn execute_intermediate_commit_with_runner(mut runner: SenderRecoveryTestRunner) {
         let mut rng = generators::rng();
 
         let threshold = 10;
-        let mut runner = SenderRecoveryTestRunner::default();
         runner.set_threshold(threshold);
         let (stage_progress, previous_stage) = (1000, 1100); // input exceeds threshold
 
@@ -719,11 +770,12 @@ mod tests {
     struct SenderRecoveryTestRunner {
         db: TestStageDB,
         threshold: u64,
+        workers: Option<usize>,
     }
 
     impl Default for SenderRecoveryTestRunner {
         fn default() -> Self {
-            Self { threshold: 1000, db: TestStageDB::default() }
+            Self { threshold: 1000, workers: None, db: TestStageDB::default() }
         }
     }
 
@@ -732,6 +784,10 @@ mod tests {
             self.threshold = threshold;
         }
 
+        fn set_workers(&mut self, workers: usize) {
+            self.workers = Some(workers);
+        }
+
         /// # Panics
         ///
         /// 1. If there are any entries in the [`tables::TransactionSenders`] table above a given
@@ -767,7 +823,10 @@ mod tests {
         }
 
         fn stage(&self) -> Self::S {
-            SenderRecoveryStage { commit_threshold: self.threshold, prune_mode: None }
+            SenderRecoveryStage::new(
+                SenderRecoveryConfig { commit_threshold: self.threshold, workers: self.workers },
+                None,
+            )
         }
     }
 
@@ -36,13 +36,15 @@ pub struct TransactionLookupStage {
     /// The maximum number of lookup entries to hold in memory before pushing them to
     /// [`reth_etl::Collector`].
     chunk_size: u64,
+    /// The number of transactions hashed by a single worker at a time.
+    hashing_chunk_size: usize,
     etl_config: EtlConfig,
     prune_mode: Option<PruneMode>,
 }
 
 impl Default for TransactionLookupStage {
     fn default() -> Self {
-        Self { chunk_size: 5_000_000, etl_config: EtlConfig::default(), prune_mode: None }
+        Self::new(TransactionLookupConfig::default(), EtlConfig::default(), None)
     }
 }
 
@@ -53,7 +55,12 @@ impl TransactionLookupStage {
         etl_config: EtlConfig,
         prune_mode: Option<PruneMode>,
     ) -> Self {
-        Self { chunk_size: config.chunk_size, etl_config, prune_mode }
+        Self {
+            chunk_size: config.chunk_size,
+            hashing_chunk_size: config.hashing_chunk_size,
+            etl_config,
+            prune_mode,
+        }
     }
 }
 
@@ -74,6 +81,10 @@ where
         StageId::TransactionLookup
     }
 
+    fn supports_chunked_unwind(&self) -> bool {
+        true
+    }
+
     /// Write transaction hash -> id entries
     fn execute(
         &mut self,
@@ -142,7 +153,12 @@ where
 
             info!(target: "sync::stages::transaction_lookup", tx_range = ?range_output.tx_range, "Calculating transaction hashes");
 
-            for (key, value) in provider.transaction_hashes_by_range(range_output.tx_range)? {
+            // Hashes are computed in parallel and returned ordered by transaction number, so they
+            // are inserted in the same order they are read from the static files.
+            for (key, value) in provider.transaction_hashes_by_range_with_chunk_size(
+                range_output.tx_range,
+                self.hashing_chunk_size,
+            )? {
                 hash_collector.insert(key, value)?;
             }
 
@@ -455,6 +471,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn transaction_hashes_ordered() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            0..=100,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 0..10, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Static).expect("insert blocks");
+
+        let expected = blocks
+            .iter()
+            .flat_map(|block| block.body().transactions.iter().map(|tx| *tx.tx_hash()))
+            .zip(0..)
+            .collect::<Vec<_>>();
+
+        let provider = db.factory.provider().unwrap();
+        for chunk_size in [1, 3, 100, expected.len()] {
+            assert_eq!(
+                provider
+                    .transaction_hashes_by_range_with_chunk_size(
+                        0..expected.len() as u64,
+                        chunk_size
+                    )
+                    .unwrap(),
+                expected
+            );
+        }
+    }
+
     struct TransactionLookupTestRunner {
         db: TestStageDB,
         chunk_size: u64,
@@ -517,6 +565,7 @@ mod tests {
         fn stage(&self) -> Self::S {
             TransactionLookupStage {
                 chunk_size: self.chunk_size,
+                hashing_chunk_size: 4,
                 etl_config: self.etl_config.clone(),
                 prune_mode: self.prune_mode,
             }
@@ -8,6 +8,7 @@ use reth_db_api::{
         AccountBeforeTx, AddressStorageKey, BlockNumberAddress, ShardedKey,
     },
     table::{Decode, Decompress, Table},
+    tables,
     transaction::DbTx,
     BlockNumberList,
 };
@@ -20,7 +21,11 @@ use reth_provider::{
 use reth_stages_api::StageError;
 use reth_static_file_types::StaticFileSegment;
 use reth_storage_api::{ChangeSetReader, StorageChangeSetReader};
-use std::{collections::HashMap, hash::Hash, ops::RangeBounds};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    ops::{RangeBounds, RangeInclusive},
+};
 use tracing::info;
 
 /// Number of blocks before pushing indices from cache to [`Collector`]
@@ -225,6 +230,50 @@ where
     Ok(collector)
 }
 
+/// Collects storage history indices for a range of changesets in
+/// [`PackedStorageChangeSets`](tables::PackedStorageChangeSets) into the [`Collector`].
+///
+/// Complements [`collect_history_indices`] over [`StorageChangeSets`](tables::StorageChangeSets),
+/// whose changesets of old blocks may have been packed.
+pub(crate) fn collect_packed_storage_history_indices<Provider: DBProvider>(
+    provider: &Provider,
+    range: RangeInclusive<BlockNumber>,
+    collector: &mut Collector<StorageShardedKey, BlockNumberList>,
+) -> Result<(), StageError> {
+    let mut cache: HashMap<AddressStorageKey, Vec<u64>> = HashMap::default();
+
+    let mut insert_fn = |key: AddressStorageKey, indices: Vec<u64>| {
+        let last = indices.last().expect("qed");
+        collector.insert(
+            StorageShardedKey::new(key.0 .0, key.0 .1, *last),
+            BlockNumberList::new_pre_sorted(indices),
+        )?;
+        Ok::<(), StageError>(())
+    };
+
+    let mut cursor = provider.tx_ref().cursor_read::<tables::PackedStorageChangeSets>()?;
+
+    let mut flush_counter = 0;
+    let mut current_block_number = u64::MAX;
+    for entry in cursor.walk_range(BlockNumberAddress::range(range))? {
+        let (BlockNumberAddress((block_number, address)), changeset) = entry?;
+        for storage in changeset.entries() {
+            cache.entry(AddressStorageKey((address, storage.key))).or_default().push(block_number);
+        }
+
+        if current_block_number != block_number {
+            current_block_number = block_number;
+            flush_counter += 1;
+            if flush_counter > DEFAULT_CACHE_THRESHOLD {
+                collect_indices(cache.drain(), &mut insert_fn)?;
+                flush_counter = 0;
+            }
+        }
+    }
+
+    collect_indices(cache.into_iter(), insert_fn)
+}
+
 /// Loads account history indices into the database via `EitherWriter`.
 ///
 /// Works with [`EitherWriter`] to support both MDBX and `RocksDB` backends.
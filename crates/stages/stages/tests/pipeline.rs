@@ -10,8 +10,9 @@ use reth_consensus::noop::NoopConsensus;
 use reth_db_api::{cursor::DbCursorRO, models::BlockNumberAddress, transaction::DbTx};
 use reth_db_common::init::init_genesis;
 use reth_downloaders::{
-    bodies::bodies::BodiesDownloaderBuilder, file_client::FileClient,
-    headers::reverse_headers::ReverseHeadersDownloaderBuilder,
+    bodies::{bodies::BodiesDownloaderBuilder, noop::NoopBodiesDownloader},
+    file_client::FileClient,
+    headers::{noop::NoopHeaderDownloader, reverse_headers::ReverseHeadersDownloaderBuilder},
 };
 use reth_ethereum_primitives::{Block, BlockBody, Transaction};
 use reth_evm::{execute::Executor, ConfigureEvm};
@@ -33,7 +34,7 @@ use reth_provider::{
 use reth_prune_types::PruneModes;
 use reth_revm::database::StateProviderDatabase;
 use reth_stages::sets::DefaultStages;
-use reth_stages_api::{Pipeline, StageId};
+use reth_stages_api::{test_utils::TestStage, Pipeline, Stage, StageId, StageSet};
 use reth_static_file::StaticFileProducer;
 use reth_storage_api::{
     ChangeSetReader, StateProvider, StorageChangeSetReader, StorageSettings, StorageSettingsCache,
@@ -611,3 +612,35 @@ async fn test_pipeline() -> eyre::Result<()> {
 async fn test_pipeline_v2() -> eyre::Result<()> {
     run_pipeline_forward_and_unwind(Some(StorageSettings::v2()), 5, 2).await
 }
+
+/// Tests that a boxed custom stage can be inserted into the default stages after a given stage.
+#[test]
+fn test_add_custom_stage_to_default_stages() {
+    let provider_factory = create_test_provider_factory_with_chain_spec(MAINNET.clone());
+    let evm_config = EthEvmConfig::new(provider_factory.chain_spec());
+    let (_tip_tx, tip_rx) = watch::channel(B256::ZERO);
+
+    type ProviderRW = <reth_provider::ProviderFactory<
+        reth_provider::test_utils::MockNodeTypesWithDB,
+    > as DatabaseProviderFactory>::ProviderRW;
+    let custom: Box<dyn Stage<ProviderRW>> = Box::new(TestStage::new(StageId::Other("Custom")));
+
+    let stages = DefaultStages::new(
+        provider_factory,
+        tip_rx,
+        NoopConsensus::arc(),
+        NoopHeaderDownloader::<Header>::default(),
+        NoopBodiesDownloader::<Block>::default(),
+        evm_config,
+        StageConfig::default(),
+        PruneModes::default(),
+        None,
+    )
+    .builder()
+    .add_after_boxed(custom, StageId::Execution);
+
+    let order = stages.stages().collect::<Vec<_>>();
+    let execution = order.iter().position(|id| *id == StageId::Execution).unwrap();
+    assert_eq!(order[execution + 1], StageId::Other("Custom"));
+    assert_eq!(order.iter().filter(|id| **id == StageId::Other("Custom")).count(), 1);
+}
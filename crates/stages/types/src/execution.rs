@@ -2,8 +2,8 @@ use core::time::Duration;
 
 /// The thresholds at which the execution stage writes state changes to the database.
 ///
-/// If any of the thresholds (`max_blocks`, `max_changes`, `max_cumulative_gas`, or `max_duration`)
-/// are hit, then the execution stage commits all pending changes to the database.
+/// If any of the thresholds (`max_blocks`, `max_changes`, `max_cumulative_gas`, `max_duration`, or
+/// `max_memory`) are hit, then the execution stage commits all pending changes to the database.
 #[derive(Debug, Clone)]
 pub struct ExecutionStageThresholds {
     /// The maximum number of blocks to execute before the execution stage commits.
@@ -14,6 +14,17 @@ pub struct ExecutionStageThresholds {
     pub max_cumulative_gas: Option<u64>,
     /// The maximum spent on blocks processing before the execution stage commits.
     pub max_duration: Option<Duration>,
+    /// The maximum estimated size in bytes of the state held in memory before the execution stage
+    /// commits.
+    ///
+    /// The size is estimated from the state changes of the executed blocks.
+    pub max_memory: Option<u64>,
+    /// The target duration of a batch, including writing its state changes.
+    ///
+    /// If set, the maximum number of blocks per batch is adapted to the gas throughput measured
+    /// in previous batches, so that batches take about this long regardless of the size of the
+    /// blocks. The other thresholds still apply.
+    pub target_batch_duration: Option<Duration>,
 }
 
 impl Default for ExecutionStageThresholds {
@@ -25,6 +36,8 @@ impl Default for ExecutionStageThresholds {
             max_cumulative_gas: Some(30_000_000 * 50_000),
             // 10 minutes
             max_duration: Some(Duration::from_secs(10 * 60)),
+            max_memory: None,
+            target_batch_duration: None,
         }
     }
 }
@@ -38,10 +51,31 @@ impl ExecutionStageThresholds {
         changes_processed: u64,
         cumulative_gas_used: u64,
         elapsed: Duration,
+        memory_used: u64,
     ) -> bool {
         blocks_processed >= self.max_blocks.unwrap_or(u64::MAX) ||
             changes_processed >= self.max_changes.unwrap_or(u64::MAX) ||
             cumulative_gas_used >= self.max_cumulative_gas.unwrap_or(u64::MAX) ||
-            elapsed >= self.max_duration.unwrap_or(Duration::MAX)
+            elapsed >= self.max_duration.unwrap_or(Duration::MAX) ||
+            memory_used >= self.max_memory.unwrap_or(u64::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_threshold() {
+        let thresholds = ExecutionStageThresholds {
+            max_blocks: None,
+            max_changes: None,
+            max_cumulative_gas: None,
+            max_duration: None,
+            max_memory: Some(1024),
+            target_batch_duration: None,
+        };
+        assert!(!thresholds.is_end_of_batch(1, 1, 1, Duration::ZERO, 1023));
+        assert!(thresholds.is_end_of_batch(1, 1, 1, Duration::ZERO, 1024));
     }
 }
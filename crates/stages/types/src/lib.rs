@@ -25,6 +25,12 @@ pub use checkpoints::{
 mod execution;
 pub use execution::*;
 
+mod retry;
+pub use retry::*;
+
+mod unwind;
+pub use unwind::*;
+
 /// Direction and target block for pipeline operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PipelineTarget {
@@ -0,0 +1,62 @@
+use core::time::Duration;
+
+/// The policy for retrying a stage that failed with a transient error, e.g. because the database
+/// was busy or a network request timed out.
+///
+/// Retries back off exponentially, starting at `initial_backoff` and doubling after every failed
+/// attempt, up to `max_backoff`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StageRetryPolicy {
+    /// The maximum number of consecutive retries before the error is returned.
+    pub max_attempts: u32,
+    /// The time to wait before the first retry.
+    pub initial_backoff: Duration,
+    /// The maximum time to wait between retries.
+    pub max_backoff: Duration,
+}
+
+impl Default for StageRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl StageRetryPolicy {
+    /// Returns a policy that never retries.
+    pub const fn disabled() -> Self {
+        Self { max_attempts: 0, initial_backoff: Duration::ZERO, max_backoff: Duration::ZERO }
+    }
+
+    /// Returns the time to wait before retrying after `attempts` consecutive failed retries, or
+    /// `None` if no more retries are allowed.
+    pub fn backoff(&self, attempts: u32) -> Option<Duration> {
+        (attempts < self.max_attempts).then(|| {
+            self.initial_backoff.saturating_mul(2u32.saturating_pow(attempts)).min(self.max_backoff)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff() {
+        let policy = StageRetryPolicy {
+            max_attempts: 4,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+        };
+        assert_eq!(policy.backoff(0), Some(Duration::from_secs(1)));
+        assert_eq!(policy.backoff(1), Some(Duration::from_secs(2)));
+        assert_eq!(policy.backoff(2), Some(Duration::from_secs(4)));
+        assert_eq!(policy.backoff(3), Some(Duration::from_secs(5)));
+        assert_eq!(policy.backoff(4), None);
+
+        assert_eq!(StageRetryPolicy::disabled().backoff(0), None);
+    }
+}
@@ -0,0 +1,43 @@
+use alloy_primitives::BlockNumber;
+
+/// The thresholds at which stages commit their progress while unwinding.
+///
+/// Unwinds of stages that support it are split into chunks of at most `max_blocks` blocks, each
+/// of which is committed to the database before the next one is unwound.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnwindStageThresholds {
+    /// The maximum number of blocks to unwind before the stage commits.
+    pub max_blocks: Option<u64>,
+}
+
+impl Default for UnwindStageThresholds {
+    fn default() -> Self {
+        Self { max_blocks: Some(10_000) }
+    }
+}
+
+impl UnwindStageThresholds {
+    /// Returns the block to unwind to in the next chunk, when unwinding from `checkpoint` to
+    /// `target`.
+    #[inline]
+    pub fn chunk_target(&self, checkpoint: BlockNumber, target: BlockNumber) -> BlockNumber {
+        self.max_blocks
+            .map_or(target, |max_blocks| target.max(checkpoint.saturating_sub(max_blocks.max(1))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunk_target() {
+        let thresholds = UnwindStageThresholds { max_blocks: Some(100) };
+        assert_eq!(thresholds.chunk_target(1_000, 0), 900);
+        assert_eq!(thresholds.chunk_target(1_000, 950), 950);
+        assert_eq!(thresholds.chunk_target(50, 0), 0);
+
+        let thresholds = UnwindStageThresholds { max_blocks: None };
+        assert_eq!(thresholds.chunk_target(1_000, 0), 0);
+    }
+}
@@ -13,14 +13,19 @@ use reth_provider::{
 };
 use reth_prune_types::PruneModes;
 use reth_stages_types::StageId;
-use reth_static_file_types::{HighestStaticFiles, StaticFileTargets};
+use reth_static_file_types::{HighestStaticFiles, StaticFileSegment, StaticFileTargets};
 use reth_storage_errors::provider::ProviderResult;
 use reth_tokio_util::{EventSender, EventStream};
 use std::{
     ops::{Deref, RangeInclusive},
     sync::Arc,
+    time::Duration,
 };
-use tracing::{debug, trace};
+use tracing::{debug, info, trace};
+
+/// Number of blocks copied to static files between two commits by
+/// [`StaticFileProducerInner::copy_to_static_files_with_interrupt`].
+const COPY_CHUNK_SIZE: u64 = 100_000;
 
 /// Result of [`StaticFileProducerInner::run`] execution.
 pub type StaticFileProducerResult = ProviderResult<StaticFileTargets>;
@@ -72,6 +77,17 @@ impl<Provider> StaticFileProducerInner<Provider> {
     fn new(provider: Provider, prune_modes: PruneModes) -> Self {
         Self { provider, prune_modes, event_sender: Default::default() }
     }
+
+    /// Returns the pruning configuration used to decide which data is moved to static files.
+    pub const fn prune_modes(&self) -> &PruneModes {
+        &self.prune_modes
+    }
+
+    /// Sets the pruning configuration used to decide which data is moved to static files, and
+    /// returns the previous one.
+    pub fn set_prune_modes(&mut self, prune_modes: PruneModes) -> PruneModes {
+        core::mem::replace(&mut self.prune_modes, prune_modes)
+    }
 }
 
 impl<Provider> StaticFileProducerInner<Provider>
@@ -171,13 +187,64 @@ where
     ///
     /// Returns highest block numbers for all static file segments.
     pub fn copy_to_static_files(&self) -> ProviderResult<HighestStaticFiles> {
+        self.copy_to_static_files_with_interrupt(|| false)
+    }
+
+    /// Same as [`Self::copy_to_static_files`], but copies the data in chunks of
+    /// [`COPY_CHUNK_SIZE`] blocks, committing the static files and logging progress after each one.
+    ///
+    /// `interrupted` is checked before every chunk. Once it returns `true`, no more chunks are
+    /// copied and the returned block numbers are the ones that were actually copied, so that a
+    /// subsequent call resumes from there.
+    pub fn copy_to_static_files_with_interrupt(
+        &self,
+        interrupted: impl Fn() -> bool,
+    ) -> ProviderResult<HighestStaticFiles> {
         let provider = self.provider.database_provider_ro()?;
         let execution_checkpoint =
             provider.get_stage_checkpoint(StageId::Execution)?.map(|c| c.block_number);
 
         let highest_static_files = HighestStaticFiles { receipts: execution_checkpoint };
         let targets = self.get_static_file_targets(highest_static_files)?;
-        self.run(targets)?;
+        let Some(block_range) = targets.receipts else { return Ok(highest_static_files) };
+
+        let total_blocks = block_range.end() - block_range.start() + 1;
+        let start = Instant::now();
+
+        let mut chunk_start = *block_range.start();
+        while chunk_start <= *block_range.end() {
+            if interrupted() {
+                let receipts = chunk_start.checked_sub(1);
+                info!(
+                    target: "static_file",
+                    segment = %StaticFileSegment::Receipts,
+                    highest_static_file_block = ?receipts,
+                    "Interrupted copying data to static files"
+                );
+                return Ok(HighestStaticFiles { receipts })
+            }
+
+            let chunk_end = chunk_start.saturating_add(COPY_CHUNK_SIZE - 1).min(*block_range.end());
+            self.run(StaticFileTargets { receipts: Some(chunk_start..=chunk_end) })?;
+
+            if total_blocks > COPY_CHUNK_SIZE {
+                let copied_blocks = chunk_end - block_range.start() + 1;
+                let elapsed = start.elapsed();
+                let eta =
+                    elapsed.mul_f64((total_blocks - copied_blocks) as f64 / copied_blocks as f64);
+                info!(
+                    target: "static_file",
+                    segment = %StaticFileSegment::Receipts,
+                    copied_blocks,
+                    total_blocks,
+                    progress = %format!("{:.2}%", copied_blocks as f64 / total_blocks as f64 * 100.0),
+                    eta = ?Duration::from_secs(eta.as_secs()),
+                    "Copying data to static files"
+                );
+            }
+
+            chunk_start = chunk_end + 1;
+        }
 
         Ok(highest_static_files)
     }
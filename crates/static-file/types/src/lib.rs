@@ -34,6 +34,9 @@ pub type StaticFileMap<T> = alloc::boxed::Box<fixed_map::Map<StaticFileSegment,
 /// Default static file block count.
 pub const DEFAULT_BLOCKS_PER_STATIC_FILE: u64 = 500_000;
 
+/// Default static file block count of the [`StaticFileSegment::BlobSidecars`] segment.
+pub const DEFAULT_BLOB_SIDECARS_BLOCKS_PER_STATIC_FILE: u64 = 8_192;
+
 /// Highest static file block numbers, per data segment.
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
 pub struct HighestStaticFiles {
@@ -1,4 +1,7 @@
-use crate::{find_fixed_range, BlockNumber, Compression};
+use crate::{
+    find_fixed_range, BlockNumber, Compression, DEFAULT_BLOB_SIDECARS_BLOCKS_PER_STATIC_FILE,
+    DEFAULT_BLOCKS_PER_STATIC_FILE,
+};
 use alloc::{format, string::String};
 use alloy_primitives::TxNumber;
 use core::{
@@ -61,6 +64,18 @@ pub enum StaticFileSegment {
     /// Storage changeset static files append block-by-block changesets sorted by address and
     /// storage slot.
     StorageChangeSets,
+    /// Static File segment responsible for the EIP-4844 blob sidecars of canonical blocks.
+    ///
+    /// Every row holds the sidecars of all blob transactions of a block, which is empty for blocks
+    /// without blob transactions or whose sidecars were not available. Unlike the other segments,
+    /// it's not written by any stage and only keeps the blocks within a retention window.
+    BlobSidecars,
+    /// Static File segment responsible for the block access lists of executed blocks.
+    ///
+    /// Every row holds the RLP encoded block access list of a block, which is empty for blocks
+    /// without a block access list. It's written by the optional block access list stage and
+    /// starts at the first block the stage executed.
+    BlockAccessLists,
 }
 
 impl StaticFileSegment {
@@ -78,6 +93,8 @@ impl StaticFileSegment {
             Self::TransactionSenders => "transaction-senders",
             Self::AccountChangeSets => "account-change-sets",
             Self::StorageChangeSets => "storage-change-sets",
+            Self::BlobSidecars => "blob-sidecars",
+            Self::BlockAccessLists => "block-access-lists",
         }
     }
 
@@ -92,6 +109,8 @@ impl StaticFileSegment {
             Self::TransactionSenders => "tx-senders",
             Self::AccountChangeSets => "account-changes",
             Self::StorageChangeSets => "storage-changes",
+            Self::BlobSidecars => "blob-sidecars",
+            Self::BlockAccessLists => "access-lists",
         }
     }
 
@@ -105,13 +124,28 @@ impl StaticFileSegment {
             Self::TransactionSenders,
             Self::AccountChangeSets,
             Self::StorageChangeSets,
+            Self::BlobSidecars,
+            Self::BlockAccessLists,
         ]
         .into_iter()
     }
 
     /// Returns the default configuration of the segment.
+    ///
+    /// Only headers are compressed by default, since transactions and receipts already compress
+    /// their values as part of their encoding, and blobs are mostly incompressible.
     pub const fn config(&self) -> SegmentConfig {
-        SegmentConfig { compression: Compression::Lz4 }
+        let compression = match self {
+            Self::Headers => Compression::Lz4,
+            Self::Transactions |
+            Self::Receipts |
+            Self::TransactionSenders |
+            Self::AccountChangeSets |
+            Self::StorageChangeSets |
+            Self::BlobSidecars |
+            Self::BlockAccessLists => Compression::Uncompressed,
+        };
+        SegmentConfig { compression }
     }
 
     /// Returns the number of columns for the segment
@@ -122,7 +156,27 @@ impl StaticFileSegment {
             Self::Receipts |
             Self::TransactionSenders |
             Self::AccountChangeSets |
-            Self::StorageChangeSets => 1,
+            Self::StorageChangeSets |
+            Self::BlobSidecars |
+            Self::BlockAccessLists => 1,
+        }
+    }
+
+    /// Returns the default number of blocks per static file of the segment.
+    ///
+    /// Blob sidecars take up to several megabytes per block, so their static files span fewer
+    /// blocks, which also lets them be deleted in smaller steps once they leave the retention
+    /// window.
+    pub const fn default_blocks_per_file(&self) -> u64 {
+        match self {
+            Self::BlobSidecars => DEFAULT_BLOB_SIDECARS_BLOCKS_PER_STATIC_FILE,
+            Self::Headers |
+            Self::Transactions |
+            Self::Receipts |
+            Self::TransactionSenders |
+            Self::AccountChangeSets |
+            Self::StorageChangeSets |
+            Self::BlockAccessLists => DEFAULT_BLOCKS_PER_STATIC_FILE,
         }
     }
 
@@ -184,7 +238,11 @@ impl StaticFileSegment {
     pub const fn is_tx_based(&self) -> bool {
         match self {
             Self::Receipts | Self::Transactions | Self::TransactionSenders => true,
-            Self::Headers | Self::AccountChangeSets | Self::StorageChangeSets => false,
+            Self::Headers |
+            Self::AccountChangeSets |
+            Self::StorageChangeSets |
+            Self::BlobSidecars |
+            Self::BlockAccessLists => false,
         }
     }
 
@@ -192,14 +250,19 @@ impl StaticFileSegment {
     pub const fn is_change_based(&self) -> bool {
         match self {
             Self::AccountChangeSets | Self::StorageChangeSets => true,
-            Self::Receipts | Self::Transactions | Self::Headers | Self::TransactionSenders => false,
+            Self::Receipts |
+            Self::Transactions |
+            Self::Headers |
+            Self::TransactionSenders |
+            Self::BlobSidecars |
+            Self::BlockAccessLists => false,
         }
     }
 
     /// Returns `true` if a segment row is linked to a block.
     pub const fn is_block_based(&self) -> bool {
         match self {
-            Self::Headers => true,
+            Self::Headers | Self::BlobSidecars | Self::BlockAccessLists => true,
             Self::Receipts |
             Self::Transactions |
             Self::TransactionSenders |
@@ -215,6 +278,8 @@ impl StaticFileSegment {
     }
 
     /// Maps this segment to the [`StageId`] responsible for it.
+    ///
+    /// Blob sidecars are not written by any stage, so they map to [`StageId::Finish`].
     pub const fn to_stage_id(&self) -> StageId {
         match self {
             Self::Headers => StageId::Headers,
@@ -223,6 +288,8 @@ impl StaticFileSegment {
                 StageId::Execution
             }
             Self::TransactionSenders => StageId::SenderRecovery,
+            Self::BlobSidecars => StageId::Finish,
+            Self::BlockAccessLists => StageId::BlockAccessList,
         }
     }
 }
@@ -804,6 +871,20 @@ mod tests {
                 segment: StaticFileSegment::StorageChangeSets,
                 changeset_offsets_len: 100,
             },
+            SegmentHeader {
+                expected_block_range: SegmentRangeInclusive::new(0, 200),
+                block_range: Some(SegmentRangeInclusive::new(0, 100)),
+                tx_range: None,
+                segment: StaticFileSegment::BlobSidecars,
+                changeset_offsets_len: 0,
+            },
+            SegmentHeader {
+                expected_block_range: SegmentRangeInclusive::new(0, 200),
+                block_range: Some(SegmentRangeInclusive::new(0, 100)),
+                tx_range: None,
+                segment: StaticFileSegment::BlockAccessLists,
+                changeset_offsets_len: 0,
+            },
         ];
         // Check that we test all segments
         assert_eq!(
@@ -838,6 +919,8 @@ mod tests {
                 StaticFileSegment::TransactionSenders => "transaction-senders",
                 StaticFileSegment::AccountChangeSets => "account-change-sets",
                 StaticFileSegment::StorageChangeSets => "storage-change-sets",
+                StaticFileSegment::BlobSidecars => "blob-sidecars",
+                StaticFileSegment::BlockAccessLists => "block-access-lists",
             };
             assert_eq!(static_str, expected_str);
         }
@@ -857,6 +940,8 @@ mod tests {
                 StaticFileSegment::TransactionSenders => "TransactionSenders",
                 StaticFileSegment::AccountChangeSets => "AccountChangeSets",
                 StaticFileSegment::StorageChangeSets => "StorageChangeSets",
+                StaticFileSegment::BlobSidecars => "BlobSidecars",
+                StaticFileSegment::BlockAccessLists => "BlockAccessLists",
             };
             assert_eq!(ser, format!("\"{expected_str}\""));
         }
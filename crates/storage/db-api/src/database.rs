@@ -7,7 +7,8 @@ use std::{fmt::Debug, path::PathBuf, sync::Arc};
 
 /// Main Database trait that can open read-only and read-write transactions.
 ///
-/// Sealed trait which cannot be implemented by 3rd parties, exposed only for consumption.
+/// This is the storage backend abstraction used by the providers: any implementation, e.g. the
+/// MDBX or the in-memory database of `reth-db`, can back a node without changes to the providers.
 pub trait Database: Send + Sync + Debug {
     /// Read-Only database transaction
     type TX: DbTx + Send + Sync + Debug + 'static;
@@ -0,0 +1,129 @@
+//! Implements [`Compress`] and [`Decompress`] for [`StoredBlobSidecars`]
+
+use crate::table::{Compress, Decompress};
+use alloy_eips::eip7594::BlobTransactionSidecarVariant;
+use alloy_primitives::{TxHash, B256};
+use reth_codecs::DecompressError;
+
+/// The blob sidecars of all blob transactions of a block, in transaction order.
+///
+/// Each entry is encoded as `transaction hash | sidecar length (4 bytes) | sidecar`, with the
+/// sidecar encoded as the RLP fields of [`BlobTransactionSidecarVariant`], same as in the blob
+/// store of the transaction pool.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StoredBlobSidecars(Vec<(TxHash, BlobTransactionSidecarVariant)>);
+
+impl StoredBlobSidecars {
+    /// Creates a new [`StoredBlobSidecars`] from the sidecars of the blob transactions of a block.
+    pub const fn new(sidecars: Vec<(TxHash, BlobTransactionSidecarVariant)>) -> Self {
+        Self(sidecars)
+    }
+
+    /// Returns the sidecars with the hashes of their transactions.
+    pub fn sidecars(&self) -> &[(TxHash, BlobTransactionSidecarVariant)] {
+        &self.0
+    }
+
+    /// Consumes the sidecars and returns them with the hashes of their transactions.
+    pub fn into_sidecars(self) -> Vec<(TxHash, BlobTransactionSidecarVariant)> {
+        self.0
+    }
+
+    /// Returns `true` if the block has no sidecars.
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the versioned hashes of all blobs of the block.
+    pub fn versioned_hashes(&self) -> impl Iterator<Item = B256> + '_ {
+        self.0.iter().flat_map(|(_, sidecar)| sidecar.versioned_hashes())
+    }
+
+    /// Returns the sidecar containing the blob with the given versioned hash.
+    pub fn find(&self, versioned_hash: B256) -> Option<&BlobTransactionSidecarVariant> {
+        self.0
+            .iter()
+            .map(|(_, sidecar)| sidecar)
+            .find(|sidecar| sidecar.versioned_hashes().any(|hash| hash == versioned_hash))
+    }
+}
+
+impl Compress for StoredBlobSidecars {
+    type Compressed = Vec<u8>;
+
+    fn compress(self) -> Self::Compressed {
+        let mut buf = Vec::new();
+        self.compress_to_buf(&mut buf);
+        buf
+    }
+
+    fn compress_to_buf<B: bytes::BufMut + AsMut<[u8]>>(&self, buf: &mut B) {
+        let mut encoded = Vec::new();
+        for (tx_hash, sidecar) in &self.0 {
+            encoded.clear();
+            sidecar.rlp_encode_fields(&mut encoded);
+
+            buf.put_slice(tx_hash.as_slice());
+            buf.put_u32(encoded.len() as u32);
+            buf.put_slice(&encoded);
+        }
+    }
+}
+
+impl Decompress for StoredBlobSidecars {
+    fn decompress(mut value: &[u8]) -> Result<Self, DecompressError> {
+        let mut sidecars = Vec::new();
+        while !value.is_empty() {
+            let tx_hash = TxHash::from_slice(take(&mut value, 32)?);
+            let len = u32::from_be_bytes(take(&mut value, 4)?.try_into().expect("4 bytes"));
+            let mut encoded = take(&mut value, len as usize)?;
+
+            let sidecar = BlobTransactionSidecarVariant::rlp_decode_fields(&mut encoded)
+                .map_err(DecompressError::new)?;
+            if !encoded.is_empty() {
+                return Err(DecompressError::new(alloy_rlp::Error::UnexpectedLength))
+            }
+            sidecars.push((tx_hash, sidecar));
+        }
+        Ok(Self(sidecars))
+    }
+}
+
+fn take<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8], DecompressError> {
+    let (value, rest) = buf
+        .split_at_checked(len)
+        .ok_or_else(|| DecompressError::new(alloy_rlp::Error::InputTooShort))?;
+    *buf = rest;
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip4844::{BlobTransactionSidecar, Bytes48};
+
+    #[test]
+    fn roundtrip() {
+        let sidecar = |byte| {
+            BlobTransactionSidecarVariant::Eip4844(BlobTransactionSidecar {
+                blobs: vec![Default::default()],
+                commitments: vec![Bytes48::repeat_byte(byte)],
+                proofs: vec![Bytes48::repeat_byte(byte + 1)],
+            })
+        };
+        let sidecars = StoredBlobSidecars::new(vec![
+            (TxHash::repeat_byte(1), sidecar(1)),
+            (TxHash::repeat_byte(2), sidecar(3)),
+        ]);
+
+        let compressed = sidecars.clone().compress();
+        assert_eq!(StoredBlobSidecars::decompress(&compressed).unwrap(), sidecars);
+        assert_eq!(StoredBlobSidecars::decompress(&[]).unwrap(), StoredBlobSidecars::default());
+        assert!(StoredBlobSidecars::decompress(&compressed[..compressed.len() - 1]).is_err());
+
+        let versioned_hash = sidecars.sidecars()[1].1.versioned_hashes().next().unwrap();
+        assert_eq!(sidecars.versioned_hashes().count(), 2);
+        assert_eq!(sidecars.find(versioned_hash), Some(&sidecars.sidecars()[1].1));
+        assert_eq!(sidecars.find(B256::ZERO), None);
+    }
+}
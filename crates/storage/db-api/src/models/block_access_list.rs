@@ -0,0 +1,64 @@
+//! Implements [`Compress`] and [`Decompress`] for [`StoredBlockAccessList`]
+
+use crate::table::{Compress, Decompress};
+use alloy_primitives::Bytes;
+use reth_codecs::DecompressError;
+
+/// The RLP encoded block access list of a block, stored as is.
+///
+/// Empty for blocks without a block access list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StoredBlockAccessList(Bytes);
+
+impl StoredBlockAccessList {
+    /// Creates a new [`StoredBlockAccessList`] from the RLP encoded block access list.
+    pub const fn new(bal: Bytes) -> Self {
+        Self(bal)
+    }
+
+    /// Returns the RLP encoded block access list, `None` if the block has none.
+    pub fn bal(&self) -> Option<&Bytes> {
+        (!self.0.is_empty()).then_some(&self.0)
+    }
+
+    /// Consumes the value and returns the RLP encoded block access list, `None` if the block has
+    /// none.
+    pub fn into_bal(self) -> Option<Bytes> {
+        (!self.0.is_empty()).then_some(self.0)
+    }
+}
+
+impl Compress for StoredBlockAccessList {
+    type Compressed = Vec<u8>;
+
+    fn compress(self) -> Self::Compressed {
+        self.0.into()
+    }
+
+    fn compress_to_buf<B: bytes::BufMut + AsMut<[u8]>>(&self, buf: &mut B) {
+        buf.put_slice(&self.0);
+    }
+}
+
+impl Decompress for StoredBlockAccessList {
+    fn decompress(value: &[u8]) -> Result<Self, DecompressError> {
+        Ok(Self(Bytes::copy_from_slice(value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let bal = StoredBlockAccessList::new(Bytes::from_static(&[0xc1, 0xc0]));
+        let compressed = bal.clone().compress();
+        assert_eq!(StoredBlockAccessList::decompress(&compressed).unwrap(), bal);
+        assert_eq!(bal.bal(), Some(&Bytes::from_static(&[0xc1, 0xc0])));
+
+        let empty = StoredBlockAccessList::decompress(&[]).unwrap();
+        assert_eq!(empty, StoredBlockAccessList::default());
+        assert_eq!(empty.into_bal(), None);
+    }
+}
@@ -11,13 +11,18 @@ use reth_trie_common::{StoredNibbles, StoredNibblesSubKey, *};
 use serde::{Deserialize, Serialize};
 
 pub mod accounts;
+pub mod blob_sidecars;
+pub mod block_access_list;
 pub mod blocks;
 pub mod integer_list;
 pub mod metadata;
 pub mod sharded_key;
+pub mod storage_changeset;
 pub mod storage_sharded_key;
 
 pub use accounts::*;
+pub use blob_sidecars::StoredBlobSidecars;
+pub use block_access_list::StoredBlockAccessList;
 pub use blocks::*;
 pub use integer_list::IntegerList;
 pub use metadata::*;
@@ -26,6 +31,7 @@ pub use reth_db_models::{
     StoredBlockBodyIndices, StoredBlockWithdrawals,
 };
 pub use sharded_key::ShardedKey;
+pub use storage_changeset::PackedStorageChangeSet;
 
 /// Macro that implements [`Encode`] and [`Decode`] for uint types.
 macro_rules! impl_uints {
@@ -0,0 +1,175 @@
+//! Implements [`Compress`] and [`Decompress`] for [`PackedStorageChangeSet`]
+
+use crate::table::{Compress, Decompress};
+use alloy_primitives::{B256, U256};
+use reth_codecs::DecompressError;
+use reth_primitives_traits::StorageEntry;
+use serde::{Deserialize, Serialize};
+
+/// The storage changes of a single account in a single block, packed into one value.
+///
+/// The [`StorageChangeSets`](crate::tables::StorageChangeSets) table stores every changed slot as
+/// a separate duplicate of the block number and address key, each carrying the full 32-byte slot.
+/// This type stores all of them in one value instead, which only stores the shared block number
+/// and address once in the key, and compresses the sorted slots:
+/// - every slot only stores the bytes following the prefix it shares with the previous slot, or
+///   with the zero slot for the first one, which shrinks the small slots of the fixed storage
+///   layout of a contract to a single byte.
+/// - every value only stores its big-endian bytes following the leading zeros.
+///
+/// Each entry is encoded as `shared prefix length (1 byte) | slot suffix | value length (1 byte) |
+/// value`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackedStorageChangeSet(Vec<StorageEntry>);
+
+impl PackedStorageChangeSet {
+    /// Creates a new [`PackedStorageChangeSet`] from the entries, sorting them by slot.
+    pub fn new(mut entries: Vec<StorageEntry>) -> Self {
+        entries.sort_unstable_by_key(|entry| entry.key);
+        Self(entries)
+    }
+
+    /// Returns the entries sorted by slot.
+    pub fn entries(&self) -> &[StorageEntry] {
+        &self.0
+    }
+
+    /// Consumes the changeset and returns the entries sorted by slot.
+    pub fn into_entries(self) -> Vec<StorageEntry> {
+        self.0
+    }
+
+    /// Returns the entry of the slot, if it was changed.
+    pub fn get(&self, slot: B256) -> Option<&StorageEntry> {
+        self.0.binary_search_by_key(&slot, |entry| entry.key).ok().map(|index| &self.0[index])
+    }
+}
+
+impl Compress for PackedStorageChangeSet {
+    type Compressed = Vec<u8>;
+
+    fn compress(self) -> Self::Compressed {
+        let mut buf = Vec::new();
+        self.compress_to_buf(&mut buf);
+        buf
+    }
+
+    fn compress_to_buf<B: bytes::BufMut + AsMut<[u8]>>(&self, buf: &mut B) {
+        let mut previous = B256::ZERO;
+        for entry in &self.0 {
+            let shared = previous.iter().zip(entry.key.iter()).take_while(|(a, b)| a == b).count();
+            buf.put_u8(shared as u8);
+            buf.put_slice(&entry.key[shared..]);
+            previous = entry.key;
+
+            let value = entry.value.to_be_bytes::<32>();
+            let leading_zeros = value.iter().take_while(|byte| **byte == 0).count();
+            buf.put_u8((32 - leading_zeros) as u8);
+            buf.put_slice(&value[leading_zeros..]);
+        }
+    }
+}
+
+impl Decompress for PackedStorageChangeSet {
+    fn decompress(mut value: &[u8]) -> Result<Self, DecompressError> {
+        let mut entries = Vec::new();
+        let mut key = B256::ZERO;
+        while !value.is_empty() {
+            let shared = take_len(&mut value)?;
+            key[shared..].copy_from_slice(take(&mut value, 32 - shared)?);
+
+            let len = take_len(&mut value)?;
+            let mut word = [0; 32];
+            word[32 - len..].copy_from_slice(take(&mut value, len)?);
+
+            entries.push(StorageEntry { key, value: U256::from_be_bytes(word) });
+        }
+
+        if !entries.is_sorted_by(|a, b| a.key < b.key) {
+            return Err(DecompressError::new(PackedStorageChangeSetError::Unsorted))
+        }
+        Ok(Self(entries))
+    }
+}
+
+/// Reads a length prefix of at most 32 bytes.
+fn take_len(buf: &mut &[u8]) -> Result<usize, DecompressError> {
+    let len = take(buf, 1)?[0] as usize;
+    if len > 32 {
+        return Err(DecompressError::new(PackedStorageChangeSetError::InvalidLength))
+    }
+    Ok(len)
+}
+
+fn take<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8], DecompressError> {
+    let (value, rest) = buf
+        .split_at_checked(len)
+        .ok_or_else(|| DecompressError::new(PackedStorageChangeSetError::UnexpectedEnd))?;
+    *buf = rest;
+    Ok(value)
+}
+
+/// Errors decoding a [`PackedStorageChangeSet`].
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+pub enum PackedStorageChangeSetError {
+    /// A length prefix exceeds 32 bytes.
+    #[display("length prefix exceeds 32 bytes")]
+    InvalidLength,
+    /// The value ends in the middle of an entry.
+    #[display("unexpected end of value")]
+    UnexpectedEnd,
+    /// The slots are not strictly ascending.
+    #[display("slots are not strictly ascending")]
+    Unsorted,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_codecs::Compact;
+
+    fn slot(n: u64) -> B256 {
+        B256::from(U256::from(n))
+    }
+
+    #[test]
+    fn roundtrip() {
+        let changeset = PackedStorageChangeSet::new(vec![
+            StorageEntry { key: B256::repeat_byte(0xab), value: U256::MAX },
+            StorageEntry { key: slot(1), value: U256::from(1_000_000) },
+            StorageEntry { key: slot(0), value: U256::ZERO },
+            StorageEntry { key: slot(2), value: U256::from(1) },
+        ]);
+        assert_eq!(changeset.entries()[0].key, slot(0));
+        assert_eq!(changeset.get(slot(1)).unwrap().value, U256::from(1_000_000));
+        assert!(changeset.get(slot(3)).is_none());
+
+        let compressed = changeset.clone().compress();
+        assert_eq!(PackedStorageChangeSet::decompress(&compressed).unwrap(), changeset);
+        assert_eq!(PackedStorageChangeSet::decompress(&[]).unwrap(), Default::default());
+
+        // Truncated values and unsorted slots are rejected.
+        assert!(PackedStorageChangeSet::decompress(&compressed[..compressed.len() - 1]).is_err());
+        let unsorted = PackedStorageChangeSet(vec![changeset.0[1], changeset.0[0]]).compress();
+        assert!(PackedStorageChangeSet::decompress(&unsorted).is_err());
+    }
+
+    #[test]
+    fn smaller_than_dupsort_entries() {
+        // The fixed storage layout of a contract, e.g. an ERC-20 with a handful of variables.
+        let entries = (0..8)
+            .map(|n| StorageEntry { key: slot(n), value: U256::from(n * 1_000) })
+            .collect::<Vec<_>>();
+
+        let dupsort = entries
+            .iter()
+            .map(|entry| {
+                let mut buf = Vec::new();
+                entry.to_compact(&mut buf);
+                buf.len()
+            })
+            .sum::<usize>();
+        let packed = PackedStorageChangeSet::new(entries).compress().len();
+        assert!(packed * 4 < dupsort, "packed {packed} bytes, dupsort {dupsort} bytes");
+    }
+}
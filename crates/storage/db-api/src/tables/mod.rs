@@ -21,8 +21,8 @@ use crate::{
         accounts::BlockNumberAddress,
         blocks::{HeaderHash, StoredBlockOmmers},
         storage_sharded_key::StorageShardedKey,
-        AccountBeforeTx, ClientVersion, CompactU256, IntegerList, ShardedKey,
-        StoredBlockBodyIndices, StoredBlockWithdrawals,
+        AccountBeforeTx, ClientVersion, CompactU256, IntegerList, PackedStorageChangeSet,
+        ShardedKey, StoredBlockBodyIndices, StoredBlockWithdrawals,
     },
     table::{Decode, DupSort, Encode, Table, TableInfo},
 };
@@ -331,6 +331,16 @@ tables! {
         type Value = H;
     }
 
+    /// Stores headers downloaded by the headers stage that are not yet connected to the local
+    /// head.
+    ///
+    /// Headers are downloaded in reverse, so they are buffered here until the download reaches the
+    /// local head, which allows an interrupted download to be resumed.
+    table HeadersDownloadBuffer<H = Header> {
+        type Key = BlockNumber;
+        type Value = H;
+    }
+
     /// Stores block indices that contains indexes of transaction and the count of them.
     ///
     /// More information about stored indices can be found in the [`StoredBlockBodyIndices`] struct.
@@ -461,6 +471,17 @@ tables! {
         type SubKey = B256;
     }
 
+    /// Stores the state of the storage keys of an account before a certain block changed them,
+    /// packed into a single prefix-compressed value.
+    ///
+    /// Holds the same data as [`StorageChangeSets`] in less space. Changesets of old blocks are
+    /// moved here by `reth db pack-storage-changesets`, while new blocks keep being written to
+    /// [`StorageChangeSets`]. A block is only ever stored in one of the two tables.
+    table PackedStorageChangeSets {
+        type Key = BlockNumberAddress;
+        type Value = PackedStorageChangeSet;
+    }
+
     /// Stores the current state of an [`Account`] indexed with `keccak256Address`
     /// This table is in preparation for merklization and calculation of state root.
     /// We are saving whole account data as it is needed for partial update when
@@ -537,6 +558,43 @@ tables! {
         type Key = String;
         type Value = Vec<u8>;
     }
+
+    /// Stores the block number of the blob sidecars static file row holding the blob with the
+    /// versioned hash.
+    ///
+    /// Only holds the blobs within the retention window of the blob sidecars static files.
+    table BlobSidecarBlocks {
+        type Key = B256;
+        type Value = BlockNumber;
+    }
+
+    /// Stores pointers to the blocks whose `trace_filter` traces involve the address, either as
+    /// sender or recipient of a call, creation, selfdestruct or block reward.
+    ///
+    /// Sharded the same way as [`AccountsHistory`], and only covers the blocks in the range
+    /// recorded in the [`Metadata`] table.
+    table TraceAddressHistory {
+        type Key = ShardedKey<Address>;
+        type Value = BlockNumberList;
+    }
+
+    /// Stores pointers to the blocks with logs emitted by the address.
+    ///
+    /// Sharded the same way as [`AccountsHistory`], and only covers the blocks in the range
+    /// recorded in the [`Metadata`] table.
+    table LogAddressHistory {
+        type Key = ShardedKey<Address>;
+        type Value = BlockNumberList;
+    }
+
+    /// Stores pointers to the blocks with logs containing the topic, at any position.
+    ///
+    /// Sharded the same way as [`AccountsHistory`], and only covers the blocks in the range
+    /// recorded in the [`Metadata`] table.
+    table LogTopicHistory {
+        type Key = ShardedKey<B256>;
+        type Value = BlockNumberList;
+    }
 }
 
 /// Packed-encoding view of the [`AccountsTrie`] table.
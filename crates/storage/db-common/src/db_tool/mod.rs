@@ -7,14 +7,22 @@ use reth_db_api::{
     database::Database,
     table::{Decode, Decompress, DupSort, Table, TableRow},
     transaction::{DbTx, DbTxMut},
-    DatabaseError, RawTable, TableRawRow,
+    DatabaseError, RawKey, RawTable, RawValue, TableRawRow,
 };
 use reth_fs_util as fs;
 use reth_node_types::NodeTypesWithDB;
 use reth_provider::{providers::ProviderNodeTypes, ChainSpecProvider, DBProvider, ProviderFactory};
-use std::{path::Path, rc::Rc, sync::Arc};
+use std::{
+    io::{self, Read, Write},
+    path::Path,
+    rc::Rc,
+    sync::Arc,
+};
 use tracing::info;
 
+/// Number of rows [`DbTool::import_table`] inserts before committing them.
+pub const IMPORT_COMMIT_THRESHOLD: usize = 100_000;
+
 /// Wrapper over DB that implements many useful DB queries.
 #[derive(Debug)]
 pub struct DbTool<N: NodeTypesWithDB> {
@@ -87,21 +95,31 @@ impl<N: NodeTypesWithDB> DbTool<N> {
                 None
             };
 
-            if filter.reverse {
-                Ok(cursor
-                    .walk_back(None)?
-                    .skip(filter.skip)
-                    .filter_map(map_filter)
-                    .take(filter.len)
-                    .collect::<Vec<(_, _)>>())
-            } else {
-                Ok(cursor
-                    .walk(None)?
-                    .skip(filter.skip)
-                    .filter_map(map_filter)
-                    .take(filter.len)
-                    .collect::<Vec<(_, _)>>())
-            }
+            // Rows before the start key or outside of the key prefix in the walking direction are
+            // skipped, and walking stops at the first row past the key prefix.
+            let rows: Box<dyn Iterator<Item = Result<TableRawRow<T>, DatabaseError>> + '_> =
+                if filter.reverse {
+                    let start = match filter.reverse_seek_key() {
+                        Some(key) => cursor.seek(RawKey::from_vec(key))?.map(|(key, _)| key),
+                        None => None,
+                    };
+                    Box::new(cursor.walk_back(start)?.skip_while(|row| {
+                        row.as_ref().is_ok_and(|(key, _)| !filter.is_below_end(key.raw_key()))
+                    }))
+                } else {
+                    let start = filter.forward_seek_key().map(RawKey::from_vec);
+                    Box::new(cursor.walk(start)?)
+                };
+
+            Ok(rows
+                .take_while(|row| {
+                    row.as_ref()
+                        .map_or(true, |(key, _)| key.raw_key().starts_with(&filter.key_prefix))
+                })
+                .skip(filter.skip)
+                .filter_map(map_filter)
+                .take(filter.len)
+                .collect::<Vec<(_, _)>>())
         })?;
 
         Ok((data.map_err(|e: DatabaseError| eyre::eyre!(e))?, hits))
@@ -160,10 +178,101 @@ impl<N: ProviderNodeTypes> DbTool<N> {
         self.provider_factory.db_ref().update(|tx| tx.clear::<T>())??;
         Ok(())
     }
+
+    /// Writes every row of the provided table to `writer` and returns the number of rows written.
+    ///
+    /// Rows are written as they are stored in the database: a little-endian `u32` key length, the
+    /// key, a little-endian `u32` value length and the value. The export can be loaded back with
+    /// [`Self::import_table`].
+    pub fn export_table<T: Table>(&self, mut writer: impl Write) -> Result<usize> {
+        self.provider_factory.db_ref().view(|tx| -> Result<usize> {
+            let mut cursor = tx.cursor_read::<RawTable<T>>()?;
+
+            let mut rows = 0;
+            for row in cursor.walk(None)? {
+                let (key, value) = row?;
+                write_length_prefixed(&mut writer, key.raw_key())?;
+                write_length_prefixed(&mut writer, value.raw_value())?;
+                rows += 1;
+            }
+            writer.flush()?;
+
+            Ok(rows)
+        })?
+    }
+
+    /// Inserts every row of an export written by [`Self::export_table`] into the provided table
+    /// and returns the number of rows inserted.
+    ///
+    /// Rows are committed every [`IMPORT_COMMIT_THRESHOLD`] rows. If the export turns out to be
+    /// malformed, the rows committed before it stay in the table; importing the export again
+    /// overwrites them.
+    pub fn import_table<T: Table>(&self, reader: impl Read) -> Result<usize> {
+        self.import_table_with_threshold::<T>(reader, IMPORT_COMMIT_THRESHOLD)
+    }
+
+    fn import_table_with_threshold<T: Table>(
+        &self,
+        mut reader: impl Read,
+        commit_threshold: usize,
+    ) -> Result<usize> {
+        let mut tx = self.provider_factory.db_ref().tx_mut()?;
+
+        let mut rows = 0;
+        while let Some(key) = read_length_prefixed(&mut reader)? {
+            let value = read_length_prefixed(&mut reader)?
+                .ok_or_else(|| eyre::eyre!("Export is truncated after row {rows}"))?;
+            tx.put::<RawTable<T>>(RawKey::from_vec(key), RawValue::from_vec(value))?;
+            rows += 1;
+
+            if rows % commit_threshold == 0 {
+                tx.commit()?;
+                info!(target: "reth::cli", table = T::NAME, rows, "Imported rows");
+                tx = self.provider_factory.db_ref().tx_mut()?;
+            }
+        }
+        tx.commit()?;
+
+        Ok(rows)
+    }
+}
+
+/// Writes `data` prefixed with its length as a little-endian `u32`.
+fn write_length_prefixed(writer: &mut impl Write, data: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(data.len()).map_err(io::Error::other)?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(data)
+}
+
+/// Reads data written by [`write_length_prefixed`], or returns [`None`] at the end of the stream.
+///
+/// A stream ending within the length prefix is truncated and returns
+/// [`io::ErrorKind::UnexpectedEof`].
+fn read_length_prefixed(reader: &mut impl Read) -> io::Result<Option<Vec<u8>>> {
+    let mut len = [0u8; 4];
+    let mut read = 0;
+    while read < len.len() {
+        match reader.read(&mut len[read..]) {
+            Ok(0) if read == 0 => return Ok(None),
+            Ok(0) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "stream ends within a length prefix",
+                ))
+            }
+            Ok(n) => read += n,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+
+    let mut data = vec![0u8; u32::from_le_bytes(len) as usize];
+    reader.read_exact(&mut data)?;
+    Ok(Some(data))
 }
 
 /// Filters the results coming from the database.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct ListFilter {
     /// Skip first N entries.
     pub skip: usize,
@@ -179,6 +288,11 @@ pub struct ListFilter {
     pub min_value_size: usize,
     /// Reverse order of entries.
     pub reverse: bool,
+    /// Only list entries whose encoded key starts with these bytes.
+    pub key_prefix: Vec<u8>,
+    /// Encoded key to start listing from. In reverse order, entries are listed from the last
+    /// key that is less than or equal to it.
+    pub start_key: Option<Vec<u8>>,
     /// Only counts the number of filtered entries without decoding and returning them.
     pub only_count: bool,
 }
@@ -194,4 +308,171 @@ impl ListFilter {
         self.skip = skip;
         self.len = len;
     }
+
+    /// Returns the key to seek to before walking forward: the greater of the start key and the key
+    /// prefix.
+    fn forward_seek_key(&self) -> Option<Vec<u8>> {
+        let prefix = (!self.key_prefix.is_empty()).then(|| self.key_prefix.clone());
+        self.start_key.clone().max(prefix)
+    }
+
+    /// Returns a key to seek to before walking backwards. Walking starts at the first entry at or
+    /// after this key, so entries past the end of the listed range need to be skipped.
+    fn reverse_seek_key(&self) -> Option<Vec<u8>> {
+        let prefix_end = (!self.key_prefix.is_empty()).then(|| prefix_successor(&self.key_prefix));
+        match (self.start_key.clone(), prefix_end) {
+            (Some(start_key), Some(Some(prefix_end))) => Some(start_key.min(prefix_end)),
+            (Some(start_key), _) => Some(start_key),
+            (None, Some(prefix_end)) => prefix_end,
+            (None, None) => None,
+        }
+    }
+
+    /// Returns `true` if `key` is not past the start key or the key prefix when walking backwards.
+    fn is_below_end(&self, key: &[u8]) -> bool {
+        self.start_key.as_ref().is_none_or(|start_key| key <= start_key.as_slice()) &&
+            (key <= self.key_prefix.as_slice() || key.starts_with(&self.key_prefix))
+    }
+}
+
+/// Returns the smallest key that is greater than all keys starting with `prefix`, or [`None`] if
+/// there is no such key.
+fn prefix_successor(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut successor = prefix.to_vec();
+    while let Some(last) = successor.pop() {
+        if last < u8::MAX {
+            successor.push(last + 1);
+            return Some(successor)
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use reth_db_api::tables;
+    use reth_provider::test_utils::create_test_provider_factory;
+
+    #[test]
+    fn export_import_table_roundtrip() {
+        let tool = DbTool::new(create_test_provider_factory()).unwrap();
+
+        tool.provider_factory
+            .db_ref()
+            .update(|tx| {
+                for number in 0..10u64 {
+                    tx.put::<tables::CanonicalHeaders>(number, B256::with_last_byte(number as u8))
+                        .unwrap();
+                }
+            })
+            .unwrap();
+
+        let mut export = Vec::new();
+        assert_eq!(tool.export_table::<tables::CanonicalHeaders>(&mut export).unwrap(), 10);
+
+        tool.drop_table::<tables::CanonicalHeaders>().unwrap();
+        assert_eq!(tool.get::<tables::CanonicalHeaders>(0).unwrap(), None);
+
+        assert_eq!(tool.import_table::<tables::CanonicalHeaders>(&export[..]).unwrap(), 10);
+        for number in 0..10u64 {
+            assert_eq!(
+                tool.get::<tables::CanonicalHeaders>(number).unwrap(),
+                Some(B256::with_last_byte(number as u8))
+            );
+        }
+
+        // A truncated export is rejected
+        assert!(tool
+            .import_table::<tables::CanonicalHeaders>(&export[..export.len() - 1])
+            .is_err());
+    }
+
+    #[test]
+    fn import_table_commits_in_batches() {
+        let tool = DbTool::new(create_test_provider_factory()).unwrap();
+
+        let mut export = Vec::new();
+        for number in 0..10u64 {
+            write_length_prefixed(&mut export, &number.to_be_bytes()).unwrap();
+            write_length_prefixed(&mut export, B256::with_last_byte(number as u8).as_slice())
+                .unwrap();
+        }
+
+        // The export is truncated within the last row, so only the full batches are committed
+        assert!(tool
+            .import_table_with_threshold::<tables::CanonicalHeaders>(&export[..export.len() - 1], 4)
+            .is_err());
+        for number in 0..8u64 {
+            assert_eq!(
+                tool.get::<tables::CanonicalHeaders>(number).unwrap(),
+                Some(B256::with_last_byte(number as u8))
+            );
+        }
+        assert_eq!(tool.get::<tables::CanonicalHeaders>(8).unwrap(), None);
+
+        assert_eq!(
+            tool.import_table_with_threshold::<tables::CanonicalHeaders>(&export[..], 4).unwrap(),
+            10
+        );
+        assert_eq!(tool.get::<tables::CanonicalHeaders>(9).unwrap(), Some(B256::with_last_byte(9)));
+    }
+
+    #[test]
+    fn read_truncated_length_prefix() {
+        let mut data = Vec::new();
+        write_length_prefixed(&mut data, b"row").unwrap();
+
+        let mut reader = &data[..];
+        assert_eq!(read_length_prefixed(&mut reader).unwrap(), Some(b"row".to_vec()));
+        assert_eq!(read_length_prefixed(&mut reader).unwrap(), None);
+
+        let err = read_length_prefixed(&mut &data[..2]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn list_with_key_prefix_and_start_key() {
+        let tool = DbTool::new(create_test_provider_factory()).unwrap();
+
+        tool.provider_factory
+            .db_ref()
+            .update(|tx| {
+                for number in 0..768u64 {
+                    tx.put::<tables::CanonicalHeaders>(number, B256::ZERO).unwrap();
+                }
+            })
+            .unwrap();
+
+        let list = |reverse, key_prefix: &[u8], start_key: Option<u64>| {
+            let filter = ListFilter {
+                len: 2,
+                reverse,
+                key_prefix: key_prefix.to_vec(),
+                start_key: start_key.map(|key| key.to_be_bytes().to_vec()),
+                ..Default::default()
+            };
+            tool.list::<tables::CanonicalHeaders>(&filter)
+                .unwrap()
+                .0
+                .into_iter()
+                .map(|(key, _)| key)
+                .collect::<Vec<_>>()
+        };
+
+        // Keys `256..512` share the prefix
+        let prefix = [0, 0, 0, 0, 0, 0, 1];
+        assert_eq!(list(false, &prefix, None), vec![256, 257]);
+        assert_eq!(list(true, &prefix, None), vec![511, 510]);
+        assert_eq!(list(false, &prefix, Some(510)), vec![510, 511]);
+        assert_eq!(list(false, &prefix, Some(511)), vec![511]);
+        assert_eq!(list(true, &prefix, Some(257)), vec![257, 256]);
+        assert_eq!(list(true, &prefix, Some(600)), vec![511, 510]);
+        assert_eq!(list(true, &prefix, Some(100)), Vec::<u64>::new());
+
+        assert_eq!(list(false, &[], Some(700)), vec![700, 701]);
+        assert_eq!(list(true, &[], Some(700)), vec![700, 699]);
+        assert_eq!(list(true, &[], Some(1000)), vec![767, 766]);
+    }
 }
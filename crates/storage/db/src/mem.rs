@@ -0,0 +1,692 @@
+//! In-memory implementation of reth's database abstraction layer.
+//!
+//! [`InMemoryDatabase`] implements the same [`Database`] traits as the MDBX backend, so it can be
+//! used anywhere the node or the providers are generic over the database, e.g. by passing it to
+//! `NodeBuilder::with_database` or `ProviderFactory::new`. Nothing is persisted, which makes it
+//! useful for tests and as a reference for alternative backends.
+
+use crate::DatabaseError;
+use reth_db_api::{
+    common::{PairResult, ValueOnlyResult},
+    cursor::{
+        DbCursorRO, DbCursorRW, DbDupCursorRO, DbDupCursorRW, DupWalker, RangeWalker,
+        ReverseWalker, Walker,
+    },
+    database::Database,
+    database_metrics::DatabaseMetrics,
+    table::{
+        Compress, Decode, Decompress, DupSort, Encode, IntoVec, Table, TableImporter, TableRow,
+    },
+    transaction::{DbTx, DbTxMut},
+};
+use reth_storage_errors::db::{DatabaseErrorInfo, DatabaseWriteError, DatabaseWriteOperation};
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Condvar, Mutex, MutexGuard, PoisonError,
+    },
+};
+
+/// Error code of writes that would overwrite an existing key, same as `MDBX_KEYEXIST`.
+const KEY_EXIST: i32 = -30799;
+
+/// Error code of appends that are not in order, same as `MDBX_EKEYMISMATCH`.
+const KEY_MISMATCH: i32 = -30418;
+
+/// Entries of a table, ordered by encoded key and then by compressed value like in MDBX.
+///
+/// Regular tables hold at most one entry per key, `DUPSORT` tables one entry per duplicate value.
+type Entries = BTreeSet<(Vec<u8>, Vec<u8>)>;
+
+/// Tables by name. Tables are shared between snapshots and copied on their first write.
+type Tables = HashMap<&'static str, Arc<Entries>>;
+
+/// A database that keeps all tables in memory.
+///
+/// Read transactions see a snapshot of the tables as of their creation. Write transactions are
+/// serialized and their changes become visible to new transactions on commit.
+#[derive(Clone, Default)]
+pub struct InMemoryDatabase {
+    shared: Arc<Shared>,
+}
+
+impl InMemoryDatabase {
+    /// Creates a new empty database.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl fmt::Debug for InMemoryDatabase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InMemoryDatabase")
+            .field("last_txnid", &self.shared.txnid.load(Ordering::Relaxed))
+            .finish_non_exhaustive()
+    }
+}
+
+/// State shared by the database and its write transactions.
+#[derive(Default)]
+struct Shared {
+    /// Last committed tables.
+    tables: Mutex<Tables>,
+    /// Whether a write transaction is open.
+    writer: Mutex<bool>,
+    /// Notified when the open write transaction is committed or dropped.
+    writer_released: Condvar,
+    /// Number of committed write transactions.
+    txnid: AtomicU64,
+}
+
+impl Database for InMemoryDatabase {
+    type TX = InMemoryTx;
+    type TXMut = InMemoryTx;
+
+    fn tx(&self) -> Result<Self::TX, DatabaseError> {
+        Ok(InMemoryTx::new(lock(&self.shared.tables).clone(), None))
+    }
+
+    /// Creates a new write transaction, blocking until the open one, if any, is closed.
+    fn tx_mut(&self) -> Result<Self::TXMut, DatabaseError> {
+        let mut writer = lock(&self.shared.writer);
+        while *writer {
+            writer =
+                self.shared.writer_released.wait(writer).unwrap_or_else(PoisonError::into_inner);
+        }
+        *writer = true;
+        drop(writer);
+
+        let tables = lock(&self.shared.tables).clone();
+        Ok(InMemoryTx::new(tables, Some(WriteLock(self.shared.clone()))))
+    }
+
+    fn path(&self) -> PathBuf {
+        PathBuf::default()
+    }
+
+    fn oldest_reader_txnid(&self) -> Option<u64> {
+        None
+    }
+
+    fn last_txnid(&self) -> Option<u64> {
+        Some(self.shared.txnid.load(Ordering::Relaxed))
+    }
+}
+
+impl DatabaseMetrics for InMemoryDatabase {}
+
+/// Releases the write lock of the database when dropped.
+struct WriteLock(Arc<Shared>);
+
+impl Drop for WriteLock {
+    fn drop(&mut self) {
+        *lock(&self.0.writer) = false;
+        self.0.writer_released.notify_one();
+    }
+}
+
+/// Read or write transaction of an [`InMemoryDatabase`].
+pub struct InMemoryTx {
+    /// Tables of the transaction, shared with its cursors.
+    tables: Arc<Mutex<Tables>>,
+    /// Write lock of the database, if this is a write transaction.
+    writer: Option<WriteLock>,
+}
+
+impl InMemoryTx {
+    fn new(tables: Tables, writer: Option<WriteLock>) -> Self {
+        Self { tables: Arc::new(Mutex::new(tables)), writer }
+    }
+
+    fn cursor<T: Table>(&self) -> InMemoryCursor<T> {
+        InMemoryCursor {
+            tables: self.tables.clone(),
+            writable: self.writer.is_some(),
+            position: Position::Unset,
+            _table: PhantomData,
+        }
+    }
+}
+
+impl fmt::Debug for InMemoryTx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InMemoryTx")
+            .field("writable", &self.writer.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl DbTx for InMemoryTx {
+    type Cursor<T: Table> = InMemoryCursor<T>;
+    type DupCursor<T: DupSort> = InMemoryCursor<T>;
+
+    fn get<T: Table>(&self, key: T::Key) -> Result<Option<T::Value>, DatabaseError> {
+        self.get_by_encoded_key::<T>(&key.encode())
+    }
+
+    fn get_by_encoded_key<T: Table>(
+        &self,
+        key: &<T::Key as Encode>::Encoded,
+    ) -> Result<Option<T::Value>, DatabaseError> {
+        let tables = lock(&self.tables);
+        tables
+            .get(T::NAME)
+            .and_then(|entries| key_entries(entries, key.as_ref()).next())
+            .map(|(_, value)| T::Value::decompress(value).map_err(Into::into))
+            .transpose()
+    }
+
+    fn commit(self) -> Result<(), DatabaseError> {
+        if let Some(WriteLock(shared)) = &self.writer {
+            *lock(&shared.tables) = lock(&self.tables).clone();
+            shared.txnid.fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+
+    fn abort(self) {}
+
+    fn cursor_read<T: Table>(&self) -> Result<Self::Cursor<T>, DatabaseError> {
+        Ok(self.cursor())
+    }
+
+    fn cursor_dup_read<T: DupSort>(&self) -> Result<Self::DupCursor<T>, DatabaseError> {
+        Ok(self.cursor())
+    }
+
+    fn entries<T: Table>(&self) -> Result<usize, DatabaseError> {
+        Ok(lock(&self.tables).get(T::NAME).map_or(0, |entries| entries.len()))
+    }
+
+    fn disable_long_read_transaction_safety(&mut self) {}
+}
+
+impl DbTxMut for InMemoryTx {
+    type CursorMut<T: Table> = InMemoryCursor<T>;
+    type DupCursorMut<T: DupSort> = InMemoryCursor<T>;
+
+    fn put<T: Table>(&self, key: T::Key, value: T::Value) -> Result<(), DatabaseError> {
+        self.cursor::<T>().put(key, &value, DatabaseWriteOperation::PutUpsert)
+    }
+
+    fn append<T: Table>(&self, key: T::Key, value: T::Value) -> Result<(), DatabaseError> {
+        self.cursor::<T>().put(key, &value, DatabaseWriteOperation::PutAppend)
+    }
+
+    fn delete<T: Table>(
+        &self,
+        key: T::Key,
+        value: Option<T::Value>,
+    ) -> Result<bool, DatabaseError> {
+        let key = key.encode().into_vec();
+        let value = value.map(|value| compress(&value));
+        self.cursor::<T>().write(|entries| match value {
+            Some(value) => entries.remove(&(key, value)),
+            None => remove_key(entries, &key),
+        })
+    }
+
+    fn clear<T: Table>(&self) -> Result<(), DatabaseError> {
+        self.cursor::<T>().write(|entries| entries.clear())
+    }
+
+    fn cursor_write<T: Table>(&self) -> Result<Self::CursorMut<T>, DatabaseError> {
+        Ok(self.cursor())
+    }
+
+    fn cursor_dup_write<T: DupSort>(&self) -> Result<Self::DupCursorMut<T>, DatabaseError> {
+        Ok(self.cursor())
+    }
+}
+
+impl TableImporter for InMemoryTx {}
+
+/// Position of an [`InMemoryCursor`].
+#[derive(Debug, Clone)]
+enum Position {
+    /// The cursor was not positioned yet.
+    Unset,
+    /// The cursor is at an entry, which may have been deleted since.
+    At(Vec<u8>, Vec<u8>),
+    /// The cursor moved past the last entry.
+    End,
+}
+
+/// Cursor of an [`InMemoryTx`].
+pub struct InMemoryCursor<T> {
+    tables: Arc<Mutex<Tables>>,
+    writable: bool,
+    position: Position,
+    _table: PhantomData<T>,
+}
+
+impl<T> fmt::Debug for InMemoryCursor<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InMemoryCursor")
+            .field("writable", &self.writable)
+            .field("position", &self.position)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Table> InMemoryCursor<T> {
+    /// Calls `f` with the entries of the table.
+    fn read<R>(&self, f: impl FnOnce(&Entries) -> R) -> R {
+        let tables = lock(&self.tables);
+        match tables.get(T::NAME) {
+            Some(entries) => f(entries),
+            None => f(&Entries::new()),
+        }
+    }
+
+    /// Calls `f` with the mutable entries of the table, copying them first if they are shared.
+    fn write<R>(&self, f: impl FnOnce(&mut Entries) -> R) -> Result<R, DatabaseError> {
+        if !self.writable {
+            return Err(DatabaseError::Other("in-memory transaction is read-only".to_string()))
+        }
+
+        let mut tables = lock(&self.tables);
+        Ok(f(Arc::make_mut(tables.entry(T::NAME).or_default())))
+    }
+
+    /// Moves the cursor to `entry` and decodes it, or past the last entry if it is `None`.
+    fn move_to(&mut self, entry: Option<(Vec<u8>, Vec<u8>)>) -> PairResult<T> {
+        let Some((key, value)) = entry else {
+            self.position = Position::End;
+            return Ok(None)
+        };
+
+        let row = decode::<T>(&key, &value)?;
+        self.position = Position::At(key, value);
+        Ok(Some(row))
+    }
+
+    /// Moves the cursor to `entry` and decodes it, keeping the position if it is `None`.
+    fn move_to_dup(&mut self, entry: Option<(Vec<u8>, Vec<u8>)>) -> PairResult<T> {
+        if entry.is_none() {
+            return Ok(None)
+        }
+        self.move_to(entry)
+    }
+
+    /// Returns the key of the current position.
+    fn current_key(&self) -> Option<Vec<u8>> {
+        match &self.position {
+            Position::At(key, _) => Some(key.clone()),
+            Position::Unset | Position::End => None,
+        }
+    }
+
+    /// Seeks to the first duplicate of `key` greater than or equal to `subkey`.
+    fn seek_dup(&mut self, key: Vec<u8>, subkey: &[u8]) -> PairResult<T> {
+        let entry = self.read(|entries| {
+            entries.range((key.clone(), subkey.to_vec())..key_end(&key)).next().cloned()
+        });
+        self.move_to(entry)
+    }
+
+    /// Writes `value` at `key` with the semantics of `operation`.
+    fn put(
+        &mut self,
+        key: T::Key,
+        value: &T::Value,
+        operation: DatabaseWriteOperation,
+    ) -> Result<(), DatabaseError> {
+        let key = key.encode().into_vec();
+        let entry = (key, compress(value));
+
+        let result = self.write(|entries| {
+            let in_order = |last: Option<&(Vec<u8>, Vec<u8>)>| match last {
+                Some(last) if T::DUPSORT => *last < entry,
+                Some(last) => last.0 < entry.0,
+                None => true,
+            };
+
+            match operation {
+                DatabaseWriteOperation::CursorInsert
+                    if key_entries(entries, &entry.0).next().is_some() =>
+                {
+                    return Err((KEY_EXIST, "key already exists"))
+                }
+                DatabaseWriteOperation::CursorAppend | DatabaseWriteOperation::PutAppend
+                    if !in_order(entries.last()) =>
+                {
+                    return Err((KEY_MISMATCH, "appended key is not the last key"))
+                }
+                DatabaseWriteOperation::CursorAppendDup
+                    if !in_order(key_entries(entries, &entry.0).next_back()) =>
+                {
+                    return Err((KEY_MISMATCH, "appended value is not the last duplicate"))
+                }
+                _ => {}
+            }
+
+            if !T::DUPSORT {
+                remove_key(entries, &entry.0);
+            }
+            entries.insert(entry.clone());
+            Ok(())
+        })?;
+
+        match result {
+            Ok(()) => {
+                self.position = Position::At(entry.0, entry.1);
+                Ok(())
+            }
+            Err((code, message)) => Err(DatabaseWriteError {
+                info: DatabaseErrorInfo { message: message.into(), code },
+                operation,
+                table_name: T::NAME,
+                key: entry.0,
+            }
+            .into()),
+        }
+    }
+}
+
+impl<T: Table> DbCursorRO<T> for InMemoryCursor<T> {
+    fn first(&mut self) -> PairResult<T> {
+        let entry = self.read(|entries| entries.first().cloned());
+        self.move_to(entry)
+    }
+
+    fn seek_exact(&mut self, key: T::Key) -> PairResult<T> {
+        let key = key.encode();
+        let entry = self.read(|entries| key_entries(entries, key.as_ref()).next().cloned());
+        self.move_to(entry)
+    }
+
+    fn seek(&mut self, key: T::Key) -> PairResult<T> {
+        let key = key.encode().into_vec();
+        let entry = self.read(|entries| entries.range((key, Vec::new())..).next().cloned());
+        self.move_to(entry)
+    }
+
+    fn next(&mut self) -> PairResult<T> {
+        let entry = match self.position.clone() {
+            Position::Unset => return self.first(),
+            Position::At(key, value) => {
+                let bound = (Bound::Excluded((key, value)), Bound::Unbounded);
+                self.read(|entries| entries.range(bound).next().cloned())
+            }
+            Position::End => return Ok(None),
+        };
+        self.move_to(entry)
+    }
+
+    fn prev(&mut self) -> PairResult<T> {
+        let entry = match self.position.clone() {
+            Position::Unset | Position::End => return self.last(),
+            Position::At(key, value) => {
+                self.read(|entries| entries.range(..(key, value)).next_back().cloned())
+            }
+        };
+        self.move_to(entry)
+    }
+
+    fn last(&mut self) -> PairResult<T> {
+        let entry = self.read(|entries| entries.last().cloned());
+        self.move_to(entry)
+    }
+
+    /// Returns the entry at the current position, or the entry following it if it was deleted.
+    fn current(&mut self) -> PairResult<T> {
+        let Position::At(key, value) = &self.position else { return Ok(None) };
+        let position = (key.clone(), value.clone());
+        let entry = self.read(|entries| entries.range(position..).next().cloned());
+        self.move_to(entry)
+    }
+
+    fn walk(&mut self, start_key: Option<T::Key>) -> Result<Walker<'_, T, Self>, DatabaseError> {
+        let start = match start_key {
+            Some(key) => self.seek(key),
+            None => self.first(),
+        }
+        .transpose();
+
+        Ok(Walker::new(self, start))
+    }
+
+    fn walk_range(
+        &mut self,
+        range: impl RangeBounds<T::Key>,
+    ) -> Result<RangeWalker<'_, T, Self>, DatabaseError> {
+        let start = match range.start_bound().cloned() {
+            Bound::Included(key) => self.seek(key),
+            Bound::Excluded(_key) => {
+                unreachable!("Rust doesn't allow for Bound::Excluded in starting bounds");
+            }
+            Bound::Unbounded => self.first(),
+        }
+        .transpose();
+
+        Ok(RangeWalker::new(self, start, range.end_bound().cloned()))
+    }
+
+    fn walk_back(
+        &mut self,
+        start_key: Option<T::Key>,
+    ) -> Result<ReverseWalker<'_, T, Self>, DatabaseError> {
+        let start = match start_key {
+            Some(key) => self.seek(key),
+            None => self.last(),
+        }
+        .transpose();
+
+        Ok(ReverseWalker::new(self, start))
+    }
+}
+
+impl<T: DupSort> DbDupCursorRO<T> for InMemoryCursor<T> {
+    fn prev_dup(&mut self) -> PairResult<T> {
+        let Position::At(key, value) = &self.position else { return Ok(None) };
+        let (key, value) = (key.clone(), value.clone());
+        let entry = self.read(|entries| {
+            entries.range((key.clone(), Vec::new())..(key, value)).next_back().cloned()
+        });
+        self.move_to_dup(entry)
+    }
+
+    fn next_dup(&mut self) -> PairResult<T> {
+        let Position::At(key, value) = &self.position else { return Ok(None) };
+        let bound = (Bound::Excluded((key.clone(), value.clone())), Bound::Excluded(key_end(key)));
+        let entry = self.read(|entries| entries.range(bound).next().cloned());
+        self.move_to_dup(entry)
+    }
+
+    fn last_dup(&mut self) -> ValueOnlyResult<T> {
+        let Some(key) = self.current_key() else { return Ok(None) };
+        let entry = self.read(|entries| key_entries(entries, &key).next_back().cloned());
+        Ok(self.move_to_dup(entry)?.map(|(_, value)| value))
+    }
+
+    fn next_no_dup(&mut self) -> PairResult<T> {
+        let entry = match self.position.clone() {
+            Position::Unset => return self.first(),
+            Position::At(key, _) => {
+                let start = key_end(&key);
+                self.read(|entries| entries.range(start..).next().cloned())
+            }
+            Position::End => return Ok(None),
+        };
+        self.move_to(entry)
+    }
+
+    fn next_dup_val(&mut self) -> ValueOnlyResult<T> {
+        Ok(self.next_dup()?.map(|(_, value)| value))
+    }
+
+    fn seek_by_key_subkey(
+        &mut self,
+        key: <T as Table>::Key,
+        subkey: <T as DupSort>::SubKey,
+    ) -> ValueOnlyResult<T> {
+        let entry = self.seek_dup(key.encode().into_vec(), subkey.encode().as_ref())?;
+        Ok(entry.map(|(_, value)| value))
+    }
+
+    fn walk_dup(
+        &mut self,
+        key: Option<T::Key>,
+        subkey: Option<T::SubKey>,
+    ) -> Result<DupWalker<'_, T, Self>, DatabaseError> {
+        let start = match (key, subkey) {
+            (Some(key), Some(subkey)) => {
+                self.seek_dup(key.encode().into_vec(), subkey.encode().as_ref())
+            }
+            (Some(key), None) => self.seek_exact(key),
+            (None, Some(subkey)) => match self.first()? {
+                Some((key, _)) => self.seek_dup(key.encode().into_vec(), subkey.encode().as_ref()),
+                None => Ok(None),
+            },
+            (None, None) => self.first(),
+        }
+        .transpose();
+
+        Ok(DupWalker::<'_, T, Self> { cursor: self, start })
+    }
+}
+
+impl<T: Table> DbCursorRW<T> for InMemoryCursor<T> {
+    /// For `DUPSORT` tables, `upsert` adds `value` as a duplicate of `key`, like in MDBX.
+    fn upsert(&mut self, key: T::Key, value: &T::Value) -> Result<(), DatabaseError> {
+        self.put(key, value, DatabaseWriteOperation::CursorUpsert)
+    }
+
+    fn insert(&mut self, key: T::Key, value: &T::Value) -> Result<(), DatabaseError> {
+        self.put(key, value, DatabaseWriteOperation::CursorInsert)
+    }
+
+    fn append(&mut self, key: T::Key, value: &T::Value) -> Result<(), DatabaseError> {
+        self.put(key, value, DatabaseWriteOperation::CursorAppend)
+    }
+
+    /// Deletes the entry at the current position. The next call to [`DbCursorRO::next`] returns
+    /// the entry that followed it.
+    fn delete_current(&mut self) -> Result<(), DatabaseError> {
+        let Position::At(key, value) = &self.position else { return Err(not_positioned()) };
+        let entry = (key.clone(), value.clone());
+        self.write(|entries| entries.remove(&entry))?;
+        Ok(())
+    }
+}
+
+impl<T: DupSort> DbDupCursorRW<T> for InMemoryCursor<T> {
+    fn delete_current_duplicates(&mut self) -> Result<(), DatabaseError> {
+        let Some(key) = self.current_key() else { return Err(not_positioned()) };
+        self.write(|entries| remove_key(entries, &key))?;
+        Ok(())
+    }
+
+    fn append_dup(&mut self, key: T::Key, value: T::Value) -> Result<(), DatabaseError> {
+        self.put(key, &value, DatabaseWriteOperation::CursorAppendDup)
+    }
+}
+
+/// Returns the exclusive upper bound of the entries of `key`.
+fn key_end(key: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let mut end = Vec::with_capacity(key.len() + 1);
+    end.extend_from_slice(key);
+    end.push(0);
+    (end, Vec::new())
+}
+
+/// Returns the entries of `key`, in order of their values.
+fn key_entries<'a>(
+    entries: &'a Entries,
+    key: &[u8],
+) -> impl DoubleEndedIterator<Item = &'a (Vec<u8>, Vec<u8>)> + 'a {
+    entries.range((key.to_vec(), Vec::new())..key_end(key))
+}
+
+/// Removes all entries of `key`, returning `true` if there were any.
+fn remove_key(entries: &mut Entries, key: &[u8]) -> bool {
+    let removed = key_entries(entries, key).cloned().collect::<Vec<_>>();
+    for entry in &removed {
+        entries.remove(entry);
+    }
+    !removed.is_empty()
+}
+
+fn compress<V: Compress>(value: &V) -> Vec<u8> {
+    if let Some(value) = value.uncompressable_ref() {
+        return value.to_vec()
+    }
+
+    let mut buf = Vec::new();
+    value.compress_to_buf(&mut buf);
+    buf
+}
+
+fn decode<T: Table>(key: &[u8], value: &[u8]) -> Result<TableRow<T>, DatabaseError> {
+    Ok((T::Key::decode(key)?, T::Value::decompress(value)?))
+}
+
+fn not_positioned() -> DatabaseError {
+    DatabaseError::Delete(DatabaseErrorInfo { message: "cursor is not positioned".into(), code: 0 })
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tables::{CanonicalHeaders, PlainStorageState};
+    use alloy_primitives::{Address, B256, U256};
+    use reth_primitives_traits::StorageEntry;
+
+    #[test]
+    fn transactions_and_cursors() {
+        let db = InMemoryDatabase::new();
+        let tx = db.tx_mut().unwrap();
+        for number in [3, 1, 2] {
+            tx.put::<CanonicalHeaders>(number, B256::with_last_byte(number as u8)).unwrap();
+        }
+
+        // Uncommitted writes are only visible to the write transaction.
+        assert_eq!(db.tx().unwrap().entries::<CanonicalHeaders>().unwrap(), 0);
+        assert!(tx.append::<CanonicalHeaders>(2, B256::ZERO).is_err());
+        tx.commit().unwrap();
+
+        let tx = db.tx().unwrap();
+        assert_eq!(tx.get::<CanonicalHeaders>(2).unwrap(), Some(B256::with_last_byte(2)));
+        assert!(tx.put::<CanonicalHeaders>(4, B256::ZERO).is_err());
+        let mut cursor = tx.cursor_read::<CanonicalHeaders>().unwrap();
+        let numbers = cursor.walk(None).unwrap().map(|row| row.unwrap().0).collect::<Vec<_>>();
+        assert_eq!(numbers, vec![1, 2, 3]);
+        assert_eq!(cursor.seek(4).unwrap(), None);
+        assert_eq!(cursor.prev().unwrap().map(|(number, _)| number), Some(3));
+
+        let address = Address::with_last_byte(1);
+        let entry =
+            |slot: u8| StorageEntry { key: B256::with_last_byte(slot), value: U256::from(slot) };
+        let tx = db.tx_mut().unwrap();
+        let mut cursor = tx.cursor_dup_write::<PlainStorageState>().unwrap();
+        for slot in [2, 1, 3] {
+            cursor.upsert(address, &entry(slot)).unwrap();
+        }
+        cursor.upsert(Address::with_last_byte(2), &entry(1)).unwrap();
+        assert!(cursor.append_dup(address, entry(1)).is_err());
+
+        assert_eq!(
+            cursor.seek_by_key_subkey(address, B256::with_last_byte(2)).unwrap(),
+            Some(entry(2))
+        );
+        cursor.delete_current().unwrap();
+        assert_eq!(cursor.next_dup_val().unwrap(), Some(entry(3)));
+        assert_eq!(cursor.next_dup_val().unwrap(), None);
+        assert_eq!(cursor.next_no_dup().unwrap(), Some((Address::with_last_byte(2), entry(1))));
+
+        assert!(tx.delete::<PlainStorageState>(address, None).unwrap());
+        assert_eq!(tx.entries::<PlainStorageState>().unwrap(), 1);
+        tx.commit().unwrap();
+        assert_eq!(db.last_txnid(), Some(2));
+    }
+}
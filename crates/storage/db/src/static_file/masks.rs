@@ -4,7 +4,11 @@ use crate::{
     HeaderTerminalDifficulties,
 };
 use alloy_primitives::{Address, BlockHash};
-use reth_db_api::{models::StorageBeforeTx, table::Table, AccountChangeSets};
+use reth_db_api::{
+    models::{StorageBeforeTx, StoredBlobSidecars, StoredBlockAccessList},
+    table::Table,
+    AccountChangeSets,
+};
 
 // HEADER MASKS
 add_static_file_mask! {
@@ -60,3 +64,15 @@ add_static_file_mask! {
     #[doc = "Mask for selecting a single changeset from `StorageChangesets` static file segment"]
     StorageChangesetMask, StorageBeforeTx, 0b1
 }
+
+// BLOB SIDECAR MASKS
+add_static_file_mask! {
+    #[doc = "Mask for selecting the blob sidecars of a block from `BlobSidecars` static file segment"]
+    BlobSidecarsMask, StoredBlobSidecars, 0b1
+}
+
+// BLOCK ACCESS LIST MASKS
+add_static_file_mask! {
+    #[doc = "Mask for selecting the block access list of a block from `BlockAccessLists` static file segment"]
+    BlockAccessListMask, StoredBlockAccessList, 0b1
+}
@@ -1,6 +1,6 @@
 //! reth's static file database table import and access
 
-use reth_nippy_jar::{NippyJar, NippyJarError};
+use reth_nippy_jar::{NippyJar, NippyJarError, CONFIG_FILE_EXTENSION};
 use reth_static_file_types::{
     SegmentHeader, SegmentRangeInclusive, StaticFileMap, StaticFileSegment,
 };
@@ -31,11 +31,21 @@ pub fn iter_static_files(path: &Path) -> Result<SortedStaticFiles, NippyJarError
         .map_err(|err| NippyJarError::Custom(err.to_string()))?
         .filter_map(Result::ok);
     for entry in entries {
+        let mut file = entry.path();
+        // Static files offloaded to cold storage only keep their configuration file locally.
+        if file.extension().is_some_and(|ext| ext == CONFIG_FILE_EXTENSION) {
+            file.set_extension("");
+            if file.exists() {
+                continue
+            }
+        }
+
         if entry.metadata().is_ok_and(|metadata| metadata.is_file()) &&
-            let Some((segment, _)) =
-                StaticFileSegment::parse_filename(&entry.file_name().to_string_lossy())
+            let Some((segment, _)) = file
+                .file_name()
+                .and_then(|name| StaticFileSegment::parse_filename(&name.to_string_lossy()))
         {
-            let jar = NippyJar::<SegmentHeader>::load(&entry.path())?;
+            let jar = NippyJar::<SegmentHeader>::load(&file)?;
 
             if let Some(block_range) = jar.user_header().block_range() {
                 static_files
@@ -56,6 +56,103 @@ pub enum DatabaseError {
     Custom(#[from] Arc<dyn Error + Send + Sync>),
 }
 
+impl DatabaseError {
+    /// Returns a stable numeric code identifying the variant of this error.
+    ///
+    /// Codes are in the range `1..=999` and are never reassigned. The backend specific error
+    /// code, if any, is available through [`DatabaseErrorInfo::code`].
+    pub const fn error_code(&self) -> u32 {
+        match self {
+            Self::Open(..) => 1,
+            Self::CreateTable(..) => 2,
+            Self::Write(..) => 3,
+            Self::Read(..) => 4,
+            Self::Delete(..) => 5,
+            Self::Commit(..) => 6,
+            Self::InitTx(..) => 7,
+            Self::InitCursor(..) => 8,
+            Self::Decode => 9,
+            Self::Stats(..) => 10,
+            Self::LogLevelUnavailable(..) => 11,
+            Self::Other(..) => 12,
+            Self::Custom(..) => 13,
+        }
+    }
+
+    /// Returns the implementation-specific error information, if any.
+    pub fn info(&self) -> Option<&DatabaseErrorInfo> {
+        match self {
+            Self::Open(info) |
+            Self::CreateTable(info) |
+            Self::Read(info) |
+            Self::Delete(info) |
+            Self::Commit(info) |
+            Self::InitTx(info) |
+            Self::InitCursor(info) |
+            Self::Stats(info) => Some(info),
+            Self::Write(err) => Some(&err.info),
+            Self::Decode | Self::LogLevelUnavailable(_) | Self::Other(_) | Self::Custom(_) => None,
+        }
+    }
+
+    /// Returns `true` if the operation may succeed when retried, e.g. because the database was
+    /// busy, all reader slots were taken or a long-lived read transaction timed out.
+    pub fn is_retryable(&self) -> bool {
+        self.info().is_some_and(|info| {
+            matches!(
+                info.code,
+                error_codes::BUSY |
+                    error_codes::READERS_FULL |
+                    error_codes::BAD_RSLOT |
+                    error_codes::READ_TRANSACTION_TIMEOUT
+            )
+        })
+    }
+
+    /// Returns `true` if the database can't be used any further without operator intervention,
+    /// e.g. because the map is full or the database is corrupted.
+    pub fn is_fatal(&self) -> bool {
+        if matches!(self, Self::Open(_) | Self::Decode) {
+            return true
+        }
+
+        self.info().is_some_and(|info| {
+            matches!(
+                info.code,
+                error_codes::PAGE_NOT_FOUND |
+                    error_codes::CORRUPTED |
+                    error_codes::PANIC |
+                    error_codes::VERSION_MISMATCH |
+                    error_codes::INVALID |
+                    error_codes::MAP_FULL |
+                    error_codes::DBS_FULL |
+                    error_codes::UNABLE_EXTEND_MAPSIZE |
+                    error_codes::INCOMPATIBLE |
+                    error_codes::WANNA_RECOVERY
+            )
+        })
+    }
+}
+
+/// MDBX error codes used to classify [`DatabaseError`]s, see `mdbx.h`.
+mod error_codes {
+    pub(super) const PAGE_NOT_FOUND: i32 = -30797;
+    pub(super) const CORRUPTED: i32 = -30796;
+    pub(super) const PANIC: i32 = -30795;
+    pub(super) const VERSION_MISMATCH: i32 = -30794;
+    pub(super) const INVALID: i32 = -30793;
+    pub(super) const MAP_FULL: i32 = -30792;
+    pub(super) const DBS_FULL: i32 = -30791;
+    pub(super) const READERS_FULL: i32 = -30790;
+    pub(super) const UNABLE_EXTEND_MAPSIZE: i32 = -30785;
+    pub(super) const INCOMPATIBLE: i32 = -30784;
+    pub(super) const BAD_RSLOT: i32 = -30783;
+    pub(super) const BUSY: i32 = -30778;
+    pub(super) const WANNA_RECOVERY: i32 = -30419;
+    /// Custom code of `reth_libmdbx::Error::ReadTransactionTimeout`.
+    pub(super) const READ_TRANSACTION_TIMEOUT: i32 = -96000;
+}
+
 /// Common error struct to propagate implementation-specific error information.
 #[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
 #[display("{message} ({code})")]
@@ -5,7 +5,7 @@ use alloy_primitives::{Address, BlockHash, BlockNumber, TxNumber, B256};
 use derive_more::Display;
 use reth_codecs::DecompressError;
 use reth_primitives_traits::{transaction::signed::RecoveryError, GotExpected};
-use reth_prune_types::PruneSegmentError;
+use reth_prune_types::{PruneSegment, PruneSegmentError};
 use reth_static_file_types::StaticFileSegment;
 use revm::{
     database_interface::{bal::EvmDatabaseError, DBErrorMarker},
@@ -125,6 +125,22 @@ pub enum ProviderError {
         /// The earliest available block number.
         earliest_available: BlockNumber,
     },
+    /// Block data is not available because it was pruned by the given prune segment.
+    #[error("{segment} data at block #{block_number} is pruned")]
+    BlockDataPruned {
+        /// The prune segment that pruned the data.
+        segment: PruneSegment,
+        /// The block number that was requested.
+        block_number: BlockNumber,
+    },
+    /// Transaction data is not available because it was pruned by the given prune segment.
+    #[error("{segment} data of transaction #{tx_number} is pruned")]
+    TransactionDataPruned {
+        /// The prune segment that pruned the data.
+        segment: PruneSegment,
+        /// The transaction number that was requested.
+        tx_number: TxNumber,
+    },
     /// Provider does not support this particular request.
     #[error("this provider does not support this request")]
     UnsupportedProvider,
@@ -196,6 +212,62 @@ pub enum ProviderError {
 }
 
 impl ProviderError {
+    /// Returns a stable numeric code identifying the variant of this error.
+    ///
+    /// Codes are in the range `1..=999` and are never reassigned.
+    pub const fn error_code(&self) -> u32 {
+        match self {
+            Self::Database(..) => 1,
+            Self::Bal(..) => 2,
+            Self::Pruning(..) => 3,
+            Self::StaticFileWriter(..) => 4,
+            Self::Rlp(..) => 5,
+            Self::TrieWitnessError(..) => 6,
+            Self::SenderRecoveryError => 7,
+            Self::BlockHashNotFound(..) => 8,
+            Self::BlockBodyIndicesNotFound(..) => 9,
+            Self::StorageChangesetNotFound { .. } => 10,
+            Self::AccountChangesetNotFound { .. } => 11,
+            Self::HeaderNotFound(..) => 12,
+            Self::TransactionNotFound(..) => 13,
+            Self::ReceiptNotFound(..) => 14,
+            Self::BestBlockNotFound => 15,
+            Self::FinalizedBlockNotFound => 16,
+            Self::SafeBlockNotFound => 17,
+            Self::UnknownBlockHash(..) => 18,
+            Self::StateForHashNotFound(..) => 19,
+            Self::StateForNumberNotFound(..) => 20,
+            Self::BlockNumberForTransactionIndexNotFound => 21,
+            Self::StateRootMismatch(..) => 22,
+            Self::UnwindStateRootMismatch(..) => 23,
+            Self::StateAtBlockPruned(..) => 24,
+            Self::BlockNotExecuted { .. } => 25,
+            Self::BlockExpired { .. } => 26,
+            Self::UnsupportedProvider => 27,
+            #[cfg(feature = "std")]
+            Self::MissingStaticFileSegmentPath(..) => 28,
+            #[cfg(feature = "std")]
+            Self::MissingStaticFilePath(..) => 29,
+            Self::MissingHighestStaticFileBlock(..) => 30,
+            Self::MissingStaticFileBlock(..) => 31,
+            Self::MissingStaticFileTx(..) => 32,
+            Self::FinalizedStaticFile(..) => 33,
+            Self::UnexpectedStaticFileBlockNumber(..) => 34,
+            Self::UnexpectedStaticFileTxNumber(..) => 35,
+            Self::CorruptedChangeSetStaticFile => 36,
+            Self::UnboundedStartUnsupported => 37,
+            Self::ReadOnlyStaticFileAccess => 38,
+            Self::ConsistentView(..) => 39,
+            Self::InvalidStorageOutput => 40,
+            Self::MissingTrieUpdates(..) => 41,
+            Self::InsufficientChangesets { .. } => 42,
+            Self::MustUnwind { .. } => 43,
+            Self::Other(..) => 44,
+            Self::BlockDataPruned { .. } => 45,
+            Self::TransactionDataPruned { .. } => 46,
+        }
+    }
+
     /// Creates a new [`ProviderError::Other`] variant by wrapping the given error into an
     /// [`AnyError`]
     pub fn other<E>(error: E) -> Self
@@ -205,6 +277,64 @@ impl ProviderError {
         Self::Other(AnyError::new(error))
     }
 
+    /// Returns `true` if the request may succeed when retried, e.g. because the database was busy
+    /// or the consistent view changed underneath the request.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Database(err) => err.is_retryable(),
+            Self::ConsistentView(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the requested data is not available because it was pruned or expired, as
+    /// opposed to being unknown to the node.
+    pub const fn is_pruned(&self) -> bool {
+        matches!(
+            self,
+            Self::StateAtBlockPruned(_) |
+                Self::BlockExpired { .. } |
+                Self::BlockDataPruned { .. } |
+                Self::TransactionDataPruned { .. }
+        )
+    }
+
+    /// Returns `true` if the requested data is unknown to the node.
+    ///
+    /// Data that is not available because it was pruned is reported by [`Self::is_pruned`]
+    /// instead.
+    pub const fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            Self::BlockHashNotFound(_) |
+                Self::BlockBodyIndicesNotFound(_) |
+                Self::StorageChangesetNotFound { .. } |
+                Self::AccountChangesetNotFound { .. } |
+                Self::HeaderNotFound(_) |
+                Self::TransactionNotFound(_) |
+                Self::ReceiptNotFound(_) |
+                Self::BestBlockNotFound |
+                Self::FinalizedBlockNotFound |
+                Self::SafeBlockNotFound |
+                Self::UnknownBlockHash(_) |
+                Self::StateForHashNotFound(_) |
+                Self::StateForNumberNotFound(_) |
+                Self::BlockNumberForTransactionIndexNotFound
+        )
+    }
+
+    /// Returns `true` if the error indicates a corrupted or unusable node state that can't be
+    /// recovered from without operator intervention.
+    pub fn is_fatal(&self) -> bool {
+        match self {
+            Self::Database(err) => err.is_fatal(),
+            Self::StateRootMismatch(_) |
+            Self::UnwindStateRootMismatch(_) |
+            Self::CorruptedChangeSetStaticFile => true,
+            _ => false,
+        }
+    }
+
     /// Returns the arbitrary error if it is [`ProviderError::Other`]
     pub fn as_other(&self) -> Option<&(dyn core::error::Error + Send + Sync + 'static)> {
         match self {
@@ -329,4 +459,17 @@ mod tests {
         assert!(err.is_other::<E>());
         assert!(err.downcast_other_ref::<E>().is_some());
     }
+
+    #[test]
+    fn pruned_or_not_found() {
+        let pruned =
+            ProviderError::BlockDataPruned { segment: PruneSegment::Receipts, block_number: 1 };
+        assert!(pruned.is_pruned());
+        assert!(!pruned.is_not_found());
+        assert_eq!(pruned.to_string(), "Receipts data at block #1 is pruned");
+
+        let not_found = ProviderError::ReceiptNotFound(1.into());
+        assert!(not_found.is_not_found());
+        assert!(!not_found.is_pruned());
+    }
 }
@@ -2,7 +2,6 @@ use crate::{compression::Compression, NippyJarError};
 use derive_more::Deref;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
-    fs::File,
     io::{Read, Write},
     sync::Arc,
 };
@@ -71,7 +70,7 @@ impl Zstd {
     }
 
     /// If using dictionaries, creates a list of [`Compressor`].
-    pub fn compressors(&self) -> Result<Option<Vec<Compressor<'_>>>, NippyJarError> {
+    pub fn compressors(&self) -> Result<Option<Vec<Compressor<'static>>>, NippyJarError> {
         match self.state {
             ZstdState::PendingDictionary => Err(NippyJarError::CompressorNotReady),
             ZstdState::Ready => {
@@ -88,14 +87,17 @@ impl Zstd {
         }
     }
 
-    /// Compresses a value using a dictionary. Reserves additional capacity for `buffer` if
-    /// necessary.
+    /// Compresses a value using a dictionary and writes it to `handle`. Reserves additional
+    /// capacity for `buffer` if necessary.
+    ///
+    /// Returns the number of bytes written to `handle`.
     pub fn compress_with_dictionary(
         column_value: &[u8],
         buffer: &mut Vec<u8>,
-        handle: &mut File,
+        handle: &mut impl Write,
         compressor: Option<&mut Compressor<'_>>,
-    ) -> Result<(), NippyJarError> {
+    ) -> Result<usize, NippyJarError> {
+        buffer.clear();
         if let Some(compressor) = compressor {
             // Compressor requires the destination buffer to be big enough to write, otherwise it
             // fails. However, we don't know how big it will be. If data is small
@@ -110,13 +112,14 @@ impl Zstd {
                 }
             }
 
+            let written = buffer.len();
             handle.write_all(buffer)?;
             buffer.clear();
+            Ok(written)
         } else {
             handle.write_all(column_value)?;
+            Ok(column_value.len())
         }
-
-        Ok(())
     }
 
     /// Appends a decompressed value using a dictionary to a user provided buffer.
@@ -189,7 +192,6 @@ impl Compression for Zstd {
         matches!(self.state, ZstdState::Ready)
     }
 
-    #[cfg(test)]
     /// If using it with dictionaries, prepares a dictionary for each column.
     fn prepare_compression(
         &mut self,
@@ -277,7 +279,6 @@ impl std::fmt::Debug for ZstdDictionaries<'_> {
 }
 
 impl ZstdDictionaries<'_> {
-    #[cfg(test)]
     /// Creates [`ZstdDictionaries`].
     pub(crate) fn new(raw: Vec<RawDictionary>) -> Self {
         Self(raw.into_iter().map(ZstdDictionary::Raw).collect())
@@ -287,7 +288,7 @@ impl ZstdDictionaries<'_> {
     pub(crate) fn load(raw: Vec<RawDictionary>) -> Self {
         Self(
             raw.into_iter()
-                .map(|dict| ZstdDictionary::Loaded(DecoderDictionary::copy(&dict)))
+                .map(|dict| ZstdDictionary::Loaded(DecoderDictionary::copy(&dict), dict))
                 .collect(),
         )
     }
@@ -304,33 +305,30 @@ impl ZstdDictionaries<'_> {
             .collect::<Result<Vec<_>, _>>()?)
     }
 
-    /// Creates a list of compressors from a list of [`ZstdDictionary::Raw`].
-    pub(crate) fn compressors(&self) -> Result<Vec<Compressor<'_>>, NippyJarError> {
+    /// Creates a list of compressors from the raw dictionaries.
+    pub(crate) fn compressors(&self) -> Result<Vec<Compressor<'static>>, NippyJarError> {
         Ok(self
             .iter()
-            .flat_map(|dict| {
-                dict.raw()
-                    .ok_or(NippyJarError::CompressorNotAllowed)
-                    .map(|dict| Compressor::with_dictionary(0, dict))
-            })
+            .map(|dict| Compressor::with_dictionary(0, dict.raw()))
             .collect::<Result<Vec<_>, _>>()?)
     }
 }
 
-/// A Zstd dictionary. It's created and serialized with [`ZstdDictionary::Raw`], and deserialized as
+/// A Zstd dictionary. It's created with [`ZstdDictionary::Raw`], and deserialized as
 /// [`ZstdDictionary::Loaded`].
+///
+/// Loaded dictionaries keep the raw dictionary, so that values can still be compressed when
+/// appending to a jar loaded from disk.
 pub(crate) enum ZstdDictionary<'a> {
-    #[cfg_attr(not(test), expect(dead_code))]
     Raw(RawDictionary),
-    Loaded(DecoderDictionary<'a>),
+    Loaded(DecoderDictionary<'a>, RawDictionary),
 }
 
 impl ZstdDictionary<'_> {
-    /// Returns a reference to the expected `RawDictionary`
-    pub(crate) const fn raw(&self) -> Option<&RawDictionary> {
+    /// Returns a reference to the `RawDictionary`
+    pub(crate) const fn raw(&self) -> &RawDictionary {
         match self {
-            ZstdDictionary::Raw(dict) => Some(dict),
-            ZstdDictionary::Loaded(_) => None,
+            ZstdDictionary::Raw(dict) | ZstdDictionary::Loaded(_, dict) => dict,
         }
     }
 
@@ -338,7 +336,7 @@ impl ZstdDictionary<'_> {
     pub(crate) const fn loaded(&self) -> Option<&DecoderDictionary<'_>> {
         match self {
             ZstdDictionary::Raw(_) => None,
-            ZstdDictionary::Loaded(dict) => Some(dict),
+            ZstdDictionary::Loaded(dict, _) => Some(dict),
         }
     }
 }
@@ -349,7 +347,7 @@ impl<'de> Deserialize<'de> for ZstdDictionary<'_> {
         D: Deserializer<'de>,
     {
         let dict = RawDictionary::deserialize(deserializer)?;
-        Ok(Self::Loaded(DecoderDictionary::copy(&dict)))
+        Ok(Self::Loaded(DecoderDictionary::copy(&dict), dict))
     }
 }
 
@@ -358,21 +356,13 @@ impl Serialize for ZstdDictionary<'_> {
     where
         S: Serializer,
     {
-        match self {
-            ZstdDictionary::Raw(r) => r.serialize(serializer),
-            ZstdDictionary::Loaded(_) => unreachable!(),
-        }
+        self.raw().serialize(serializer)
     }
 }
 
 #[cfg(test)]
 impl PartialEq for ZstdDictionary<'_> {
     fn eq(&self, other: &Self) -> bool {
-        if let (Self::Raw(a), Self::Raw(b)) = (self, &other) {
-            return a == b
-        }
-        unimplemented!(
-            "`DecoderDictionary` can't be compared. So comparison should be done after decompressing a value."
-        );
+        self.raw() == other.raw()
     }
 }
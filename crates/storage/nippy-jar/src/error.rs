@@ -28,6 +28,10 @@ pub enum NippyJarError {
     #[error(transparent)]
     EliasFano(#[from] anyhow::Error),
 
+    /// The data or offsets file of a jar doesn't match the checksum in its configuration.
+    #[error("static file {0:?} doesn't match its checksum")]
+    ChecksumMismatch(PathBuf),
+
     /// Compression was enabled, but the compressor is not ready yet.
     #[error("compression was enabled, but it's not ready yet")]
     CompressorNotReady,
@@ -52,16 +52,22 @@ mod consistency;
 pub use consistency::NippyJarChecker;
 
 /// The version number of the Nippy Jar format.
-const NIPPY_JAR_VERSION: usize = 1;
+pub const NIPPY_JAR_VERSION: usize = 1;
+/// Number of rows appended by [`NippyJar::rewrite_into`] between two commits, which bounds the
+/// amount of offsets held in memory.
+const REWRITE_COMMIT_INTERVAL: usize = 100_000;
 /// The file extension used for index files.
 const INDEX_FILE_EXTENSION: &str = "idx";
 /// The file extension used for offsets files.
-const OFFSETS_FILE_EXTENSION: &str = "off";
+pub const OFFSETS_FILE_EXTENSION: &str = "off";
 /// The file extension used for configuration files.
 pub const CONFIG_FILE_EXTENSION: &str = "conf";
 /// The file extension used for changeset offset sidecar files.
 pub const CHANGESET_OFFSETS_FILE_EXTENSION: &str = "csoff";
 
+/// Checksum of the data and offsets files of a [`NippyJar`].
+pub type NippyJarChecksum = [u8; 32];
+
 /// A [`RefRow`] is a list of column value slices pointing to either an internal buffer or a
 /// memory-mapped file.
 type RefRow<'a> = Vec<&'a [u8]>;
@@ -111,6 +117,16 @@ pub struct NippyJar<H = ()> {
     /// Data path for file. Supporting files will have a format `{path}.{extension}`.
     #[serde(skip)]
     path: PathBuf,
+    /// Checksum of the data and offsets files, set once the jar is sealed with
+    /// [`NippyJarWriter::seal`].
+    ///
+    /// Like all fields below, it's stored after the serialized configuration, so jars with and
+    /// without it can be read by any version.
+    #[serde(skip)]
+    checksum: Option<NippyJarChecksum>,
+    /// User-defined format of the column values, on top of the compression. Default: `0`.
+    #[serde(skip)]
+    value_format: u8,
 }
 
 impl<H: NippyJarHeader> std::fmt::Debug for NippyJar<H> {
@@ -125,6 +141,8 @@ impl<H: NippyJarHeader> std::fmt::Debug for NippyJar<H> {
             .field("phf", &self.phf)
             .field("path", &self.path)
             .field("max_row_size", &self.max_row_size)
+            .field("checksum", &self.checksum)
+            .field("value_format", &self.value_format)
             .finish_non_exhaustive()
     }
 }
@@ -154,6 +172,8 @@ impl<H: NippyJarHeader> NippyJar<H> {
             filter: None,
             phf: None,
             path: path.to_path_buf(),
+            checksum: None,
+            value_format: 0,
         }
     }
 
@@ -170,6 +190,31 @@ impl<H: NippyJarHeader> NippyJar<H> {
         self
     }
 
+    /// Removes any compression, so that column values are stored as they are.
+    pub fn without_compression(mut self) -> Self {
+        self.compressor = None;
+        self
+    }
+
+    /// Sets the user-defined format of the column values.
+    ///
+    /// It's not interpreted by the jar itself, but lets its user tell apart values written with
+    /// different encodings.
+    pub const fn with_value_format(mut self, value_format: u8) -> Self {
+        self.value_format = value_format;
+        self
+    }
+
+    /// Gets the user-defined format of the column values.
+    pub const fn value_format(&self) -> u8 {
+        self.value_format
+    }
+
+    /// Gets the version of the `NippyJar` format this jar was written with.
+    pub const fn version(&self) -> usize {
+        self.version
+    }
+
     /// Gets a reference to the user header.
     pub const fn user_header(&self) -> &H {
         &self.user_header
@@ -213,13 +258,61 @@ impl<H: NippyJarHeader> NippyJar<H> {
     }
 
     /// Deserializes an instance of [`Self`] from a [`Read`] type.
-    pub fn load_from_reader<R: Read>(reader: R) -> Result<Self, NippyJarError> {
-        Ok(bincode::deserialize_from(reader)?)
+    pub fn load_from_reader<R: Read>(mut reader: R) -> Result<Self, NippyJarError> {
+        let mut jar: Self = bincode::deserialize_from(&mut reader)?;
+
+        // Fields stored after the configuration are read for as long as there are bytes left, so
+        // that new ones can be appended without breaking older jars.
+        let mut trailer = Vec::new();
+        reader.read_to_end(&mut trailer)?;
+        let mut trailer = trailer.as_slice();
+        if !trailer.is_empty() {
+            jar.checksum = bincode::deserialize_from(&mut trailer)?;
+        }
+        if !trailer.is_empty() {
+            jar.value_format = bincode::deserialize_from(&mut trailer)?;
+        }
+
+        Ok(jar)
     }
 
     /// Serializes an instance of [`Self`] to a [`Write`] type.
-    pub fn save_to_writer<W: Write>(&self, writer: W) -> Result<(), NippyJarError> {
-        Ok(bincode::serialize_into(writer, self)?)
+    pub fn save_to_writer<W: Write>(&self, mut writer: W) -> Result<(), NippyJarError> {
+        bincode::serialize_into(&mut writer, self)?;
+        if self.checksum.is_some() || self.value_format != 0 {
+            bincode::serialize_into(&mut writer, &self.checksum)?;
+            bincode::serialize_into(&mut writer, &self.value_format)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the checksum of the data and offsets files, if the jar was sealed.
+    pub const fn checksum(&self) -> Option<&NippyJarChecksum> {
+        self.checksum.as_ref()
+    }
+
+    /// Computes the checksum of the data and offsets files as they are on disk.
+    pub fn compute_checksum(&self) -> Result<NippyJarChecksum, NippyJarError> {
+        let mut hasher = blake3::Hasher::new();
+        for path in [self.data_path().to_path_buf(), self.offsets_path()] {
+            let mut file =
+                File::open(&path).map_err(|err| reth_fs_util::FsPathError::open(err, path))?;
+            io::copy(&mut file, &mut hasher)?;
+        }
+        Ok(hasher.finalize().into())
+    }
+
+    /// Verifies the data and offsets files against the checksum stored in the configuration.
+    ///
+    /// Returns `false` if the jar has no checksum, because it was not sealed yet, and
+    /// [`NippyJarError::ChecksumMismatch`] if the files don't match it.
+    pub fn verify_checksum(&self) -> Result<bool, NippyJarError> {
+        let Some(checksum) = self.checksum else { return Ok(false) };
+
+        if self.compute_checksum()? != checksum {
+            return Err(NippyJarError::ChecksumMismatch(self.data_path().to_path_buf()))
+        }
+        Ok(true)
     }
 
     /// Returns the path for the data file
@@ -272,15 +365,59 @@ impl<H: NippyJarHeader> NippyJar<H> {
         DataReader::new(self.data_path())
     }
 
+    /// Copies every row of this jar into `target`, re-encoding the column values with the
+    /// compression configured on `target`.
+    ///
+    /// `target` is written with the current [`NIPPY_JAR_VERSION`], so this can also be used to
+    /// upgrade older jars. It must point to a path without existing data and have the same number
+    /// of columns. Since the values are copied as they are, `target` keeps the value format of
+    /// this jar.
+    pub fn rewrite_into(&self, mut target: Self) -> Result<Self, NippyJarError> {
+        if target.columns != self.columns {
+            return Err(NippyJarError::ColumnLenMismatch(self.columns, target.columns))
+        }
+
+        if let Some(compression) = &target.compressor &&
+            !compression.is_ready()
+        {
+            return Err(NippyJarError::CompressorNotReady)
+        }
+
+        debug!(target: "nippy-jar", from=?self.data_path(), to=?target.data_path(), "Rewriting jar.");
+        target.value_format = self.value_format;
+
+        let mut cursor = NippyJarCursor::new(self)?;
+        let mut writer = NippyJarWriter::new(target)?;
+
+        while let Some(row) = cursor.next_row()? {
+            for value in row {
+                writer.append_column(Some(Ok::<_, Box<dyn StdError + Send + Sync>>(value)))?;
+            }
+
+            if writer.rows() % REWRITE_COMMIT_INTERVAL == 0 {
+                writer.commit()?;
+            }
+        }
+
+        // A rewritten sealed jar is complete as well.
+        if self.checksum.is_some() {
+            writer.seal()?;
+        } else {
+            writer.commit()?;
+        }
+
+        Ok(writer.into_jar())
+    }
+
     /// Writes all necessary configuration to file.
     fn freeze_config(&self) -> Result<(), NippyJarError> {
         Ok(reth_fs_util::atomic_write_file(&self.config_path(), |file| self.save_to_writer(file))?)
     }
-}
 
-#[cfg(test)]
-impl<H: NippyJarHeader> NippyJar<H> {
     /// If required, prepares any compression algorithm to an early pass of the data.
+    ///
+    /// For zstd with dictionaries, this trains a dictionary per column from the given samples,
+    /// which is required before any data can be written to the jar.
     pub fn prepare_compression(
         &mut self,
         columns: Vec<impl IntoIterator<Item = Vec<u8>>>,
@@ -292,7 +429,10 @@ impl<H: NippyJarHeader> NippyJar<H> {
         }
         Ok(())
     }
+}
 
+#[cfg(test)]
+impl<H: NippyJarHeader> NippyJar<H> {
     /// Writes all data and configuration to a file and the offset index to another.
     pub fn freeze(
         self,
@@ -574,6 +714,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_zstd_with_dictionaries_append() {
+        let (col1, col2) = test_data(None);
+        let num_columns = 2;
+        let file_path = tempfile::NamedTempFile::new().unwrap();
+
+        let mut nippy =
+            NippyJar::new_without_header(num_columns, file_path.path()).with_zstd(true, 5000);
+        nippy.prepare_compression(vec![col1.clone(), col2.clone()]).unwrap();
+        nippy
+            .freeze(
+                vec![
+                    clone_with_result(&col1[..1].to_vec()),
+                    clone_with_result(&col2[..1].to_vec()),
+                ],
+                1,
+            )
+            .unwrap();
+
+        // A jar loaded from disk keeps its dictionaries, so that values can be appended to it.
+        let loaded_nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        let mut writer = NippyJarWriter::new(loaded_nippy).unwrap();
+        for row in 1..col1.len() {
+            writer.append_column(Some(Ok(&col1[row]))).unwrap();
+            writer.append_column(Some(Ok(&col2[row]))).unwrap();
+        }
+        writer.commit().unwrap();
+
+        let loaded_nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        let mut cursor = NippyJarCursor::new(&loaded_nippy).unwrap();
+        let mut row_index = 0usize;
+        while let Some(row) = cursor.next_row().unwrap() {
+            assert_eq!((row[0], row[1]), (col1[row_index].as_slice(), col2[row_index].as_slice()));
+            row_index += 1;
+        }
+        assert_eq!(row_index, col1.len());
+    }
+
     #[test]
     fn test_lz4() {
         let (col1, col2) = test_data(None);
@@ -611,6 +789,101 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rewrite_into() {
+        let (col1, col2) = test_data(None);
+        let num_rows = col1.len() as u64;
+        let num_columns = 2;
+        let file_path = tempfile::NamedTempFile::new().unwrap();
+        let target_dir = tempfile::tempdir().unwrap();
+
+        let nippy = NippyJar::new_without_header(num_columns, file_path.path())
+            .with_lz4()
+            .freeze(vec![clone_with_result(&col1), clone_with_result(&col2)], num_rows)
+            .unwrap();
+
+        for (name, target) in [
+            (
+                "uncompressed",
+                NippyJar::new_without_header(num_columns, &target_dir.path().join("a")),
+            ),
+            (
+                "zstd",
+                NippyJar::new_without_header(num_columns, &target_dir.path().join("b"))
+                    .with_zstd(false, 0),
+            ),
+        ] {
+            let rewritten = nippy.rewrite_into(target).unwrap();
+            assert_eq!(rewritten.rows(), nippy.rows(), "{name}");
+            assert_eq!(rewritten.version(), NIPPY_JAR_VERSION, "{name}");
+
+            let loaded = NippyJar::load_without_header(rewritten.data_path()).unwrap();
+            assert_eq!(rewritten, loaded, "{name}");
+
+            let mut cursor = NippyJarCursor::new(&loaded).unwrap();
+            let mut row_index = 0usize;
+            while let Some(row) = cursor.next_row().unwrap() {
+                assert_eq!(
+                    (row[0], row[1]),
+                    (col1[row_index].as_slice(), col2[row_index].as_slice()),
+                    "{name}"
+                );
+                row_index += 1;
+            }
+            assert_eq!(row_index, col1.len(), "{name}");
+        }
+
+        // Mismatched column count is rejected
+        let target = NippyJar::new_without_header(num_columns + 1, &target_dir.path().join("c"));
+        assert!(matches!(nippy.rewrite_into(target), Err(NippyJarError::ColumnLenMismatch(2, 3))));
+    }
+
+    #[test]
+    fn test_checksum() {
+        let (col1, col2) = test_data(None);
+        let num_columns = 2;
+        let file_path = tempfile::NamedTempFile::new().unwrap();
+
+        append_two_rows(num_columns, file_path.path(), &col1, &col2);
+        let nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        assert_eq!(nippy.checksum(), None);
+        assert!(!nippy.verify_checksum().unwrap());
+
+        let mut writer = NippyJarWriter::new(nippy).unwrap();
+        writer.seal().unwrap();
+        let nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        assert_eq!(nippy.checksum(), Some(&nippy.compute_checksum().unwrap()));
+        assert!(nippy.verify_checksum().unwrap());
+
+        // The checksum is stored after the configuration, which older versions still read.
+        let config = reth_fs_util::read(nippy.config_path()).unwrap();
+        let mut old = bincode::deserialize_from::<_, NippyJar>(&config[..]).unwrap();
+        assert_eq!(old.checksum, None);
+        old.path = nippy.path.clone();
+        old.checksum = nippy.checksum;
+        assert_eq!(old, nippy);
+
+        // Corrupted data is detected
+        let mut data = reth_fs_util::read(nippy.data_path()).unwrap();
+        data[0] ^= 1;
+        reth_fs_util::write(nippy.data_path(), &data).unwrap();
+        assert!(matches!(nippy.verify_checksum(), Err(NippyJarError::ChecksumMismatch(_))));
+
+        // The value format is stored after the checksum
+        let nippy = nippy.with_value_format(1);
+        nippy.freeze_config().unwrap();
+        let loaded = NippyJar::load_without_header(file_path.path()).unwrap();
+        assert_eq!((loaded.value_format(), loaded.checksum()), (1, nippy.checksum()));
+
+        // Appending rows clears the checksum
+        let mut writer = NippyJarWriter::new(nippy).unwrap();
+        writer.append_column(Some(Ok(&col1[2]))).unwrap();
+        writer.append_column(Some(Ok(&col2[2]))).unwrap();
+        writer.commit().unwrap();
+        let nippy = NippyJar::load_without_header(file_path.path()).unwrap();
+        assert_eq!(nippy.checksum(), None);
+    }
+
     #[test]
     fn test_zstd_no_dictionaries() {
         let (col1, col2) = test_data(None);
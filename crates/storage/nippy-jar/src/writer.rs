@@ -1,12 +1,14 @@
 use crate::{
-    compression::Compression, ColumnResult, NippyJar, NippyJarChecker, NippyJarError,
-    NippyJarHeader,
+    compression::{Compression, Compressors, Zstd},
+    ColumnResult, NippyJar, NippyJarChecker, NippyJarError, NippyJarHeader,
 };
 use std::{
+    fmt::Debug,
     fs::{File, OpenOptions},
     io::{BufWriter, Read, Seek, SeekFrom, Write},
     path::Path,
 };
+use zstd::bulk::Compressor;
 
 /// Size of one offset in bytes.
 pub(crate) const OFFSET_SIZE_BYTES: u8 = 8;
@@ -35,6 +37,8 @@ pub struct NippyJarWriter<H: NippyJarHeader = ()> {
     offsets_file: BufWriter<File>,
     /// Temporary buffer to reuse when compressing data.
     tmp_buf: Vec<u8>,
+    /// Compressors with the column dictionaries, if the jar uses zstd with dictionaries.
+    dictionary_compressors: Option<DictionaryCompressors>,
     /// Used to find the maximum uncompressed size of a row in a jar.
     uncompressed_row_size: usize,
     /// Partial offset list which hasn't been flushed to disk.
@@ -70,11 +74,19 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
             (jar, data_file.expect("qed"), offsets_file.expect("qed"))
         };
 
+        let dictionary_compressors = match &jar.compressor {
+            Some(Compressors::Zstd(zstd)) if zstd.use_dict => Some(DictionaryCompressors(
+                zstd.compressors()?.ok_or(NippyJarError::CompressorNotReady)?,
+            )),
+            _ => None,
+        };
+
         let mut writer = Self {
             jar,
             data_file,
             offsets_file,
             tmp_buf: Vec::with_capacity(1_000_000),
+            dictionary_compressors,
             uncompressed_row_size: 0,
             offsets: Vec::with_capacity(1_000_000),
             column: 0,
@@ -226,8 +238,16 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
 
     /// Writes column to data file. If it's the last column of the row, call `finalize_row()`
     fn write_column(&mut self, value: &[u8]) -> Result<usize, NippyJarError> {
+        self.jar.checksum = None;
         self.uncompressed_row_size += value.len();
-        let len = if let Some(compression) = &self.jar.compressor {
+        let len = if let Some(compressors) = &mut self.dictionary_compressors {
+            Zstd::compress_with_dictionary(
+                value,
+                &mut self.tmp_buf,
+                &mut self.data_file,
+                Some(&mut compressors.0[self.column]),
+            )?
+        } else if let Some(compression) = &self.jar.compressor {
             let before = self.tmp_buf.len();
             let len = compression.compress_to(value, &mut self.tmp_buf)?;
             self.data_file.write_all(&self.tmp_buf[before..before + len])?;
@@ -249,6 +269,7 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
     /// Prunes rows from data and offsets file and updates its configuration on disk
     pub fn prune_rows(&mut self, num_rows: usize) -> Result<(), NippyJarError> {
         self.dirty = true;
+        self.jar.checksum = None;
 
         self.offsets_file.flush()?;
         self.data_file.flush()?;
@@ -352,6 +373,17 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
         Ok(())
     }
 
+    /// Commits the jar and stores the checksum of its data and offsets files in the
+    /// configuration, see [`NippyJar::verify_checksum`].
+    ///
+    /// Should be called once no more rows will be appended. Appending or pruning rows afterwards
+    /// clears the checksum again.
+    pub fn seal(&mut self) -> Result<(), NippyJarError> {
+        self.sync_all()?;
+        self.jar.checksum = Some(self.jar.compute_checksum()?);
+        self.finalize()
+    }
+
     /// Syncs data and offsets to disk.
     ///
     /// This does NOT commit the configuration. Call [`Self::finalize`] after to write the
@@ -485,3 +517,12 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
         &self.jar
     }
 }
+
+/// Per column zstd [`Compressor`]s with the dictionaries of a jar loaded.
+struct DictionaryCompressors(Vec<Compressor<'static>>);
+
+impl Debug for DictionaryCompressors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DictionaryCompressors").field("columns", &self.0.len()).finish()
+    }
+}
@@ -0,0 +1,97 @@
+use alloy_eips::{eip7594::BlobTransactionSidecarVariant, BlockNumHash};
+use alloy_primitives::{BlockHash, BlockNumber, TxHash};
+use parking_lot::Mutex;
+use reth_storage_api::BlobSidecarWriter;
+use reth_storage_errors::provider::ProviderResult;
+use std::{collections::BTreeMap, sync::Arc};
+
+/// Blob sidecars of a block, with the hashes of their transactions.
+type BlockSidecars = Vec<(TxHash, BlobTransactionSidecarVariant)>;
+
+/// Blob sidecars of canonical blocks that aren't persisted yet.
+///
+/// The sidecars are staged when their blocks become canonical, and written to the blob sidecars
+/// static files by the engine persistence, in the same transaction as their blocks. Blocks that
+/// are unwound have their sidecars removed with them, so only the sidecars of the persisted
+/// canonical chain are stored.
+#[derive(Debug, Clone)]
+pub struct BlobSidecarStage {
+    staged: Arc<Mutex<BTreeMap<(BlockNumber, BlockHash), BlockSidecars>>>,
+    retention_blocks: u64,
+}
+
+impl BlobSidecarStage {
+    /// Creates a new stage, keeping the sidecars of the last `retention_blocks` persisted blocks.
+    pub fn new(retention_blocks: u64) -> Self {
+        Self { staged: Default::default(), retention_blocks }
+    }
+
+    /// Returns the number of most recent blocks whose sidecars are kept.
+    pub const fn retention_blocks(&self) -> u64 {
+        self.retention_blocks
+    }
+
+    /// Stages the sidecars of the canonical block.
+    pub fn insert(&self, block: BlockNumHash, sidecars: BlockSidecars) {
+        self.staged.lock().insert((block.number, block.hash), sidecars);
+    }
+
+    /// Takes the staged sidecars of the block.
+    ///
+    /// The sidecars staged for lower blocks, or for other blocks of the same height, are dropped,
+    /// since those blocks were either persisted or reorged out.
+    pub fn take(&self, block: BlockNumHash) -> BlockSidecars {
+        let mut staged = self.staged.lock();
+        let sidecars = staged.remove(&(block.number, block.hash)).unwrap_or_default();
+        *staged = staged.split_off(&(block.number + 1, BlockHash::ZERO));
+        sidecars
+    }
+
+    /// Writes the staged sidecars of the persisted blocks, in ascending order, and prunes the
+    /// sidecars that fall out of the retention window.
+    pub fn persist<P: BlobSidecarWriter>(
+        &self,
+        provider: &P,
+        blocks: impl IntoIterator<Item = BlockNumHash>,
+    ) -> ProviderResult<()> {
+        let mut tip = None;
+        for block in blocks {
+            provider.write_blob_sidecars(block.number, self.take(block))?;
+            tip = Some(block.number);
+        }
+
+        if let Some(tip) = tip {
+            provider.prune_blob_sidecars(tip.saturating_sub(self.retention_blocks))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+
+    #[test]
+    fn take_drops_reorged_and_persisted_blocks() {
+        let stage = BlobSidecarStage::new(10);
+        let block = |number, hash| BlockNumHash::new(number, B256::with_last_byte(hash));
+        let sidecars = |hash| {
+            vec![(
+                B256::with_last_byte(hash),
+                BlobTransactionSidecarVariant::Eip4844(Default::default()),
+            )]
+        };
+
+        stage.insert(block(1, 1), sidecars(1));
+        stage.insert(block(2, 2), sidecars(2));
+        // reorged block of the same height
+        stage.insert(block(2, 3), sidecars(3));
+        stage.insert(block(3, 4), sidecars(4));
+
+        assert_eq!(stage.take(block(2, 3)), sidecars(3));
+        assert!(stage.take(block(1, 1)).is_empty());
+        assert!(stage.take(block(2, 2)).is_empty());
+        assert_eq!(stage.take(block(3, 4)), sidecars(4));
+    }
+}
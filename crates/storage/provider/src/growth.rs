@@ -0,0 +1,270 @@
+//! Storage growth forecasting.
+//!
+//! [`StorageGrowthTracker`] keeps a window of [`StorageSample`]s of the sizes of all database
+//! tables and static file segments, and projects from them how fast the storage grows and when
+//! the disk holding the datadir runs out of space.
+
+use crate::{ProviderFactory, ProviderResult, StaticFileProviderFactory};
+use metrics::gauge;
+use reth_db_api::database_metrics::DatabaseMetrics;
+use reth_node_types::NodeTypesWithDB;
+use reth_static_file_types::StaticFileSegment;
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// A part of the storage whose size is sampled.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum StorageComponent {
+    /// A database table.
+    Table(String),
+    /// A static file segment.
+    Segment(StaticFileSegment),
+}
+
+impl StorageComponent {
+    /// Returns the kind of the component, used as a metrics label.
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Table(_) => "table",
+            Self::Segment(_) => "segment",
+        }
+    }
+}
+
+impl fmt::Display for StorageComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Table(table) => f.write_str(table),
+            Self::Segment(segment) => f.write_str(segment.as_str()),
+        }
+    }
+}
+
+/// The sizes of all storage components at a point in time.
+#[derive(Debug, Clone)]
+pub struct StorageSample {
+    /// When the sample was taken.
+    pub taken_at: Instant,
+    /// Size in bytes of each component.
+    pub sizes: BTreeMap<StorageComponent, u64>,
+}
+
+impl StorageSample {
+    /// Samples the sizes of all database tables and static file segments.
+    pub fn collect<N: NodeTypesWithDB>(factory: &ProviderFactory<N>) -> ProviderResult<Self> {
+        let mut sizes = BTreeMap::new();
+
+        for (name, value, labels) in factory.db_ref().gauge_metrics() {
+            if name != "db.table_size" {
+                continue
+            }
+            if let Some(table) = labels.iter().find(|label| label.key() == "table") {
+                sizes.insert(StorageComponent::Table(table.value().to_string()), value as u64);
+            }
+        }
+
+        for (segment, size) in factory.static_file_provider().segment_sizes()? {
+            sizes.insert(StorageComponent::Segment(segment), size);
+        }
+
+        Ok(Self { taken_at: Instant::now(), sizes })
+    }
+
+    /// Returns the total size in bytes of all components.
+    pub fn total_size(&self) -> u64 {
+        self.sizes.values().sum()
+    }
+}
+
+/// Keeps the storage samples taken within a time window and forecasts the storage growth from
+/// them.
+#[derive(Debug)]
+pub struct StorageGrowthTracker {
+    /// How long samples are kept for.
+    window: Duration,
+    /// Samples ordered from oldest to newest.
+    samples: VecDeque<StorageSample>,
+}
+
+impl StorageGrowthTracker {
+    /// The default window of one day, which smooths out bursts like pruning runs.
+    pub const DEFAULT_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
+    /// Creates a new tracker keeping samples for the given window.
+    pub const fn new(window: Duration) -> Self {
+        Self { window, samples: VecDeque::new() }
+    }
+
+    /// Records a sample and drops the ones that fell out of the window.
+    ///
+    /// The newest sample that fell out of the window is kept, so the forecast spans the full
+    /// window once enough samples were taken.
+    pub fn record(&mut self, sample: StorageSample) {
+        let taken_at = sample.taken_at;
+        self.samples.push_back(sample);
+        while self
+            .samples
+            .get(1)
+            .is_some_and(|sample| taken_at.duration_since(sample.taken_at) >= self.window)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Returns the number of recorded samples.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns `true` if no samples were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Forecasts the storage growth from the oldest and newest samples, with `available_space`
+    /// bytes left on the disk.
+    ///
+    /// Returns `None` if fewer than two samples were recorded.
+    pub fn forecast(&self, available_space: Option<u64>) -> Option<StorageGrowthForecast> {
+        let (first, last) = (self.samples.front()?, self.samples.back()?);
+        let elapsed = last.taken_at.duration_since(first.taken_at).as_secs_f64();
+        if elapsed == 0.0 {
+            return None
+        }
+
+        let rate = |first: u64, last: u64| (last as f64 - first as f64) / elapsed;
+        let growth_rates = last
+            .sizes
+            .iter()
+            .map(|(component, &size)| {
+                let previous = first.sizes.get(component).copied().unwrap_or_default();
+                (component.clone(), rate(previous, size))
+            })
+            .collect();
+        let total_growth_rate = rate(first.total_size(), last.total_size());
+
+        let exhaustion_eta = available_space
+            .filter(|_| total_growth_rate > 0.0)
+            .map(|available| Duration::from_secs_f64(available as f64 / total_growth_rate));
+
+        Some(StorageGrowthForecast {
+            total_size: last.total_size(),
+            growth_rates,
+            total_growth_rate,
+            available_space,
+            exhaustion_eta,
+        })
+    }
+}
+
+impl Default for StorageGrowthTracker {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_WINDOW)
+    }
+}
+
+/// Projected storage growth.
+#[derive(Debug, Clone)]
+pub struct StorageGrowthForecast {
+    /// Total size in bytes of all components in the newest sample.
+    pub total_size: u64,
+    /// Growth rate in bytes per second of each component.
+    pub growth_rates: BTreeMap<StorageComponent, f64>,
+    /// Growth rate in bytes per second of all components.
+    pub total_growth_rate: f64,
+    /// Bytes left on the disk holding the datadir, if known.
+    pub available_space: Option<u64>,
+    /// Time until the disk is full at the current growth rate, if it grows at all.
+    pub exhaustion_eta: Option<Duration>,
+}
+
+impl StorageGrowthForecast {
+    /// Returns `true` if the disk is projected to be full within the horizon.
+    pub fn exhausted_within(&self, horizon: Duration) -> bool {
+        self.exhaustion_eta.is_some_and(|eta| eta <= horizon)
+    }
+
+    /// Reports the forecast as metrics.
+    pub fn report_metrics(&self) {
+        for (component, rate) in &self.growth_rates {
+            gauge!(
+                "storage.growth.bytes_per_second",
+                "kind" => component.kind(),
+                "name" => component.to_string()
+            )
+            .set(*rate);
+        }
+        gauge!("storage.growth.total_bytes_per_second").set(self.total_growth_rate);
+        gauge!("storage.total_size").set(self.total_size as f64);
+
+        if let Some(available_space) = self.available_space {
+            gauge!("storage.disk_available_bytes").set(available_space as f64);
+        }
+        // A negative value means the storage doesn't grow.
+        gauge!("storage.disk_exhaustion_eta_seconds")
+            .set(self.exhaustion_eta.map_or(-1.0, |eta| eta.as_secs_f64()));
+    }
+}
+
+/// Returns the space available on the disk holding the path, if it can be determined.
+pub fn available_disk_space(path: &Path) -> Option<u64> {
+    let path = path.canonicalize().ok()?;
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| disk.available_space())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(taken_at: Instant, table: u64, segment: u64) -> StorageSample {
+        StorageSample {
+            taken_at,
+            sizes: BTreeMap::from([
+                (StorageComponent::Table("PlainAccountState".to_string()), table),
+                (StorageComponent::Segment(StaticFileSegment::Headers), segment),
+            ]),
+        }
+    }
+
+    #[test]
+    fn forecast() {
+        let start = Instant::now();
+        let mut tracker = StorageGrowthTracker::new(Duration::from_secs(100));
+        tracker.record(sample(start, 1_000, 1_000));
+        assert!(tracker.forecast(Some(10_000)).is_none());
+
+        tracker.record(sample(start + Duration::from_secs(10), 1_100, 1_000));
+        tracker.record(sample(start + Duration::from_secs(50), 1_500, 1_500));
+        let forecast = tracker.forecast(Some(10_000)).unwrap();
+        assert_eq!(forecast.total_size, 3_000);
+        assert_eq!(
+            forecast.growth_rates[&StorageComponent::Table("PlainAccountState".to_string())],
+            10.0
+        );
+        assert_eq!(
+            forecast.growth_rates[&StorageComponent::Segment(StaticFileSegment::Headers)],
+            10.0
+        );
+        assert_eq!(forecast.total_growth_rate, 20.0);
+        assert_eq!(forecast.exhaustion_eta, Some(Duration::from_secs(500)));
+        assert!(forecast.exhausted_within(Duration::from_secs(500)));
+        assert!(!forecast.exhausted_within(Duration::from_secs(499)));
+
+        // Samples older than the window are dropped, except for the newest of them.
+        tracker.record(sample(start + Duration::from_secs(160), 1_500, 1_500));
+        assert_eq!(tracker.len(), 2);
+        let forecast = tracker.forecast(Some(10_000)).unwrap();
+        assert_eq!(forecast.total_growth_rate, 0.0);
+        assert_eq!(forecast.exhaustion_eta, None);
+    }
+}
@@ -41,6 +41,11 @@ pub use either_writer::*;
 mod bal;
 pub use bal::{BalConfig, InMemoryBalStore};
 
+mod blob_sidecars;
+pub use blob_sidecars::BlobSidecarStage;
+
+pub mod growth;
+
 pub use reth_chain_state::{
     CanonStateNotification, CanonStateNotificationSender, CanonStateNotificationStream,
     CanonStateNotifications, CanonStateSubscriptions,
@@ -52,8 +57,10 @@ pub use revm::database::states::OriginalValuesKnown;
 pub use reth_static_file_types as static_file;
 pub use reth_storage_api::{
     BalNotification, BalNotificationStream, BalProvider, BalStore, BalStoreHandle,
-    GetBlockAccessListLimit, HistoryWriter, MetadataProvider, MetadataWriter, NoopBalStore, RawBal,
+    BlobSidecarProvider, BlobSidecarWriter, GetBlockAccessListLimit, HistoryWriter,
+    LogIndexProvider, LogIndexWriter, MetadataProvider, MetadataWriter, NoopBalStore, RawBal,
     StateWriteConfig, StatsReader, StorageSettings, StorageSettingsCache,
+    TraceAddressIndexProvider, TraceAddressIndexWriter,
 };
 /// Re-export provider error.
 pub use reth_storage_errors::provider::{ProviderError, ProviderResult};
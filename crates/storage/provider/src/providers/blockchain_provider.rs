@@ -1,7 +1,7 @@
 use crate::{
     providers::{
-        ConsistentProvider, ProviderNodeTypes, RocksDBProvider, StaticFileProvider,
-        StaticFileProviderRWRefMut,
+        ConsistentProvider, ProviderCache, ProviderCacheConfig, ProviderNodeTypes, RocksDBProvider,
+        StaticFileProvider, StaticFileProviderRWRefMut,
     },
     AccountReader, BalProvider, BalStoreHandle, BlockHashReader, BlockIdReader, BlockNumReader,
     BlockReader, BlockReaderIdExt, BlockSource, CanonChainTracker, CanonStateNotifications,
@@ -12,7 +12,10 @@ use crate::{
     StateReader, StaticFileProviderFactory, TransactionVariant, TransactionsProvider,
 };
 use alloy_consensus::transaction::TransactionMeta;
-use alloy_eips::{BlockHashOrNumber, BlockId, BlockNumHash, BlockNumberOrTag};
+use alloy_eips::{
+    eip7594::BlobTransactionSidecarVariant, BlockHashOrNumber, BlockId, BlockNumHash,
+    BlockNumberOrTag,
+};
 use alloy_primitives::{Address, BlockHash, BlockNumber, TxHash, TxNumber, B256};
 use alloy_rpc_types_engine::ForkchoiceState;
 use reth_chain_state::{
@@ -24,12 +27,16 @@ use reth_db_api::models::{AccountBeforeTx, BlockNumberAddress, StoredBlockBodyIn
 use reth_execution_types::ExecutionOutcome;
 use reth_node_types::{BlockTy, HeaderTy, NodeTypesWithDB, ReceiptTy, TxTy};
 use reth_primitives_traits::{
-    Account, RecoveredBlock, SealedHeader, SealedOrRecoveredBlock, StorageEntry,
+    Account, Block as _, BlockBody as _, RecoveredBlock, SealedHeader, SealedOrRecoveredBlock,
+    StorageEntry,
 };
 use reth_prune_types::{PruneCheckpoint, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_static_file_types::StaticFileSegment;
-use reth_storage_api::{BlockBodyIndicesProvider, NodePrimitivesProvider, StorageChangeSetReader};
+use reth_storage_api::{
+    BlobSidecarProvider, BlockBodyIndicesProvider, BlockChangeset, BlockChangesetReader,
+    LogIndexProvider, NodePrimitivesProvider, StorageChangeSetReader, TraceAddressIndexProvider,
+};
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::{HashedPostState, KeccakKeyHasher};
 use revm::database::BundleState;
@@ -54,6 +61,8 @@ pub struct BlockchainProvider<N: NodeTypesWithDB> {
     pub(crate) canonical_in_memory_state: CanonicalInMemoryState<N::Primitives>,
     /// Store for BALs associated with this provider view.
     pub(crate) bal_store: BalStoreHandle,
+    /// Cache of headers, blocks, receipts and latest state reads, if enabled.
+    pub(crate) cache: Option<Arc<ProviderCache<HeaderTy<N>, BlockTy<N>, ReceiptTy<N>>>>,
 }
 
 impl<N: NodeTypesWithDB> Clone for BlockchainProvider<N> {
@@ -62,6 +71,7 @@ impl<N: NodeTypesWithDB> Clone for BlockchainProvider<N> {
             database: self.database.clone(),
             canonical_in_memory_state: self.canonical_in_memory_state.clone(),
             bal_store: self.bal_store.clone(),
+            cache: self.cache.clone(),
         }
     }
 }
@@ -116,9 +126,36 @@ impl<N: ProviderNodeTypes> BlockchainProvider<N> {
                 safe_header,
             ),
             bal_store,
+            cache: None,
         })
     }
 
+    /// Enables caching of headers, blocks, receipts and reads of the latest state, bounded by
+    /// the config.
+    ///
+    /// [`ProviderCache::on_canon_state_notification`] must be called with every canonical state
+    /// notification of the provider, see [`Self::cache`].
+    pub fn with_cache(mut self, config: ProviderCacheConfig) -> Self {
+        self.cache = Some(Arc::new(ProviderCache::new(config)));
+        self
+    }
+
+    /// Returns the cache of the provider, if enabled.
+    pub const fn cache(
+        &self,
+    ) -> Option<&Arc<ProviderCache<HeaderTy<N>, BlockTy<N>, ReceiptTy<N>>>> {
+        self.cache.as_ref()
+    }
+
+    /// Returns the hash of the canonical block of the number, used to check that blocks cached by
+    /// number are still canonical.
+    fn canonical_hash(&self, number: BlockNumber) -> ProviderResult<Option<BlockHash>> {
+        match self.canonical_in_memory_state.hash_by_number(number) {
+            Some(hash) => Ok(Some(hash)),
+            None => self.database.block_hash(number),
+        }
+    }
+
     /// Gets a clone of `canonical_in_memory_state`.
     pub fn canonical_in_memory_state(&self) -> CanonicalInMemoryState<N::Primitives> {
         self.canonical_in_memory_state.clone()
@@ -199,11 +236,21 @@ impl<N: ProviderNodeTypes> HeaderProvider for BlockchainProvider<N> {
     type Header = HeaderTy<N>;
 
     fn header(&self, block_hash: BlockHash) -> ProviderResult<Option<Self::Header>> {
-        self.consistent_provider()?.header(block_hash)
+        let read = || self.consistent_provider()?.header(block_hash);
+        match &self.cache {
+            Some(cache) => {
+                cache.header(block_hash.into(), |number| self.canonical_hash(number), read)
+            }
+            None => read(),
+        }
     }
 
     fn header_by_number(&self, num: BlockNumber) -> ProviderResult<Option<Self::Header>> {
-        self.consistent_provider()?.header_by_number(num)
+        let read = || self.consistent_provider()?.header_by_number(num);
+        match &self.cache {
+            Some(cache) => cache.header(num.into(), |number| self.canonical_hash(number), read),
+            None => read(),
+        }
     }
 
     fn headers_range(
@@ -306,7 +353,11 @@ impl<N: ProviderNodeTypes> BlockReader for BlockchainProvider<N> {
     }
 
     fn block(&self, id: BlockHashOrNumber) -> ProviderResult<Option<Self::Block>> {
-        self.consistent_provider()?.block(id)
+        let read = || self.consistent_provider()?.block(id);
+        match &self.cache {
+            Some(cache) => cache.block(id, |number| self.canonical_hash(number), read),
+            None => read(),
+        }
     }
 
     fn pending_block(&self) -> ProviderResult<Option<RecoveredBlock<Self::Block>>> {
@@ -397,6 +448,12 @@ impl<N: ProviderNodeTypes> TransactionsProvider for BlockchainProvider<N> {
         &self,
         id: BlockHashOrNumber,
     ) -> ProviderResult<Option<Vec<Self::Transaction>>> {
+        if let Some(cache) = &self.cache &&
+            let Some(block) = cache.cached_block(id, |number| self.canonical_hash(number))?
+        {
+            return Ok(Some(block.body().transactions().to_vec()))
+        }
+
         self.consistent_provider()?.transactions_by_block(id)
     }
 
@@ -441,7 +498,11 @@ impl<N: ProviderNodeTypes> ReceiptProvider for BlockchainProvider<N> {
         &self,
         block: BlockHashOrNumber,
     ) -> ProviderResult<Option<Vec<Self::Receipt>>> {
-        self.consistent_provider()?.receipts_by_block(block)
+        let read = || self.consistent_provider()?.receipts_by_block(block);
+        match &self.cache {
+            Some(cache) => cache.receipts(block, |number| self.canonical_hash(number), read),
+            None => read(),
+        }
     }
 
     fn receipts_by_tx_range(
@@ -481,6 +542,58 @@ impl<N: ProviderNodeTypes> BlockBodyIndicesProvider for BlockchainProvider<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> BlobSidecarProvider for BlockchainProvider<N> {
+    fn blob_sidecars(
+        &self,
+        block: BlockNumber,
+    ) -> ProviderResult<Option<Vec<(TxHash, BlobTransactionSidecarVariant)>>> {
+        self.database.blob_sidecars(block)
+    }
+
+    fn blob_sidecar_by_versioned_hash(
+        &self,
+        versioned_hash: B256,
+    ) -> ProviderResult<Option<BlobTransactionSidecarVariant>> {
+        self.database.blob_sidecar_by_versioned_hash(versioned_hash)
+    }
+}
+
+impl<N: ProviderNodeTypes> TraceAddressIndexProvider for BlockchainProvider<N> {
+    fn trace_address_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.database.trace_address_index_range()
+    }
+
+    fn trace_address_blocks(
+        &self,
+        addresses: &[Address],
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.database.trace_address_blocks(addresses, range)
+    }
+}
+
+impl<N: ProviderNodeTypes> LogIndexProvider for BlockchainProvider<N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.database.log_index_range()
+    }
+
+    fn log_address_blocks(
+        &self,
+        addresses: &[Address],
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.database.log_address_blocks(addresses, range)
+    }
+
+    fn log_topic_blocks(
+        &self,
+        topics: &[B256],
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.database.log_topic_blocks(topics, range)
+    }
+}
+
 impl<N: ProviderNodeTypes> StageCheckpointReader for BlockchainProvider<N> {
     fn get_stage_checkpoint(&self, id: StageId) -> ProviderResult<Option<StageCheckpoint>> {
         self.consistent_provider()?.get_stage_checkpoint(id)
@@ -521,12 +634,17 @@ impl<N: ProviderNodeTypes> StateProviderFactory for BlockchainProvider<N> {
     fn latest(&self) -> ProviderResult<StateProviderBox> {
         trace!(target: "providers::blockchain", "Getting latest block state provider");
         // use latest state provider if the head state exists
-        if let Some(state) = self.canonical_in_memory_state.head_state() {
+        let (tip, state) = if let Some(state) = self.canonical_in_memory_state.head_state() {
             trace!(target: "providers::blockchain", "Using head state for latest state provider");
-            Ok(self.block_state_provider(&state)?.boxed())
+            (state.hash(), self.block_state_provider(&state)?.boxed())
         } else {
             trace!(target: "providers::blockchain", "Using database state for latest state provider");
-            self.database.latest()
+            (self.canonical_in_memory_state.chain_info().best_hash, self.database.latest()?)
+        };
+
+        match &self.cache {
+            Some(cache) => Ok(cache.latest_state(tip, state)),
+            None => Ok(state),
         }
     }
 
@@ -767,6 +885,19 @@ impl<N: ProviderNodeTypes> ChangeSetReader for BlockchainProvider<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> BlockChangesetReader for BlockchainProvider<N> {
+    fn block_changeset(&self, block_number: BlockNumber) -> ProviderResult<BlockChangeset> {
+        let provider = self.consistent_provider()?;
+        let accounts = provider.account_block_changeset(block_number)?;
+        let storage = provider.storage_block_changeset(block_number)?;
+        let hash = provider
+            .block_hash(block_number)?
+            .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+        let state = provider.into_state_provider_at_block_hash(hash)?;
+        BlockChangeset::from_changesets(block_number, accounts, storage, &*state)
+    }
+}
+
 impl<N: ProviderNodeTypes> AccountReader for BlockchainProvider<N> {
     /// Get basic account information.
     fn basic_account(&self, address: &Address) -> ProviderResult<Option<Account>> {
@@ -814,17 +945,18 @@ mod tests {
     };
     use reth_chainspec::{ChainSpec, MAINNET};
     use reth_db_api::models::{AccountBeforeTx, StoredBlockBodyIndices};
-    use reth_errors::ProviderError;
+    use reth_errors::{ProviderError, ProviderResult};
     use reth_ethereum_primitives::{Block, Receipt};
     use reth_execution_types::{
         BlockExecutionOutput, BlockExecutionResult, Chain, ExecutionOutcome,
     };
     use reth_primitives_traits::{RecoveredBlock, SealedBlock, SignerRecoverable};
     use reth_storage_api::{
-        BlockBodyIndicesProvider, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader,
-        BlockReaderIdExt, BlockSource, ChangeSetReader, DBProvider, DatabaseProviderFactory,
-        HeaderProvider, ReceiptProvider, ReceiptProviderIdExt, StateProviderFactory,
-        StateWriteConfig, StateWriter, TransactionVariant, TransactionsProvider,
+        AccountChange, BlockBodyIndicesProvider, BlockChangesetReader, BlockHashReader,
+        BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt, BlockSource, ChangeSetReader,
+        DBProvider, DatabaseProviderFactory, HeaderProvider, ReceiptProvider, ReceiptProviderIdExt,
+        StateProviderFactory, StateWriteConfig, StateWriter, TransactionVariant,
+        TransactionsProvider,
     };
     use reth_testing_utils::generators::{
         self, random_block, random_block_range, random_changeset_range, random_eoa_accounts,
@@ -1690,6 +1822,9 @@ mod tests {
             0..0,
         );
 
+        let database_accounts: BTreeMap<_, _> =
+            database_state.iter().map(|(address, (account, _))| (*address, *account)).collect();
+
         let factory = create_test_provider_factory();
 
         let provider_rw = factory.provider_rw()?;
@@ -1774,6 +1909,29 @@ mod tests {
                 .collect::<Vec<_>>()
         );
 
+        // The post values of the last database block are the final database state.
+        let changeset = provider.block_changeset(last_database_block)?;
+        assert_eq!(
+            changeset.accounts,
+            provider
+                .account_block_changeset(last_database_block)?
+                .into_iter()
+                .map(|account| AccountChange {
+                    address: account.address,
+                    pre: account.info,
+                    post: Some(database_accounts[&account.address]),
+                })
+                .collect::<Vec<_>>()
+        );
+        assert!(changeset.storage.is_empty());
+        assert_eq!(
+            provider
+                .block_changesets(first_database_block..=first_in_memory_block)
+                .map(|changeset| changeset.map(|changeset| changeset.block_number))
+                .collect::<ProviderResult<Vec<_>>>()?,
+            (first_database_block..=first_in_memory_block).collect::<Vec<_>>()
+        );
+
         Ok(())
     }
 
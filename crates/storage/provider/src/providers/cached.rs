@@ -0,0 +1,1096 @@
+use crate::{
+    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockSource,
+    HeaderProvider, ReceiptProvider, StateProviderBox, StateProviderFactory, TransactionVariant,
+    TransactionsProvider,
+};
+use alloy_consensus::{transaction::TransactionMeta, BlockHeader as _};
+use alloy_eips::{BlockHashOrNumber, BlockNumHash, BlockNumberOrTag};
+use alloy_primitives::{
+    Address, BlockHash, BlockNumber, Bytes, StorageKey, StorageValue, TxHash, TxNumber, B256,
+};
+use metrics::Counter;
+use parking_lot::Mutex;
+use reth_chain_state::CanonStateNotification;
+use reth_chainspec::ChainInfo;
+use reth_db_api::models::StoredBlockBodyIndices;
+use reth_metrics::Metrics;
+use reth_primitives_traits::{
+    Account, Block as _, BlockBody as _, Bytecode, NodePrimitives, RecoveredBlock, SealedHeader,
+    SealedOrRecoveredBlock,
+};
+use reth_storage_api::{
+    BlockBodyIndicesProvider, BytecodeReader, HashedPostStateProvider, StateProofProvider,
+    StateProvider, StateRootProvider, StorageRootProvider,
+};
+use reth_storage_errors::provider::ProviderResult;
+use reth_trie::{
+    updates::TrieUpdates, AccountProof, HashedPostState, HashedStorage, MultiProof,
+    MultiProofTargets, StorageMultiProof, StorageProof, TrieInput,
+};
+use revm::database::BundleState;
+use schnellru::{ByLength, LruMap};
+use std::{
+    ops::{RangeBounds, RangeInclusive},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+/// Size limits of the caches of a [`CachingProvider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProviderCacheConfig {
+    /// Maximum number of cached headers, per lookup key (hash and number).
+    pub max_headers: u32,
+    /// Maximum number of cached blocks, per lookup key (hash and number).
+    pub max_blocks: u32,
+    /// Maximum number of cached block receipts, per lookup key (hash and number).
+    pub max_receipts: u32,
+    /// Maximum number of cached accounts of the latest state.
+    pub max_accounts: u32,
+    /// Maximum number of cached storage slots of the latest state.
+    pub max_storage_slots: u32,
+}
+
+impl Default for ProviderCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_headers: 10_000,
+            max_blocks: 1_000,
+            max_receipts: 1_000,
+            max_accounts: 100_000,
+            max_storage_slots: 500_000,
+        }
+    }
+}
+
+/// Hit and miss counters of a single cache of a [`CachingProvider`].
+#[derive(Metrics, Clone)]
+#[metrics(scope = "storage.providers.cache")]
+struct ProviderCacheMetrics {
+    /// Number of lookups served from the cache.
+    hits: Counter,
+    /// Number of lookups that had to read the underlying provider.
+    misses: Counter,
+}
+
+impl ProviderCacheMetrics {
+    fn for_cache(cache: &'static str) -> Self {
+        Self::new_with_labels(&[("cache", cache)])
+    }
+
+    fn record(&self, hit: bool) {
+        if hit {
+            self.hits.increment(1);
+        } else {
+            self.misses.increment(1);
+        }
+    }
+}
+
+/// LRU cache of per-block data, looked up by block hash or number.
+struct BlockCache<V> {
+    by_hash: LruMap<BlockHash, V, ByLength>,
+    /// Entries by number, with the hash of the block they were read for.
+    by_number: LruMap<BlockNumber, (BlockHash, V), ByLength>,
+}
+
+impl<V: Clone> BlockCache<V> {
+    fn new(max_len: u32) -> Self {
+        Self {
+            by_hash: LruMap::new(ByLength::new(max_len)),
+            by_number: LruMap::new(ByLength::new(max_len)),
+        }
+    }
+
+    fn remove(&mut self, number: BlockNumber, hash: BlockHash) {
+        self.by_number.remove(&number);
+        self.by_hash.remove(&hash);
+    }
+
+    fn clear(&mut self) {
+        self.by_hash.clear();
+        self.by_number.clear();
+    }
+}
+
+/// LRU cache of accounts and storage slots of the latest state.
+struct StateCache {
+    /// The block the cached values belong to.
+    tip: Option<BlockHash>,
+    accounts: LruMap<Address, Option<Account>, ByLength>,
+    storage: LruMap<(Address, StorageKey), Option<StorageValue>, ByLength>,
+}
+
+impl StateCache {
+    fn clear(&mut self, tip: Option<BlockHash>) {
+        self.tip = tip;
+        self.accounts.clear();
+        self.storage.clear();
+    }
+}
+
+/// Bounded in-memory caches of headers, blocks, receipts and reads of the latest state.
+///
+/// Used by [`CachingProvider`], and by the
+/// [`BlockchainProvider`](crate::providers::BlockchainProvider) if enabled. Entries of reorged
+/// blocks and changed state are invalidated by [`ProviderCache::on_canon_state_notification`],
+/// which must be called for every canonical state notification.
+///
+/// Because notifications are handled asynchronously, entries cached by number are only returned
+/// if they belong to the current canonical block of that number, as returned by the
+/// `canonical_hash` lookup passed to the getters.
+pub struct ProviderCache<H, B, R> {
+    headers: Mutex<BlockCache<H>>,
+    blocks: Mutex<BlockCache<B>>,
+    receipts: Mutex<BlockCache<Vec<R>>>,
+    state: Mutex<StateCache>,
+    /// Incremented whenever block entries are invalidated, so that values read before the
+    /// invalidation are not cached afterwards.
+    generation: AtomicU64,
+    header_metrics: ProviderCacheMetrics,
+    block_metrics: ProviderCacheMetrics,
+    receipt_metrics: ProviderCacheMetrics,
+    account_metrics: ProviderCacheMetrics,
+    storage_metrics: ProviderCacheMetrics,
+}
+
+impl<H, B, R> core::fmt::Debug for ProviderCache<H, B, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ProviderCache")
+            .field("generation", &self.generation.load(Ordering::Relaxed))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<H, B, R> ProviderCache<H, B, R>
+where
+    H: Clone,
+    B: Clone,
+    R: Clone,
+{
+    /// Creates new empty caches, bounded by the config.
+    pub fn new(config: ProviderCacheConfig) -> Self {
+        Self {
+            headers: Mutex::new(BlockCache::new(config.max_headers)),
+            blocks: Mutex::new(BlockCache::new(config.max_blocks)),
+            receipts: Mutex::new(BlockCache::new(config.max_receipts)),
+            state: Mutex::new(StateCache {
+                tip: None,
+                accounts: LruMap::new(ByLength::new(config.max_accounts)),
+                storage: LruMap::new(ByLength::new(config.max_storage_slots)),
+            }),
+            generation: AtomicU64::new(0),
+            header_metrics: ProviderCacheMetrics::for_cache("headers"),
+            block_metrics: ProviderCacheMetrics::for_cache("blocks"),
+            receipt_metrics: ProviderCacheMetrics::for_cache("receipts"),
+            account_metrics: ProviderCacheMetrics::for_cache("accounts"),
+            storage_metrics: ProviderCacheMetrics::for_cache("storage"),
+        }
+    }
+
+    /// Returns the cached header, or reads it with `read` and caches it if it exists.
+    ///
+    /// `canonical_hash` returns the hash of the canonical block of a number.
+    pub fn header(
+        &self,
+        id: BlockHashOrNumber,
+        canonical_hash: impl Fn(BlockNumber) -> ProviderResult<Option<BlockHash>>,
+        read: impl FnOnce() -> ProviderResult<Option<H>>,
+    ) -> ProviderResult<Option<H>> {
+        self.get_or_read(&self.headers, &self.header_metrics, id, canonical_hash, read)
+    }
+
+    /// Returns the cached block, or reads it with `read` and caches it if it exists.
+    ///
+    /// `canonical_hash` returns the hash of the canonical block of a number.
+    pub fn block(
+        &self,
+        id: BlockHashOrNumber,
+        canonical_hash: impl Fn(BlockNumber) -> ProviderResult<Option<BlockHash>>,
+        read: impl FnOnce() -> ProviderResult<Option<B>>,
+    ) -> ProviderResult<Option<B>> {
+        self.get_or_read(&self.blocks, &self.block_metrics, id, canonical_hash, read)
+    }
+
+    /// Returns the cached block, without reading it if it's missing.
+    ///
+    /// `canonical_hash` returns the hash of the canonical block of a number.
+    pub fn cached_block(
+        &self,
+        id: BlockHashOrNumber,
+        canonical_hash: impl Fn(BlockNumber) -> ProviderResult<Option<BlockHash>>,
+    ) -> ProviderResult<Option<B>> {
+        Self::get(&self.blocks, id, canonical_hash)
+    }
+
+    /// Returns the cached receipts of the block, or reads them with `read` and caches them if
+    /// they exist.
+    ///
+    /// `canonical_hash` returns the hash of the canonical block of a number.
+    pub fn receipts(
+        &self,
+        id: BlockHashOrNumber,
+        canonical_hash: impl Fn(BlockNumber) -> ProviderResult<Option<BlockHash>>,
+        read: impl FnOnce() -> ProviderResult<Option<Vec<R>>>,
+    ) -> ProviderResult<Option<Vec<R>>> {
+        self.get_or_read(&self.receipts, &self.receipt_metrics, id, canonical_hash, read)
+    }
+
+    /// Returns the cached value of `id`, if entries cached by number still belong to the
+    /// canonical block.
+    fn get<V: Clone>(
+        cache: &Mutex<BlockCache<V>>,
+        id: BlockHashOrNumber,
+        canonical_hash: impl Fn(BlockNumber) -> ProviderResult<Option<BlockHash>>,
+    ) -> ProviderResult<Option<V>> {
+        match id {
+            BlockHashOrNumber::Hash(hash) => Ok(cache.lock().by_hash.get(&hash).cloned()),
+            BlockHashOrNumber::Number(number) => {
+                let Some((hash, value)) = cache.lock().by_number.get(&number).cloned() else {
+                    return Ok(None)
+                };
+                // the block may have been reorged before the entry was invalidated
+                if canonical_hash(number)? != Some(hash) {
+                    cache.lock().by_number.remove(&number);
+                    return Ok(None)
+                }
+                Ok(Some(value))
+            }
+        }
+    }
+
+    /// Returns the value of `id` from `cache`, or reads it with `read` and caches it if it exists.
+    fn get_or_read<V: Clone>(
+        &self,
+        cache: &Mutex<BlockCache<V>>,
+        metrics: &ProviderCacheMetrics,
+        id: BlockHashOrNumber,
+        canonical_hash: impl Fn(BlockNumber) -> ProviderResult<Option<BlockHash>>,
+        read: impl FnOnce() -> ProviderResult<Option<V>>,
+    ) -> ProviderResult<Option<V>> {
+        if let Some(value) = Self::get(cache, id, &canonical_hash)? {
+            metrics.record(true);
+            return Ok(Some(value))
+        }
+
+        metrics.record(false);
+        let generation = self.generation.load(Ordering::Acquire);
+        match id {
+            BlockHashOrNumber::Hash(hash) => {
+                let value = read()?;
+                if let Some(value) = &value {
+                    let mut cache = cache.lock();
+                    // the value may belong to a block that was reorged while it was read
+                    if self.generation.load(Ordering::Acquire) == generation {
+                        cache.by_hash.insert(hash, value.clone());
+                    }
+                }
+                Ok(value)
+            }
+            BlockHashOrNumber::Number(number) => {
+                let hash = canonical_hash(number)?;
+                let value = read()?;
+                // the canonical block of the number may have changed while the value was read
+                if let (Some(hash), Some(value)) = (hash, &value) &&
+                    matches!(canonical_hash(number), Ok(Some(current)) if current == hash)
+                {
+                    let mut cache = cache.lock();
+                    if self.generation.load(Ordering::Acquire) == generation {
+                        cache.by_number.insert(number, (hash, value.clone()));
+                    }
+                }
+                Ok(value)
+            }
+        }
+    }
+
+    /// Removes all cached entries.
+    pub fn clear(&self) {
+        let mut headers = self.headers.lock();
+        let mut blocks = self.blocks.lock();
+        let mut receipts = self.receipts.lock();
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        headers.clear();
+        blocks.clear();
+        receipts.clear();
+        drop((headers, blocks, receipts));
+
+        self.state.lock().clear(None);
+    }
+
+    /// Invalidates the cached entries affected by a canonical state change.
+    ///
+    /// The entries of reverted blocks are removed, as well as the cached accounts and storage
+    /// slots changed by the committed blocks.
+    pub fn on_canon_state_notification<N>(&self, notification: &CanonStateNotification<N>)
+    where
+        N: NodePrimitives,
+    {
+        let committed = notification.committed();
+        let tip = committed.tip().hash();
+
+        if let Some(reverted) = notification.reverted() {
+            let mut headers = self.headers.lock();
+            let mut blocks = self.blocks.lock();
+            let mut receipts = self.receipts.lock();
+            self.generation.fetch_add(1, Ordering::AcqRel);
+            for block in reverted.blocks_iter() {
+                headers.remove(block.number(), block.hash());
+                blocks.remove(block.number(), block.hash());
+                receipts.remove(block.number(), block.hash());
+            }
+            drop((headers, blocks, receipts));
+
+            self.state.lock().clear(Some(tip));
+            return
+        }
+
+        let mut state = self.state.lock();
+        if state.tip != Some(committed.first().parent_hash()) {
+            // The cached state is not the parent of the committed blocks.
+            state.clear(Some(tip));
+            return
+        }
+
+        state.tip = Some(tip);
+        for (address, account) in committed.execution_outcome().bundle.state() {
+            state.accounts.remove(address);
+            for key in account.storage.keys() {
+                state.storage.remove(&(*address, StorageKey::from(*key)));
+            }
+        }
+    }
+}
+
+impl<H, B, R> ProviderCache<H, B, R>
+where
+    H: Send + Sync + 'static,
+    B: Send + Sync + 'static,
+    R: Send + Sync + 'static,
+{
+    /// Wraps the latest state, the state after the block `tip`, caching its account and storage
+    /// reads.
+    pub fn latest_state(
+        self: &Arc<Self>,
+        tip: BlockHash,
+        state: StateProviderBox,
+    ) -> StateProviderBox {
+        Box::new(CachedLatestStateProvider { inner: state, tip, caches: self.clone() })
+    }
+}
+
+/// A provider that caches headers, blocks, receipts and reads of the latest state of another
+/// provider in memory.
+///
+/// The caches are shared between clones of the provider, and bounded by the
+/// [`ProviderCacheConfig`]. Entries of reorged blocks and changed state are invalidated by
+/// [`CachingProvider::on_canon_state_notification`], which must be called for every canonical
+/// state notification.
+pub struct CachingProvider<P: BlockReader> {
+    /// The underlying provider.
+    inner: P,
+    /// The caches, shared between clones.
+    caches: Arc<ProviderCache<P::Header, P::Block, P::Receipt>>,
+}
+
+impl<P: BlockReader + core::fmt::Debug> core::fmt::Debug for CachingProvider<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CachingProvider").field("inner", &self.inner).finish_non_exhaustive()
+    }
+}
+
+impl<P: BlockReader + Clone> Clone for CachingProvider<P> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone(), caches: self.caches.clone() }
+    }
+}
+
+impl<P: BlockReader> CachingProvider<P> {
+    /// Creates a new caching provider on top of `inner`.
+    pub fn new(inner: P, config: ProviderCacheConfig) -> Self {
+        Self { inner, caches: Arc::new(ProviderCache::new(config)) }
+    }
+
+    /// Returns the underlying provider.
+    pub const fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns the caches of the provider.
+    pub const fn cache(&self) -> &Arc<ProviderCache<P::Header, P::Block, P::Receipt>> {
+        &self.caches
+    }
+
+    /// Removes all cached entries.
+    pub fn clear(&self) {
+        self.caches.clear();
+    }
+
+    /// Invalidates the cached entries affected by a canonical state change, see
+    /// [`ProviderCache::on_canon_state_notification`].
+    pub fn on_canon_state_notification<N>(&self, notification: &CanonStateNotification<N>)
+    where
+        N: NodePrimitives,
+    {
+        self.caches.on_canon_state_notification(notification)
+    }
+}
+
+impl<P: BlockReader> BlockHashReader for CachingProvider<P> {
+    fn block_hash(&self, number: BlockNumber) -> ProviderResult<Option<B256>> {
+        self.inner.block_hash(number)
+    }
+
+    fn canonical_hashes_range(
+        &self,
+        start: BlockNumber,
+        end: BlockNumber,
+    ) -> ProviderResult<Vec<B256>> {
+        self.inner.canonical_hashes_range(start, end)
+    }
+}
+
+impl<P: BlockReader> BlockNumReader for CachingProvider<P> {
+    fn chain_info(&self) -> ProviderResult<ChainInfo> {
+        self.inner.chain_info()
+    }
+
+    fn best_block_number(&self) -> ProviderResult<BlockNumber> {
+        self.inner.best_block_number()
+    }
+
+    fn last_block_number(&self) -> ProviderResult<BlockNumber> {
+        self.inner.last_block_number()
+    }
+
+    fn earliest_block_number(&self) -> ProviderResult<BlockNumber> {
+        self.inner.earliest_block_number()
+    }
+
+    fn block_number(&self, hash: B256) -> ProviderResult<Option<BlockNumber>> {
+        self.inner.block_number(hash)
+    }
+}
+
+impl<P: BlockReader + BlockIdReader> BlockIdReader for CachingProvider<P> {
+    fn pending_block_num_hash(&self) -> ProviderResult<Option<BlockNumHash>> {
+        self.inner.pending_block_num_hash()
+    }
+
+    fn safe_block_num_hash(&self) -> ProviderResult<Option<BlockNumHash>> {
+        self.inner.safe_block_num_hash()
+    }
+
+    fn finalized_block_num_hash(&self) -> ProviderResult<Option<BlockNumHash>> {
+        self.inner.finalized_block_num_hash()
+    }
+}
+
+impl<P: BlockReader> HeaderProvider for CachingProvider<P> {
+    type Header = P::Header;
+
+    fn header(&self, block_hash: BlockHash) -> ProviderResult<Option<Self::Header>> {
+        self.caches.header(
+            block_hash.into(),
+            |number| self.inner.block_hash(number),
+            || self.inner.header(block_hash),
+        )
+    }
+
+    fn header_by_number(&self, num: u64) -> ProviderResult<Option<Self::Header>> {
+        self.caches.header(
+            num.into(),
+            |number| self.inner.block_hash(number),
+            || self.inner.header_by_number(num),
+        )
+    }
+
+    fn headers_range(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> ProviderResult<Vec<Self::Header>> {
+        self.inner.headers_range(range)
+    }
+
+    fn sealed_header(
+        &self,
+        number: BlockNumber,
+    ) -> ProviderResult<Option<SealedHeader<Self::Header>>> {
+        self.inner.sealed_header(number)
+    }
+
+    fn sealed_headers_while(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+        predicate: impl FnMut(&SealedHeader<Self::Header>) -> bool,
+    ) -> ProviderResult<Vec<SealedHeader<Self::Header>>> {
+        self.inner.sealed_headers_while(range, predicate)
+    }
+}
+
+impl<P: BlockReader> BlockBodyIndicesProvider for CachingProvider<P> {
+    fn block_body_indices(&self, num: u64) -> ProviderResult<Option<StoredBlockBodyIndices>> {
+        self.inner.block_body_indices(num)
+    }
+
+    fn block_body_indices_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<StoredBlockBodyIndices>> {
+        self.inner.block_body_indices_range(range)
+    }
+}
+
+impl<P: BlockReader> TransactionsProvider for CachingProvider<P> {
+    type Transaction = P::Transaction;
+
+    fn transaction_id(&self, tx_hash: TxHash) -> ProviderResult<Option<TxNumber>> {
+        self.inner.transaction_id(tx_hash)
+    }
+
+    fn transaction_by_id(&self, id: TxNumber) -> ProviderResult<Option<Self::Transaction>> {
+        self.inner.transaction_by_id(id)
+    }
+
+    fn transaction_by_id_unhashed(
+        &self,
+        id: TxNumber,
+    ) -> ProviderResult<Option<Self::Transaction>> {
+        self.inner.transaction_by_id_unhashed(id)
+    }
+
+    fn transaction_by_hash(&self, hash: TxHash) -> ProviderResult<Option<Self::Transaction>> {
+        self.inner.transaction_by_hash(hash)
+    }
+
+    fn transaction_by_hash_with_meta(
+        &self,
+        hash: TxHash,
+    ) -> ProviderResult<Option<(Self::Transaction, TransactionMeta)>> {
+        self.inner.transaction_by_hash_with_meta(hash)
+    }
+
+    fn transactions_by_block(
+        &self,
+        block: BlockHashOrNumber,
+    ) -> ProviderResult<Option<Vec<Self::Transaction>>> {
+        if let Some(block) =
+            self.caches.cached_block(block, |number| self.inner.block_hash(number))?
+        {
+            return Ok(Some(block.body().transactions().to_vec()))
+        }
+
+        self.inner.transactions_by_block(block)
+    }
+
+    fn transactions_by_block_range(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> ProviderResult<Vec<Vec<Self::Transaction>>> {
+        self.inner.transactions_by_block_range(range)
+    }
+
+    fn transactions_by_tx_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> ProviderResult<Vec<Self::Transaction>> {
+        self.inner.transactions_by_tx_range(range)
+    }
+
+    fn senders_by_tx_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> ProviderResult<Vec<Address>> {
+        self.inner.senders_by_tx_range(range)
+    }
+
+    fn transaction_sender(&self, id: TxNumber) -> ProviderResult<Option<Address>> {
+        self.inner.transaction_sender(id)
+    }
+}
+
+impl<P: BlockReader> ReceiptProvider for CachingProvider<P> {
+    type Receipt = P::Receipt;
+
+    fn receipt(&self, id: TxNumber) -> ProviderResult<Option<Self::Receipt>> {
+        self.inner.receipt(id)
+    }
+
+    fn receipt_by_hash(&self, hash: TxHash) -> ProviderResult<Option<Self::Receipt>> {
+        self.inner.receipt_by_hash(hash)
+    }
+
+    fn receipts_by_block(
+        &self,
+        block: BlockHashOrNumber,
+    ) -> ProviderResult<Option<Vec<Self::Receipt>>> {
+        self.caches.receipts(
+            block,
+            |number| self.inner.block_hash(number),
+            || self.inner.receipts_by_block(block),
+        )
+    }
+
+    fn receipts_by_tx_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> ProviderResult<Vec<Self::Receipt>> {
+        self.inner.receipts_by_tx_range(range)
+    }
+
+    fn receipts_by_block_range(
+        &self,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<Vec<Self::Receipt>>> {
+        self.inner.receipts_by_block_range(block_range)
+    }
+}
+
+impl<P: BlockReader> BlockReader for CachingProvider<P> {
+    type Block = P::Block;
+
+    fn find_block_by_hash(
+        &self,
+        hash: B256,
+        source: BlockSource,
+    ) -> ProviderResult<Option<Self::Block>> {
+        self.inner.find_block_by_hash(hash, source)
+    }
+
+    fn find_sealed_or_recovered_block(
+        &self,
+        hash: B256,
+        source: BlockSource,
+    ) -> ProviderResult<Option<SealedOrRecoveredBlock<Self::Block>>> {
+        self.inner.find_sealed_or_recovered_block(hash, source)
+    }
+
+    fn block(&self, id: BlockHashOrNumber) -> ProviderResult<Option<Self::Block>> {
+        self.caches.block(id, |number| self.inner.block_hash(number), || self.inner.block(id))
+    }
+
+    fn pending_block(&self) -> ProviderResult<Option<RecoveredBlock<Self::Block>>> {
+        self.inner.pending_block()
+    }
+
+    fn pending_block_and_receipts(
+        &self,
+    ) -> ProviderResult<Option<(RecoveredBlock<Self::Block>, Vec<Self::Receipt>)>> {
+        self.inner.pending_block_and_receipts()
+    }
+
+    fn recovered_block(
+        &self,
+        id: BlockHashOrNumber,
+        transaction_kind: TransactionVariant,
+    ) -> ProviderResult<Option<RecoveredBlock<Self::Block>>> {
+        self.inner.recovered_block(id, transaction_kind)
+    }
+
+    fn sealed_block_with_senders(
+        &self,
+        id: BlockHashOrNumber,
+        transaction_kind: TransactionVariant,
+    ) -> ProviderResult<Option<RecoveredBlock<Self::Block>>> {
+        self.inner.sealed_block_with_senders(id, transaction_kind)
+    }
+
+    fn block_range(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<Vec<Self::Block>> {
+        self.inner.block_range(range)
+    }
+
+    fn block_with_senders_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<RecoveredBlock<Self::Block>>> {
+        self.inner.block_with_senders_range(range)
+    }
+
+    fn recovered_block_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<RecoveredBlock<Self::Block>>> {
+        self.inner.recovered_block_range(range)
+    }
+
+    fn block_by_transaction_id(&self, id: TxNumber) -> ProviderResult<Option<BlockNumber>> {
+        self.inner.block_by_transaction_id(id)
+    }
+}
+
+impl<P> StateProviderFactory for CachingProvider<P>
+where
+    P: BlockReader + StateProviderFactory,
+    P::Header: 'static,
+    P::Block: 'static,
+    P::Receipt: 'static,
+{
+    /// Returns the latest state, with cached account and storage reads.
+    fn latest(&self) -> ProviderResult<StateProviderBox> {
+        let tip = self.inner.chain_info()?.best_hash;
+        let state = self.inner.latest()?;
+        Ok(self.caches.latest_state(tip, state))
+    }
+
+    fn state_by_block_number_or_tag(
+        &self,
+        number_or_tag: BlockNumberOrTag,
+    ) -> ProviderResult<StateProviderBox> {
+        match number_or_tag {
+            BlockNumberOrTag::Latest => self.latest(),
+            number_or_tag => self.inner.state_by_block_number_or_tag(number_or_tag),
+        }
+    }
+
+    fn history_by_block_number(&self, block: BlockNumber) -> ProviderResult<StateProviderBox> {
+        self.inner.history_by_block_number(block)
+    }
+
+    fn history_by_block_hash(&self, block: BlockHash) -> ProviderResult<StateProviderBox> {
+        self.inner.history_by_block_hash(block)
+    }
+
+    fn state_by_block_hash(&self, block: BlockHash) -> ProviderResult<StateProviderBox> {
+        self.inner.state_by_block_hash(block)
+    }
+
+    fn pending(&self) -> ProviderResult<StateProviderBox> {
+        self.inner.pending()
+    }
+
+    fn pending_state_by_hash(&self, block_hash: B256) -> ProviderResult<Option<StateProviderBox>> {
+        self.inner.pending_state_by_hash(block_hash)
+    }
+
+    fn maybe_pending(&self) -> ProviderResult<Option<StateProviderBox>> {
+        self.inner.maybe_pending()
+    }
+}
+
+/// The latest state of a [`CachingProvider`], caching account and storage reads.
+///
+/// The cache is only used while it belongs to the same block as this state.
+struct CachedLatestStateProvider<H, B, R> {
+    inner: StateProviderBox,
+    /// The block of this state.
+    tip: BlockHash,
+    caches: Arc<ProviderCache<H, B, R>>,
+}
+
+impl<H, B, R> core::fmt::Debug for CachedLatestStateProvider<H, B, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CachedLatestStateProvider").field("tip", &self.tip).finish_non_exhaustive()
+    }
+}
+
+impl<H: Send + Sync, B: Send + Sync, R: Send + Sync> AccountReader
+    for CachedLatestStateProvider<H, B, R>
+{
+    fn basic_account(&self, address: &Address) -> ProviderResult<Option<Account>> {
+        {
+            let mut state = self.caches.state.lock();
+            if state.tip == Some(self.tip) &&
+                let Some(account) = state.accounts.get(address)
+            {
+                self.caches.account_metrics.record(true);
+                return Ok(*account)
+            }
+        }
+
+        self.caches.account_metrics.record(false);
+        let account = self.inner.basic_account(address)?;
+        let mut state = self.caches.state.lock();
+        if state.tip.is_none() {
+            state.tip = Some(self.tip);
+        }
+        if state.tip == Some(self.tip) {
+            state.accounts.insert(*address, account);
+        }
+        Ok(account)
+    }
+}
+
+impl<H: Send + Sync, B: Send + Sync, R: Send + Sync> StateProvider
+    for CachedLatestStateProvider<H, B, R>
+{
+    fn storage(
+        &self,
+        account: Address,
+        storage_key: StorageKey,
+    ) -> ProviderResult<Option<StorageValue>> {
+        {
+            let mut state = self.caches.state.lock();
+            if state.tip == Some(self.tip) &&
+                let Some(value) = state.storage.get(&(account, storage_key))
+            {
+                self.caches.storage_metrics.record(true);
+                return Ok(*value)
+            }
+        }
+
+        self.caches.storage_metrics.record(false);
+        let value = self.inner.storage(account, storage_key)?;
+        let mut state = self.caches.state.lock();
+        if state.tip.is_none() {
+            state.tip = Some(self.tip);
+        }
+        if state.tip == Some(self.tip) {
+            state.storage.insert((account, storage_key), value);
+        }
+        Ok(value)
+    }
+}
+
+impl<H: Send + Sync, B: Send + Sync, R: Send + Sync> BlockHashReader
+    for CachedLatestStateProvider<H, B, R>
+{
+    fn block_hash(&self, number: BlockNumber) -> ProviderResult<Option<B256>> {
+        self.inner.block_hash(number)
+    }
+
+    fn canonical_hashes_range(
+        &self,
+        start: BlockNumber,
+        end: BlockNumber,
+    ) -> ProviderResult<Vec<B256>> {
+        self.inner.canonical_hashes_range(start, end)
+    }
+}
+
+impl<H: Send + Sync, B: Send + Sync, R: Send + Sync> BytecodeReader
+    for CachedLatestStateProvider<H, B, R>
+{
+    fn bytecode_by_hash(&self, code_hash: &B256) -> ProviderResult<Option<Bytecode>> {
+        self.inner.bytecode_by_hash(code_hash)
+    }
+}
+
+impl<H: Send + Sync, B: Send + Sync, R: Send + Sync> StateRootProvider
+    for CachedLatestStateProvider<H, B, R>
+{
+    fn state_root(&self, state: HashedPostState) -> ProviderResult<B256> {
+        self.inner.state_root(state)
+    }
+
+    fn state_root_from_nodes(&self, input: TrieInput) -> ProviderResult<B256> {
+        self.inner.state_root_from_nodes(input)
+    }
+
+    fn state_root_with_updates(
+        &self,
+        state: HashedPostState,
+    ) -> ProviderResult<(B256, TrieUpdates)> {
+        self.inner.state_root_with_updates(state)
+    }
+
+    fn state_root_from_nodes_with_updates(
+        &self,
+        input: TrieInput,
+    ) -> ProviderResult<(B256, TrieUpdates)> {
+        self.inner.state_root_from_nodes_with_updates(input)
+    }
+}
+
+impl<H: Send + Sync, B: Send + Sync, R: Send + Sync> StorageRootProvider
+    for CachedLatestStateProvider<H, B, R>
+{
+    fn storage_root(&self, address: Address, storage: HashedStorage) -> ProviderResult<B256> {
+        self.inner.storage_root(address, storage)
+    }
+
+    fn storage_proof(
+        &self,
+        address: Address,
+        slot: B256,
+        storage: HashedStorage,
+    ) -> ProviderResult<StorageProof> {
+        self.inner.storage_proof(address, slot, storage)
+    }
+
+    fn storage_multiproof(
+        &self,
+        address: Address,
+        slots: &[B256],
+        storage: HashedStorage,
+    ) -> ProviderResult<StorageMultiProof> {
+        self.inner.storage_multiproof(address, slots, storage)
+    }
+}
+
+impl<H: Send + Sync, B: Send + Sync, R: Send + Sync> StateProofProvider
+    for CachedLatestStateProvider<H, B, R>
+{
+    fn proof(
+        &self,
+        input: TrieInput,
+        address: Address,
+        slots: &[B256],
+    ) -> ProviderResult<AccountProof> {
+        self.inner.proof(input, address, slots)
+    }
+
+    fn multiproof(
+        &self,
+        input: TrieInput,
+        targets: MultiProofTargets,
+    ) -> ProviderResult<MultiProof> {
+        self.inner.multiproof(input, targets)
+    }
+
+    fn witness(
+        &self,
+        input: TrieInput,
+        target: HashedPostState,
+        mode: reth_trie::ExecutionWitnessMode,
+    ) -> ProviderResult<Vec<Bytes>> {
+        self.inner.witness(input, target, mode)
+    }
+
+    fn proof_input(&self) -> ProviderResult<Option<(B256, TrieInput)>> {
+        self.inner.proof_input()
+    }
+}
+
+impl<H: Send + Sync, B: Send + Sync, R: Send + Sync> HashedPostStateProvider
+    for CachedLatestStateProvider<H, B, R>
+{
+    fn hashed_post_state(&self, bundle_state: &BundleState) -> HashedPostState {
+        self.inner.hashed_post_state(bundle_state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockEthProvider;
+    use reth_ethereum_primitives::{Block, EthPrimitives};
+    use reth_execution_types::{Chain, ExecutionOutcome};
+    use reth_primitives_traits::SealedBlock;
+    use reth_testing_utils::generators::{self, random_block, BlockParams};
+
+    #[test]
+    fn caches_blocks_and_headers() {
+        let mut rng = generators::rng();
+        let provider: MockEthProvider = MockEthProvider::default();
+        let block =
+            random_block(&mut rng, 1, BlockParams { tx_count: Some(0), ..Default::default() });
+        provider.add_block(block.hash(), block.clone().into_block());
+
+        let cached = CachingProvider::new(provider.clone(), ProviderCacheConfig::default());
+        assert_eq!(cached.header(block.hash()).unwrap().as_ref(), Some(block.header()));
+        assert_eq!(cached.block(1.into()).unwrap(), Some(block.clone().into_block()));
+
+        // Cached entries are served without reading the underlying provider.
+        provider.blocks.lock().clear();
+        assert_eq!(cached.block(1.into()).unwrap(), Some(block.clone().into_block()));
+        provider.headers.lock().clear();
+        assert_eq!(cached.header(block.hash()).unwrap().as_ref(), Some(block.header()));
+        assert_eq!(cached.header_by_number(2).unwrap(), None);
+
+        // Entries cached by number are only served while the block is canonical.
+        assert_eq!(cached.block(1.into()).unwrap(), None);
+
+        cached.clear();
+        assert_eq!(cached.header(block.hash()).unwrap(), None);
+    }
+
+    #[test]
+    fn does_not_serve_reorged_blocks_by_number() {
+        let mut rng = generators::rng();
+        let provider: MockEthProvider = MockEthProvider::default();
+        let old =
+            random_block(&mut rng, 1, BlockParams { tx_count: Some(0), ..Default::default() });
+        let new =
+            random_block(&mut rng, 1, BlockParams { tx_count: Some(0), ..Default::default() });
+        provider.add_block(old.hash(), old.clone().into_block());
+
+        let cached = CachingProvider::new(provider.clone(), ProviderCacheConfig::default());
+        assert_eq!(cached.header_by_number(1).unwrap().as_ref(), Some(old.header()));
+        assert_eq!(cached.block(1.into()).unwrap(), Some(old.into_block()));
+
+        // the reorg is not notified yet
+        provider.blocks.lock().clear();
+        provider.headers.lock().clear();
+        provider.add_block(new.hash(), new.clone().into_block());
+
+        assert_eq!(cached.header_by_number(1).unwrap().as_ref(), Some(new.header()));
+        assert_eq!(cached.block(1.into()).unwrap(), Some(new.clone().into_block()));
+        assert_eq!(
+            cached.transactions_by_block(1.into()).unwrap(),
+            Some(new.body().transactions.clone())
+        );
+    }
+
+    #[test]
+    fn invalidates_reorged_blocks() {
+        let mut rng = generators::rng();
+        let provider: MockEthProvider = MockEthProvider::default();
+        let old =
+            random_block(&mut rng, 1, BlockParams { tx_count: Some(0), ..Default::default() });
+        let new =
+            random_block(&mut rng, 1, BlockParams { tx_count: Some(0), ..Default::default() });
+        provider.add_block(old.hash(), old.clone().into_block());
+
+        let cached = CachingProvider::new(provider.clone(), ProviderCacheConfig::default());
+        assert_eq!(cached.header_by_number(1).unwrap().as_ref(), Some(old.header()));
+        assert_eq!(cached.block(old.hash().into()).unwrap(), Some(old.clone().into_block()));
+
+        provider.blocks.lock().clear();
+        provider.headers.lock().clear();
+        provider.add_block(new.hash(), new.clone().into_block());
+
+        let chain = |block: &SealedBlock<Block>| {
+            Arc::new(Chain::<EthPrimitives>::new(
+                [block.clone().try_recover().unwrap()],
+                ExecutionOutcome::default(),
+                Default::default(),
+            ))
+        };
+        cached.on_canon_state_notification(&CanonStateNotification::Reorg {
+            old: chain(&old),
+            new: chain(&new),
+        });
+
+        assert_eq!(cached.header_by_number(1).unwrap().as_ref(), Some(new.header()));
+        assert_eq!(cached.block(old.hash().into()).unwrap(), None);
+        assert_eq!(cached.block(new.hash().into()).unwrap(), Some(new.into_block()));
+    }
+
+    #[test]
+    fn read_racing_invalidation_is_not_cached() {
+        let cache = ProviderCache::<u64, u64, u64>::new(ProviderCacheConfig::default());
+        let canonical_hash = |_| -> ProviderResult<_> { Ok(Some(B256::with_last_byte(1))) };
+
+        // the entries are invalidated while the value is read
+        let read = cache.header(1.into(), canonical_hash, || {
+            cache.clear();
+            Ok(Some(1))
+        });
+        assert_eq!(read.unwrap(), Some(1));
+        assert_eq!(cache.header(1.into(), canonical_hash, || Ok(Some(2))).unwrap(), Some(2));
+
+        // values read without a concurrent invalidation are cached
+        assert_eq!(cache.header(1.into(), canonical_hash, || Ok(Some(3))).unwrap(), Some(2));
+    }
+
+    #[test]
+    fn read_racing_reorg_is_not_cached() {
+        let cache = ProviderCache::<u64, u64, u64>::new(ProviderCacheConfig::default());
+        let canonical = std::cell::Cell::new(B256::with_last_byte(1));
+        let canonical_hash = |_| -> ProviderResult<_> { Ok(Some(canonical.get())) };
+
+        // the canonical block changes while the value is read
+        let read = cache.header(1.into(), canonical_hash, || {
+            canonical.set(B256::with_last_byte(2));
+            Ok(Some(1))
+        });
+        assert_eq!(read.unwrap(), Some(1));
+        assert_eq!(cache.header(1.into(), canonical_hash, || Ok(Some(2))).unwrap(), Some(2));
+        assert_eq!(cache.header(1.into(), canonical_hash, || Ok(Some(3))).unwrap(), Some(2));
+
+        // the cached entry belongs to the previous canonical block
+        canonical.set(B256::with_last_byte(3));
+        assert_eq!(cache.header(1.into(), canonical_hash, || Ok(Some(4))).unwrap(), Some(4));
+    }
+}
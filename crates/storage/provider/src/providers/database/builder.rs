@@ -231,6 +231,12 @@ impl ReadOnlyConfig {
         self
     }
 
+    /// Configures the `RocksDB` directory.
+    pub fn with_rocksdb_dir(mut self, rocksdb_dir: impl Into<PathBuf>) -> Self {
+        self.rocksdb_dir = rocksdb_dir.into();
+        self
+    }
+
     /// Don't watch the static files directory for changes.
     ///
     /// This is only recommended if this is used without a running node instance that modifies
@@ -1,25 +1,26 @@
 use crate::{
     providers::{
-        state::latest::LatestStateProvider, NodeTypesForProvider, RocksDBProvider,
-        StaticFileProvider, StaticFileProviderRWRefMut,
+        state::latest::LatestStateProvider, NodeTypesForProvider, ProviderSnapshot,
+        RocksDBProvider, StaticFileProvider, StaticFileProviderRWRefMut,
     },
     to_range,
     traits::{BlockSource, ReceiptProvider},
-    BalProvider, BalStoreHandle, BlockHashReader, BlockNumReader, BlockReader, ChainSpecProvider,
-    DatabaseProviderFactory, EitherWriterDestination, HashedPostStateProvider, HeaderProvider,
-    HeaderSyncGapProvider, InMemoryBalStore, MetadataProvider, ProviderError,
-    PruneCheckpointReader, RocksDBProviderFactory, StageCheckpointReader, StateProviderBox,
-    StaticFileProviderFactory, StaticFileWriter, TransactionVariant, TransactionsProvider,
+    BalProvider, BalStoreHandle, BlobSidecarStage, BlockHashReader, BlockNumReader, BlockReader,
+    ChainSpecProvider, DatabaseProviderFactory, EitherWriterDestination, HashedPostStateProvider,
+    HeaderProvider, HeaderSyncGapProvider, HistoryWriter, InMemoryBalStore, MetadataProvider,
+    ProviderError, PruneCheckpointReader, RocksDBProviderFactory, StageCheckpointReader,
+    StageCheckpointWriter, StateProviderBox, StaticFileProviderFactory, StaticFileWriter,
+    TransactionVariant, TransactionsProvider,
 };
 use alloy_consensus::transaction::TransactionMeta;
-use alloy_eips::BlockHashOrNumber;
+use alloy_eips::{eip7594::BlobTransactionSidecarVariant, BlockHashOrNumber};
 use alloy_primitives::{Address, BlockHash, BlockNumber, TxHash, TxNumber, B256};
 use core::fmt;
 use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 use parking_lot::RwLock;
 use reth_chainspec::ChainInfo;
 use reth_db::{init_db, mdbx::DatabaseArguments, DatabaseEnv};
-use reth_db_api::{database::Database, models::StoredBlockBodyIndices};
+use reth_db_api::{database::Database, models::StoredBlockBodyIndices, DatabaseError};
 use reth_errors::{RethError, RethResult};
 use reth_node_types::{
     BlockTy, HeaderTy, NodeTypesWithDB, NodeTypesWithDBAdapter, ReceiptTy, TxTy,
@@ -29,8 +30,9 @@ use reth_prune_types::{PruneCheckpoint, PruneModes, PruneSegment, MINIMUM_UNWIND
 use reth_stages_types::{PipelineTarget, StageCheckpoint, StageId};
 use reth_static_file_types::StaticFileSegment;
 use reth_storage_api::{
-    BlockBodyIndicesProvider, ChainStateBlockReader, ChainStateBlockWriter, DBProvider,
-    NodePrimitivesProvider, StorageSettings, StorageSettingsCache, TryIntoHistoricalStateProvider,
+    BlobSidecarProvider, BlockBodyIndicesProvider, ChainStateBlockReader, ChainStateBlockWriter,
+    DBProvider, LogIndexProvider, NodePrimitivesProvider, StorageSettings, StorageSettingsCache,
+    TraceAddressIndexProvider, TryIntoHistoricalStateProvider,
 };
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::HashedPostState;
@@ -93,6 +95,8 @@ pub struct ProviderFactory<N: NodeTypesWithDB> {
     changeset_cache: ChangesetCache,
     /// Store for block access lists.
     bal_store: BalStoreHandle,
+    /// Blob sidecars staged for persistence, if blob sidecars are persisted.
+    blob_sidecar_stage: Option<BlobSidecarStage>,
     /// Task runtime for spawning parallel I/O work.
     runtime: reth_tasks::Runtime,
     /// Minimum distance from tip required before pruning can occur.
@@ -160,6 +164,7 @@ impl<N: ProviderNodeTypes> ProviderFactory<N> {
             rocksdb_provider,
             changeset_cache: ChangesetCache::new(),
             bal_store: BalStoreHandle::new(InMemoryBalStore::default()),
+            blob_sidecar_stage: None,
             runtime,
             minimum_pruning_distance: MINIMUM_UNWIND_SAFE_DISTANCE,
             database_provider_metrics,
@@ -198,6 +203,17 @@ impl<N: NodeTypesWithDB> ProviderFactory<N> {
         self
     }
 
+    /// Enables persisting the blob sidecars staged in `stage` together with their blocks.
+    pub fn with_blob_sidecar_stage(mut self, stage: BlobSidecarStage) -> Self {
+        self.blob_sidecar_stage = Some(stage);
+        self
+    }
+
+    /// Returns the stage of the blob sidecars to persist, if enabled.
+    pub const fn blob_sidecar_stage(&self) -> Option<&BlobSidecarStage> {
+        self.blob_sidecar_stage.as_ref()
+    }
+
     /// Sets the changeset cache for an existing [`ProviderFactory`].
     pub fn with_changeset_cache(mut self, changeset_cache: ChangesetCache) -> Self {
         self.changeset_cache = changeset_cache;
@@ -401,6 +417,15 @@ impl<N: ProviderNodeTypes> ProviderFactory<N> {
         .with_minimum_pruning_distance(self.minimum_pruning_distance))
     }
 
+    /// Returns a [`ProviderSnapshot`] over a provider with a created `DbTx` inside.
+    ///
+    /// The snapshot pins the read transaction together with the highest persisted block and its
+    /// transaction watermark, so reads served from both the database and static files observe a
+    /// single consistent height, even if the writer advances while the snapshot is open.
+    pub fn snapshot(&self) -> ProviderResult<ProviderSnapshot<DatabaseProviderRO<N::DB, N>>> {
+        ProviderSnapshot::new(self.provider()?)
+    }
+
     /// Returns a provider with a created `DbTxMut` inside, which allows fetching and updating
     /// data from the database using different types of providers. Example: [`HeaderProvider`]
     /// [`BlockHashReader`].  This may fail if the inner read/write database transaction fails to
@@ -453,6 +478,65 @@ impl<N: ProviderNodeTypes> ProviderFactory<N> {
         .with_minimum_pruning_distance(self.minimum_pruning_distance))
     }
 
+    /// Compacts the account and storage history shards of the database, see
+    /// [`HistoryWriter::compact_account_history_shards`].
+    ///
+    /// Keys are compacted in batches of `batch_size`, each committed in its own transaction along
+    /// with a progress checkpoint stored under [`HISTORY_SHARD_COMPACTION_ID`]. This keeps every
+    /// write transaction short, so the compaction can run in the background of a live node, and
+    /// lets an interrupted compaction resume where it left off. `on_batch` is called with the
+    /// progress after every committed batch.
+    pub fn compact_history_shards(
+        &self,
+        batch_size: usize,
+        mut on_batch: impl FnMut(&HistoryShardCompactionProgress),
+    ) -> ProviderResult<HistoryShardCompactionProgress> {
+        let mut progress = HistoryShardCompactionProgress::default();
+        let mut position = HistoryShardCompactionPosition::decode(
+            &self.get_stage_checkpoint_progress(HISTORY_SHARD_COMPACTION_ID)?.unwrap_or_default(),
+        )?;
+
+        loop {
+            let provider = self.provider_rw()?;
+            position = match position {
+                HistoryShardCompactionPosition::Accounts(address) => {
+                    let compaction =
+                        provider.compact_account_history_shards(address, batch_size)?;
+                    progress.accounts += compaction.keys;
+                    progress.compacted_keys += compaction.compacted_keys;
+                    progress.removed_shards += compaction.removed_shards;
+                    compaction.next.map_or(
+                        HistoryShardCompactionPosition::Storages(Address::ZERO, B256::ZERO),
+                        HistoryShardCompactionPosition::Accounts,
+                    )
+                }
+                HistoryShardCompactionPosition::Storages(address, storage_key) => {
+                    let compaction = provider
+                        .compact_storage_history_shards((address, storage_key), batch_size)?;
+                    progress.storage_slots += compaction.keys;
+                    progress.compacted_keys += compaction.compacted_keys;
+                    progress.removed_shards += compaction.removed_shards;
+                    compaction.next.map_or(
+                        HistoryShardCompactionPosition::Done,
+                        |(address, storage_key)| {
+                            HistoryShardCompactionPosition::Storages(address, storage_key)
+                        },
+                    )
+                }
+                HistoryShardCompactionPosition::Done => unreachable!("compaction already done"),
+            };
+
+            provider
+                .save_stage_checkpoint_progress(HISTORY_SHARD_COMPACTION_ID, position.encode())?;
+            provider.commit()?;
+            on_batch(&progress);
+
+            if position == HistoryShardCompactionPosition::Done {
+                return Ok(progress)
+            }
+        }
+    }
+
     /// State provider for latest block
     #[track_caller]
     pub fn latest(&self) -> ProviderResult<StateProviderBox> {
@@ -597,6 +681,55 @@ impl<N: ProviderNodeTypes> ProviderFactory<N> {
     }
 }
 
+/// Identifier of the stage checkpoint progress storing the position of
+/// [`ProviderFactory::compact_history_shards`].
+pub const HISTORY_SHARD_COMPACTION_ID: StageId = StageId::Other("HistoryShardCompaction");
+
+/// Progress of [`ProviderFactory::compact_history_shards`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryShardCompactionProgress {
+    /// Number of addresses whose account history shards were visited.
+    pub accounts: usize,
+    /// Number of storage slots whose storage history shards were visited.
+    pub storage_slots: usize,
+    /// Number of addresses and storage slots whose shards were rewritten.
+    pub compacted_keys: usize,
+    /// Number of shards removed by the compaction.
+    pub removed_shards: usize,
+}
+
+/// Position of [`ProviderFactory::compact_history_shards`], persisted after every batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HistoryShardCompactionPosition {
+    /// Compacting the account history, starting at the address.
+    Accounts(Address),
+    /// Compacting the storage history, starting at the storage slot.
+    Storages(Address, B256),
+    /// All shards are compacted, the next compaction starts over.
+    Done,
+}
+
+impl HistoryShardCompactionPosition {
+    fn encode(self) -> Vec<u8> {
+        match self {
+            Self::Accounts(address) => address.to_vec(),
+            Self::Storages(address, storage_key) => {
+                [address.as_slice(), storage_key.as_slice()].concat()
+            }
+            Self::Done => Vec::new(),
+        }
+    }
+
+    fn decode(buf: &[u8]) -> ProviderResult<Self> {
+        match buf.len() {
+            0 => Ok(Self::Accounts(Address::ZERO)),
+            20 => Ok(Self::Accounts(Address::from_slice(buf))),
+            52 => Ok(Self::Storages(Address::from_slice(&buf[..20]), B256::from_slice(&buf[20..]))),
+            _ => Err(ProviderError::Database(DatabaseError::Decode)),
+        }
+    }
+}
+
 impl<N: NodeTypesWithDB> NodePrimitivesProvider for ProviderFactory<N> {
     type Primitives = N::Primitives;
 }
@@ -919,6 +1052,58 @@ impl<N: ProviderNodeTypes> BlockBodyIndicesProvider for ProviderFactory<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> BlobSidecarProvider for ProviderFactory<N> {
+    fn blob_sidecars(
+        &self,
+        block: BlockNumber,
+    ) -> ProviderResult<Option<Vec<(TxHash, BlobTransactionSidecarVariant)>>> {
+        self.provider()?.blob_sidecars(block)
+    }
+
+    fn blob_sidecar_by_versioned_hash(
+        &self,
+        versioned_hash: B256,
+    ) -> ProviderResult<Option<BlobTransactionSidecarVariant>> {
+        self.provider()?.blob_sidecar_by_versioned_hash(versioned_hash)
+    }
+}
+
+impl<N: ProviderNodeTypes> TraceAddressIndexProvider for ProviderFactory<N> {
+    fn trace_address_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.provider()?.trace_address_index_range()
+    }
+
+    fn trace_address_blocks(
+        &self,
+        addresses: &[Address],
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.provider()?.trace_address_blocks(addresses, range)
+    }
+}
+
+impl<N: ProviderNodeTypes> LogIndexProvider for ProviderFactory<N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.provider()?.log_index_range()
+    }
+
+    fn log_address_blocks(
+        &self,
+        addresses: &[Address],
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.provider()?.log_address_blocks(addresses, range)
+    }
+
+    fn log_topic_blocks(
+        &self,
+        topics: &[B256],
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.provider()?.log_topic_blocks(topics, range)
+    }
+}
+
 impl<N: ProviderNodeTypes> StageCheckpointReader for ProviderFactory<N> {
     fn get_stage_checkpoint(&self, id: StageId) -> ProviderResult<Option<StageCheckpoint>> {
         self.provider()?.get_stage_checkpoint(id)
@@ -980,6 +1165,7 @@ where
             rocksdb_provider,
             changeset_cache,
             bal_store,
+            blob_sidecar_stage,
             runtime,
             minimum_pruning_distance,
             database_provider_metrics: _,
@@ -995,6 +1181,7 @@ where
             .field("rocksdb_provider", &rocksdb_provider)
             .field("changeset_cache", &changeset_cache)
             .field("bal_store", &bal_store)
+            .field("blob_sidecar_stage", &blob_sidecar_stage)
             .field("runtime", &runtime)
             .field("minimum_pruning_distance", &minimum_pruning_distance)
             .field(
@@ -1017,6 +1204,7 @@ impl<N: NodeTypesWithDB> Clone for ProviderFactory<N> {
             rocksdb_provider: self.rocksdb_provider.clone(),
             changeset_cache: self.changeset_cache.clone(),
             bal_store: self.bal_store.clone(),
+            blob_sidecar_stage: self.blob_sidecar_stage.clone(),
             runtime: self.runtime.clone(),
             minimum_pruning_distance: self.minimum_pruning_distance,
             database_provider_metrics: self.database_provider_metrics.clone(),
@@ -14,9 +14,9 @@ use crate::{
     BlockReader, BlockWriter, BundleStateInit, ChainStateBlockReader, ChainStateBlockWriter,
     DBProvider, EitherReader, EitherWriter, EitherWriterDestination, HashingWriter, HeaderProvider,
     HeaderSyncGapProvider, HistoricalStateProvider, HistoricalStateProviderRef, HistoryWriter,
-    LatestStateProvider, LatestStateProviderRef, OriginalValuesKnown, ProviderError,
-    PruneCheckpointReader, PruneCheckpointWriter, RawRocksDBBatch, RevertsInit, RocksBatchArg,
-    RocksDBProviderFactory, StageCheckpointReader, StateProviderBox, StateWriter,
+    LatestStateProvider, LatestStateProviderRef, LowestAvailableBlocks, OriginalValuesKnown,
+    ProviderError, PruneCheckpointReader, PruneCheckpointWriter, RawRocksDBBatch, RevertsInit,
+    RocksBatchArg, RocksDBProviderFactory, StageCheckpointReader, StateProviderBox, StateWriter,
     StaticFileProviderFactory, StatsReader, StorageReader, StorageTrieWriter, TransactionVariant,
     TransactionsProvider, TransactionsProviderExt, TrieWriter,
 };
@@ -24,11 +24,11 @@ use alloy_consensus::{
     transaction::{SignerRecoverable, TransactionMeta, TxHashRef},
     BlockHeader, TxReceipt,
 };
-use alloy_eips::BlockHashOrNumber;
+use alloy_eips::{eip7594::BlobTransactionSidecarVariant, BlockHashOrNumber};
 use alloy_primitives::{
     keccak256,
     map::{hash_map, AddressSet, B256Map, HashMap},
-    Address, BlockHash, BlockNumber, StorageKey, StorageValue, TxHash, TxNumber, B256,
+    Address, BlockHash, BlockNumber, Log, StorageKey, StorageValue, TxHash, TxNumber, B256,
 };
 use itertools::Itertools;
 use parking_lot::RwLock;
@@ -40,8 +40,8 @@ use reth_db_api::{
     database::{Database, ReaderTxnTracker},
     models::{
         sharded_key, storage_sharded_key::StorageShardedKey, AccountBeforeTx, BlockNumberAddress,
-        BlockNumberAddressRange, ShardedKey, StorageBeforeTx, StorageSettings,
-        StoredBlockBodyIndices,
+        BlockNumberAddressRange, PackedStorageChangeSet, ShardedKey, StorageBeforeTx,
+        StorageSettings, StoredBlobSidecars, StoredBlockBodyIndices,
     },
     table::Table,
     tables,
@@ -60,9 +60,12 @@ use reth_prune_types::{
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_static_file_types::StaticFileSegment;
 use reth_storage_api::{
-    BlockBodyIndicesProvider, BlockBodyReader, MetadataProvider, MetadataWriter,
-    NodePrimitivesProvider, StateProvider, StateReader, StateWriteConfig, StorageChangeSetReader,
-    StoragePath, StorageSettingsCache, TryIntoHistoricalStateProvider, WriteStateInput,
+    metadata::keys, BlobSidecarProvider, BlobSidecarWriter, BlockBodyIndicesProvider,
+    BlockBodyReader, BlockChangeset, BlockChangesetReader, HistoryShardCompaction,
+    LogIndexProvider, LogIndexWriter, MetadataProvider, MetadataWriter, NodePrimitivesProvider,
+    StateProvider, StateReader, StateWriteConfig, StorageChangeSetReader, StoragePath,
+    StorageSettingsCache, TraceAddressIndexProvider, TraceAddressIndexWriter,
+    TryIntoHistoricalStateProvider, WriteStateInput,
 };
 use reth_storage_errors::provider::{ProviderResult, StaticFileWriterError};
 use reth_trie::{
@@ -1035,6 +1038,104 @@ where
     Ok(Vec::new())
 }
 
+/// Rewrite of the history shards of a single key, see [`plan_history_shard_compaction`].
+#[derive(Debug)]
+struct HistoryShardRewrite<P> {
+    /// The partial key of the shards.
+    key: P,
+    /// Highest block numbers of the shards to remove.
+    removed: Vec<BlockNumber>,
+    /// Shards to insert, keyed by their highest block number.
+    inserted: Vec<(BlockNumber, BlockNumberList)>,
+}
+
+/// Walks the history shards of up to `limit` partial keys and returns the rewrites needed to
+/// compact them, see [`rechunk_history_shards`].
+fn plan_history_shard_compaction<P, S, K>(
+    shards: impl Iterator<Item = ProviderResult<(K, BlockNumberList)>>,
+    limit: usize,
+    partial_key: impl Fn(&K) -> P,
+) -> ProviderResult<(HistoryShardCompaction<P>, Vec<HistoryShardRewrite<P>>)>
+where
+    P: Copy + PartialEq,
+    K: AsRef<ShardedKey<S>>,
+{
+    let mut compaction = HistoryShardCompaction::default();
+    let mut rewrites = Vec::new();
+
+    let mut shards = shards.map(|shard| {
+        shard.map(|(sharded_key, list)| {
+            (partial_key(&sharded_key), sharded_key.as_ref().highest_block_number, list)
+        })
+    });
+    let mut entry = shards.next().transpose()?;
+    while let Some((key, ..)) = &entry {
+        let key = *key;
+        if compaction.keys == limit {
+            compaction.next = Some(key);
+            break
+        }
+        compaction.keys += 1;
+
+        let mut key_shards = Vec::new();
+        while let Some((_, highest_block_number, list)) = entry.take_if(|(next, ..)| *next == key) {
+            key_shards.push((highest_block_number, list));
+            entry = shards.next().transpose()?;
+        }
+
+        if let Some(inserted) = rechunk_history_shards(&key_shards) {
+            compaction.compacted_keys += 1;
+            compaction.removed_shards += key_shards.len().saturating_sub(inserted.len());
+            rewrites.push(HistoryShardRewrite {
+                key,
+                removed: key_shards.into_iter().map(|(highest, _)| highest).collect(),
+                inserted,
+            });
+        }
+    }
+
+    Ok((compaction, rewrites))
+}
+
+/// Rechunks the history shards of a key, given by their highest block number, to full capacity.
+///
+/// Every resulting shard but the last one holds [`sharded_key::NUM_OF_INDICES_IN_SHARD`] indices
+/// and is keyed by its highest block number, while the last one is keyed by `u64::MAX`. Returns
+/// `None` if the shards are already in this shape.
+fn rechunk_history_shards(
+    shards: &[(BlockNumber, BlockNumberList)],
+) -> Option<Vec<(BlockNumber, BlockNumberList)>> {
+    let capacity = sharded_key::NUM_OF_INDICES_IN_SHARD as u64;
+    let is_compact = shards.split_last().is_none_or(|((highest, last), rest)| {
+        *highest == u64::MAX &&
+            !last.is_empty() &&
+            last.len() <= capacity &&
+            rest.iter().all(|(highest, list)| {
+                list.len() == capacity && list.iter().next_back() == Some(*highest)
+            })
+    });
+    if is_compact {
+        return None
+    }
+
+    let chunks = shards
+        .iter()
+        .flat_map(|(_, list)| list.iter())
+        .chunks(sharded_key::NUM_OF_INDICES_IN_SHARD);
+    let mut rechunked = chunks
+        .into_iter()
+        .map(|chunk| {
+            let shard = BlockNumberList::new_pre_sorted(chunk);
+            (shard.iter().next_back().expect("`chunks` does not return empty list"), shard)
+        })
+        .collect::<Vec<_>>();
+    if let Some((highest, _)) = rechunked.last_mut() {
+        *highest = u64::MAX;
+    }
+
+    Some(rechunked)
+}
+
 impl<TX: DbTx + 'static, N: NodeTypesForProvider> DatabaseProvider<TX, N> {
     /// Creates a provider with an inner read-only transaction.
     #[expect(clippy::too_many_arguments)]
@@ -1457,6 +1558,100 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
 
         Ok(())
     }
+
+    /// Takes the storage changesets of the range from both
+    /// [`PackedStorageChangeSets`](tables::PackedStorageChangeSets) and
+    /// [`StorageChangeSets`](tables::StorageChangeSets).
+    fn take_storage_changesets(
+        &self,
+        range: Range<BlockNumberAddress>,
+    ) -> ProviderResult<Vec<(BlockNumberAddress, StorageEntry)>> {
+        let mut changesets = Vec::new();
+        for (key, changeset) in self.take::<tables::PackedStorageChangeSets>(range.clone())? {
+            changesets.extend(changeset.into_entries().into_iter().map(|entry| (key, entry)));
+        }
+        changesets.extend(self.take::<tables::StorageChangeSets>(range)?);
+        Ok(changesets)
+    }
+
+    /// Moves the storage changesets of the block range from
+    /// [`StorageChangeSets`](tables::StorageChangeSets) to
+    /// [`PackedStorageChangeSets`](tables::PackedStorageChangeSets), packing the changed slots of
+    /// each account into a single value.
+    ///
+    /// The range should only contain finalized blocks, whose changesets are only read and never
+    /// unwound.
+    ///
+    /// Returns the number of packed changesets.
+    pub fn pack_storage_changesets(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<usize> {
+        let entries = self.take::<tables::StorageChangeSets>(BlockNumberAddress::range(range))?;
+
+        let mut cursor = self.tx.cursor_write::<tables::PackedStorageChangeSets>()?;
+        let mut packed = 0;
+        for (key, entries) in &entries.into_iter().chunk_by(|(key, _)| *key) {
+            let entries = entries.map(|(_, entry)| entry).collect();
+            cursor.upsert(key, &PackedStorageChangeSet::new(entries))?;
+            packed += 1;
+        }
+
+        Ok(packed)
+    }
+
+    /// Compacts the history shards of up to `limit` partial keys, starting at `from`.
+    ///
+    /// Shards are read from and written to `RocksDB` with storage v2, and MDBX otherwise.
+    fn compact_history_shards<P, S, T>(
+        &self,
+        from: P,
+        limit: usize,
+        partial_key: impl Fn(&T::Key) -> P,
+        sharded_key_factory: impl Fn(P, BlockNumber) -> T::Key,
+    ) -> ProviderResult<HistoryShardCompaction<P>>
+    where
+        P: Copy + PartialEq,
+        T: Table<Value = BlockNumberList>,
+        T::Key: AsRef<ShardedKey<S>>,
+    {
+        let start_key = sharded_key_factory(from, 0);
+
+        if self.cached_storage_settings().storage_v2 {
+            let shards = self.rocksdb_provider.iter_from::<T>(start_key)?;
+            let (compaction, rewrites) = plan_history_shard_compaction(shards, limit, partial_key)?;
+
+            let mut batch = self.rocksdb_provider.batch();
+            for rewrite in rewrites {
+                for highest_block_number in rewrite.removed {
+                    batch.delete::<T>(sharded_key_factory(rewrite.key, highest_block_number))?;
+                }
+                for (highest_block_number, shard) in rewrite.inserted {
+                    batch
+                        .put::<T>(sharded_key_factory(rewrite.key, highest_block_number), &shard)?;
+                }
+            }
+            self.pending_rocksdb_batches.lock().push(batch.into_inner());
+
+            return Ok(compaction)
+        }
+
+        let mut cursor = self.tx.cursor_read::<T>()?;
+        let shards = cursor.walk(Some(start_key))?.map(|shard| shard.map_err(Into::into));
+        let (compaction, rewrites) = plan_history_shard_compaction(shards, limit, partial_key)?;
+
+        for rewrite in rewrites {
+            for highest_block_number in rewrite.removed {
+                self.tx
+                    .delete::<T>(sharded_key_factory(rewrite.key, highest_block_number), None)?;
+            }
+            for (highest_block_number, shard) in rewrite.inserted {
+                self.tx.put::<T>(sharded_key_factory(rewrite.key, highest_block_number), shard)?;
+            }
+        }
+
+        Ok(compaction)
+    }
 }
 
 impl<TX: DbTx, N: NodeTypes> AccountReader for DatabaseProvider<TX, N> {
@@ -1549,6 +1744,59 @@ impl<TX: DbTx + 'static, N: NodeTypes> AccountExtReader for DatabaseProvider<TX,
     }
 }
 
+impl<TX: DbTx + 'static, N: NodeTypes> BlockChangesetReader for DatabaseProvider<TX, N> {
+    fn block_changeset(&self, block_number: BlockNumber) -> ProviderResult<BlockChangeset> {
+        let accounts = self.account_block_changeset(block_number)?;
+        let storage = self.storage_block_changeset(block_number)?;
+
+        // The state at the end of the block is the latest state for the best block, and the
+        // historical state before the next block otherwise.
+        let best_block = self.best_block_number()?;
+        if block_number >= best_block {
+            let state = LatestStateProviderRef::new(self);
+            return BlockChangeset::from_changesets(block_number, accounts, storage, &state)
+        }
+
+        let lowest_available_blocks = LowestAvailableBlocks {
+            account_history_block_number: self
+                .get_prune_checkpoint(PruneSegment::AccountHistory)?
+                .and_then(|checkpoint| checkpoint.block_number)
+                .map(|block_number| block_number + 1),
+            storage_history_block_number: self
+                .get_prune_checkpoint(PruneSegment::StorageHistory)?
+                .and_then(|checkpoint| checkpoint.block_number)
+                .map(|block_number| block_number + 1),
+        };
+        let state = HistoricalStateProviderRef::new_with_lowest_available_blocks(
+            self,
+            block_number + 1,
+            lowest_available_blocks,
+            self.changeset_cache.clone(),
+        );
+        BlockChangeset::from_changesets(block_number, accounts, storage, &state)
+    }
+}
+
+impl<TX: DbTx, N: NodeTypes> DatabaseProvider<TX, N> {
+    /// Returns the storage changesets of the block range that were moved to
+    /// [`PackedStorageChangeSets`](tables::PackedStorageChangeSets).
+    fn packed_storage_changesets_range(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumberAddress, StorageEntry)>> {
+        let mut changesets = Vec::new();
+        for entry in self
+            .tx
+            .cursor_read::<tables::PackedStorageChangeSets>()?
+            .walk_range(BlockNumberAddressRange::from(range))?
+        {
+            let (key, changeset) = entry?;
+            changesets.extend(changeset.into_entries().into_iter().map(|entry| (key, entry)));
+        }
+        Ok(changesets)
+    }
+}
+
 impl<TX: DbTx, N: NodeTypes> StorageChangeSetReader for DatabaseProvider<TX, N> {
     fn storage_changeset(
         &self,
@@ -1557,16 +1805,7 @@ impl<TX: DbTx, N: NodeTypes> StorageChangeSetReader for DatabaseProvider<TX, N>
         if self.cached_storage_settings().storage_v2 {
             self.static_file_provider.storage_changeset(block_number)
         } else {
-            let range = block_number..=block_number;
-            let storage_range = BlockNumberAddress::range(range);
-            self.tx
-                .cursor_dup_read::<tables::StorageChangeSets>()?
-                .walk_range(storage_range)?
-                .map(|r| {
-                    let (bna, entry) = r?;
-                    Ok((bna, entry))
-                })
-                .collect()
+            self.storage_changesets_range(block_number..=block_number)
         }
     }
 
@@ -1579,11 +1818,20 @@ impl<TX: DbTx, N: NodeTypes> StorageChangeSetReader for DatabaseProvider<TX, N>
         if self.cached_storage_settings().storage_v2 {
             self.static_file_provider.get_storage_before_block(block_number, address, storage_key)
         } else {
-            Ok(self
+            let key = BlockNumberAddress((block_number, address));
+            if let Some(entry) = self
                 .tx
                 .cursor_dup_read::<tables::StorageChangeSets>()?
-                .seek_by_key_subkey(BlockNumberAddress((block_number, address)), storage_key)?
-                .filter(|entry| entry.key == storage_key))
+                .seek_by_key_subkey(key, storage_key)?
+                .filter(|entry| entry.key == storage_key)
+            {
+                return Ok(Some(entry))
+            }
+
+            Ok(self
+                .tx
+                .get::<tables::PackedStorageChangeSets>(key)?
+                .and_then(|changeset| changeset.get(storage_key).copied()))
         }
     }
 
@@ -1594,14 +1842,17 @@ impl<TX: DbTx, N: NodeTypes> StorageChangeSetReader for DatabaseProvider<TX, N>
         if self.cached_storage_settings().storage_v2 {
             self.static_file_provider.storage_changesets_range(range)
         } else {
-            self.tx
+            // Packed changesets always belong to blocks below the ones in `StorageChangeSets`.
+            let range = to_range(range);
+            let mut changesets = self.packed_storage_changesets_range(range.clone())?;
+            for entry in self
+                .tx
                 .cursor_dup_read::<tables::StorageChangeSets>()?
                 .walk_range(BlockNumberAddressRange::from(range))?
-                .map(|r| {
-                    let (bna, entry) = r?;
-                    Ok((bna, entry))
-                })
-                .collect()
+            {
+                changesets.push(entry?);
+            }
+            Ok(changesets)
         }
     }
 }
@@ -1990,6 +2241,14 @@ impl<TX: DbTx + 'static, N: NodeTypesForProvider> TransactionsProviderExt
     ) -> ProviderResult<Vec<(TxHash, TxNumber)>> {
         self.static_file_provider.transaction_hashes_by_range(tx_range)
     }
+
+    fn transaction_hashes_by_range_with_chunk_size(
+        &self,
+        tx_range: Range<TxNumber>,
+        chunk_size: usize,
+    ) -> ProviderResult<Vec<(TxHash, TxNumber)>> {
+        self.static_file_provider.transaction_hashes_by_range_with_chunk_size(tx_range, chunk_size)
+    }
 }
 
 // Calculates the hash of the given transaction
@@ -2133,7 +2392,11 @@ impl<TX: DbTx + 'static, N: NodeTypesForProvider> ReceiptProvider for DatabasePr
 
     fn receipt_by_hash(&self, hash: TxHash) -> ProviderResult<Option<Self::Receipt>> {
         if let Some(id) = self.transaction_id(hash)? {
-            self.receipt(id)
+            let receipt = self.receipt(id)?;
+            if receipt.is_none() {
+                self.ensure_tx_not_pruned(PruneSegment::Receipts, id)?;
+            }
+            Ok(receipt)
         } else {
             Ok(None)
         }
@@ -2153,6 +2416,7 @@ impl<TX: DbTx + 'static, N: NodeTypesForProvider> ReceiptProvider for DatabasePr
                 let receipts = self.receipts_by_tx_range(tx_range)?;
 
                 if receipts.len() != body.tx_count as usize {
+                    self.ensure_block_not_pruned(PruneSegment::Receipts, number)?;
                     return Ok(None)
                 }
 
@@ -2232,6 +2496,279 @@ impl<TX: DbTx + 'static, N: NodeTypesForProvider> ReceiptProvider for DatabasePr
     }
 }
 
+impl<TX: DbTx + 'static, N: NodeTypesForProvider> BlobSidecarProvider for DatabaseProvider<TX, N> {
+    fn blob_sidecars(
+        &self,
+        block: BlockNumber,
+    ) -> ProviderResult<Option<Vec<(TxHash, BlobTransactionSidecarVariant)>>> {
+        Ok(self.static_file_provider.blob_sidecars(block)?.map(StoredBlobSidecars::into_sidecars))
+    }
+
+    fn blob_sidecar_by_versioned_hash(
+        &self,
+        versioned_hash: B256,
+    ) -> ProviderResult<Option<BlobTransactionSidecarVariant>> {
+        let Some(block) = self.tx.get::<tables::BlobSidecarBlocks>(versioned_hash)? else {
+            return Ok(None)
+        };
+
+        // The index may point to a block whose static file row was already pruned or replaced,
+        // so only return the sidecar if it actually contains the blob.
+        Ok(self
+            .static_file_provider
+            .blob_sidecars(block)?
+            .and_then(|sidecars| sidecars.find(versioned_hash).cloned()))
+    }
+}
+
+impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> BlobSidecarWriter
+    for DatabaseProvider<TX, N>
+{
+    fn write_blob_sidecars(
+        &self,
+        block: BlockNumber,
+        sidecars: Vec<(TxHash, BlobTransactionSidecarVariant)>,
+    ) -> ProviderResult<()> {
+        let segment = StaticFileSegment::BlobSidecars;
+        let mut writer = match self.static_file_provider.get_highest_static_file_block(segment) {
+            Some(highest) if block <= highest => return Ok(()),
+            Some(highest) => {
+                let mut writer = self.static_file_provider.latest_writer(segment)?;
+                for number in highest + 1..block {
+                    writer.append_blob_sidecars(number, &StoredBlobSidecars::default())?;
+                }
+                writer
+            }
+            None => {
+                // Start the segment at the block instead of the beginning of its file range.
+                let mut writer = self.static_file_provider.get_writer(block, segment)?;
+                if writer.current_block_number().is_none() {
+                    writer.user_header_mut().set_expected_block_start(block);
+                }
+                writer
+            }
+        };
+
+        let sidecars = StoredBlobSidecars::new(sidecars);
+        let mut cursor = self.tx.cursor_write::<tables::BlobSidecarBlocks>()?;
+        for versioned_hash in sidecars.versioned_hashes() {
+            cursor.upsert(versioned_hash, &block)?;
+        }
+
+        writer.append_blob_sidecars(block, &sidecars)
+    }
+
+    fn remove_blob_sidecars_above(&self, block: BlockNumber) -> ProviderResult<()> {
+        let segment = StaticFileSegment::BlobSidecars;
+        let Some(highest) = self.static_file_provider.get_highest_static_file_block(segment) else {
+            return Ok(())
+        };
+        if highest <= block {
+            return Ok(())
+        }
+
+        let mut cursor = self.tx.cursor_write::<tables::BlobSidecarBlocks>()?;
+        for number in block + 1..=highest {
+            let Some(sidecars) = self.static_file_provider.blob_sidecars(number)? else { continue };
+            for versioned_hash in sidecars.versioned_hashes() {
+                if cursor.seek_exact(versioned_hash)?.is_some_and(|(_, entry)| entry == number) {
+                    cursor.delete_current()?;
+                }
+            }
+        }
+
+        if self
+            .static_file_provider
+            .get_lowest_range_start(segment)
+            .is_none_or(|lowest| block < lowest)
+        {
+            self.static_file_provider.delete_segment(segment)?;
+        } else {
+            let mut writer = self.static_file_provider.latest_writer(segment)?;
+            writer.prune_blob_sidecars(highest - block)?;
+            // Commit right away, so the sidecars of the new blocks can be appended.
+            writer.commit()?;
+        }
+
+        Ok(())
+    }
+
+    fn prune_blob_sidecars(&self, block: BlockNumber) -> ProviderResult<usize> {
+        let segment = StaticFileSegment::BlobSidecars;
+        let deleted = self.static_file_provider.delete_segment_below_block(segment, block)?;
+        if deleted.is_empty() {
+            return Ok(0)
+        }
+
+        let lowest = self.static_file_provider.get_lowest_range_start(segment);
+        let mut cursor = self.tx.cursor_write::<tables::BlobSidecarBlocks>()?;
+        let mut walker = cursor.walk(None)?;
+        while let Some((_, number)) = walker.next().transpose()? {
+            if lowest.is_none_or(|lowest| number < lowest) {
+                walker.delete_current()?;
+            }
+        }
+
+        Ok(deleted.len())
+    }
+}
+
+impl<TX: DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
+    /// Reads the block range of an optional index from the [`tables::Metadata`] table.
+    fn index_range(&self, key: &str) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        let Some(value) = self.get_metadata(key)? else { return Ok(None) };
+        let (start, end) = value
+            .split_first_chunk::<8>()
+            .and_then(|(start, rest)| Some((*start, *rest.first_chunk::<8>()?)))
+            .ok_or(ProviderError::Database(reth_db_api::DatabaseError::Decode))?;
+        Ok(Some(u64::from_be_bytes(start)..=u64::from_be_bytes(end)))
+    }
+
+    /// Returns the blocks in the range the sharded history index `T` holds for any of the keys,
+    /// in ascending order.
+    fn indexed_blocks<K, T>(
+        &self,
+        keys: &[K],
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>>
+    where
+        K: Copy + PartialEq,
+        T: Table<Key = ShardedKey<K>, Value = BlockNumberList>,
+    {
+        let mut cursor = self.tx.cursor_read::<T>()?;
+        let mut blocks = BTreeSet::new();
+        for &key in keys {
+            let mut walker = cursor.walk(Some(ShardedKey::new(key, *range.start())))?;
+            while let Some((sharded_key, list)) = walker.next().transpose()? {
+                if sharded_key.key != key {
+                    break
+                }
+                blocks.extend(list.iter().filter(|block| range.contains(block)));
+                if sharded_key.highest_block_number >= *range.end() {
+                    break
+                }
+            }
+        }
+        Ok(blocks.into_iter().collect())
+    }
+}
+
+impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
+    /// Extends the block range of an optional index in the [`tables::Metadata`] table by the
+    /// blocks `first..=last`, which must directly follow it.
+    fn extend_index_range(
+        &self,
+        key: &str,
+        first: BlockNumber,
+        last: BlockNumber,
+    ) -> ProviderResult<()> {
+        let start = match self.index_range(key)? {
+            Some(range) if first != range.end() + 1 => {
+                return Err(ProviderError::Database(reth_db_api::DatabaseError::Other(format!(
+                    "{key} expected block {}, got {first}",
+                    range.end() + 1
+                ))))
+            }
+            Some(range) => *range.start(),
+            None => first,
+        };
+
+        let mut range = Vec::with_capacity(16);
+        range.extend_from_slice(&start.to_be_bytes());
+        range.extend_from_slice(&last.to_be_bytes());
+        self.write_metadata(key, range)
+    }
+}
+
+impl<TX: DbTx + 'static, N: NodeTypes> TraceAddressIndexProvider for DatabaseProvider<TX, N> {
+    fn trace_address_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.index_range(keys::TRACE_ADDRESS_INDEX_RANGE)
+    }
+
+    fn trace_address_blocks(
+        &self,
+        addresses: &[Address],
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.indexed_blocks::<_, tables::TraceAddressHistory>(addresses, range)
+    }
+}
+
+impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> TraceAddressIndexWriter
+    for DatabaseProvider<TX, N>
+{
+    fn write_trace_address_index(
+        &self,
+        blocks: Vec<(BlockNumber, Vec<Address>)>,
+    ) -> ProviderResult<()> {
+        let (Some((first, _)), Some((last, _))) = (blocks.first(), blocks.last()) else {
+            return Ok(())
+        };
+        self.extend_index_range(keys::TRACE_ADDRESS_INDEX_RANGE, *first, *last)?;
+
+        let mut index_updates = BTreeMap::<Address, Vec<BlockNumber>>::new();
+        for (block, addresses) in blocks {
+            for address in addresses {
+                index_updates.entry(address).or_default().push(block);
+            }
+        }
+        self.append_history_index::<_, tables::TraceAddressHistory>(index_updates, ShardedKey::new)
+    }
+}
+
+impl<TX: DbTx + 'static, N: NodeTypes> LogIndexProvider for DatabaseProvider<TX, N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.index_range(keys::LOG_INDEX_RANGE)
+    }
+
+    fn log_address_blocks(
+        &self,
+        addresses: &[Address],
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.indexed_blocks::<_, tables::LogAddressHistory>(addresses, range)
+    }
+
+    fn log_topic_blocks(
+        &self,
+        topics: &[B256],
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.indexed_blocks::<_, tables::LogTopicHistory>(topics, range)
+    }
+}
+
+impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> LogIndexWriter for DatabaseProvider<TX, N> {
+    fn write_log_index(&self, blocks: Vec<(BlockNumber, Vec<Log>)>) -> ProviderResult<()> {
+        let (Some((first, _)), Some((last, _))) = (blocks.first(), blocks.last()) else {
+            return Ok(())
+        };
+        self.extend_index_range(keys::LOG_INDEX_RANGE, *first, *last)?;
+
+        let mut address_updates = BTreeMap::<Address, Vec<BlockNumber>>::new();
+        let mut topic_updates = BTreeMap::<B256, Vec<BlockNumber>>::new();
+        for (block, logs) in blocks {
+            for log in logs {
+                let blocks = address_updates.entry(log.address).or_default();
+                if blocks.last() != Some(&block) {
+                    blocks.push(block);
+                }
+                for topic in log.topics() {
+                    let blocks = topic_updates.entry(*topic).or_default();
+                    if blocks.last() != Some(&block) {
+                        blocks.push(block);
+                    }
+                }
+            }
+        }
+        self.append_history_index::<_, tables::LogAddressHistory>(
+            address_updates,
+            ShardedKey::new,
+        )?;
+        self.append_history_index::<_, tables::LogTopicHistory>(topic_updates, ShardedKey::new)
+    }
+}
+
 impl<TX: DbTx + 'static, N: NodeTypesForProvider> BlockBodyIndicesProvider
     for DatabaseProvider<TX, N>
 {
@@ -2374,19 +2911,23 @@ impl<TX: DbTx + 'static, N: NodeTypes> StorageReader for DatabaseProvider<TX, N>
                 },
             )
         } else {
+            let mut accounts: BTreeMap<Address, BTreeSet<B256>> = BTreeMap::new();
+            for (BlockNumberAddress((_, address)), storage_entry) in
+                self.packed_storage_changesets_range(range.clone())?
+            {
+                accounts.entry(address).or_default().insert(storage_entry.key);
+            }
+
             self.tx
                 .cursor_read::<tables::StorageChangeSets>()?
                 .walk_range(BlockNumberAddress::range(range))?
                 // fold all storages and save its old state so we can remove it from HashedStorage
                 // it is needed as it is dup table.
-                .try_fold(
-                    BTreeMap::new(),
-                    |mut accounts: BTreeMap<Address, BTreeSet<B256>>, entry| {
-                        let (BlockNumberAddress((_, address)), storage_entry) = entry?;
-                        accounts.entry(address).or_default().insert(storage_entry.key);
-                        Ok(accounts)
-                    },
-                )
+                .try_fold(accounts, |mut accounts: BTreeMap<Address, BTreeSet<B256>>, entry| {
+                    let (BlockNumberAddress((_, address)), storage_entry) = entry?;
+                    accounts.entry(address).or_default().insert(storage_entry.key);
+                    Ok(accounts)
+                })
         }
     }
 
@@ -2406,11 +2947,19 @@ impl<TX: DbTx + 'static, N: NodeTypes> StorageReader for DatabaseProvider<TX, N>
                 },
             )
         } else {
+            let mut storages: BTreeMap<(Address, B256), Vec<u64>> = BTreeMap::new();
+            for (index, storage) in self.packed_storage_changesets_range(range.clone())? {
+                storages
+                    .entry((index.address(), storage.key))
+                    .or_default()
+                    .push(index.block_number());
+            }
+
             let mut changeset_cursor = self.tx.cursor_read::<tables::StorageChangeSets>()?;
 
             let storage_changeset_lists =
                 changeset_cursor.walk_range(BlockNumberAddress::range(range))?.try_fold(
-                    BTreeMap::new(),
+                    storages,
                     |mut storages: BTreeMap<(Address, B256), Vec<u64>>,
                      entry|
                      -> ProviderResult<_> {
@@ -2778,7 +3327,7 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
             changeset_writer.prune_storage_changesets(block)?;
             changesets
         } else {
-            self.take::<tables::StorageChangeSets>(storage_range)?.into_iter().collect()
+            self.take_storage_changesets(storage_range)?
         };
         let account_changeset = if self.cached_storage_settings().storage_v2 {
             let changesets = self.account_changesets_range(range)?;
@@ -2930,7 +3479,7 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
             changeset_writer.prune_storage_changesets(block)?;
             changesets
         } else {
-            self.take::<tables::StorageChangeSets>(storage_range)?.into_iter().collect()
+            self.take_storage_changesets(storage_range)?
         };
 
         // if there are static files for this segment, prune them.
@@ -3458,6 +4007,34 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> HistoryWriter for DatabaseProvi
 
         Ok(())
     }
+
+    fn compact_account_history_shards(
+        &self,
+        from: Address,
+        limit: usize,
+    ) -> ProviderResult<HistoryShardCompaction<Address>> {
+        self.compact_history_shards::<_, _, tables::AccountsHistory>(
+            from,
+            limit,
+            |sharded_key| sharded_key.key,
+            ShardedKey::new,
+        )
+    }
+
+    fn compact_storage_history_shards(
+        &self,
+        from: (Address, B256),
+        limit: usize,
+    ) -> ProviderResult<HistoryShardCompaction<(Address, B256)>> {
+        self.compact_history_shards::<_, _, tables::StoragesHistory>(
+            from,
+            limit,
+            |sharded_key| (sharded_key.address, sharded_key.sharded_key.key),
+            |(address, storage_key), highest_block_number| {
+                StorageShardedKey::new(address, storage_key, highest_block_number)
+            },
+        )
+    }
 }
 
 impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> BlockExecutionWriter
@@ -3496,6 +4073,9 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> BlockExecutionWriter
         // that is why it is deleted afterwards.
         self.remove_blocks_above(block)?;
 
+        // the blob sidecars of the removed blocks must not be served for their replacements
+        self.remove_blob_sidecars_above(block)?;
+
         // Update pipeline progress
         self.update_pipeline_stages(block, true)?;
 
@@ -3982,6 +4562,63 @@ mod tests {
         assert_eq!(result, Vec::<Vec<reth_ethereum_primitives::Receipt>>::new());
     }
 
+    #[test]
+    fn compact_history_shards() {
+        let factory = create_test_provider_factory();
+        factory.set_storage_settings_cache(StorageSettings::v1());
+        let (first, second) = (Address::with_last_byte(1), Address::with_last_byte(2));
+        let capacity = sharded_key::NUM_OF_INDICES_IN_SHARD as u64;
+
+        // The first address has a pruned shard, followed by a full one and the last one
+        let provider = factory.provider_rw().unwrap();
+        for (key, shard) in [
+            (ShardedKey::new(first, 10), BlockNumberList::new_pre_sorted(5..=10)),
+            (
+                ShardedKey::new(first, 10 + capacity),
+                BlockNumberList::new_pre_sorted(11..=10 + capacity),
+            ),
+            (ShardedKey::last(first), BlockNumberList::new_pre_sorted([20 + capacity])),
+            (ShardedKey::last(second), BlockNumberList::new_pre_sorted([1, 2])),
+        ] {
+            provider.tx_ref().put::<tables::AccountsHistory>(key, shard).unwrap();
+        }
+
+        assert_eq!(
+            provider.compact_account_history_shards(Address::ZERO, 1).unwrap(),
+            HistoryShardCompaction {
+                keys: 1,
+                compacted_keys: 1,
+                removed_shards: 1,
+                next: Some(second)
+            }
+        );
+        assert_eq!(
+            provider.compact_account_history_shards(second, 1).unwrap(),
+            HistoryShardCompaction { keys: 1, compacted_keys: 0, removed_shards: 0, next: None }
+        );
+
+        let shards = provider
+            .tx_ref()
+            .cursor_read::<tables::AccountsHistory>()
+            .unwrap()
+            .walk(None)
+            .unwrap()
+            .map(|entry| entry.map(|(key, shard)| (key, shard.iter().collect::<Vec<_>>())))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            shards,
+            vec![
+                (ShardedKey::new(first, 4 + capacity), (5..=4 + capacity).collect()),
+                (
+                    ShardedKey::last(first),
+                    (5 + capacity..=10 + capacity).chain([20 + capacity]).collect()
+                ),
+                (ShardedKey::last(second), vec![1, 2]),
+            ]
+        );
+    }
+
     #[test]
     fn unwind_commit_waits_for_pre_commit_readers() {
         let factory = create_test_provider_factory();
@@ -4251,6 +4888,36 @@ mod tests {
         assert!(provider.receipts_by_block(1.into()).unwrap().is_none());
     }
 
+    #[test]
+    fn test_receipts_by_block_returns_pruned_error_for_pruned_receipts() {
+        let factory = create_test_provider_factory();
+        let data = BlockchainTestData::default();
+
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.insert_block(&data.genesis.try_recover().unwrap()).unwrap();
+        provider_rw.insert_block(&data.blocks[0].0).unwrap();
+        provider_rw
+            .save_prune_checkpoint(
+                PruneSegment::Receipts,
+                PruneCheckpoint {
+                    block_number: Some(1),
+                    tx_number: None,
+                    prune_mode: PruneMode::Full,
+                },
+            )
+            .unwrap();
+        provider_rw.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert!(matches!(
+            provider.receipts_by_block(1.into()),
+            Err(ProviderError::BlockDataPruned {
+                segment: PruneSegment::Receipts,
+                block_number: 1
+            })
+        ));
+    }
+
     #[test]
     fn test_write_trie_updates_sorted() {
         use reth_trie::{
@@ -5492,4 +6159,91 @@ mod tests {
         assert!(!all_blocks.contains(&7), "block 7 should be unwound");
         assert!(!all_blocks.contains(&10), "block 10 should be unwound");
     }
+
+    #[test]
+    fn test_trace_address_index() {
+        let factory = create_test_provider_factory();
+        let (a, b) = (Address::with_last_byte(1), Address::with_last_byte(2));
+
+        let provider_rw = factory.provider_rw().unwrap();
+        assert_eq!(provider_rw.trace_address_index_range().unwrap(), None);
+        provider_rw
+            .write_trace_address_index(vec![(5, vec![a]), (6, vec![a, b]), (7, vec![])])
+            .unwrap();
+        provider_rw.write_trace_address_index(vec![(8, vec![b])]).unwrap();
+
+        // blocks must directly follow the indexed range
+        assert!(provider_rw.write_trace_address_index(vec![(10, vec![a])]).is_err());
+        provider_rw.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.trace_address_index_range().unwrap(), Some(5..=8));
+        assert_eq!(provider.trace_address_blocks(&[a], 0..=10).unwrap(), vec![5, 6]);
+        assert_eq!(provider.trace_address_blocks(&[b], 0..=10).unwrap(), vec![6, 8]);
+        assert_eq!(provider.trace_address_blocks(&[a, b], 6..=7).unwrap(), vec![6]);
+        assert_eq!(
+            provider.trace_address_blocks(&[Address::with_last_byte(3)], 0..=10).unwrap(),
+            Vec::<u64>::new()
+        );
+    }
+
+    #[test]
+    fn test_log_index() {
+        let factory = create_test_provider_factory();
+        let (a, b) = (Address::with_last_byte(1), Address::with_last_byte(2));
+        let (t1, t2) = (B256::with_last_byte(1), B256::with_last_byte(2));
+        let log = |address, topics| Log::new_unchecked(address, topics, Default::default());
+
+        let provider_rw = factory.provider_rw().unwrap();
+        assert_eq!(provider_rw.log_index_range().unwrap(), None);
+        provider_rw
+            .write_log_index(vec![
+                (5, vec![log(a, vec![t1]), log(a, vec![t1, t2])]),
+                (6, vec![log(b, vec![t2])]),
+                (7, vec![]),
+            ])
+            .unwrap();
+        provider_rw.write_log_index(vec![(8, vec![log(a, vec![])])]).unwrap();
+
+        // blocks must directly follow the indexed range
+        assert!(provider_rw.write_log_index(vec![(10, vec![log(a, vec![])])]).is_err());
+        provider_rw.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.log_index_range().unwrap(), Some(5..=8));
+        assert_eq!(provider.log_address_blocks(&[a], 0..=10).unwrap(), vec![5, 8]);
+        assert_eq!(provider.log_address_blocks(&[a, b], 6..=10).unwrap(), vec![6, 8]);
+        assert_eq!(provider.log_topic_blocks(&[t1], 0..=10).unwrap(), vec![5]);
+        assert_eq!(provider.log_topic_blocks(&[t2], 0..=10).unwrap(), vec![5, 6]);
+        assert_eq!(
+            provider.log_topic_blocks(&[B256::with_last_byte(3)], 0..=10).unwrap(),
+            Vec::<u64>::new()
+        );
+    }
+
+    #[test]
+    fn test_blob_sidecars_replaced_after_unwind() {
+        let factory = create_test_provider_factory();
+        let sidecars = |hash| {
+            vec![(
+                B256::with_last_byte(hash),
+                BlobTransactionSidecarVariant::Eip4844(Default::default()),
+            )]
+        };
+
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.write_blob_sidecars(1, sidecars(1)).unwrap();
+        provider_rw.write_blob_sidecars(2, sidecars(2)).unwrap();
+        provider_rw.commit().unwrap();
+
+        // block 2 is unwound and replaced by a block of the same height
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.remove_blob_sidecars_above(1).unwrap();
+        provider_rw.write_blob_sidecars(2, sidecars(3)).unwrap();
+        provider_rw.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.blob_sidecars(1).unwrap(), Some(sidecars(1)));
+        assert_eq!(provider.blob_sidecars(2).unwrap(), Some(sidecars(3)));
+    }
 }
@@ -9,8 +9,9 @@ pub use database::*;
 
 mod static_file;
 pub use static_file::{
-    StaticFileAccess, StaticFileJarProvider, StaticFileProvider, StaticFileProviderBuilder,
-    StaticFileProviderRW, StaticFileProviderRWRefMut, StaticFileWriteCtx, StaticFileWriter,
+    file_checksum, with_compression, ColdStorage, DirectoryColdStorage, StaticFileAccess,
+    StaticFileJarProvider, StaticFileProvider, StaticFileProviderBuilder, StaticFileProviderRW,
+    StaticFileProviderRWRefMut, StaticFileWriteCtx, StaticFileWriter,
 };
 
 mod state;
@@ -29,9 +30,15 @@ pub use consistent_view::{ConsistentDbView, ConsistentViewError};
 mod blockchain_provider;
 pub use blockchain_provider::BlockchainProvider;
 
+mod cached;
+pub use cached::{CachingProvider, ProviderCache, ProviderCacheConfig};
+
 mod consistent;
 pub use consistent::ConsistentProvider;
 
+mod snapshot;
+pub use snapshot::ProviderSnapshot;
+
 pub(crate) mod rocksdb;
 
 pub use rocksdb::{
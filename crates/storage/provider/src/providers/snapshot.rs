@@ -0,0 +1,467 @@
+use crate::{
+    to_range, BlockHashReader, BlockNumReader, BlockReader, BlockSource, HeaderProvider,
+    ReceiptProvider, TransactionVariant, TransactionsProvider,
+};
+use alloy_consensus::{transaction::TransactionMeta, BlockHeader as _};
+use alloy_eips::BlockHashOrNumber;
+use alloy_primitives::{Address, BlockHash, BlockNumber, TxHash, TxNumber, B256};
+use reth_chainspec::ChainInfo;
+use reth_db_api::models::StoredBlockBodyIndices;
+use reth_primitives_traits::{RecoveredBlock, SealedHeader, SealedOrRecoveredBlock};
+use reth_storage_api::BlockBodyIndicesProvider;
+use reth_storage_errors::provider::ProviderResult;
+use std::ops::{Range, RangeBounds, RangeInclusive};
+
+/// A read view of a provider that is pinned to a single block height.
+///
+/// A database provider reads blocks, transactions and receipts from both the database and the
+/// static files. Static files are appended to before the database transaction advancing the chain
+/// is committed, so a reader can observe data of blocks that are not part of the database view
+/// yet, e.g. the receipts of a block whose body indices aren't committed.
+///
+/// The snapshot captures the highest fully persisted block of the wrapped provider, see
+/// [`BlockNumReader::best_block_number`], together with the matching transaction watermark on
+/// creation, and hides everything above them. Multi-step reads, e.g. a block followed by its
+/// receipts and senders, therefore observe a single consistent height even while the writer
+/// advances.
+///
+/// The wrapped provider should keep its own view pinned for the lifetime of the snapshot, like
+/// the read transaction of a [`DatabaseProvider`](crate::DatabaseProvider), see
+/// [`ProviderFactory::snapshot`](crate::ProviderFactory::snapshot).
+#[derive(Debug)]
+pub struct ProviderSnapshot<P> {
+    /// The wrapped provider.
+    inner: P,
+    /// The highest block visible in the snapshot.
+    height: BlockNumber,
+    /// The first transaction number that is not visible in the snapshot.
+    next_tx_num: TxNumber,
+}
+
+impl<P: BlockReader> ProviderSnapshot<P> {
+    /// Creates a new [`ProviderSnapshot`] pinned to the best block of the provider.
+    pub fn new(inner: P) -> ProviderResult<Self> {
+        let height = inner.best_block_number()?;
+        let next_tx_num = inner
+            .block_body_indices(height)?
+            .map(|indices| indices.next_tx_num())
+            .unwrap_or_default();
+        Ok(Self { inner, height, next_tx_num })
+    }
+
+    /// Returns the highest block visible in the snapshot.
+    pub const fn height(&self) -> BlockNumber {
+        self.height
+    }
+
+    /// Returns the wrapped provider.
+    pub const fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Consumes the snapshot and returns the wrapped provider.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Returns the number of the block if it's visible in the snapshot.
+    fn visible_block(&self, id: BlockHashOrNumber) -> ProviderResult<Option<BlockNumber>> {
+        let number = match id {
+            BlockHashOrNumber::Hash(hash) => self.inner.block_number(hash)?,
+            BlockHashOrNumber::Number(number) => Some(number),
+        };
+        Ok(number.filter(|number| *number <= self.height))
+    }
+
+    /// Returns `true` if the transaction is visible in the snapshot.
+    const fn is_visible_tx(&self, id: TxNumber) -> bool {
+        id < self.next_tx_num
+    }
+
+    /// Caps the block range to the blocks visible in the snapshot.
+    fn cap_block_range(&self, range: impl RangeBounds<BlockNumber>) -> Range<BlockNumber> {
+        let range = to_range(range);
+        range.start..range.end.min(self.height.saturating_add(1))
+    }
+
+    /// Caps the inclusive block range to the blocks visible in the snapshot.
+    fn cap_block_range_inclusive(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> RangeInclusive<BlockNumber> {
+        let (start, end) = range.into_inner();
+        start..=end.min(self.height)
+    }
+
+    /// Caps the transaction range to the transactions visible in the snapshot.
+    fn cap_tx_range(&self, range: impl RangeBounds<TxNumber>) -> Range<TxNumber> {
+        let range = to_range(range);
+        range.start..range.end.min(self.next_tx_num)
+    }
+}
+
+impl<P: BlockReader> BlockHashReader for ProviderSnapshot<P> {
+    fn block_hash(&self, number: BlockNumber) -> ProviderResult<Option<B256>> {
+        if number > self.height {
+            return Ok(None)
+        }
+        self.inner.block_hash(number)
+    }
+
+    fn canonical_hashes_range(
+        &self,
+        start: BlockNumber,
+        end: BlockNumber,
+    ) -> ProviderResult<Vec<B256>> {
+        let range = self.cap_block_range(start..end);
+        self.inner.canonical_hashes_range(range.start, range.end)
+    }
+}
+
+impl<P: BlockReader> BlockNumReader for ProviderSnapshot<P> {
+    fn chain_info(&self) -> ProviderResult<ChainInfo> {
+        let best_hash = self.inner.block_hash(self.height)?.unwrap_or_default();
+        Ok(ChainInfo { best_hash, best_number: self.height })
+    }
+
+    fn best_block_number(&self) -> ProviderResult<BlockNumber> {
+        Ok(self.height)
+    }
+
+    fn last_block_number(&self) -> ProviderResult<BlockNumber> {
+        Ok(self.height)
+    }
+
+    fn earliest_block_number(&self) -> ProviderResult<BlockNumber> {
+        self.inner.earliest_block_number()
+    }
+
+    fn block_number(&self, hash: B256) -> ProviderResult<Option<BlockNumber>> {
+        self.visible_block(hash.into())
+    }
+}
+
+impl<P: BlockReader> HeaderProvider for ProviderSnapshot<P> {
+    type Header = P::Header;
+
+    fn header(&self, block_hash: BlockHash) -> ProviderResult<Option<Self::Header>> {
+        Ok(self.inner.header(block_hash)?.filter(|header| header.number() <= self.height))
+    }
+
+    fn header_by_number(&self, num: u64) -> ProviderResult<Option<Self::Header>> {
+        if num > self.height {
+            return Ok(None)
+        }
+        self.inner.header_by_number(num)
+    }
+
+    fn headers_range(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> ProviderResult<Vec<Self::Header>> {
+        self.inner.headers_range(self.cap_block_range(range))
+    }
+
+    fn sealed_header(
+        &self,
+        number: BlockNumber,
+    ) -> ProviderResult<Option<SealedHeader<Self::Header>>> {
+        if number > self.height {
+            return Ok(None)
+        }
+        self.inner.sealed_header(number)
+    }
+
+    fn sealed_headers_while(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+        predicate: impl FnMut(&SealedHeader<Self::Header>) -> bool,
+    ) -> ProviderResult<Vec<SealedHeader<Self::Header>>> {
+        self.inner.sealed_headers_while(self.cap_block_range(range), predicate)
+    }
+}
+
+impl<P: BlockReader> BlockBodyIndicesProvider for ProviderSnapshot<P> {
+    fn block_body_indices(&self, num: u64) -> ProviderResult<Option<StoredBlockBodyIndices>> {
+        if num > self.height {
+            return Ok(None)
+        }
+        self.inner.block_body_indices(num)
+    }
+
+    fn block_body_indices_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<StoredBlockBodyIndices>> {
+        self.inner.block_body_indices_range(self.cap_block_range_inclusive(range))
+    }
+}
+
+impl<P: BlockReader> TransactionsProvider for ProviderSnapshot<P> {
+    type Transaction = P::Transaction;
+
+    fn transaction_id(&self, tx_hash: TxHash) -> ProviderResult<Option<TxNumber>> {
+        Ok(self.inner.transaction_id(tx_hash)?.filter(|id| self.is_visible_tx(*id)))
+    }
+
+    fn transaction_by_id(&self, id: TxNumber) -> ProviderResult<Option<Self::Transaction>> {
+        if !self.is_visible_tx(id) {
+            return Ok(None)
+        }
+        self.inner.transaction_by_id(id)
+    }
+
+    fn transaction_by_id_unhashed(
+        &self,
+        id: TxNumber,
+    ) -> ProviderResult<Option<Self::Transaction>> {
+        if !self.is_visible_tx(id) {
+            return Ok(None)
+        }
+        self.inner.transaction_by_id_unhashed(id)
+    }
+
+    fn transaction_by_hash(&self, hash: TxHash) -> ProviderResult<Option<Self::Transaction>> {
+        match self.transaction_id(hash)? {
+            Some(id) => self.inner.transaction_by_id(id),
+            None => Ok(None),
+        }
+    }
+
+    fn transaction_by_hash_with_meta(
+        &self,
+        hash: TxHash,
+    ) -> ProviderResult<Option<(Self::Transaction, TransactionMeta)>> {
+        Ok(self
+            .inner
+            .transaction_by_hash_with_meta(hash)?
+            .filter(|(_, meta)| meta.block_number <= self.height))
+    }
+
+    fn transactions_by_block(
+        &self,
+        block: BlockHashOrNumber,
+    ) -> ProviderResult<Option<Vec<Self::Transaction>>> {
+        match self.visible_block(block)? {
+            Some(number) => self.inner.transactions_by_block(number.into()),
+            None => Ok(None),
+        }
+    }
+
+    fn transactions_by_block_range(
+        &self,
+        range: impl RangeBounds<BlockNumber>,
+    ) -> ProviderResult<Vec<Vec<Self::Transaction>>> {
+        self.inner.transactions_by_block_range(self.cap_block_range(range))
+    }
+
+    fn transactions_by_tx_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> ProviderResult<Vec<Self::Transaction>> {
+        self.inner.transactions_by_tx_range(self.cap_tx_range(range))
+    }
+
+    fn senders_by_tx_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> ProviderResult<Vec<Address>> {
+        self.inner.senders_by_tx_range(self.cap_tx_range(range))
+    }
+
+    fn transaction_sender(&self, id: TxNumber) -> ProviderResult<Option<Address>> {
+        if !self.is_visible_tx(id) {
+            return Ok(None)
+        }
+        self.inner.transaction_sender(id)
+    }
+}
+
+impl<P: BlockReader> ReceiptProvider for ProviderSnapshot<P> {
+    type Receipt = P::Receipt;
+
+    fn receipt(&self, id: TxNumber) -> ProviderResult<Option<Self::Receipt>> {
+        if !self.is_visible_tx(id) {
+            return Ok(None)
+        }
+        self.inner.receipt(id)
+    }
+
+    fn receipt_by_hash(&self, hash: TxHash) -> ProviderResult<Option<Self::Receipt>> {
+        match self.transaction_id(hash)? {
+            Some(id) => self.inner.receipt(id),
+            None => Ok(None),
+        }
+    }
+
+    fn receipts_by_block(
+        &self,
+        block: BlockHashOrNumber,
+    ) -> ProviderResult<Option<Vec<Self::Receipt>>> {
+        match self.visible_block(block)? {
+            Some(number) => self.inner.receipts_by_block(number.into()),
+            None => Ok(None),
+        }
+    }
+
+    fn receipts_by_tx_range(
+        &self,
+        range: impl RangeBounds<TxNumber>,
+    ) -> ProviderResult<Vec<Self::Receipt>> {
+        self.inner.receipts_by_tx_range(self.cap_tx_range(range))
+    }
+
+    fn receipts_by_block_range(
+        &self,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<Vec<Self::Receipt>>> {
+        self.inner.receipts_by_block_range(self.cap_block_range_inclusive(block_range))
+    }
+}
+
+impl<P: BlockReader> BlockReader for ProviderSnapshot<P> {
+    type Block = P::Block;
+
+    fn find_block_by_hash(
+        &self,
+        hash: B256,
+        source: BlockSource,
+    ) -> ProviderResult<Option<Self::Block>> {
+        if self.block_number(hash)?.is_none() {
+            return Ok(None)
+        }
+        self.inner.find_block_by_hash(hash, source)
+    }
+
+    fn find_sealed_or_recovered_block(
+        &self,
+        hash: B256,
+        source: BlockSource,
+    ) -> ProviderResult<Option<SealedOrRecoveredBlock<Self::Block>>> {
+        if self.block_number(hash)?.is_none() {
+            return Ok(None)
+        }
+        self.inner.find_sealed_or_recovered_block(hash, source)
+    }
+
+    fn block(&self, id: BlockHashOrNumber) -> ProviderResult<Option<Self::Block>> {
+        match self.visible_block(id)? {
+            Some(number) => self.inner.block(number.into()),
+            None => Ok(None),
+        }
+    }
+
+    fn pending_block(&self) -> ProviderResult<Option<RecoveredBlock<Self::Block>>> {
+        // The pending block is never part of the persisted chain.
+        Ok(None)
+    }
+
+    fn pending_block_and_receipts(
+        &self,
+    ) -> ProviderResult<Option<(RecoveredBlock<Self::Block>, Vec<Self::Receipt>)>> {
+        Ok(None)
+    }
+
+    fn recovered_block(
+        &self,
+        id: BlockHashOrNumber,
+        transaction_kind: TransactionVariant,
+    ) -> ProviderResult<Option<RecoveredBlock<Self::Block>>> {
+        match self.visible_block(id)? {
+            Some(number) => self.inner.recovered_block(number.into(), transaction_kind),
+            None => Ok(None),
+        }
+    }
+
+    fn sealed_block_with_senders(
+        &self,
+        id: BlockHashOrNumber,
+        transaction_kind: TransactionVariant,
+    ) -> ProviderResult<Option<RecoveredBlock<Self::Block>>> {
+        match self.visible_block(id)? {
+            Some(number) => self.inner.sealed_block_with_senders(number.into(), transaction_kind),
+            None => Ok(None),
+        }
+    }
+
+    fn block_range(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<Vec<Self::Block>> {
+        self.inner.block_range(self.cap_block_range_inclusive(range))
+    }
+
+    fn block_with_senders_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<RecoveredBlock<Self::Block>>> {
+        self.inner.block_with_senders_range(self.cap_block_range_inclusive(range))
+    }
+
+    fn recovered_block_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<RecoveredBlock<Self::Block>>> {
+        self.inner.recovered_block_range(self.cap_block_range_inclusive(range))
+    }
+
+    fn block_by_transaction_id(&self, id: TxNumber) -> ProviderResult<Option<BlockNumber>> {
+        if !self.is_visible_tx(id) {
+            return Ok(None)
+        }
+        self.inner.block_by_transaction_id(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::create_test_provider_factory, BlockWriter, DBProvider, StageCheckpointWriter,
+    };
+    use reth_stages_types::{StageCheckpoint, StageId};
+    use reth_testing_utils::generators::{self, BlockRangeParams};
+
+    #[test]
+    fn hides_blocks_above_height() {
+        let factory = create_test_provider_factory();
+        let mut rng = generators::rng();
+        let blocks = generators::random_block_range(
+            &mut rng,
+            0..=2,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+
+        let provider = factory.provider_rw().unwrap();
+        for block in &blocks[..2] {
+            provider.insert_block(&block.clone().try_recover().unwrap()).unwrap();
+        }
+        provider.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(1)).unwrap();
+        provider.commit().unwrap();
+
+        let snapshot = factory.snapshot().unwrap();
+        assert_eq!(snapshot.height(), 1);
+
+        // Advance the chain while the snapshot is open.
+        let provider = factory.provider_rw().unwrap();
+        provider.insert_block(&blocks[2].clone().try_recover().unwrap()).unwrap();
+        provider.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(2)).unwrap();
+        provider.commit().unwrap();
+        assert_eq!(factory.best_block_number().unwrap(), 2);
+
+        assert_eq!(snapshot.best_block_number().unwrap(), 1);
+        assert_eq!(snapshot.last_block_number().unwrap(), 1);
+        assert!(snapshot.block(1.into()).unwrap().is_some());
+        assert!(snapshot.block(2.into()).unwrap().is_none());
+        assert!(snapshot.header_by_number(2).unwrap().is_none());
+        assert!(snapshot.block_number(blocks[2].hash()).unwrap().is_none());
+        assert_eq!(snapshot.headers_range(..).unwrap().len(), 2);
+        assert_eq!(snapshot.block_range(0..=2).unwrap().len(), 2);
+
+        let tx_count = blocks[..2].iter().map(|block| block.body().transactions.len()).sum();
+        assert_eq!(snapshot.transactions_by_tx_range(..).unwrap().len(), tx_count);
+        assert_eq!(snapshot.senders_by_tx_range(..).unwrap().len(), tx_count);
+        assert!(snapshot.transaction_by_id(tx_count as TxNumber).unwrap().is_none());
+
+        let hidden_tx = *blocks[2].body().transactions[0].tx_hash();
+        assert!(snapshot.transaction_by_hash(hidden_tx).unwrap().is_none());
+        assert!(factory.transaction_by_hash(hidden_tx).unwrap().is_some());
+    }
+}
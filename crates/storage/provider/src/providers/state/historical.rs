@@ -1,4 +1,4 @@
-use super::overlay::{Overlay, OverlayBuilder, OverlaySource};
+use super::overlay::{db_tip_block, Overlay, OverlayBuilder, OverlaySource};
 use crate::{
     AccountReader, BlockHashReader, ChangeSetReader, EitherReader, HashedPostStateProvider,
     ProviderError, RocksDBProviderFactory, StateProvider, StateRootProvider,
@@ -636,6 +636,19 @@ where
             })
         })
     }
+
+    /// Returns the reverts from the tip of the database to this block.
+    fn proof_input(&self) -> ProviderResult<Option<(B256, TrieInput)>> {
+        let tip = db_tip_block(self.provider)?;
+        let TrieInputSorted { nodes, state, prefix_sets } =
+            self.build_overlay(TrieInputSorted::default())?;
+        let input = TrieInput::new(
+            Arc::unwrap_or_clone(nodes).into(),
+            Arc::unwrap_or_clone(state).into(),
+            prefix_sets,
+        );
+        Ok(Some((tip.hash, input)))
+    }
 }
 
 impl<Provider, N> HashedPostStateProvider for HistoricalStateProviderRef<'_, Provider, N>
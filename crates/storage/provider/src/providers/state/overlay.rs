@@ -85,6 +85,23 @@ pub(super) enum OverlaySource<N: NodePrimitives = EthPrimitives> {
     },
 }
 
+/// Returns the block which is at the tip of the DB, i.e. the block which the state tables of the
+/// DB are currently synced to.
+pub(super) fn db_tip_block<Provider>(provider: &Provider) -> ProviderResult<BlockNumHash>
+where
+    Provider: StageCheckpointReader + BlockNumReader,
+{
+    let block_number = provider
+        .get_stage_checkpoint(StageId::Finish)?
+        .as_ref()
+        .map(|chk| chk.block_number)
+        .ok_or_else(|| ProviderError::InsufficientChangesets { requested: 0, available: 0..=0 })?;
+    let hash = provider
+        .convert_number(block_number.into())?
+        .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+    Ok(BlockNumHash::new(block_number, hash))
+}
+
 /// Builder for calculating trie and hashed-state overlays.
 ///
 /// This stores the overlay configuration and the logic for resolving overlays and collecting
@@ -219,18 +236,7 @@ impl<N: NodePrimitives> OverlayBuilder<N> {
     where
         Provider: StageCheckpointReader + BlockNumReader,
     {
-        let block_number = provider
-            .get_stage_checkpoint(StageId::Finish)?
-            .as_ref()
-            .map(|chk| chk.block_number)
-            .ok_or_else(|| ProviderError::InsufficientChangesets {
-                requested: 0,
-                available: 0..=0,
-            })?;
-        let hash = provider
-            .convert_number(block_number.into())?
-            .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
-        Ok(BlockNumHash::new(block_number, hash))
+        db_tip_block(provider)
     }
 
     /// Returns whether or not it is required to collect reverts, and validates that there are
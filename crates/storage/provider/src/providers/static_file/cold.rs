@@ -0,0 +1,412 @@
+//! Secondary directory tier for old static files.
+//!
+//! Static files whose block range ends below the configured cold height can be moved to a
+//! secondary directory through [`DirectoryColdStorage`], e.g. on a larger but slower disk. Only the
+//! small configuration file of a moved static file is kept locally, so the provider index can still
+//! be built on launch. The data files are copied back on first access and the local static files
+//! directory acts as a bounded read-through cache for them.
+//!
+//! There is no object storage client. The [`ColdStorage`] trait is the extension point for other
+//! backends.
+
+use alloy_primitives::{Keccak256, B256};
+use parking_lot::Mutex;
+use reth_nippy_jar::{CHANGESET_OFFSETS_FILE_EXTENSION, OFFSETS_FILE_EXTENSION};
+use reth_static_file_types::SegmentRangeInclusive;
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::SystemTime,
+};
+use tracing::debug;
+
+/// Extensions of the static file satellite files that are moved to cold storage alongside the data
+/// file. The configuration file always stays local.
+const COLD_FILE_EXTENSIONS: [&str; 2] = [OFFSETS_FILE_EXTENSION, CHANGESET_OFFSETS_FILE_EXTENSION];
+
+/// A backend for the static files of the secondary tier.
+///
+/// Files are addressed by their name in the static files directory.
+pub trait ColdStorage: Debug + Send + Sync {
+    /// Returns `true` if the file exists in the secondary tier.
+    fn contains(&self, name: &str) -> io::Result<bool>;
+
+    /// Returns the [`file_checksum`] of the file, or `None` if it doesn't exist.
+    fn checksum(&self, name: &str) -> io::Result<Option<B256>>;
+
+    /// Copies the file to the local file at `dest`.
+    fn fetch(&self, name: &str, dest: &Path) -> io::Result<()>;
+
+    /// Copies the local file at `src` into the tier, replacing an existing one.
+    fn store(&self, name: &str, src: &Path) -> io::Result<()>;
+
+    /// Removes the file, if it exists.
+    fn remove(&self, name: &str) -> io::Result<()>;
+}
+
+/// Returns the keccak256 hash of the contents of the file.
+pub fn file_checksum(path: &Path) -> io::Result<B256> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Keccak256::new();
+    let mut buf = vec![0; 1 << 16];
+    loop {
+        let read = file.read(&mut buf)?;
+        if read == 0 {
+            return Ok(hasher.finalize())
+        }
+        hasher.update(&buf[..read]);
+    }
+}
+
+/// A [`ColdStorage`] backed by a secondary directory, e.g. on another disk.
+#[derive(Debug, Clone)]
+pub struct DirectoryColdStorage {
+    path: PathBuf,
+}
+
+impl DirectoryColdStorage {
+    /// Creates a new [`DirectoryColdStorage`] storing files in the given directory.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl ColdStorage for DirectoryColdStorage {
+    fn contains(&self, name: &str) -> io::Result<bool> {
+        self.path.join(name).try_exists()
+    }
+
+    fn checksum(&self, name: &str) -> io::Result<Option<B256>> {
+        match file_checksum(&self.path.join(name)) {
+            Ok(checksum) => Ok(Some(checksum)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn fetch(&self, name: &str, dest: &Path) -> io::Result<()> {
+        std::fs::copy(self.path.join(name), dest).map(drop)
+    }
+
+    fn store(&self, name: &str, src: &Path) -> io::Result<()> {
+        reth_fs_util::create_dir_all(&self.path).map_err(io::Error::other)?;
+
+        // Copy to a temporary file first, so an interrupted copy is never mistaken for a complete
+        // one.
+        let tmp = self.path.join(format!("{name}.tmp"));
+        std::fs::copy(src, &tmp)?;
+        std::fs::rename(tmp, self.path.join(name))
+    }
+
+    fn remove(&self, name: &str) -> io::Result<()> {
+        match std::fs::remove_file(self.path.join(name)) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Size and modification time of the data and satellite files of a static file.
+type FileStamp = Vec<Option<(u64, SystemTime)>>;
+
+/// Local cache of fetched cold static files.
+#[derive(Debug, Default)]
+struct ColdCache {
+    /// Cold static files fetched into the local directory, least recently fetched first, with
+    /// the stamp of their files right after the fetch.
+    fetched: VecDeque<(PathBuf, FileStamp)>,
+    /// Number of readers loading each static file.
+    loading: HashMap<PathBuf, usize>,
+}
+
+/// Marks a static file as being loaded by a reader, so it isn't evicted until the reader holds
+/// it in the provider.
+#[derive(Debug)]
+pub(crate) struct ColdLoadGuard<'a> {
+    cache: &'a Mutex<ColdCache>,
+    path: PathBuf,
+}
+
+impl Drop for ColdLoadGuard<'_> {
+    fn drop(&mut self) {
+        let mut cache = self.cache.lock();
+        if let Some(count) = cache.loading.get_mut(&self.path) {
+            *count -= 1;
+            if *count == 0 {
+                cache.loading.remove(&self.path);
+            }
+        }
+    }
+}
+
+/// Cold tier of a static file provider.
+#[derive(Debug)]
+pub(crate) struct ColdTier {
+    /// Backend the cold static files are stored in.
+    storage: Arc<dyn ColdStorage>,
+    /// Static files whose block range ends below this block are offloaded to cold storage.
+    below_block: u64,
+    /// Maximum number of fetched cold static files kept in the local directory.
+    max_cached_files: usize,
+    /// Serializes fetches, so concurrent readers of the same static file download it only once.
+    fetch_lock: Mutex<()>,
+    /// Local cache of fetched static files.
+    cache: Mutex<ColdCache>,
+}
+
+impl ColdTier {
+    /// Creates a new [`ColdTier`].
+    pub(crate) fn new(
+        storage: Arc<dyn ColdStorage>,
+        below_block: u64,
+        max_cached_files: usize,
+    ) -> Self {
+        Self {
+            storage,
+            below_block,
+            max_cached_files,
+            fetch_lock: Default::default(),
+            cache: Default::default(),
+        }
+    }
+
+    /// Returns `true` if the static file with the given block range belongs to the cold tier.
+    pub(crate) const fn is_cold(&self, range: &SegmentRangeInclusive) -> bool {
+        range.end() < self.below_block
+    }
+
+    /// Fetches the data and satellite files of the static file at `path` from cold storage, if the
+    /// data file is missing locally.
+    ///
+    /// The static file is not evicted until the returned guard is dropped.
+    pub(crate) fn fetch(&self, path: &Path) -> io::Result<ColdLoadGuard<'_>> {
+        *self.cache.lock().loading.entry(path.to_path_buf()).or_default() += 1;
+        let guard = ColdLoadGuard { cache: &self.cache, path: path.to_path_buf() };
+        if path.exists() {
+            return Ok(guard)
+        }
+
+        let _fetch_guard = self.fetch_lock.lock();
+        // Another reader might have fetched it while we were waiting for the lock.
+        if path.exists() {
+            return Ok(guard)
+        }
+
+        debug!(target: "providers::static_file", ?path, "Fetching static file from cold storage");
+        for file in Self::cold_files(path) {
+            let name = file_name(&file)?;
+            if !self.storage.contains(&name)? {
+                continue
+            }
+
+            // Download to a temporary file first, so a partial download is never loaded.
+            let tmp = file.with_file_name(format!("{name}.tmp"));
+            self.storage.fetch(&name, &tmp)?;
+            std::fs::rename(tmp, &file)?;
+        }
+
+        // The data file is renamed last, since its presence marks the static file as fetched.
+        let name = file_name(path)?;
+        let tmp = path.with_file_name(format!("{name}.tmp"));
+        self.storage.fetch(&name, &tmp)?;
+        std::fs::rename(tmp, path)?;
+
+        let stamp = Self::stamp(path)?;
+        let mut cache = self.cache.lock();
+        cache.fetched.retain(|(fetched, _)| fetched != path);
+        cache.fetched.push_back((path.to_path_buf(), stamp));
+        drop(cache);
+
+        Ok(guard)
+    }
+
+    /// Returns the fetched static files exceeding the local cache bound, least recently fetched
+    /// first.
+    pub(crate) fn excess_cached_files(&self) -> Vec<PathBuf> {
+        let cache = self.cache.lock();
+        let excess = cache.fetched.len().saturating_sub(self.max_cached_files);
+        cache.fetched.iter().take(excess).map(|(path, _)| path.clone()).collect()
+    }
+
+    /// Uploads the data and satellite files of the static file at `path` to cold storage, unless
+    /// stored there with the same checksum already, and removes them locally.
+    ///
+    /// The local files are only removed if no reader is loading the static file and `evict`
+    /// returns `true`, which must release the static file from the provider if it isn't in use.
+    ///
+    /// Returns `false` if the data file was not present locally, or is still in use.
+    pub(crate) fn offload(&self, path: &Path, evict: impl FnOnce() -> bool) -> io::Result<bool> {
+        if !path.exists() {
+            return Ok(false)
+        }
+
+        let files = Self::cold_files(path).filter(|file| file.exists()).collect::<Vec<_>>();
+
+        // Fetched static files that weren't modified since are stored already.
+        let stamp = Self::stamp(path)?;
+        let unmodified = self
+            .cache
+            .lock()
+            .fetched
+            .iter()
+            .any(|(fetched, fetched_stamp)| fetched == path && *fetched_stamp == stamp);
+        if !unmodified {
+            debug!(target: "providers::static_file", ?path, "Offloading static file to cold storage");
+            for file in files.iter().map(PathBuf::as_path).chain([path]) {
+                let name = file_name(file)?;
+                // The static file may have been rewritten since it was stored, e.g. by an unwind.
+                if self.storage.checksum(&name)? != Some(file_checksum(file)?) {
+                    self.storage.store(&name, file)?;
+                }
+            }
+        }
+
+        let mut cache = self.cache.lock();
+        if cache.loading.contains_key(path) || !evict() {
+            return Ok(false)
+        }
+        cache.fetched.retain(|(fetched, _)| fetched != path);
+
+        // The data file is removed first, since its absence marks the static file as offloaded.
+        std::fs::remove_file(path)?;
+        for file in files {
+            std::fs::remove_file(file)?;
+        }
+
+        Ok(true)
+    }
+
+    /// Removes the data and satellite files of the static file at `path` from cold storage, e.g.
+    /// once it was deleted locally by a prune or unwind.
+    pub(crate) fn remove(&self, path: &Path) -> io::Result<()> {
+        self.cache.lock().fetched.retain(|(fetched, _)| fetched != path);
+        for file in Self::cold_files(path).chain([path.to_path_buf()]) {
+            self.storage.remove(&file_name(&file)?)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the paths of the satellite files that are moved to cold storage alongside the data
+    /// file at `path`.
+    fn cold_files(path: &Path) -> impl Iterator<Item = PathBuf> + '_ {
+        COLD_FILE_EXTENSIONS.into_iter().map(|ext| path.with_extension(ext))
+    }
+
+    /// Returns the stamp of the data and satellite files of the static file at `path`.
+    fn stamp(path: &Path) -> io::Result<FileStamp> {
+        Self::cold_files(path)
+            .chain([path.to_path_buf()])
+            .map(|file| match std::fs::metadata(file) {
+                Ok(metadata) => Ok(Some((metadata.len(), metadata.modified()?))),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+                Err(err) => Err(err),
+            })
+            .collect()
+    }
+}
+
+fn file_name(path: &Path) -> io::Result<String> {
+    path.file_name().map(|name| name.to_string_lossy().into_owned()).ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, format!("invalid static file path {path:?}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_static_file(dir: &Path, data: &[u8]) -> PathBuf {
+        let path = dir.join("static_file_headers_0_499999");
+        std::fs::write(&path, data).unwrap();
+        std::fs::write(path.with_extension("off"), b"offsets").unwrap();
+        std::fs::write(path.with_extension("conf"), b"config").unwrap();
+        path
+    }
+
+    #[test]
+    fn offload_and_fetch() {
+        let local = tempfile::tempdir().unwrap();
+        let remote = tempfile::tempdir().unwrap();
+        let tier = ColdTier::new(Arc::new(DirectoryColdStorage::new(remote.path())), 500_000, 1);
+        let data = write_static_file(local.path(), b"data");
+
+        assert!(tier.offload(&data, || true).unwrap());
+        assert!(!data.exists());
+        assert!(!data.with_extension("off").exists());
+        assert!(data.with_extension("conf").exists());
+        assert!(!tier.offload(&data, || true).unwrap());
+
+        drop(tier.fetch(&data).unwrap());
+        assert_eq!(std::fs::read(&data).unwrap(), b"data");
+        assert_eq!(std::fs::read(data.with_extension("off")).unwrap(), b"offsets");
+        assert!(!data.with_extension("csoff").exists());
+
+        // Offloading a cached static file only evicts it.
+        assert!(tier.offload(&data, || true).unwrap());
+        assert!(!data.exists());
+    }
+
+    #[test]
+    fn offload_uploads_rewritten_static_file() {
+        let local = tempfile::tempdir().unwrap();
+        let remote = tempfile::tempdir().unwrap();
+        let tier = ColdTier::new(Arc::new(DirectoryColdStorage::new(remote.path())), 500_000, 1);
+        let data = write_static_file(local.path(), b"data");
+        assert!(tier.offload(&data, || true).unwrap());
+
+        // Rewriting a static file that was offloaded before, e.g. after an unwind.
+        write_static_file(local.path(), b"rewritten");
+        assert!(tier.offload(&data, || true).unwrap());
+
+        drop(tier.fetch(&data).unwrap());
+        assert_eq!(std::fs::read(&data).unwrap(), b"rewritten");
+    }
+
+    #[test]
+    fn offload_keeps_static_file_in_use() {
+        let local = tempfile::tempdir().unwrap();
+        let remote = tempfile::tempdir().unwrap();
+        let tier = ColdTier::new(Arc::new(DirectoryColdStorage::new(remote.path())), 500_000, 1);
+        let data = write_static_file(local.path(), b"data");
+
+        // Still mapped by a reader of the provider.
+        assert!(!tier.offload(&data, || false).unwrap());
+        assert!(data.exists());
+
+        // Still being loaded by a reader.
+        let guard = tier.fetch(&data).unwrap();
+        assert!(!tier.offload(&data, || true).unwrap());
+        assert!(data.exists());
+
+        drop(guard);
+        assert!(tier.offload(&data, || true).unwrap());
+        assert!(!data.exists());
+    }
+
+    #[test]
+    fn bounded_cache_and_remove() {
+        let local = tempfile::tempdir().unwrap();
+        let remote = tempfile::tempdir().unwrap();
+        let tier = ColdTier::new(Arc::new(DirectoryColdStorage::new(remote.path())), 1_000_000, 1);
+
+        let first = write_static_file(local.path(), b"first");
+        let second = local.path().join("static_file_headers_500000_999999");
+        std::fs::write(&second, b"second").unwrap();
+        assert!(tier.offload(&first, || true).unwrap());
+        assert!(tier.offload(&second, || true).unwrap());
+
+        drop(tier.fetch(&first).unwrap());
+        assert!(tier.excess_cached_files().is_empty());
+        drop(tier.fetch(&second).unwrap());
+        assert_eq!(tier.excess_cached_files(), vec![first.clone()]);
+
+        tier.remove(&first).unwrap();
+        assert!(tier.excess_cached_files().is_empty());
+        assert!(!remote.path().join("static_file_headers_0_499999").exists());
+        assert!(!remote.path().join("static_file_headers_0_499999.off").exists());
+        assert!(remote.path().join("static_file_headers_500000_999999").exists());
+    }
+}
@@ -0,0 +1,72 @@
+use reth_nippy_jar::{NippyJar, NippyJarCursor, NippyJarError};
+use reth_static_file_types::{Compression, SegmentHeader};
+use tracing::{debug, warn};
+
+/// Maximum size of the zstd dictionary trained for each column of a static file.
+const ZSTD_DICTIONARY_MAX_SIZE: usize = 100_000;
+
+/// Maximum size of the column values sampled to train the zstd dictionaries of a static file.
+///
+/// Zstd recommends a training set of around 100 times the size of the dictionary.
+const ZSTD_DICTIONARY_SAMPLES_SIZE: usize = 100 * ZSTD_DICTIONARY_MAX_SIZE;
+
+/// Configures `jar` to compress its column values with `compression`.
+///
+/// The dictionaries of [`Compression::ZstdWithDictionary`] are trained on the values of
+/// `samples`, usually the previous static file of the same segment. If there are no samples or
+/// training fails, `jar` falls back to [`Compression::Zstd`] without dictionaries.
+pub fn with_compression(
+    jar: NippyJar<SegmentHeader>,
+    compression: Compression,
+    samples: Option<&NippyJar<SegmentHeader>>,
+) -> NippyJar<SegmentHeader> {
+    match compression {
+        Compression::Uncompressed => jar.without_compression(),
+        Compression::Lz4 => jar.with_lz4(),
+        Compression::Zstd => jar.with_zstd(false, 0),
+        Compression::ZstdWithDictionary => {
+            let Some(samples) = samples else {
+                debug!(
+                    target: "providers::static_file",
+                    path = ?jar.data_path(),
+                    "No samples to train zstd dictionaries, using zstd without dictionaries"
+                );
+                return jar.with_zstd(false, 0)
+            };
+
+            let mut dictionary_jar = jar.with_zstd(true, ZSTD_DICTIONARY_MAX_SIZE);
+            match sample_columns(samples)
+                .and_then(|columns| dictionary_jar.prepare_compression(columns))
+            {
+                Ok(()) => dictionary_jar,
+                Err(err) => {
+                    warn!(
+                        target: "providers::static_file",
+                        path = ?dictionary_jar.data_path(),
+                        %err,
+                        "Failed to train zstd dictionaries, using zstd without dictionaries"
+                    );
+                    dictionary_jar.with_zstd(false, 0)
+                }
+            }
+        }
+    }
+}
+
+/// Reads the column values of `jar` until [`ZSTD_DICTIONARY_SAMPLES_SIZE`] is reached.
+fn sample_columns(jar: &NippyJar<SegmentHeader>) -> Result<Vec<Vec<Vec<u8>>>, NippyJarError> {
+    let mut columns = vec![Vec::new(); jar.columns()];
+    let mut size = 0;
+
+    let mut cursor = NippyJarCursor::new(jar)?;
+    while size < ZSTD_DICTIONARY_SAMPLES_SIZE &&
+        let Some(row) = cursor.next_row()?
+    {
+        for (column, value) in columns.iter_mut().zip(row) {
+            size += value.len();
+            column.push(value.to_vec());
+        }
+    }
+
+    Ok(columns)
+}
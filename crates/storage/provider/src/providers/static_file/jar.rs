@@ -1,5 +1,6 @@
 use super::{
     metrics::{StaticFileProviderMetrics, StaticFileProviderOperation},
+    receipts::get_receipt,
     LoadedJarRef,
 };
 use crate::{
@@ -11,7 +12,7 @@ use alloy_eips::BlockHashOrNumber;
 use alloy_primitives::{Address, BlockHash, BlockNumber, TxHash, TxNumber, B256};
 use reth_chainspec::ChainInfo;
 use reth_db::static_file::{
-    BlockHashMask, HeaderMask, HeaderWithHashMask, ReceiptMask, StaticFileCursor, TransactionMask,
+    BlockHashMask, HeaderMask, HeaderWithHashMask, StaticFileCursor, TransactionMask,
     TransactionSenderMask,
 };
 use reth_db_api::table::{Decompress, Value};
@@ -349,7 +350,7 @@ impl<N: NodePrimitives<SignedTx: Decompress + SignedTransaction, Receipt: Decomp
     type Receipt = N::Receipt;
 
     fn receipt(&self, num: TxNumber) -> ProviderResult<Option<Self::Receipt>> {
-        self.cursor()?.get_one::<ReceiptMask<Self::Receipt>>(num.into())
+        get_receipt(&mut self.cursor()?, num)
     }
 
     fn receipt_by_hash(&self, hash: TxHash) -> ProviderResult<Option<Self::Receipt>> {
@@ -378,7 +379,7 @@ impl<N: NodePrimitives<SignedTx: Decompress + SignedTransaction, Receipt: Decomp
         let mut receipts = Vec::with_capacity(range_size_hint(&range).unwrap_or(1024));
 
         for num in to_range(range) {
-            if let Some(tx) = cursor.get_one::<ReceiptMask<Self::Receipt>>(num.into())? {
+            if let Some(tx) = get_receipt(&mut cursor, num)? {
                 receipts.push(tx)
             }
         }
@@ -1,6 +1,9 @@
 use super::{
-    metrics::StaticFileProviderMetrics, writer::StaticFileWriters, LoadedJar,
-    StaticFileJarProvider, StaticFileProviderRW, StaticFileProviderRWRefMut,
+    cold::{ColdStorage, ColdTier},
+    metrics::{StaticFileProviderMetrics, StaticFileQuerySource},
+    receipts::{get_receipt, COMPACT_RECEIPTS_FORMAT},
+    writer::StaticFileWriters,
+    LoadedJar, StaticFileJarProvider, StaticFileProviderRW, StaticFileProviderRWRefMut,
 };
 use crate::{
     changeset_walker::{StaticFileAccountChangesetWalker, StaticFileStorageChangesetWalker},
@@ -16,18 +19,23 @@ use alloy_eips::BlockHashOrNumber;
 use alloy_primitives::{b256, Address, BlockHash, BlockNumber, TxHash, TxNumber, B256};
 
 use parking_lot::RwLock;
+use rayon::prelude::*;
 use reth_chain_state::ExecutedBlock;
 use reth_chainspec::{ChainInfo, ChainSpecProvider, EthChainSpec, NamedChain};
 use reth_db::{
     lockfile::StorageLock,
     static_file::{
-        iter_static_files, BlockHashMask, HeaderMask, HeaderWithHashMask, ReceiptMask,
-        StaticFileCursor, StorageChangesetMask, TransactionMask, TransactionSenderMask,
+        iter_static_files, BlobSidecarsMask, BlockAccessListMask, BlockHashMask, HeaderMask,
+        HeaderWithHashMask, StaticFileCursor, StorageChangesetMask, TransactionMask,
+        TransactionSenderMask,
     },
 };
 use reth_db_api::{
     cursor::DbCursorRO,
-    models::{AccountBeforeTx, BlockNumberAddress, StorageBeforeTx, StoredBlockBodyIndices},
+    models::{
+        AccountBeforeTx, BlockNumberAddress, StorageBeforeTx, StoredBlobSidecars,
+        StoredBlockAccessList, StoredBlockBodyIndices,
+    },
     table::{Decompress, Table, Value},
     tables,
     transaction::DbTx,
@@ -36,14 +44,15 @@ use reth_ethereum_primitives::{Receipt, TransactionSigned};
 use reth_nippy_jar::{NippyJar, NippyJarChecker};
 use reth_node_types::NodePrimitives;
 use reth_primitives_traits::{
-    dashmap::DashMap, AlloyBlockHeader as _, BlockBody as _, RecoveredBlock, SealedHeader,
+    dashmap::{mapref::entry::Entry, DashMap},
+    AlloyBlockHeader as _, BlockBody as _, FastInstant as Instant, RecoveredBlock, SealedHeader,
     SignedTransaction, StorageEntry,
 };
 use reth_prune_types::PruneSegment;
 use reth_stages_types::PipelineTarget;
 use reth_static_file_types::{
-    find_fixed_range, HighestStaticFiles, SegmentHeader, SegmentRangeInclusive, StaticFileMap,
-    StaticFileSegment, DEFAULT_BLOCKS_PER_STATIC_FILE,
+    find_fixed_range, Compression, HighestStaticFiles, SegmentHeader, SegmentRangeInclusive,
+    StaticFileMap, StaticFileSegment,
 };
 use reth_storage_api::{
     BlockBodyIndicesProvider, ChangeSetReader, DBProvider, PruneCheckpointReader,
@@ -55,7 +64,7 @@ use std::{
     fmt::Debug,
     ops::{Bound, Deref, Range, RangeBounds, RangeInclusive},
     path::{Path, PathBuf},
-    sync::{atomic::AtomicU64, mpsc, Arc},
+    sync::{atomic::AtomicU64, Arc},
 };
 use tracing::{debug, info, info_span, instrument, trace, warn};
 
@@ -63,6 +72,10 @@ use tracing::{debug, info, info_span, instrument, trace, warn};
 /// represent either a block or a transaction number end of a static file range.
 type SegmentRanges = BTreeMap<u64, SegmentRangeInclusive>;
 
+/// Maximum number of items read by a single worker of [`StaticFileProvider::fetch_range_parallel`].
+/// Ranges of up to this size are read sequentially.
+const PARALLEL_RANGE_CHUNK_SIZE: u64 = 4_096;
+
 /// Access mode on a static file provider. RO/RW.
 #[derive(Debug, Default, PartialEq, Eq)]
 pub enum StaticFileAccess {
@@ -129,8 +142,12 @@ pub struct StaticFileProviderBuilder<P> {
     access: StaticFileAccess,
     use_metrics: bool,
     blocks_per_file: StaticFileMap<u64>,
+    compression: StaticFileMap<Compression>,
     path: P,
     genesis_block_number: u64,
+    cold_tier: Option<ColdTier>,
+    verify_checksums: bool,
+    compact_receipts: bool,
 }
 
 impl<P: AsRef<Path>> StaticFileProviderBuilder<P> {
@@ -140,8 +157,12 @@ impl<P: AsRef<Path>> StaticFileProviderBuilder<P> {
             path,
             access: StaticFileAccess::RW,
             blocks_per_file: Default::default(),
+            compression: Default::default(),
             use_metrics: false,
             genesis_block_number: 0,
+            cold_tier: None,
+            verify_checksums: false,
+            compact_receipts: false,
         }
     }
 
@@ -151,8 +172,12 @@ impl<P: AsRef<Path>> StaticFileProviderBuilder<P> {
             path,
             access: StaticFileAccess::RO,
             blocks_per_file: Default::default(),
+            compression: Default::default(),
             use_metrics: false,
             genesis_block_number: 0,
+            cold_tier: None,
+            verify_checksums: false,
+            compact_receipts: false,
         }
     }
 
@@ -195,6 +220,32 @@ impl<P: AsRef<Path>> StaticFileProviderBuilder<P> {
         self
     }
 
+    /// Set the compression of new static files for specific segments.
+    ///
+    /// If it is changed for an existing node, existing static files keep their compression, and
+    /// only static files created afterwards use the new setting. For
+    /// [`Compression::ZstdWithDictionary`], the dictionaries of a new static file are trained on
+    /// the previous static file of the segment.
+    pub fn with_compression_for_segments(
+        mut self,
+        segments: &<StaticFileMap<Compression> as Deref>::Target,
+    ) -> Self {
+        for (segment, &compression) in segments {
+            self.compression.insert(segment, compression);
+        }
+        self
+    }
+
+    /// Set the compression of new static files for a specific segment.
+    pub fn with_compression_for_segment(
+        mut self,
+        segment: StaticFileSegment,
+        compression: Compression,
+    ) -> Self {
+        self.compression.insert(segment, compression);
+        self
+    }
+
     /// Enables metrics on the [`StaticFileProvider`].
     pub const fn with_metrics(mut self) -> Self {
         self.use_metrics = true;
@@ -218,6 +269,39 @@ impl<P: AsRef<Path>> StaticFileProviderBuilder<P> {
         self
     }
 
+    /// Enables the secondary tier for static files whose block range ends below `below_block`.
+    ///
+    /// Such static files are moved to `storage` by
+    /// [`StaticFileProvider::offload_cold_static_files`], keeping only their configuration
+    /// files locally. On access, they are fetched back into the static files directory, which
+    /// acts as a read-through cache of at most `max_cached_files` static files.
+    pub fn with_cold_storage(
+        mut self,
+        storage: Arc<dyn ColdStorage>,
+        below_block: u64,
+        max_cached_files: usize,
+    ) -> Self {
+        self.cold_tier = Some(ColdTier::new(storage, below_block, max_cached_files));
+        self
+    }
+
+    /// Verifies the checksums of all local static files when building the [`StaticFileProvider`],
+    /// see [`StaticFileProvider::verify_checksums`].
+    pub const fn with_checksum_verification(mut self) -> Self {
+        self.verify_checksums = true;
+        self
+    }
+
+    /// Stores receipts of new static files with the compact receipt encoding.
+    ///
+    /// It doesn't store the logs bloom, packs the success flag into the transaction type and
+    /// deduplicates log topics, which makes receipt static files considerably smaller at the cost
+    /// of decoding time. Existing static files keep the encoding they were created with.
+    pub const fn with_compact_receipts(mut self) -> Self {
+        self.compact_receipts = true;
+        self
+    }
+
     /// Builds the final [`StaticFileProvider`] and initializes the index.
     pub fn build<N: NodePrimitives>(self) -> ProviderResult<StaticFileProvider<N>> {
         let mut provider = StaticFileProviderInner::new(self.path, self.access)?;
@@ -228,10 +312,18 @@ impl<P: AsRef<Path>> StaticFileProviderBuilder<P> {
         for (segment, blocks_per_file) in *self.blocks_per_file {
             provider.blocks_per_file.insert(segment, blocks_per_file);
         }
+        for (segment, compression) in *self.compression {
+            provider.compression.insert(segment, compression);
+        }
         provider.genesis_block_number = self.genesis_block_number;
+        provider.cold_tier = self.cold_tier;
+        provider.compact_receipts = self.compact_receipts;
 
         let provider = StaticFileProvider(Arc::new(provider));
         provider.initialize_index()?;
+        if self.verify_checksums {
+            provider.verify_checksums()?;
+        }
         Ok(provider)
     }
 }
@@ -297,10 +389,16 @@ pub struct StaticFileProviderInner<N> {
     access: StaticFileAccess,
     /// Number of blocks per file, per segment.
     blocks_per_file: StaticFileMap<u64>,
+    /// Compression of new static files, per segment.
+    compression: StaticFileMap<Compression>,
     /// Write lock for when access is [`StaticFileAccess::RW`].
     _lock_file: Option<StorageLock>,
     /// Genesis block number, default is 0;
     genesis_block_number: u64,
+    /// Cold storage tier of old static files, if enabled.
+    cold_tier: Option<ColdTier>,
+    /// Whether new receipt static files use the compact receipt encoding.
+    compact_receipts: bool,
 }
 
 impl<N: NodePrimitives> StaticFileProviderInner<N> {
@@ -313,8 +411,10 @@ impl<N: NodePrimitives> StaticFileProviderInner<N> {
         };
 
         let mut blocks_per_file = StaticFileMap::default();
+        let mut compression = StaticFileMap::default();
         for segment in StaticFileSegment::iter() {
-            blocks_per_file.insert(segment, DEFAULT_BLOCKS_PER_STATIC_FILE);
+            blocks_per_file.insert(segment, segment.default_blocks_per_file());
+            compression.insert(segment, segment.config().compression);
         }
 
         let provider = Self {
@@ -326,8 +426,11 @@ impl<N: NodePrimitives> StaticFileProviderInner<N> {
             metrics: None,
             access,
             blocks_per_file,
+            compression,
             _lock_file,
             genesis_block_number: 0,
+            cold_tier: None,
+            compact_receipts: false,
         };
 
         Ok(provider)
@@ -337,6 +440,20 @@ impl<N: NodePrimitives> StaticFileProviderInner<N> {
         self.access.is_read_only()
     }
 
+    /// Returns the compression of new static files of the segment.
+    pub fn compression(&self, segment: StaticFileSegment) -> Compression {
+        self.compression.get(segment).copied().unwrap_or_else(|| segment.config().compression)
+    }
+
+    /// Returns the value format of new static files of the segment.
+    pub(crate) const fn value_format(&self, segment: StaticFileSegment) -> u8 {
+        if self.compact_receipts && matches!(segment, StaticFileSegment::Receipts) {
+            COMPACT_RECEIPTS_FORMAT
+        } else {
+            0
+        }
+    }
+
     /// Each static file has a fixed number of blocks. This gives out the range where the requested
     /// block is positioned.
     ///
@@ -352,7 +469,7 @@ impl<N: NodePrimitives> StaticFileProviderInner<N> {
         block: BlockNumber,
     ) -> SegmentRangeInclusive {
         let blocks_per_file =
-            self.blocks_per_file.get(segment).copied().unwrap_or(DEFAULT_BLOCKS_PER_STATIC_FILE);
+            self.blocks_per_file.get(segment).copied().unwrap_or(segment.default_blocks_per_file());
 
         if let Some(block_index) = block_index {
             // Find first block range that contains the requested block
@@ -438,6 +555,26 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         Ok(())
     }
 
+    /// Returns the total size in bytes of the static files of each segment.
+    pub fn segment_sizes(&self) -> ProviderResult<Vec<(StaticFileSegment, u64)>> {
+        let static_files = iter_static_files(&self.path).map_err(ProviderError::other)?;
+        let mut sizes = Vec::new();
+        for (segment, headers) in &*static_files {
+            let mut size = 0;
+            for (block_range, _) in headers {
+                let fixed_block_range = self.find_fixed_range(segment, block_range.start());
+                let jar_provider = self
+                    .get_segment_provider_for_range(segment, || Some(fixed_block_range), None)?
+                    .ok_or_else(|| {
+                        ProviderError::MissingStaticFileBlock(segment, block_range.start())
+                    })?;
+                size += jar_provider.size() as u64;
+            }
+            sizes.push((segment, size));
+        }
+        Ok(sizes)
+    }
+
     /// Writes headers for all blocks to the static file segment.
     #[instrument(level = "debug", target = "providers::static_file", skip_all)]
     fn write_headers(
@@ -879,6 +1016,124 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         }
     }
 
+    /// Moves the static files that belong to the cold tier to cold storage, see
+    /// [`StaticFileProviderBuilder::with_cold_storage`].
+    ///
+    /// Cold static files that were fetched back since the last call are evicted from the local
+    /// static files directory, unless they are still in use. The static file of the highest block
+    /// of a segment is never moved.
+    ///
+    /// Returns the number of static files removed locally.
+    pub fn offload_cold_static_files(&self) -> ProviderResult<usize> {
+        let Some(cold_tier) = &self.cold_tier else { return Ok(0) };
+
+        let mut offloaded = 0;
+        for segment in StaticFileSegment::iter() {
+            let Some(highest_block) = self.get_highest_static_file_block(segment) else { continue };
+            let ranges = self
+                .indexes
+                .read()
+                .get(segment)
+                .map(|index| {
+                    index.expected_block_ranges_by_max_block.values().copied().collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            for range in ranges {
+                if !cold_tier.is_cold(&range) || range.end() >= highest_block {
+                    continue
+                }
+
+                if self.evict_cold_static_file(cold_tier, segment, &range)? {
+                    offloaded += 1;
+                }
+            }
+        }
+
+        Ok(offloaded)
+    }
+
+    /// Offloads the cold static file of the segment and range, see [`ColdTier::offload`].
+    ///
+    /// Its files are only removed if its jar isn't in use, so no memory map of them is live.
+    fn evict_cold_static_file(
+        &self,
+        cold_tier: &ColdTier,
+        segment: StaticFileSegment,
+        range: &SegmentRangeInclusive,
+    ) -> ProviderResult<bool> {
+        let path = self.path.join(segment.filename(range));
+        cold_tier
+            .offload(&path, || {
+                // Never blocks on the map: readers of the jar, or of another jar of the same
+                // shard, hold the shard lock.
+                match self.map.try_entry((range.end(), segment)) {
+                    None => false,
+                    Some(Entry::Vacant(_)) => true,
+                    Some(Entry::Occupied(entry)) => {
+                        if entry.get().is_mapped_elsewhere() {
+                            return false
+                        }
+                        entry.remove();
+                        true
+                    }
+                }
+            })
+            .map_err(ProviderError::other)
+    }
+
+    /// Evicts fetched cold static files exceeding the local cache bound, skipping the ones in use.
+    fn evict_excess_cold_static_files(&self, cold_tier: &ColdTier) -> ProviderResult<()> {
+        for path in cold_tier.excess_cached_files() {
+            let Some((segment, range)) = path
+                .file_name()
+                .and_then(|name| StaticFileSegment::parse_filename(&name.to_string_lossy()))
+            else {
+                continue
+            };
+            self.evict_cold_static_file(cold_tier, segment, &range)?;
+        }
+        Ok(())
+    }
+
+    /// Removes the static file at `path` from cold storage, once it was deleted locally.
+    pub(crate) fn remove_cold_static_file(&self, path: &Path) -> ProviderResult<()> {
+        if let Some(cold_tier) = &self.cold_tier {
+            cold_tier.remove(path).map_err(ProviderError::other)?;
+        }
+        Ok(())
+    }
+
+    /// Verifies the contents of all sealed static files against the checksums in their
+    /// configuration, so that disk corruption is detected before the data is served.
+    ///
+    /// Static files are sealed once full, so the latest static file of each segment is not
+    /// verified. Static files moved to cold storage are skipped as well.
+    ///
+    /// Returns the number of verified static files, or [`NippyJarError::ChecksumMismatch`] for the
+    /// first corrupted one.
+    ///
+    /// [`NippyJarError::ChecksumMismatch`]: reth_nippy_jar::NippyJarError::ChecksumMismatch
+    pub fn verify_checksums(&self) -> ProviderResult<usize> {
+        let mut verified = 0;
+        for (segment, headers) in &*iter_static_files(&self.path).map_err(ProviderError::other)? {
+            for (_, header) in headers {
+                let path = self.path.join(segment.filename(&header.expected_block_range()));
+                if !path.exists() {
+                    continue
+                }
+
+                let jar = NippyJar::<SegmentHeader>::load(&path).map_err(ProviderError::other)?;
+                if jar.verify_checksum().map_err(ProviderError::other)? {
+                    verified += 1;
+                }
+            }
+        }
+
+        debug!(target: "providers::static_file", verified, "Verified static file checksums");
+        Ok(verified)
+    }
+
     /// Given a segment and block, it deletes the jar and all files from the respective block range.
     ///
     /// CAUTION: destructive. Deletes files on disk.
@@ -918,6 +1173,7 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         }
 
         jar.delete().map_err(ProviderError::other)?;
+        self.remove_cold_static_file(&file)?;
 
         // SAFETY: this is currently necessary to ensure that certain indexes like
         // `static_files_min_block` have the correct values after pruning.
@@ -974,10 +1230,22 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         } else {
             trace!(target: "providers::static_file", ?segment, ?fixed_block_range, "Creating jar from scratch");
             let path = self.path.join(segment.filename(fixed_block_range));
+            // Keeps the fetched static file from being evicted until its jar is in the map.
+            let _cold_guard = self
+                .cold_tier
+                .as_ref()
+                .filter(|cold_tier| cold_tier.is_cold(fixed_block_range))
+                .map(|cold_tier| cold_tier.fetch(&path))
+                .transpose()
+                .map_err(ProviderError::other)?;
             let jar = NippyJar::load(&path).map_err(ProviderError::other)?;
             self.map.entry(key).insert(LoadedJar::new(jar)?).downgrade().into()
         };
 
+        if let Some(cold_tier) = &self.cold_tier {
+            self.evict_excess_cold_static_files(cold_tier)?;
+        }
+
         if let Some(metrics) = &self.metrics {
             provider = provider.with_metrics(metrics.clone());
         }
@@ -1483,6 +1751,11 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
                 }
                 true
             }
+            // Blob sidecars are not tied to any database table or stage checkpoint.
+            StaticFileSegment::BlobSidecars => false,
+            // Block access lists are not tied to any database table, the block access list stage
+            // heals the segment against its checkpoint.
+            StaticFileSegment::BlockAccessLists => false,
         }
     }
 
@@ -1619,6 +1892,7 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
                     highest_block,
                     |key| key.block_number(),
                 ),
+            StaticFileSegment::BlobSidecars | StaticFileSegment::BlockAccessLists => Ok(None),
         }
     }
 
@@ -1747,7 +2021,9 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
                         }
                         StaticFileSegment::Headers |
                         StaticFileSegment::AccountChangeSets |
-                        StaticFileSegment::StorageChangeSets => {
+                        StaticFileSegment::StorageChangeSets |
+                        StaticFileSegment::BlobSidecars |
+                        StaticFileSegment::BlockAccessLists => {
                             unreachable!()
                         }
                     }
@@ -1761,6 +2037,14 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
             StaticFileSegment::StorageChangeSets => {
                 writer.prune_storage_changesets(checkpoint_block_number)?;
             }
+            StaticFileSegment::BlobSidecars => {
+                writer.prune_blob_sidecars(highest_static_file_block - checkpoint_block_number)?;
+            }
+            StaticFileSegment::BlockAccessLists => {
+                writer.prune_block_access_lists(
+                    highest_static_file_block - checkpoint_block_number,
+                )?;
+            }
         }
 
         debug!(target: "reth::providers::static_file", "Committing writer after pruning");
@@ -1951,6 +2235,31 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         }
     }
 
+    /// Returns the blob sidecars of the block, if it's within the range of the blob sidecars
+    /// static files.
+    pub fn blob_sidecars(&self, block: BlockNumber) -> ProviderResult<Option<StoredBlobSidecars>> {
+        let Some(provider) =
+            self.get_maybe_segment_provider(StaticFileSegment::BlobSidecars, block)?
+        else {
+            return Ok(None)
+        };
+        provider.cursor()?.get_one::<BlobSidecarsMask>(block.into())
+    }
+
+    /// Returns the block access list of the block, if it's within the range of the block access
+    /// lists static files.
+    pub fn block_access_list(
+        &self,
+        block: BlockNumber,
+    ) -> ProviderResult<Option<StoredBlockAccessList>> {
+        let Some(provider) =
+            self.get_maybe_segment_provider(StaticFileSegment::BlockAccessLists, block)?
+        else {
+            return Ok(None)
+        };
+        provider.cursor()?.get_one::<BlockAccessListMask>(block.into())
+    }
+
     /// Iterates through segment `static_files` in reverse order, executing a function until it
     /// returns some object. Useful for finding objects by [`TxHash`] or [`BlockHash`].
     pub fn find_static_file<T>(
@@ -2049,6 +2358,66 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         Ok(result)
     }
 
+    /// Fetches data within a specified range across multiple static files, reading parts of the
+    /// range concurrently.
+    ///
+    /// The range is split at static file boundaries and into chunks of at most 4096 items, which
+    /// are read on the global rayon pool. The results are returned in order and, like
+    /// [`Self::fetch_range_with_predicate`], end before the first item that is not found.
+    pub fn fetch_range_parallel<T, F>(
+        &self,
+        segment: StaticFileSegment,
+        range: Range<u64>,
+        get_fn: F,
+    ) -> ProviderResult<Vec<T>>
+    where
+        F: Fn(&mut StaticFileCursor<'_>, u64) -> ProviderResult<Option<T>> + Sync,
+        T: Send,
+    {
+        if range.end.saturating_sub(range.start) <= PARALLEL_RANGE_CHUNK_SIZE {
+            return self.fetch_range_with_predicate(segment, range, get_fn, |_| true)
+        }
+
+        // Split the range at static file boundaries, so that each chunk is read from a single
+        // static file.
+        let mut chunks = Vec::new();
+        let mut start = range.start;
+        while start < range.end {
+            let Some(provider) = self.get_maybe_segment_provider(segment, start)? else { break };
+            let header = provider.user_header();
+            let file_end = if segment.is_tx_based() { header.tx_end() } else { header.block_end() };
+            let Some(file_end) = file_end.filter(|end| *end >= start) else { break };
+
+            let end = range.end.min(file_end + 1);
+            chunks.extend(
+                (start..end).step_by(PARALLEL_RANGE_CHUNK_SIZE as usize).map(|chunk_start| {
+                    chunk_start..end.min(chunk_start + PARALLEL_RANGE_CHUNK_SIZE)
+                }),
+            );
+            start = end;
+        }
+
+        let chunks = chunks
+            .into_par_iter()
+            .map(|chunk| {
+                let len = (chunk.end - chunk.start) as usize;
+                self.fetch_range_with_predicate(segment, chunk, &get_fn, |_| true)
+                    .map(|items| (len, items))
+            })
+            .collect::<ProviderResult<Vec<_>>>()?;
+
+        let mut result = Vec::with_capacity((range.end - range.start).min(100) as usize);
+        for (len, items) in chunks {
+            let complete = items.len() == len;
+            result.extend(items);
+            if !complete {
+                break
+            }
+        }
+
+        Ok(result)
+    }
+
     /// Fetches data within a specified range across multiple static files.
     ///
     /// Returns an iterator over the data. Yields [`None`] if the data for the specified number is
@@ -2122,9 +2491,11 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         if static_file_upper_bound
             .is_some_and(|static_file_upper_bound| static_file_upper_bound >= number)
         {
-            return fetch_from_static_file(self);
+            return self.timed_query(segment, StaticFileQuerySource::StaticFile, || {
+                fetch_from_static_file(self)
+            });
         }
-        fetch_from_database()
+        self.timed_query(segment, StaticFileQuerySource::Database, fetch_from_database)
     }
 
     /// Gets data within a specified range, potentially spanning different `static_files` and
@@ -2161,21 +2532,35 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         } && block_or_tx_range.start <= static_file_upper_bound
         {
             let end = block_or_tx_range.end.min(static_file_upper_bound + 1);
-            data.extend(fetch_from_static_file(
-                self,
-                block_or_tx_range.start..end,
-                &mut predicate,
-            )?);
+            data.extend(self.timed_query(segment, StaticFileQuerySource::StaticFile, || {
+                fetch_from_static_file(self, block_or_tx_range.start..end, &mut predicate)
+            })?);
             block_or_tx_range.start = end;
         }
 
         if block_or_tx_range.end > block_or_tx_range.start {
-            data.extend(fetch_from_database(block_or_tx_range, predicate)?)
+            data.extend(self.timed_query(segment, StaticFileQuerySource::Database, || {
+                fetch_from_database(block_or_tx_range, predicate)
+            })?)
         }
 
         Ok(data)
     }
 
+    /// Runs a query for `segment` data, recording its latency if metrics are enabled.
+    fn timed_query<T>(
+        &self,
+        segment: StaticFileSegment,
+        source: StaticFileQuerySource,
+        query: impl FnOnce() -> T,
+    ) -> T {
+        let Some(metrics) = &self.metrics else { return query() };
+        let start = Instant::now();
+        let result = query();
+        metrics.record_segment_query(segment, source, start.elapsed());
+        result
+    }
+
     /// Returns static files directory
     #[cfg(any(test, feature = "test-utils"))]
     pub fn path(&self) -> &Path {
@@ -2571,12 +2956,9 @@ impl<N: NodePrimitives<BlockHeader: Value>> HeaderProvider for StaticFileProvide
         &self,
         range: impl RangeBounds<BlockNumber>,
     ) -> ProviderResult<Vec<Self::Header>> {
-        self.fetch_range_with_predicate(
-            StaticFileSegment::Headers,
-            to_range(range),
-            |cursor, number| cursor.get_one::<HeaderMask<Self::Header>>(number.into()),
-            |_| true,
-        )
+        self.fetch_range_parallel(StaticFileSegment::Headers, to_range(range), |cursor, number| {
+            cursor.get_one::<HeaderMask<Self::Header>>(number.into())
+        })
     }
 
     fn sealed_header(
@@ -2674,12 +3056,9 @@ impl<N: NodePrimitives<SignedTx: Value + SignedTransaction, Receipt: Value>> Rec
         &self,
         range: impl RangeBounds<TxNumber>,
     ) -> ProviderResult<Vec<Self::Receipt>> {
-        self.fetch_range_with_predicate(
-            StaticFileSegment::Receipts,
-            to_range(range),
-            |cursor, number| cursor.get_one::<ReceiptMask<Self::Receipt>>(number.into()),
-            |_| true,
-        )
+        self.fetch_range_parallel(StaticFileSegment::Receipts, to_range(range), |cursor, number| {
+            get_receipt(cursor, number)
+        })
     }
 
     fn receipts_by_block_range(
@@ -2697,55 +3076,45 @@ impl<N: NodePrimitives<SignedTx: Value, Receipt: Value, BlockHeader: Value>> Tra
         &self,
         tx_range: Range<TxNumber>,
     ) -> ProviderResult<Vec<(TxHash, TxNumber)>> {
-        let tx_range_size = (tx_range.end - tx_range.start) as usize;
-
         // Transactions are different size, so chunks will not all take the same processing time. If
         // chunks are too big, there will be idle threads waiting for work. Choosing an
         // arbitrary smaller value to make sure it doesn't happen.
-        let chunk_size = 100;
+        self.transaction_hashes_by_range_with_chunk_size(tx_range, 100)
+    }
 
-        // iterator over the chunks
+    fn transaction_hashes_by_range_with_chunk_size(
+        &self,
+        tx_range: Range<TxNumber>,
+        chunk_size: usize,
+    ) -> ProviderResult<Vec<(TxHash, TxNumber)>> {
+        let chunk_size = chunk_size.max(1) as u64;
         let chunks = tx_range
             .clone()
-            .step_by(chunk_size)
-            .map(|start| start..std::cmp::min(start + chunk_size as u64, tx_range.end));
-        let mut channels = Vec::with_capacity(tx_range_size.div_ceil(chunk_size));
-
-        for chunk_range in chunks {
-            let (channel_tx, channel_rx) = mpsc::channel();
-            channels.push(channel_rx);
-
-            let manager = self.clone();
-
-            // Spawn the task onto the global rayon pool
-            // This task will send the cached transaction hash through the channel.
-            rayon::spawn(move || {
-                let _ = manager.fetch_range_with_predicate(
+            .step_by(chunk_size as usize)
+            .map(|start| start..std::cmp::min(start + chunk_size, tx_range.end))
+            .collect::<Vec<_>>();
+
+        // Hash the chunks on the global rayon pool. `collect` preserves the order of the chunks, so
+        // the hashes are returned ordered by transaction number.
+        let hashes = chunks
+            .into_par_iter()
+            .map(|chunk_range| {
+                let mut hashes = Vec::with_capacity((chunk_range.end - chunk_range.start) as usize);
+                self.fetch_range_with_predicate(
                     StaticFileSegment::Transactions,
                     chunk_range,
                     |cursor, number| {
                         Ok(cursor
                             .get_one::<TransactionMask<Self::Transaction>>(number.into())?
-                            .map(|transaction| {
-                                let _ = channel_tx.send(transaction_hash((number, transaction)));
-                            }))
+                            .map(|transaction| hashes.push((*transaction.tx_hash(), number))))
                     },
                     |_| true,
-                );
-            });
-        }
-
-        let mut tx_list = Vec::with_capacity(tx_range_size);
-
-        // Iterate over channels and append the tx hashes unsorted
-        for channel in channels {
-            while let Ok(tx) = channel.recv() {
-                let (tx_hash, tx_id) = tx.map_err(|boxed| *boxed)?;
-                tx_list.push((tx_hash, tx_id));
-            }
-        }
+                )?;
+                Ok(hashes)
+            })
+            .collect::<ProviderResult<Vec<Vec<_>>>>()?;
 
-        Ok(tx_list)
+        Ok(hashes.into_iter().flatten().collect())
     }
 }
 
@@ -2833,11 +3202,10 @@ impl<N: NodePrimitives<SignedTx: Decompress + SignedTransaction>> TransactionsPr
         &self,
         range: impl RangeBounds<TxNumber>,
     ) -> ProviderResult<Vec<Self::Transaction>> {
-        self.fetch_range_with_predicate(
+        self.fetch_range_parallel(
             StaticFileSegment::Transactions,
             to_range(range),
             |cursor, number| cursor.get_one::<TransactionMask<Self::Transaction>>(number.into()),
-            |_| true,
         )
     }
 
@@ -3003,16 +3371,6 @@ impl<N: NodePrimitives> StatsReader for StaticFileProvider<N> {
     }
 }
 
-/// Returns the tx hash for the given transaction and its id.
-#[inline]
-fn transaction_hash<T>(entry: (TxNumber, T)) -> Result<(B256, TxNumber), Box<ProviderError>>
-where
-    T: TxHashRef,
-{
-    let (tx_id, tx) = entry;
-    Ok((*tx.tx_hash(), tx_id))
-}
-
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
@@ -14,6 +14,7 @@ pub struct StaticFileProviderMetrics {
         (StaticFileSegment, StaticFileProviderOperation),
         StaticFileProviderOperationMetrics,
     >,
+    segment_queries: HashMap<(StaticFileSegment, StaticFileQuerySource), StaticFileQueryMetrics>,
 }
 
 impl Default for StaticFileProviderMetrics {
@@ -44,6 +45,18 @@ impl Default for StaticFileProviderMetrics {
                     )
                 })
                 .collect(),
+            segment_queries: StaticFileSegment::iter()
+                .cartesian_product(StaticFileQuerySource::iter())
+                .map(|(segment, source)| {
+                    (
+                        (segment, source),
+                        StaticFileQueryMetrics::new_with_labels(&[
+                            ("segment", segment.as_str()),
+                            ("source", source.as_str()),
+                        ]),
+                    )
+                })
+                .collect(),
         }
     }
 }
@@ -104,6 +117,37 @@ impl StaticFileProviderMetrics {
                 .record(duration.as_secs_f64() / count as f64);
         }
     }
+
+    pub(crate) fn record_segment_query(
+        &self,
+        segment: StaticFileSegment,
+        source: StaticFileQuerySource,
+        duration: Duration,
+    ) {
+        let segment_query = self
+            .segment_queries
+            .get(&(segment, source))
+            .expect("segment query metrics should exist");
+
+        segment_query.calls_total.increment(1);
+        segment_query.duration_seconds.record(duration.as_secs_f64());
+    }
+}
+
+/// Where a query for segment data was served from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+pub(crate) enum StaticFileQuerySource {
+    StaticFile,
+    Database,
+}
+
+impl StaticFileQuerySource {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            Self::StaticFile => "static-file",
+            Self::Database => "database",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
@@ -141,6 +185,17 @@ pub(crate) struct StaticFileSegmentMetrics {
     entries: Gauge,
 }
 
+/// Metrics for queries of a specific segment, labeled by whether the data was read from static
+/// files or the database.
+#[derive(Metrics)]
+#[metrics(scope = "static_files.queries")]
+pub(crate) struct StaticFileQueryMetrics {
+    /// Total number of queries.
+    calls_total: Counter,
+    /// The time it took to execute a query.
+    duration_seconds: Histogram,
+}
+
 #[derive(Metrics)]
 #[metrics(scope = "static_files.jar_provider")]
 pub(crate) struct StaticFileProviderOperationMetrics {
@@ -7,9 +7,17 @@ pub use manager::{
 mod jar;
 pub use jar::StaticFileJarProvider;
 
+mod cold;
+pub use cold::{file_checksum, ColdStorage, DirectoryColdStorage};
+
+mod receipts;
+
 mod writer;
 pub use writer::{StaticFileProviderRW, StaticFileProviderRWRefMut};
 
+mod compression;
+pub use compression::with_compression;
+
 mod metrics;
 
 #[cfg(test)]
@@ -65,6 +73,11 @@ impl LoadedJar {
         self.jar.user_header().segment()
     }
 
+    /// Returns `true` if a cursor still holds the memory map of the data file.
+    fn is_mapped_elsewhere(&self) -> bool {
+        Arc::strong_count(&self.mmap_handle) > 1
+    }
+
     /// Returns the total size of the data and offsets files (from the in-memory mmap).
     fn size(&self) -> usize {
         self.mmap_handle.size() + self.mmap_handle.offsets_size()
@@ -184,6 +197,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_headers_range_parallel() {
+        let (static_dir, _) = create_test_static_files_dir();
+        let sf_rw: StaticFileProvider<EthPrimitives> =
+            StaticFileProviderBuilder::read_write(&static_dir)
+                .with_blocks_per_file(3_000)
+                .build()
+                .expect("Failed to build static file provider");
+
+        let tip = 9_999;
+        {
+            let mut header_writer = sf_rw.latest_writer(StaticFileSegment::Headers).unwrap();
+            let mut header = Header::default();
+            for num in 0..=tip {
+                header.number = num;
+                header_writer.append_header(&header, &BlockHash::default()).unwrap();
+            }
+            header_writer.commit().unwrap();
+        }
+
+        // Ranges spanning several static files are read in parallel, in order.
+        let numbers = |range| {
+            sf_rw.headers_range(range).unwrap().into_iter().map(|h| h.number).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(100..9_900), (100..9_900).collect::<Vec<_>>());
+        // Reads end at the highest block.
+        assert_eq!(numbers(5_000..20_000), (5_000..=tip).collect::<Vec<_>>());
+        assert!(numbers(20_000..30_000).is_empty());
+        // Unbounded ranges end at the highest block as well.
+        assert_eq!(numbers(5_000..u64::MAX), (5_000..=tip).collect::<Vec<_>>());
+        assert_eq!(
+            sf_rw.headers_range(9_000..).unwrap().into_iter().map(|h| h.number).collect::<Vec<_>>(),
+            (9_000..=tip).collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn test_header_truncation() {
         let (static_dir, _) = create_test_static_files_dir();
@@ -352,7 +401,9 @@ mod tests {
                     match segment {
                         StaticFileSegment::Headers |
                         StaticFileSegment::AccountChangeSets |
-                        StaticFileSegment::StorageChangeSets => {
+                        StaticFileSegment::StorageChangeSets |
+                        StaticFileSegment::BlobSidecars |
+                        StaticFileSegment::BlockAccessLists => {
                             panic!("non tx based segment")
                         }
                         StaticFileSegment::Transactions => {
@@ -471,7 +522,9 @@ mod tests {
             match segment {
                 StaticFileSegment::Headers |
                 StaticFileSegment::AccountChangeSets |
-                StaticFileSegment::StorageChangeSets => {
+                StaticFileSegment::StorageChangeSets |
+                StaticFileSegment::BlobSidecars |
+                StaticFileSegment::BlockAccessLists => {
                     panic!("non tx based segment")
                 }
                 StaticFileSegment::Transactions => {
@@ -498,7 +551,9 @@ mod tests {
                 match segment {
                     StaticFileSegment::Headers |
                     StaticFileSegment::AccountChangeSets |
-                    StaticFileSegment::StorageChangeSets => {
+                    StaticFileSegment::StorageChangeSets |
+                    StaticFileSegment::BlobSidecars |
+                    StaticFileSegment::BlockAccessLists => {
                         panic!("non tx based segment")
                     }
                     StaticFileSegment::Transactions => assert_eyre(
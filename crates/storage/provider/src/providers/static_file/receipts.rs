@@ -0,0 +1,254 @@
+//! Compact encoding of receipts in static files.
+//!
+//! Receipt static files created with [`COMPACT_RECEIPTS_FORMAT`] store each receipt in a denser
+//! encoding than the [`Compact`] codec:
+//! - the logs bloom is never stored, since it's recomputed from the logs on read.
+//! - the success flag is packed into the transaction type byte.
+//! - every distinct log topic is stored once per receipt and referenced by index, which shrinks
+//!   receipts emitting many events of the same kind, e.g. token transfers.
+//!
+//! Reading a receipt rebuilds its network RLP encoding and decodes it with
+//! [`RlpDecodableReceipt`], trading some CPU for a smaller archive. Receipts that can't be
+//! represented losslessly, e.g. pre-Byzantium receipts with a state root or receipt types with
+//! additional fields, are stored with the [`Compact`] codec instead. Each row starts with a tag
+//! telling the two apart.
+
+use alloy_consensus::{
+    Eip658Value, RlpDecodableReceipt, RlpEncodableReceipt, TxReceipt, Typed2718,
+};
+use alloy_primitives::{logs_bloom, map::B256Map, Address, Bytes, Log, TxNumber, B256};
+use alloy_rlp::Header;
+use reth_codecs::Compact;
+use reth_db::static_file::{ColumnSelectorOne, ReceiptMask, StaticFileCursor};
+use reth_db_api::{table::Decompress, DatabaseError};
+use reth_primitives_traits::Receipt;
+use reth_storage_errors::provider::ProviderResult;
+
+/// Value format of receipt static files storing receipts with the compact encoding.
+pub(crate) const COMPACT_RECEIPTS_FORMAT: u8 = 1;
+
+/// Tag of a row encoded with the [`Compact`] codec.
+const COMPACT_CODEC_TAG: u8 = 0;
+
+/// Tag of a row encoded with the compact receipt encoding.
+const COMPACT_RECEIPT_TAG: u8 = 1;
+
+/// Bit of the transaction type byte holding the success flag.
+const SUCCESS_BIT: u8 = 0x80;
+
+/// Reads the receipt with the given transaction number from a receipt static file.
+pub(crate) fn get_receipt<R: Receipt + Decompress>(
+    cursor: &mut StaticFileCursor<'_>,
+    num: TxNumber,
+) -> ProviderResult<Option<R>> {
+    let format = cursor.jar().value_format();
+    cursor
+        .get(num.into(), ReceiptMask::<R>::MASK)?
+        .map(|row| decode_receipt(format, row[0]))
+        .transpose()
+}
+
+/// Decodes a receipt stored in a receipt static file with the given value format.
+pub(crate) fn decode_receipt<R: Receipt + Decompress>(
+    format: u8,
+    value: &[u8],
+) -> ProviderResult<R> {
+    if format != COMPACT_RECEIPTS_FORMAT {
+        return Ok(R::decompress(value)?)
+    }
+
+    match value.split_first() {
+        Some((&COMPACT_CODEC_TAG, value)) => Ok(R::decompress(value)?),
+        Some((&COMPACT_RECEIPT_TAG, value)) => {
+            let rlp = compact_receipt_to_rlp(value).ok_or(DatabaseError::Decode)?;
+            Ok(R::rlp_decode_with_bloom(&mut rlp.as_slice())
+                .map_err(|_| DatabaseError::Decode)?
+                .receipt)
+        }
+        _ => Err(DatabaseError::Decode.into()),
+    }
+}
+
+/// Encodes a receipt for a receipt static file with [`COMPACT_RECEIPTS_FORMAT`].
+pub(crate) fn encode_receipt<R: Receipt + Compact>(receipt: &R, buf: &mut Vec<u8>) {
+    let start = buf.len();
+    if let Eip658Value::Eip658(success) = receipt.status_or_post_state() &&
+        receipt.ty() & SUCCESS_BIT == 0
+    {
+        buf.push(COMPACT_RECEIPT_TAG);
+        encode_compact_receipt(receipt, success, buf);
+
+        // Only keep the compact encoding if it yields back the exact same receipt.
+        let mut expected = Vec::new();
+        receipt.rlp_encode_with_bloom(&receipt.bloom(), &mut expected);
+        if compact_receipt_to_rlp(&buf[start + 1..]).is_some_and(|rlp| rlp == expected) {
+            return
+        }
+        buf.truncate(start);
+    }
+
+    buf.push(COMPACT_CODEC_TAG);
+    receipt.to_compact(buf);
+}
+
+fn encode_compact_receipt<R: Receipt>(receipt: &R, success: bool, buf: &mut Vec<u8>) {
+    buf.push(receipt.ty() | if success { SUCCESS_BIT } else { 0 });
+    put_varint(buf, receipt.cumulative_gas_used());
+
+    let logs = receipt.logs();
+    let mut topics = Vec::new();
+    let mut indices = B256Map::default();
+    for topic in logs.iter().flat_map(|log| log.topics()) {
+        indices.entry(*topic).or_insert_with(|| {
+            topics.push(*topic);
+            topics.len() as u64 - 1
+        });
+    }
+
+    put_varint(buf, topics.len() as u64);
+    for topic in &topics {
+        buf.extend_from_slice(topic.as_slice());
+    }
+
+    put_varint(buf, logs.len() as u64);
+    for log in logs {
+        buf.extend_from_slice(log.address.as_slice());
+        put_varint(buf, log.topics().len() as u64);
+        for topic in log.topics() {
+            put_varint(buf, indices[topic]);
+        }
+        put_varint(buf, log.data.data.len() as u64);
+        buf.extend_from_slice(&log.data.data);
+    }
+}
+
+/// Rebuilds the network RLP encoding of a receipt from its compact encoding, recomputing the logs
+/// bloom.
+fn compact_receipt_to_rlp(mut buf: &[u8]) -> Option<Vec<u8>> {
+    let (&ty, rest) = buf.split_first()?;
+    buf = rest;
+    let cumulative_gas_used = get_varint(&mut buf)?;
+
+    let topics = take(&mut buf, usize::try_from(get_varint(&mut buf)?).ok()?.checked_mul(32)?)?
+        .chunks_exact(32)
+        .map(B256::from_slice)
+        .collect::<Vec<_>>();
+
+    let mut logs = Vec::new();
+    for _ in 0..get_varint(&mut buf)? {
+        let address = Address::from_slice(take(&mut buf, 20)?);
+        let log_topics = (0..get_varint(&mut buf)?)
+            .map(|_| topics.get(usize::try_from(get_varint(&mut buf)?).ok()?).copied())
+            .collect::<Option<Vec<_>>>()?;
+        let data = take(&mut buf, usize::try_from(get_varint(&mut buf)?).ok()?)?;
+        logs.push(Log::new_unchecked(address, log_topics, Bytes::copy_from_slice(data)));
+    }
+    if !buf.is_empty() {
+        return None
+    }
+
+    let bloom = logs_bloom(&logs);
+    let receipt = alloy_consensus::Receipt {
+        status: Eip658Value::Eip658(ty & SUCCESS_BIT != 0),
+        cumulative_gas_used,
+        logs,
+    };
+
+    let mut rlp = Vec::new();
+    let ty = ty & !SUCCESS_BIT;
+    if ty != 0 {
+        let payload_length = 1 + receipt.rlp_encoded_length_with_bloom(&bloom);
+        Header { list: false, payload_length }.encode(&mut rlp);
+        rlp.push(ty);
+    }
+    receipt.rlp_encode_with_bloom(&bloom, &mut rlp);
+    Some(rlp)
+}
+
+fn put_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn get_varint(buf: &mut &[u8]) -> Option<u64> {
+    let mut value = 0;
+    for shift in (0..64).step_by(7) {
+        let (&byte, rest) = buf.split_first()?;
+        *buf = rest;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value)
+        }
+    }
+    None
+}
+
+fn take<'a>(buf: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    let (value, rest) = buf.split_at_checked(len)?;
+    *buf = rest;
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256, bytes};
+    use reth_ethereum_primitives::{Receipt, TxType};
+
+    #[test]
+    fn compact_receipt_roundtrip() {
+        let transfer = b256!("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+        let from = B256::left_padding_from(
+            address!("0x00000000000000000000000000000000000000aa").as_slice(),
+        );
+        let log = Log::new_unchecked(
+            address!("0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"),
+            vec![transfer, from, from],
+            bytes!("0x00000000000000000000000000000000000000000000000000000000000f4240"),
+        );
+        let receipts = [
+            Receipt {
+                tx_type: TxType::Eip1559,
+                success: true,
+                cumulative_gas_used: 1_234_567,
+                logs: vec![log.clone(); 8],
+            },
+            Receipt {
+                tx_type: TxType::Legacy,
+                success: false,
+                cumulative_gas_used: 21_000,
+                logs: vec![],
+            },
+        ];
+
+        for receipt in &receipts {
+            let mut buf = Vec::new();
+            encode_receipt(receipt, &mut buf);
+            assert_eq!(buf[0], COMPACT_RECEIPT_TAG);
+            assert_eq!(&decode_receipt::<Receipt>(COMPACT_RECEIPTS_FORMAT, &buf).unwrap(), receipt);
+
+            let mut compact = Vec::new();
+            receipt.to_compact(&mut compact);
+            assert_eq!(&decode_receipt::<Receipt>(0, &compact).unwrap(), receipt);
+
+            // Rows encoded with the `Compact` codec are tagged as such
+            let mut tagged = vec![COMPACT_CODEC_TAG];
+            tagged.extend(compact);
+            assert_eq!(
+                &decode_receipt::<Receipt>(COMPACT_RECEIPTS_FORMAT, &tagged).unwrap(),
+                receipt
+            );
+        }
+
+        // Repeated topics are only stored once
+        let (mut buf, mut compact) = (Vec::new(), Vec::new());
+        encode_receipt(&receipts[0], &mut buf);
+        receipts[0].to_compact(&mut compact);
+        assert!(buf.len() < compact.len() / 2);
+
+        assert!(decode_receipt::<Receipt>(COMPACT_RECEIPTS_FORMAT, &[COMPACT_RECEIPT_TAG]).is_err());
+    }
+}
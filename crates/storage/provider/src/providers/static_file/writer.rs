@@ -1,5 +1,8 @@
 use super::{
-    manager::StaticFileProviderInner, metrics::StaticFileProviderMetrics, StaticFileProvider,
+    manager::StaticFileProviderInner,
+    metrics::StaticFileProviderMetrics,
+    receipts::{encode_receipt, COMPACT_RECEIPTS_FORMAT},
+    with_compression, StaticFileProvider,
 };
 use crate::providers::static_file::metrics::StaticFileProviderOperation;
 use alloy_consensus::BlockHeader;
@@ -7,12 +10,15 @@ use alloy_primitives::{BlockHash, BlockNumber, TxNumber, U256};
 use parking_lot::{lock_api::RwLockWriteGuard, RawRwLock, RwLock};
 use reth_codecs::Compact;
 use reth_db::models::{AccountBeforeTx, StorageBeforeTx};
-use reth_db_api::models::CompactU256;
+use reth_db_api::{
+    models::{CompactU256, StoredBlobSidecars, StoredBlockAccessList},
+    table::Compress,
+};
 use reth_nippy_jar::{NippyJar, NippyJarError, NippyJarWriter};
 use reth_node_types::NodePrimitives;
 use reth_primitives_traits::FastInstant as Instant;
 use reth_static_file_types::{
-    ChangesetOffset, ChangesetOffsetReader, ChangesetOffsetWriter, SegmentHeader,
+    ChangesetOffset, ChangesetOffsetReader, ChangesetOffsetWriter, Compression, SegmentHeader,
     SegmentRangeInclusive, StaticFileSegment,
 };
 use reth_storage_errors::provider::{ProviderError, ProviderResult, StaticFileWriterError};
@@ -64,6 +70,16 @@ enum PruneStrategy {
         /// The target block number to prune to.
         last_block: BlockNumber,
     },
+    /// Prune blob sidecars by number of blocks to delete.
+    BlobSidecars {
+        /// Number of blocks to delete.
+        num_blocks: u64,
+    },
+    /// Prune block access lists by number of blocks to delete.
+    BlockAccessLists {
+        /// Number of blocks to delete.
+        num_blocks: u64,
+    },
 }
 
 /// Static file writers for every known [`StaticFileSegment`].
@@ -78,6 +94,8 @@ pub(crate) struct StaticFileWriters<N> {
     transaction_senders: RwLock<Option<StaticFileProviderRW<N>>>,
     account_change_sets: RwLock<Option<StaticFileProviderRW<N>>>,
     storage_change_sets: RwLock<Option<StaticFileProviderRW<N>>>,
+    blob_sidecars: RwLock<Option<StaticFileProviderRW<N>>>,
+    block_access_lists: RwLock<Option<StaticFileProviderRW<N>>>,
 }
 
 impl<N> Default for StaticFileWriters<N> {
@@ -89,6 +107,8 @@ impl<N> Default for StaticFileWriters<N> {
             transaction_senders: Default::default(),
             account_change_sets: Default::default(),
             storage_change_sets: Default::default(),
+            blob_sidecars: Default::default(),
+            block_access_lists: Default::default(),
         }
     }
 }
@@ -106,6 +126,8 @@ impl<N: NodePrimitives> StaticFileWriters<N> {
             StaticFileSegment::TransactionSenders => self.transaction_senders.write(),
             StaticFileSegment::AccountChangeSets => self.account_change_sets.write(),
             StaticFileSegment::StorageChangeSets => self.storage_change_sets.write(),
+            StaticFileSegment::BlobSidecars => self.blob_sidecars.write(),
+            StaticFileSegment::BlockAccessLists => self.block_access_lists.write(),
         };
 
         if write_guard.is_none() {
@@ -124,6 +146,8 @@ impl<N: NodePrimitives> StaticFileWriters<N> {
             StaticFileSegment::TransactionSenders => self.transaction_senders.write(),
             StaticFileSegment::AccountChangeSets => self.account_change_sets.write(),
             StaticFileSegment::StorageChangeSets => self.storage_change_sets.write(),
+            StaticFileSegment::BlobSidecars => self.blob_sidecars.write(),
+            StaticFileSegment::BlockAccessLists => self.block_access_lists.write(),
         };
 
         *write_guard = None;
@@ -145,6 +169,7 @@ impl<N: NodePrimitives> StaticFileWriters<N> {
             &self.transaction_senders,
             &self.account_change_sets,
             &self.storage_change_sets,
+            &self.blob_sidecars,
         ] {
             let mut writer = writer_lock.write();
             if let Some(writer) = writer.as_mut() {
@@ -164,6 +189,7 @@ impl<N: NodePrimitives> StaticFileWriters<N> {
             &self.transaction_senders,
             &self.account_change_sets,
             &self.storage_change_sets,
+            &self.blob_sidecars,
         ] {
             let writer = writer_lock.read();
             if let Some(writer) = writer.as_ref() &&
@@ -195,6 +221,7 @@ impl<N: NodePrimitives> StaticFileWriters<N> {
             &self.transaction_senders,
             &self.account_change_sets,
             &self.storage_change_sets,
+            &self.blob_sidecars,
         ] {
             let mut writer = writer_lock.write();
             if let Some(writer) = writer.as_mut() {
@@ -315,7 +342,24 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
             ),
             Err(ProviderError::MissingStaticFileBlock(_, _)) => {
                 let path = static_file_provider.directory().join(segment.filename(&block_range));
-                (create_jar(segment, &path, block_range), path)
+
+                // Dictionaries are trained on the previous static file of the segment, if any.
+                let compression = static_file_provider.compression(segment);
+                let samples =
+                    if compression == Compression::ZstdWithDictionary && block_range.start() > 0 {
+                        static_file_provider
+                            .get_segment_provider_for_block(segment, block_range.start() - 1, None)
+                            .ok()
+                            .map(|provider| NippyJar::load(provider.data_path()))
+                            .transpose()
+                            .map_err(ProviderError::other)?
+                    } else {
+                        None
+                    };
+
+                let jar = create_jar(segment, compression, samples.as_ref(), &path, block_range)
+                    .with_value_format(static_file_provider.value_format(segment));
+                (jar, path)
             }
             Err(err) => return Err(err),
         };
@@ -627,6 +671,12 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
                 PruneStrategy::StorageChangeSets { last_block } => {
                     self.prune_storage_changeset_data(last_block)?
                 }
+                PruneStrategy::BlobSidecars { num_blocks } => {
+                    self.prune_blob_sidecar_data(num_blocks)?
+                }
+                PruneStrategy::BlockAccessLists { num_blocks } => {
+                    self.prune_block_access_list_data(num_blocks)?
+                }
             }
         }
 
@@ -782,8 +832,10 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
         if let Some(last_block) = self.writer.user_header().block_end() {
             // We have finished the previous static file and must freeze it
             if last_block == self.writer.user_header().expected_block_end() {
-                // Commits offsets and new user_header to disk
+                // Commits offsets and new user_header to disk, and seals the full static file
+                // with a checksum of its contents.
                 self.commit()?;
+                self.writer.seal().map_err(ProviderError::other)?;
 
                 // Opens the new static file
                 let (writer, data_path) =
@@ -989,12 +1041,12 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
                 let block_start = self.writer.user_header().expected_block_start();
 
                 // We only delete the file if it's NOT the first static file AND:
-                // * it's a Header segment  OR
+                // * it's a block-based segment  OR
                 // * it's a tx-based segment AND `last_block` is lower than the first block of this
                 //   file's block range. Otherwise, having no rows simply means that this block
                 //   range has no transactions, but the file should remain.
                 if block_start != 0 &&
-                    (segment.is_headers() || last_block.is_some_and(|b| b < block_start))
+                    (segment.is_block_based() || last_block.is_some_and(|b| b < block_start))
                 {
                     self.delete_current_and_open_previous()?;
                 } else {
@@ -1074,6 +1126,7 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
             .map_err(ProviderError::other)?
             .delete()
             .map_err(ProviderError::other)?;
+        self.reader().remove_cold_static_file(&current_path)?;
         Ok(())
     }
 
@@ -1092,6 +1145,35 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
         tx_num: TxNumber,
         value: V,
     ) -> ProviderResult<()> {
+        self.increment_tx(tx_num)?;
+        self.append_column(value)?;
+
+        Ok(())
+    }
+
+    /// Appends receipt to tx number-based static file, with the value format of the static file.
+    fn append_receipt_with_tx_number(
+        &mut self,
+        tx_num: TxNumber,
+        receipt: &N::Receipt,
+    ) -> ProviderResult<()>
+    where
+        N::Receipt: Compact,
+    {
+        if self.writer.jar().value_format() != COMPACT_RECEIPTS_FORMAT {
+            return self.append_with_tx_number(tx_num, receipt)
+        }
+
+        self.increment_tx(tx_num)?;
+        self.buf.clear();
+        encode_receipt(receipt, &mut self.buf);
+        self.writer.append_column(Some(Ok(&self.buf))).map_err(ProviderError::other)?;
+
+        Ok(())
+    }
+
+    /// Extends the tx range of the static file by `tx_num`, which has to be the next transaction.
+    fn increment_tx(&mut self, tx_num: TxNumber) -> ProviderResult<()> {
         if let Some(range) = self.writer.user_header().tx_range() {
             let next_tx = range.end() + 1;
             if next_tx != tx_num {
@@ -1106,8 +1188,6 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
             self.writer.user_header_mut().set_tx_range(tx_num, tx_num);
         }
 
-        self.append_column(value)?;
-
         Ok(())
     }
 
@@ -1197,6 +1277,68 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
         Ok(())
     }
 
+    /// Appends the blob sidecars of a block to static file.
+    ///
+    /// It **CALLS** `increment_block()` since every block has a row, which is empty for blocks
+    /// without blob sidecars.
+    pub fn append_blob_sidecars(
+        &mut self,
+        block: BlockNumber,
+        sidecars: &StoredBlobSidecars,
+    ) -> ProviderResult<()> {
+        let start = Instant::now();
+        self.ensure_no_queued_prune()?;
+
+        debug_assert!(self.writer.user_header().segment() == StaticFileSegment::BlobSidecars);
+
+        self.increment_block(block)?;
+
+        self.buf.clear();
+        sidecars.compress_to_buf(&mut self.buf);
+        self.writer.append_column(Some(Ok(&self.buf))).map_err(ProviderError::other)?;
+
+        if let Some(metrics) = &self.metrics {
+            metrics.record_segment_operation(
+                StaticFileSegment::BlobSidecars,
+                StaticFileProviderOperation::Append,
+                Some(start.elapsed()),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Appends the block access list of a block to static file.
+    ///
+    /// It **CALLS** `increment_block()` since every block has a row, which is empty for blocks
+    /// without a block access list.
+    pub fn append_block_access_list(
+        &mut self,
+        block: BlockNumber,
+        bal: &StoredBlockAccessList,
+    ) -> ProviderResult<()> {
+        let start = Instant::now();
+        self.ensure_no_queued_prune()?;
+
+        debug_assert!(self.writer.user_header().segment() == StaticFileSegment::BlockAccessLists);
+
+        self.increment_block(block)?;
+
+        self.buf.clear();
+        bal.compress_to_buf(&mut self.buf);
+        self.writer.append_column(Some(Ok(&self.buf))).map_err(ProviderError::other)?;
+
+        if let Some(metrics) = &self.metrics {
+            metrics.record_segment_operation(
+                StaticFileSegment::BlockAccessLists,
+                StaticFileProviderOperation::Append,
+                Some(start.elapsed()),
+            );
+        }
+
+        Ok(())
+    }
+
     /// Appends transaction to static file.
     ///
     /// It **DOES NOT CALL** `increment_block()`, it should be handled elsewhere. There might be
@@ -1234,7 +1376,7 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
         self.ensure_no_queued_prune()?;
 
         debug_assert!(self.writer.user_header().segment() == StaticFileSegment::Receipts);
-        self.append_with_tx_number(tx_num, receipt)?;
+        self.append_receipt_with_tx_number(tx_num, receipt)?;
 
         if let Some(metrics) = &self.metrics {
             metrics.record_segment_operation(
@@ -1270,7 +1412,7 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
 
         for receipt_result in receipts_iter {
             let (tx_num, receipt) = receipt_result?;
-            self.append_with_tx_number(tx_num, receipt.borrow())?;
+            self.append_receipt_with_tx_number(tx_num, receipt.borrow())?;
             count += 1;
         }
 
@@ -1534,6 +1676,19 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
         self.queue_prune(PruneStrategy::StorageChangeSets { last_block })
     }
 
+    /// Adds an instruction to prune the blob sidecars of the last `to_delete` blocks during commit.
+    pub fn prune_blob_sidecars(&mut self, to_delete: u64) -> ProviderResult<()> {
+        debug_assert_eq!(self.writer.user_header().segment(), StaticFileSegment::BlobSidecars);
+        self.queue_prune(PruneStrategy::BlobSidecars { num_blocks: to_delete })
+    }
+
+    /// Adds an instruction to prune the block access lists of the last `to_delete` blocks during
+    /// commit.
+    pub fn prune_block_access_lists(&mut self, to_delete: u64) -> ProviderResult<()> {
+        debug_assert_eq!(self.writer.user_header().segment(), StaticFileSegment::BlockAccessLists);
+        self.queue_prune(PruneStrategy::BlockAccessLists { num_blocks: to_delete })
+    }
+
     /// Adds an instruction to prune elements during commit using the specified strategy.
     fn queue_prune(&mut self, strategy: PruneStrategy) -> ProviderResult<()> {
         self.ensure_no_queued_prune()?;
@@ -1677,6 +1832,44 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
         Ok(())
     }
 
+    /// Prunes the blob sidecars of the last `to_delete` blocks from the data file.
+    fn prune_blob_sidecar_data(&mut self, to_delete: u64) -> ProviderResult<()> {
+        let start = Instant::now();
+
+        debug_assert!(self.writer.user_header().segment() == StaticFileSegment::BlobSidecars);
+
+        self.truncate(to_delete, None)?;
+
+        if let Some(metrics) = &self.metrics {
+            metrics.record_segment_operation(
+                StaticFileSegment::BlobSidecars,
+                StaticFileProviderOperation::Prune,
+                Some(start.elapsed()),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Prunes the block access lists of the last `to_delete` blocks from the data file.
+    fn prune_block_access_list_data(&mut self, to_delete: u64) -> ProviderResult<()> {
+        let start = Instant::now();
+
+        debug_assert!(self.writer.user_header().segment() == StaticFileSegment::BlockAccessLists);
+
+        self.truncate(to_delete, None)?;
+
+        if let Some(metrics) = &self.metrics {
+            metrics.record_segment_operation(
+                StaticFileSegment::BlockAccessLists,
+                StaticFileProviderOperation::Prune,
+                Some(start.elapsed()),
+            );
+        }
+
+        Ok(())
+    }
+
     /// Returns a [`StaticFileProvider`] associated with this writer.
     pub fn reader(&self) -> StaticFileProvider<N> {
         Self::upgrade_provider_to_strong_reference(&self.reader)
@@ -1721,20 +1914,16 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
 
 fn create_jar(
     segment: StaticFileSegment,
+    compression: Compression,
+    samples: Option<&NippyJar<SegmentHeader>>,
     path: &Path,
     expected_block_range: SegmentRangeInclusive,
 ) -> NippyJar<SegmentHeader> {
-    let mut jar = NippyJar::new(
+    let jar = NippyJar::new(
         segment.columns(),
         path,
         SegmentHeader::new(expected_block_range, None, None, segment),
     );
 
-    // Transaction and Receipt already have the compression scheme used natively in its encoding.
-    // (zstd-dictionary)
-    if segment.is_headers() {
-        jar = jar.with_lz4();
-    }
-
-    jar
+    with_compression(jar, compression, samples)
 }
@@ -11,8 +11,12 @@
 mod tests {
     use alloy_primitives::{Address, U256};
     use reth_db::{models::AccountBeforeTx, test_utils::create_test_static_files_dir};
+    use reth_nippy_jar::compression::Compressors;
     use reth_primitives_traits::Account;
-    use reth_static_file_types::{ChangesetOffset, ChangesetOffsetReader, StaticFileSegment};
+    use reth_static_file_types::{
+        ChangesetOffset, ChangesetOffsetReader, Compression, StaticFileSegment,
+    };
+    use reth_storage_api::ChangeSetReader;
     use std::{fs::OpenOptions, io::Write as _, path::PathBuf};
 
     use crate::providers::{
@@ -883,4 +887,50 @@ mod tests {
 
         drop(writer);
     }
+
+    /// New static files of a segment configured with zstd dictionaries are compressed with
+    /// dictionaries trained on the previous static file.
+    #[test]
+    fn test_zstd_dictionary_trained_on_previous_file() {
+        let (static_dir, _) = create_test_static_files_dir();
+        let provider: StaticFileProvider<EthPrimitives> =
+            StaticFileProviderBuilder::read_write(&static_dir)
+                .with_blocks_per_file(100)
+                .with_compression_for_segment(
+                    StaticFileSegment::AccountChangeSets,
+                    Compression::ZstdWithDictionary,
+                )
+                .build()
+                .unwrap();
+
+        let changesets =
+            (0..200).map(|block| generate_test_changeset(block, 200)).collect::<Vec<_>>();
+        {
+            let mut writer = provider.latest_writer(StaticFileSegment::AccountChangeSets).unwrap();
+            for (block, changeset) in changesets.iter().enumerate() {
+                writer.append_account_changeset(changeset.clone(), block as u64).unwrap();
+            }
+            writer.commit().unwrap();
+        }
+
+        let use_dict = |block| {
+            let jar_provider = provider
+                .get_segment_provider_for_block(StaticFileSegment::AccountChangeSets, block, None)
+                .unwrap();
+            match jar_provider.compressor() {
+                Some(Compressors::Zstd(zstd)) => zstd.use_dict,
+                _ => panic!("expected zstd compression"),
+            }
+        };
+        // The first file has no previous file to train dictionaries on.
+        assert!(!use_dict(0));
+        assert!(use_dict(100));
+
+        for block in [0, 150, 199] {
+            assert_eq!(
+                provider.account_block_changeset(block).unwrap(),
+                changesets[block as usize]
+            );
+        }
+    }
 }
@@ -10,7 +10,9 @@ use alloy_consensus::{
     transaction::{TransactionMeta, TxHashRef},
     BlockHeader,
 };
-use alloy_eips::{BlockHashOrNumber, BlockId, BlockNumberOrTag};
+use alloy_eips::{
+    eip7594::BlobTransactionSidecarVariant, BlockHashOrNumber, BlockId, BlockNumberOrTag,
+};
 use alloy_primitives::{
     keccak256,
     map::{AddressMap, B256Map, HashMap},
@@ -33,9 +35,10 @@ use reth_primitives_traits::{
 use reth_prune_types::{PruneCheckpoint, PruneModes, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_storage_api::{
-    BlockBodyIndicesProvider, BytecodeReader, DBProvider, DatabaseProviderFactory,
-    HashedPostStateProvider, NodePrimitivesProvider, StageCheckpointReader, StateProofProvider,
-    StorageChangeSetReader, StorageRootProvider, StorageSettingsCache,
+    BlobSidecarProvider, BlockBodyIndicesProvider, BytecodeReader, DBProvider,
+    DatabaseProviderFactory, HashedPostStateProvider, LogIndexProvider, NodePrimitivesProvider,
+    StageCheckpointReader, StateProofProvider, StorageChangeSetReader, StorageRootProvider,
+    StorageSettingsCache, TraceAddressIndexProvider,
 };
 use reth_storage_errors::provider::{ConsistentViewError, ProviderError, ProviderResult};
 use reth_trie::{
@@ -989,6 +992,62 @@ impl<T: NodePrimitives, ChainSpec: EthChainSpec + Send + Sync + 'static> StatePr
     }
 }
 
+impl<T: NodePrimitives, ChainSpec: Send + Sync> BlobSidecarProvider
+    for MockEthProvider<T, ChainSpec>
+{
+    fn blob_sidecars(
+        &self,
+        _block: BlockNumber,
+    ) -> ProviderResult<Option<Vec<(TxHash, BlobTransactionSidecarVariant)>>> {
+        Ok(None)
+    }
+
+    fn blob_sidecar_by_versioned_hash(
+        &self,
+        _versioned_hash: B256,
+    ) -> ProviderResult<Option<BlobTransactionSidecarVariant>> {
+        Ok(None)
+    }
+}
+
+impl<T: NodePrimitives, ChainSpec: Send + Sync> TraceAddressIndexProvider
+    for MockEthProvider<T, ChainSpec>
+{
+    fn trace_address_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn trace_address_blocks(
+        &self,
+        _addresses: &[Address],
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::new())
+    }
+}
+
+impl<T: NodePrimitives, ChainSpec: Send + Sync> LogIndexProvider for MockEthProvider<T, ChainSpec> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn log_address_blocks(
+        &self,
+        _addresses: &[Address],
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::new())
+    }
+
+    fn log_topic_blocks(
+        &self,
+        _topics: &[B256],
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::new())
+    }
+}
+
 impl<T: NodePrimitives, ChainSpec: Send + Sync> BlockBodyIndicesProvider
     for MockEthProvider<T, ChainSpec>
 {
@@ -1,10 +1,11 @@
 //! Helper provider traits to encapsulate all provider traits for simplicity.
 
 use crate::{
-    AccountReader, BalProvider, BlockReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader,
-    DatabaseProviderFactory, HashedPostStateProvider, PruneCheckpointReader,
-    RocksDBProviderFactory, StageCheckpointReader, StateProviderFactory, StateReader,
-    StaticFileProviderFactory,
+    AccountReader, BalProvider, BlobSidecarProvider, BlockChangesetReader, BlockReader,
+    BlockReaderIdExt, ChainSpecProvider, ChangeSetReader, DatabaseProviderFactory,
+    HashedPostStateProvider, LogIndexProvider, PruneCheckpointReader, RocksDBProviderFactory,
+    StageCheckpointReader, StateProviderFactory, StateReader, StaticFileProviderFactory,
+    TraceAddressIndexProvider,
 };
 use reth_chain_state::{
     CanonStateSubscriptions, ForkChoiceSubscriptions, PersistedBlockSubscriptions,
@@ -33,12 +34,16 @@ pub trait FullProvider<N: NodeTypesWithDB>:
         Header = HeaderTy<N>,
     > + AccountReader
     + BalProvider
+    + BlobSidecarProvider
+    + TraceAddressIndexProvider
+    + LogIndexProvider
     + StateProviderFactory
     + StateReader
     + HashedPostStateProvider
     + ChainSpecProvider<ChainSpec = N::ChainSpec>
     + ChangeSetReader
     + StorageChangeSetReader
+    + BlockChangesetReader
     + CanonStateSubscriptions
     + ForkChoiceSubscriptions<Header = HeaderTy<N>>
     + PersistedBlockSubscriptions
@@ -70,12 +75,16 @@ impl<T, N: NodeTypesWithDB> FullProvider<N> for T where
             Header = HeaderTy<N>,
         > + AccountReader
         + BalProvider
+        + BlobSidecarProvider
+        + TraceAddressIndexProvider
+        + LogIndexProvider
         + StateProviderFactory
         + StateReader
         + HashedPostStateProvider
         + ChainSpecProvider<ChainSpec = N::ChainSpec>
         + ChangeSetReader
         + StorageChangeSetReader
+        + BlockChangesetReader
         + CanonStateSubscriptions
         + ForkChoiceSubscriptions<Header = HeaderTy<N>>
         + PersistedBlockSubscriptions
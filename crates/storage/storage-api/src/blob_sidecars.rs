@@ -0,0 +1,46 @@
+use alloc::vec::Vec;
+use alloy_eips::eip7594::BlobTransactionSidecarVariant;
+use alloy_primitives::{BlockNumber, TxHash, B256};
+use reth_storage_errors::provider::ProviderResult;
+
+/// Client trait for fetching the blob sidecars of canonical blocks persisted in static files.
+///
+/// Only the blob sidecars within the configured retention window are available.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait BlobSidecarProvider: Send {
+    /// Returns the blob sidecars of all blob transactions of the block, with the hashes of their
+    /// transactions.
+    ///
+    /// Returns `None` if the block is outside the persisted range.
+    fn blob_sidecars(
+        &self,
+        block: BlockNumber,
+    ) -> ProviderResult<Option<Vec<(TxHash, BlobTransactionSidecarVariant)>>>;
+
+    /// Returns the blob sidecar containing the blob with the given versioned hash.
+    fn blob_sidecar_by_versioned_hash(
+        &self,
+        versioned_hash: B256,
+    ) -> ProviderResult<Option<BlobTransactionSidecarVariant>>;
+}
+
+/// Blob sidecar writer.
+pub trait BlobSidecarWriter {
+    /// Appends the blob sidecars of the block and indexes their versioned hashes.
+    ///
+    /// Blocks between the highest persisted block and `block` are stored without sidecars, and
+    /// blocks that were already persisted are skipped.
+    fn write_blob_sidecars(
+        &self,
+        block: BlockNumber,
+        sidecars: Vec<(TxHash, BlobTransactionSidecarVariant)>,
+    ) -> ProviderResult<()>;
+
+    /// Removes the blob sidecars of all blocks above `block`, e.g. when they were reorged out.
+    fn remove_blob_sidecars_above(&self, block: BlockNumber) -> ProviderResult<()>;
+
+    /// Deletes the blob sidecars static files whose blocks are all below `block`.
+    ///
+    /// Returns the number of deleted static files.
+    fn prune_blob_sidecars(&self, block: BlockNumber) -> ProviderResult<usize>;
+}
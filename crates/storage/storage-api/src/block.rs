@@ -1,5 +1,5 @@
 use crate::{
-    BlockBodyIndicesProvider, BlockNumReader, HeaderProvider, ReceiptProvider,
+    BlockBodyIndicesProvider, BlockNumReader, BlockRangeIter, HeaderProvider, ReceiptProvider,
     ReceiptProviderIdExt, TransactionVariant, TransactionsProvider,
 };
 use alloc::{sync::Arc, vec::Vec};
@@ -164,6 +164,27 @@ pub trait BlockReader:
 
     /// Returns the block number that contains the given transaction.
     fn block_by_transaction_id(&self, id: TxNumber) -> ProviderResult<Option<BlockNumber>>;
+
+    /// Returns a lazy iterator over the blocks in the given inclusive range, that reads them in
+    /// chunks with [`Self::block_range`] instead of materializing the whole range.
+    ///
+    /// Note: yields only available blocks
+    fn block_range_iter(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> BlockRangeIter<'_, Self, Self::Block> {
+        BlockRangeIter::new(self, range, Self::block_range)
+    }
+
+    /// Returns a lazy iterator over the recovered blocks in the given inclusive range, that reads
+    /// them in chunks with [`Self::recovered_block_range`] instead of materializing the whole
+    /// range.
+    fn recovered_block_range_iter(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> BlockRangeIter<'_, Self, RecoveredBlock<Self::Block>> {
+        BlockRangeIter::new(self, range, Self::recovered_block_range)
+    }
 }
 
 impl<T: BlockReader + Send + Sync> BlockReader for Arc<T> {
@@ -0,0 +1,121 @@
+use crate::StateProvider;
+use alloc::vec::Vec;
+use alloy_primitives::{Address, BlockNumber, B256, U256};
+use core::ops::RangeInclusive;
+use reth_db_models::{AccountBeforeTx, StorageBeforeTx};
+use reth_primitives_traits::Account;
+use reth_storage_errors::provider::ProviderResult;
+
+/// The change of an account in a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountChange {
+    /// The address of the account.
+    pub address: Address,
+    /// The account before the block, `None` if it did not exist.
+    pub pre: Option<Account>,
+    /// The account after the block, `None` if it does not exist.
+    pub post: Option<Account>,
+}
+
+/// The change of a storage slot in a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageSlotChange {
+    /// The address of the account owning the slot.
+    pub address: Address,
+    /// The storage key of the slot.
+    pub key: B256,
+    /// The value of the slot before the block.
+    pub pre: U256,
+    /// The value of the slot after the block.
+    pub post: U256,
+}
+
+/// The account and storage changes of a block, with the values before and after the block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlockChangeset {
+    /// The number of the block.
+    pub block_number: BlockNumber,
+    /// The changed accounts, in the order of the account changeset.
+    pub accounts: Vec<AccountChange>,
+    /// The changed storage slots, in the order of the storage changeset.
+    pub storage: Vec<StorageSlotChange>,
+}
+
+impl BlockChangeset {
+    /// Creates the changeset of a block from its account and storage changesets, reading the
+    /// values after the block from `state`, the state at the end of the block.
+    pub fn from_changesets(
+        block_number: BlockNumber,
+        accounts: Vec<AccountBeforeTx>,
+        storage: Vec<StorageBeforeTx>,
+        state: &(impl StateProvider + ?Sized),
+    ) -> ProviderResult<Self> {
+        let accounts = accounts
+            .into_iter()
+            .map(|account| {
+                Ok(AccountChange {
+                    address: account.address,
+                    pre: account.info,
+                    post: state.basic_account(&account.address)?,
+                })
+            })
+            .collect::<ProviderResult<_>>()?;
+        let storage = storage
+            .into_iter()
+            .map(|slot| {
+                Ok(StorageSlotChange {
+                    address: slot.address,
+                    key: slot.key,
+                    pre: slot.value,
+                    post: state.storage(slot.address, slot.key)?.unwrap_or_default(),
+                })
+            })
+            .collect::<ProviderResult<_>>()?;
+
+        Ok(Self { block_number, accounts, storage })
+    }
+
+    /// Returns `true` if the block changed no account or storage slot.
+    pub const fn is_empty(&self) -> bool {
+        self.accounts.is_empty() && self.storage.is_empty()
+    }
+}
+
+/// Reader of the account and storage changes of blocks, with the values before and after each
+/// block.
+///
+/// This resolves the post-block values of the changesets, so consumers don't need to walk the
+/// changeset tables and look up the state themselves.
+pub trait BlockChangesetReader {
+    /// Returns the account and storage changes of the given block.
+    fn block_changeset(&self, block_number: BlockNumber) -> ProviderResult<BlockChangeset>;
+
+    /// Returns an iterator over the changes of each block in the range, in ascending order.
+    ///
+    /// Blocks are read lazily, one at a time, as the iterator advances.
+    fn block_changesets(&self, range: RangeInclusive<BlockNumber>) -> BlockChangesets<'_, Self> {
+        BlockChangesets { provider: self, blocks: range }
+    }
+}
+
+/// Iterator over the [`BlockChangeset`]s of a range of blocks.
+///
+/// See [`BlockChangesetReader::block_changesets`].
+#[derive(Debug)]
+pub struct BlockChangesets<'a, P: ?Sized> {
+    provider: &'a P,
+    blocks: RangeInclusive<BlockNumber>,
+}
+
+impl<P: BlockChangesetReader + ?Sized> Iterator for BlockChangesets<'_, P> {
+    type Item = ProviderResult<BlockChangeset>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let block_number = self.blocks.next()?;
+        Some(self.provider.block_changeset(block_number))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.blocks.size_hint()
+    }
+}
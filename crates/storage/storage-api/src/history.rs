@@ -55,4 +55,37 @@ pub trait HistoryWriter: Send {
 
     /// Read account/storage changesets and update account/storage history indices.
     fn update_history_indices(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<()>;
+
+    /// Compacts the account history shards of up to `limit` addresses, starting at `from`.
+    ///
+    /// Shards left partially filled, e.g. by pruning or unwinding, are merged and rechunked, so
+    /// that every shard of an address but the last one is full.
+    fn compact_account_history_shards(
+        &self,
+        from: Address,
+        limit: usize,
+    ) -> ProviderResult<HistoryShardCompaction<Address>>;
+
+    /// Compacts the storage history shards of up to `limit` storage slots, starting at `from`.
+    ///
+    /// See [`HistoryWriter::compact_account_history_shards`].
+    fn compact_storage_history_shards(
+        &self,
+        from: (Address, B256),
+        limit: usize,
+    ) -> ProviderResult<HistoryShardCompaction<(Address, B256)>>;
+}
+
+/// Outcome of compacting a batch of history shards, see
+/// [`HistoryWriter::compact_account_history_shards`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryShardCompaction<K> {
+    /// Number of keys whose shards were visited.
+    pub keys: usize,
+    /// Number of keys whose shards were rewritten.
+    pub compacted_keys: usize,
+    /// Number of shards removed by the compaction.
+    pub removed_shards: usize,
+    /// Key to continue the compaction from, `None` if all keys were visited.
+    pub next: Option<K>,
 }
@@ -19,6 +19,9 @@ pub use bal::*;
 mod account;
 pub use account::*;
 
+mod blob_sidecars;
+pub use blob_sidecars::*;
+
 mod block;
 pub use block::*;
 
@@ -39,6 +42,9 @@ pub use header::*;
 mod prune_checkpoint;
 pub use prune_checkpoint::*;
 
+mod range_iter;
+pub use range_iter::*;
+
 mod receipts;
 pub use receipts::*;
 
@@ -60,6 +66,15 @@ pub use trie::*;
 mod chain_info;
 pub use chain_info::*;
 
+mod changeset;
+pub use changeset::*;
+
+mod trace_index;
+pub use trace_index::*;
+
+mod log_index;
+pub use log_index::*;
+
 #[cfg(feature = "db-api")]
 mod database_provider;
 #[cfg(feature = "db-api")]
@@ -0,0 +1,44 @@
+use alloc::vec::Vec;
+use alloy_primitives::{Address, BlockNumber, Log, B256};
+use core::ops::RangeInclusive;
+use reth_storage_errors::provider::ProviderResult;
+
+/// Client trait for the index of the addresses and topics of the logs emitted in each block.
+///
+/// The index is optional and only covers the range of blocks returned by
+/// [`LogIndexProvider::log_index_range`]. Topics are indexed regardless of their position in the
+/// log.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait LogIndexProvider: Send {
+    /// Returns the range of blocks covered by the index, or `None` if no block was indexed.
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>>;
+
+    /// Returns the blocks in the range with logs emitted by any of the addresses, in ascending
+    /// order.
+    ///
+    /// Only meaningful for the part of the range covered by the index.
+    fn log_address_blocks(
+        &self,
+        addresses: &[Address],
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>>;
+
+    /// Returns the blocks in the range with logs containing any of the topics, in ascending
+    /// order.
+    ///
+    /// Only meaningful for the part of the range covered by the index.
+    fn log_topic_blocks(
+        &self,
+        topics: &[B256],
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>>;
+}
+
+/// Log index writer.
+pub trait LogIndexWriter {
+    /// Appends the addresses and topics of the logs of each block to the index.
+    ///
+    /// The blocks must be ascending and directly follow the indexed range, if any block was
+    /// already indexed.
+    fn write_log_index(&self, blocks: Vec<(BlockNumber, Vec<Log>)>) -> ProviderResult<()>;
+}
@@ -8,6 +8,12 @@ use reth_storage_errors::provider::{ProviderError, ProviderResult};
 pub mod keys {
     /// Storage configuration settings for this node.
     pub const STORAGE_SETTINGS: &str = "storage_settings";
+
+    /// Range of blocks covered by the `trace_filter` address index.
+    pub const TRACE_ADDRESS_INDEX_RANGE: &str = "trace_address_index_range";
+
+    /// Range of blocks covered by the log index.
+    pub const LOG_INDEX_RANGE: &str = "log_index_range";
 }
 
 /// Client trait for reading node metadata from the database.
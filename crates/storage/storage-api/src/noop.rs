@@ -3,19 +3,23 @@
 pub use crate::bal::NoopBalStore;
 
 use crate::{
-    AccountReader, BalProvider, BalStoreHandle, BlockBodyIndicesProvider, BlockHashReader,
-    BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt, BlockSource, BytecodeReader,
-    ChangeSetReader, HashedPostStateProvider, HeaderProvider, NodePrimitivesProvider,
+    AccountReader, BalProvider, BalStoreHandle, BlobSidecarProvider, BlockBodyIndicesProvider,
+    BlockChangeset, BlockChangesetReader, BlockHashReader, BlockIdReader, BlockNumReader,
+    BlockReader, BlockReaderIdExt, BlockSource, BytecodeReader, ChangeSetReader,
+    HashedPostStateProvider, HeaderProvider, LogIndexProvider, NodePrimitivesProvider,
     PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt, StageCheckpointReader,
     StateProofProvider, StateProvider, StateProviderBox, StateProviderFactory, StateReader,
-    StateRootProvider, StorageRootProvider, TransactionVariant, TransactionsProvider,
+    StateRootProvider, StorageRootProvider, TraceAddressIndexProvider, TransactionVariant,
+    TransactionsProvider,
 };
 
 #[cfg(feature = "db-api")]
 use crate::{DBProvider, DatabaseProviderFactory, StorageChangeSetReader, StorageSettingsCache};
 use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
 use alloy_consensus::transaction::TransactionMeta;
-use alloy_eips::{BlockHashOrNumber, BlockId, BlockNumberOrTag};
+use alloy_eips::{
+    eip7594::BlobTransactionSidecarVariant, BlockHashOrNumber, BlockId, BlockNumberOrTag,
+};
 use alloy_primitives::{
     Address, BlockHash, BlockNumber, Bytes, StorageKey, StorageValue, TxHash, TxNumber, B256,
 };
@@ -117,6 +121,60 @@ impl<ChainSpec, N> BalProvider for NoopProvider<ChainSpec, N> {
     }
 }
 
+impl<ChainSpec: Send + Sync, N: Send + Sync> BlobSidecarProvider for NoopProvider<ChainSpec, N> {
+    fn blob_sidecars(
+        &self,
+        _block: BlockNumber,
+    ) -> ProviderResult<Option<Vec<(TxHash, BlobTransactionSidecarVariant)>>> {
+        Ok(None)
+    }
+
+    fn blob_sidecar_by_versioned_hash(
+        &self,
+        _versioned_hash: B256,
+    ) -> ProviderResult<Option<BlobTransactionSidecarVariant>> {
+        Ok(None)
+    }
+}
+
+impl<ChainSpec: Send + Sync, N: Send + Sync> TraceAddressIndexProvider
+    for NoopProvider<ChainSpec, N>
+{
+    fn trace_address_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn trace_address_blocks(
+        &self,
+        _addresses: &[Address],
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::new())
+    }
+}
+
+impl<ChainSpec: Send + Sync, N: Send + Sync> LogIndexProvider for NoopProvider<ChainSpec, N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn log_address_blocks(
+        &self,
+        _addresses: &[Address],
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::new())
+    }
+
+    fn log_topic_blocks(
+        &self,
+        _topics: &[B256],
+        _range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::new())
+    }
+}
+
 /// Noop implementation for testing purposes
 impl<ChainSpec: Send + Sync, N: Send + Sync> BlockHashReader for NoopProvider<ChainSpec, N> {
     fn block_hash(&self, _number: u64) -> ProviderResult<Option<B256>> {
@@ -416,6 +474,12 @@ impl<C: Send + Sync, N: NodePrimitives> ChangeSetReader for NoopProvider<C, N> {
     }
 }
 
+impl<C: Send + Sync, N: NodePrimitives> BlockChangesetReader for NoopProvider<C, N> {
+    fn block_changeset(&self, block_number: BlockNumber) -> ProviderResult<BlockChangeset> {
+        Ok(BlockChangeset { block_number, ..Default::default() })
+    }
+}
+
 #[cfg(feature = "db-api")]
 impl<C: Send + Sync, N: NodePrimitives> StorageChangeSetReader for NoopProvider<C, N> {
     fn storage_changeset(
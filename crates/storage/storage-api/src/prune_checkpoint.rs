@@ -1,6 +1,7 @@
 use alloc::vec::Vec;
+use alloy_primitives::{BlockNumber, TxNumber};
 use reth_prune_types::{PruneCheckpoint, PruneSegment};
-use reth_storage_errors::provider::ProviderResult;
+use reth_storage_errors::provider::{ProviderError, ProviderResult};
 
 /// The trait for fetching prune checkpoint related data.
 #[auto_impl::auto_impl(&, Arc)]
@@ -13,6 +14,40 @@ pub trait PruneCheckpointReader: Send {
 
     /// Fetch all the prune checkpoints.
     fn get_prune_checkpoints(&self) -> ProviderResult<Vec<(PruneSegment, PruneCheckpoint)>>;
+
+    /// Returns [`ProviderError::BlockDataPruned`] if the data of the segment at the given block
+    /// was pruned.
+    fn ensure_block_not_pruned(
+        &self,
+        segment: PruneSegment,
+        block_number: BlockNumber,
+    ) -> ProviderResult<()> {
+        let checkpoint = self.get_prune_checkpoint(segment)?;
+        if checkpoint
+            .and_then(|checkpoint| checkpoint.block_number)
+            .is_some_and(|pruned| block_number <= pruned)
+        {
+            return Err(ProviderError::BlockDataPruned { segment, block_number })
+        }
+        Ok(())
+    }
+
+    /// Returns [`ProviderError::TransactionDataPruned`] if the data of the segment for the given
+    /// transaction was pruned.
+    fn ensure_tx_not_pruned(
+        &self,
+        segment: PruneSegment,
+        tx_number: TxNumber,
+    ) -> ProviderResult<()> {
+        let checkpoint = self.get_prune_checkpoint(segment)?;
+        if checkpoint
+            .and_then(|checkpoint| checkpoint.tx_number)
+            .is_some_and(|pruned| tx_number <= pruned)
+        {
+            return Err(ProviderError::TransactionDataPruned { segment, tx_number })
+        }
+        Ok(())
+    }
 }
 
 /// The trait for updating prune checkpoint related data.
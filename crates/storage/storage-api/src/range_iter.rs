@@ -0,0 +1,123 @@
+use alloc::vec::{IntoIter, Vec};
+use alloy_primitives::BlockNumber;
+use core::{fmt, ops::RangeInclusive};
+use reth_storage_errors::provider::ProviderResult;
+
+/// Default number of blocks a [`BlockRangeIter`] reads from the provider at once.
+pub const DEFAULT_BLOCK_RANGE_ITER_CHUNK_SIZE: u64 = 1_000;
+
+/// A lazy iterator over the items of a block range, e.g. blocks, receipts or transactions.
+///
+/// Instead of materializing the whole range, the items are read from the provider in chunks of
+/// blocks, see [`BlockRangeIter::with_chunk_size`], so only a single chunk is held in memory at a
+/// time. The iterator is fused after the first error.
+pub struct BlockRangeIter<'a, P: ?Sized, T> {
+    /// The provider to read the items from.
+    provider: &'a P,
+    /// Reads the items of a range of blocks.
+    read: fn(&P, RangeInclusive<BlockNumber>) -> ProviderResult<Vec<T>>,
+    /// The blocks that have not been read yet, `None` once all blocks were read or reading
+    /// failed.
+    remaining: Option<RangeInclusive<BlockNumber>>,
+    /// The maximum number of blocks read at once.
+    chunk_size: u64,
+    /// The items of the last chunk that have not been yielded yet.
+    chunk: IntoIter<T>,
+}
+
+impl<'a, P: ?Sized, T> BlockRangeIter<'a, P, T> {
+    /// Creates a new iterator over the items of `range`, read from `provider` with `read`.
+    pub fn new(
+        provider: &'a P,
+        range: RangeInclusive<BlockNumber>,
+        read: fn(&P, RangeInclusive<BlockNumber>) -> ProviderResult<Vec<T>>,
+    ) -> Self {
+        Self {
+            provider,
+            read,
+            remaining: Some(range),
+            chunk_size: DEFAULT_BLOCK_RANGE_ITER_CHUNK_SIZE,
+            chunk: Vec::new().into_iter(),
+        }
+    }
+
+    /// Sets the maximum number of blocks read from the provider at once.
+    pub fn with_chunk_size(mut self, chunk_size: u64) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+}
+
+impl<P: ?Sized, T> Iterator for BlockRangeIter<'_, P, T> {
+    type Item = ProviderResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.chunk.next() {
+                return Some(Ok(item))
+            }
+
+            let (start, last) = self.remaining.take()?.into_inner();
+            if start > last {
+                return None
+            }
+
+            let end = start.saturating_add(self.chunk_size - 1).min(last);
+            match (self.read)(self.provider, start..=end) {
+                Ok(items) => self.chunk = items.into_iter(),
+                Err(err) => return Some(Err(err)),
+            }
+            if end < last {
+                self.remaining = Some(end + 1..=last);
+            }
+        }
+    }
+}
+
+impl<P: ?Sized, T> fmt::Debug for BlockRangeIter<'_, P, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockRangeIter")
+            .field("remaining", &self.remaining)
+            .field("chunk_size", &self.chunk_size)
+            .field("buffered", &self.chunk.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_storage_errors::provider::ProviderError;
+
+    fn numbers(_: &(), range: RangeInclusive<BlockNumber>) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(range.collect())
+    }
+
+    #[test]
+    fn yields_range_in_chunks() {
+        let items = BlockRangeIter::new(&(), 3..=12, numbers)
+            .with_chunk_size(4)
+            .collect::<ProviderResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(items, (3..=12).collect::<Vec<_>>());
+
+        let mut unbounded = BlockRangeIter::new(&(), 0..=u64::MAX, numbers).with_chunk_size(1);
+        assert_eq!(unbounded.nth(2).transpose().unwrap(), Some(2));
+    }
+
+    #[test]
+    fn stops_after_error() {
+        let mut iter = BlockRangeIter::new(&(), 0..=10, |_: &(), range| {
+            if *range.start() == 0 {
+                return Ok(range.collect())
+            }
+            Err(ProviderError::UnsupportedProvider)
+        })
+        .with_chunk_size(2);
+
+        assert_eq!(iter.next().transpose().unwrap(), Some(0));
+        assert_eq!(iter.next().transpose().unwrap(), Some(1));
+        assert!(matches!(iter.next(), Some(Err(ProviderError::UnsupportedProvider))));
+        assert!(iter.next().is_none());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::BlockIdReader;
+use crate::{BlockIdReader, BlockRangeIter};
 use alloc::vec::Vec;
 use alloy_eips::{BlockHashOrNumber, BlockId, BlockNumberOrTag};
 use alloy_primitives::{BlockNumber, TxHash, TxNumber};
@@ -52,6 +52,17 @@ pub trait ReceiptProvider {
         &self,
         block_range: RangeInclusive<BlockNumber>,
     ) -> ProviderResult<Vec<Vec<Self::Receipt>>>;
+
+    /// Returns a lazy iterator over the receipts of each block in the given inclusive range, that
+    /// reads them in chunks with [`Self::receipts_by_block_range`] instead of materializing the
+    /// whole range.
+    #[auto_impl(keep_default_for(&, Arc))]
+    fn receipts_by_block_range_iter(
+        &self,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> BlockRangeIter<'_, Self, Vec<Self::Receipt>> {
+        BlockRangeIter::new(self, block_range, Self::receipts_by_block_range)
+    }
 }
 
 /// Trait extension for `ReceiptProvider`, for types that implement `BlockId` conversion.
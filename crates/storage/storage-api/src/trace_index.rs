@@ -0,0 +1,37 @@
+use alloc::vec::Vec;
+use alloy_primitives::{Address, BlockNumber};
+use core::ops::RangeInclusive;
+use reth_storage_errors::provider::ProviderResult;
+
+/// Client trait for the index of the addresses appearing in the `trace_filter` traces of each
+/// block.
+///
+/// The index is optional and only covers the range of blocks returned by
+/// [`TraceAddressIndexProvider::trace_address_index_range`].
+#[auto_impl::auto_impl(&, Arc)]
+pub trait TraceAddressIndexProvider: Send {
+    /// Returns the range of blocks covered by the index, or `None` if no block was indexed.
+    fn trace_address_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>>;
+
+    /// Returns the blocks in the range whose traces involve any of the addresses, in ascending
+    /// order.
+    ///
+    /// Only meaningful for the part of the range covered by the index.
+    fn trace_address_blocks(
+        &self,
+        addresses: &[Address],
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>>;
+}
+
+/// Trace address index writer.
+pub trait TraceAddressIndexWriter {
+    /// Appends the addresses involved in the traces of each block to the index.
+    ///
+    /// The blocks must be ascending and directly follow the indexed range, if any block was
+    /// already indexed.
+    fn write_trace_address_index(
+        &self,
+        blocks: Vec<(BlockNumber, Vec<Address>)>,
+    ) -> ProviderResult<()>;
+}
@@ -1,4 +1,4 @@
-use crate::{BlockNumReader, BlockReader};
+use crate::{BlockNumReader, BlockRangeIter, BlockReader};
 use alloc::vec::Vec;
 use alloy_consensus::transaction::TransactionMeta;
 use alloy_eips::BlockHashOrNumber;
@@ -61,6 +61,19 @@ pub trait TransactionsProvider: BlockNumReader + Send {
         range: impl RangeBounds<BlockNumber>,
     ) -> ProviderResult<Vec<Vec<Self::Transaction>>>;
 
+    /// Returns a lazy iterator over the transactions of each block in the given inclusive range,
+    /// that reads them in chunks with [`Self::transactions_by_block_range`] instead of
+    /// materializing the whole range.
+    #[auto_impl(keep_default_for(&, Arc))]
+    fn transactions_by_block_range_iter(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> BlockRangeIter<'_, Self, Vec<Self::Transaction>> {
+        BlockRangeIter::new(self, range, |provider, range| {
+            provider.transactions_by_block_range(range)
+        })
+    }
+
     /// Get transactions by tx range.
     fn transactions_by_tx_range(
         &self,
@@ -108,4 +121,19 @@ pub trait TransactionsProviderExt: BlockReader {
         &self,
         tx_range: Range<TxNumber>,
     ) -> ProviderResult<Vec<(TxHash, TxNumber)>>;
+
+    /// Get transaction hashes from a transaction range, hashing chunks of `chunk_size`
+    /// transactions in parallel where supported.
+    ///
+    /// The hashes are returned ordered by transaction number.
+    fn transaction_hashes_by_range_with_chunk_size(
+        &self,
+        tx_range: Range<TxNumber>,
+        chunk_size: usize,
+    ) -> ProviderResult<Vec<(TxHash, TxNumber)>> {
+        let _ = chunk_size;
+        let mut hashes = self.transaction_hashes_by_range(tx_range)?;
+        hashes.sort_unstable_by_key(|(_, tx_number)| *tx_number);
+        Ok(hashes)
+    }
 }
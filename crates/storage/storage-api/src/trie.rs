@@ -92,6 +92,15 @@ pub trait StateProofProvider {
         target: HashedPostState,
         mode: ExecutionWitnessMode,
     ) -> ProviderResult<Vec<Bytes>>;
+
+    /// Returns the block this state is proven on top of, and the [`TrieInput`] that turns the
+    /// state of that block into this state, if computing it is expensive.
+    ///
+    /// Concurrent proofs of this state can compute the input once and pass it to the proofs of
+    /// the state of the returned block, instead of computing it again for every proof.
+    fn proof_input(&self) -> ProviderResult<Option<(B256, TrieInput)>> {
+        Ok(None)
+    }
 }
 
 /// Trie Writer
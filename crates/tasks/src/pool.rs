@@ -1,6 +1,7 @@
 //! Additional helpers for executing tracing calls
 
 use crate::metrics::WorkerPoolMetrics;
+use parking_lot::Mutex;
 use std::{
     any::Any,
     cell::RefCell,
@@ -26,23 +27,71 @@ use tokio::sync::{oneshot, AcquireError, OwnedSemaphorePermit, Semaphore};
 /// This types serves as an entry guard for the [`BlockingTaskPool`] and is used to rate limit
 /// parallel blocking tasks in the pool.
 #[derive(Clone, Debug)]
-pub struct BlockingTaskGuard(Arc<Semaphore>);
+pub struct BlockingTaskGuard {
+    semaphore: Arc<Semaphore>,
+    /// The maximum number of blocking tasks in parallel
+    max_blocking_tasks: Arc<AtomicUsize>,
+    /// Number of permits that were in use when the maximum was lowered, which are forgotten
+    /// once they are acquired again.
+    debt: Arc<Mutex<usize>>,
+}
 
 impl BlockingTaskGuard {
     /// Create a new `BlockingTaskGuard` with the given maximum number of blocking tasks in
     /// parallel.
     pub fn new(max_blocking_tasks: usize) -> Self {
-        Self(Arc::new(Semaphore::new(max_blocking_tasks)))
+        Self {
+            semaphore: Arc::new(Semaphore::new(max_blocking_tasks)),
+            max_blocking_tasks: Arc::new(AtomicUsize::new(max_blocking_tasks)),
+            debt: Arc::new(Mutex::new(0)),
+        }
+    }
+
+    /// Returns the maximum number of blocking tasks in parallel.
+    pub fn max_blocking_tasks(&self) -> usize {
+        self.max_blocking_tasks.load(Ordering::Relaxed)
+    }
+
+    /// Sets the maximum number of blocking tasks in parallel.
+    ///
+    /// Running tasks are not interrupted if the maximum is lowered, the excess permits still in
+    /// use are forgotten once they are released and acquired again. Raising the maximum first
+    /// settles the permits that are still owed.
+    pub fn set_max_blocking_tasks(&self, max_blocking_tasks: usize) {
+        let mut debt = self.debt.lock();
+        let previous = self.max_blocking_tasks.swap(max_blocking_tasks, Ordering::Relaxed);
+        if max_blocking_tasks > previous {
+            let added = max_blocking_tasks - previous;
+            let settled = added.min(*debt);
+            *debt -= settled;
+            self.semaphore.add_permits(added - settled);
+        } else if max_blocking_tasks < previous {
+            let excess = previous - max_blocking_tasks;
+            *debt += excess - self.semaphore.forget_permits(excess);
+        }
     }
 
     /// See also [`Semaphore::acquire_owned`]
     pub async fn acquire_owned(self) -> Result<OwnedSemaphorePermit, AcquireError> {
-        self.0.acquire_owned().await
+        self.acquire_many_owned(1).await
     }
 
     /// See also [`Semaphore::acquire_many_owned`]
     pub async fn acquire_many_owned(self, n: u32) -> Result<OwnedSemaphorePermit, AcquireError> {
-        self.0.acquire_many_owned(n).await
+        loop {
+            let permit = self.semaphore.clone().acquire_many_owned(n).await?;
+
+            let mut debt = self.debt.lock();
+            if *debt == 0 {
+                return Ok(permit)
+            }
+
+            // permits owed after lowering the maximum are forgotten instead of handed out
+            let settled = (n as usize).min(*debt);
+            *debt -= settled;
+            permit.forget();
+            self.semaphore.add_permits(n as usize - settled);
+        }
     }
 }
 
@@ -446,6 +495,7 @@ impl Worker {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
 
     #[tokio::test]
     async fn blocking_pool() {
@@ -465,6 +515,53 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[tokio::test]
+    async fn resize_blocking_task_guard() {
+        let guard = BlockingTaskGuard::new(2);
+        let running = guard.clone().acquire_owned().await.unwrap();
+
+        // the permit in use is forgotten once it's released and acquired again
+        guard.set_max_blocking_tasks(0);
+        assert_eq!(guard.max_blocking_tasks(), 0);
+        assert_eq!(guard.semaphore.available_permits(), 0);
+        drop(running);
+        let acquire =
+            tokio::time::timeout(Duration::from_millis(50), guard.clone().acquire_owned());
+        assert!(acquire.await.is_err());
+        assert_eq!(guard.semaphore.available_permits(), 0);
+
+        guard.set_max_blocking_tasks(3);
+        assert_eq!(guard.semaphore.available_permits(), 3);
+    }
+
+    #[tokio::test]
+    async fn lower_then_raise_blocking_task_guard() {
+        let guard = BlockingTaskGuard::new(2);
+        let first = guard.clone().acquire_owned().await.unwrap();
+        let second = guard.clone().acquire_owned().await.unwrap();
+
+        // raising the maximum before the permits in use are released settles them
+        guard.set_max_blocking_tasks(0);
+        guard.set_max_blocking_tasks(2);
+        assert_eq!(guard.max_blocking_tasks(), 2);
+        assert_eq!(guard.semaphore.available_permits(), 0);
+        drop(first);
+        drop(second);
+
+        let permits = guard.clone().acquire_many_owned(2).await.unwrap();
+        assert_eq!(permits.num_permits(), 2);
+        drop(permits);
+        assert_eq!(guard.semaphore.available_permits(), 2);
+
+        // partially settled
+        let running = guard.clone().acquire_owned().await.unwrap();
+        guard.set_max_blocking_tasks(0);
+        guard.set_max_blocking_tasks(1);
+        drop(running);
+        let _permit = guard.clone().acquire_owned().await.unwrap();
+        assert_eq!(guard.semaphore.available_permits(), 0);
+    }
+
     #[test]
     fn worker_pool_init_and_access() {
         let pool = WorkerPool::new(2, "test");
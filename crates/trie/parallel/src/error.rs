@@ -11,6 +11,11 @@ pub enum StateRootTaskError {
     /// Sparse trie error.
     #[error(transparent)]
     SparseTrie(#[from] SparseTrieError),
+    /// The computation was cancelled through its [`StateRootCancellation`] handle.
+    ///
+    /// [`StateRootCancellation`]: crate::state_root_task::StateRootCancellation
+    #[error("state root computation cancelled")]
+    Cancelled,
     /// Other unspecified error.
     #[error("{_0}")]
     Other(String),
@@ -61,6 +61,8 @@ pub struct StateRootHandle {
         Option<std::sync::mpsc::Receiver<Result<StateRootComputeOutcome, StateRootTaskError>>>,
     /// Receiver for the hashed post state.
     hashed_state_rx: Option<std::sync::mpsc::Receiver<HashedPostState>>,
+    /// Handle to cancel the computation, if supported by the task.
+    cancellation: Option<StateRootCancellation>,
 }
 
 impl StateRootHandle {
@@ -78,9 +80,21 @@ impl StateRootHandle {
             updates_tx,
             state_root_rx: Some(state_root_rx),
             hashed_state_rx: Some(hashed_state_rx),
+            cancellation: None,
         }
     }
 
+    /// Sets the handle used to cancel the computation.
+    pub fn with_cancellation(mut self, cancellation: StateRootCancellation) -> Self {
+        self.cancellation = Some(cancellation);
+        self
+    }
+
+    /// Returns the handle to cancel the computation, if the task supports cancellation.
+    pub const fn cancellation(&self) -> Option<&StateRootCancellation> {
+        self.cancellation.as_ref()
+    }
+
     /// Returns the state root that the cached sparse trie is anchored at.
     pub const fn cached_trie_state_root(&self) -> B256 {
         self.cached_trie_state_root
@@ -136,6 +150,33 @@ impl StateRootHandle {
     }
 }
 
+/// Handle to cancel a background state root computation.
+///
+/// Cancelling makes the task stop dispatching and revealing proofs and return
+/// [`StateRootTaskError::Cancelled`] instead of a state root. This is useful to release the proof
+/// workers early once the result is no longer needed, e.g. because a newer payload superseded the
+/// one being validated.
+#[derive(Debug, Clone)]
+pub struct StateRootCancellation(crossbeam_channel::Sender<()>);
+
+impl StateRootCancellation {
+    /// Creates a new cancellation handle, returning the receiver the task should select on.
+    pub fn new() -> (Self, crossbeam_channel::Receiver<()>) {
+        let (tx, rx) = crossbeam_channel::bounded(1);
+        (Self(tx), rx)
+    }
+
+    /// Requests cancellation of the computation.
+    ///
+    /// Returns `false` if the computation has already finished.
+    pub fn cancel(&self) -> bool {
+        match self.0.try_send(()) {
+            Ok(()) | Err(crossbeam_channel::TrySendError::Full(())) => true,
+            Err(crossbeam_channel::TrySendError::Disconnected(())) => false,
+        }
+    }
+}
+
 /// A wrapper for the sender that signals completion when dropped.
 ///
 /// This type is intended to be used in combination with the evm executor statehook.
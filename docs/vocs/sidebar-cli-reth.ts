@@ -111,6 +111,33 @@ export const rethCliSidebar: SidebarItem = {
                         }
                     ]
                 },
+                {
+                    text: "reth db drop-table",
+                    link: "/cli/reth/db/drop-table"
+                },
+                {
+                    text: "reth db restore-table",
+                    link: "/cli/reth/db/restore-table"
+                },
+                {
+                    text: "reth db rebuild",
+                    link: "/cli/reth/db/rebuild",
+                    collapsed: true,
+                    items: [
+                        {
+                            text: "reth db rebuild tx-lookup",
+                            link: "/cli/reth/db/rebuild/tx-lookup"
+                        }
+                    ]
+                },
+                {
+                    text: "reth db compact-history",
+                    link: "/cli/reth/db/compact-history"
+                },
+                {
+                    text: "reth db pack-storage-changesets",
+                    link: "/cli/reth/db/pack-storage-changesets"
+                },
                 {
                     text: "reth db repair-trie",
                     link: "/cli/reth/db/repair-trie"
@@ -130,6 +157,21 @@ export const rethCliSidebar: SidebarItem = {
                         }
                     ]
                 },
+                {
+                    text: "reth db static-file",
+                    link: "/cli/reth/db/static-file",
+                    collapsed: true,
+                    items: [
+                        {
+                            text: "reth db static-file recompress",
+                            link: "/cli/reth/db/static-file/recompress"
+                        },
+                        {
+                            text: "reth db static-file verify",
+                            link: "/cli/reth/db/static-file/verify"
+                        }
+                    ]
+                },
                 {
                     text: "reth db version",
                     link: "/cli/reth/db/version"
@@ -198,6 +240,10 @@ export const rethCliSidebar: SidebarItem = {
                     text: "reth db state",
                     link: "/cli/reth/db/state"
                 },
+                {
+                    text: "reth db tx-lookup",
+                    link: "/cli/reth/db/tx-lookup"
+                },
                 {
                     text: "reth db migrate-v2",
                     link: "/cli/reth/db/migrate-v2"
@@ -301,7 +347,14 @@ export const rethCliSidebar: SidebarItem = {
         },
         {
             text: "reth config",
-            link: "/cli/reth/config"
+            link: "/cli/reth/config",
+            collapsed: true,
+            items: [
+                {
+                    text: "reth config validate",
+                    link: "/cli/reth/config/validate"
+                }
+            ]
         },
         {
             text: "reth prune",